@@ -141,6 +141,8 @@ pub enum AstKind<'a> {
     TSVoidKeyword(&'a TSVoidKeyword),
 
     TSIndexedAccessType(&'a TSIndexedAccessType<'a>),
+    TSArrayType(&'a TSArrayType<'a>),
+    TSTypeOperatorType(&'a TSTypeOperatorType<'a>),
 
     TSAsExpression(&'a TSAsExpression<'a>),
     TSSatisfiesExpression(&'a TSSatisfiesExpression<'a>),
@@ -445,6 +447,8 @@ impl<'a> GetSpan for AstKind<'a> {
             Self::TSVoidKeyword(x) => x.span,
 
             Self::TSIndexedAccessType(x) => x.span,
+            Self::TSArrayType(x) => x.span,
+            Self::TSTypeOperatorType(x) => x.span,
 
             Self::TSAsExpression(x) => x.span,
             Self::TSSatisfiesExpression(x) => x.span,
@@ -621,6 +625,8 @@ impl<'a> AstKind<'a> {
             Self::TSVoidKeyword(_) => "TSVoidKeyword".into(),
 
             Self::TSIndexedAccessType(_) => "TSIndexedAccessType".into(),
+            Self::TSArrayType(_) => "TSArrayType".into(),
+            Self::TSTypeOperatorType(_) => "TSTypeOperatorType".into(),
 
             Self::TSAsExpression(_) => "TSAsExpression".into(),
             Self::TSSatisfiesExpression(_) => "TSSatisfiesExpression".into(),
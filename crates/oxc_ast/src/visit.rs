@@ -1593,7 +1593,10 @@ pub trait Visit<'a>: Sized {
     }
 
     fn visit_ts_type_operator_type(&mut self, ty: &TSTypeOperatorType<'a>) {
+        let kind = AstKind::TSTypeOperatorType(self.alloc(ty));
+        self.enter_node(kind);
         self.visit_ts_type(&ty.type_annotation);
+        self.leave_node(kind);
     }
 
     fn visit_ts_tuple_type(&mut self, ty: &TSTupleType<'a>) {
@@ -1678,7 +1681,10 @@ pub trait Visit<'a>: Sized {
     }
 
     fn visit_ts_array_type(&mut self, ty: &TSArrayType<'a>) {
+        let kind = AstKind::TSArrayType(self.alloc(ty));
+        self.enter_node(kind);
         self.visit_ts_type(&ty.element_type);
+        self.leave_node(kind);
     }
 
     fn visit_ts_type_name(&mut self, name: &TSTypeName<'a>) {
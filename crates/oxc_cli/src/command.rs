@@ -1,6 +1,6 @@
 use bpaf::{doc::Style, Bpaf};
-use oxc_linter::AllowWarnDeny;
-use std::{ffi::OsString, path::PathBuf};
+use oxc_linter::{AllowWarnDeny, FixFilter};
+use std::{ffi::OsString, path::PathBuf, str::FromStr};
 
 #[derive(Debug, Clone, Bpaf)]
 #[bpaf(options)]
@@ -98,6 +98,11 @@ pub struct EnablePlugins {
     /// Enable the JSX-a11y plugin and detect accessibility problems
     #[bpaf(switch, hide_usage)]
     pub jsx_a11y_plugin: bool,
+
+    /// Enable the Markdown plugin and lint the JavaScript and TypeScript code blocks of
+    /// Markdown files
+    #[bpaf(switch, hide_usage)]
+    pub markdown_plugin: bool,
 }
 
 #[derive(Debug, Clone, Bpaf)]
@@ -117,6 +122,12 @@ pub struct LintOptions {
     #[bpaf(external)]
     pub warning_options: WarningOptions,
 
+    #[bpaf(external)]
+    pub output_options: OutputOptions,
+
+    #[bpaf(external)]
+    pub cache_options: CacheOptions,
+
     #[bpaf(external)]
     pub misc_options: MiscOptions,
 
@@ -205,6 +216,10 @@ pub struct FixOptions {
     /// Fix as many issues as possible. Only unfixed issues are reported in the output
     #[bpaf(switch)]
     pub fix: bool,
+
+    /// Kind of fixes applied by `--fix` (safe, all). Dangerous fixes may change the behavior of the code
+    #[bpaf(argument("KIND"), fallback(FixFilter::Safe), hide_usage)]
+    pub fix_kind: FixFilter,
 }
 
 const NO_IGNORE_HELP: &[(&str, Style)] = &[
@@ -249,6 +264,67 @@ pub struct WarningOptions {
     /// which can be used to force exit with an error status if there are too many warning-level rule violations in your project
     #[bpaf(argument("INT"), hide_usage)]
     pub max_warnings: Option<usize>,
+
+    /// Report `eslint-disable` directives which do not suppress any problem,
+    /// `--fix` removes them
+    #[bpaf(switch, hide_usage)]
+    pub report_unused_disable_directives: bool,
+}
+
+/// Output
+#[derive(Debug, Clone, Bpaf)]
+pub struct OutputOptions {
+    /// Use a specific output format (default, json, sarif, checkstyle)
+    #[bpaf(long, short('f'), argument("FORMAT"), fallback(OutputFormat::Default), hide_usage)]
+    pub format: OutputFormat,
+}
+
+/// Caching
+#[derive(Debug, Clone, Bpaf)]
+pub struct CacheOptions {
+    /// Only lint changed files, reusing the results of the previous run for the others
+    #[bpaf(switch, hide_usage)]
+    pub cache: bool,
+
+    /// Path to the cache file
+    #[bpaf(argument("PATH"), fallback(".oxc/cache".into()), hide_usage)]
+    pub cache_location: PathBuf,
+
+    /// Disable the cache, overrides `--cache`
+    #[bpaf(switch, hide_usage)]
+    pub no_cache: bool,
+}
+
+impl CacheOptions {
+    /// The cache file to use, `None` when caching is disabled.
+    pub fn cache_location(&self) -> Option<PathBuf> {
+        (self.cache && !self.no_cache).then(|| self.cache_location.clone())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputFormat {
+    Default,
+    /// ESLint compatible json
+    Json,
+    /// SARIF 2.1.0 for code scanning integrations
+    Sarif,
+    /// Checkstyle xml
+    Checkstyle,
+}
+
+impl FromStr for OutputFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "default" => Ok(Self::Default),
+            "json" => Ok(Self::Json),
+            "sarif" => Ok(Self::Sarif),
+            "checkstyle" => Ok(Self::Checkstyle),
+            _ => Err(format!("'{s}' is not a known format")),
+        }
+    }
 }
 
 #[derive(Debug, Clone, Bpaf)]
@@ -316,6 +392,7 @@ mod warning_options {
         let options = get_warning_options(".");
         assert!(!options.quiet);
         assert_eq!(options.max_warnings, None);
+        assert!(!options.report_unused_disable_directives);
     }
 
     #[test]
@@ -329,12 +406,83 @@ mod warning_options {
         let options = get_warning_options("--max-warnings 10 .");
         assert_eq!(options.max_warnings, Some(10));
     }
+
+    #[test]
+    fn report_unused_disable_directives() {
+        let options = get_warning_options("--report-unused-disable-directives .");
+        assert!(options.report_unused_disable_directives);
+    }
+}
+
+#[cfg(test)]
+mod output_options {
+    use super::{lint_command, OutputFormat, OutputOptions};
+
+    fn get_output_options(arg: &str) -> OutputOptions {
+        let args = arg.split(' ').map(std::string::ToString::to_string).collect::<Vec<_>>();
+        lint_command().run_inner(args.as_slice()).unwrap().lint_options.output_options
+    }
+
+    #[test]
+    fn default() {
+        let options = get_output_options(".");
+        assert_eq!(options.format, OutputFormat::Default);
+    }
+
+    #[test]
+    fn format() {
+        let options = get_output_options("--format json .");
+        assert_eq!(options.format, OutputFormat::Json);
+        let options = get_output_options("--format sarif .");
+        assert_eq!(options.format, OutputFormat::Sarif);
+        let options = get_output_options("--format checkstyle .");
+        assert_eq!(options.format, OutputFormat::Checkstyle);
+        let options = get_output_options("-f default .");
+        assert_eq!(options.format, OutputFormat::Default);
+    }
+
+    #[test]
+    fn unknown_format() {
+        let args = ["--format", "xml", "."];
+        assert!(lint_command().run_inner(args.as_slice()).is_err());
+    }
+}
+
+#[cfg(test)]
+mod cache_options {
+    use super::{lint_command, CacheOptions};
+    use std::path::PathBuf;
+
+    fn get_cache_options(arg: &str) -> CacheOptions {
+        let args = arg.split(' ').map(std::string::ToString::to_string).collect::<Vec<_>>();
+        lint_command().run_inner(args.as_slice()).unwrap().lint_options.cache_options
+    }
+
+    #[test]
+    fn default() {
+        let options = get_cache_options(".");
+        assert_eq!(options.cache_location(), None);
+    }
+
+    #[test]
+    fn cache() {
+        let options = get_cache_options("--cache .");
+        assert_eq!(options.cache_location(), Some(PathBuf::from(".oxc/cache")));
+        let options = get_cache_options("--cache --cache-location foo/cache .");
+        assert_eq!(options.cache_location(), Some(PathBuf::from("foo/cache")));
+    }
+
+    #[test]
+    fn no_cache() {
+        let options = get_cache_options("--cache --no-cache .");
+        assert_eq!(options.cache_location(), None);
+    }
 }
 
 #[cfg(test)]
 mod lint_options {
     use super::{lint_command, LintOptions};
-    use oxc_linter::AllowWarnDeny;
+    use oxc_linter::{AllowWarnDeny, FixFilter};
     use std::path::PathBuf;
 
     fn get_lint_options(arg: &str) -> LintOptions {
@@ -362,6 +510,11 @@ mod lint_options {
     fn fix() {
         let options = get_lint_options("--fix test.js");
         assert!(options.fix_options.fix);
+        assert_eq!(options.fix_options.fix_kind, FixFilter::Safe);
+        let options = get_lint_options("--fix --fix-kind all test.js");
+        assert_eq!(options.fix_options.fix_kind, FixFilter::All);
+        let args = ["--fix-kind", "none", "test.js"];
+        assert!(lint_command().run_inner(args.as_slice()).is_err());
     }
 
     #[test]
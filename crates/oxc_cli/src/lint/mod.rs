@@ -1,12 +1,18 @@
 use std::{env, io::BufWriter, path::Path, vec::Vec};
 
-use oxc_diagnostics::{DiagnosticService, GraphicalReportHandler};
-use oxc_linter::{partial_loader::LINT_PARTIAL_LOADER_EXT, LintOptions, LintService, Linter};
+use oxc_diagnostics::{
+    CheckstyleReporter, DiagnosticReporter, DiagnosticService, GraphicalReportHandler,
+    GraphicalReporter, JsonReporter, SarifReporter,
+};
+use oxc_linter::{
+    partial_loader::{LINT_MARKDOWN_EXT, LINT_PARTIAL_LOADER_EXT},
+    LintOptions, LintService, Linter,
+};
 use oxc_span::VALID_EXTENSIONS;
 
 use crate::{
     codeowners,
-    command::LintOptions as CliLintOptions,
+    command::{LintOptions as CliLintOptions, OutputFormat},
     walk::{Extensions, Walk},
     CliRunResult, CodeownerOptions, LintResult, Runner,
 };
@@ -66,6 +72,8 @@ impl Runner for LintRunner {
             paths,
             filter,
             warning_options,
+            output_options,
+            cache_options,
             ignore_options,
             fix_options,
             misc_options,
@@ -88,9 +96,11 @@ impl Runner for LintRunner {
 
         let now = std::time::Instant::now();
 
+        let markdown_ext = if enable_plugins.markdown_plugin { LINT_MARKDOWN_EXT } else { &[] };
         let extensions = VALID_EXTENSIONS
             .iter()
             .chain(LINT_PARTIAL_LOADER_EXT.iter())
+            .chain(markdown_ext.iter())
             .copied()
             .collect::<Vec<&'static str>>();
 
@@ -109,10 +119,16 @@ impl Runner for LintRunner {
             .with_filter(filter)
             .with_config_path(config)
             .with_fix(fix_options.fix)
+            .with_fix_kind(fix_options.fix_kind)
+            .with_report_fixes(output_options.format == OutputFormat::Json)
             .with_timing(misc_options.timing)
+            .with_max_warnings(warning_options.max_warnings)
+            .with_report_unused_directives(warning_options.report_unused_disable_directives)
+            .with_cache_location(cache_options.cache_location())
             .with_import_plugin(enable_plugins.import_plugin)
             .with_jest_plugin(enable_plugins.jest_plugin)
-            .with_jsx_a11y_plugin(enable_plugins.jsx_a11y_plugin);
+            .with_jsx_a11y_plugin(enable_plugins.jsx_a11y_plugin)
+            .with_markdown_plugin(enable_plugins.markdown_plugin);
 
         let linter = match Linter::from_options(lint_options) {
             Ok(lint_service) => lint_service,
@@ -129,9 +145,19 @@ impl Runner for LintRunner {
 
         let lint_service = LintService::new(cwd, &paths, linter);
 
-        let diagnostic_service = DiagnosticService::default()
+        let reporter: Box<dyn DiagnosticReporter> = match output_options.format {
+            OutputFormat::Default => Box::<GraphicalReporter>::default(),
+            OutputFormat::Json => Box::<JsonReporter>::default(),
+            OutputFormat::Sarif => Box::new(SarifReporter::new(
+                "oxlint",
+                Linter::rules_metadata().iter().map(Into::into).collect(),
+            )),
+            OutputFormat::Checkstyle => Box::<CheckstyleReporter>::default(),
+        };
+        let mut diagnostic_service = DiagnosticService::default()
             .with_quiet(warning_options.quiet)
-            .with_max_warnings(warning_options.max_warnings);
+            .with_max_warnings(warning_options.max_warnings)
+            .with_reporter(reporter);
 
         // Spawn linting in another thread so diagnostics can be printed immediately from diagnostic_service.run.
         rayon::spawn({
@@ -144,15 +170,18 @@ impl Runner for LintRunner {
         diagnostic_service.run();
 
         lint_service.linter().print_execution_times_if_enable();
+        let lint_result = lint_service.result();
 
         CliRunResult::LintResult(LintResult {
             duration: now.elapsed(),
             number_of_rules: lint_service.linter().number_of_rules(),
             number_of_files,
-            number_of_warnings: diagnostic_service.warnings_count(),
-            number_of_errors: diagnostic_service.errors_count(),
-            max_warnings_exceeded: diagnostic_service.max_warnings_exceeded(),
+            number_of_warnings: lint_result.warnings,
+            number_of_errors: lint_result.errors,
+            number_of_fixables: lint_result.fixables,
+            max_warnings_exceeded: lint_service.max_warnings_exceeded(),
             deny_warnings: warning_options.deny_warnings,
+            print_summary: output_options.format == OutputFormat::Default,
         })
     }
 }
@@ -206,8 +235,13 @@ impl LintRunner {
 
 #[cfg(all(test, not(target_os = "windows")))]
 mod test {
+    use std::{path::PathBuf, sync::mpsc};
+
+    use oxc_diagnostics::{CheckstyleReporter, DiagnosticReporter, JsonReporter, SarifReporter};
+    use oxc_linter::{LintOptions, LintService, Linter};
+
     use super::LintRunner;
-    use crate::{lint_command, CliRunResult, LintResult, Runner};
+    use crate::{lint_command, walk::Walk, CliRunResult, LintResult, Runner};
 
     fn test(args: &[&str]) -> LintResult {
         let mut new_args = vec!["--quiet"];
@@ -231,8 +265,8 @@ mod test {
         let args = &[];
         let result = test(args);
         assert!(result.number_of_rules > 0);
-        assert_eq!(result.number_of_files, 5);
-        assert_eq!(result.number_of_warnings, 3);
+        assert_eq!(result.number_of_files, 6);
+        assert_eq!(result.number_of_warnings, 4);
         assert_eq!(result.number_of_errors, 0);
     }
 
@@ -275,7 +309,15 @@ mod test {
 
     #[test]
     fn ignore_pattern() {
-        let args = &["--ignore-pattern", "**/*.js", "--ignore-pattern", "**/*.vue", "fixtures"];
+        let args = &[
+            "--ignore-pattern",
+            "**/*.js",
+            "--ignore-pattern",
+            "**/*.vue",
+            "--ignore-pattern",
+            "**/*.astro",
+            "fixtures",
+        ];
         let result = test(args);
         assert_eq!(result.number_of_files, 0);
         assert_eq!(result.number_of_warnings, 0);
@@ -300,6 +342,43 @@ mod test {
         assert_eq!(result.number_of_errors, 0);
     }
 
+    #[test]
+    fn max_warnings() {
+        // `fixtures/linter` has 3 warnings
+        let result = test(&["--max-warnings", "3", "fixtures/linter"]);
+        assert_eq!(result.number_of_warnings, 3);
+        assert!(!result.max_warnings_exceeded);
+
+        let result = test(&["--max-warnings", "2", "fixtures/linter"]);
+        assert_eq!(result.number_of_warnings, 3);
+        assert!(result.max_warnings_exceeded);
+    }
+
+    #[test]
+    fn fixables() {
+        let result = test(&["fixtures/linter/debugger.js"]);
+        assert_eq!(result.number_of_warnings, 1);
+        assert_eq!(result.number_of_fixables, 1);
+
+        let result = test(&["fixtures/linter/nan.js"]);
+        assert_eq!(result.number_of_fixables, 0);
+    }
+
+    #[test]
+    fn cache() {
+        let cache_location = std::env::temp_dir().join("oxc_cli_lint_cache");
+        let _ = std::fs::remove_file(&cache_location);
+        let cache_location = cache_location.to_string_lossy().to_string();
+        let args = &["--cache", "--cache-location", &cache_location, "fixtures/linter"];
+        let first = test(args);
+        assert!(std::path::Path::new(&cache_location).exists());
+        let second = test(args);
+        assert_eq!(first.number_of_warnings, 3);
+        assert_eq!(second.number_of_warnings, first.number_of_warnings);
+        assert_eq!(second.number_of_fixables, first.number_of_fixables);
+        std::fs::remove_file(&cache_location).unwrap();
+    }
+
     #[test]
     fn test_lint_vue_file() {
         let args = &["fixtures/linter/debugger.vue"];
@@ -308,4 +387,101 @@ mod test {
         assert_eq!(result.number_of_warnings, 1);
         assert_eq!(result.number_of_errors, 0);
     }
+
+    #[test]
+    fn test_lint_astro_file() {
+        // `debugger` is in the frontmatter, after a `---` in a template literal
+        let args = &["fixtures/astro/debugger.astro"];
+        let result = test(args);
+        assert_eq!(result.number_of_files, 1);
+        assert_eq!(result.number_of_warnings, 1);
+        assert_eq!(result.number_of_errors, 0);
+    }
+
+    #[test]
+    fn test_lint_markdown_file() {
+        let args = &["fixtures/markdown/README.md"];
+        let result = test(args);
+        assert_eq!(result.number_of_files, 0);
+
+        let args = &["--markdown-plugin", "fixtures/markdown/README.md"];
+        let result = test(args);
+        assert_eq!(result.number_of_files, 1);
+        assert_eq!(result.number_of_warnings, 2);
+        assert_eq!(result.number_of_errors, 0);
+    }
+
+    /// Lint `fixtures/linter` and return the output of `reporter`
+    fn report(reporter: &mut dyn DiagnosticReporter) -> String {
+        let paths = [PathBuf::from("fixtures/linter")];
+        let ignore_options = lint_command().run_inner(&["."]).unwrap().lint_options.ignore_options;
+        let paths = Walk::new(&paths, &ignore_options).paths();
+        let options = LintOptions::default().with_report_fixes(true);
+        let linter = Linter::from_options(options).unwrap();
+        let cwd = std::env::current_dir().unwrap().into_boxed_path();
+        let lint_service = LintService::new(cwd, &paths, linter);
+
+        let (tx_error, rx_error) = mpsc::channel();
+        lint_service.run(&tx_error);
+        let mut results = rx_error.into_iter().map_while(|result| result).collect::<Vec<_>>();
+        // files are linted in parallel
+        results.sort_by(|(a, _), (b, _)| a.cmp(b));
+        for (path, diagnostics) in results {
+            reporter.render_diagnostics(&path, diagnostics);
+        }
+
+        reporter.finish().unwrap()
+    }
+
+    #[test]
+    fn json_format() {
+        let output = report(&mut JsonReporter::default());
+        let json = serde_json::from_str::<serde_json::Value>(&output).unwrap();
+        insta::assert_snapshot!(serde_json::to_string_pretty(&json).unwrap());
+    }
+
+    #[test]
+    fn sarif_format() {
+        let output = report(&mut SarifReporter::new(
+            "oxlint",
+            Linter::rules_metadata().iter().map(Into::into).collect(),
+        ));
+        let log = serde_json::from_str::<serde_json::Value>(&output).unwrap();
+
+        // Required properties and value constraints of the SARIF 2.1.0 schema
+        assert_eq!(log["version"], "2.1.0");
+        assert_eq!(log["$schema"], "https://json.schemastore.org/sarif-2.1.0.json");
+        let run = &log["runs"][0];
+        assert_eq!(run["tool"]["driver"]["name"], "oxlint");
+        let rules = run["tool"]["driver"]["rules"].as_array().unwrap();
+        assert!(!rules.is_empty());
+        assert!(rules.iter().all(|rule| rule["id"].is_string()));
+        let results = run["results"].as_array().unwrap();
+        assert!(!results.is_empty());
+        for result in results {
+            assert!(result["message"]["text"].is_string());
+            assert!(
+                ["none", "note", "warning", "error"].contains(&result["level"].as_str().unwrap())
+            );
+            let rule_index = usize::try_from(result["ruleIndex"].as_u64().unwrap()).unwrap();
+            assert_eq!(rules[rule_index]["id"], result["ruleId"]);
+            let region = &result["locations"][0]["physicalLocation"]["region"];
+            for key in ["startLine", "startColumn", "endLine", "endColumn"] {
+                assert!(region[key].as_u64().unwrap() >= 1);
+            }
+        }
+
+        // rule indices change whenever a rule is added
+        let mut results = run["results"].clone();
+        for result in results.as_array_mut().unwrap() {
+            result.as_object_mut().unwrap().remove("ruleIndex");
+        }
+        insta::assert_snapshot!(serde_json::to_string_pretty(&results).unwrap());
+    }
+
+    #[test]
+    fn checkstyle_format() {
+        let output = report(&mut CheckstyleReporter::default());
+        insta::assert_snapshot!(output.replace("><", ">\n<"));
+    }
 }
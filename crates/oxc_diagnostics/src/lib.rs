@@ -3,11 +3,18 @@
 
 mod graphic_reporter;
 mod graphical_theme;
+mod reporter;
 mod service;
 
-use std::path::PathBuf;
+use std::{fmt, ops::Range, path::PathBuf};
 
-pub use crate::service::{DiagnosticSender, DiagnosticService, DiagnosticTuple};
+pub use crate::{
+    reporter::{
+        CheckstyleReporter, DiagnosticReporter, GraphicalReporter, JsonReporter, RuleMetadata,
+        SarifReporter,
+    },
+    service::{DiagnosticSender, DiagnosticService, DiagnosticTuple},
+};
 pub use graphic_reporter::{GraphicalReportHandler, GraphicalTheme};
 pub use miette;
 pub use thiserror;
@@ -18,9 +25,203 @@ pub type Report = miette::Report;
 
 pub type Result<T> = std::result::Result<T, Error>;
 
-use miette::Diagnostic;
+use miette::{Diagnostic, LabeledSpan, SourceCode};
 use thiserror::Error;
 
+/// A diagnostic reported with another severity than its own,
+/// e.g. the severity configured for a lint rule.
+#[derive(Debug)]
+pub struct DiagnosticWithSeverity {
+    diagnostic: Error,
+    severity: Severity,
+}
+
+impl DiagnosticWithSeverity {
+    pub fn new(diagnostic: Error, severity: Severity) -> Self {
+        Self { diagnostic, severity }
+    }
+}
+
+impl fmt::Display for DiagnosticWithSeverity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.diagnostic, f)
+    }
+}
+
+impl std::error::Error for DiagnosticWithSeverity {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        self.diagnostic.source()
+    }
+}
+
+impl Diagnostic for DiagnosticWithSeverity {
+    fn code<'a>(&'a self) -> Option<Box<dyn fmt::Display + 'a>> {
+        self.diagnostic.code()
+    }
+
+    fn severity(&self) -> Option<Severity> {
+        Some(self.severity)
+    }
+
+    fn help<'a>(&'a self) -> Option<Box<dyn fmt::Display + 'a>> {
+        self.diagnostic.help()
+    }
+
+    fn url<'a>(&'a self) -> Option<Box<dyn fmt::Display + 'a>> {
+        self.diagnostic.url()
+    }
+
+    fn source_code(&self) -> Option<&dyn SourceCode> {
+        self.diagnostic.source_code()
+    }
+
+    fn labels(&self) -> Option<Box<dyn Iterator<Item = LabeledSpan> + '_>> {
+        self.diagnostic.labels()
+    }
+
+    fn related<'a>(&'a self) -> Option<Box<dyn Iterator<Item = &'a dyn Diagnostic> + 'a>> {
+        self.diagnostic.related()
+    }
+
+    fn diagnostic_source(&self) -> Option<&dyn Diagnostic> {
+        self.diagnostic.diagnostic_source()
+    }
+}
+
+/// A diagnostic with its labels moved by `offset` bytes,
+/// e.g. one reported on code extracted from a larger file.
+#[derive(Debug)]
+pub struct DiagnosticWithOffset {
+    diagnostic: Error,
+    offset: usize,
+}
+
+impl DiagnosticWithOffset {
+    pub fn new(diagnostic: Error, offset: usize) -> Self {
+        Self { diagnostic, offset }
+    }
+}
+
+impl fmt::Display for DiagnosticWithOffset {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.diagnostic, f)
+    }
+}
+
+impl std::error::Error for DiagnosticWithOffset {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        self.diagnostic.source()
+    }
+}
+
+impl Diagnostic for DiagnosticWithOffset {
+    fn code<'a>(&'a self) -> Option<Box<dyn fmt::Display + 'a>> {
+        self.diagnostic.code()
+    }
+
+    fn severity(&self) -> Option<Severity> {
+        self.diagnostic.severity()
+    }
+
+    fn help<'a>(&'a self) -> Option<Box<dyn fmt::Display + 'a>> {
+        self.diagnostic.help()
+    }
+
+    fn url<'a>(&'a self) -> Option<Box<dyn fmt::Display + 'a>> {
+        self.diagnostic.url()
+    }
+
+    fn source_code(&self) -> Option<&dyn SourceCode> {
+        self.diagnostic.source_code()
+    }
+
+    fn labels(&self) -> Option<Box<dyn Iterator<Item = LabeledSpan> + '_>> {
+        let labels = self.diagnostic.labels()?;
+        Some(Box::new(labels.map(|label| {
+            let span = (label.offset() + self.offset, label.len());
+            LabeledSpan::new_with_span(label.label().map(ToString::to_string), span)
+        })))
+    }
+
+    fn related<'a>(&'a self) -> Option<Box<dyn Iterator<Item = &'a dyn Diagnostic> + 'a>> {
+        self.diagnostic.related()
+    }
+
+    fn diagnostic_source(&self) -> Option<&dyn Diagnostic> {
+        self.diagnostic.diagnostic_source()
+    }
+}
+
+/// The replacement of the bytes in `range` by `text`, fixing a diagnostic.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiagnosticFix {
+    pub range: Range<usize>,
+    pub text: String,
+}
+
+/// A diagnostic with the fix reported along with it, e.g. in the JSON output.
+#[derive(Debug)]
+pub struct DiagnosticWithFix {
+    diagnostic: Error,
+    fix: DiagnosticFix,
+}
+
+impl DiagnosticWithFix {
+    pub fn new(diagnostic: Error, fix: DiagnosticFix) -> Self {
+        Self { diagnostic, fix }
+    }
+
+    pub fn fix(&self) -> &DiagnosticFix {
+        &self.fix
+    }
+}
+
+impl fmt::Display for DiagnosticWithFix {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.diagnostic, f)
+    }
+}
+
+impl std::error::Error for DiagnosticWithFix {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        self.diagnostic.source()
+    }
+}
+
+impl Diagnostic for DiagnosticWithFix {
+    fn code<'a>(&'a self) -> Option<Box<dyn fmt::Display + 'a>> {
+        self.diagnostic.code()
+    }
+
+    fn severity(&self) -> Option<Severity> {
+        self.diagnostic.severity()
+    }
+
+    fn help<'a>(&'a self) -> Option<Box<dyn fmt::Display + 'a>> {
+        self.diagnostic.help()
+    }
+
+    fn url<'a>(&'a self) -> Option<Box<dyn fmt::Display + 'a>> {
+        self.diagnostic.url()
+    }
+
+    fn source_code(&self) -> Option<&dyn SourceCode> {
+        self.diagnostic.source_code()
+    }
+
+    fn labels(&self) -> Option<Box<dyn Iterator<Item = LabeledSpan> + '_>> {
+        self.diagnostic.labels()
+    }
+
+    fn related<'a>(&'a self) -> Option<Box<dyn Iterator<Item = &'a dyn Diagnostic> + 'a>> {
+        self.diagnostic.related()
+    }
+
+    fn diagnostic_source(&self) -> Option<&dyn Diagnostic> {
+        self.diagnostic.diagnostic_source()
+    }
+}
+
 #[derive(Debug, Error, Diagnostic)]
 #[error("File is too long to fit on the screen")]
 #[diagnostic(help("{0:?} seems like a minified file"))]
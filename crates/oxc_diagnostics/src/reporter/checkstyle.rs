@@ -0,0 +1,87 @@
+use std::{borrow::Cow, fmt::Write, path::Path};
+
+use super::{line_column, split_scoped_rule_name, DiagnosticReporter};
+use crate::{Error, Severity};
+
+/// Checkstyle xml output, understood by Jenkins and many code review tools.
+///
+/// All files are collected and printed as a single document once linting has finished.
+#[derive(Default)]
+pub struct CheckstyleReporter {
+    files: String,
+}
+
+impl DiagnosticReporter for CheckstyleReporter {
+    fn render_diagnostics(&mut self, path: &Path, diagnostics: Vec<Error>) -> Option<String> {
+        let name = escape_xml(&path.to_string_lossy()).into_owned();
+        write!(self.files, r#"<file name="{name}">"#).unwrap();
+        for diagnostic in diagnostics {
+            let message = diagnostic.to_string();
+            let (source, message) = split_scoped_rule_name(&message)
+                .map_or((None, message.as_str()), |(plugin, rule, message)| {
+                    (Some(format!("{plugin}({rule})")), message)
+                });
+            let (line, column) = diagnostic
+                .source_code()
+                .zip(diagnostic.labels().and_then(|mut labels| labels.next()))
+                .and_then(|(source, label)| line_column(source, label.offset()))
+                .unwrap_or((1, 1));
+            let severity = match diagnostic.severity() {
+                Some(Severity::Error) | None => "error",
+                Some(Severity::Warning) => "warning",
+                Some(Severity::Advice) => "info",
+            };
+            let message = escape_xml(message);
+            write!(
+                self.files,
+                r#"<error line="{line}" column="{column}" severity="{severity}" message="{message}""#
+            )
+            .unwrap();
+            if let Some(source) = source {
+                write!(self.files, r#" source="{}""#, escape_xml(&source)).unwrap();
+            }
+            self.files.push_str(" />");
+        }
+        self.files.push_str("</file>");
+        None
+    }
+
+    fn finish(&mut self) -> Option<String> {
+        Some(format!(
+            r#"<?xml version="1.0" encoding="utf-8"?><checkstyle version="4.3">{}</checkstyle>"#,
+            self.files
+        ))
+    }
+}
+
+fn escape_xml(text: &str) -> Cow<'_, str> {
+    if !text.contains(['&', '<', '>', '"', '\'']) {
+        return Cow::Borrowed(text);
+    }
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            _ => escaped.push(c),
+        }
+    }
+    Cow::Owned(escaped)
+}
+
+#[cfg(test)]
+mod test {
+    use super::escape_xml;
+
+    #[test]
+    fn escape() {
+        assert_eq!(escape_xml("no-debugger"), "no-debugger");
+        assert_eq!(
+            escape_xml(r#"Expected "<div>" & 'x'"#),
+            "Expected &quot;&lt;div&gt;&quot; &amp; &apos;x&apos;"
+        );
+    }
+}
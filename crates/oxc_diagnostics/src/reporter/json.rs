@@ -0,0 +1,109 @@
+use std::path::Path;
+
+use miette::{SourceCode, SourceSpan};
+use serde::Serialize;
+
+use super::{line_column, split_rule_id, DiagnosticReporter};
+use crate::{DiagnosticWithFix, Error, Severity};
+
+/// Output compatible with ESLint's `--format json`.
+///
+/// All files are collected and printed as a single json array once linting has finished.
+#[derive(Default)]
+pub struct JsonReporter {
+    results: Vec<JsonFileResult>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct JsonFileResult {
+    file_path: String,
+    messages: Vec<JsonMessage>,
+    error_count: usize,
+    warning_count: usize,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct JsonMessage {
+    rule_id: Option<String>,
+    /// 1 for warnings and 2 for errors
+    severity: u8,
+    message: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    help: Option<String>,
+    #[serde(flatten, skip_serializing_if = "Option::is_none")]
+    location: Option<JsonLocation>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    fix: Option<JsonFix>,
+}
+
+/// One based line and UTF-16 column numbers, as reported by ESLint.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct JsonLocation {
+    line: usize,
+    column: usize,
+    end_line: usize,
+    end_column: usize,
+}
+
+/// The byte range of the replaced source text, and its replacement.
+#[derive(Debug, Serialize)]
+struct JsonFix {
+    range: [usize; 2],
+    text: String,
+}
+
+impl DiagnosticReporter for JsonReporter {
+    fn render_diagnostics(&mut self, path: &Path, diagnostics: Vec<Error>) -> Option<String> {
+        let messages = diagnostics.iter().map(JsonMessage::new).collect::<Vec<_>>();
+        let error_count = messages.iter().filter(|message| message.severity == 2).count();
+        self.results.push(JsonFileResult {
+            file_path: path.to_string_lossy().to_string(),
+            warning_count: messages.len() - error_count,
+            error_count,
+            messages,
+        });
+        None
+    }
+
+    fn finish(&mut self) -> Option<String> {
+        Some(serde_json::to_string(&self.results).unwrap())
+    }
+}
+
+impl JsonMessage {
+    fn new(diagnostic: &Error) -> Self {
+        let message = diagnostic.to_string();
+        let (rule_id, message) = split_rule_id(&message);
+        let severity = match diagnostic.severity() {
+            Some(Severity::Warning | Severity::Advice) => 1,
+            Some(Severity::Error) | None => 2,
+        };
+        let location = diagnostic
+            .source_code()
+            .zip(diagnostic.labels().and_then(|mut labels| labels.next()))
+            .and_then(|(source, label)| JsonLocation::new(source, *label.inner()));
+        let fix = diagnostic.downcast_ref::<DiagnosticWithFix>().map(|diagnostic| {
+            let fix = diagnostic.fix();
+            JsonFix { range: [fix.range.start, fix.range.end], text: fix.text.clone() }
+        });
+        Self {
+            rule_id,
+            severity,
+            message: message.to_string(),
+            help: diagnostic.help().map(|help| help.to_string()),
+            location,
+            fix,
+        }
+    }
+}
+
+impl JsonLocation {
+    fn new(source: &dyn SourceCode, span: SourceSpan) -> Option<Self> {
+        let (line, column) = line_column(source, span.offset())?;
+        let (end_line, end_column) = line_column(source, span.offset() + span.len())?;
+        Some(Self { line, column, end_line, end_column })
+    }
+}
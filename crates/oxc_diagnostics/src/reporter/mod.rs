@@ -0,0 +1,95 @@
+mod checkstyle;
+mod graphical;
+mod json;
+mod sarif;
+
+use std::path::Path;
+
+use miette::SourceCode;
+
+use crate::Error;
+
+pub use self::{
+    checkstyle::CheckstyleReporter,
+    graphical::GraphicalReporter,
+    json::JsonReporter,
+    sarif::{RuleMetadata, SarifReporter},
+};
+
+/// Output format of the diagnostics printed by [crate::DiagnosticService].
+pub trait DiagnosticReporter {
+    /// Render the diagnostics of a single file.
+    ///
+    /// The returned output is written as soon as the file has been processed.
+    fn render_diagnostics(&mut self, path: &Path, diagnostics: Vec<Error>) -> Option<String>;
+
+    /// Render the output after all files have been processed,
+    /// for formats which emit a single document.
+    fn finish(&mut self) -> Option<String> {
+        None
+    }
+}
+
+/// Split a message such as `eslint(no-debugger): ...` into its plugin, rule and message.
+fn split_scoped_rule_name(message: &str) -> Option<(&str, &str, &str)> {
+    let (prefix, rest) = message.split_once("): ")?;
+    let (plugin, rule) = prefix.split_once('(')?;
+    if plugin.contains(char::is_whitespace) || rule.contains(char::is_whitespace) {
+        return None;
+    }
+    Some((plugin, rule, rest))
+}
+
+/// Split a message such as `eslint(no-debugger): ...` into its ESLint rule id and message.
+fn split_rule_id(message: &str) -> (Option<String>, &str) {
+    let Some((plugin, rule, rest)) = split_scoped_rule_name(message) else {
+        return (None, message);
+    };
+    let rule_id = match plugin {
+        "eslint" => rule.to_string(),
+        "typescript-eslint" => format!("@typescript-eslint/{rule}"),
+        _ => {
+            let plugin = plugin.strip_prefix("eslint-plugin-").unwrap_or(plugin);
+            format!("{plugin}/{rule}")
+        }
+    };
+    (Some(rule_id), rest)
+}
+
+/// One based line and UTF-16 column of `offset`.
+fn line_column(source: &dyn SourceCode, offset: usize) -> Option<(usize, usize)> {
+    // Read with all preceding lines as context so the contents start at the beginning of the file.
+    let contents = source.read_span(&(offset, 0).into(), usize::MAX, 0).ok()?;
+    let prefix = contents.data().get(..offset.checked_sub(contents.span().offset())?)?;
+    let prefix = std::str::from_utf8(prefix).ok()?;
+    let line_start = prefix.rfind('\n').map_or(0, |i| i + 1);
+    let line = prefix.matches('\n').count() + 1;
+    let column = prefix[line_start..].encode_utf16().count() + 1;
+    Some((line, column))
+}
+
+#[cfg(test)]
+mod test {
+    use super::split_rule_id;
+
+    #[test]
+    fn rule_id() {
+        assert_eq!(
+            split_rule_id("eslint(no-debugger): `debugger` statement is not allowed"),
+            (Some("no-debugger".into()), "`debugger` statement is not allowed")
+        );
+        assert_eq!(
+            split_rule_id("typescript-eslint(no-namespace): ES2015 module syntax is preferred"),
+            (Some("@typescript-eslint/no-namespace".into()), "ES2015 module syntax is preferred")
+        );
+        assert_eq!(
+            split_rule_id("eslint-plugin-jest(no-focused-tests): Unexpected focused test."),
+            (Some("jest/no-focused-tests".into()), "Unexpected focused test.")
+        );
+        assert_eq!(split_rule_id("Unexpected token"), (None, "Unexpected token"));
+        assert_eq!(
+            split_rule_id("Expected `(` but found `Identifier`): x"),
+            (None, "Expected `(` but found `Identifier`): x")
+        );
+    }
+}
@@ -0,0 +1,290 @@
+use std::path::Path;
+
+use miette::{LabeledSpan, SourceCode};
+use serde::Serialize;
+
+use super::{line_column, split_rule_id, DiagnosticReporter};
+use crate::{Error, Severity};
+
+const SARIF_SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+const SARIF_VERSION: &str = "2.1.0";
+
+/// Metadata of a rule listed in `runs[0].tool.driver.rules`.
+pub struct RuleMetadata {
+    /// The rule id as it appears in diagnostics, e.g. `no-debugger` or `jest/no-focused-tests`
+    pub id: String,
+    pub category: String,
+    /// Markdown documentation of the rule
+    pub documentation: Option<String>,
+}
+
+/// [SARIF 2.1.0](https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html) output
+/// for code scanning integrations.
+///
+/// All files are collected and printed as a single log once linting has finished.
+pub struct SarifReporter {
+    tool_name: String,
+    rules: Vec<RuleMetadata>,
+    results: Vec<SarifResult>,
+}
+
+#[derive(Debug, Serialize)]
+struct SarifLog<'a> {
+    #[serde(rename = "$schema")]
+    schema: &'static str,
+    version: &'static str,
+    runs: [SarifRun<'a>; 1],
+}
+
+#[derive(Debug, Serialize)]
+struct SarifRun<'a> {
+    tool: SarifTool<'a>,
+    results: &'a [SarifResult],
+}
+
+#[derive(Debug, Serialize)]
+struct SarifTool<'a> {
+    driver: SarifDriver<'a>,
+}
+
+#[derive(Debug, Serialize)]
+struct SarifDriver<'a> {
+    name: &'a str,
+    rules: Vec<SarifRule<'a>>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifRule<'a> {
+    id: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    short_description: Option<SarifMessage>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    help: Option<SarifMarkdownMessage<'a>>,
+    properties: SarifRuleProperties<'a>,
+}
+
+#[derive(Debug, Serialize)]
+struct SarifRuleProperties<'a> {
+    category: &'a str,
+}
+
+#[derive(Debug, Serialize)]
+struct SarifMessage {
+    text: String,
+}
+
+#[derive(Debug, Serialize)]
+struct SarifMarkdownMessage<'a> {
+    text: &'a str,
+    markdown: &'a str,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifResult {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rule_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rule_index: Option<usize>,
+    level: &'static str,
+    message: SarifMessage,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    locations: Vec<SarifLocation>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    related_locations: Vec<SarifLocation>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifLocation {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    id: Option<usize>,
+    physical_location: SarifPhysicalLocation,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    message: Option<SarifMessage>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifPhysicalLocation {
+    artifact_location: SarifArtifactLocation,
+    region: SarifRegion,
+}
+
+#[derive(Debug, Serialize)]
+struct SarifArtifactLocation {
+    uri: String,
+}
+
+/// One based lines and UTF-16 columns, the SARIF default column kind.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifRegion {
+    start_line: usize,
+    start_column: usize,
+    end_line: usize,
+    end_column: usize,
+}
+
+impl SarifReporter {
+    pub fn new<S: Into<String>>(tool_name: S, rules: Vec<RuleMetadata>) -> Self {
+        Self { tool_name: tool_name.into(), rules, results: vec![] }
+    }
+
+    fn result(&self, uri: &str, diagnostic: &Error) -> SarifResult {
+        let message = diagnostic.to_string();
+        let (rule_id, message) = split_rule_id(&message);
+        let rule_index = rule_id
+            .as_ref()
+            .and_then(|rule_id| self.rules.iter().position(|rule| &rule.id == rule_id));
+        let level = match diagnostic.severity() {
+            Some(Severity::Error) | None => "error",
+            Some(Severity::Warning) => "warning",
+            Some(Severity::Advice) => "note",
+        };
+
+        let mut locations = vec![];
+        let mut related_locations = vec![];
+        if let (Some(source), Some(labels)) = (diagnostic.source_code(), diagnostic.labels()) {
+            for (i, label) in labels.enumerate() {
+                let Some(physical_location) = SarifPhysicalLocation::new(source, uri, &label)
+                else {
+                    continue;
+                };
+                if i == 0 {
+                    locations.push(SarifLocation { id: None, physical_location, message: None });
+                } else {
+                    related_locations.push(SarifLocation {
+                        id: Some(related_locations.len()),
+                        physical_location,
+                        message: label.label().map(|text| SarifMessage { text: text.to_string() }),
+                    });
+                }
+            }
+        }
+
+        SarifResult {
+            rule_id,
+            rule_index,
+            level,
+            message: SarifMessage { text: message.to_string() },
+            locations,
+            related_locations,
+        }
+    }
+}
+
+impl DiagnosticReporter for SarifReporter {
+    fn render_diagnostics(&mut self, path: &Path, diagnostics: Vec<Error>) -> Option<String> {
+        let uri = path.to_string_lossy().replace('\\', "/");
+        let results =
+            diagnostics.iter().map(|diagnostic| self.result(&uri, diagnostic)).collect::<Vec<_>>();
+        self.results.extend(results);
+        None
+    }
+
+    fn finish(&mut self) -> Option<String> {
+        let rules = self
+            .rules
+            .iter()
+            .map(|rule| SarifRule {
+                id: &rule.id,
+                short_description: rule
+                    .documentation
+                    .as_deref()
+                    .and_then(short_description)
+                    .map(|text| SarifMessage { text }),
+                help: rule.documentation.as_deref().map(|documentation| SarifMarkdownMessage {
+                    text: documentation,
+                    markdown: documentation,
+                }),
+                properties: SarifRuleProperties { category: &rule.category },
+            })
+            .collect();
+        let log = SarifLog {
+            schema: SARIF_SCHEMA,
+            version: SARIF_VERSION,
+            runs: [SarifRun {
+                tool: SarifTool { driver: SarifDriver { name: &self.tool_name, rules } },
+                results: &self.results,
+            }],
+        };
+        Some(serde_json::to_string(&log).unwrap())
+    }
+}
+
+impl SarifPhysicalLocation {
+    fn new(source: &dyn SourceCode, uri: &str, label: &LabeledSpan) -> Option<Self> {
+        let (start_line, start_column) = line_column(source, label.offset())?;
+        let (end_line, end_column) = line_column(source, label.offset() + label.len())?;
+        Some(Self {
+            artifact_location: SarifArtifactLocation { uri: uri.to_string() },
+            region: SarifRegion { start_line, start_column, end_line, end_column },
+        })
+    }
+}
+
+/// The first paragraph of the "What it does" section of the rule documentation.
+fn short_description(documentation: &str) -> Option<String> {
+    let text = documentation
+        .lines()
+        .map(str::trim)
+        .skip_while(|line| line.is_empty() || line.starts_with('#'))
+        .take_while(|line| !line.is_empty() && !line.starts_with('#'))
+        .collect::<Vec<_>>()
+        .join(" ");
+    (!text.is_empty()).then_some(text)
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::Path;
+
+    use miette::{miette, LabeledSpan};
+
+    use super::{RuleMetadata, SarifReporter};
+    use crate::DiagnosticReporter;
+
+    #[test]
+    fn related_locations() {
+        let rules = vec![RuleMetadata {
+            id: "no-dupe-keys".into(),
+            category: "correctness".into(),
+            documentation: Some(
+                "### What it does\n\nDisallow duplicate keys.\n\n### Example".into(),
+            ),
+        }];
+        let mut reporter = SarifReporter::new("oxlint", rules);
+        let diagnostic = miette!(
+            labels = vec![LabeledSpan::at(20..21, "duplicated"), LabeledSpan::at(8..9, "first")],
+            "eslint(no-dupe-keys): Disallow duplicate keys"
+        )
+        .with_source_code("x = {\n  a, 🦀: 1, a };".to_string());
+        reporter.render_diagnostics(Path::new("src/test.js"), vec![diagnostic]);
+
+        let output = reporter.finish().unwrap();
+        let log = serde_json::from_str::<serde_json::Value>(&output).unwrap();
+        let run = &log["runs"][0];
+        assert_eq!(
+            run["tool"]["driver"]["rules"][0]["shortDescription"]["text"],
+            "Disallow duplicate keys."
+        );
+        let result = &run["results"][0];
+        assert_eq!(result["ruleId"], "no-dupe-keys");
+        assert_eq!(result["ruleIndex"], 0);
+        assert_eq!(result["level"], "error");
+        let location = &result["locations"][0]["physicalLocation"];
+        assert_eq!(location["artifactLocation"]["uri"], "src/test.js");
+        assert_eq!(
+            location["region"],
+            serde_json::json!({ "startLine": 2, "startColumn": 13, "endLine": 2, "endColumn": 14 })
+        );
+        let related = &result["relatedLocations"][0];
+        assert_eq!(related["message"]["text"], "first");
+        assert_eq!(
+            related["physicalLocation"]["region"],
+            serde_json::json!({ "startLine": 2, "startColumn": 3, "endLine": 2, "endColumn": 4 })
+        );
+    }
+}
@@ -6,7 +6,11 @@ use std::{
     sync::Arc,
 };
 
-use crate::{miette::NamedSource, Error, GraphicalReportHandler, MinifiedFileError, Severity};
+use crate::{
+    miette::NamedSource,
+    reporter::{DiagnosticReporter, GraphicalReporter},
+    Error, Severity,
+};
 
 pub type DiagnosticTuple = (PathBuf, Vec<Error>);
 pub type DiagnosticSender = mpsc::Sender<Option<DiagnosticTuple>>;
@@ -26,6 +30,9 @@ pub struct DiagnosticService {
     /// Total number of errors received
     errors_count: Cell<usize>,
 
+    /// Output format of the reported diagnostics
+    reporter: Box<dyn DiagnosticReporter>,
+
     sender: DiagnosticSender,
     receiver: DiagnosticReceiver,
 }
@@ -38,6 +45,7 @@ impl Default for DiagnosticService {
             max_warnings: None,
             warnings_count: Cell::new(0),
             errors_count: Cell::new(0),
+            reporter: Box::<GraphicalReporter>::default(),
             sender,
             receiver,
         }
@@ -57,6 +65,12 @@ impl DiagnosticService {
         self
     }
 
+    #[must_use]
+    pub fn with_reporter(mut self, reporter: Box<dyn DiagnosticReporter>) -> Self {
+        self.reporter = reporter;
+        self
+    }
+
     pub fn sender(&self) -> &DiagnosticSender {
         &self.sender
     }
@@ -89,12 +103,11 @@ impl DiagnosticService {
     /// # Panics
     ///
     /// * When the writer fails to write
-    pub fn run(&self) {
+    pub fn run(&mut self) {
         let mut buf_writer = BufWriter::new(std::io::stdout());
-        let handler = GraphicalReportHandler::new();
 
         while let Ok(Some((path, diagnostics))) = self.receiver.recv() {
-            let mut output = String::new();
+            let mut reported = vec![];
             for diagnostic in diagnostics {
                 let severity = diagnostic.severity();
                 let is_warning = severity == Some(Severity::Warning);
@@ -121,20 +134,16 @@ impl DiagnosticService {
                     }
                 }
 
-                let mut err = String::new();
-                handler.render_report(&mut err, diagnostic.as_ref()).unwrap();
-                // Skip large output and print only once
-                if err.lines().any(|line| line.len() >= 400) {
-                    let minified_diagnostic = Error::new(MinifiedFileError(path.clone()));
-                    err = format!("{minified_diagnostic:?}");
-                    output = err;
-                    break;
-                }
-                output.push_str(&err);
+                reported.push(diagnostic);
+            }
+            if let Some(output) = self.reporter.render_diagnostics(&path, reported) {
+                buf_writer.write_all(output.as_bytes()).unwrap();
             }
-            buf_writer.write_all(output.as_bytes()).unwrap();
         }
 
+        if let Some(output) = self.reporter.finish() {
+            buf_writer.write_all(output.as_bytes()).unwrap();
+        }
         buf_writer.flush().unwrap();
     }
 }
@@ -0,0 +1,74 @@
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+};
+
+use dashmap::DashMap;
+use tower_lsp::lsp_types::Url;
+
+/// Cancels the lint of an older version of a document once a newer version is being linted.
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken {
+    /// Generation of the latest lint of the document
+    latest: Arc<AtomicUsize>,
+    generation: usize,
+}
+
+impl CancellationToken {
+    pub fn is_cancelled(&self) -> bool {
+        self.latest.load(Ordering::Acquire) != self.generation
+    }
+}
+
+/// Per document generation counters, bumped on every lint request.
+#[derive(Debug, Default)]
+pub struct DocumentGenerations {
+    generations: DashMap<Url, Arc<AtomicUsize>>,
+}
+
+impl DocumentGenerations {
+    /// Start a new generation for `uri`, cancelling all tokens handed out before.
+    pub fn next(&self, uri: &Url) -> CancellationToken {
+        let latest = Arc::clone(&self.generations.entry(uri.clone()).or_default());
+        let generation = latest.fetch_add(1, Ordering::AcqRel) + 1;
+        CancellationToken { latest, generation }
+    }
+
+    /// Cancel the in-flight lint of a closed document.
+    pub fn remove(&self, uri: &Url) {
+        if let Some((_, latest)) = self.generations.remove(uri) {
+            latest.fetch_add(1, Ordering::AcqRel);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use tower_lsp::lsp_types::Url;
+
+    use super::{CancellationToken, DocumentGenerations};
+
+    #[test]
+    fn newer_generation_cancels_older() {
+        let generations = DocumentGenerations::default();
+        let uri = Url::parse("file:///test.js").unwrap();
+        let other_uri = Url::parse("file:///other.js").unwrap();
+
+        let first = generations.next(&uri);
+        let other = generations.next(&other_uri);
+        assert!(!first.is_cancelled());
+        let second = generations.next(&uri);
+        assert!(first.is_cancelled());
+        assert!(!second.is_cancelled());
+        assert!(!other.is_cancelled());
+
+        generations.remove(&uri);
+        assert!(second.is_cancelled());
+        assert!(!generations.next(&uri).is_cancelled());
+    }
+
+    #[test]
+    fn default_is_never_cancelled() {
+        assert!(!CancellationToken::default().is_cancelled());
+    }
+}
@@ -0,0 +1,300 @@
+use std::collections::HashMap;
+
+use ropey::Rope;
+use tower_lsp::lsp_types::{
+    CodeAction, CodeActionKind, Position, Range, TextEdit, Url, WorkspaceEdit,
+};
+
+use oxc_linter::FixKind;
+
+use crate::linter::{DiagnosticReport, FixedContent, PositionEncoding};
+
+const DISABLE_NEXT_LINE: &str = "eslint-disable-next-line";
+
+/// Kind of the code action applying all fixes of a document, e.g. run by editors on save.
+pub const SOURCE_FIX_ALL_OXC: CodeActionKind = CodeActionKind::new("source.fixAll.oxc");
+
+/// Code action applying the linter's fix for `report`, only preferred if the fix is safe.
+pub fn quick_fix(uri: &Url, report: &DiagnosticReport) -> Option<CodeAction> {
+    let fixed_content = report.fixed_content.clone()?;
+    let title = report
+        .diagnostic
+        .message
+        .split(':')
+        .next()
+        .map_or_else(|| "Fix this problem".into(), |s| format!("Fix this {s} problem"));
+
+    Some(CodeAction {
+        title,
+        kind: Some(CodeActionKind::QUICKFIX),
+        is_preferred: Some(fixed_content.kind == FixKind::Safe),
+        edit: Some(workspace_edit(uri, fixed_content)),
+        disabled: None,
+        data: None,
+        diagnostics: Some(vec![report.diagnostic.clone()]),
+        command: None,
+    })
+}
+
+/// Code actions applying each suggestion of `report`, titled by its description.
+pub fn suggestions<'a>(
+    uri: &'a Url,
+    report: &'a DiagnosticReport,
+) -> impl Iterator<Item = CodeAction> + 'a {
+    report.suggestions.iter().map(|suggestion| CodeAction {
+        title: suggestion.message.clone().unwrap_or_else(|| "Apply this suggestion".into()),
+        kind: Some(CodeActionKind::QUICKFIX),
+        is_preferred: Some(false),
+        edit: Some(workspace_edit(uri, suggestion.clone())),
+        disabled: None,
+        data: None,
+        diagnostics: Some(vec![report.diagnostic.clone()]),
+        command: None,
+    })
+}
+
+/// Code action suppressing `report` with an `eslint-disable-next-line` comment.
+pub fn disable_next_line(uri: &Url, report: &DiagnosticReport) -> Option<CodeAction> {
+    let rule_name = report.rule_name?;
+    let fixed_content = report.disable_next_line.clone()?;
+
+    Some(CodeAction {
+        title: format!("Disable {rule_name} for this line"),
+        kind: Some(CodeActionKind::QUICKFIX),
+        is_preferred: Some(false),
+        edit: Some(workspace_edit(uri, fixed_content)),
+        disabled: None,
+        data: None,
+        diagnostics: Some(vec![report.diagnostic.clone()]),
+        command: None,
+    })
+}
+
+/// Code action applying all fixes of `reports` in a single edit.
+///
+/// Of overlapping fixes only the earliest starting one is applied.
+pub fn fix_all(uri: &Url, reports: &[DiagnosticReport]) -> Option<CodeAction> {
+    let mut fixes =
+        reports.iter().filter_map(|report| report.fixed_content.as_ref()).collect::<Vec<_>>();
+    fixes.sort_by_key(|fix| (fix.range.start, fix.range.end));
+    let mut edits: Vec<TextEdit> = vec![];
+    for fix in fixes {
+        if edits.last().map_or(false, |edit| fix.range.start < edit.range.end) {
+            continue;
+        }
+        edits.push(TextEdit { range: fix.range, new_text: fix.code.clone() });
+    }
+    if edits.is_empty() {
+        return None;
+    }
+
+    Some(CodeAction {
+        title: "Fix all auto-fixable problems".into(),
+        kind: Some(SOURCE_FIX_ALL_OXC),
+        is_preferred: None,
+        edit: Some(WorkspaceEdit {
+            changes: Some(HashMap::from([(uri.clone(), edits)])),
+            ..WorkspaceEdit::default()
+        }),
+        disabled: None,
+        data: None,
+        diagnostics: None,
+        command: None,
+    })
+}
+
+fn workspace_edit(uri: &Url, fixed_content: FixedContent) -> WorkspaceEdit {
+    WorkspaceEdit {
+        changes: Some(HashMap::from([(
+            uri.clone(),
+            vec![TextEdit { range: fixed_content.range, new_text: fixed_content.code }],
+        )])),
+        ..WorkspaceEdit::default()
+    }
+}
+
+/// Compute the edit disabling `rule_name` on `line`.
+///
+/// A fresh `// eslint-disable-next-line` comment is inserted above the line with the same
+/// indentation, unless the preceding line already is such a comment, in which case the rule is
+/// appended to its rule list.
+#[allow(clippy::cast_possible_truncation)]
+pub fn disable_next_line_fix(
+    rope: &Rope,
+    line: u32,
+    rule_name: &str,
+    encoding: PositionEncoding,
+) -> Option<FixedContent> {
+    let line_idx = line as usize;
+    let line_text = rope.get_line(line_idx)?.to_string();
+
+    if let Some(previous_line) = line_idx.checked_sub(1).and_then(|idx| rope.get_line(idx)) {
+        let previous_line = previous_line.to_string();
+        if let Some(directive_end) =
+            previous_line.find(DISABLE_NEXT_LINE).map(|start| start + DISABLE_NEXT_LINE.len())
+        {
+            let rest = &previous_line[directive_end..];
+            // The rule list ends at the description (`-- reason`) or the end of the comment.
+            let list_len = ["--", "*/", "\n", "\r"]
+                .iter()
+                .filter_map(|terminator| rest.find(terminator))
+                .min()
+                .unwrap_or(rest.len());
+            let rules = rest[..list_len].trim_end();
+            // A bare `eslint-disable-next-line` already disables every rule.
+            if rules.trim().is_empty() || rules.split(',').any(|rule| rule.trim() == rule_name) {
+                return None;
+            }
+            let insert_at = directive_end + rules.len();
+            let position =
+                Position::new(line - 1, encoding.text_len(&previous_line[..insert_at]) as u32);
+            return Some(FixedContent {
+                code: format!(", {rule_name}"),
+                range: Range::new(position, position),
+                kind: FixKind::Safe,
+                message: None,
+            });
+        }
+    }
+
+    let indentation: String =
+        line_text.chars().take_while(|c| c.is_whitespace() && *c != '\n' && *c != '\r').collect();
+    let position = Position::new(line, 0);
+    Some(FixedContent {
+        code: format!("{indentation}// {DISABLE_NEXT_LINE} {rule_name}\n"),
+        range: Range::new(position, position),
+        kind: FixKind::Safe,
+        message: None,
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_linter::FixKind;
+    use ropey::Rope;
+    use tower_lsp::lsp_types::{Diagnostic, Position, Range, TextEdit, Url};
+
+    use super::{disable_next_line_fix, fix_all, quick_fix, suggestions, SOURCE_FIX_ALL_OXC};
+    use crate::linter::{DiagnosticReport, FixedContent, PositionEncoding};
+
+    fn report(code: &str, range: Range) -> DiagnosticReport {
+        DiagnosticReport {
+            diagnostic: Diagnostic::default(),
+            fixed_content: Some(FixedContent {
+                code: code.into(),
+                range,
+                kind: FixKind::Safe,
+                message: None,
+            }),
+            suggestions: vec![],
+            rule_name: None,
+            disable_next_line: None,
+        }
+    }
+
+    fn fix_all_edits(reports: &[DiagnosticReport]) -> Vec<TextEdit> {
+        let uri = Url::parse("file:///test.js").unwrap();
+        let action = fix_all(&uri, reports).unwrap();
+        assert_eq!(action.kind, Some(SOURCE_FIX_ALL_OXC));
+        action.edit.unwrap().changes.unwrap().remove(&uri).unwrap()
+    }
+
+    #[test]
+    fn fix_all_keeps_the_earliest_of_overlapping_fixes() {
+        let later = report("b", Range::new(Position::new(0, 3), Position::new(0, 8)));
+        let earlier = report("a", Range::new(Position::new(0, 0), Position::new(0, 5)));
+        let edits = fix_all_edits(&[later, earlier]);
+        assert_eq!(edits.len(), 1);
+        assert_eq!(edits[0].new_text, "a");
+    }
+
+    #[test]
+    fn fix_all_applies_fixes_on_separate_lines() {
+        let first = report("", Range::new(Position::new(0, 0), Position::new(0, 9)));
+        let second = report("", Range::new(Position::new(1, 0), Position::new(1, 9)));
+        let edits = fix_all_edits(&[second, first]);
+        assert_eq!(edits.len(), 2);
+        assert_eq!(edits[0].range.start, Position::new(0, 0));
+        assert_eq!(edits[1].range.start, Position::new(1, 0));
+    }
+
+    #[test]
+    fn fix_all_without_fixes() {
+        let uri = Url::parse("file:///test.js").unwrap();
+        let mut without_fix = report("", Range::default());
+        without_fix.fixed_content = None;
+        assert!(fix_all(&uri, &[without_fix]).is_none());
+    }
+
+    #[test]
+    fn each_suggestion_is_an_action() {
+        let uri = Url::parse("file:///test.js").unwrap();
+        let mut report = report("", Range::default());
+        report.fixed_content = None;
+        for (code, message) in [("!(a in b)", Some("Negate")), ("(!a) in b", None)] {
+            report.suggestions.push(FixedContent {
+                code: code.into(),
+                range: Range::new(Position::new(0, 0), Position::new(0, 7)),
+                kind: FixKind::Dangerous,
+                message: message.map(Into::into),
+            });
+        }
+
+        let actions = suggestions(&uri, &report).collect::<Vec<_>>();
+        let titles = actions.iter().map(|action| action.title.as_str()).collect::<Vec<_>>();
+        assert_eq!(titles, vec!["Negate", "Apply this suggestion"]);
+        assert!(actions.iter().all(|action| action.is_preferred == Some(false)));
+        let edits = actions[1].edit.clone().unwrap().changes.unwrap().remove(&uri).unwrap();
+        assert_eq!(edits[0].new_text, "(!a) in b");
+        assert!(quick_fix(&uri, &report).is_none());
+    }
+
+    #[test]
+    fn only_safe_quick_fixes_are_preferred() {
+        let uri = Url::parse("file:///test.js").unwrap();
+        let mut report = report("", Range::default());
+        assert_eq!(quick_fix(&uri, &report).unwrap().is_preferred, Some(true));
+        report.fixed_content.as_mut().unwrap().kind = FixKind::Dangerous;
+        assert_eq!(quick_fix(&uri, &report).unwrap().is_preferred, Some(false));
+    }
+
+    #[test]
+    fn insert_fresh_comment() {
+        let rope = Rope::from_str("function foo() {\n    debugger;\n}\n");
+        let fix = disable_next_line_fix(&rope, 1, "no-debugger", PositionEncoding::Utf16).unwrap();
+        assert_eq!(fix.code, "    // eslint-disable-next-line no-debugger\n");
+        assert_eq!(fix.range, Range::new(Position::new(1, 0), Position::new(1, 0)));
+    }
+
+    #[test]
+    fn insert_fresh_comment_on_first_line() {
+        let rope = Rope::from_str("debugger;");
+        let fix = disable_next_line_fix(&rope, 0, "no-debugger", PositionEncoding::Utf16).unwrap();
+        assert_eq!(fix.code, "// eslint-disable-next-line no-debugger\n");
+        assert_eq!(fix.range, Range::new(Position::new(0, 0), Position::new(0, 0)));
+    }
+
+    #[test]
+    fn append_to_existing_comment() {
+        let rope = Rope::from_str("  // eslint-disable-next-line no-console\n  debugger;\n");
+        let fix = disable_next_line_fix(&rope, 1, "no-debugger", PositionEncoding::Utf16).unwrap();
+        assert_eq!(fix.code, ", no-debugger");
+        assert_eq!(fix.range, Range::new(Position::new(0, 40), Position::new(0, 40)));
+    }
+
+    #[test]
+    fn append_before_description() {
+        let source_text = "/* eslint-disable-next-line no-console -- 🦀 */\ndebugger;\n";
+        let rope = Rope::from_str(source_text);
+        let fix = disable_next_line_fix(&rope, 1, "no-debugger", PositionEncoding::Utf16).unwrap();
+        assert_eq!(fix.code, ", no-debugger");
+        let column = u32::try_from(source_text.find(" --").unwrap()).unwrap();
+        assert_eq!(fix.range, Range::new(Position::new(0, column), Position::new(0, column)));
+    }
+
+    #[test]
+    fn rule_already_disabled() {
+        let rope = Rope::from_str("// eslint-disable-next-line no-debugger\ndebugger;\n");
+        assert!(disable_next_line_fix(&rope, 1, "no-debugger", PositionEncoding::Utf16).is_none());
+    }
+}
@@ -0,0 +1,65 @@
+use std::time::Duration;
+
+use dashmap::DashMap;
+use tower_lsp::lsp_types::Url;
+
+/// Coalesces bursts of changes to the same document.
+#[derive(Debug, Default)]
+pub struct Debouncer {
+    /// Number of changes seen per document
+    changes: DashMap<Url, usize>,
+}
+
+impl Debouncer {
+    /// Wait for `delay` and return whether no newer change of `uri` arrived in the meantime,
+    /// i.e. whether this change should be linted.
+    pub async fn debounce(&self, uri: &Url, delay: Duration) -> bool {
+        let change = {
+            let mut changes = self.changes.entry(uri.clone()).or_default();
+            *changes += 1;
+            *changes
+        };
+        tokio::time::sleep(delay).await;
+        self.changes.get(uri).map_or(false, |changes| *changes == change)
+    }
+
+    pub fn remove(&self, uri: &Url) {
+        self.changes.remove(uri);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use tower_lsp::lsp_types::Url;
+
+    use super::Debouncer;
+
+    const DELAY: Duration = Duration::from_millis(20);
+
+    #[tokio::test]
+    async fn burst_is_linted_once() {
+        let debouncer = Debouncer::default();
+        let uri = Url::parse("file:///test.js").unwrap();
+        let lints = tokio::join!(
+            debouncer.debounce(&uri, DELAY),
+            debouncer.debounce(&uri, DELAY),
+            debouncer.debounce(&uri, DELAY),
+        );
+        assert_eq!(lints, (false, false, true));
+    }
+
+    #[tokio::test]
+    async fn changes_outside_the_window_are_linted() {
+        let debouncer = Debouncer::default();
+        let uri = Url::parse("file:///test.js").unwrap();
+        let other_uri = Url::parse("file:///other.js").unwrap();
+        assert!(debouncer.debounce(&uri, DELAY).await);
+        assert!(debouncer.debounce(&uri, DELAY).await);
+        // other documents do not cancel each other
+        let lints =
+            tokio::join!(debouncer.debounce(&uri, DELAY), debouncer.debounce(&other_uri, DELAY));
+        assert_eq!(lints, (true, true));
+    }
+}
@@ -1,31 +1,36 @@
 use std::{
+    borrow::Cow,
     fs,
     path::{Path, PathBuf},
     rc::Rc,
     sync::{
         atomic::{AtomicUsize, Ordering},
-        mpsc, Arc, RwLock,
+        Arc, RwLock,
     },
 };
 
-use crate::walk::Walk;
-use crate::{options::LintOptions, walk::Extensions};
+use crate::walk::{IgnoreMatcher, Walk};
+use crate::{
+    cancellation::CancellationToken, code_actions::disable_next_line_fix, options::LintOptions,
+    walk::Extensions,
+};
 use miette::NamedSource;
 use oxc_allocator::Allocator;
-use oxc_diagnostics::{miette, Error, Severity};
+use oxc_diagnostics::{miette, Error, Report, Severity};
 use oxc_linter::{
-    partial_loader::{
-        vue_partial_loader::VuePartialLoader, PartialLoader, LINT_PARTIAL_LOADER_EXT,
-    },
-    LintContext, LintSettings, Linter,
+    partial_loader::{PartialLoader, LINT_MARKDOWN_EXT, LINT_PARTIAL_LOADER_EXT},
+    ESLintConfig, Fix, FixFilter, FixKind, LintCache, LintContext, Linter, Message,
 };
 use oxc_linter_plugin::{make_relative_path_parts, LinterPlugin};
 use oxc_parser::Parser;
 use oxc_semantic::SemanticBuilder;
 use oxc_span::{SourceType, VALID_EXTENSIONS};
+use rayon::prelude::*;
 use ropey::Rope;
+use tokio::sync::mpsc::{self, UnboundedReceiver, UnboundedSender};
 use tower_lsp::lsp_types::{
-    self, DiagnosticRelatedInformation, DiagnosticSeverity, Position, Range, Url,
+    self, DiagnosticRelatedInformation, DiagnosticSeverity, Position, PositionEncodingKind, Range,
+    Url,
 };
 
 #[derive(Debug)]
@@ -34,6 +39,9 @@ struct ErrorWithPosition {
     pub end_pos: Position,
     pub miette_err: Error,
     pub fixed_content: Option<FixedContent>,
+    pub suggestions: Vec<FixedContent>,
+    pub rule_name: Option<&'static str>,
+    pub disable_next_line: Option<FixedContent>,
     pub labels_with_pos: Vec<LabeledSpanWithPosition>,
 }
 
@@ -45,35 +53,63 @@ struct LabeledSpanWithPosition {
 }
 
 impl ErrorWithPosition {
-    pub fn new(error: Error, text: &str, fixed_content: Option<FixedContent>) -> Self {
+    pub fn new(
+        error: Error,
+        rope: &Rope,
+        fixed_content: Option<FixedContent>,
+        suggestions: Vec<FixedContent>,
+        rule_name: Option<&'static str>,
+        encoding: PositionEncoding,
+    ) -> Self {
         let labels = error.labels().map_or(vec![], Iterator::collect);
         let labels_with_pos: Vec<LabeledSpanWithPosition> = labels
             .iter()
             .map(|labeled_span| LabeledSpanWithPosition {
-                start_pos: offset_to_position(labeled_span.offset(), text).unwrap_or_default(),
-                end_pos: offset_to_position(labeled_span.offset() + labeled_span.len(), text)
+                start_pos: offset_to_position(labeled_span.offset(), rope, encoding)
                     .unwrap_or_default(),
+                end_pos: offset_to_position(
+                    labeled_span.offset() + labeled_span.len(),
+                    rope,
+                    encoding,
+                )
+                .unwrap_or_default(),
                 message: labeled_span.label().map(ToString::to_string),
             })
             .collect();
 
-        let start_pos = labels_with_pos[0].start_pos;
-        let end_pos = labels_with_pos[labels_with_pos.len() - 1].end_pos;
+        // Some diagnostics (e.g. parser panics and plugin errors) carry no labels,
+        // they are reported at the start of the file instead.
+        let start_pos = labels_with_pos.first().map_or_else(Position::default, |l| l.start_pos);
+        let end_pos = labels_with_pos.last().map_or_else(Position::default, |l| l.end_pos);
+
+        let disable_next_line = rule_name
+            .and_then(|rule_name| disable_next_line_fix(rope, start_pos.line, rule_name, encoding));
 
-        Self { miette_err: error, start_pos, end_pos, labels_with_pos, fixed_content }
+        Self {
+            miette_err: error,
+            start_pos,
+            end_pos,
+            labels_with_pos,
+            fixed_content,
+            suggestions,
+            rule_name,
+            disable_next_line,
+        }
     }
 
-    fn to_lsp_diagnostic(&self, path: &PathBuf) -> lsp_types::Diagnostic {
+    fn to_lsp_diagnostic(&self, uri: &Url) -> lsp_types::Diagnostic {
         let severity = match self.miette_err.severity() {
-            Some(Severity::Error) => Some(lsp_types::DiagnosticSeverity::ERROR),
-            _ => Some(lsp_types::DiagnosticSeverity::WARNING),
+            // miette treats diagnostics without a severity as errors
+            Some(Severity::Error) | None => Some(lsp_types::DiagnosticSeverity::ERROR),
+            Some(Severity::Warning) => Some(lsp_types::DiagnosticSeverity::WARNING),
+            Some(Severity::Advice) => Some(lsp_types::DiagnosticSeverity::INFORMATION),
         };
-        let related_information = Some(
+        let related_information = (!self.labels_with_pos.is_empty()).then(|| {
             self.labels_with_pos
                 .iter()
                 .map(|labeled_span| lsp_types::DiagnosticRelatedInformation {
                     location: lsp_types::Location {
-                        uri: lsp_types::Url::from_file_path(path).unwrap(),
+                        uri: uri.clone(),
                         range: lsp_types::Range {
                             start: lsp_types::Position {
                                 line: labeled_span.start_pos.line,
@@ -87,8 +123,8 @@ impl ErrorWithPosition {
                     },
                     message: labeled_span.message.clone().unwrap_or_default(),
                 })
-                .collect(),
-        );
+                .collect()
+        });
         let range = related_information.as_ref().map_or(
             Range { start: self.start_pos, end: self.end_pos },
             |infos: &Vec<DiagnosticRelatedInformation>| {
@@ -123,10 +159,13 @@ impl ErrorWithPosition {
         }
     }
 
-    fn into_diagnostic_report(self, path: &PathBuf) -> DiagnosticReport {
+    fn into_diagnostic_report(self, uri: &Url) -> DiagnosticReport {
         DiagnosticReport {
-            diagnostic: self.to_lsp_diagnostic(path),
+            diagnostic: self.to_lsp_diagnostic(uri),
             fixed_content: self.fixed_content,
+            suggestions: self.suggestions,
+            rule_name: self.rule_name,
+            disable_next_line: self.disable_next_line,
         }
     }
 }
@@ -135,17 +174,28 @@ impl ErrorWithPosition {
 pub struct DiagnosticReport {
     pub diagnostic: lsp_types::Diagnostic,
     pub fixed_content: Option<FixedContent>,
+    /// Alternative fixes, each offered as its own code action
+    pub suggestions: Vec<FixedContent>,
+    /// Name of the lint rule, `None` for syntax errors and hints
+    pub rule_name: Option<&'static str>,
+    /// Edit inserting (or extending) an `eslint-disable-next-line` comment for this rule
+    pub disable_next_line: Option<FixedContent>,
 }
 #[derive(Debug)]
 struct ErrorReport {
     pub error: Error,
     pub fixed_content: Option<FixedContent>,
+    pub suggestions: Vec<FixedContent>,
+    pub rule_name: Option<&'static str>,
 }
 
 #[derive(Debug, Clone)]
 pub struct FixedContent {
     pub code: String,
     pub range: Range,
+    pub kind: FixKind,
+    /// Description of a suggestion
+    pub message: Option<String>,
 }
 
 type Plugin = Arc<RwLock<Option<LinterPlugin>>>;
@@ -154,197 +204,333 @@ type Plugin = Arc<RwLock<Option<LinterPlugin>>>;
 pub struct IsolatedLintHandler {
     options: Arc<LintOptions>,
     linter: Arc<Linter>,
+    cache: Option<Arc<LintCache>>,
+    parsed_files: Arc<AtomicUsize>,
     plugin: Plugin,
+    encoding: PositionEncoding,
 }
 
 impl IsolatedLintHandler {
-    pub fn new(options: Arc<LintOptions>, linter: Arc<Linter>, plugin: Plugin) -> Self {
-        Self { options, linter, plugin }
+    pub fn new(
+        options: Arc<LintOptions>,
+        linter: Arc<Linter>,
+        cache: Option<Arc<LintCache>>,
+        parsed_files: Arc<AtomicUsize>,
+        plugin: Plugin,
+        encoding: PositionEncoding,
+    ) -> Self {
+        Self { options, linter, cache, parsed_files, plugin, encoding }
     }
 
-    /// # Panics
+    /// Lint all files of the workspace in the background.
     ///
-    /// * When `mpsc::channel` fails to send.
-    pub fn run_full(&self) -> Vec<(PathBuf, Vec<DiagnosticReport>)> {
-        let number_of_files = Arc::new(AtomicUsize::new(0));
-        let (tx_error, rx_error) = mpsc::channel::<(PathBuf, Vec<ErrorWithPosition>)>();
-
-        self.process_paths(&number_of_files, tx_error);
-        Self::process_diagnostics(&rx_error)
+    /// Returns the number of files found by the walk and the receiver of the reports of
+    /// each file, in the order they finish linting. Files without diagnostics are received
+    /// with no reports.
+    pub fn run_full(&self) -> (usize, UnboundedReceiver<(PathBuf, Vec<DiagnosticReport>)>) {
+        let paths = Walk::new(&self.options)
+            .with_extensions(Extensions(get_extensions(self.linter.options().markdown_plugin)))
+            .iter()
+            .collect::<Vec<_>>();
+        let number_of_files = paths.len();
+        let (tx_report, rx_report) = mpsc::unbounded_channel();
+        self.process_paths(paths, tx_report);
+        (number_of_files, rx_report)
     }
 
+    /// Lint the document of `uri`, documents without a backing file (e.g. `untitled:` buffers)
+    /// are linted from `content` as the language of `language_id`.
+    ///
+    /// Returns `None` for unsupported files and when `token` is cancelled before linting finished.
     pub fn run_single(
         &self,
-        path: &Path,
+        uri: &Url,
         content: Option<String>,
+        language_id: Option<&str>,
+        token: &CancellationToken,
     ) -> Option<Vec<DiagnosticReport>> {
-        if Self::is_wanted_ext(path) {
-            Some(Self::lint_path(&self.linter, path, Arc::clone(&self.plugin), content).map_or(
-                vec![],
-                |(p, errors)| {
-                    let mut diagnostics: Vec<DiagnosticReport> =
-                        errors.into_iter().map(|e| e.into_diagnostic_report(&p)).collect();
-                    // a diagnostics connected from related_info to original diagnostic
-                    let mut inverted_diagnostics = vec![];
-                    for d in &diagnostics {
-                        let Some(ref related_info) = d.diagnostic.related_information else {
-                            continue;
-                        };
+        let (path, cache) = match uri.to_file_path() {
+            Ok(path) => (path, self.cache.as_deref()),
+            // Nothing to read from disk, and nothing worth caching.
+            Err(()) if content.is_some() => {
+                (placeholder_path(uri, language_id, self.linter.options().markdown_plugin)?, None)
+            }
+            Err(()) => return None,
+        };
+        if Self::is_wanted_ext(&path, self.linter.options().markdown_plugin) {
+            let result = Self::lint_path(
+                &self.linter,
+                cache,
+                &self.parsed_files,
+                &path,
+                Arc::clone(&self.plugin),
+                content,
+                self.encoding,
+                token,
+            );
+            if token.is_cancelled() {
+                return None;
+            }
+            Some(result.map_or(vec![], |(_, errors)| {
+                let mut diagnostics: Vec<DiagnosticReport> =
+                    errors.into_iter().map(|e| e.into_diagnostic_report(uri)).collect();
+                // a diagnostics connected from related_info to original diagnostic
+                let mut inverted_diagnostics = vec![];
+                for d in &diagnostics {
+                    let Some(ref related_info) = d.diagnostic.related_information else {
+                        continue;
+                    };
 
-                        let related_information = Some(vec![DiagnosticRelatedInformation {
-                            location: lsp_types::Location {
-                                uri: lsp_types::Url::from_file_path(path).unwrap(),
-                                range: d.diagnostic.range,
-                            },
-                            message: "original diagnostic".to_string(),
-                        }]);
-                        for r in related_info {
-                            if r.location.range == d.diagnostic.range {
-                                continue;
-                            }
-                            inverted_diagnostics.push(DiagnosticReport {
-                                diagnostic: lsp_types::Diagnostic {
-                                    range: r.location.range,
-                                    severity: Some(DiagnosticSeverity::HINT),
-                                    code: None,
-                                    message: r.message.clone(),
-                                    source: Some("oxc".into()),
-                                    code_description: None,
-                                    related_information: related_information.clone(),
-                                    tags: None,
-                                    data: None,
-                                },
-                                fixed_content: None,
-                            });
+                    let related_information = Some(vec![DiagnosticRelatedInformation {
+                        location: lsp_types::Location {
+                            uri: uri.clone(),
+                            range: d.diagnostic.range,
+                        },
+                        message: "original diagnostic".to_string(),
+                    }]);
+                    for r in related_info {
+                        if r.location.range == d.diagnostic.range {
+                            continue;
                         }
+                        inverted_diagnostics.push(DiagnosticReport {
+                            diagnostic: lsp_types::Diagnostic {
+                                range: r.location.range,
+                                severity: Some(DiagnosticSeverity::HINT),
+                                code: None,
+                                message: r.message.clone(),
+                                source: Some("oxc".into()),
+                                code_description: None,
+                                related_information: related_information.clone(),
+                                tags: None,
+                                data: None,
+                            },
+                            fixed_content: None,
+                            suggestions: vec![],
+                            rule_name: None,
+                            disable_next_line: None,
+                        });
                     }
-                    diagnostics.append(&mut inverted_diagnostics);
-                    diagnostics
-                },
-            ))
+                }
+                diagnostics.append(&mut inverted_diagnostics);
+                diagnostics
+            }))
         } else {
             None
         }
     }
 
-    fn is_wanted_ext(path: &Path) -> bool {
-        let extensions = get_extensions();
+    fn is_wanted_ext(path: &Path, markdown: bool) -> bool {
+        let extensions = get_extensions(markdown);
         path.extension().map_or(false, |ext| extensions.contains(&ext.to_string_lossy().as_ref()))
     }
 
     fn process_paths(
         &self,
-        number_of_files: &Arc<AtomicUsize>,
-        tx_error: mpsc::Sender<(PathBuf, Vec<ErrorWithPosition>)>,
+        paths: Vec<Box<Path>>,
+        tx_report: UnboundedSender<(PathBuf, Vec<DiagnosticReport>)>,
     ) {
-        let (tx_path, rx_path) = mpsc::channel::<Box<Path>>();
-
-        let walk = Walk::new(&self.options).with_extensions(Extensions(get_extensions()));
-        let number_of_files = Arc::clone(number_of_files);
-        rayon::spawn(move || {
-            let mut count = 0;
-            walk.iter().for_each(|path| {
-                count += 1;
-                tx_path.send(path).unwrap();
-            });
-            number_of_files.store(count, Ordering::Relaxed);
-        });
-
         let linter = Arc::clone(&self.linter);
+        let cache = self.cache.clone();
+        let parsed_files = Arc::clone(&self.parsed_files);
         let plugin = Arc::clone(&self.plugin);
+        let encoding = self.encoding;
         rayon::spawn(move || {
-            while let Ok(path) = rx_path.recv() {
-                let tx_error = tx_error.clone();
-                let linter = Arc::clone(&linter);
-                let plugin = Arc::clone(&plugin);
-                rayon::spawn(move || {
-                    if let Some(diagnostics) = Self::lint_path(&linter, &path, plugin, None) {
-                        tx_error.send(diagnostics).unwrap();
-                    }
-                    drop(tx_error);
+            paths.into_par_iter().for_each_with(tx_report.clone(), |tx_report, path| {
+                let reports = Self::lint_path(
+                    &linter,
+                    cache.as_deref(),
+                    &parsed_files,
+                    &path,
+                    Arc::clone(&plugin),
+                    None,
+                    encoding,
+                    &CancellationToken::default(),
+                )
+                .and_then(|(path, errors)| Some((Url::from_file_path(path).ok()?, errors)))
+                .map_or_else(Vec::new, |(uri, errors)| {
+                    errors.into_iter().map(|e| e.into_diagnostic_report(&uri)).collect()
                 });
+                // The receiver is gone when the caller is no longer interested in the results.
+                let _ = tx_report.send((path.to_path_buf(), reports));
+            });
+            if let Some(cache) = cache {
+                save_cache(&cache);
             }
+            // Close the channel only once the cache is saved.
+            drop(tx_report);
         });
     }
 
-    fn process_diagnostics(
-        rx_error: &mpsc::Receiver<(PathBuf, Vec<ErrorWithPosition>)>,
-    ) -> Vec<(PathBuf, Vec<DiagnosticReport>)> {
-        rx_error
-            .iter()
-            .map(|(path, errors)| {
-                (
-                    path.clone(),
-                    errors.into_iter().map(|e| e.into_diagnostic_report(&path)).collect(),
-                )
-            })
-            .collect()
+    /// The text of the file at `path`, `None` if it is neither JavaScript nor handled by a partial
+    /// loader.
+    fn get_source_text(
+        linter: &Linter,
+        path: &Path,
+        source_text: Option<String>,
+    ) -> Option<String> {
+        let ext = path.extension().and_then(std::ffi::OsStr::to_str)?;
+        if SourceType::from_path(path).is_err()
+            && PartialLoader::from_extension(ext, linter.options()).is_none()
+        {
+            return None;
+        }
+        Some(source_text.unwrap_or_else(|| {
+            fs::read_to_string(path).unwrap_or_else(|_| panic!("Failed to read {path:?}"))
+        }))
     }
 
-    fn get_source_type_and_text(
+    #[allow(clippy::too_many_arguments)]
+    fn lint_path(
+        linter: &Linter,
+        cache: Option<&LintCache>,
+        parsed_files: &AtomicUsize,
         path: &Path,
+        plugin: Plugin,
         source_text: Option<String>,
-    ) -> Option<(SourceType, String)> {
-        let read_file = |path: &Path| -> String {
-            if let Some(source_text) = source_text {
-                return source_text;
+        encoding: PositionEncoding,
+        token: &CancellationToken,
+    ) -> Option<(PathBuf, Vec<ErrorWithPosition>)> {
+        let source_text = Self::get_source_text(linter, path, source_text)?;
+        let allocator = Allocator::default();
+        // Plugins are loaded from the workspace and are not part of the cache fingerprint.
+        let cache = cache.filter(|_| plugin.read().map_or(false, |guard| guard.is_none()));
+        let cached_messages = cache.and_then(|cache| cache.get(path, &source_text));
+        let messages = cached_messages.or_else(|| {
+            parsed_files.fetch_add(1, Ordering::Relaxed);
+            let messages = Self::lint_file(linter, &allocator, path, plugin, &source_text, token)?;
+            if let Some(cache) = cache {
+                cache.insert(path, &source_text, &messages);
             }
-            fs::read_to_string(path).unwrap_or_else(|_| panic!("Failed to read {path:?}"))
-        };
+            Some(messages)
+        })?;
+
+        if messages.is_empty() {
+            return None;
+        }
+
+        // Built once per file and shared by all offset conversions below.
+        let rope = Rope::from_str(&source_text);
+        let reports = messages
+            .into_iter()
+            .map(|msg| {
+                let rule_name = msg.rule_name();
+                let to_fixed_content = |f: Fix| FixedContent {
+                    code: f.content.to_string(),
+                    range: Range {
+                        start: offset_to_position(f.span.start as usize, &rope, encoding)
+                            .unwrap_or_default(),
+                        end: offset_to_position(f.span.end as usize, &rope, encoding)
+                            .unwrap_or_default(),
+                    },
+                    kind: f.kind,
+                    message: f.message.map(Cow::into_owned),
+                };
+                let fixed_content = msg.fix.filter(|_| linter.options().fix).map(to_fixed_content);
+                let suggestions = msg.suggestions.into_iter().map(to_fixed_content).collect();
+                ErrorReport { error: msg.error, fixed_content, suggestions, rule_name }
+            })
+            .collect();
+        Some(Self::wrap_diagnostics(path, &source_text, &rope, reports, encoding))
+    }
 
+    /// Lint the code of a file, or each block of code extracted by its partial loader.
+    ///
+    /// The spans of the messages are offsets in `source_text`.
+    fn lint_file<'a>(
+        linter: &Linter,
+        allocator: &'a Allocator,
+        path: &Path,
+        plugin: Plugin,
+        source_text: &'a str,
+        token: &CancellationToken,
+    ) -> Option<Vec<Message<'a>>> {
         if let Ok(source_type) = SourceType::from_path(path) {
-            return Some((source_type, read_file(path)));
+            return Self::lint_source(
+                linter,
+                allocator,
+                path,
+                plugin,
+                source_text,
+                source_type,
+                token,
+            );
         }
         let ext = path.extension().and_then(std::ffi::OsStr::to_str)?;
-        let partial_loader = if ext == "vue" { Some(PartialLoader::Vue) } else { None };
-        let partial_loader = partial_loader?;
-
-        let source_text = read_file(path);
-        let ret = partial_loader.parse(&source_text);
-        Some((ret.source_type, ret.source_text))
+        let partial_loader = PartialLoader::from_extension(ext, linter.options())?;
+        let mut messages = vec![];
+        for source in partial_loader.parse(source_text) {
+            let allocator = Allocator::default();
+            let block_messages = Self::lint_source(
+                linter,
+                &allocator,
+                path,
+                Arc::clone(&plugin),
+                &source.source_text,
+                source.source_type,
+                token,
+            )?;
+            messages.extend(
+                block_messages
+                    .into_iter()
+                    .map(|message| message.into_owned().with_offset(source.start)),
+            );
+        }
+        Some(messages)
     }
 
-    fn lint_path(
+    /// Parser and semantic errors, or the lint messages if there are none.
+    ///
+    /// Returns `None` when `token` is cancelled, checked after parsing, after semantic analysis
+    /// and while the rules run.
+    fn lint_source<'a>(
         linter: &Linter,
+        allocator: &'a Allocator,
         path: &Path,
         plugin: Plugin,
-        source_text: Option<String>,
-    ) -> Option<(PathBuf, Vec<ErrorWithPosition>)> {
-        let (source_type, source_text) = Self::get_source_type_and_text(path, source_text)?;
-        let allocator = Allocator::default();
-        let ret = Parser::new(&allocator, &source_text, source_type)
+        source_text: &'a str,
+        source_type: SourceType,
+        token: &CancellationToken,
+    ) -> Option<Vec<Message<'a>>> {
+        let ret = Parser::new(allocator, source_text, source_type)
             .allow_return_outside_function(true)
             .parse();
 
-        if !ret.errors.is_empty() {
-            let reports = ret
-                .errors
-                .into_iter()
-                .map(|diagnostic| ErrorReport { error: diagnostic, fixed_content: None })
-                .collect();
+        if token.is_cancelled() {
+            return None;
+        }
 
-            return Some(Self::wrap_diagnostics(path, &source_text, reports));
+        if !ret.errors.is_empty() {
+            return Some(
+                ret.errors.into_iter().map(|diagnostic| Message::new(diagnostic, None)).collect(),
+            );
         };
 
         let program = allocator.alloc(ret.program);
-        let semantic_ret = SemanticBuilder::new(&source_text, source_type)
+        // The module record is checked for syntax errors and used by the rules.
+        let semantic_ret = SemanticBuilder::new(source_text, source_type)
             .with_trivias(ret.trivias)
             .with_check_syntax_error(true)
+            .build_module_record(path.to_path_buf(), program)
             .build(program);
 
+        if token.is_cancelled() {
+            return None;
+        }
+
         if !semantic_ret.errors.is_empty() {
-            let reports = semantic_ret
-                .errors
-                .into_iter()
-                .map(|diagnostic| ErrorReport { error: diagnostic, fixed_content: None })
-                .collect();
-            return Some(Self::wrap_diagnostics(path, &source_text, reports));
+            return Some(
+                semantic_ret
+                    .errors
+                    .into_iter()
+                    .map(|diagnostic| Message::new(diagnostic, None))
+                    .collect(),
+            );
         };
 
         let mut lint_ctx = LintContext::new(
             path.to_path_buf().into_boxed_path(),
             &Rc::new(semantic_ret.semantic),
-            LintSettings::default(),
+            linter.get_settings(),
         );
         {
             if let Ok(guard) = plugin.read() {
@@ -358,44 +544,15 @@ impl IsolatedLintHandler {
 
         drop(plugin); // explicitly drop plugin so that we consume the plugin in this function's body
 
-        let result = linter.run(lint_ctx);
-
-        if result.is_empty() {
-            return None;
-        }
-
-        if linter.options().fix {
-            let reports = result
-                .into_iter()
-                .map(|msg| {
-                    let fixed_content = msg.fix.map(|f| FixedContent {
-                        code: f.content.to_string(),
-                        range: Range {
-                            start: offset_to_position(f.span.start as usize, &source_text)
-                                .unwrap_or_default(),
-                            end: offset_to_position(f.span.end as usize, &source_text)
-                                .unwrap_or_default(),
-                        },
-                    });
-
-                    ErrorReport { error: msg.error, fixed_content }
-                })
-                .collect::<Vec<ErrorReport>>();
-
-            return Some(Self::wrap_diagnostics(path, &source_text, reports));
-        }
-
-        let errors = result
-            .into_iter()
-            .map(|diagnostic| ErrorReport { error: diagnostic.error, fixed_content: None })
-            .collect();
-        Some(Self::wrap_diagnostics(path, &source_text, errors))
+        linter.run_with_cancellation(lint_ctx, || token.is_cancelled())
     }
 
     fn wrap_diagnostics(
         path: &Path,
         source_text: &str,
+        rope: &Rope,
         reports: Vec<ErrorReport>,
+        encoding: PositionEncoding,
     ) -> (PathBuf, Vec<ErrorWithPosition>) {
         let source = Arc::new(NamedSource::new(path.to_string_lossy(), source_text.to_owned()));
         let diagnostics = reports
@@ -403,8 +560,11 @@ impl IsolatedLintHandler {
             .map(|report| {
                 ErrorWithPosition::new(
                     report.error.with_source_code(Arc::clone(&source)),
-                    source_text,
+                    rope,
                     report.fixed_content,
+                    report.suggestions,
+                    report.rule_name,
+                    encoding,
                 )
             })
             .collect();
@@ -412,58 +572,270 @@ impl IsolatedLintHandler {
     }
 }
 
-fn get_extensions() -> Vec<&'static str> {
+/// Path of a document without a backing file, with the extension of its language so the
+/// source type is derived from it, e.g. `Untitled-1.tsx` for an untitled `typescriptreact` buffer.
+fn placeholder_path(uri: &Url, language_id: Option<&str>, markdown: bool) -> Option<PathBuf> {
+    let name =
+        Path::new(uri.path()).file_name().and_then(|name| name.to_str()).unwrap_or("untitled");
+    let path = PathBuf::from(name);
+    if IsolatedLintHandler::is_wanted_ext(&path, markdown) {
+        return Some(path);
+    }
+    let extension = match language_id? {
+        "javascript" => "js",
+        "javascriptreact" => "jsx",
+        "typescript" => "ts",
+        "typescriptreact" => "tsx",
+        "vue" => "vue",
+        "astro" => "astro",
+        "html" => "html",
+        "markdown" if markdown => "md",
+        _ => return None,
+    };
+    Some(PathBuf::from(format!("{name}.{extension}")))
+}
+
+/// Workspace roots with another scheme than `file` fall back to their path.
+pub fn root_path(root_uri: &Url) -> PathBuf {
+    root_uri.to_file_path().unwrap_or_else(|()| PathBuf::from(root_uri.path()))
+}
+
+fn save_cache(cache: &LintCache) {
+    if let Err(err) = cache.save() {
+        log::error!("Failed to write the lint cache {:?}: {err}", cache.path());
+    }
+}
+
+/// The extensions of the linted files, Markdown files are only linted with `markdown`
+pub fn get_extensions(markdown: bool) -> Vec<&'static str> {
+    let markdown_ext = if markdown { LINT_MARKDOWN_EXT } else { &[] };
     VALID_EXTENSIONS
         .iter()
         .chain(LINT_PARTIAL_LOADER_EXT.iter())
+        .chain(markdown_ext.iter())
         .copied()
         .collect::<Vec<&'static str>>()
 }
 
+/// The unit in which `Position::character` is counted, negotiated with the client in `initialize`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum PositionEncoding {
+    Utf8,
+    #[default]
+    Utf16,
+    Utf32,
+}
+
+impl PositionEncoding {
+    /// Pick the first encoding offered by the client that we support,
+    /// falling back to UTF-16 which all clients must support.
+    pub fn negotiate(client_encodings: Option<&[PositionEncodingKind]>) -> Self {
+        client_encodings
+            .unwrap_or_default()
+            .iter()
+            .find_map(|kind| match kind.as_str() {
+                "utf-8" => Some(Self::Utf8),
+                "utf-16" => Some(Self::Utf16),
+                "utf-32" => Some(Self::Utf32),
+                _ => None,
+            })
+            .unwrap_or_default()
+    }
+
+    /// Length of `text` in units of this encoding.
+    pub fn text_len(self, text: &str) -> usize {
+        match self {
+            Self::Utf8 => text.len(),
+            Self::Utf16 => text.encode_utf16().count(),
+            Self::Utf32 => text.chars().count(),
+        }
+    }
+
+    pub fn to_kind(self) -> PositionEncodingKind {
+        match self {
+            Self::Utf8 => PositionEncodingKind::UTF8,
+            Self::Utf16 => PositionEncodingKind::UTF16,
+            Self::Utf32 => PositionEncodingKind::UTF32,
+        }
+    }
+}
+
+/// Convert a byte offset into a `Position` whose column is counted in `encoding` units.
 #[allow(clippy::cast_possible_truncation)]
-fn offset_to_position(offset: usize, source_text: &str) -> Option<Position> {
-    let rope = Rope::from_str(source_text);
-    let line = rope.try_char_to_line(offset).ok()?;
+fn offset_to_position(offset: usize, rope: &Rope, encoding: PositionEncoding) -> Option<Position> {
+    let char_idx = rope.try_byte_to_char(offset).ok()?;
+    let line = rope.try_char_to_line(char_idx).ok()?;
     let first_char_of_line = rope.try_line_to_char(line).ok()?;
-    let column = offset - first_char_of_line;
+    let column = match encoding {
+        PositionEncoding::Utf8 => offset - rope.try_line_to_byte(line).ok()?,
+        PositionEncoding::Utf16 => {
+            rope.try_char_to_utf16_cu(char_idx).ok()?
+                - rope.try_char_to_utf16_cu(first_char_of_line).ok()?
+        }
+        PositionEncoding::Utf32 => char_idx - first_char_of_line,
+    };
     Some(Position::new(line as u32, column as u32))
 }
 
+/// Configuration files looked up in the workspace root, in order of precedence.
+const CONFIG_FILES: [&str; 3] = [".oxlintrc.json", ".eslintrc.json", ".eslintrc"];
+
+/// Location of the lint cache, relative to the workspace root.
+const CACHE_LOCATION: &str = ".oxc/cache";
+
+/// Ignore files looked up in every directory of the workspace, in addition to `.gitignore`.
+const IGNORE_FILE: &str = ".eslintignore";
+
 #[derive(Debug)]
 pub struct ServerLinter {
-    linter: Arc<Linter>,
+    linter: RwLock<Arc<Linter>>,
+    cache: RwLock<Option<Arc<LintCache>>>,
+    /// Number of files parsed so far, files served from the cache are not counted
+    parsed_files: Arc<AtomicUsize>,
     plugin: Plugin,
+    /// Extra patterns of ignored files from the server settings
+    ignore_patterns: RwLock<Vec<String>>,
+    ignore_matcher: RwLock<Option<IgnoreMatcher>>,
 }
 
 impl ServerLinter {
     pub fn new() -> Self {
         let linter = Linter::new().with_fix(true);
-        Self { linter: Arc::new(linter), plugin: Arc::new(RwLock::new(None)) }
-    }
-
-    pub fn make_plugin(&self, root_uri: &Url) {
-        let mut path = root_uri.to_file_path().unwrap();
-        path.push(".oxc/");
-        path.push("plugins");
-        if path.exists() {
-            let mut plugin = self.plugin.write().unwrap();
-            plugin.replace(LinterPlugin::new(&path).unwrap());
+        Self {
+            linter: RwLock::new(Arc::new(linter)),
+            cache: RwLock::new(None),
+            parsed_files: Arc::new(AtomicUsize::new(0)),
+            plugin: Arc::new(RwLock::new(None)),
+            ignore_patterns: RwLock::new(vec![]),
+            ignore_matcher: RwLock::new(None),
         }
     }
 
-    pub fn run_full(&self, root_uri: &Url) -> Vec<(PathBuf, Vec<DiagnosticReport>)> {
-        let options = LintOptions {
-            paths: vec![root_uri.to_file_path().unwrap()],
-            ignore_path: "node_modules".into(),
-            ignore_pattern: vec!["!**/node_modules/**/*".into()],
+    fn lint_options(&self, root_uri: &Url) -> LintOptions {
+        let mut ignore_pattern = vec!["node_modules".to_string()];
+        ignore_pattern.extend(self.ignore_patterns.read().unwrap().iter().cloned());
+        LintOptions {
+            paths: vec![root_path(root_uri)],
+            ignore_path: IGNORE_FILE.into(),
+            ignore_pattern,
             fix: true,
             ..LintOptions::default()
+        }
+    }
+
+    /// Collect the `.gitignore` and `.eslintignore` files of the workspace, files matching them or
+    /// `ignore_patterns` are neither walked nor linted.
+    pub fn make_ignore(&self, root_uri: &Url, ignore_patterns: &[String]) {
+        *self.ignore_patterns.write().unwrap() = ignore_patterns.to_vec();
+        let matcher = IgnoreMatcher::new(&self.lint_options(root_uri));
+        *self.ignore_matcher.write().unwrap() = Some(matcher);
+    }
+
+    pub fn is_ignored(&self, path: &Path) -> bool {
+        self.ignore_matcher
+            .read()
+            .unwrap()
+            .as_ref()
+            .map_or(false, |matcher| matcher.is_ignored(path))
+    }
+
+    /// Reuse the diagnostics of unchanged files, persisted in `.oxc/cache` of the workspace root.
+    pub fn enable_cache(&self, root_uri: &Url) {
+        let path = root_path(root_uri).join(CACHE_LOCATION);
+        let cache = LintCache::load(path, &self.linter());
+        *self.cache.write().unwrap() = Some(Arc::new(cache));
+    }
+
+    pub fn number_of_parsed_files(&self) -> usize {
+        self.parsed_files.load(Ordering::Relaxed)
+    }
+
+    fn cache(&self) -> Option<Arc<LintCache>> {
+        self.cache.read().unwrap().clone()
+    }
+
+    fn save_cache(&self) {
+        if let Some(cache) = self.cache() {
+            save_cache(&cache);
+        }
+    }
+
+    /// Rebuild the linter from `config_path`, or the first configuration file found in the
+    /// workspace root. Relative paths are resolved from the workspace root.
+    ///
+    /// Only the fixes allowed by `fix_kind` are offered, none if it is `None`. The code blocks of
+    /// Markdown files are linted with `markdown`.
+    ///
+    /// Returns the warnings found in the configuration, e.g. unknown rule names.
+    /// The default rules are used if there is no configuration file.
+    pub fn make_config(
+        &self,
+        root_uri: &Url,
+        config_path: Option<&str>,
+        fix_kind: Option<FixFilter>,
+        markdown: bool,
+    ) -> Result<Vec<String>, Report> {
+        let root = root_path(root_uri);
+        let path = config_path.map(|config_path| root.join(config_path)).or_else(|| {
+            CONFIG_FILES.iter().map(|name| root.join(name)).find(|path| path.is_file())
+        });
+        let mut warnings = vec![];
+        let linter = match path {
+            Some(path) => {
+                let config = ESLintConfig::new(&path)?;
+                warnings = config.warnings().iter().map(ToString::to_string).collect();
+                Linter::from_config(config)
+            }
+            None => Linter::new(),
         };
+        let linter = linter
+            .with_fix(fix_kind.is_some())
+            .with_fix_kind(fix_kind.unwrap_or_default())
+            .with_markdown_plugin(markdown);
+        // The cached diagnostics were reported by the previous rules.
+        if let Some(cache) = self.cache.write().unwrap().as_mut() {
+            *cache = Arc::new(LintCache::load(cache.path().to_path_buf(), &linter));
+        }
+        *self.linter.write().unwrap() = Arc::new(linter);
+        Ok(warnings)
+    }
+
+    fn linter(&self) -> Arc<Linter> {
+        Arc::clone(&self.linter.read().unwrap())
+    }
+
+    /// Directory of the plugins, `plugin_path` or `.oxc/plugins` of the workspace root.
+    pub fn plugin_dir(root_uri: &Url, plugin_path: Option<&str>) -> PathBuf {
+        root_path(root_uri).join(plugin_path.unwrap_or(".oxc/plugins"))
+    }
+
+    /// (Re)load the plugins in [`ServerLinter::plugin_dir`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if a plugin query is invalid, the previous plugins are kept in that case.
+    pub fn make_plugin(&self, root_uri: &Url, plugin_path: Option<&str>) -> Result<(), Report> {
+        let path = Self::plugin_dir(root_uri, plugin_path);
+        let plugin = if path.exists() { Some(LinterPlugin::new(&path)?) } else { None };
+        *self.plugin.write().unwrap() = plugin;
+        Ok(())
+    }
+
+    /// See [`IsolatedLintHandler::run_full`], the cache is saved once all files are linted.
+    pub fn run_full(
+        &self,
+        root_uri: &Url,
+        encoding: PositionEncoding,
+    ) -> (usize, UnboundedReceiver<(PathBuf, Vec<DiagnosticReport>)>) {
+        let options = self.lint_options(root_uri);
 
         IsolatedLintHandler::new(
             Arc::new(options),
-            Arc::clone(&self.linter),
+            self.linter(),
+            self.cache(),
+            Arc::clone(&self.parsed_files),
             Arc::clone(&self.plugin),
+            encoding,
         )
         .run_full()
     }
@@ -473,21 +845,31 @@ impl ServerLinter {
         root_uri: &Url,
         uri: &Url,
         content: Option<String>,
+        language_id: Option<&str>,
+        encoding: PositionEncoding,
+        token: &CancellationToken,
     ) -> Option<Vec<DiagnosticReport>> {
-        let options = LintOptions {
-            paths: vec![root_uri.to_file_path().unwrap()],
-            ignore_path: "node_modules".into(),
-            ignore_pattern: vec!["!**/node_modules/**/*".into()],
-            fix: true,
-            ..LintOptions::default()
-        };
+        // Ignored files are cleared instead of being linted.
+        if uri.to_file_path().map_or(false, |path| self.is_ignored(&path)) {
+            return Some(vec![]);
+        }
+        let options = self.lint_options(root_uri);
 
-        IsolatedLintHandler::new(
+        // Unsaved content is cached in memory, the cache file is only written for files on disk.
+        let save_cache = content.is_none();
+        let result = IsolatedLintHandler::new(
             Arc::new(options),
-            Arc::clone(&self.linter),
+            self.linter(),
+            self.cache(),
+            Arc::clone(&self.parsed_files),
             Arc::clone(&self.plugin),
+            encoding,
         )
-        .run_single(&uri.to_file_path().unwrap(), content)
+        .run_single(uri, content, language_id, token);
+        if save_cache {
+            self.save_cache();
+        }
+        result
     }
 }
 
@@ -497,3 +879,438 @@ fn cmp_range(first: &Range, other: &Range) -> std::cmp::Ordering {
         o => o,
     }
 }
+
+#[cfg(test)]
+mod test {
+    use std::path::{Path, PathBuf};
+
+    use ropey::Rope;
+    use tower_lsp::lsp_types::{DiagnosticSeverity, Position, PositionEncodingKind, Url};
+
+    use super::{
+        offset_to_position, DiagnosticReport, ErrorWithPosition, FixFilter, FixKind,
+        PositionEncoding, ServerLinter,
+    };
+    use crate::cancellation::{CancellationToken, DocumentGenerations};
+
+    // `debugger` starts at byte 22, utf-16 code unit 18 and char 16.
+    const SOURCE_WITH_CRABS: &str = "const a = \"🦀🦀\"; debugger;";
+
+    #[test]
+    fn offset_to_position_multi_byte() {
+        let rope = Rope::from_str(SOURCE_WITH_CRABS);
+        let offset = SOURCE_WITH_CRABS.find("debugger").unwrap();
+        assert_eq!(
+            offset_to_position(offset, &rope, PositionEncoding::Utf16),
+            Some(Position::new(0, 18))
+        );
+        assert_eq!(
+            offset_to_position(offset, &rope, PositionEncoding::Utf8),
+            Some(Position::new(0, 22))
+        );
+        assert_eq!(
+            offset_to_position(offset, &rope, PositionEncoding::Utf32),
+            Some(Position::new(0, 16))
+        );
+    }
+
+    #[test]
+    fn offset_to_position_multi_line() {
+        let source_text = "// 🦀\n🦀; debugger;";
+        let rope = Rope::from_str(source_text);
+        let offset = source_text.find("debugger").unwrap();
+        assert_eq!(
+            offset_to_position(offset, &rope, PositionEncoding::Utf16),
+            Some(Position::new(1, 4))
+        );
+    }
+
+    #[test]
+    #[allow(clippy::cast_possible_truncation)]
+    fn offset_to_position_with_shared_rope() {
+        let source_text = "let a = 1;\n// 🦀 é 中文\nconst b = \"🦀\";\r\n\tdebugger;\n";
+        let rope = Rope::from_str(source_text);
+        for (offset, _) in source_text.char_indices() {
+            let before = &source_text[..offset];
+            let line = before.matches('\n').count() as u32;
+            let line_text = before.rsplit('\n').next().unwrap();
+            let character = line_text.encode_utf16().count() as u32;
+            assert_eq!(
+                offset_to_position(offset, &rope, PositionEncoding::Utf16),
+                Some(Position::new(line, character)),
+                "offset {offset}"
+            );
+        }
+    }
+
+    #[test]
+    fn negotiate_position_encoding() {
+        assert_eq!(PositionEncoding::negotiate(None), PositionEncoding::Utf16);
+        assert_eq!(
+            PositionEncoding::negotiate(Some(&[
+                PositionEncodingKind::new("utf-7"),
+                PositionEncodingKind::UTF8,
+                PositionEncodingKind::UTF16
+            ])),
+            PositionEncoding::Utf8
+        );
+    }
+
+    #[test]
+    fn diagnostic_range_after_multi_byte_characters() {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+        let root_uri = Url::from_file_path(root).unwrap();
+        let uri = Url::from_file_path(root.join("crabs.js")).unwrap();
+        let reports = ServerLinter::new()
+            .run_single(
+                &root_uri,
+                &uri,
+                Some(SOURCE_WITH_CRABS.to_string()),
+                None,
+                PositionEncoding::Utf16,
+                &CancellationToken::default(),
+            )
+            .unwrap();
+        let range = reports[0].diagnostic.range;
+        assert_eq!(range.start, Position::new(0, 18));
+        assert_eq!(range.end, Position::new(0, 27));
+    }
+
+    #[test]
+    fn rapid_edits_only_report_the_latest_version() {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+        let root_uri = Url::from_file_path(root).unwrap();
+        let uri = Url::from_file_path(root.join("edits.js")).unwrap();
+        let server_linter = ServerLinter::new();
+        let generations = DocumentGenerations::default();
+
+        // All three edits arrive before the first lint finishes.
+        let edits = ["debugger;", "debugger;\ndebugger;", "debugger;\ndebugger;\ndebugger;"]
+            .map(|content| (content, generations.next(&uri)));
+        let reports = edits
+            .iter()
+            .filter_map(|(content, token)| {
+                server_linter.run_single(
+                    &root_uri,
+                    &uri,
+                    Some((*content).to_string()),
+                    None,
+                    PositionEncoding::Utf16,
+                    token,
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(reports.len(), 1);
+        assert_eq!(reports[0].len(), 3);
+    }
+
+    #[test]
+    fn load_workspace_config() {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("fixtures/config");
+        let root_uri = Url::from_file_path(&root).unwrap();
+        let uri = Url::from_file_path(root.join("test.js")).unwrap();
+        let server_linter = ServerLinter::new();
+        let warnings =
+            server_linter.make_config(&root_uri, None, Some(FixFilter::Safe), false).unwrap();
+        assert_eq!(warnings, vec!["Unknown rule \"eslint/no-such-rule\" in configuration"]);
+        let reports = server_linter
+            .run_single(
+                &root_uri,
+                &uri,
+                Some("debugger;\nif (a == b) {}\nclass A { set a(v) { return v; } }\n".to_string()),
+                None,
+                PositionEncoding::Utf16,
+                &CancellationToken::default(),
+            )
+            .unwrap();
+        let rule_names = reports.iter().map(|report| report.rule_name).collect::<Vec<_>>();
+        assert_eq!(rule_names, vec![Some("eqeqeq"), Some("no-setter-return")]);
+        // The configured severity overrides the one declared by the rule
+        let severities =
+            reports.iter().map(|report| report.diagnostic.severity).collect::<Vec<_>>();
+        assert_eq!(
+            severities,
+            vec![Some(DiagnosticSeverity::ERROR), Some(DiagnosticSeverity::WARNING)]
+        );
+
+        // `Image` is an `img` through `settings.jsx-a11y.components`
+        let uri = Url::from_file_path(root.join("test.jsx")).unwrap();
+        let reports = server_linter
+            .run_single(
+                &root_uri,
+                &uri,
+                Some("<Image alt='Photo of a friend' />;\n".to_string()),
+                None,
+                PositionEncoding::Utf16,
+                &CancellationToken::default(),
+            )
+            .unwrap();
+        let rule_names = reports.iter().map(|report| report.rule_name).collect::<Vec<_>>();
+        assert_eq!(rule_names, vec![Some("img-redundant-alt")]);
+        assert_eq!(reports[0].diagnostic.severity, Some(DiagnosticSeverity::WARNING));
+    }
+
+    #[test]
+    fn untitled_documents() {
+        let root_uri = Url::from_file_path(Path::new(env!("CARGO_MANIFEST_DIR"))).unwrap();
+        let uri = Url::parse("untitled:Untitled-1").unwrap();
+        let server_linter = ServerLinter::new();
+        let run = |content: Option<&str>, language_id: &str| {
+            server_linter.run_single(
+                &root_uri,
+                &uri,
+                content.map(ToString::to_string),
+                Some(language_id),
+                PositionEncoding::Utf16,
+                &CancellationToken::default(),
+            )
+        };
+
+        let source_text = "const a: JSX.Element = <div />;\ndebugger;";
+        let reports = run(Some(source_text), "typescriptreact").unwrap();
+        assert_eq!(reports.len(), 1);
+        assert_eq!(reports[0].rule_name, Some("no-debugger"));
+        let related_information = reports[0].diagnostic.related_information.as_ref().unwrap();
+        assert_eq!(related_information[0].location.uri, uri);
+
+        // the language decides how the content is parsed
+        let reports = run(Some(source_text), "javascript").unwrap();
+        assert_eq!(reports[0].rule_name, None);
+
+        assert!(run(Some(source_text), "markdown").is_none());
+        assert!(run(None, "typescriptreact").is_none());
+    }
+
+    fn run_full(
+        server_linter: &ServerLinter,
+        root_uri: &Url,
+    ) -> Vec<(PathBuf, Vec<DiagnosticReport>)> {
+        let (_, mut rx) = server_linter.run_full(root_uri, PositionEncoding::Utf16);
+        let mut results = vec![];
+        while let Some(result) = rx.blocking_recv() {
+            results.push(result);
+        }
+        results
+    }
+
+    #[test]
+    fn unchanged_files_are_not_parsed() {
+        let root = std::env::temp_dir().join("oxc_language_server_cache");
+        let _ = std::fs::remove_dir_all(&root);
+        std::fs::create_dir_all(&root).unwrap();
+        std::fs::write(root.join("debugger.js"), "debugger;").unwrap();
+        let root_uri = Url::from_file_path(&root).unwrap();
+
+        let server_linter = ServerLinter::new();
+        server_linter.enable_cache(&root_uri);
+        let result = run_full(&server_linter, &root_uri);
+        assert_eq!(result[0].1.len(), 1);
+        assert_eq!(server_linter.number_of_parsed_files(), 1);
+        assert!(root.join(".oxc/cache").is_file());
+
+        // a new server reads the cache written by the previous one
+        let server_linter = ServerLinter::new();
+        server_linter.enable_cache(&root_uri);
+        let cached = run_full(&server_linter, &root_uri);
+        assert_eq!(server_linter.number_of_parsed_files(), 0);
+        assert_eq!(cached[0].1[0].diagnostic, result[0].1[0].diagnostic);
+        assert!(cached[0].1[0].fixed_content.is_some());
+
+        std::fs::write(root.join("debugger.js"), "debugger;\ndebugger;").unwrap();
+        let result = run_full(&server_linter, &root_uri);
+        assert_eq!(result[0].1.len(), 2);
+        assert_eq!(server_linter.number_of_parsed_files(), 1);
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn config_path_and_fix() {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+        let root_uri = Url::from_file_path(root).unwrap();
+        let uri = Url::from_file_path(root.join("test.js")).unwrap();
+        let server_linter = ServerLinter::new();
+        let run = |source_text: &str| {
+            server_linter
+                .run_single(
+                    &root_uri,
+                    &uri,
+                    Some(source_text.to_string()),
+                    None,
+                    PositionEncoding::Utf16,
+                    &CancellationToken::default(),
+                )
+                .unwrap()
+        };
+
+        let config_path = Some("fixtures/config/.oxlintrc.json");
+        server_linter.make_config(&root_uri, config_path, Some(FixFilter::Safe), false).unwrap();
+        let reports = run("debugger;\nif (typeof a == 'b') {}\n");
+        let rule_names = reports.iter().map(|report| report.rule_name).collect::<Vec<_>>();
+        assert_eq!(rule_names, vec![Some("eqeqeq")]);
+        // the fix of `eqeqeq` is dangerous
+        assert!(reports[0].fixed_content.is_none());
+
+        server_linter.make_config(&root_uri, config_path, Some(FixFilter::All), false).unwrap();
+        let reports = run("if (typeof a == 'b') {}\n");
+        let fixed_content = reports[0].fixed_content.as_ref().unwrap();
+        assert_eq!(fixed_content.kind, FixKind::Dangerous);
+
+        server_linter.make_config(&root_uri, None, Some(FixFilter::Safe), false).unwrap();
+        let reports = run("debugger;");
+        assert_eq!(reports[0].fixed_content.as_ref().unwrap().kind, FixKind::Safe);
+
+        server_linter.make_config(&root_uri, None, None, false).unwrap();
+        let reports = run("debugger;");
+        assert_eq!(reports[0].rule_name, Some("no-debugger"));
+        assert!(reports[0].fixed_content.is_none());
+
+        let missing = server_linter.make_config(&root_uri, Some("missing.json"), None, false);
+        assert!(missing.is_err());
+    }
+
+    #[test]
+    fn vue_positions_refer_to_the_vue_file() {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+        let root_uri = Url::from_file_path(root).unwrap();
+        let uri = Url::from_file_path(root.join("test.vue")).unwrap();
+        let server_linter = ServerLinter::new();
+        server_linter.make_config(&root_uri, None, Some(FixFilter::Safe), false).unwrap();
+
+        let template = "  <div>🦀</div>\n".repeat(100);
+        let source_text = format!(
+            "<template>\n{template}</template>\n<script>\nlet a = 1;\ndebugger;\n</script>\n"
+        );
+        let reports = server_linter
+            .run_single(
+                &root_uri,
+                &uri,
+                Some(source_text),
+                None,
+                PositionEncoding::Utf16,
+                &CancellationToken::default(),
+            )
+            .unwrap();
+        assert_eq!(reports.len(), 1);
+        // `debugger` is on line 105 of the file
+        let range = reports[0].diagnostic.range;
+        assert_eq!(range.start, Position::new(104, 0));
+        assert_eq!(range.end.line, 104);
+        let fixed_content = reports[0].fixed_content.as_ref().unwrap();
+        assert_eq!(fixed_content.range.start, Position::new(104, 0));
+        assert_eq!(fixed_content.range.end.line, 104);
+    }
+
+    #[test]
+    fn vue_script_blocks_are_linted_separately() {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+        let root_uri = Url::from_file_path(root).unwrap();
+        let uri = Url::from_file_path(root.join("test.vue")).unwrap();
+        let server_linter = ServerLinter::new();
+        server_linter.make_config(&root_uri, None, None, false).unwrap();
+
+        // only the first block is TypeScript
+        let source_text = concat!(
+            "<script lang=\"ts\">\nexport default {} as object;\nif (a == NaN) {}\n</script>\n",
+            "<template><div /></template>\n",
+            "<script setup>\ndebugger;\n</script>\n",
+        );
+        let reports = server_linter
+            .run_single(
+                &root_uri,
+                &uri,
+                Some(source_text.to_string()),
+                None,
+                PositionEncoding::Utf16,
+                &CancellationToken::default(),
+            )
+            .unwrap();
+        let rule_names = reports.iter().map(|report| report.rule_name).collect::<Vec<_>>();
+        assert_eq!(rule_names, vec![Some("use-isnan"), Some("no-debugger")]);
+        let lines = reports.iter().map(|report| report.diagnostic.range.start.line);
+        assert_eq!(lines.collect::<Vec<_>>(), vec![2, 6]);
+    }
+
+    #[test]
+    fn markdown_code_blocks_are_linted_when_enabled() {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+        let root_uri = Url::from_file_path(root).unwrap();
+        let uri = Url::from_file_path(root.join("README.md")).unwrap();
+        let source_text = "# Title\n\n- item\n\n  ```ts\n  if (a == NaN) {}\n  ```\n";
+        let server_linter = ServerLinter::new();
+        let run = || {
+            server_linter.run_single(
+                &root_uri,
+                &uri,
+                Some(source_text.to_string()),
+                None,
+                PositionEncoding::Utf16,
+                &CancellationToken::default(),
+            )
+        };
+
+        server_linter.make_config(&root_uri, None, None, false).unwrap();
+        assert!(run().is_none());
+
+        server_linter.make_config(&root_uri, None, None, true).unwrap();
+        let reports = run().unwrap();
+        assert_eq!(reports.len(), 1);
+        assert_eq!(reports[0].rule_name, Some("use-isnan"));
+        assert_eq!(reports[0].diagnostic.range.start, Position::new(5, 11));
+    }
+
+    #[test]
+    fn module_record_is_built() {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+        let root_uri = Url::from_file_path(root).unwrap();
+        let uri = Url::from_file_path(root.join("test.js")).unwrap();
+        let reports = ServerLinter::new()
+            .run_single(
+                &root_uri,
+                &uri,
+                Some("export { a };".to_string()),
+                None,
+                PositionEncoding::Utf16,
+                &CancellationToken::default(),
+            )
+            .unwrap();
+        assert_eq!(reports.len(), 1);
+        assert!(reports[0].diagnostic.message.starts_with("Export 'a' is not defined"));
+        assert_eq!(reports[0].diagnostic.range.start, Position::new(0, 9));
+    }
+
+    #[test]
+    fn suggestions() {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+        let root_uri = Url::from_file_path(root).unwrap();
+        let uri = Url::from_file_path(root.join("test.js")).unwrap();
+        let reports = ServerLinter::new()
+            .run_single(
+                &root_uri,
+                &uri,
+                Some("!a in b".to_string()),
+                None,
+                PositionEncoding::Utf16,
+                &CancellationToken::default(),
+            )
+            .unwrap();
+        assert_eq!(reports[0].rule_name, Some("no-unsafe-negation"));
+        assert!(reports[0].fixed_content.is_none());
+        let codes = reports[0].suggestions.iter().map(|s| s.code.as_str()).collect::<Vec<_>>();
+        assert_eq!(codes, vec!["!(a in b)", "(!a)"]);
+        assert!(reports[0].suggestions.iter().all(|s| s.message.is_some()));
+    }
+
+    #[test]
+    fn diagnostic_without_labels() {
+        let rope = Rope::from_str("debugger;");
+        let error = oxc_diagnostics::miette::miette!("no labels");
+        let error =
+            ErrorWithPosition::new(error, &rope, None, vec![], None, PositionEncoding::Utf16);
+        let diagnostic = error.to_lsp_diagnostic(
+            &Url::from_file_path(Path::new(env!("CARGO_MANIFEST_DIR")).join("test.js")).unwrap(),
+        );
+        assert_eq!(diagnostic.range.start, Position::new(0, 0));
+        assert_eq!(diagnostic.range.end, Position::new(0, 0));
+        assert!(diagnostic.related_information.is_none());
+    }
+}
@@ -1,29 +1,46 @@
 #![allow(unused)]
+mod cancellation;
+mod code_actions;
+mod debounce;
 mod linter;
 mod options;
+mod progress;
 mod walk;
+mod workspace;
 
-use crate::linter::{DiagnosticReport, ServerLinter};
-use globset::Glob;
-use ignore::gitignore::Gitignore;
+use crate::cancellation::DocumentGenerations;
+use crate::code_actions::SOURCE_FIX_ALL_OXC;
+use crate::debounce::Debouncer;
+use crate::linter::{get_extensions, DiagnosticReport, PositionEncoding, ServerLinter};
+use crate::progress::ProgressReporter;
+use crate::workspace::{WorkspaceFolder, WorkspaceFolders};
 use log::{debug, error};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::hash_map::DefaultHasher;
 use std::fmt::Debug;
+use std::hash::{Hash, Hasher};
 use std::path::{Path, PathBuf};
-use std::str::FromStr;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
 
-use dashmap::DashMap;
+use dashmap::{DashMap, DashSet};
 use futures::future::join_all;
-use tokio::sync::{Mutex, OnceCell, SetError};
-use tower_lsp::jsonrpc::{Error, ErrorCode, Result};
+use oxc_linter::FixFilter;
+use tokio::sync::{Mutex, OnceCell};
+use tower_lsp::jsonrpc::Result;
 use tower_lsp::lsp_types::{
     CodeAction, CodeActionKind, CodeActionOptions, CodeActionOrCommand, CodeActionParams,
-    CodeActionProviderCapability, CodeActionResponse, Diagnostic, DidChangeConfigurationParams,
-    DidChangeTextDocumentParams, DidCloseTextDocumentParams, DidOpenTextDocumentParams,
-    DidSaveTextDocumentParams, InitializeParams, InitializeResult, InitializedParams, MessageType,
-    OneOf, Registration, ServerCapabilities, ServerInfo, TextDocumentSyncCapability,
-    TextDocumentSyncKind, TextEdit, Url, WorkDoneProgressOptions, WorkspaceEdit,
+    CodeActionProviderCapability, CodeActionResponse, Diagnostic, DiagnosticOptions,
+    DiagnosticServerCapabilities, DidChangeConfigurationParams, DidChangeTextDocumentParams,
+    DidChangeWatchedFilesParams, DidChangeWatchedFilesRegistrationOptions,
+    DidChangeWorkspaceFoldersParams, DidCloseTextDocumentParams, DidOpenTextDocumentParams,
+    DidSaveTextDocumentParams, DocumentDiagnosticParams, DocumentDiagnosticReport,
+    DocumentDiagnosticReportResult, FileChangeType, FileSystemWatcher,
+    FullDocumentDiagnosticReport, GlobPattern, InitializeParams, InitializeResult,
+    InitializedParams, MessageType, OneOf, Range, Registration,
+    RelatedFullDocumentDiagnosticReport, RelatedUnchangedDocumentDiagnosticReport,
+    ServerCapabilities, ServerInfo, TextDocumentSyncCapability, TextDocumentSyncKind,
+    UnchangedDocumentDiagnosticReport, Url, WorkDoneProgressOptions,
     WorkspaceFoldersServerCapabilities, WorkspaceServerCapabilities,
 };
 use tower_lsp::{Client, LanguageServer, LspService, Server};
@@ -31,11 +48,33 @@ use tower_lsp::{Client, LanguageServer, LspService, Server};
 #[derive(Debug)]
 struct Backend {
     client: Client,
-    root_uri: OnceCell<Option<Url>>,
-    server_linter: ServerLinter,
+    workspace_folders: WorkspaceFolders,
+    position_encoding: OnceCell<PositionEncoding>,
+    /// The client pulls diagnostics with `textDocument/diagnostic` instead of having them published
+    pull_diagnostics: OnceCell<bool>,
+    /// The client supports `workspace/diagnostic/refresh`
+    diagnostic_refresh: OnceCell<bool>,
+    /// The client supports registering a `workspace/didChangeWatchedFiles` watcher
+    watched_files_registration: OnceCell<bool>,
+    /// The client supports server initiated `window/workDoneProgress`
+    work_done_progress: OnceCell<bool>,
+    /// Bumped on every configuration change, part of the pull diagnostics result id
+    config_generation: AtomicUsize,
     diagnostics_report_map: DashMap<String, Vec<DiagnosticReport>>,
+    /// Documents with published diagnostics, cleared once they are removed from the workspace
+    published: DashSet<Url>,
+    /// Content of the open documents, re-linted when the configuration changes
+    documents: DashMap<Url, Document>,
+    /// Lints of outdated document versions are cancelled and never published
+    generations: DocumentGenerations,
+    debouncer: Debouncer,
     options: Mutex<Options>,
-    gitignore_glob: Mutex<Option<Gitignore>>,
+}
+#[derive(Debug, Clone)]
+struct Document {
+    text: String,
+    /// Language of documents without a backing file, e.g. `typescriptreact`
+    language_id: String,
 }
 #[derive(Debug, Serialize, Deserialize, Default, PartialEq, PartialOrd, Clone, Copy)]
 #[serde(rename_all = "camelCase")]
@@ -44,10 +83,51 @@ enum Run {
     #[default]
     OnType,
 }
-#[derive(Debug, Serialize, Deserialize, Default, Clone)]
+#[derive(Debug, Serialize, Deserialize, Default, PartialEq, Eq, Clone, Copy)]
+#[serde(rename_all = "camelCase")]
+enum FixKind {
+    /// No fixes are offered as code actions
+    None,
+    #[default]
+    Safe,
+    All,
+}
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "camelCase", default)]
 struct Options {
     run: Run,
     enable: bool,
+    /// Persist diagnostics of unchanged files in `.oxc/cache`
+    cache: bool,
+    /// Milliseconds to wait for further changes before linting on type
+    debounce_ms: u64,
+    /// Fixes offered as code actions, dangerous fixes are only offered with `all`
+    fix_kind: FixKind,
+    /// Configuration file, relative to the workspace root
+    config_path: Option<String>,
+    /// Directory of the linter plugins, relative to the workspace root
+    plugin_path: Option<String>,
+    /// Patterns of ignored files in `.gitignore` syntax, in addition to the `.gitignore` and
+    /// `.eslintignore` files of the workspace
+    ignore_patterns: Vec<String>,
+    /// Lint the JavaScript and TypeScript code blocks of Markdown files
+    markdown: bool,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            run: Run::default(),
+            enable: true,
+            cache: false,
+            debounce_ms: 0,
+            fix_kind: FixKind::default(),
+            config_path: None,
+            plugin_path: None,
+            ignore_patterns: vec![],
+            markdown: false,
+        }
+    }
 }
 
 impl Options {
@@ -61,6 +141,14 @@ impl Options {
             SyntheticRunLevel::Disable
         }
     }
+
+    fn fix_filter(&self) -> Option<FixFilter> {
+        match self.fix_kind {
+            FixKind::None => None,
+            FixKind::Safe => Some(FixFilter::Safe),
+            FixKind::All => Some(FixFilter::All),
+        }
+    }
 }
 
 #[derive(Debug, PartialEq, PartialOrd, Clone, Copy)]
@@ -73,8 +161,41 @@ enum SyntheticRunLevel {
 #[tower_lsp::async_trait]
 impl LanguageServer for Backend {
     async fn initialize(&self, params: InitializeParams) -> Result<InitializeResult> {
-        self.init(params.root_uri)?;
-        self.init_ignore_glob().await;
+        // `root_uri` is deprecated in favor of `workspace_folders`.
+        let root_uris = params.workspace_folders.map_or_else(
+            || params.root_uri.into_iter().collect(),
+            |folders| folders.into_iter().map(|folder| folder.uri).collect::<Vec<_>>(),
+        );
+        for root_uri in root_uris {
+            self.workspace_folders.add(root_uri);
+        }
+        let position_encoding = PositionEncoding::negotiate(
+            params.capabilities.general.as_ref().and_then(|g| g.position_encodings.as_deref()),
+        );
+        // `initialize` is only called once, so the cell is always empty here.
+        let _ = self.position_encoding.set(position_encoding);
+        let pull_diagnostics =
+            params.capabilities.text_document.as_ref().map_or(false, |c| c.diagnostic.is_some());
+        let _ = self.pull_diagnostics.set(pull_diagnostics);
+        let diagnostic_refresh = params
+            .capabilities
+            .workspace
+            .as_ref()
+            .and_then(|c| c.diagnostic.as_ref())
+            .and_then(|c| c.refresh_support)
+            .unwrap_or(false);
+        let _ = self.diagnostic_refresh.set(diagnostic_refresh);
+        let watched_files_registration = params
+            .capabilities
+            .workspace
+            .as_ref()
+            .and_then(|c| c.did_change_watched_files)
+            .and_then(|c| c.dynamic_registration)
+            .unwrap_or(false);
+        let _ = self.watched_files_registration.set(watched_files_registration);
+        let work_done_progress =
+            params.capabilities.window.as_ref().and_then(|c| c.work_done_progress).unwrap_or(false);
+        let _ = self.work_done_progress.set(work_done_progress);
         let options = params.initialization_options.and_then(|mut value| {
             let settings = value.get_mut("settings")?.take();
             serde_json::from_value::<Options>(settings).ok()
@@ -88,6 +209,7 @@ impl LanguageServer for Backend {
             server_info: Some(ServerInfo { name: "oxc".into(), version: None }),
             offset_encoding: None,
             capabilities: ServerCapabilities {
+                position_encoding: Some(position_encoding.to_kind()),
                 text_document_sync: Some(TextDocumentSyncCapability::Kind(
                     TextDocumentSyncKind::FULL,
                 )),
@@ -100,13 +222,21 @@ impl LanguageServer for Backend {
                 }),
                 code_action_provider: Some(CodeActionProviderCapability::Options(
                     CodeActionOptions {
-                        code_action_kinds: Some(vec![CodeActionKind::QUICKFIX]),
+                        code_action_kinds: Some(vec![CodeActionKind::QUICKFIX, SOURCE_FIX_ALL_OXC]),
                         work_done_progress_options: WorkDoneProgressOptions {
                             work_done_progress: None,
                         },
                         resolve_provider: None,
                     },
                 )),
+                diagnostic_provider: Some(DiagnosticServerCapabilities::Options(
+                    DiagnosticOptions {
+                        identifier: Some("oxc".into()),
+                        inter_file_dependencies: false,
+                        workspace_diagnostics: false,
+                        work_done_progress_options: WorkDoneProgressOptions::default(),
+                    },
+                )),
                 ..ServerCapabilities::default()
             },
         })
@@ -121,41 +251,120 @@ impl LanguageServer for Backend {
             }
         };
         debug!("{:?}", &changed_options.get_lint_level());
+        let previous_options =
+            std::mem::replace(&mut *self.options.lock().await, changed_options.clone());
+        self.config_generation.fetch_add(1, Ordering::Relaxed);
         if changed_options.get_lint_level() == SyntheticRunLevel::Disable {
-            // clear all exists diagnostics when linter is disabled
-            let opened_files = self.diagnostics_report_map.iter().map(|k| k.key().to_string());
-            let cleared_diagnostics = opened_files
-                .into_iter()
-                .map(|uri| {
-                    (
-                        // should convert successfully, case the key is from `params.document.uri`
-                        Url::from_str(&uri)
-                            .ok()
-                            .and_then(|url| url.to_file_path().ok())
-                            .expect("should convert to path"),
-                        vec![],
-                    )
-                })
-                .collect::<Vec<_>>();
-            self.publish_all_diagnostics(&cleared_diagnostics).await;
+            self.clear_all_diagnostics().await;
+            return;
+        }
+        let linter_changed = previous_options.fix_kind != changed_options.fix_kind
+            || previous_options.config_path != changed_options.config_path
+            || previous_options.markdown != changed_options.markdown;
+        let plugin_changed = previous_options.plugin_path != changed_options.plugin_path;
+        let ignore_changed = previous_options.ignore_patterns != changed_options.ignore_patterns;
+        for folder in self.workspace_folders.all() {
+            if ignore_changed {
+                self.init_ignore(&folder).await;
+            }
+            if linter_changed {
+                self.init_linter_config(&folder).await;
+            }
+            if plugin_changed {
+                self.init_plugin(&folder).await;
+            }
+        }
+        if linter_changed
+            || plugin_changed
+            || ignore_changed
+            || previous_options.get_lint_level() == SyntheticRunLevel::Disable
+        {
+            self.relint_open_documents().await;
         }
-        *self.options.lock().await = changed_options;
     }
 
     async fn initialized(&self, params: InitializedParams) {
         debug!("oxc initialized.");
 
-        if let Some(Some(root_uri)) = self.root_uri.get() {
-            self.server_linter.make_plugin(root_uri);
-            // let result = self.server_linter.run_full(root_uri);
+        self.watch_files().await;
+        for folder in self.workspace_folders.all() {
+            self.init_folder(&folder).await;
+        }
+    }
+
+    async fn did_change_workspace_folders(&self, params: DidChangeWorkspaceFoldersParams) {
+        for removed in params.event.removed {
+            let Some(folder) = self.workspace_folders.remove(&removed.uri) else {
+                continue;
+            };
+            let cleared_diagnostics = self
+                .published
+                .iter()
+                .map(|uri| uri.key().clone())
+                .filter(|uri| {
+                    uri.to_file_path().map_or(false, |path| folder.contains(&path))
+                        && self.workspace_folders.find(uri).is_none()
+                })
+                .map(|uri| (uri, vec![]))
+                .collect::<Vec<_>>();
+            for (uri, _) in &cleared_diagnostics {
+                self.diagnostics_report_map.remove(&uri.to_string());
+            }
+            self.publish_all_diagnostics(cleared_diagnostics).await;
+        }
+        for added in params.event.added {
+            let folder = self.workspace_folders.add(added.uri);
+            self.init_folder(&folder).await;
+        }
+        if self.pull_diagnostics() {
+            self.refresh_diagnostics().await;
+        }
+    }
+
+    async fn did_change_watched_files(&self, params: DidChangeWatchedFilesParams) {
+        let (run_level, plugin_path) = {
+            let options = self.options.lock().await;
+            (options.get_lint_level(), options.plugin_path.clone())
+        };
+        let folders = self.workspace_folders.all();
+        let plugin_dirs = folders
+            .iter()
+            .map(|folder| ServerLinter::plugin_dir(&folder.root_uri, plugin_path.as_deref()))
+            .collect::<Vec<_>>();
+        let mut plugin_changed = vec![false; folders.len()];
+        // A rename is a deletion of the old and a creation of the new file.
+        for change in params.changes {
+            let uri = change.uri;
+            let plugin = uri.to_file_path().ok().and_then(|path| {
+                plugin_dirs.iter().position(|plugin_dir| path.starts_with(plugin_dir))
+            });
+            if let Some(index) = plugin {
+                plugin_changed[index] = true;
+                continue;
+            }
+            if change.typ == FileChangeType::DELETED {
+                self.generations.remove(&uri);
+                self.diagnostics_report_map.remove(&uri.to_string());
+                if self.published.contains(&uri) {
+                    self.publish_diagnostics(uri, vec![], None).await;
+                }
+                continue;
+            }
+            // The content of open documents is linted on change instead.
+            if run_level == SyntheticRunLevel::Disable
+                || self.documents.contains_key(&uri)
+                || self.is_ignored(&uri)
+            {
+                continue;
+            }
+            self.handle_file_update(uri, None, None).await;
+        }
 
-            // self.publish_all_diagnostics(
-            // &result
-            // .into_iter()
-            // .map(|(p, d)| (p, d.into_iter().map(|d| d.diagnostic).collect()))
-            // .collect(),
-            // )
-            // .await;
+        for (folder, _) in folders.iter().zip(&plugin_changed).filter(|(_, changed)| **changed) {
+            self.init_plugin(folder).await;
+        }
+        if plugin_changed.contains(&true) && run_level != SyntheticRunLevel::Disable {
+            self.relint_open_documents().await;
         }
     }
 
@@ -170,24 +379,33 @@ impl LanguageServer for Backend {
         if run_level < SyntheticRunLevel::OnSave {
             return;
         }
-        if self.is_ignored(&params.text_document.uri).await {
-            return;
-        }
         self.handle_file_update(params.text_document.uri, None, None).await;
     }
 
     /// When the document changed, it may not be written to disk, so we should
     /// get the file context from the language client
     async fn did_change(&self, params: DidChangeTextDocumentParams) {
-        let run_level = { self.options.lock().await.get_lint_level() };
+        // The documents are kept up to date whatever the run mode, for the pull diagnostics.
+        let content = params.content_changes.first().map(|c| c.text.clone());
+        if let (Some(content), Some(mut document)) =
+            (&content, self.documents.get_mut(&params.text_document.uri))
+        {
+            document.text = content.clone();
+        }
+
+        let (run_level, debounce_ms) = {
+            let options = self.options.lock().await;
+            (options.get_lint_level(), options.debounce_ms)
+        };
         if run_level < SyntheticRunLevel::OnType {
             return;
         }
 
-        if self.is_ignored(&params.text_document.uri).await {
+        // Only the last change of a burst is linted, with the final content.
+        let delay = Duration::from_millis(debounce_ms);
+        if !self.debouncer.debounce(&params.text_document.uri, delay).await {
             return;
         }
-        let content = params.content_changes.first().map(|c| c.text.clone());
         self.handle_file_update(
             params.text_document.uri,
             content,
@@ -197,145 +415,390 @@ impl LanguageServer for Backend {
     }
 
     async fn did_open(&self, params: DidOpenTextDocumentParams) {
+        let document = params.text_document;
+        self.documents.insert(
+            document.uri.clone(),
+            Document { text: document.text.clone(), language_id: document.language_id },
+        );
         let run_level = { self.options.lock().await.get_lint_level() };
-        if run_level < SyntheticRunLevel::OnType {
-            return;
-        }
-        if self.is_ignored(&params.text_document.uri).await {
+        if run_level < SyntheticRunLevel::OnSave {
             return;
         }
-        self.handle_file_update(params.text_document.uri, None, Some(params.text_document.version))
-            .await;
+        self.handle_file_update(document.uri, Some(document.text), Some(document.version)).await;
     }
 
     async fn did_close(&self, params: DidCloseTextDocumentParams) {
+        self.documents.remove(&params.text_document.uri);
+        self.generations.remove(&params.text_document.uri);
+        self.debouncer.remove(&params.text_document.uri);
         let uri = params.text_document.uri.to_string();
         self.diagnostics_report_map.remove(&uri);
     }
 
+    async fn diagnostic(
+        &self,
+        params: DocumentDiagnosticParams,
+    ) -> Result<DocumentDiagnosticReportResult> {
+        let uri = params.text_document.uri;
+        let content =
+            self.documents.get(&uri).map(|document| document.text.clone()).or_else(|| {
+                uri.to_file_path().ok().and_then(|path| std::fs::read_to_string(path).ok())
+            });
+        let Some(content) = content else {
+            return Ok(full_diagnostic_report(None, vec![]));
+        };
+
+        let result_id = result_id(&content, self.config_generation.load(Ordering::Relaxed));
+        if params.previous_result_id.as_ref() == Some(&result_id) {
+            return Ok(DocumentDiagnosticReportResult::Report(
+                DocumentDiagnosticReport::Unchanged(RelatedUnchangedDocumentDiagnosticReport {
+                    related_documents: None,
+                    unchanged_document_diagnostic_report: UnchangedDocumentDiagnosticReport {
+                        result_id,
+                    },
+                }),
+            ));
+        }
+
+        let run_level = { self.options.lock().await.get_lint_level() };
+        if run_level == SyntheticRunLevel::Disable || self.is_ignored(&uri) {
+            return Ok(full_diagnostic_report(Some(result_id), vec![]));
+        }
+        // Without a result id the client asks again, e.g. after the next change.
+        let Some(reports) = self.lint_document(&uri, Some(content)) else {
+            return Ok(full_diagnostic_report(None, vec![]));
+        };
+        let items = reports.into_iter().map(|report| report.diagnostic).collect();
+        Ok(full_diagnostic_report(Some(result_id), items))
+    }
+
     async fn code_action(&self, params: CodeActionParams) -> Result<Option<CodeActionResponse>> {
         let uri = params.text_document.uri;
 
-        if let Some(value) = self.diagnostics_report_map.get(&uri.to_string()) {
-            if let Some(report) = value
-                .iter()
-                .find(|r| r.diagnostic.range == params.range && r.fixed_content.is_some())
-            {
-                let title =
-                    report.diagnostic.message.split(':').next().map_or_else(
-                        || "Fix this problem".into(),
-                        |s| format!("Fix this {s} problem"),
-                    );
-
-                let fixed_content = report.fixed_content.clone().unwrap();
-
-                return Ok(Some(vec![CodeActionOrCommand::CodeAction(CodeAction {
-                    title,
-                    kind: Some(CodeActionKind::QUICKFIX),
-                    is_preferred: Some(true),
-                    edit: Some(WorkspaceEdit {
-                        changes: Some(HashMap::from([(
-                            uri,
-                            vec![TextEdit {
-                                range: fixed_content.range,
-                                new_text: fixed_content.code,
-                            }],
-                        )])),
-                        ..WorkspaceEdit::default()
-                    }),
-                    disabled: None,
-                    data: None,
-                    diagnostics: None,
-                    command: None,
-                })]));
-            }
+        let Some(value) = self.diagnostics_report_map.get(&uri.to_string()) else {
+            return Ok(None);
+        };
+
+        let only = params.context.only.as_deref();
+        let mut code_actions = vec![];
+        if is_requested(only, &CodeActionKind::QUICKFIX) {
+            // Every fix overlapping the requested range gets its own action,
+            // edits of different reports are never merged into one.
+            let reports =
+                value.iter().filter(|r| range_overlaps(&r.diagnostic.range, &params.range));
+            let quick_fixes = reports.clone().filter_map(|r| code_actions::quick_fix(&uri, r));
+            let suggestions = reports.clone().flat_map(|r| code_actions::suggestions(&uri, r));
+            let disable_comments = reports.filter_map(|r| code_actions::disable_next_line(&uri, r));
+            code_actions.extend(quick_fixes.chain(suggestions).chain(disable_comments));
+        }
+        // All fixes of the document, regardless of the requested range.
+        if is_requested(only, &SOURCE_FIX_ALL_OXC) {
+            code_actions.extend(code_actions::fix_all(&uri, &value));
+        }
+        let code_actions =
+            code_actions.into_iter().map(CodeActionOrCommand::CodeAction).collect::<Vec<_>>();
+
+        if code_actions.is_empty() {
+            return Ok(None);
         }
 
-        Ok(None)
+        Ok(Some(code_actions))
     }
 }
 
 impl Backend {
-    fn init(&self, root_uri: Option<Url>) -> Result<()> {
-        self.root_uri.set(root_uri).map_err(|err| {
-            let message = match err {
-                SetError::AlreadyInitializedError(_) => "root uri already initialized".into(),
-                SetError::InitializingError(_) => "initializing error".into(),
-            };
+    fn new(client: Client) -> Self {
+        Self {
+            client,
+            workspace_folders: WorkspaceFolders::default(),
+            position_encoding: OnceCell::new(),
+            pull_diagnostics: OnceCell::new(),
+            diagnostic_refresh: OnceCell::new(),
+            watched_files_registration: OnceCell::new(),
+            work_done_progress: OnceCell::new(),
+            config_generation: AtomicUsize::new(0),
+            diagnostics_report_map: DashMap::new(),
+            published: DashSet::new(),
+            documents: DashMap::new(),
+            generations: DocumentGenerations::default(),
+            debouncer: Debouncer::default(),
+            options: Mutex::new(Options::default()),
+        }
+    }
 
-            Error { code: ErrorCode::ParseError, message, data: None }
-        })?;
+    /// Load the configuration, cache and plugins of a new folder and lint its files.
+    async fn init_folder(&self, folder: &WorkspaceFolder) {
+        self.init_ignore(folder).await;
+        self.init_linter_config(folder).await;
+        if self.options.lock().await.cache {
+            folder.server_linter.enable_cache(&folder.root_uri);
+        }
+        self.init_plugin(folder).await;
+        // Clients pulling diagnostics request them per document.
+        if !self.pull_diagnostics() {
+            self.lint_workspace(folder).await;
+        }
+    }
 
-        Ok(())
+    async fn init_ignore(&self, folder: &WorkspaceFolder) {
+        let ignore_patterns = self.options.lock().await.ignore_patterns.clone();
+        folder.server_linter.make_ignore(&folder.root_uri, &ignore_patterns);
     }
 
-    async fn init_ignore_glob(&self) {
-        let uri = self
-            .root_uri
-            .get()
-            .expect("The root uri should be initialized already")
-            .as_ref()
-            .expect("should get uri");
-        let mut builder = globset::GlobSetBuilder::new();
-        // Collecting all ignore files
-        builder.add(Glob::new("**/.eslintignore").unwrap());
-        builder.add(Glob::new("**/.gitignore").unwrap());
-
-        let ignore_file_glob_set = builder.build().unwrap();
-
-        let mut gitignore_builder = ignore::gitignore::GitignoreBuilder::new(uri.path());
-        let walk = ignore::WalkBuilder::new(uri.path())
-            .ignore(true)
-            .hidden(false)
-            .git_global(false)
-            .build();
-        for entry in walk.flatten() {
-            if ignore_file_glob_set.is_match(entry.path()) {
-                gitignore_builder.add(entry.path());
+    async fn init_linter_config(&self, folder: &WorkspaceFolder) {
+        let options = self.options.lock().await.clone();
+        match folder.server_linter.make_config(
+            &folder.root_uri,
+            options.config_path.as_deref(),
+            options.fix_filter(),
+            options.markdown,
+        ) {
+            Ok(warnings) => {
+                for warning in warnings {
+                    self.client.log_message(MessageType::WARNING, warning).await;
+                }
+            }
+            Err(err) => {
+                self.client
+                    .show_message(MessageType::ERROR, format!("Failed to load oxc config: {err}"))
+                    .await;
             }
         }
+    }
 
-        *self.gitignore_glob.lock().await = gitignore_builder.build().ok();
+    async fn publish_diagnostics(
+        &self,
+        uri: Url,
+        diagnostics: Vec<Diagnostic>,
+        version: Option<i32>,
+    ) {
+        if diagnostics.is_empty() {
+            self.published.remove(&uri);
+        } else {
+            self.published.insert(uri.clone());
+        }
+        self.client.publish_diagnostics(uri, diagnostics, version).await;
     }
 
-    #[allow(clippy::ptr_arg)]
-    async fn publish_all_diagnostics(&self, result: &Vec<(PathBuf, Vec<Diagnostic>)>) {
-        join_all(result.iter().map(|(path, diagnostics)| {
-            self.client.publish_diagnostics(
-                Url::from_file_path(path).unwrap(),
-                diagnostics.clone(),
-                None,
-            )
-        }))
+    async fn publish_all_diagnostics(&self, result: Vec<(Url, Vec<Diagnostic>)>) {
+        join_all(
+            result
+                .into_iter()
+                .map(|(uri, diagnostics)| self.publish_diagnostics(uri, diagnostics, None)),
+        )
         .await;
     }
 
+    async fn init_plugin(&self, folder: &WorkspaceFolder) {
+        let plugin_path = self.options.lock().await.plugin_path.clone();
+        if let Err(err) = folder.server_linter.make_plugin(&folder.root_uri, plugin_path.as_deref())
+        {
+            self.client
+                .show_message(MessageType::ERROR, format!("Failed to load oxc plugins: {err}"))
+                .await;
+        }
+    }
+
+    /// Watch the files with lintable extensions and the plugin queries for `workspace/didChangeWatchedFiles`.
+    async fn watch_files(&self) {
+        if !self.watched_files_registration.get().copied().unwrap_or_default() {
+            return;
+        }
+        let watcher = FileSystemWatcher {
+            // Markdown files are filtered when they are linted, so that the option can be
+            // changed without registering the watcher again.
+            glob_pattern: GlobPattern::String(format!(
+                "**/*.{{{}}}",
+                get_extensions(true).join(",")
+            )),
+            kind: None,
+        };
+        // The plugin directory is configurable, its queries are filtered on change.
+        let plugin_watcher =
+            FileSystemWatcher { glob_pattern: GlobPattern::String("**/*.yml".into()), kind: None };
+        let options =
+            DidChangeWatchedFilesRegistrationOptions { watchers: vec![watcher, plugin_watcher] };
+        let registration = Registration {
+            id: "oxc/watchedFiles".into(),
+            method: "workspace/didChangeWatchedFiles".into(),
+            register_options: serde_json::to_value(options).ok(),
+        };
+        if let Err(err) = self.client.register_capability(vec![registration]).await {
+            error!("failed to watch files: {:?}", err);
+        }
+    }
+
+    fn pull_diagnostics(&self) -> bool {
+        self.pull_diagnostics.get().copied().unwrap_or_default()
+    }
+
+    /// Ask a client pulling diagnostics to pull them again for all documents.
+    async fn refresh_diagnostics(&self) {
+        if self.diagnostic_refresh.get().copied().unwrap_or_default() {
+            if let Err(err) = self.client.workspace_diagnostic_refresh().await {
+                error!("failed to refresh diagnostics: {:?}", err);
+            }
+        }
+    }
+
+    /// Clear the published diagnostics of all documents.
+    async fn clear_all_diagnostics(&self) {
+        if self.pull_diagnostics() {
+            self.diagnostics_report_map.clear();
+            self.refresh_diagnostics().await;
+            return;
+        }
+        let cleared_diagnostics =
+            self.published.iter().map(|uri| (uri.key().clone(), vec![])).collect::<Vec<_>>();
+        self.diagnostics_report_map.clear();
+        self.publish_all_diagnostics(cleared_diagnostics).await;
+    }
+
+    async fn relint_open_documents(&self) {
+        if self.pull_diagnostics() {
+            self.refresh_diagnostics().await;
+            return;
+        }
+        let documents = self
+            .documents
+            .iter()
+            .map(|entry| (entry.key().clone(), entry.value().text.clone()))
+            .collect::<Vec<_>>();
+        // Documents which became ignored are cleared.
+        for (uri, content) in documents {
+            self.handle_file_update(uri, Some(content), None).await;
+        }
+    }
+
     async fn handle_file_update(&self, uri: Url, content: Option<String>, version: Option<i32>) {
-        if let Some(Some(root_uri)) = self.root_uri.get() {
-            self.server_linter.make_plugin(root_uri);
-            if let Some(diagnostics) = self.server_linter.run_single(root_uri, &uri, content) {
-                self.client
-                    .publish_diagnostics(
-                        uri.clone(),
-                        diagnostics.clone().into_iter().map(|d| d.diagnostic).collect(),
-                        None,
-                    )
-                    .await;
+        // Clients pulling diagnostics request them once they need them.
+        if self.pull_diagnostics() {
+            return;
+        }
+        if let Some(diagnostics) = self.lint_document(&uri, content) {
+            let diagnostics = diagnostics.into_iter().map(|d| d.diagnostic).collect();
+            self.publish_diagnostics(uri, diagnostics, version).await;
+        }
+    }
 
-                self.diagnostics_report_map.insert(uri.to_string(), diagnostics);
+    /// Lint `uri` and keep the reports for code actions.
+    ///
+    /// Returns `None` for unsupported files and if a newer version was linted in the meantime.
+    fn lint_document(&self, uri: &Url, content: Option<String>) -> Option<Vec<DiagnosticReport>> {
+        let folder = self.workspace_folders.find_or_first(uri)?;
+        let token = self.generations.next(uri);
+        let encoding = self.position_encoding.get().copied().unwrap_or_default();
+        let language_id = self.documents.get(uri).map(|document| document.language_id.clone());
+        let diagnostics = folder.server_linter.run_single(
+            &folder.root_uri,
+            uri,
+            content,
+            language_id.as_deref(),
+            encoding,
+            &token,
+        )?;
+        // A newer version may have been linted while this one was being linted.
+        if token.is_cancelled() {
+            return None;
+        }
+        self.diagnostics_report_map.insert(uri.to_string(), diagnostics.clone());
+        Some(diagnostics)
+    }
+
+    /// Lint all files of the workspace, reporting the progress to clients supporting it.
+    ///
+    /// Diagnostics are published per file as soon as it is linted, files without diagnostics
+    /// get an empty publish to clear the diagnostics of a previous run.
+    async fn lint_workspace(&self, folder: &WorkspaceFolder) {
+        let encoding = self.position_encoding.get().copied().unwrap_or_default();
+        let (number_of_files, mut results) =
+            folder.server_linter.run_full(&folder.root_uri, encoding);
+        let mut progress = if self.work_done_progress.get().copied().unwrap_or_default() {
+            let message = format!("Linting {number_of_files} files");
+            ProgressReporter::begin(
+                &self.client,
+                WORKSPACE_LINT_PROGRESS,
+                "oxc",
+                message,
+                number_of_files,
+            )
+            .await
+        } else {
+            None
+        };
+
+        let mut processed = 0;
+        while let Some((path, reports)) = results.recv().await {
+            processed += 1;
+            if let Ok(uri) = Url::from_file_path(&path) {
+                let diagnostics = reports.iter().map(|r| r.diagnostic.clone()).collect();
+                if reports.is_empty() {
+                    self.diagnostics_report_map.remove(&uri.to_string());
+                } else {
+                    self.diagnostics_report_map.insert(uri.to_string(), reports);
+                }
+                self.publish_diagnostics(uri, diagnostics, None).await;
+            }
+            if let Some(progress) = &mut progress {
+                progress.report(processed).await;
             }
         }
+
+        if let Some(progress) = progress {
+            progress.end(Some(format!("Linted {processed} files"))).await;
+        }
     }
 
-    async fn is_ignored(&self, uri: &Url) -> bool {
-        let Some(ref gitignore_globs) = *self.gitignore_glob.lock().await else {
+    fn is_ignored(&self, uri: &Url) -> bool {
+        let Some(folder) = self.workspace_folders.find(uri) else {
+            return false;
+        };
+        let Ok(path) = uri.to_file_path() else {
             return false;
         };
-        let path = PathBuf::from(uri.path());
-        gitignore_globs.matched_path_or_any_parents(&path, path.is_dir()).is_ignore()
+        folder.is_ignored(&path)
     }
 }
 
+/// Identifies the diagnostics of `content` under the configuration of `config_generation`.
+fn result_id(content: &str, config_generation: usize) -> String {
+    let mut hasher = DefaultHasher::new();
+    content.hash(&mut hasher);
+    config_generation.hash(&mut hasher);
+    format!("{:x}", hasher.finish())
+}
+
+fn full_diagnostic_report(
+    result_id: Option<String>,
+    items: Vec<Diagnostic>,
+) -> DocumentDiagnosticReportResult {
+    DocumentDiagnosticReportResult::Report(DocumentDiagnosticReport::Full(
+        RelatedFullDocumentDiagnosticReport {
+            related_documents: None,
+            full_document_diagnostic_report: FullDocumentDiagnosticReport { result_id, items },
+        },
+    ))
+}
+
+/// Token of the work done progress of [`Backend::lint_workspace`]
+const WORKSPACE_LINT_PROGRESS: &str = "oxc/lintWorkspace";
+
+/// Whether actions of `kind` are requested by the `only` filter of the client,
+/// which also requests all sub kinds, e.g. `source` requests `source.fixAll.oxc`.
+fn is_requested(only: Option<&[CodeActionKind]>, kind: &CodeActionKind) -> bool {
+    only.map_or(true, |only| {
+        only.iter().any(|requested| {
+            kind.as_str()
+                .strip_prefix(requested.as_str())
+                .map_or(false, |rest| rest.is_empty() || rest.starts_with('.'))
+        })
+    })
+}
+
+fn range_overlaps(a: &Range, b: &Range) -> bool {
+    a.start <= b.end && b.start <= a.end
+}
+
 #[tokio::main]
 async fn main() {
     env_logger::init();
@@ -343,18 +806,576 @@ async fn main() {
     let stdin = tokio::io::stdin();
     let stdout = tokio::io::stdout();
 
-    let server_linter = ServerLinter::new();
-    let diagnostics_report_map = DashMap::new();
-
-    let (service, socket) = LspService::build(|client| Backend {
-        client,
-        root_uri: OnceCell::new(),
-        server_linter,
-        diagnostics_report_map,
-        options: Mutex::new(Options::default()),
-        gitignore_glob: Mutex::new(None),
-    })
-    .finish();
+    let (service, socket) = LspService::build(Backend::new).finish();
 
     Server::new(stdin, stdout, socket).serve(service).await;
 }
+
+#[cfg(test)]
+mod test {
+    use std::{
+        fs,
+        path::{Path, PathBuf},
+    };
+
+    use futures::{SinkExt, StreamExt};
+    use serde::de::DeserializeOwned;
+    use serde_json::json;
+    use tokio::sync::mpsc;
+    use tower_lsp::{
+        jsonrpc::{Request, Response},
+        lsp_types::{
+            CodeAction, CodeActionKind, DocumentDiagnosticReport, MessageType, ProgressParams,
+            ProgressParamsValue, PublishDiagnosticsParams, ShowMessageParams, Url,
+            WorkDoneProgress,
+        },
+        LspService,
+    };
+    use tower_service::Service;
+
+    use super::{
+        is_requested, result_id, Backend, FixFilter, FixKind, Options, Run, SOURCE_FIX_ALL_OXC,
+    };
+
+    #[test]
+    fn deserialize_options() {
+        let options = serde_json::from_value::<Options>(json!({
+            "run": "onSave",
+            "enable": false,
+            "fixKind": "none",
+            "configPath": "config/.eslintrc.json",
+            "pluginPath": "plugins",
+        }))
+        .unwrap();
+        assert_eq!(options.run, Run::OnSave);
+        assert!(!options.enable);
+        assert_eq!(options.fix_kind, FixKind::None);
+        assert_eq!(options.fix_filter(), None);
+        assert_eq!(options.config_path.as_deref(), Some("config/.eslintrc.json"));
+        assert_eq!(options.plugin_path.as_deref(), Some("plugins"));
+
+        // missing settings fall back to the defaults
+        let options = serde_json::from_value::<Options>(json!({ "fixKind": "all" })).unwrap();
+        assert_eq!(options, Options { fix_kind: FixKind::All, ..Options::default() });
+        assert!(options.enable);
+        assert_eq!(options.fix_filter(), Some(FixFilter::All));
+
+        assert!(serde_json::from_value::<Options>(json!({ "fixKind": "unsafe" })).is_err());
+    }
+
+    fn notification(method: &'static str, params: serde_json::Value) -> Request {
+        Request::build(method).params(params).finish()
+    }
+
+    /// Create an empty workspace in the temp directory containing `files`.
+    fn workspace(name: &str, files: &[(&str, &str)]) -> PathBuf {
+        let root = std::env::temp_dir().join(format!("oxc_language_server_{name}"));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(&root).unwrap();
+        for (file, content) in files {
+            let path = root.join(file);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, content).unwrap();
+        }
+        root
+    }
+
+    /// Initialize a server for `root`.
+    ///
+    /// Returns the service and all requests and notifications sent to the client,
+    /// requests of the server are answered with `null`.
+    async fn initialize(
+        root: &Path,
+        capabilities: serde_json::Value,
+    ) -> (LspService<Backend>, mpsc::UnboundedReceiver<Request>) {
+        let params =
+            json!({ "rootUri": Url::from_file_path(root).unwrap(), "capabilities": capabilities });
+        initialize_with(params).await
+    }
+
+    async fn initialize_with(
+        mut params: serde_json::Value,
+    ) -> (LspService<Backend>, mpsc::UnboundedReceiver<Request>) {
+        let (mut service, socket) = LspService::new(Backend::new);
+        let (tx, rx) = mpsc::unbounded_channel();
+        tokio::spawn(async move {
+            let (mut requests, mut responses) = socket.split();
+            while let Some(request) = requests.next().await {
+                if let Some(id) = request.id().cloned() {
+                    responses.send(Response::from_ok(id, serde_json::Value::Null)).await.unwrap();
+                }
+                let _ = tx.send(request);
+            }
+        });
+
+        if params.get("initializationOptions").is_none() {
+            params["initializationOptions"] =
+                json!({ "settings": { "run": "onType", "enable": true } });
+        }
+        let initialize = Request::build("initialize").params(params).id(1).finish();
+        service.call(initialize).await.unwrap();
+        service.call(notification("initialized", json!({}))).await.unwrap();
+        (service, rx)
+    }
+
+    /// Initialize a server for an empty workspace with `debugger;` opened in `test.js`.
+    async fn start(
+        name: &str,
+        capabilities: serde_json::Value,
+    ) -> (LspService<Backend>, Url, mpsc::UnboundedReceiver<Request>) {
+        let root = workspace(name, &[]);
+        let uri = Url::from_file_path(root.join("test.js")).unwrap();
+        let (mut service, rx) = initialize(&root, capabilities).await;
+        open(&mut service, &uri, "debugger;").await;
+        (service, uri, rx)
+    }
+
+    async fn open(service: &mut LspService<Backend>, uri: &Url, text: &str) {
+        let text_document =
+            json!({ "uri": uri, "languageId": "javascript", "version": 1, "text": text });
+        let did_open =
+            notification("textDocument/didOpen", json!({ "textDocument": text_document }));
+        service.call(did_open).await.unwrap();
+    }
+
+    fn params<T: DeserializeOwned>(request: &Request) -> T {
+        serde_json::from_value(request.params().cloned().unwrap()).unwrap()
+    }
+
+    /// Skip to the next published diagnostics.
+    async fn next_published(rx: &mut mpsc::UnboundedReceiver<Request>) -> PublishDiagnosticsParams {
+        loop {
+            let request = rx.recv().await.unwrap();
+            if request.method() == "textDocument/publishDiagnostics" {
+                return params(&request);
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn disable_clears_diagnostics() {
+        let (mut service, uri, mut rx) = start("disable_clears_diagnostics", json!({})).await;
+        let published = next_published(&mut rx).await;
+        assert_eq!(published.uri, uri);
+        assert_eq!(published.diagnostics.len(), 1);
+
+        let disable = json!({ "settings": { "run": "onType", "enable": false } });
+        service.call(notification("workspace/didChangeConfiguration", disable)).await.unwrap();
+        let published = next_published(&mut rx).await;
+        assert_eq!(published.uri, uri);
+        assert!(published.diagnostics.is_empty());
+
+        // enabling again re-lints the open document
+        let enable = json!({ "settings": { "run": "onType", "enable": true } });
+        service.call(notification("workspace/didChangeConfiguration", enable)).await.unwrap();
+        let published = next_published(&mut rx).await;
+        assert_eq!(published.diagnostics.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn pull_diagnostics() {
+        let capabilities = json!({ "textDocument": { "diagnostic": {} } });
+        let (mut service, uri, mut rx) = start("pull_diagnostics", capabilities).await;
+        let mut pull = |previous_result_id: Option<String>| {
+            let request = Request::build("textDocument/diagnostic")
+                .params(json!({ "textDocument": { "uri": uri }, "previousResultId": previous_result_id }))
+                .id(2)
+                .finish();
+            let response = service.call(request);
+            async move {
+                let (_, result) = response.await.unwrap().unwrap().into_parts();
+                serde_json::from_value::<DocumentDiagnosticReport>(result.unwrap()).unwrap()
+            }
+        };
+
+        let DocumentDiagnosticReport::Full(report) = pull(None).await else {
+            panic!("expected a full report");
+        };
+        let report = report.full_document_diagnostic_report;
+        assert_eq!(report.items.len(), 1);
+        let result_id = report.result_id.unwrap();
+
+        // unchanged content answers without linting again
+        let DocumentDiagnosticReport::Unchanged(report) = pull(Some(result_id.clone())).await
+        else {
+            panic!("expected an unchanged report");
+        };
+        assert_eq!(report.unchanged_document_diagnostic_report.result_id, result_id);
+
+        // a stale result id gets a full report
+        let DocumentDiagnosticReport::Full(report) = pull(Some("stale".into())).await else {
+            panic!("expected a full report");
+        };
+        assert_eq!(report.full_document_diagnostic_report.result_id, Some(result_id));
+
+        // diagnostics are not published to clients pulling them
+        while let Ok(request) = rx.try_recv() {
+            assert_ne!(request.method(), "textDocument/publishDiagnostics");
+        }
+    }
+
+    #[tokio::test]
+    async fn changes_are_pulled_when_run_on_save() {
+        let root = workspace("changes_are_pulled_when_run_on_save", &[]);
+        let uri = Url::from_file_path(root.join("test.js")).unwrap();
+        let init_params = json!({
+            "rootUri": Url::from_file_path(&root).unwrap(),
+            "capabilities": { "textDocument": { "diagnostic": {} } },
+            "initializationOptions": { "settings": { "run": "onSave", "enable": true } },
+        });
+        let (mut service, _rx) = initialize_with(init_params).await;
+        open(&mut service, &uri, "debugger;").await;
+
+        // the changed document is pulled even though it is only linted on save
+        let text_document = json!({ "uri": uri, "version": 2 });
+        let content_changes = json!([{ "text": "let a = 1;" }]);
+        let did_change = notification(
+            "textDocument/didChange",
+            json!({ "textDocument": text_document, "contentChanges": content_changes }),
+        );
+        service.call(did_change).await.unwrap();
+        let request = Request::build("textDocument/diagnostic")
+            .params(json!({ "textDocument": { "uri": uri } }))
+            .id(2)
+            .finish();
+        let (_, result) = service.call(request).await.unwrap().unwrap().into_parts();
+        let DocumentDiagnosticReport::Full(report) =
+            serde_json::from_value::<DocumentDiagnosticReport>(result.unwrap()).unwrap()
+        else {
+            panic!("expected a full report");
+        };
+        assert!(report.full_document_diagnostic_report.items.is_empty());
+    }
+
+    /// Collect the messages sent to the client until the workspace lint progress ended.
+    async fn workspace_lint_messages(rx: &mut mpsc::UnboundedReceiver<Request>) -> Vec<Request> {
+        let mut messages = vec![];
+        loop {
+            let request = rx.recv().await.unwrap();
+            let end = request.method() == "$/progress"
+                && matches!(
+                    params::<ProgressParams>(&request).value,
+                    ProgressParamsValue::WorkDone(WorkDoneProgress::End(_))
+                );
+            messages.push(request);
+            if end {
+                return messages;
+            }
+        }
+    }
+
+    fn progress(messages: &[Request]) -> Vec<WorkDoneProgress> {
+        messages
+            .iter()
+            .filter(|request| request.method() == "$/progress")
+            .map(|request| {
+                let ProgressParamsValue::WorkDone(value) = params::<ProgressParams>(request).value;
+                value
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn workspace_lint_reports_progress() {
+        let files = [("a.js", "debugger;"), ("b.js", "let a = 1;")];
+        let root = workspace("workspace_lint_reports_progress", &files);
+        let capabilities = json!({ "window": { "workDoneProgress": true } });
+        let (_service, mut rx) = initialize(&root, capabilities).await;
+        let messages = workspace_lint_messages(&mut rx).await;
+
+        assert_eq!(messages[0].method(), "window/workDoneProgress/create");
+        let progress = progress(&messages);
+        let Some(WorkDoneProgress::Begin(begin)) = progress.first() else {
+            panic!("expected the progress to begin");
+        };
+        assert_eq!(begin.message.as_deref(), Some("Linting 2 files"));
+        let Some(WorkDoneProgress::Report(report)) = progress.iter().nth_back(1) else {
+            panic!("expected a progress report");
+        };
+        assert_eq!(report.percentage, Some(100));
+    }
+
+    #[tokio::test]
+    async fn workspace_diagnostics_are_published_as_completed() {
+        let files = [("a.js", "debugger;"), ("b.js", "let a = 1;")];
+        let root = workspace("workspace_diagnostics_are_published_as_completed", &files);
+        let capabilities = json!({ "window": { "workDoneProgress": true } });
+        let (_service, mut rx) = initialize(&root, capabilities).await;
+        let messages = workspace_lint_messages(&mut rx).await;
+
+        let publishes = messages
+            .iter()
+            .enumerate()
+            .filter(|(_, request)| request.method() == "textDocument/publishDiagnostics")
+            .map(|(i, request)| (i, params::<PublishDiagnosticsParams>(request)))
+            .collect::<Vec<_>>();
+        let diagnostics = |file: &str| {
+            let uri = Url::from_file_path(root.join(file)).unwrap();
+            publishes.iter().find(|(_, p)| p.uri == uri).map(|(_, p)| p.diagnostics.len())
+        };
+        assert_eq!(diagnostics("a.js"), Some(1));
+        // files without diagnostics are published to clear stale diagnostics
+        assert_eq!(diagnostics("b.js"), Some(0));
+
+        // each file is published before the progress of the next file is reported
+        let first_report = messages
+            .iter()
+            .position(|request| {
+                request.method() == "$/progress"
+                    && matches!(
+                        params::<ProgressParams>(request).value,
+                        ProgressParamsValue::WorkDone(WorkDoneProgress::Report(_))
+                    )
+            })
+            .unwrap();
+        assert!(publishes[0].0 < first_report);
+        assert!(first_report < publishes[1].0);
+    }
+
+    #[tokio::test]
+    async fn removed_files_are_cleared() {
+        let root = workspace("removed_files_are_cleared", &[("a.js", "debugger;")]);
+        let capabilities =
+            json!({ "workspace": { "didChangeWatchedFiles": { "dynamicRegistration": true } } });
+        let (mut service, mut rx) = initialize(&root, capabilities).await;
+        let register = rx.recv().await.unwrap();
+        assert_eq!(register.method(), "client/registerCapability");
+        let a = Url::from_file_path(root.join("a.js")).unwrap();
+        let b = Url::from_file_path(root.join("b.js")).unwrap();
+        let published = next_published(&mut rx).await;
+        assert_eq!(published.uri, a);
+        assert_eq!(published.diagnostics.len(), 1);
+
+        // renaming clears the old and lints the new file
+        fs::rename(root.join("a.js"), root.join("b.js")).unwrap();
+        let changes = json!({ "changes": [{ "uri": a, "type": 3 }, { "uri": b, "type": 1 }] });
+        service.call(notification("workspace/didChangeWatchedFiles", changes)).await.unwrap();
+        let published = next_published(&mut rx).await;
+        assert_eq!(published.uri, a);
+        assert!(published.diagnostics.is_empty());
+        let published = next_published(&mut rx).await;
+        assert_eq!(published.uri, b);
+        assert_eq!(published.diagnostics.len(), 1);
+
+        fs::remove_file(root.join("b.js")).unwrap();
+        let changes = json!({ "changes": [{ "uri": b, "type": 3 }] });
+        service.call(notification("workspace/didChangeWatchedFiles", changes)).await.unwrap();
+        let published = next_published(&mut rx).await;
+        assert_eq!(published.uri, b);
+        assert!(published.diagnostics.is_empty());
+    }
+
+    #[tokio::test]
+    async fn plugins_are_reloaded_on_change() {
+        let root = workspace("plugins_are_reloaded_on_change", &[]);
+        let (mut service, mut rx) = initialize(&root, json!({})).await;
+        let uri = Url::from_file_path(root.join("test.js")).unwrap();
+        open(&mut service, &uri, "foo.trimLeft();").await;
+        assert!(next_published(&mut rx).await.diagnostics.is_empty());
+
+        let plugin_dir = root.join(".oxc/plugins");
+        fs::create_dir_all(&plugin_dir).unwrap();
+        let query = Path::new(env!("CARGO_MANIFEST_DIR")).join(
+            "../oxc_linter_plugin/examples/queries/unicorn/prefer-string-trim-start-end/rule.yml",
+        );
+        fs::copy(query, plugin_dir.join("rule.yml")).unwrap();
+        let rule_uri = Url::from_file_path(plugin_dir.join("rule.yml")).unwrap();
+        let changes = json!({ "changes": [{ "uri": rule_uri, "type": 1 }] });
+        service.call(notification("workspace/didChangeWatchedFiles", changes)).await.unwrap();
+        let published = next_published(&mut rx).await;
+        assert_eq!(published.uri, uri);
+        assert_eq!(published.diagnostics.len(), 1);
+
+        // invalid queries are reported instead of taking the server down
+        fs::write(plugin_dir.join("rule.yml"), "query: [").unwrap();
+        let changes = json!({ "changes": [{ "uri": rule_uri, "type": 2 }] });
+        service.call(notification("workspace/didChangeWatchedFiles", changes)).await.unwrap();
+        let message = loop {
+            let request = rx.recv().await.unwrap();
+            if request.method() == "window/showMessage" {
+                break params::<ShowMessageParams>(&request);
+            }
+        };
+        assert_eq!(message.typ, MessageType::ERROR);
+        assert!(message.message.starts_with("Failed to load oxc plugins"));
+        assert_eq!(next_published(&mut rx).await.diagnostics.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn ignored_files() {
+        let files = [
+            (".gitignore", "dist/\n"),
+            (".eslintignore", "coverage/\n"),
+            ("src/.gitignore", "generated.js\n"),
+            ("src/a.js", "debugger;"),
+            ("src/generated.js", "debugger;"),
+            ("generated.js", "debugger;"),
+            ("dist/b.js", "debugger;"),
+            ("coverage/c.js", "debugger;"),
+            ("build/d.js", "debugger;"),
+        ];
+        let root = workspace("ignored_files", &files);
+        let uri = |file: &str| Url::from_file_path(root.join(file)).unwrap();
+        let init_params = json!({
+            "rootUri": uri(""),
+            "capabilities": { "window": { "workDoneProgress": true } },
+            "initializationOptions": {
+                "settings": { "run": "onType", "enable": true, "ignorePatterns": ["build/"] }
+            },
+        });
+        let (mut service, mut rx) = initialize_with(init_params).await;
+        let mut published = workspace_lint_messages(&mut rx)
+            .await
+            .iter()
+            .filter(|request| request.method() == "textDocument/publishDiagnostics")
+            .map(|request| params::<PublishDiagnosticsParams>(request).uri)
+            .collect::<Vec<_>>();
+        published.sort_unstable();
+        // the nested `.gitignore` only applies to `src`
+        assert_eq!(published, vec![uri("generated.js"), uri("src/a.js")]);
+
+        // open documents which are ignored are cleared instead of being linted
+        for file in ["dist/b.js", "coverage/c.js", "build/d.js", "src/generated.js"] {
+            open(&mut service, &uri(file), "debugger;").await;
+            let published = next_published(&mut rx).await;
+            assert_eq!(published.uri, uri(file));
+            assert!(published.diagnostics.is_empty());
+        }
+        open(&mut service, &uri("src/a.js"), "debugger;").await;
+        assert_eq!(next_published(&mut rx).await.diagnostics.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn workspace_folders() {
+        let first = workspace("workspace_folders_first", &[]);
+        let config = r#"{ "rules": { "no-debugger": "off", "eqeqeq": "error" } }"#;
+        let files = [(".oxlintrc.json", config), (".gitignore", "ignored.js")];
+        let second = workspace("workspace_folders_second", &files);
+        let folder = |root: &Path| json!({ "uri": Url::from_file_path(root).unwrap(), "name": "" });
+        let params = json!({
+            "rootUri": Url::from_file_path(&first).unwrap(),
+            "workspaceFolders": [folder(&first), folder(&second)],
+            "capabilities": {},
+        });
+        let (mut service, mut rx) = initialize_with(params).await;
+        let source_text = "debugger;\nif (a == b) {}\n";
+        // the document of the second folder is linted with its configuration
+        let uri = Url::from_file_path(second.join("test.js")).unwrap();
+        open(&mut service, &uri, source_text).await;
+        let published = next_published(&mut rx).await;
+        assert_eq!(published.uri, uri);
+        assert_eq!(published.diagnostics.len(), 1);
+        assert!(published.diagnostics[0].message.contains("eqeqeq"));
+
+        let uri = Url::from_file_path(first.join("test.js")).unwrap();
+        open(&mut service, &uri, source_text).await;
+        let published = next_published(&mut rx).await;
+        assert_eq!(published.uri, uri);
+        assert_eq!(published.diagnostics.len(), 1);
+        assert!(published.diagnostics[0].message.contains("debugger"));
+
+        // ignore files apply to their own folder only
+        let uri = Url::from_file_path(second.join("ignored.js")).unwrap();
+        open(&mut service, &uri, source_text).await;
+        let published = next_published(&mut rx).await;
+        assert_eq!(published.uri, uri);
+        assert!(published.diagnostics.is_empty());
+        let uri = Url::from_file_path(first.join("ignored.js")).unwrap();
+        open(&mut service, &uri, source_text).await;
+        let published = next_published(&mut rx).await;
+        assert_eq!(published.uri, uri);
+        assert_eq!(published.diagnostics.len(), 1);
+
+        // added folders are linted, removed folders are cleared
+        let third = workspace("workspace_folders_third", &[("a.js", "debugger;")]);
+        let added = json!({ "event": { "added": [folder(&third)], "removed": [] } });
+        service.call(notification("workspace/didChangeWorkspaceFolders", added)).await.unwrap();
+        let uri = Url::from_file_path(third.join("a.js")).unwrap();
+        let published = next_published(&mut rx).await;
+        assert_eq!(published.uri, uri);
+        assert_eq!(published.diagnostics.len(), 1);
+        let removed = json!({ "event": { "added": [], "removed": [folder(&third)] } });
+        service.call(notification("workspace/didChangeWorkspaceFolders", removed)).await.unwrap();
+        let published = next_published(&mut rx).await;
+        assert_eq!(published.uri, uri);
+        assert!(published.diagnostics.is_empty());
+    }
+
+    #[tokio::test]
+    async fn untitled_documents() {
+        let root = workspace("untitled_documents", &[]);
+        let (mut service, mut rx) = initialize(&root, json!({})).await;
+        let uri = Url::parse("untitled:Untitled-1").unwrap();
+        let text = "const a: JSX.Element = <div />;\ndebugger;";
+        let text_document =
+            json!({ "uri": uri, "languageId": "typescriptreact", "version": 1, "text": text });
+        let did_open =
+            notification("textDocument/didOpen", json!({ "textDocument": text_document }));
+        service.call(did_open).await.unwrap();
+        let published = next_published(&mut rx).await;
+        assert_eq!(published.uri, uri);
+        assert_eq!(published.diagnostics.len(), 1);
+
+        // fixes edit the untitled document
+        let range = published.diagnostics[0].range;
+        let request = Request::build("textDocument/codeAction")
+            .params(json!({ "textDocument": { "uri": uri }, "range": range, "context": { "diagnostics": [] } }))
+            .id(2)
+            .finish();
+        let (_, result) = service.call(request).await.unwrap().unwrap().into_parts();
+        let actions = serde_json::from_value::<Vec<CodeAction>>(result.unwrap()).unwrap();
+        assert!(!actions.is_empty());
+        for action in actions {
+            let changes = action.edit.unwrap().changes.unwrap();
+            assert_eq!(changes.keys().collect::<Vec<_>>(), vec![&uri]);
+        }
+    }
+
+    #[tokio::test]
+    async fn source_fix_all() {
+        let (mut service, uri, mut rx) = start("source_fix_all", json!({})).await;
+        next_published(&mut rx).await;
+        let mut code_actions = |only: serde_json::Value| {
+            let range = json!({ "start": { "line": 0, "character": 0 }, "end": { "line": 0, "character": 0 } });
+            let request = Request::build("textDocument/codeAction")
+                .params(json!({ "textDocument": { "uri": uri }, "range": range, "context": { "diagnostics": [], "only": only } }))
+                .id(2)
+                .finish();
+            let response = service.call(request);
+            async move {
+                let (_, result) = response.await.unwrap().unwrap().into_parts();
+                serde_json::from_value::<Vec<CodeAction>>(result.unwrap()).unwrap()
+            }
+        };
+
+        let actions = code_actions(json!(["source.fixAll"])).await;
+        assert_eq!(actions.len(), 1);
+        assert_eq!(actions[0].kind.as_ref().map(CodeActionKind::as_str), Some("source.fixAll.oxc"));
+        let actions = code_actions(json!(["quickfix"])).await;
+        assert!(actions.iter().all(|action| action.kind == Some(CodeActionKind::QUICKFIX)));
+        let actions = code_actions(json!(null)).await;
+        assert!(actions.iter().any(|action| action.kind == Some(CodeActionKind::QUICKFIX)));
+        assert!(actions
+            .iter()
+            .any(|action| action.kind.as_ref().map(CodeActionKind::as_str)
+                == Some("source.fixAll.oxc")));
+    }
+
+    #[test]
+    fn requested_code_action_kinds() {
+        let fix_all = SOURCE_FIX_ALL_OXC;
+        assert!(is_requested(None, &fix_all));
+        assert!(is_requested(Some(&[CodeActionKind::SOURCE]), &fix_all));
+        assert!(is_requested(Some(&[CodeActionKind::SOURCE_FIX_ALL]), &fix_all));
+        assert!(is_requested(Some(&[fix_all.clone()]), &fix_all));
+        assert!(!is_requested(Some(&[CodeActionKind::QUICKFIX]), &fix_all));
+        assert!(!is_requested(Some(&[CodeActionKind::new("source.fix")]), &fix_all));
+    }
+
+    #[test]
+    fn result_id_changes_with_content_and_configuration() {
+        assert_eq!(result_id("debugger;", 0), result_id("debugger;", 0));
+        assert_ne!(result_id("debugger;", 0), result_id("debugger;;", 0));
+        assert_ne!(result_id("debugger;", 0), result_id("debugger;", 1));
+    }
+}
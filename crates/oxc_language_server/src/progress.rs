@@ -0,0 +1,90 @@
+use tower_lsp::{
+    lsp_types::{
+        notification::Progress, request::WorkDoneProgressCreate, NumberOrString, ProgressParams,
+        ProgressParamsValue, WorkDoneProgress, WorkDoneProgressBegin, WorkDoneProgressCreateParams,
+        WorkDoneProgressEnd, WorkDoneProgressReport,
+    },
+    Client,
+};
+
+/// [Server initiated progress](https://microsoft.github.io/language-server-protocol/specifications/lsp/3.17/specification/#serverInitiatedProgress)
+/// of work on a known number of items.
+pub struct ProgressReporter<'a> {
+    client: &'a Client,
+    token: NumberOrString,
+    total: usize,
+    percentage: u32,
+}
+
+impl<'a> ProgressReporter<'a> {
+    /// Returns `None` if the client failed to create the progress.
+    pub async fn begin(
+        client: &'a Client,
+        token: &str,
+        title: &str,
+        message: String,
+        total: usize,
+    ) -> Option<ProgressReporter<'a>> {
+        let token = NumberOrString::String(token.to_string());
+        let params = WorkDoneProgressCreateParams { token: token.clone() };
+        client.send_request::<WorkDoneProgressCreate>(params).await.ok()?;
+        let reporter = Self { client, token, total, percentage: 0 };
+        reporter
+            .notify(WorkDoneProgress::Begin(WorkDoneProgressBegin {
+                title: title.to_string(),
+                cancellable: Some(false),
+                message: Some(message),
+                percentage: Some(0),
+            }))
+            .await;
+        Some(reporter)
+    }
+
+    /// Report that `processed` items are done, only sent when the percentage changed.
+    pub async fn report(&mut self, processed: usize) {
+        let percentage = percentage(processed, self.total);
+        if percentage == self.percentage {
+            return;
+        }
+        self.percentage = percentage;
+        self.notify(WorkDoneProgress::Report(WorkDoneProgressReport {
+            cancellable: None,
+            message: Some(format!("{processed}/{}", self.total)),
+            percentage: Some(percentage),
+        }))
+        .await;
+    }
+
+    pub async fn end(self, message: Option<String>) {
+        self.notify(WorkDoneProgress::End(WorkDoneProgressEnd { message })).await;
+    }
+
+    async fn notify(&self, value: WorkDoneProgress) {
+        let params = ProgressParams {
+            token: self.token.clone(),
+            value: ProgressParamsValue::WorkDone(value),
+        };
+        self.client.send_notification::<Progress>(params).await;
+    }
+}
+
+#[allow(clippy::cast_possible_truncation)]
+fn percentage(processed: usize, total: usize) -> u32 {
+    if total == 0 {
+        return 100;
+    }
+    (processed.min(total) * 100 / total) as u32
+}
+
+#[cfg(test)]
+mod test {
+    use super::percentage;
+
+    #[test]
+    fn percentages() {
+        assert_eq!(percentage(0, 3), 0);
+        assert_eq!(percentage(1, 3), 33);
+        assert_eq!(percentage(3, 3), 100);
+        assert_eq!(percentage(0, 0), 100);
+    }
+}
@@ -1,6 +1,11 @@
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
-use ignore::{overrides::OverrideBuilder, DirEntry, WalkBuilder};
+use ignore::{
+    gitignore::{Gitignore, GitignoreBuilder},
+    overrides::OverrideBuilder,
+    DirEntry, Match, WalkBuilder,
+};
+use log::warn;
 use oxc_span::VALID_EXTENSIONS;
 
 use crate::options::LintOptions;
@@ -13,38 +18,49 @@ impl Default for Extensions {
 }
 
 pub struct Walk {
-    inner: ignore::Walk,
+    /// `None` without any paths to walk
+    inner: Option<ignore::Walk>,
     extensions: Extensions,
 }
 
 impl Walk {
-    /// # Panics
     pub fn new(options: &LintOptions) -> Self {
-        let mut inner = WalkBuilder::new(&options.paths[0]);
+        let Some((first_path, paths)) = options.paths.split_first() else {
+            return Self { inner: None, extensions: Extensions::default() };
+        };
+        let mut inner = WalkBuilder::new(first_path);
 
-        if let Some(paths) = options.paths.get(1..) {
-            for path in paths {
-                inner.add(path);
-            }
+        for path in paths {
+            inner.add(path);
         }
 
         if !options.no_ignore {
             inner.add_custom_ignore_filename(&options.ignore_path);
 
             if !options.ignore_pattern.is_empty() {
-                let mut override_builder = OverrideBuilder::new(Path::new("/"));
+                let mut override_builder = OverrideBuilder::new(first_path);
                 for pattern in &options.ignore_pattern {
-                    override_builder.add(pattern).unwrap();
+                    // Overrides are a whitelist, negated globs ignore the matching files.
+                    if let Err(err) = override_builder.add(&format!("!{pattern}")) {
+                        warn!("Invalid ignore pattern {pattern:?}: {err}");
+                    }
+                }
+                match override_builder.build() {
+                    Ok(r#override) => {
+                        inner.overrides(r#override);
+                    }
+                    Err(err) => warn!("Failed to build the ignore patterns: {err}"),
                 }
-                let r#override = override_builder.build().unwrap();
-                inner.overrides(r#override);
             }
         }
         // Turning off `follow_links` because:
         // * following symlinks is a really slow syscall
         // * it is super rare to have symlinked source code
-        let inner = inner.ignore(false).git_global(false).follow_links(false).build();
-        Self { inner, extensions: Extensions::default() }
+        // `.gitignore` files are honored in their own directory and below, also outside of a git
+        // repository.
+        let inner =
+            inner.ignore(false).git_global(false).require_git(false).follow_links(false).build();
+        Self { inner: Some(inner), extensions: Extensions::default() }
     }
 
     pub fn with_extensions(mut self, extensions: Extensions) -> Self {
@@ -54,7 +70,7 @@ impl Walk {
 
     pub fn iter(self) -> impl Iterator<Item = Box<Path>> {
         let extensions = self.extensions;
-        self.inner.filter_map(Result::ok).filter_map(move |dir_entry| {
+        self.inner.into_iter().flatten().filter_map(Result::ok).filter_map(move |dir_entry| {
             if Self::is_wanted_entry(&dir_entry, &extensions) {
                 Some(dir_entry.path().to_path_buf().into_boxed_path())
             } else {
@@ -76,3 +92,170 @@ impl Walk {
         extensions.0.contains(&extension.to_string_lossy().as_ref())
     }
 }
+
+/// Matches single files against the same ignore files and patterns as [`Walk`].
+#[derive(Debug)]
+pub struct IgnoreMatcher {
+    root: PathBuf,
+    /// The `.gitignore` and then the custom ignore files below `root`, each from the outermost
+    /// directory to the innermost ones.
+    ignore_files: Vec<Gitignore>,
+    /// Extra patterns, relative to `root`
+    patterns: Gitignore,
+}
+
+impl IgnoreMatcher {
+    pub fn new(options: &LintOptions) -> Self {
+        let Some(root) = options.paths.first().cloned() else {
+            return Self {
+                root: PathBuf::new(),
+                ignore_files: vec![],
+                patterns: Gitignore::empty(),
+            };
+        };
+        let mut ignore_files = vec![];
+        if !options.no_ignore {
+            let ignore_filename = options.ignore_path.as_os_str();
+            let walk = WalkBuilder::new(&root)
+                .add_custom_ignore_filename(ignore_filename)
+                .hidden(false)
+                .ignore(false)
+                .git_global(false)
+                .require_git(false)
+                .follow_links(false)
+                .build();
+            for entry in walk.flatten() {
+                let file_name = entry.file_name();
+                if file_name == ".gitignore" || file_name == ignore_filename {
+                    let (gitignore, _) = Gitignore::new(entry.path());
+                    ignore_files.push((file_name == ignore_filename, gitignore));
+                }
+            }
+            // Same as the walk, custom ignore files take precedence over `.gitignore` files.
+            ignore_files.sort_by_key(|(is_custom, gitignore)| {
+                (*is_custom, gitignore.path().components().count())
+            });
+        }
+        let ignore_files = ignore_files.into_iter().map(|(_, gitignore)| gitignore).collect();
+        let mut builder = GitignoreBuilder::new(&root);
+        if !options.no_ignore {
+            for pattern in &options.ignore_pattern {
+                if let Err(err) = builder.add_line(None, pattern) {
+                    warn!("Invalid ignore pattern {pattern:?}: {err}");
+                }
+            }
+        }
+        let patterns = builder.build().unwrap_or_else(|_| Gitignore::empty());
+        Self { root, ignore_files, patterns }
+    }
+
+    /// Whether the file of `path` is ignored, paths outside of the root never are.
+    pub fn is_ignored(&self, path: &Path) -> bool {
+        if !path.starts_with(&self.root) {
+            return false;
+        }
+        if self.patterns.matched_path_or_any_parents(path, false).is_ignore() {
+            return true;
+        }
+        // The first ignore file with a matching pattern decides.
+        for gitignore in self.ignore_files.iter().rev() {
+            if !path.starts_with(gitignore.path()) {
+                continue;
+            }
+            match gitignore.matched_path_or_any_parents(path, false) {
+                Match::None => continue,
+                matched => return matched.is_ignore(),
+            }
+        }
+        false
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{
+        fs,
+        path::{Path, PathBuf},
+    };
+
+    use super::{IgnoreMatcher, Walk};
+    use crate::options::LintOptions;
+
+    #[test]
+    fn walk_and_matcher_agree() {
+        let root = std::env::temp_dir().join("oxc_language_server_walk_ignore");
+        let _ = fs::remove_dir_all(&root);
+        let files = [
+            (".gitignore", "dist/\n"),
+            (".eslintignore", "*.generated.js\n"),
+            ("src/.gitignore", "fixtures/\n"),
+            ("src/.eslintignore", "!keep.generated.js\n"),
+            ("src/a.js", ""),
+            ("src/a.generated.js", ""),
+            ("src/keep.generated.js", ""),
+            ("src/fixtures/b.js", ""),
+            ("fixtures/c.js", ""),
+            ("dist/d.js", ""),
+            ("build/e.js", ""),
+            ("node_modules/f.js", ""),
+        ];
+        for (file, content) in files {
+            let path = root.join(file);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, content).unwrap();
+        }
+        let options = LintOptions {
+            paths: vec![root.clone()],
+            ignore_path: ".eslintignore".into(),
+            ignore_pattern: vec!["node_modules".into(), "build/".into()],
+            ..LintOptions::default()
+        };
+
+        let mut walked = Walk::new(&options)
+            .iter()
+            .map(|path| path.strip_prefix(&root).unwrap().to_path_buf())
+            .collect::<Vec<_>>();
+        walked.sort_unstable();
+        let expected = ["fixtures/c.js", "src/a.js", "src/keep.generated.js"];
+        assert_eq!(walked, expected.iter().map(PathBuf::from).collect::<Vec<_>>());
+
+        let matcher = IgnoreMatcher::new(&options);
+        for (file, _) in files
+            .iter()
+            .filter(|(file, _)| Path::new(file).extension().is_some_and(|ext| ext == "js"))
+        {
+            let ignored = !expected.contains(file);
+            assert_eq!(matcher.is_ignored(&root.join(file)), ignored, "{file}");
+        }
+        // paths outside of the root are never ignored
+        assert!(!matcher.is_ignored(&std::env::temp_dir().join("dist/d.js")));
+    }
+
+    #[test]
+    fn without_paths() {
+        let options = LintOptions {
+            paths: vec![],
+            ignore_pattern: vec!["[".into()],
+            ..LintOptions::default()
+        };
+        assert_eq!(Walk::new(&options).iter().count(), 0);
+        assert!(!IgnoreMatcher::new(&options).is_ignored(Path::new("/dist/d.js")));
+    }
+
+    #[test]
+    fn invalid_ignore_pattern() {
+        let root = std::env::temp_dir().join("oxc_language_server_walk_invalid_pattern");
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("build")).unwrap();
+        fs::write(root.join("a.js"), "").unwrap();
+        fs::write(root.join("build/b.js"), "").unwrap();
+        let options = LintOptions {
+            paths: vec![root.clone()],
+            ignore_pattern: vec!["[".into(), "build/".into()],
+            ..LintOptions::default()
+        };
+        // The invalid pattern is skipped, the others still apply
+        let walked = Walk::new(&options).iter().collect::<Vec<_>>();
+        assert_eq!(walked, vec![root.join("a.js").into_boxed_path()]);
+    }
+}
@@ -0,0 +1,109 @@
+use std::{
+    path::{Path, PathBuf},
+    sync::{Arc, RwLock},
+};
+
+use tower_lsp::lsp_types::Url;
+
+use crate::linter::{root_path, ServerLinter};
+
+/// A workspace folder, linted with its own configuration, plugins and ignore files.
+#[derive(Debug)]
+pub struct WorkspaceFolder {
+    pub root_uri: Url,
+    root: PathBuf,
+    pub server_linter: ServerLinter,
+}
+
+impl WorkspaceFolder {
+    pub fn new(root_uri: Url) -> Self {
+        let root = root_path(&root_uri);
+        Self { root_uri, root, server_linter: ServerLinter::new() }
+    }
+
+    pub fn contains(&self, path: &Path) -> bool {
+        path.starts_with(&self.root)
+    }
+
+    /// Whether `path` is ignored by an ignore file or pattern of this folder.
+    pub fn is_ignored(&self, path: &Path) -> bool {
+        self.contains(path) && self.server_linter.is_ignored(path)
+    }
+}
+
+/// The workspace folders of the client, added and removed at runtime.
+#[derive(Debug, Default)]
+pub struct WorkspaceFolders {
+    folders: RwLock<Vec<Arc<WorkspaceFolder>>>,
+}
+
+impl WorkspaceFolders {
+    /// Add the folder of `root_uri`, replacing a previous folder with the same root.
+    pub fn add(&self, root_uri: Url) -> Arc<WorkspaceFolder> {
+        let folder = Arc::new(WorkspaceFolder::new(root_uri));
+        let mut folders = self.folders.write().unwrap();
+        folders.retain(|f| f.root_uri != folder.root_uri);
+        folders.push(Arc::clone(&folder));
+        folder
+    }
+
+    pub fn remove(&self, root_uri: &Url) -> Option<Arc<WorkspaceFolder>> {
+        let mut folders = self.folders.write().unwrap();
+        let index = folders.iter().position(|f| &f.root_uri == root_uri)?;
+        Some(folders.remove(index))
+    }
+
+    pub fn all(&self) -> Vec<Arc<WorkspaceFolder>> {
+        self.folders.read().unwrap().clone()
+    }
+
+    /// The folder containing `uri`, the innermost one of nested folders.
+    pub fn find(&self, uri: &Url) -> Option<Arc<WorkspaceFolder>> {
+        let path = uri.to_file_path().ok()?;
+        self.folders
+            .read()
+            .unwrap()
+            .iter()
+            .filter(|folder| folder.contains(&path))
+            .max_by_key(|folder| folder.root.components().count())
+            .cloned()
+    }
+
+    /// Same as [`WorkspaceFolders::find`], files outside of all folders belong to the first folder.
+    pub fn find_or_first(&self, uri: &Url) -> Option<Arc<WorkspaceFolder>> {
+        self.find(uri).or_else(|| self.folders.read().unwrap().first().cloned())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::Path;
+
+    use tower_lsp::lsp_types::Url;
+
+    use super::WorkspaceFolders;
+
+    #[test]
+    fn find_innermost_folder() {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+        let uri = |path: &Path| Url::from_file_path(path).unwrap();
+        let folders = WorkspaceFolders::default();
+        folders.add(uri(root));
+        folders.add(uri(&root.join("fixtures")));
+
+        let find = |path: &Path| folders.find(&uri(path)).map(|folder| folder.root_uri.clone());
+        assert_eq!(find(&root.join("test.js")), Some(uri(root)));
+        assert_eq!(find(&root.join("fixtures/test.js")), Some(uri(&root.join("fixtures"))));
+        // a common prefix of the names is not enough
+        assert_eq!(find(&root.join("fixtures2/test.js")), Some(uri(root)));
+        let outside = root.parent().unwrap().join("test.js");
+        assert_eq!(find(&outside), None);
+        assert_eq!(
+            folders.find_or_first(&uri(&outside)).map(|f| f.root_uri.clone()),
+            Some(uri(root))
+        );
+
+        folders.remove(&uri(&root.join("fixtures")));
+        assert_eq!(find(&root.join("fixtures/test.js")), Some(uri(root)));
+    }
+}
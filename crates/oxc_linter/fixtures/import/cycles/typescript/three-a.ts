@@ -0,0 +1,3 @@
+import './three-b';
+
+export const a = 1;
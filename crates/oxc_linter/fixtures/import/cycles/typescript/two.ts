@@ -0,0 +1,3 @@
+import { index } from './index';
+
+export const two = index + 2;
@@ -0,0 +1,256 @@
+use std::{
+    fs,
+    hash::{Hash, Hasher},
+    io,
+    path::{Path, PathBuf},
+};
+
+use dashmap::DashMap;
+use oxc_diagnostics::{miette::MietteDiagnostic, Error};
+use oxc_span::Span;
+use rustc_hash::FxHasher;
+use serde::{Deserialize, Serialize};
+
+use crate::{Fix, FixKind, Linter, Message, RULES};
+
+/// Persistent cache of the messages reported for each file.
+///
+/// An entry is only used when the file content is unchanged,
+/// and the whole cache is discarded when the crate version, enabled rules or settings change.
+#[derive(Debug)]
+pub struct LintCache {
+    /// Location of the cache file
+    path: PathBuf,
+    fingerprint: u64,
+    entries: DashMap<PathBuf, CacheEntry>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct CacheFile {
+    fingerprint: u64,
+    entries: Vec<(PathBuf, CacheEntry)>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CacheEntry {
+    /// Hash of the file content
+    hash: u64,
+    messages: Vec<CachedMessage>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CachedMessage {
+    diagnostic: MietteDiagnostic,
+    fix: Option<CachedFix>,
+    suggestions: Vec<CachedFix>,
+    fixable: bool,
+    rule_name: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CachedFix {
+    content: String,
+    start: u32,
+    end: u32,
+    kind: FixKind,
+    message: Option<String>,
+}
+
+impl LintCache {
+    /// Read the cache at `path`, starting empty if it is missing, unreadable or out of date.
+    pub fn load(path: PathBuf, linter: &Linter) -> Self {
+        let fingerprint = fingerprint(linter);
+        let entries = fs::read(&path)
+            .ok()
+            .and_then(|bytes| serde_json::from_slice::<CacheFile>(&bytes).ok())
+            .filter(|file| file.fingerprint == fingerprint)
+            .map_or_else(DashMap::default, |file| file.entries.into_iter().collect());
+        Self { path, fingerprint, entries }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// The cached messages of `path`, if its content has not changed since they were stored.
+    pub fn get(&self, path: &Path, source_text: &str) -> Option<Vec<Message<'static>>> {
+        let entry = self.entries.get(path)?;
+        if entry.hash != hash(source_text) {
+            return None;
+        }
+        Some(entry.messages.iter().cloned().map(CachedMessage::into_message).collect())
+    }
+
+    pub fn insert(&self, path: &Path, source_text: &str, messages: &[Message]) {
+        let messages = messages.iter().map(CachedMessage::new).collect();
+        self.entries.insert(path.to_path_buf(), CacheEntry { hash: hash(source_text), messages });
+    }
+
+    /// # Errors
+    ///
+    /// Returns `Err` if the cache file cannot be written.
+    pub fn save(&self) -> io::Result<()> {
+        let file = CacheFile {
+            fingerprint: self.fingerprint,
+            entries: self
+                .entries
+                .iter()
+                .map(|entry| (entry.key().clone(), entry.value().clone()))
+                .collect(),
+        };
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(&self.path, serde_json::to_vec(&file)?)
+    }
+}
+
+impl CachedMessage {
+    fn new(message: &Message) -> Self {
+        let error = &message.error;
+        let diagnostic = MietteDiagnostic {
+            message: error.to_string(),
+            code: error.code().map(|code| code.to_string()),
+            severity: error.severity(),
+            help: error.help().map(|help| help.to_string()),
+            url: error.url().map(|url| url.to_string()),
+            labels: error.labels().map(Iterator::collect),
+        };
+        Self {
+            diagnostic,
+            fix: message.fix.as_ref().map(CachedFix::new),
+            suggestions: message.suggestions.iter().map(CachedFix::new).collect(),
+            fixable: message.is_fixable(),
+            rule_name: message.rule_name().map(ToString::to_string),
+        }
+    }
+
+    fn into_message(self) -> Message<'static> {
+        let fix = self.fix.map(CachedFix::into_fix);
+        let suggestions = self.suggestions.into_iter().map(CachedFix::into_fix).collect();
+        let message = Message::new(Error::new(self.diagnostic), fix)
+            .with_suggestions(suggestions)
+            .with_fixable(self.fixable);
+        // Rule names are only kept as `&'static str`, so look up the registered rule.
+        match self.rule_name.and_then(|name| RULES.iter().find(|rule| rule.name() == name)) {
+            Some(rule) => message.with_rule_name(rule.name()),
+            None => message,
+        }
+    }
+}
+
+impl CachedFix {
+    fn new(fix: &Fix) -> Self {
+        Self {
+            content: fix.content.to_string(),
+            start: fix.span.start,
+            end: fix.span.end,
+            kind: fix.kind,
+            message: fix.message.as_ref().map(ToString::to_string),
+        }
+    }
+
+    fn into_fix(self) -> Fix<'static> {
+        let fix = Fix::new(self.content, Span::new(self.start, self.end)).with_kind(self.kind);
+        match self.message {
+            Some(message) => fix.with_message(message),
+            None => fix,
+        }
+    }
+}
+
+fn hash(source_text: &str) -> u64 {
+    let mut hasher = FxHasher::default();
+    source_text.hash(&mut hasher);
+    hasher.finish()
+}
+
+fn fingerprint(linter: &Linter) -> u64 {
+    let mut hasher = FxHasher::default();
+    env!("CARGO_PKG_VERSION").hash(&mut hasher);
+    format!("{:?}", linter.rules).hash(&mut hasher);
+    format!("{:?}", linter.categories).hash(&mut hasher);
+    format!("{:?}", linter.overrides).hash(&mut hasher);
+    format!("{:?}", linter.settings).hash(&mut hasher);
+    linter.options.report_unused_directives.hash(&mut hasher);
+    linter.options.fix.hash(&mut hasher);
+    linter.options.fix_kind.hash(&mut hasher);
+    linter.options.report_fixes.hash(&mut hasher);
+    hasher.finish()
+}
+
+#[cfg(test)]
+mod test {
+    use std::{env, fs, path::Path, sync::mpsc};
+
+    use oxc_diagnostics::miette::{miette, LabeledSpan};
+    use oxc_span::Span;
+
+    use super::LintCache;
+    use crate::{rules::RULES, Fix, LintOptions, LintResult, LintService, Linter, Message};
+
+    fn linter(rule_name: &str) -> Linter {
+        let rule = RULES.iter().find(|rule| rule.name() == rule_name).unwrap().clone();
+        Linter::new().with_rules(vec![rule])
+    }
+
+    #[test]
+    fn roundtrip() {
+        let path = env::temp_dir().join("oxc_linter_cache_roundtrip");
+        let _ = std::fs::remove_file(&path);
+        let file = Path::new("src/debugger.js");
+        let source_text = "debugger;";
+
+        let cache = LintCache::load(path.clone(), &linter("no-debugger"));
+        assert!(cache.get(file, source_text).is_none());
+        let error = miette!(labels = vec![LabeledSpan::at(0..9, "")], "eslint(no-debugger): x");
+        let suggestion = Fix::new("", Span::new(0, 9)).with_message("Remove it");
+        let message = Message::new(error, Some(Fix::delete(Span::new(0, 9))))
+            .with_suggestions(vec![suggestion])
+            .with_rule_name("no-debugger");
+        cache.insert(file, source_text, &[message]);
+        cache.save().unwrap();
+
+        let cache = LintCache::load(path.clone(), &linter("no-debugger"));
+        let messages = cache.get(file, source_text).unwrap();
+        assert_eq!(messages.len(), 1);
+        assert_eq!(messages[0].error.to_string(), "eslint(no-debugger): x");
+        assert_eq!(messages[0].rule_name(), Some("no-debugger"));
+        assert_eq!(messages[0].fix.as_ref().unwrap().span, Span::new(0, 9));
+        assert_eq!(messages[0].suggestions[0].message.as_deref(), Some("Remove it"));
+        assert_eq!(messages[0].start(), 0);
+        assert_eq!(messages[0].end(), 9);
+        // changed content
+        assert!(cache.get(file, "debugger;;").is_none());
+
+        // changed rules
+        let cache = LintCache::load(path.clone(), &linter("eqeqeq"));
+        assert!(cache.get(file, source_text).is_none());
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn unchanged_files_are_not_parsed() {
+        let dir = env::temp_dir().join("oxc_linter_cache_service");
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        let file = dir.join("debugger.js");
+        fs::write(&file, "debugger;").unwrap();
+
+        let run = || {
+            let options = LintOptions::default().with_cache_location(Some(dir.join("cache")));
+            let linter = Linter::from_options(options).unwrap();
+            let service = LintService::new(dir.clone().into(), &[file.clone().into()], linter);
+            let (tx_error, _rx_error) = mpsc::channel();
+            service.run(&tx_error);
+            (service.number_of_parsed_files(), service.result())
+        };
+        let expected = LintResult { errors: 0, warnings: 1, fixables: 1 };
+        assert_eq!(run(), (1, expected));
+        assert_eq!(run(), (0, expected));
+
+        fs::write(&file, "debugger;\ndebugger;").unwrap();
+        assert_eq!(run(), (1, LintResult { errors: 0, warnings: 2, fixables: 2 }));
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,39 @@
+use rustc_hash::FxHashMap;
+use serde_json::Value;
+
+/// The `env` section of the configuration, e.g. `{ "browser": true, "node": true }`.
+///
+/// The `builtin` environment is always enabled unless it is turned off explicitly.
+#[derive(Debug, Clone)]
+pub struct ESLintEnv(FxHashMap<String, bool>);
+
+impl Default for ESLintEnv {
+    fn default() -> Self {
+        let mut map = FxHashMap::default();
+        map.insert("builtin".to_string(), true);
+        Self(map)
+    }
+}
+
+impl ESLintEnv {
+    pub fn from_value(value: &Value) -> Self {
+        let mut env = Self::default();
+        if let Value::Object(object) = value {
+            for (name, enabled) in object {
+                if let Some(enabled) = enabled.as_bool() {
+                    env.0.insert(name.clone(), enabled);
+                }
+            }
+        }
+        env
+    }
+
+    pub fn contains(&self, env: &str) -> bool {
+        self.0.get(env).copied().unwrap_or_default()
+    }
+
+    /// Names of the enabled environments.
+    pub fn iter(&self) -> impl Iterator<Item = &str> + '_ {
+        self.0.iter().filter(|(_, enabled)| **enabled).map(|(env, _)| env.as_str())
+    }
+}
@@ -39,3 +39,18 @@ pub struct FailedToParseAllowWarnDenyFromNumberError(pub String);
 #[error(r#"Failed to parse rule severity, expected a string or a number, but got {0:?}"#)]
 #[diagnostic()]
 pub struct FailedToParseAllowWarnDenyFromJsonValueError(pub String);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Unknown rule {0:?} in configuration")]
+#[diagnostic(severity(warning))]
+pub struct UnknownRuleError(pub String);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Unknown category {0:?} in configuration")]
+#[diagnostic(severity(warning))]
+pub struct UnknownCategoryError(pub String);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Failed to parse glob {0:?} in overrides with error {1:?}")]
+#[diagnostic()]
+pub struct FailedToParseGlobError(pub String, pub String);
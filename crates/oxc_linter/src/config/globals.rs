@@ -0,0 +1,57 @@
+use rustc_hash::FxHashMap;
+use serde_json::Value;
+
+/// How a global variable declared in the configuration may be used.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GlobalValue {
+    Readonly,
+    Writeable,
+    /// Disables a global variable, including one provided by an environment.
+    Off,
+}
+
+impl GlobalValue {
+    fn from_value(value: &Value) -> Option<Self> {
+        match value {
+            Value::Bool(true) => Some(Self::Writeable),
+            Value::Bool(false) | Value::Null => Some(Self::Readonly),
+            Value::String(s) => match s.as_str() {
+                "readonly" | "readable" | "false" => Some(Self::Readonly),
+                "writable" | "writeable" | "true" => Some(Self::Writeable),
+                "off" => Some(Self::Off),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    pub fn is_enabled(self) -> bool {
+        self != Self::Off
+    }
+}
+
+/// The `globals` section of the configuration, e.g. `{ "jQuery": "readonly" }`.
+#[derive(Debug, Default, Clone)]
+pub struct ESLintGlobals(FxHashMap<String, GlobalValue>);
+
+impl ESLintGlobals {
+    pub fn from_value(value: &Value) -> Self {
+        let Value::Object(object) = value else { return Self::default() };
+        Self(
+            object
+                .iter()
+                .filter_map(|(name, value)| {
+                    GlobalValue::from_value(value).map(|value| (name.clone(), value))
+                })
+                .collect(),
+        )
+    }
+
+    pub fn get(&self, name: &str) -> Option<GlobalValue> {
+        self.0.get(name).copied()
+    }
+
+    pub fn is_enabled(&self, name: &str) -> bool {
+        self.get(name).is_some_and(GlobalValue::is_enabled)
+    }
+}
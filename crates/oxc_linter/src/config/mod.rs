@@ -1,6 +1,9 @@
-use std::{collections::HashSet, path::PathBuf};
+use std::{collections::HashSet, path::Path};
 
+mod env;
 pub mod errors;
+mod globals;
+mod overrides;
 use oxc_diagnostics::{Error, FailedToOpenFileError, Report};
 use phf::{phf_map, Map};
 use rustc_hash::FxHashMap;
@@ -8,26 +11,41 @@ use serde_json::Value;
 
 use crate::{
     rules::{RuleEnum, RULES},
-    AllowWarnDeny, JsxA11y, LintSettings,
+    AllowWarnDeny, JsxA11y, LintSettings, RuleCategory,
+};
+
+pub use self::{
+    env::ESLintEnv,
+    globals::{ESLintGlobals, GlobalValue},
+    overrides::LintOverride,
 };
 
 use self::errors::{
     FailedToParseConfigError, FailedToParseConfigJsonError, FailedToParseConfigPropertyError,
-    FailedToParseRuleValueError,
+    FailedToParseRuleValueError, UnknownCategoryError, UnknownRuleError,
 };
 
 pub struct ESLintConfig {
-    rules: std::vec::Vec<RuleEnum>,
+    /// Enabled rules with their configured severity
+    rules: std::vec::Vec<(RuleEnum, AllowWarnDeny)>,
+    /// Severity of the rules of a category, `rules` take precedence
+    categories: Vec<(RuleCategory, AllowWarnDeny)>,
     settings: LintSettings,
+    overrides: Vec<LintOverride>,
+    /// Non-fatal problems found in the configuration, e.g. unknown rule names
+    warnings: Vec<Error>,
 }
 
 impl ESLintConfig {
-    pub fn new(path: &PathBuf) -> Result<Self, Report> {
+    /// # Errors
+    ///
+    /// Returns `Err` if the file cannot be read, is not json, or contains invalid rule values.
+    pub fn new(path: &Path) -> Result<Self, Report> {
         let file = match std::fs::read_to_string(path) {
             Ok(file) => file,
             Err(e) => {
                 return Err(FailedToParseConfigError(vec![Error::new(FailedToOpenFileError(
-                    path.clone(),
+                    path.to_path_buf(),
                     e,
                 ))])
                 .into());
@@ -49,16 +67,29 @@ impl ESLintConfig {
                     }
                 };
                 return Err(FailedToParseConfigError(vec![Error::new(
-                    FailedToParseConfigJsonError(path.clone(), err),
+                    FailedToParseConfigJsonError(path.to_path_buf(), err),
                 )])
                 .into());
             }
         };
 
+        let mut config = Self::from_value(&file)?;
+        if let Some(root) = path.parent() {
+            for lint_override in &mut config.overrides {
+                lint_override.set_root(root);
+            }
+        }
+        Ok(config)
+    }
+
+    /// # Errors
+    ///
+    /// Returns `Err` if there are invalid rule values or `overrides`.
+    pub fn from_value(file: &Value) -> Result<Self, Report> {
         // See https://github.com/oxc-project/oxc/issues/1672
         let extends_hm: HashSet<&str> = HashSet::new();
 
-        let roles_hm = match parse_rules(&file) {
+        let roles_hm = match parse_rules(file) {
             Ok(roles_hm) => roles_hm
                 .into_iter()
                 .map(|(plugin_name, rule_name, allow_warn_deny, config)| {
@@ -70,9 +101,26 @@ impl ESLintConfig {
             }
         };
 
-        let settings = parse_settings_from_root(&file);
+        let mut settings = parse_settings_from_root(file);
+        if let Some(env) = file.get("env") {
+            settings.env = ESLintEnv::from_value(env);
+        }
+        if let Some(globals) = file.get("globals") {
+            settings.globals = ESLintGlobals::from_value(globals);
+        }
+
+        let mut warnings = roles_hm
+            .keys()
+            .filter(|(plugin_name, rule_name)| find_rule(plugin_name, rule_name).is_none())
+            .map(|(plugin_name, rule_name)| {
+                Error::new(UnknownRuleError(format!("{plugin_name}/{rule_name}")))
+            })
+            .collect::<Vec<_>>();
 
-        // `extends` provides the defaults
+        let categories = parse_categories(file, &mut warnings)?;
+        let overrides = parse_overrides(file, &mut warnings)?;
+
+        // `extends` and `categories` provide the defaults
         // `rules` provides the overrides
         let rules = RULES.clone().into_iter().filter_map(|rule| {
             // Check if the extends set is empty or contains the plugin name
@@ -83,6 +131,11 @@ impl ESLintConfig {
                 if let Some((policy, config)) = roles_hm.get(&(rule.plugin_name(), rule.name())) {
                     // Return true for handling, and also whether it's enabled or not
                     (true, *policy, config)
+                } else if let Some((_, policy)) =
+                    categories.iter().find(|(category, _)| *category == rule.category())
+                {
+                    // Handled by the category of the rule
+                    (true, *policy, &None)
                 } else {
                     // Not explicitly handled
                     (false, AllowWarnDeny::Allow, &None)
@@ -90,23 +143,38 @@ impl ESLintConfig {
 
             // The rule is included if it's in the extends set and not explicitly disabled,
             // or if it's explicitly enabled
-            if (in_extends && !is_explicitly_handled) || policy.is_enabled() {
-                Some(rule.read_json(config.clone()))
+            if in_extends && !is_explicitly_handled {
+                Some((rule.read_json(config.clone()), AllowWarnDeny::Warn))
+            } else if policy.is_enabled() {
+                Some((rule.read_json(config.clone()), policy))
             } else {
                 None
             }
         });
 
-        Ok(Self { rules: rules.collect::<Vec<_>>(), settings })
+        Ok(Self { rules: rules.collect::<Vec<_>>(), categories, settings, overrides, warnings })
     }
 
+    #[must_use]
     pub fn into_rules(mut self) -> Self {
-        self.rules.sort_unstable_by_key(RuleEnum::name);
+        self.rules.sort_unstable_by_key(|(rule, _)| rule.name());
         self
     }
 
-    pub fn get_config(self) -> (std::vec::Vec<RuleEnum>, LintSettings) {
-        (self.rules, self.settings)
+    pub fn rules(&self) -> &[(RuleEnum, AllowWarnDeny)] {
+        &self.rules
+    }
+
+    pub fn warnings(&self) -> &[Error] {
+        &self.warnings
+    }
+
+    pub fn categories(&self) -> &[(RuleCategory, AllowWarnDeny)] {
+        &self.categories
+    }
+
+    pub fn get_config(self) -> (Vec<(RuleEnum, AllowWarnDeny)>, LintSettings, Vec<LintOverride>) {
+        (self.rules, self.settings, self.overrides)
     }
 }
 
@@ -162,6 +230,68 @@ fn parse_rules(
         .collect::<Result<Vec<_>, Error>>()
 }
 
+fn find_rule(plugin_name: &str, rule_name: &str) -> Option<&'static RuleEnum> {
+    RULES.iter().find(|rule| rule.plugin_name() == plugin_name && rule.name() == rule_name)
+}
+
+/// Parses `categories`, unknown categories are reported to `warnings` and skipped.
+///
+/// ```json
+/// { "categories": { "correctness": "error", "style": "warn", "nursery": "off" } }
+/// ```
+fn parse_categories(
+    root_json: &Value,
+    warnings: &mut Vec<Error>,
+) -> Result<Vec<(RuleCategory, AllowWarnDeny)>, Error> {
+    let Some(categories) = root_json.get("categories") else { return Ok(vec![]) };
+    let Value::Object(categories) = categories else {
+        return Err(FailedToParseConfigPropertyError("categories", "Expected an object.").into());
+    };
+
+    let mut result = vec![];
+    for (name, value) in categories {
+        let severity = AllowWarnDeny::try_from(value)?;
+        let Some(category) = RuleCategory::from(name) else {
+            warnings.push(Error::new(UnknownCategoryError(name.clone())));
+            continue;
+        };
+        result.push((category, severity));
+    }
+    Ok(result)
+}
+
+/// Parses `overrides`, unknown rules are reported to `warnings` and skipped.
+fn parse_overrides(
+    root_json: &Value,
+    warnings: &mut Vec<Error>,
+) -> Result<Vec<LintOverride>, Error> {
+    let Some(overrides) = root_json.get("overrides") else { return Ok(vec![]) };
+    let Value::Array(overrides) = overrides else {
+        return Err(FailedToParseConfigPropertyError("overrides", "Expected an array.").into());
+    };
+
+    overrides
+        .iter()
+        .map(|override_json| {
+            let rules = parse_rules(override_json)?
+                .into_iter()
+                .filter_map(|(plugin_name, rule_name, severity, config)| {
+                    let Some(rule) = find_rule(plugin_name, rule_name) else {
+                        warnings.push(Error::new(UnknownRuleError(format!(
+                            "{plugin_name}/{rule_name}"
+                        ))));
+                        return None;
+                    };
+                    let has_options = config.is_some();
+                    Some((rule.read_json(config), severity, has_options))
+                })
+                .collect();
+            let files = override_json.get("files").unwrap_or(&Value::Null);
+            LintOverride::new(files, rules)
+        })
+        .collect()
+}
+
 fn parse_settings_from_root(root_json: &Value) -> LintSettings {
     let Value::Object(root_object) = root_json else { return LintSettings::default() };
 
@@ -191,7 +321,7 @@ pub fn parse_settings(setting_value: &Value) -> LintSettings {
                     .set_polymorphic_prop_name(Some(String::from(polymorphic_prop_name)));
             }
 
-            return LintSettings { jsx_a11y: jsx_a11y_setting };
+            return LintSettings { jsx_a11y: jsx_a11y_setting, ..LintSettings::default() };
         }
     }
 
@@ -214,6 +344,8 @@ fn parse_rule_name(name: &str) -> (&str, &str) {
         // if it matches typescript-eslint, map it to typescript
         let category = match category {
             "typescript-eslint" => "typescript",
+            // plugin modules are named in snake case
+            "jsx-a11y" => "jsx_a11y",
             _ => category,
         };
 
@@ -234,8 +366,8 @@ fn parse_rule_name(name: &str) -> (&str, &str) {
 /// }
 /// ```
 fn resolve_rule_value(value: &serde_json::Value) -> Result<(AllowWarnDeny, Option<Value>), Error> {
-    if let Some(v) = value.as_str() {
-        return Ok((AllowWarnDeny::try_from(v)?, None));
+    if value.is_string() || value.is_number() {
+        return Ok((AllowWarnDeny::try_from(value)?, None));
     }
 
     if let Some(v) = value.as_array() {
@@ -254,8 +386,232 @@ fn resolve_rule_value(value: &serde_json::Value) -> Result<(AllowWarnDeny, Optio
 
 #[cfg(test)]
 mod test {
-    use super::parse_rules;
-    use std::env;
+    use super::{parse_rules, ESLintConfig};
+    use crate::{AllowWarnDeny, LintService, Linter, RuleCategory};
+    use oxc_allocator::Allocator;
+    use oxc_diagnostics::{DiagnosticService, Severity};
+    use serde_json::json;
+    use std::{env, path::Path};
+
+    fn severities(config: &ESLintConfig) -> Vec<(&'static str, AllowWarnDeny)> {
+        config.rules().iter().map(|(rule, severity)| (rule.name(), *severity)).collect()
+    }
+
+    #[test]
+    fn test_rule_severities() {
+        let config = ESLintConfig::from_value(&json!({
+            "rules": {
+                "no-debugger": "off",
+                "no-console": "warn",
+                "eqeqeq": "error",
+                "no-empty": 0,
+                "no-bitwise": 1,
+                "no-eval": 2,
+                "no-empty-pattern": ["error"],
+                "no-cond-assign": ["warn", "always"],
+                "no-redeclare": ["off", { "builtinGlobals": true }],
+            }
+        }))
+        .unwrap()
+        .into_rules();
+        assert_eq!(
+            severities(&config),
+            vec![
+                ("eqeqeq", AllowWarnDeny::Deny),
+                ("no-bitwise", AllowWarnDeny::Warn),
+                ("no-cond-assign", AllowWarnDeny::Warn),
+                ("no-console", AllowWarnDeny::Warn),
+                ("no-empty-pattern", AllowWarnDeny::Deny),
+                ("no-eval", AllowWarnDeny::Deny),
+            ]
+        );
+        assert!(config.warnings().is_empty());
+    }
+
+    fn lint(config: &serde_json::Value, source_text: &str) -> usize {
+        lint_path(config, "test.js", source_text)
+    }
+
+    fn lint_path(config: &serde_json::Value, path: &str, source_text: &str) -> usize {
+        lint_severities(config, path, source_text).len()
+    }
+
+    /// The reporting rules with the severity of their diagnostics
+    fn lint_severities(
+        config: &serde_json::Value,
+        path: &str,
+        source_text: &str,
+    ) -> Vec<(&'static str, Severity)> {
+        let config = ESLintConfig::from_value(config).unwrap();
+        let linter = Linter::from_config(config);
+        let path = Path::new(path).to_path_buf().into_boxed_path();
+        let lint_service =
+            LintService::from_linter(env::current_dir().unwrap().into(), &[path], linter);
+        let allocator = Allocator::default();
+        let diagnostic_service = DiagnosticService::default();
+        lint_service
+            .run_source(&allocator, source_text, false, diagnostic_service.sender())
+            .iter()
+            .map(|message| {
+                let severity = message.error.severity().unwrap_or(Severity::Error);
+                (message.rule_name().unwrap_or_default(), severity)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_rule_options() {
+        assert_eq!(lint(&json!({ "rules": { "eqeqeq": "error" } }), "a == null"), 1);
+        assert_eq!(lint(&json!({ "rules": { "eqeqeq": ["error", "smart"] } }), "a == null"), 0);
+
+        let source_text = "console.info(1)";
+        assert_eq!(lint(&json!({ "rules": { "no-console": "warn" } }), source_text), 1);
+        let config = json!({ "rules": { "no-console": ["warn", { "allow": ["info"] }] } });
+        assert_eq!(lint(&config, source_text), 0);
+    }
+
+    #[test]
+    fn test_env_and_globals() {
+        let source_text = "document; process;";
+        let rules = json!({ "no-undef": "error" });
+        assert_eq!(lint(&json!({ "rules": rules }), source_text), 2);
+        assert_eq!(lint(&json!({ "rules": rules, "env": { "browser": true } }), source_text), 1);
+        let config = json!({ "rules": rules, "env": { "browser": true, "node": true } });
+        assert_eq!(lint(&config, source_text), 0);
+        let config = json!({ "rules": rules, "globals": { "document": "readonly" } });
+        assert_eq!(lint(&config, source_text), 1);
+        let config =
+            json!({ "rules": rules, "env": { "browser": true }, "globals": { "document": "off" } });
+        assert_eq!(lint(&config, source_text), 2);
+    }
+
+    #[test]
+    fn test_readonly_globals() {
+        let rules = json!({ "no-global-assign": "error" });
+        assert_eq!(lint(&json!({ "rules": rules }), "Object = 1; foo = 1;"), 1);
+        let config = json!({ "rules": rules, "globals": { "foo": "readonly" } });
+        assert_eq!(lint(&config, "foo = 1;"), 1);
+        let config = json!({ "rules": rules, "globals": { "foo": "writable" } });
+        assert_eq!(lint(&config, "foo = 1;"), 0);
+        assert_eq!(lint(&json!({ "rules": rules, "env": { "browser": true } }), "window = 1;"), 1);
+    }
+
+    #[test]
+    fn test_overrides() {
+        let config = json!({
+            "rules": { "no-console": "warn" },
+            "overrides": [
+                { "files": ["*.spec.ts"], "rules": { "no-debugger": "error", "no-console": "off" } },
+                { "files": "src/legacy/**", "rules": { "eqeqeq": ["error", "smart"] } },
+            ]
+        });
+        let source_text = "debugger; console.log(a == null);";
+        assert_eq!(lint_path(&config, "src/foo.spec.ts", source_text), 1);
+        assert_eq!(lint_path(&config, "test/nested/foo.spec.ts", source_text), 1);
+        assert_eq!(lint_path(&config, "src/foo.ts", source_text), 1);
+        assert_eq!(lint_path(&config, "src/foo.spec.js", source_text), 1);
+        assert_eq!(lint_path(&config, "src/legacy/foo.ts", source_text), 1);
+        assert_eq!(lint_path(&config, "src/legacy/foo.ts", "a == b"), 1);
+        assert_eq!(lint_path(&config, "src/foo.ts", "a == b"), 0);
+    }
+
+    #[test]
+    fn test_invalid_overrides() {
+        let config = json!({ "overrides": [{ "files": ["[*.ts"], "rules": {} }] });
+        assert!(ESLintConfig::from_value(&config).is_err());
+        let config = json!({ "overrides": [{ "rules": { "no-debugger": "error" } }] });
+        assert!(ESLintConfig::from_value(&config).is_err());
+        let config = json!({ "overrides": [{ "files": "*.ts", "rules": { "no-such-rule": 2 } }] });
+        let config = ESLintConfig::from_value(&config).unwrap();
+        assert_eq!(config.warnings().len(), 1);
+    }
+
+    #[test]
+    fn test_categories() {
+        let config = ESLintConfig::from_value(&json!({
+            "categories": { "correctness": "error", "style": "warn", "nursery": "off" }
+        }))
+        .unwrap();
+        let mut categories = config.categories().to_vec();
+        categories.sort_unstable_by_key(|(category, _)| category.to_string());
+        assert_eq!(
+            categories,
+            vec![
+                (RuleCategory::Correctness, AllowWarnDeny::Deny),
+                (RuleCategory::Nursery, AllowWarnDeny::Allow),
+                (RuleCategory::Style, AllowWarnDeny::Warn),
+            ]
+        );
+
+        let config = json!({ "categories": { "correctness": "on" } });
+        assert!(ESLintConfig::from_value(&config).is_err());
+        let config = json!({ "categories": { "no-such-category": "warn" } });
+        let warnings = ESLintConfig::from_value(&config).unwrap().warnings().len();
+        assert_eq!(warnings, 1);
+    }
+
+    #[test]
+    fn test_category_severities() {
+        let source_text = "debugger; let foo = null; let bar = 1.0;";
+        let severity_of = |config: &serde_json::Value, rule_name: &str| {
+            lint_severities(config, "test.js", source_text)
+                .into_iter()
+                .find(|(name, _)| *name == rule_name)
+                .map(|(_, severity)| severity)
+        };
+
+        let config = json!({
+            "categories": { "correctness": "error", "style": "error", "nursery": "off" },
+            "rules": { "unicorn/no-null": "warn" }
+        });
+        // `no-debugger` declares its diagnostics as warnings
+        assert_eq!(severity_of(&config, "no-debugger"), Some(Severity::Error));
+        assert_eq!(severity_of(&config, "no-zero-fractions"), Some(Severity::Error));
+        // `rules` take precedence over the category
+        assert_eq!(severity_of(&config, "no-null"), Some(Severity::Warning));
+
+        let config = json!({
+            "categories": { "correctness": "off", "style": "warn" },
+            "rules": { "no-debugger": "error", "unicorn/no-zero-fractions": "off" }
+        });
+        assert_eq!(severity_of(&config, "no-debugger"), Some(Severity::Error));
+        assert_eq!(severity_of(&config, "no-null"), Some(Severity::Warning));
+        assert_eq!(severity_of(&config, "no-zero-fractions"), None);
+    }
+
+    #[test]
+    fn test_reported_severities() {
+        let source_text = "if (a == b) {}\nclass A { set a(v) { return v; } }";
+        // `eqeqeq` declares its diagnostics as warnings, `no-setter-return` as errors
+        let config = json!({ "rules": { "eqeqeq": "error", "no-setter-return": "warn" } });
+        assert_eq!(
+            lint_severities(&config, "test.js", source_text),
+            vec![("eqeqeq", Severity::Error), ("no-setter-return", Severity::Warning)]
+        );
+
+        let config = json!({ "rules": { "eqeqeq": "warn", "no-setter-return": "error" } });
+        assert_eq!(
+            lint_severities(&config, "test.js", source_text),
+            vec![("eqeqeq", Severity::Warning), ("no-setter-return", Severity::Error)]
+        );
+    }
+
+    #[test]
+    fn test_invalid_rule_severity() {
+        assert!(ESLintConfig::from_value(&json!({ "rules": { "eqeqeq": "on" } })).is_err());
+        assert!(ESLintConfig::from_value(&json!({ "rules": { "eqeqeq": 3 } })).is_err());
+    }
+
+    #[test]
+    fn test_unknown_rule() {
+        let config = ESLintConfig::from_value(&json!({
+            "rules": { "no-such-rule": "error", "jsx-a11y/alt-text": "warn" }
+        }))
+        .unwrap();
+        assert_eq!(severities(&config), vec![("alt-text", AllowWarnDeny::Warn)]);
+        let warnings = config.warnings().iter().map(ToString::to_string).collect::<Vec<_>>();
+        assert_eq!(warnings, vec!["Unknown rule \"eslint/no-such-rule\" in configuration"]);
+    }
 
     #[test]
     fn test_parse_rules() {
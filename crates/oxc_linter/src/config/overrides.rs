@@ -0,0 +1,98 @@
+use std::path::{Path, PathBuf};
+
+use globset::{Glob, GlobSet, GlobSetBuilder};
+use oxc_diagnostics::Error;
+use serde_json::Value;
+
+use super::errors::{FailedToParseConfigPropertyError, FailedToParseGlobError};
+use crate::{rules::RuleEnum, AllowWarnDeny, LintRule};
+
+/// An entry of `overrides`, its rules are layered on top of the base rules for the matching files.
+///
+/// ```json
+/// { "overrides": [{ "files": ["**/*.test.ts"], "rules": { "no-console": "off" } }] }
+/// ```
+#[derive(Debug, Clone)]
+pub struct LintOverride {
+    /// `files` globs are relative to the directory of the configuration file.
+    root: PathBuf,
+    files: GlobSet,
+    rules: Vec<OverrideRule>,
+}
+
+#[derive(Debug, Clone)]
+struct OverrideRule {
+    rule: RuleEnum,
+    severity: AllowWarnDeny,
+    /// Without options, an enabled rule keeps the options of the base configuration.
+    has_options: bool,
+}
+
+impl LintOverride {
+    /// # Errors
+    ///
+    /// Returns `Err` if `files` is missing or contains an invalid glob.
+    pub(super) fn new(
+        files: &Value,
+        rules: Vec<(RuleEnum, AllowWarnDeny, bool)>,
+    ) -> Result<Self, Error> {
+        let globs = match files {
+            Value::String(glob) => vec![glob.as_str()],
+            Value::Array(globs) => globs.iter().filter_map(Value::as_str).collect(),
+            _ => {
+                return Err(FailedToParseConfigPropertyError(
+                    "overrides",
+                    "Expected `files` to be a string or an array of strings.",
+                )
+                .into())
+            }
+        };
+        let mut builder = GlobSetBuilder::new();
+        for glob in globs {
+            let glob = Glob::new(glob)
+                .map_err(|e| FailedToParseGlobError(glob.to_string(), e.to_string()))?;
+            builder.add(glob);
+        }
+        let files =
+            builder.build().map_err(|e| FailedToParseGlobError(String::new(), e.to_string()))?;
+        let rules = rules
+            .into_iter()
+            .map(|(rule, severity, has_options)| OverrideRule { rule, severity, has_options })
+            .collect();
+        Ok(Self { root: PathBuf::new(), files, rules })
+    }
+
+    pub(super) fn set_root(&mut self, root: &Path) {
+        self.root = root.to_path_buf();
+    }
+
+    pub fn is_match(&self, path: &Path) -> bool {
+        self.files.is_match(path.strip_prefix(&self.root).unwrap_or(path))
+    }
+
+    /// Enable, reconfigure or turn off the rules of this override in `rules`.
+    pub(crate) fn apply(&self, rules: &mut Vec<LintRule>) {
+        for OverrideRule { rule, severity, has_options } in &self.rules {
+            let existing = rules.iter().position(|(_, base, _)| {
+                base.plugin_name() == rule.plugin_name() && base.name() == rule.name()
+            });
+            match existing {
+                Some(i) if !severity.is_enabled() => {
+                    rules.remove(i);
+                }
+                Some(i) => {
+                    if *has_options {
+                        rules[i].1 = rule.clone();
+                    }
+                    rules[i].2 = Some(*severity);
+                }
+                None if severity.is_enabled() => {
+                    rules.push((rule.name(), rule.clone(), Some(*severity)));
+                }
+                None => {}
+            }
+        }
+        // for stable diagnostics output ordering
+        rules.sort_unstable_by_key(|(name, _, _)| *name);
+    }
+}
@@ -1,14 +1,18 @@
 use std::{cell::RefCell, path::Path, rc::Rc};
 
-use oxc_diagnostics::Error;
+use oxc_diagnostics::{Error, Severity};
 use oxc_formatter::{Formatter, FormatterOptions};
-use oxc_semantic::{AstNodes, JSDocComment, ScopeTree, Semantic, SymbolTable};
+use oxc_semantic::{AstNodes, JSDocComment, ModuleRecord, ScopeTree, Semantic, SymbolTable};
 use oxc_span::SourceType;
 
 use crate::{
-    disable_directives::{DisableDirectives, DisableDirectivesBuilder},
-    fixer::{Fix, Message},
-    AstNode, LintSettings,
+    disable_directives::{
+        DisableDirectives, DisableDirectivesBuilder, UnusedDirective, UnusedDisableDirective,
+        UnusedDisableRuleDirective,
+    },
+    fixer::{Fix, FixKind, Message},
+    globals::ENVIRONMENTS,
+    AstNode, ESLintEnv, ESLintGlobals, FixFilter, GlobalValue, LintSettings,
 };
 
 pub struct LintContext<'a> {
@@ -21,8 +25,14 @@ pub struct LintContext<'a> {
     /// Whether or not to apply code fixes during linting.
     fix: bool,
 
+    /// Which fixes to apply, the others are only reported as fixable.
+    fix_kind: FixFilter,
+
     current_rule_name: &'static str,
 
+    /// Severity configured for the current rule, `None` keeps the severity of its diagnostics.
+    current_severity: Option<Severity>,
+
     file_path: Box<Path>,
 
     settings: LintSettings,
@@ -37,7 +47,9 @@ impl<'a> LintContext<'a> {
             diagnostics: RefCell::new(vec![]),
             disable_directives,
             fix: false,
+            fix_kind: FixFilter::default(),
             current_rule_name: "",
+            current_severity: None,
             file_path,
             settings,
         }
@@ -49,10 +61,22 @@ impl<'a> LintContext<'a> {
         self
     }
 
+    #[must_use]
+    pub fn with_fix_kind(mut self, fix_kind: FixFilter) -> Self {
+        self.fix_kind = fix_kind;
+        self
+    }
+
     pub fn semantic(&self) -> &Rc<Semantic<'a>> {
         &self.semantic
     }
 
+    /// The imports and exports of the file. With the import plugin, the requested modules which
+    /// are resolved are in `loaded_modules`.
+    pub fn module_record(&self) -> &ModuleRecord {
+        self.semantic.module_record()
+    }
+
     pub fn disable_directives(&self) -> &DisableDirectives<'a> {
         &self.disable_directives
     }
@@ -61,6 +85,32 @@ impl<'a> LintContext<'a> {
         self.settings.clone()
     }
 
+    pub fn env(&self) -> &ESLintEnv {
+        &self.settings.env
+    }
+
+    /// Whether the environment `env`, e.g. `browser`, is enabled.
+    pub fn env_contains(&self, env: &str) -> bool {
+        self.settings.env.contains(env)
+    }
+
+    pub fn globals(&self) -> &ESLintGlobals {
+        &self.settings.globals
+    }
+
+    /// Look up the global variable `name` in the configured `globals`, then in the enabled environments.
+    ///
+    /// Returns `None` if the variable is not defined or turned `off`.
+    pub fn global_value(&self, name: &str) -> Option<GlobalValue> {
+        if let Some(value) = self.settings.globals.get(name) {
+            return value.is_enabled().then_some(value);
+        }
+        self.settings.env.iter().find_map(|env| {
+            let writeable = ENVIRONMENTS.get(env)?.get(name)?;
+            Some(if *writeable { GlobalValue::Writeable } else { GlobalValue::Readonly })
+        })
+    }
+
     pub fn source_text(&self) -> &'a str {
         self.semantic().source_text()
     }
@@ -73,19 +123,62 @@ impl<'a> LintContext<'a> {
         &self.file_path
     }
 
+    /// Whether the file being linted is a test file: it lives in a `__tests__` directory,
+    /// is named like `*.test.*` or `*.spec.*`, or the `jest` environment is enabled.
+    pub fn is_test_file(&self) -> bool {
+        self.env_contains("jest") || is_test_path(&self.file_path)
+    }
+
     #[inline]
     pub fn with_rule_name(&mut self, name: &'static str) {
         self.current_rule_name = name;
     }
 
+    #[inline]
+    pub fn with_severity(&mut self, severity: Option<Severity>) {
+        self.current_severity = severity;
+    }
+
     /* Diagnostics */
 
     pub fn into_message(self) -> Vec<Message<'a>> {
         self.diagnostics.into_inner()
     }
 
+    /// Report `eslint-disable` directives which did not suppress any diagnostic,
+    /// fixed by removing the comment or only the unused rule name.
+    ///
+    /// Must be called after all rules have run.
+    pub fn report_unused_directives(&self) {
+        let mut diagnostics = self.diagnostics.borrow_mut();
+        for UnusedDirective { span, rule_name, fix_span } in
+            self.disable_directives.unused_directives()
+        {
+            let error = rule_name.map_or_else(
+                || UnusedDisableDirective(span).into(),
+                |rule_name| UnusedDisableRuleDirective(span, rule_name.to_string()).into(),
+            );
+            let fix = Fix::delete(fix_span).with_kind(FixKind::Safe);
+            let message = if self.fix && self.fix_kind.allows(fix.kind) {
+                Message::new(error, Some(fix))
+            } else {
+                Message::new(error, None).with_fixable(true)
+            };
+            diagnostics.push(message);
+        }
+    }
+
     fn add_diagnostic(&self, message: Message<'a>) {
         if !self.disable_directives.contains(self.current_rule_name, message.start()) {
+            let message = if self.current_rule_name.is_empty() {
+                message
+            } else {
+                message.with_rule_name(self.current_rule_name)
+            };
+            let message = match self.current_severity {
+                Some(severity) => message.with_severity(severity),
+                None => message,
+            };
             self.diagnostics.borrow_mut().push(message);
         }
     }
@@ -100,12 +193,31 @@ impl<'a> LintContext<'a> {
         F: FnOnce() -> Fix<'a>,
     {
         if self.fix {
-            self.add_diagnostic(Message::new(diagnostic.into(), Some(fix())));
+            let fix = fix();
+            let message = if self.fix_kind.allows(fix.kind) {
+                Message::new(diagnostic.into(), Some(fix))
+            } else {
+                Message::new(diagnostic.into(), None).with_fixable(true)
+            };
+            self.add_diagnostic(message);
         } else {
-            self.diagnostic(diagnostic);
+            self.add_diagnostic(Message::new(diagnostic.into(), None).with_fixable(true));
         }
     }
 
+    /// Report `diagnostic` with alternative fixes, described by [Fix::message].
+    ///
+    /// Suggestions are offered by the language server but never applied by `--fix`.
+    pub fn diagnostic_with_suggestions<T: Into<Error>>(
+        &self,
+        diagnostic: T,
+        suggestions: Vec<Fix<'a>>,
+    ) {
+        let message = Message::new(diagnostic.into(), None);
+        let message = if self.fix { message.with_suggestions(suggestions) } else { message };
+        self.add_diagnostic(message);
+    }
+
     pub fn nodes(&self) -> &AstNodes<'a> {
         self.semantic().nodes()
     }
@@ -128,3 +240,13 @@ impl<'a> LintContext<'a> {
         self.semantic().jsdoc().get_by_node(node)
     }
 }
+
+/// Matches jest's default `testMatch`: `**/__tests__/**/*.[jt]s?(x)` and `**/?(*.)+(spec|test).[jt]s?(x)`.
+fn is_test_path(path: &Path) -> bool {
+    if path.components().any(|component| component.as_os_str() == "__tests__") {
+        return true;
+    }
+    path.file_stem().and_then(|stem| stem.to_str()).is_some_and(|stem| {
+        stem.rsplit('.').next().is_some_and(|suffix| matches!(suffix, "test" | "spec"))
+    })
+}
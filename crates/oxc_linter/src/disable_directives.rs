@@ -1,12 +1,29 @@
+use std::cell::RefCell;
+
 use oxc_ast::TriviasMap;
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
 use oxc_span::Span;
 use rust_lapper::{Interval, Lapper};
-use rustc_hash::FxHashMap;
+use rustc_hash::{FxHashMap, FxHashSet};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Unused eslint-disable directive (no problems were reported).")]
+#[diagnostic(severity(warning))]
+pub struct UnusedDisableDirective(#[label] pub Span);
 
+#[derive(Debug, Error, Diagnostic)]
+#[error("Unused eslint-disable directive (no problems were reported from '{1}').")]
+#[diagnostic(severity(warning))]
+pub struct UnusedDisableRuleDirective(#[label] pub Span, pub String);
+
+/// A disabled rule together with the span of the comment which disabled it.
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 enum DisabledRule<'a> {
-    All,
-    Single(&'a str),
+    All { comment_span: Span },
+    Single { rule_name: &'a str, comment_span: Span },
 }
 
 /// A comment which disables one or more specific rules
@@ -15,26 +32,107 @@ pub struct DisableRuleComment<'a> {
     pub span: Span,
     /// Rules disabled by the comment
     pub rules: Vec<&'a str>,
+    /// Spans of the rule names, in the same order as `rules`
+    pub rule_spans: Vec<Span>,
+}
+
+/// A directive which did not suppress any diagnostic.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UnusedDirective<'a> {
+    /// Span of the comment
+    pub span: Span,
+    /// The unused rule, `None` when the comment disables all rules
+    pub rule_name: Option<&'a str>,
+    /// What to delete to remove the directive:
+    /// the whole comment, or only the rule name when other rules of the comment are used
+    pub fix_span: Span,
 }
 
 pub struct DisableDirectives<'a> {
+    source_text: &'a str,
     /// All the disabled rules with their corresponding covering spans
     intervals: Lapper<u32, DisabledRule<'a>>,
     /// Spans of comments that disable all rules
     disable_all_comments: Vec<Span>,
     /// All comments that disable one or more specific rules
     disable_rule_comments: Vec<DisableRuleComment<'a>>,
+    /// Directives which suppressed at least one diagnostic,
+    /// keyed by comment span and rule name (`None` for directives disabling all rules)
+    used_directives: RefCell<FxHashSet<(Span, Option<&'a str>)>>,
 }
 
 impl<'a> DisableDirectives<'a> {
     pub fn contains(&self, rule_name: &'static str, start: u32) -> bool {
-        self.intervals.find(start, start + 1).any(|interval| {
-            interval.val == DisabledRule::All
+        let mut contains = false;
+        for interval in self.intervals.find(start, start + 1) {
+            let directive = match interval.val {
+                DisabledRule::All { comment_span } => (comment_span, None),
                 // Our rule name currently does not contain the prefix.
                 // For example, this will match `@typescript-eslint/no-var-requires` given
                 // our rule_name is `no-var-requires`.
-                || matches!(interval.val, DisabledRule::Single(name) if name.contains(rule_name))
-        })
+                DisabledRule::Single { rule_name: name, comment_span }
+                    if name.contains(rule_name) =>
+                {
+                    (comment_span, Some(name))
+                }
+                DisabledRule::Single { .. } => continue,
+            };
+            self.used_directives.borrow_mut().insert(directive);
+            contains = true;
+        }
+        contains
+    }
+
+    /// Directives which did not suppress any diagnostic, ordered by position.
+    pub fn unused_directives(&self) -> Vec<UnusedDirective<'a>> {
+        let used_directives = self.used_directives.borrow();
+        let used_directives = &*used_directives;
+        let disable_all = self
+            .disable_all_comments
+            .iter()
+            .filter(|span| !used_directives.contains(&(**span, None)))
+            .map(|span| UnusedDirective {
+                span: *span,
+                rule_name: None,
+                fix_span: self.comment_span(*span),
+            });
+        let disable_rules = self.disable_rule_comments.iter().flat_map(move |comment| {
+            let is_used = move |rule_name: &&'a str| {
+                used_directives.contains(&(comment.span, Some(*rule_name)))
+            };
+            let is_partially_used = comment.rules.iter().any(is_used);
+            comment.rules.iter().enumerate().filter(move |(_, rule_name)| !is_used(rule_name)).map(
+                move |(i, rule_name)| UnusedDirective {
+                    span: comment.span,
+                    rule_name: Some(*rule_name),
+                    fix_span: if is_partially_used {
+                        Self::rule_name_span(&comment.rule_spans, i)
+                    } else {
+                        self.comment_span(comment.span)
+                    },
+                },
+            )
+        });
+        let mut unused = disable_all.chain(disable_rules).collect::<Vec<_>>();
+        unused.sort_unstable_by_key(|directive| directive.span);
+        unused
+    }
+
+    /// Span of the comment including its delimiters, `span` only covers the content.
+    fn comment_span(&self, span: Span) -> Span {
+        let is_multi_line = self.source_text[span.end as usize..].starts_with("*/");
+        Span::new(span.start - 2, if is_multi_line { span.end + 2 } else { span.end })
+    }
+
+    /// Span of the `i`-th rule name together with the comma separating it from its neighbour.
+    fn rule_name_span(rule_spans: &[Span], i: usize) -> Span {
+        match (i.checked_sub(1).map(|i| rule_spans[i]), rule_spans.get(i + 1)) {
+            // `foo, bar` -> `bar`
+            (_, Some(next)) => Span::new(rule_spans[i].start, next.start),
+            // `foo, bar` -> `foo`
+            (Some(prev), None) => Span::new(prev.end, rule_spans[i].end),
+            (None, None) => rule_spans[i],
+        }
     }
 
     pub fn disable_all_comments(&self) -> &Vec<Span> {
@@ -51,10 +149,10 @@ pub struct DisableDirectivesBuilder<'a, 'b> {
     trivias: &'b TriviasMap,
     /// All the disabled rules with their corresponding covering spans
     intervals: Lapper<u32, DisabledRule<'a>>,
-    /// Start of `eslint-disable`
-    disable_all_start: Option<u32>,
-    /// Start of `eslint-disable rule_name`
-    disable_start_map: FxHashMap<&'a str, u32>,
+    /// Comment of the first `eslint-disable`, the disabled region starts at its end
+    disable_all_start: Option<Span>,
+    /// Comment of `eslint-disable rule_name`, the disabled region starts at its end
+    disable_start_map: FxHashMap<&'a str, Span>,
     /// Spans of comments that disable all rules
     disable_all_comments: Vec<Span>,
     /// All comments that disable one or more specific rules
@@ -77,9 +175,11 @@ impl<'a, 'b> DisableDirectivesBuilder<'a, 'b> {
     pub fn build(mut self) -> DisableDirectives<'a> {
         self.build_impl();
         DisableDirectives {
+            source_text: self.source_text,
             intervals: self.intervals,
             disable_all_comments: self.disable_all_comments,
             disable_rule_comments: self.disable_rule_comments,
+            used_directives: RefCell::default(),
         }
     }
 
@@ -102,7 +202,7 @@ impl<'a, 'b> DisableDirectivesBuilder<'a, 'b> {
                 // `eslint-disable`
                 if text.trim().is_empty() {
                     if self.disable_all_start.is_none() {
-                        self.disable_all_start = Some(span.end);
+                        self.disable_all_start = Some(span);
                     }
                     self.disable_all_comments.push(span);
                     continue;
@@ -116,16 +216,25 @@ impl<'a, 'b> DisableDirectivesBuilder<'a, 'b> {
                         .take(2)
                         .fold(span.end, |acc, line| acc + line.len() as u32);
                     if text.trim().is_empty() {
-                        self.add_interval(span.end, stop, DisabledRule::All);
+                        self.add_interval(span.end, stop, DisabledRule::All { comment_span: span });
                         self.disable_all_comments.push(span);
                     } else {
                         // `eslint-disable-next-line rule_name1, rule_name2`
-                        let mut rules = vec![];
-                        Self::get_rule_names(text, |rule_name| {
-                            self.add_interval(span.end, stop, DisabledRule::Single(rule_name));
+                        let (mut rules, mut rule_spans) = (vec![], vec![]);
+                        Self::get_rule_names(text, span.end, |rule_name, rule_span| {
+                            self.add_interval(
+                                span.end,
+                                stop,
+                                DisabledRule::Single { rule_name, comment_span: span },
+                            );
                             rules.push(rule_name);
+                            rule_spans.push(rule_span);
+                        });
+                        self.disable_rule_comments.push(DisableRuleComment {
+                            span,
+                            rules,
+                            rule_spans,
                         });
-                        self.disable_rule_comments.push(DisableRuleComment { span, rules });
                     }
                     continue;
                 }
@@ -141,27 +250,37 @@ impl<'a, 'b> DisableDirectivesBuilder<'a, 'b> {
 
                     // `eslint-disable-line`
                     if text.trim().is_empty() {
-                        self.add_interval(start, stop, DisabledRule::All);
+                        self.add_interval(start, stop, DisabledRule::All { comment_span: span });
                         self.disable_all_comments.push(span);
                     } else {
                         // `eslint-disable-line rule-name1, rule-name2`
-                        let mut rules = vec![];
-                        Self::get_rule_names(text, |rule_name| {
-                            self.add_interval(start, stop, DisabledRule::Single(rule_name));
+                        let (mut rules, mut rule_spans) = (vec![], vec![]);
+                        Self::get_rule_names(text, span.end, |rule_name, rule_span| {
+                            self.add_interval(
+                                start,
+                                stop,
+                                DisabledRule::Single { rule_name, comment_span: span },
+                            );
                             rules.push(rule_name);
+                            rule_spans.push(rule_span);
+                        });
+                        self.disable_rule_comments.push(DisableRuleComment {
+                            span,
+                            rules,
+                            rule_spans,
                         });
-                        self.disable_rule_comments.push(DisableRuleComment { span, rules });
                     }
                     continue;
                 }
 
                 // `eslint-disable rule-name1, rule-name2`
-                let mut rules = vec![];
-                Self::get_rule_names(text, |rule_name| {
-                    self.disable_start_map.entry(rule_name).or_insert(span.end);
+                let (mut rules, mut rule_spans) = (vec![], vec![]);
+                Self::get_rule_names(text, span.end, |rule_name, rule_span| {
+                    self.disable_start_map.entry(rule_name).or_insert(span);
                     rules.push(rule_name);
+                    rule_spans.push(rule_span);
                 });
-                self.disable_rule_comments.push(DisableRuleComment { span, rules });
+                self.disable_rule_comments.push(DisableRuleComment { span, rules, rule_spans });
 
                 continue;
             }
@@ -169,14 +288,22 @@ impl<'a, 'b> DisableDirectivesBuilder<'a, 'b> {
             if let Some(text) = text.strip_prefix("eslint-enable") {
                 // `eslint-enable`
                 if text.trim().is_empty() {
-                    if let Some(start) = self.disable_all_start.take() {
-                        self.add_interval(start, span.start, DisabledRule::All);
+                    if let Some(comment_span) = self.disable_all_start.take() {
+                        self.add_interval(
+                            comment_span.end,
+                            span.start,
+                            DisabledRule::All { comment_span },
+                        );
                     }
                 } else {
                     // `eslint-enable rule-name1, rule-name2`
-                    Self::get_rule_names(text, |rule_name| {
-                        if let Some(start) = self.disable_start_map.remove(rule_name) {
-                            self.add_interval(start, span.start, DisabledRule::Single(rule_name));
+                    Self::get_rule_names(text, span.end, |rule_name, _| {
+                        if let Some(comment_span) = self.disable_start_map.remove(rule_name) {
+                            self.add_interval(
+                                comment_span.end,
+                                span.start,
+                                DisabledRule::Single { rule_name, comment_span },
+                            );
                         }
                     });
                 }
@@ -185,20 +312,32 @@ impl<'a, 'b> DisableDirectivesBuilder<'a, 'b> {
         }
 
         // Lone `eslint-disable`
-        if let Some(start) = self.disable_all_start {
-            self.add_interval(start, source_len, DisabledRule::All);
+        if let Some(comment_span) = self.disable_all_start {
+            self.add_interval(comment_span.end, source_len, DisabledRule::All { comment_span });
         }
 
         // Lone `eslint-disable rule_name`
         let disable_start_map = self.disable_start_map.drain().collect::<Vec<_>>();
-        for (rule_name, start) in disable_start_map {
-            self.add_interval(start, source_len, DisabledRule::Single(rule_name));
+        for (rule_name, comment_span) in disable_start_map {
+            self.add_interval(
+                comment_span.end,
+                source_len,
+                DisabledRule::Single { rule_name, comment_span },
+            );
         }
     }
 
-    fn get_rule_names<F: FnMut(&'a str)>(text: &'a str, cb: F) {
+    /// Calls `cb` with every rule name in `text` and its span, `text` is the end of a comment ending at `end`.
+    #[allow(clippy::cast_possible_truncation)] // for `as u32`
+    fn get_rule_names<F: FnMut(&'a str, Span)>(text: &'a str, end: u32, mut cb: F) {
+        let mut start = end - text.len() as u32;
         if let Some(text) = text.split_terminator("--").next() {
-            text.split(',').map(str::trim).for_each(cb);
+            for rule_name in text.split(',') {
+                let trimmed = rule_name.trim();
+                let offset = start + (rule_name.len() - rule_name.trim_start().len()) as u32;
+                cb(trimmed, Span::new(offset, offset + trimmed.len() as u32));
+                start += rule_name.len() as u32 + 1;
+            }
         }
     }
 }
@@ -356,3 +495,94 @@ fn test() {
 
     Tester::new_without_config("no-debugger", pass, fail).test();
 }
+
+#[test]
+fn test_unused_directives() {
+    use std::{path::Path, rc::Rc};
+
+    use oxc_allocator::Allocator;
+    use oxc_parser::Parser;
+    use oxc_semantic::SemanticBuilder;
+    use oxc_span::SourceType;
+
+    use crate::{rules::RULES, Fixer, LintContext, LintOptions, LintSettings, Linter};
+
+    /// Returns the reported messages and the fixed code
+    fn lint(source_text: &str) -> (Vec<String>, String) {
+        let allocator = Allocator::default();
+        let source_type = SourceType::default();
+        let ret = Parser::new(&allocator, source_text, source_type).parse();
+        let program = allocator.alloc(ret.program);
+        let semantic = SemanticBuilder::new(source_text, source_type)
+            .with_trivias(ret.trivias)
+            .build(program)
+            .semantic;
+        let semantic = Rc::new(semantic);
+        let rule = RULES.iter().find(|rule| rule.name() == "no-debugger").unwrap().clone();
+        let lint_with_fix = |fix: bool| {
+            let options = LintOptions::default().with_report_unused_directives(true).with_fix(fix);
+            let linter = Linter::from_options(options).unwrap().with_rules(vec![rule.clone()]);
+            let ctx =
+                LintContext::new(Path::new("test.js").into(), &semantic, LintSettings::default());
+            linter.run(ctx)
+        };
+        let messages =
+            lint_with_fix(false).into_iter().map(|message| message.error.to_string()).collect();
+        let fixed_code = Fixer::new(source_text, lint_with_fix(true)).fix().fixed_code.to_string();
+        (messages, fixed_code)
+    }
+
+    fn run(source_text: &str) -> Vec<String> {
+        lint(source_text).0
+    }
+
+    fn fix(source_text: &str) -> String {
+        lint(source_text).1
+    }
+
+    let unused_all = "Unused eslint-disable directive (no problems were reported).";
+    let unused_debugger =
+        "Unused eslint-disable directive (no problems were reported from 'no-debugger').";
+    let unused_console =
+        "Unused eslint-disable directive (no problems were reported from 'no-console').";
+
+    assert!(run("// eslint-disable-next-line no-debugger\ndebugger;").is_empty());
+    assert!(run("/* eslint-disable */\ndebugger;\n/* eslint-enable */").is_empty());
+    assert!(run("debugger; // eslint-disable-line").is_empty());
+    assert_eq!(run("// eslint-disable-next-line no-debugger\nfoo();"), vec![unused_debugger]);
+    assert_eq!(run("/* eslint-disable */\nfoo();"), vec![unused_all]);
+    assert_eq!(run("foo(); // eslint-disable-line"), vec![unused_all]);
+    assert_eq!(
+        run("// eslint-disable-next-line no-console, no-debugger\ndebugger;"),
+        vec![unused_console]
+    );
+    assert_eq!(
+        run("// eslint-disable-next-line no-console, no-alert\nfoo();"),
+        vec![
+            unused_console,
+            "Unused eslint-disable directive (no problems were reported from 'no-alert')."
+        ]
+    );
+
+    // The whole comment is removed
+    assert_eq!(fix("// eslint-disable-next-line no-debugger\nfoo();"), "\nfoo();");
+    assert_eq!(fix("/* eslint-disable */\nfoo();"), "\nfoo();");
+    assert_eq!(fix("foo(); // eslint-disable-line"), "foo(); ");
+    assert_eq!(fix("foo(); /* eslint-disable-line no-debugger */ bar();"), "foo();  bar();");
+    assert_eq!(fix("// eslint-disable-next-line no-console, no-alert\nfoo();"), "\nfoo();");
+    // Only the unused rule name is removed when another rule of the comment is used
+    assert_eq!(
+        fix("// eslint-disable-next-line no-console, no-debugger\ndebugger;"),
+        "// eslint-disable-next-line no-debugger\ndebugger;"
+    );
+    assert_eq!(
+        fix("// eslint-disable-next-line no-debugger, no-console\ndebugger;"),
+        "// eslint-disable-next-line no-debugger\ndebugger;"
+    );
+    assert_eq!(
+        fix(
+            "/* eslint-disable-next-line no-console, no-debugger, no-alert -- reason */\ndebugger;"
+        ),
+        "/* eslint-disable-next-line no-debugger -- reason */\ndebugger;"
+    );
+}
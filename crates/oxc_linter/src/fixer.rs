@@ -1,21 +1,64 @@
 use std::borrow::Cow;
 
-use oxc_diagnostics::Error;
+use oxc_diagnostics::{
+    DiagnosticFix, DiagnosticWithOffset, DiagnosticWithSeverity, Error, Severity,
+};
 use oxc_span::Span;
+use serde::{Deserialize, Serialize};
+
+/// Whether applying a fix can change the behavior of the code.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum FixKind {
+    /// Only changes the syntax, e.g. removing an unused label
+    Safe,
+    /// May change the runtime behavior, fixes not known to be safe are dangerous
+    #[default]
+    Dangerous,
+}
 
 #[derive(Debug, Default)]
 pub struct Fix<'a> {
     pub content: Cow<'a, str>,
     pub span: Span,
+    pub kind: FixKind,
+    /// Description of the fix, shown for suggestions
+    pub message: Option<Cow<'a, str>>,
 }
 
 impl<'a> Fix<'a> {
     pub const fn delete(span: Span) -> Self {
-        Self { content: Cow::Borrowed(""), span }
+        Self { content: Cow::Borrowed(""), span, kind: FixKind::Dangerous, message: None }
     }
 
     pub fn new<T: Into<Cow<'a, str>>>(content: T, span: Span) -> Self {
-        Self { content: content.into(), span }
+        Self { content: content.into(), span, kind: FixKind::Dangerous, message: None }
+    }
+
+    #[must_use]
+    pub fn with_message<T: Into<Cow<'a, str>>>(mut self, message: T) -> Self {
+        self.message = Some(message.into());
+        self
+    }
+
+    pub fn into_owned(self) -> Fix<'static> {
+        Fix {
+            content: Cow::Owned(self.content.into_owned()),
+            span: self.span,
+            kind: self.kind,
+            message: self.message.map(|message| Cow::Owned(message.into_owned())),
+        }
+    }
+
+    #[must_use]
+    pub fn with_kind(mut self, kind: FixKind) -> Self {
+        self.kind = kind;
+        self
+    }
+
+    /// The fix as reported by [oxc_diagnostics::DiagnosticWithFix]
+    pub fn to_diagnostic_fix(&self) -> DiagnosticFix {
+        let range = self.span.start as usize..self.span.end as usize;
+        DiagnosticFix { range, text: self.content.to_string() }
     }
 }
 
@@ -32,7 +75,13 @@ pub struct Message<'a> {
     start: u32,
     end: u32,
     pub fix: Option<Fix<'a>>,
+    /// Alternative fixes the user chooses among, never applied by the [Fixer]
+    pub suggestions: Vec<Fix<'a>>,
     fixed: bool,
+    /// Name of the rule that reported this message, `None` for parser and semantic errors
+    rule_name: Option<&'static str>,
+    /// A fix is available, even if it was not computed because fixing is disabled
+    fixable: bool,
 }
 
 impl<'a> Message<'a> {
@@ -45,7 +94,69 @@ impl<'a> Message<'a> {
             .iter()
             .max_by_key(|span| span.offset() + span.len())
             .map_or(0, |span| (span.offset() + span.len()) as u32);
-        Self { error, start, end, fix, fixed: false }
+        let fixable = fix.is_some();
+        Self { error, start, end, fix, suggestions: vec![], fixed: false, rule_name: None, fixable }
+    }
+
+    #[must_use]
+    pub fn with_suggestions(mut self, suggestions: Vec<Fix<'a>>) -> Self {
+        self.suggestions = suggestions;
+        self
+    }
+
+    /// Mark the message as fixable without providing the fix.
+    #[must_use]
+    pub fn with_fixable(mut self, fixable: bool) -> Self {
+        self.fixable = fixable;
+        self
+    }
+
+    /// Report the error with `severity` instead of its own.
+    #[must_use]
+    pub fn with_severity(mut self, severity: Severity) -> Self {
+        if self.error.severity().unwrap_or(Severity::Error) != severity {
+            self.error = Error::new(DiagnosticWithSeverity::new(self.error, severity));
+        }
+        self
+    }
+
+    /// Move the message by `offset` bytes, e.g. from the code extracted by a partial loader to
+    /// its position in the file.
+    #[must_use]
+    pub fn with_offset(mut self, offset: u32) -> Self {
+        if offset == 0 {
+            return self;
+        }
+        let move_fix = |fix: Fix<'a>| {
+            let span = Span::new(fix.span.start + offset, fix.span.end + offset);
+            Fix { span, ..fix }
+        };
+        self.error = Error::new(DiagnosticWithOffset::new(self.error, offset as usize));
+        self.start += offset;
+        self.end += offset;
+        self.fix = self.fix.map(move_fix);
+        self.suggestions = self.suggestions.into_iter().map(move_fix).collect();
+        self
+    }
+
+    #[must_use]
+    pub fn with_rule_name(mut self, rule_name: &'static str) -> Self {
+        self.rule_name = Some(rule_name);
+        self
+    }
+
+    /// Copy the borrowed source text so the message outlives the linted source.
+    pub fn into_owned(self) -> Message<'static> {
+        Message {
+            error: self.error,
+            start: self.start,
+            end: self.end,
+            fix: self.fix.map(Fix::into_owned),
+            suggestions: self.suggestions.into_iter().map(Fix::into_owned).collect(),
+            fixed: self.fixed,
+            rule_name: self.rule_name,
+            fixable: self.fixable,
+        }
     }
 
     pub fn start(&self) -> u32 {
@@ -55,6 +166,14 @@ impl<'a> Message<'a> {
     pub fn end(&self) -> u32 {
         self.end
     }
+
+    pub fn rule_name(&self) -> Option<&'static str> {
+        self.rule_name
+    }
+
+    pub fn is_fixable(&self) -> bool {
+        self.fixable
+    }
 }
 
 /// The fixer of the code.
@@ -85,7 +204,7 @@ impl<'a> Fixer<'a> {
         let mut output = String::with_capacity(source_text.len());
         let mut last_pos: i64 = -1;
         self.messages.iter_mut().filter(|m| m.fix.is_some()).for_each(|m| {
-            let Fix { content, span } = m.fix.as_ref().unwrap();
+            let Fix { content, span, .. } = m.fix.as_ref().unwrap();
             let start = span.start;
             let end = span.end;
             if start > end {
@@ -120,38 +239,68 @@ mod test {
     use oxc_diagnostics::{thiserror::Error, Error};
     use oxc_span::Span;
 
-    use super::{Fix, FixResult, Fixer, Message};
+    use super::{Fix, FixKind, FixResult, Fixer, Message};
 
     const TEST_CODE: &str = "var answer = 6 * 7;";
 
     #[derive(Debug, Error, Diagnostic)]
     #[error("End")]
     struct InsertAtEnd;
-    const INSERT_AT_END: Fix = Fix { span: Span::new(19, 19), content: Cow::Borrowed("// end") };
+    const INSERT_AT_END: Fix = Fix {
+        span: Span::new(19, 19),
+        content: Cow::Borrowed("// end"),
+        kind: FixKind::Safe,
+        message: None,
+    };
 
     #[derive(Debug, Error, Diagnostic)]
     #[error("Start")]
     struct InsertAtStart;
-    const INSERT_AT_START: Fix = Fix { span: Span::new(0, 0), content: Cow::Borrowed("// start") };
+    const INSERT_AT_START: Fix = Fix {
+        span: Span::new(0, 0),
+        content: Cow::Borrowed("// start"),
+        kind: FixKind::Safe,
+        message: None,
+    };
 
     #[derive(Debug, Error, Diagnostic)]
     #[error("Multiply")]
     struct InsertAtMiddle;
-    const INSERT_AT_MIDDLE: Fix = Fix { span: Span::new(13, 13), content: Cow::Borrowed("5 *") };
+    const INSERT_AT_MIDDLE: Fix = Fix {
+        span: Span::new(13, 13),
+        content: Cow::Borrowed("5 *"),
+        kind: FixKind::Safe,
+        message: None,
+    };
 
     #[derive(Debug, Error, Diagnostic)]
     #[error("foo")]
     struct ReplaceId;
-    const REPLACE_ID: Fix = Fix { span: Span::new(4, 10), content: Cow::Borrowed("foo") };
+    const REPLACE_ID: Fix = Fix {
+        span: Span::new(4, 10),
+        content: Cow::Borrowed("foo"),
+        kind: FixKind::Safe,
+        message: None,
+    };
     #[derive(Debug, Error, Diagnostic)]
     #[error("let")]
     struct ReplaceVar;
-    const REPLACE_VAR: Fix = Fix { span: Span::new(0, 3), content: Cow::Borrowed("let") };
+    const REPLACE_VAR: Fix = Fix {
+        span: Span::new(0, 3),
+        content: Cow::Borrowed("let"),
+        kind: FixKind::Safe,
+        message: None,
+    };
 
     #[derive(Debug, Error, Diagnostic)]
     #[error("5")]
     struct ReplaceNum;
-    const REPLACE_NUM: Fix = Fix { span: Span::new(13, 14), content: Cow::Borrowed("5") };
+    const REPLACE_NUM: Fix = Fix {
+        span: Span::new(13, 14),
+        content: Cow::Borrowed("5"),
+        kind: FixKind::Safe,
+        message: None,
+    };
 
     #[derive(Debug, Error, Diagnostic)]
     #[error("removestart")]
@@ -171,7 +320,12 @@ mod test {
     #[derive(Debug, Error, Diagnostic)]
     #[error("reversed range")]
     struct ReverseRange;
-    const REVERSE_RANGE: Fix = Fix { span: Span::new(3, 0), content: Cow::Borrowed(" ") };
+    const REVERSE_RANGE: Fix = Fix {
+        span: Span::new(3, 0),
+        content: Cow::Borrowed(" "),
+        kind: FixKind::Safe,
+        message: None,
+    };
 
     #[derive(Debug, Error, Diagnostic)]
     #[error("nofix")]
@@ -370,6 +524,17 @@ mod test {
         assert_eq!(result1.fixed_code, result2.fixed_code);
     }
 
+    #[test]
+    fn apply_the_first_reported_fix_of_the_same_span() {
+        let result = get_fix_result(vec![
+            create_message(ReplaceId, Some(REPLACE_ID)),
+            create_message(RemoveMiddle(Span::default()), Some(Fix::delete(REPLACE_ID.span))),
+        ]);
+        assert_eq!(result.fixed_code, TEST_CODE.replace("answer", "foo"));
+        assert_eq!(result.messages.len(), 1);
+        assert_eq!(result.messages[0].error.to_string(), "removemiddle");
+    }
+
     #[test]
     fn should_not_apply_fix_with_one_no_fix() {
         let result = get_fix_result(vec![create_message(NoFix(Span::default()), None)]);
@@ -392,4 +557,16 @@ mod test {
         assert_eq!(result.messages[1].error.to_string(), "nofix2");
         assert!(result.fixed);
     }
+
+    #[test]
+    fn move_message_by_offset() {
+        let message = Message::new(RemoveMiddle(Span::new(5, 10)).into(), Some(REMOVE_MIDDLE))
+            .with_suggestions(vec![REPLACE_ID])
+            .with_offset(100);
+        assert_eq!((message.start(), message.end()), (105, 110));
+        let label = message.error.labels().unwrap().next().unwrap();
+        assert_eq!((label.offset(), label.len()), (105, 5));
+        assert_eq!(message.fix.unwrap().span, Span::new(105, 110));
+        assert_eq!(message.suggestions[0].span, Span::new(104, 110));
+    }
 }
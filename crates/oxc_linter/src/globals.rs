@@ -74,6 +74,228 @@ pub const BUILTINS: Map<&'static str, bool> = phf_map! {
     "WeakSet" => false
 };
 
+/// Global variables of the environments which can be enabled by the `env` configuration.
+///
+/// The ECMAScript environments share the latest [BUILTINS].
+pub const ENVIRONMENTS: Map<&'static str, Map<&'static str, bool>> = phf_map! {
+    "builtin" => BUILTINS,
+    "es6" => BUILTINS,
+    "es2015" => BUILTINS,
+    "es2016" => BUILTINS,
+    "es2017" => BUILTINS,
+    "es2018" => BUILTINS,
+    "es2019" => BUILTINS,
+    "es2020" => BUILTINS,
+    "es2021" => BUILTINS,
+    "es2022" => BUILTINS,
+    "es2023" => BUILTINS,
+    "es2024" => BUILTINS,
+    "browser" => BROWSER,
+    "node" => NODE,
+    "commonjs" => COMMONJS,
+    "jest" => JEST,
+};
+
+pub const BROWSER: Map<&'static str, bool> = phf_map! {
+    "AbortController" => false,
+    "AbortSignal" => false,
+    "addEventListener" => false,
+    "alert" => false,
+    "atob" => false,
+    "Blob" => false,
+    "BroadcastChannel" => false,
+    "btoa" => false,
+    "caches" => false,
+    "cancelAnimationFrame" => false,
+    "cancelIdleCallback" => false,
+    "clearInterval" => false,
+    "clearTimeout" => false,
+    "close" => false,
+    "closed" => false,
+    "confirm" => false,
+    "console" => false,
+    "Credential" => false,
+    "crypto" => false,
+    "CSS" => false,
+    "customElements" => false,
+    "CustomEvent" => false,
+    "devicePixelRatio" => false,
+    "document" => false,
+    "DOMException" => false,
+    "DOMParser" => false,
+    "Element" => false,
+    "Event" => false,
+    "EventSource" => false,
+    "EventTarget" => false,
+    "fetch" => false,
+    "File" => false,
+    "FileList" => false,
+    "FileReader" => false,
+    "FormData" => false,
+    "frames" => false,
+    "getComputedStyle" => false,
+    "getSelection" => false,
+    "Headers" => false,
+    "history" => false,
+    "HTMLAnchorElement" => false,
+    "HTMLButtonElement" => false,
+    "HTMLCanvasElement" => false,
+    "HTMLDivElement" => false,
+    "HTMLElement" => false,
+    "HTMLFormElement" => false,
+    "HTMLImageElement" => false,
+    "HTMLInputElement" => false,
+    "HTMLSelectElement" => false,
+    "HTMLTextAreaElement" => false,
+    "HTMLVideoElement" => false,
+    "Image" => false,
+    "indexedDB" => false,
+    "innerHeight" => false,
+    "innerWidth" => false,
+    "IntersectionObserver" => false,
+    "Intl" => false,
+    "KeyboardEvent" => false,
+    "localStorage" => false,
+    "location" => false,
+    "matchMedia" => false,
+    "MessageChannel" => false,
+    "MessageEvent" => false,
+    "MouseEvent" => false,
+    "MutationObserver" => false,
+    "name" => false,
+    "navigator" => false,
+    "Node" => false,
+    "NodeList" => false,
+    "Notification" => false,
+    "onblur" => true,
+    "onchange" => true,
+    "onclick" => true,
+    "onerror" => true,
+    "onfocus" => true,
+    "oninput" => true,
+    "onkeydown" => true,
+    "onkeyup" => true,
+    "onload" => true,
+    "onmessage" => true,
+    "onresize" => true,
+    "onscroll" => true,
+    "onsubmit" => true,
+    "onunload" => true,
+    "open" => false,
+    "opener" => false,
+    "outerHeight" => false,
+    "outerWidth" => false,
+    "parent" => false,
+    "performance" => false,
+    "PerformanceObserver" => false,
+    "postMessage" => false,
+    "print" => false,
+    "PromiseRejectionEvent" => false,
+    "prompt" => false,
+    "queueMicrotask" => false,
+    "removeEventListener" => false,
+    "Request" => false,
+    "requestAnimationFrame" => false,
+    "requestIdleCallback" => false,
+    "ResizeObserver" => false,
+    "Response" => false,
+    "screen" => false,
+    "screenX" => false,
+    "screenY" => false,
+    "scroll" => false,
+    "scrollBy" => false,
+    "scrollTo" => false,
+    "scrollX" => false,
+    "scrollY" => false,
+    "self" => false,
+    "sessionStorage" => false,
+    "setInterval" => false,
+    "setTimeout" => false,
+    "ShadowRoot" => false,
+    "status" => false,
+    "structuredClone" => false,
+    "SVGElement" => false,
+    "Text" => false,
+    "TextDecoder" => false,
+    "TextEncoder" => false,
+    "top" => false,
+    "URL" => false,
+    "URLSearchParams" => false,
+    "WebSocket" => false,
+    "window" => false,
+    "Worker" => false,
+    "XMLHttpRequest" => false,
+    "XMLSerializer" => false,
+};
+
+pub const NODE: Map<&'static str, bool> = phf_map! {
+    "__dirname" => false,
+    "__filename" => false,
+    "AbortController" => false,
+    "AbortSignal" => false,
+    "atob" => false,
+    "Blob" => false,
+    "btoa" => false,
+    "Buffer" => false,
+    "clearImmediate" => false,
+    "clearInterval" => false,
+    "clearTimeout" => false,
+    "console" => false,
+    "crypto" => false,
+    "DOMException" => false,
+    "Event" => false,
+    "EventTarget" => false,
+    "exports" => true,
+    "fetch" => false,
+    "FormData" => false,
+    "global" => false,
+    "Headers" => false,
+    "Intl" => false,
+    "MessageChannel" => false,
+    "MessageEvent" => false,
+    "module" => true,
+    "performance" => false,
+    "process" => false,
+    "queueMicrotask" => false,
+    "Request" => false,
+    "require" => true,
+    "Response" => false,
+    "setImmediate" => false,
+    "setInterval" => false,
+    "setTimeout" => false,
+    "structuredClone" => false,
+    "TextDecoder" => false,
+    "TextEncoder" => false,
+    "URL" => false,
+    "URLSearchParams" => false,
+    "WebAssembly" => false,
+};
+
+pub const COMMONJS: Map<&'static str, bool> = phf_map! {
+    "exports" => true,
+    "global" => false,
+    "module" => false,
+    "require" => false,
+};
+
+pub const JEST: Map<&'static str, bool> = phf_map! {
+    "afterAll" => false,
+    "afterEach" => false,
+    "beforeAll" => false,
+    "beforeEach" => false,
+    "describe" => false,
+    "expect" => false,
+    "fit" => false,
+    "it" => false,
+    "jest" => false,
+    "pit" => false,
+    "require" => false,
+    "test" => false,
+    "xdescribe" => false,
+    "xit" => false,
+    "xtest" => false,
+};
+
 pub const PRE_DEFINE_VAR: Map<&'static str, bool> = phf_map! {
     "undefined" => false,
     "Infinity" => false,
@@ -448,3 +670,24 @@ pub const RESERVED_HTML_TAG: phf::Set<&'static str> = phf_set! {
     "title",
     "track",
 };
+
+/// Elements that can't have any children.
+/// Reference: <https://html.spec.whatwg.org/multipage/syntax.html#void-elements>
+pub const VOID_DOM_ELEMENTS: phf::Set<&'static str> = phf_set! {
+    "area",
+    "base",
+    "br",
+    "col",
+    "embed",
+    "hr",
+    "img",
+    "input",
+    "keygen",
+    "link",
+    "menuitem",
+    "meta",
+    "param",
+    "source",
+    "track",
+    "wbr",
+};
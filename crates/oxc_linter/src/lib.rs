@@ -5,6 +5,7 @@
 mod tester;
 
 mod ast_util;
+mod cache;
 mod config;
 mod context;
 mod disable_directives;
@@ -12,24 +13,30 @@ mod fixer;
 mod globals;
 mod options;
 pub mod partial_loader;
+mod result;
 pub mod rule;
 mod rule_timer;
 mod rules;
 mod service;
 mod utils;
 
-use std::{self, fs, io::Write, rc::Rc, time::Duration};
+use std::{self, borrow::Cow, fmt::Write as _, fs, io::Write, path::Path, rc::Rc, time::Duration};
 
 use oxc_diagnostics::Report;
 pub(crate) use oxc_semantic::AstNode;
 use rustc_hash::FxHashMap;
 
+use crate::rule::RULES_METADATA;
+
 pub use crate::{
+    cache::LintCache,
+    config::{ESLintConfig, ESLintEnv, ESLintGlobals, GlobalValue, LintOverride},
     context::LintContext,
     fixer::Fix,
-    fixer::{FixResult, Fixer, Message},
-    options::{AllowWarnDeny, LintOptions},
-    rule::RuleCategory,
+    fixer::{FixKind, FixResult, Fixer, Message},
+    options::{AllowWarnDeny, FixFilter, LintOptions},
+    result::LintResult,
+    rule::{RuleCategory, RuleMetadata},
     service::LintService,
 };
 pub(crate) use rules::{RuleEnum, RULES};
@@ -45,14 +52,23 @@ fn size_asserts() {
     assert_eq_size!(RuleEnum, [u8; 16]);
 }
 
+/// Number of AST nodes visited between two checks of [`Linter::run_with_cancellation`].
+pub const CANCELLATION_CHECK_INTERVAL: usize = 1024;
+
 #[derive(Debug, Clone)]
 pub struct LintSettings {
     jsx_a11y: JsxA11y,
+    env: ESLintEnv,
+    globals: ESLintGlobals,
 }
 
 impl Default for LintSettings {
     fn default() -> Self {
-        Self { jsx_a11y: JsxA11y { polymorphic_prop_name: None, components: FxHashMap::default() } }
+        Self {
+            jsx_a11y: JsxA11y { polymorphic_prop_name: None, components: FxHashMap::default() },
+            env: ESLintEnv::default(),
+            globals: ESLintGlobals::default(),
+        }
     }
 }
 
@@ -72,9 +88,19 @@ impl JsxA11y {
     }
 }
 
+/// An enabled rule with its name and the severity configured for the rule itself.
+///
+/// Without a configured severity, the rule reports with the severity of its category
+/// or else the severity of its diagnostics.
+pub(crate) type LintRule = (/* rule name */ &'static str, RuleEnum, Option<AllowWarnDeny>);
+
 #[derive(Debug)]
 pub struct Linter {
-    rules: Vec<(/* rule name */ &'static str, RuleEnum)>,
+    rules: Vec<LintRule>,
+    /// Severity of the rules of a category, see [`Linter::with_category`]
+    categories: FxHashMap<RuleCategory, AllowWarnDeny>,
+    /// Applied on top of `rules` for the matching files
+    overrides: Vec<LintOverride>,
     options: LintOptions,
     settings: LintSettings,
 }
@@ -91,23 +117,73 @@ impl Linter {
             .iter()
             .filter(|&rule| rule.category() == RuleCategory::Correctness)
             .cloned()
-            .map(|rule| (rule.name(), rule))
+            .map(|rule| (rule.name(), rule, None))
             .collect::<Vec<_>>();
-        Self { rules, options: LintOptions::default(), settings: LintSettings::default() }
+        Self {
+            rules,
+            categories: FxHashMap::default(),
+            overrides: vec![],
+            options: LintOptions::default(),
+            settings: LintSettings::default(),
+        }
     }
 
     /// # Errors
     ///
     /// Returns `Err` if there are any errors parsing the configuration file.
     pub fn from_options(options: LintOptions) -> Result<Self, Report> {
-        let (rules, settings) = options.derive_rules_and_settings()?;
-        let rules = rules.into_iter().map(|rule| (rule.name(), rule)).collect();
-        Ok(Self { rules, options, settings })
+        if let Some(path) = &options.config_path {
+            let config = ESLintConfig::new(path)?;
+            return Ok(Self { options, ..Self::from_config(config) });
+        }
+        let (rules, settings) = options.derive_rules_and_settings();
+        let rules = rules.into_iter().map(|rule| (rule.name(), rule, None)).collect();
+        Ok(Self { rules, categories: FxHashMap::default(), overrides: vec![], options, settings })
+    }
+
+    /// Build a linter from the rules, settings and overrides of a parsed configuration file.
+    pub fn from_config(config: ESLintConfig) -> Self {
+        let (rules, settings, overrides) = config.into_rules().get_config();
+        let rules =
+            rules.into_iter().map(|(rule, severity)| (rule.name(), rule, Some(severity))).collect();
+        Self {
+            rules,
+            categories: FxHashMap::default(),
+            overrides,
+            options: LintOptions::default(),
+            settings,
+        }
     }
 
     #[must_use]
     pub fn with_rules(mut self, rules: Vec<RuleEnum>) -> Self {
-        self.rules = rules.into_iter().map(|rule| (rule.name(), rule)).collect();
+        self.rules = rules.into_iter().map(|rule| (rule.name(), rule, None)).collect();
+        self
+    }
+
+    /// Enable all rules of `category` reporting with `severity`, or turn them off with
+    /// [`AllowWarnDeny::Allow`].
+    ///
+    /// Rules with a severity configured for themselves are left as they are.
+    #[must_use]
+    pub fn with_category(mut self, category: RuleCategory, severity: AllowWarnDeny) -> Self {
+        if severity.is_enabled() {
+            for rule in RULES.iter().filter(|rule| rule.category() == category) {
+                let is_enabled = self.rules.iter().any(|(_, enabled, _)| {
+                    enabled.plugin_name() == rule.plugin_name() && enabled.name() == rule.name()
+                });
+                if !is_enabled {
+                    self.rules.push((rule.name(), rule.clone(), None));
+                }
+            }
+            // for stable diagnostics output ordering
+            self.rules.sort_unstable_by_key(|(name, _, _)| *name);
+        } else {
+            self.rules.retain(|(_, rule, configured)| {
+                configured.is_some() || rule.category() != category
+            });
+        }
+        self.categories.insert(category, severity);
         self
     }
 
@@ -131,37 +207,102 @@ impl Linter {
         self
     }
 
+    #[must_use]
+    pub fn with_fix_kind(mut self, fix_kind: FixFilter) -> Self {
+        self.options.fix_kind = fix_kind;
+        self
+    }
+
+    #[must_use]
+    pub fn with_markdown_plugin(mut self, yes: bool) -> Self {
+        self.options.markdown_plugin = yes;
+        self
+    }
+
     #[must_use]
     pub fn with_print_execution_times(mut self, yes: bool) -> Self {
         self.options.timing = yes;
         self
     }
 
+    /// The rules for `path`, the matching overrides are applied in order on top of the base rules.
+    fn rules_for_path(&self, path: &Path) -> Cow<'_, [LintRule]> {
+        let mut overrides =
+            self.overrides.iter().filter(|lint_override| lint_override.is_match(path)).peekable();
+        if overrides.peek().is_none() {
+            return Cow::Borrowed(&self.rules);
+        }
+        let mut rules = self.rules.clone();
+        for lint_override in overrides {
+            lint_override.apply(&mut rules);
+        }
+        Cow::Owned(rules)
+    }
+
     pub fn run<'a>(&self, ctx: LintContext<'a>) -> Vec<Message<'a>> {
+        self.run_with_cancellation(ctx, || false).unwrap_or_default()
+    }
+
+    /// Same as [`Linter::run`], but gives up and returns `None` once `is_cancelled` returns true.
+    ///
+    /// `is_cancelled` is checked between the rule passes and every [`CANCELLATION_CHECK_INTERVAL`] nodes.
+    pub fn run_with_cancellation<'a, F: Fn() -> bool>(
+        &self,
+        ctx: LintContext<'a>,
+        is_cancelled: F,
+    ) -> Option<Vec<Message<'a>>> {
         let timing = self.options.timing;
         let semantic = Rc::clone(ctx.semantic());
-        let mut ctx = ctx.with_fix(self.options.fix);
+        let rules = self.rules_for_path(ctx.file_path());
+        let rules = rules
+            .iter()
+            .map(|(rule_name, rule, severity)| {
+                let severity = severity.or_else(|| self.categories.get(&rule.category()).copied());
+                (*rule_name, rule, severity.and_then(AllowWarnDeny::severity))
+            })
+            .collect::<Vec<_>>();
+        let mut ctx = ctx
+            .with_fix(self.options.fix || self.options.report_fixes)
+            .with_fix_kind(self.options.fix_kind);
 
-        for (rule_name, rule) in &self.rules {
+        for (rule_name, rule, severity) in &rules {
             ctx.with_rule_name(rule_name);
+            ctx.with_severity(*severity);
             rule.run_once(&ctx, timing);
         }
 
+        if is_cancelled() {
+            return None;
+        }
+
         for symbol in semantic.symbols().iter() {
-            for (rule_name, rule) in &self.rules {
+            for (rule_name, rule, severity) in &rules {
                 ctx.with_rule_name(rule_name);
+                ctx.with_severity(*severity);
                 rule.run_on_symbol(symbol, &ctx, timing);
             }
         }
 
-        for node in semantic.nodes().iter() {
-            for (rule_name, rule) in &self.rules {
+        if is_cancelled() {
+            return None;
+        }
+
+        for (i, node) in semantic.nodes().iter().enumerate() {
+            if i % CANCELLATION_CHECK_INTERVAL == 0 && is_cancelled() {
+                return None;
+            }
+            for (rule_name, rule, severity) in &rules {
                 ctx.with_rule_name(rule_name);
+                ctx.with_severity(*severity);
                 rule.run(node, &ctx, timing);
             }
         }
 
-        ctx.into_message()
+        if self.options.report_unused_directives {
+            ctx.report_unused_directives();
+        }
+
+        Some(ctx.into_message())
     }
 
     pub fn get_settings(&self) -> LintSettings {
@@ -196,6 +337,43 @@ impl Linter {
         writeln!(writer, "Total: {}", RULES.len()).unwrap();
     }
 
+    /// Metadata of all registered rules, in the order they are declared.
+    pub fn rules_metadata() -> &'static [RuleMetadata] {
+        &RULES_METADATA
+    }
+
+    /// A markdown document listing all rules in a table, followed by their documentation.
+    pub fn rules_markdown() -> String {
+        let mut rules = RULES_METADATA.iter().collect::<Vec<_>>();
+        rules.sort_unstable_by(|a, b| a.scoped_name.cmp(&b.scoped_name));
+
+        let mut markdown = String::from("# Rules\n\n");
+        markdown.push_str("| Rule | Category | Fixable | Description |\n");
+        markdown.push_str("| ---- | -------- | ------- | ----------- |\n");
+        for rule in &rules {
+            // GitHub anchors drop punctuation, `jsx-a11y/alt-text` becomes `#jsx-a11yalt-text`
+            let anchor = rule
+                .scoped_name
+                .chars()
+                .filter(|c| c.is_alphanumeric() || matches!(c, '-' | '_'))
+                .collect::<String>();
+            let fixable = if rule.fixable { "🛠️" } else { "" };
+            let summary = rule.summary.replace('|', "\\|");
+            writeln!(
+                markdown,
+                "| [{}](#{anchor}) | {} | {fixable} | {summary} |",
+                rule.scoped_name, rule.category
+            )
+            .unwrap();
+        }
+        writeln!(markdown, "\nTotal: {}", rules.len()).unwrap();
+
+        for rule in rules {
+            write!(markdown, "\n## {}\n\n{}", rule.scoped_name, rule.documentation).unwrap();
+        }
+        markdown
+    }
+
     #[allow(clippy::print_stdout)]
     pub fn print_execution_times_if_enable(&self) {
         if !self.options.timing {
@@ -204,7 +382,7 @@ impl Linter {
         let mut timings = self
             .rules
             .iter()
-            .map(|(rule_name, rule)| (rule_name, rule.execute_time()))
+            .map(|(rule_name, rule, _)| (rule_name, rule.execute_time()))
             .collect::<Vec<_>>();
 
         timings.sort_by_key(|x| x.1);
@@ -223,7 +401,19 @@ impl Linter {
 
 #[cfg(test)]
 mod test {
-    use super::Linter;
+    use std::{cell::Cell, path::Path, rc::Rc};
+
+    use oxc_allocator::Allocator;
+    use oxc_parser::Parser;
+    use oxc_semantic::SemanticBuilder;
+    use oxc_span::SourceType;
+
+    use oxc_diagnostics::Severity;
+
+    use super::{
+        AllowWarnDeny, FixFilter, FixKind, LintContext, LintSettings, Linter, Message,
+        RuleCategory, RULES,
+    };
 
     #[test]
     fn print_rules() {
@@ -231,4 +421,107 @@ mod test {
         Linter::print_rules(&mut writer);
         assert!(!writer.is_empty());
     }
+
+    #[test]
+    fn run_with_cancellation() {
+        let allocator = Allocator::default();
+        let source_text = "debugger;";
+        let source_type = SourceType::default();
+        let ret = Parser::new(&allocator, source_text, source_type).parse();
+        let program = allocator.alloc(ret.program);
+        let semantic = Rc::new(
+            SemanticBuilder::new(source_text, source_type)
+                .with_trivias(ret.trivias)
+                .build(program)
+                .semantic,
+        );
+        let ctx =
+            || LintContext::new(Path::new("test.js").into(), &semantic, LintSettings::default());
+
+        let linter = Linter::new();
+        assert_eq!(linter.run_with_cancellation(ctx(), || false).map(|m| m.len()), Some(1));
+
+        // cancelled after the first rule pass
+        let checks = Cell::new(0);
+        let messages = linter.run_with_cancellation(ctx(), || {
+            checks.set(checks.get() + 1);
+            checks.get() > 1
+        });
+        assert!(messages.is_none());
+        assert_eq!(checks.get(), 2);
+    }
+
+    #[test]
+    fn with_category() {
+        let allocator = Allocator::default();
+        let source_text = "debugger; let foo = null;";
+        let source_type = SourceType::default();
+        let ret = Parser::new(&allocator, source_text, source_type).parse();
+        let program = allocator.alloc(ret.program);
+        let semantic = Rc::new(
+            SemanticBuilder::new(source_text, source_type)
+                .with_trivias(ret.trivias)
+                .build(program)
+                .semantic,
+        );
+        let severity_of = |linter: &Linter, rule_name: &str| {
+            let ctx =
+                LintContext::new(Path::new("test.js").into(), &semantic, LintSettings::default());
+            linter
+                .run(ctx)
+                .into_iter()
+                .find(|message| message.rule_name() == Some(rule_name))
+                .map(|message| message.error.severity().unwrap_or(Severity::Error))
+        };
+
+        // without a configured severity, diagnostics keep their own
+        let linter = Linter::new();
+        assert_eq!(severity_of(&linter, "no-debugger"), Some(Severity::Warning));
+        assert_eq!(severity_of(&linter, "no-null"), None);
+
+        let linter = Linter::new()
+            .with_category(RuleCategory::Correctness, AllowWarnDeny::Deny)
+            .with_category(RuleCategory::Style, AllowWarnDeny::Warn);
+        assert_eq!(severity_of(&linter, "no-debugger"), Some(Severity::Error));
+        assert_eq!(severity_of(&linter, "no-null"), Some(Severity::Warning));
+
+        let linter = linter.with_category(RuleCategory::Correctness, AllowWarnDeny::Allow);
+        assert_eq!(severity_of(&linter, "no-debugger"), None);
+        assert!(linter.rules.iter().all(|(_, rule, _)| rule.category() == RuleCategory::Style));
+    }
+
+    #[test]
+    fn fix_kind() {
+        let allocator = Allocator::default();
+        let source_text = "debugger;\nnull == null;";
+        let source_type = SourceType::default();
+        let ret = Parser::new(&allocator, source_text, source_type).parse();
+        let program = allocator.alloc(ret.program);
+        let semantic = Rc::new(
+            SemanticBuilder::new(source_text, source_type)
+                .with_trivias(ret.trivias)
+                .build(program)
+                .semantic,
+        );
+        let rules = RULES
+            .iter()
+            .filter(|rule| matches!(rule.name(), "no-debugger" | "eqeqeq"))
+            .cloned()
+            .collect::<Vec<_>>();
+        let fixed = |fix_kind| {
+            let ctx =
+                LintContext::new(Path::new("test.js").into(), &semantic, LintSettings::default());
+            let linter =
+                Linter::new().with_rules(rules.clone()).with_fix(true).with_fix_kind(fix_kind);
+            let messages = linter.run(ctx);
+            assert!(messages.iter().all(Message::is_fixable));
+            messages.iter().filter_map(|m| m.fix.as_ref().map(|fix| fix.kind)).collect::<Vec<_>>()
+        };
+
+        // the dangerous fix of `eqeqeq` is only applied with `FixFilter::All`
+        assert_eq!(fixed(FixFilter::Safe), vec![FixKind::Safe]);
+        let mut kinds = fixed(FixFilter::All);
+        kinds.sort_unstable_by_key(|kind| *kind == FixKind::Dangerous);
+        assert_eq!(kinds, vec![FixKind::Safe, FixKind::Dangerous]);
+    }
 }
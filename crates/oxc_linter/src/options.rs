@@ -1,17 +1,14 @@
-use std::path::PathBuf;
+use std::{path::PathBuf, str::FromStr};
 
 use crate::{
-    config::{
-        errors::{
-            FailedToParseAllowWarnDenyFromJsonValueError,
-            FailedToParseAllowWarnDenyFromNumberError, FailedToParseAllowWarnDenyFromStringError,
-        },
-        ESLintConfig,
+    config::errors::{
+        FailedToParseAllowWarnDenyFromJsonValueError, FailedToParseAllowWarnDenyFromNumberError,
+        FailedToParseAllowWarnDenyFromStringError,
     },
     rules::RULES,
-    LintSettings, RuleCategory, RuleEnum,
+    FixKind, LintSettings, RuleCategory, RuleEnum,
 };
-use oxc_diagnostics::{Error, Report};
+use oxc_diagnostics::{Error, Severity};
 use rustc_hash::FxHashSet;
 use serde_json::{Number, Value};
 
@@ -22,10 +19,22 @@ pub struct LintOptions {
     pub filter: Vec<(AllowWarnDeny, String)>,
     pub config_path: Option<PathBuf>,
     pub fix: bool,
+    /// Which fixes are applied with `fix`
+    pub fix_kind: FixFilter,
+    /// Report the fixes along with the diagnostics, they are only applied with `fix`
+    pub report_fixes: bool,
     pub timing: bool,
     pub import_plugin: bool,
     pub jest_plugin: bool,
     pub jsx_a11y_plugin: bool,
+    /// Lint the JavaScript and TypeScript code blocks of Markdown files
+    pub markdown_plugin: bool,
+    /// Report `eslint-disable` directives which did not suppress any diagnostic
+    pub report_unused_directives: bool,
+    /// Number of warnings allowed before the run is considered failed, see [crate::LintResult]
+    pub max_warnings: Option<usize>,
+    /// Location of the [crate::LintCache] file, caching is disabled when `None`
+    pub cache_location: Option<PathBuf>,
 }
 
 impl Default for LintOptions {
@@ -34,10 +43,16 @@ impl Default for LintOptions {
             filter: vec![(AllowWarnDeny::Deny, String::from("correctness"))],
             config_path: None,
             fix: false,
+            fix_kind: FixFilter::default(),
+            report_fixes: false,
             timing: false,
             import_plugin: false,
             jest_plugin: false,
             jsx_a11y_plugin: false,
+            markdown_plugin: false,
+            report_unused_directives: false,
+            max_warnings: None,
+            cache_location: None,
         }
     }
 }
@@ -63,6 +78,18 @@ impl LintOptions {
         self
     }
 
+    #[must_use]
+    pub fn with_fix_kind(mut self, fix_kind: FixFilter) -> Self {
+        self.fix_kind = fix_kind;
+        self
+    }
+
+    #[must_use]
+    pub fn with_report_fixes(mut self, yes: bool) -> Self {
+        self.report_fixes = yes;
+        self
+    }
+
     #[must_use]
     pub fn with_timing(mut self, yes: bool) -> Self {
         self.timing = yes;
@@ -86,6 +113,57 @@ impl LintOptions {
         self.jsx_a11y_plugin = yes;
         self
     }
+
+    #[must_use]
+    pub fn with_markdown_plugin(mut self, yes: bool) -> Self {
+        self.markdown_plugin = yes;
+        self
+    }
+
+    #[must_use]
+    pub fn with_report_unused_directives(mut self, yes: bool) -> Self {
+        self.report_unused_directives = yes;
+        self
+    }
+
+    #[must_use]
+    pub fn with_max_warnings(mut self, max_warnings: Option<usize>) -> Self {
+        self.max_warnings = max_warnings;
+        self
+    }
+
+    #[must_use]
+    pub fn with_cache_location(mut self, cache_location: Option<PathBuf>) -> Self {
+        self.cache_location = cache_location;
+        self
+    }
+}
+
+/// The kinds of fixes to apply, see [FixKind].
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum FixFilter {
+    #[default]
+    Safe,
+    /// Dangerous fixes as well
+    All,
+}
+
+impl FixFilter {
+    pub fn allows(self, kind: FixKind) -> bool {
+        self == Self::All || kind == FixKind::Safe
+    }
+}
+
+impl FromStr for FixFilter {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "safe" => Ok(Self::Safe),
+            "all" => Ok(Self::All),
+            _ => Err(format!("'{s}' is not a known fix kind, expected 'safe' or 'all'")),
+        }
+    }
 }
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
@@ -99,6 +177,15 @@ impl AllowWarnDeny {
     pub fn is_enabled(self) -> bool {
         self != Self::Allow
     }
+
+    /// Severity of the reported diagnostics, `None` when turned off
+    pub fn severity(self) -> Option<Severity> {
+        match self {
+            Self::Allow => None,
+            Self::Warn => Some(Severity::Warning),
+            Self::Deny => Some(Severity::Error),
+        }
+    }
 }
 
 impl TryFrom<&str> for AllowWarnDeny {
@@ -143,16 +230,10 @@ const JEST_PLUGIN_NAME: &str = "jest";
 const JSX_A11Y_PLUGIN_NAME: &str = "jsx_a11y";
 
 impl LintOptions {
-    /// # Errors
-    /// Returns `Err` if there are any errors parsing the configuration file.
-    pub fn derive_rules_and_settings(&self) -> Result<(Vec<RuleEnum>, LintSettings), Report> {
+    /// Rules selected by `filter` and the plugin options, used when there is no `config_path`.
+    pub fn derive_rules_and_settings(&self) -> (Vec<RuleEnum>, LintSettings) {
         let mut rules: FxHashSet<RuleEnum> = FxHashSet::default();
 
-        if let Some(path) = &self.config_path {
-            let (rules, settings) = ESLintConfig::new(path)?.into_rules().get_config();
-            return Ok((rules, settings));
-        }
-
         let all_rules = self.get_filtered_rules();
 
         for (allow_warn_deny, name_or_category) in &self.filter {
@@ -195,7 +276,7 @@ impl LintOptions {
         let mut rules = rules.into_iter().collect::<Vec<_>>();
         // for stable diagnostics output ordering
         rules.sort_unstable_by_key(RuleEnum::name);
-        Ok((rules, LintSettings::default()))
+        (rules, LintSettings::default())
     }
 
     // get final filtered rules by reading `self.jest_plugin` and `self.jsx_a11y_plugin`
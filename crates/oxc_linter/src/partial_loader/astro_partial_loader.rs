@@ -0,0 +1,234 @@
+use oxc_span::Span;
+
+use super::PartialLoaderValue;
+
+const FRONTMATTER_FENCE: &str = "---";
+const SCRIPT_START: &str = "<script";
+const SCRIPT_END: &str = "</script>";
+
+/// Extracts the frontmatter and the `<script>` tags of an `.astro` file.
+///
+/// Everything else is replaced by spaces, so the offsets of the extracted code are the same as in
+/// the original file. The frontmatter is always TypeScript, and so are the scripts processed by
+/// Astro. `is:inline` scripts are left as they are by Astro, but they are linted all the same.
+pub struct AstroPartialLoader<'a> {
+    source_text: &'a str,
+}
+
+impl<'a> AstroPartialLoader<'a> {
+    pub fn from(source_text: &'a str) -> Self {
+        Self { source_text }
+    }
+
+    pub fn build(self) -> PartialLoaderValue {
+        let frontmatter = self.parse_frontmatter();
+        let template_start = frontmatter.map_or(0, |span| span.end as usize);
+        let spans = frontmatter.into_iter().chain(self.parse_scripts(template_start));
+
+        let mut code = self
+            .source_text
+            .bytes()
+            .map(|byte| if matches!(byte, b'\n' | b'\r') { byte } else { b' ' })
+            .collect::<Vec<_>>();
+        for span in spans {
+            let (start, end) = (span.start as usize, span.end as usize);
+            code[start..end].copy_from_slice(&self.source_text.as_bytes()[start..end]);
+        }
+        // SAFETY: the spans start and end at ASCII delimiters, so the copied code is valid UTF-8
+        // and every other byte is replaced by an ASCII character.
+        let js_content = unsafe { String::from_utf8_unchecked(code) };
+        PartialLoaderValue::from(js_content, true, false, 0)
+    }
+
+    /// The code between the `---` fences, which must be at the start of the file.
+    fn parse_frontmatter(&self) -> Option<Span> {
+        let start = self.source_text.len() - self.source_text.trim_start().len();
+        if !self.source_text[start..].starts_with(FRONTMATTER_FENCE) {
+            return None;
+        }
+        let start = start + FRONTMATTER_FENCE.len();
+        let end = self.find_frontmatter_end(start)?;
+        Some(span(start, end))
+    }
+
+    /// The offset of the closing fence, a `---` at the start of a line outside of strings and
+    /// comments.
+    fn find_frontmatter_end(&self, start: usize) -> Option<usize> {
+        let bytes = self.source_text.as_bytes();
+        let mut pos = start;
+        while pos < bytes.len() {
+            match bytes[pos] {
+                b'\n' => {
+                    pos += 1;
+                    if self.source_text[pos..].starts_with(FRONTMATTER_FENCE) {
+                        return Some(pos);
+                    }
+                }
+                b'\'' | b'"' => pos = skip_string(bytes, pos),
+                b'`' => pos = skip_template_literal(bytes, pos),
+                b'/' if bytes.get(pos + 1) == Some(&b'/') => {
+                    pos = self.source_text[pos..].find('\n').map_or(bytes.len(), |i| pos + i);
+                }
+                b'/' if bytes.get(pos + 1) == Some(&b'*') => {
+                    pos =
+                        self.source_text[pos + 2..].find("*/").map_or(bytes.len(), |i| pos + i + 4);
+                }
+                _ => pos += 1,
+            }
+        }
+        None
+    }
+
+    /// The content of the `<script>` tags in the template
+    fn parse_scripts(&self, start: usize) -> Vec<Span> {
+        let bytes = self.source_text.as_bytes();
+        let mut spans = vec![];
+        let mut pos = start;
+        while let Some(offset) = self.source_text[pos..].find(SCRIPT_START) {
+            let tag_start = pos + offset + SCRIPT_START.len();
+            pos = tag_start;
+            // e.g. `<scripts>`
+            if !bytes.get(tag_start).is_some_and(|&b| b == b'>' || b.is_ascii_whitespace()) {
+                continue;
+            }
+            let Some(offset) = self.source_text[tag_start..].find('>') else { break };
+            let tag_end = tag_start + offset;
+            pos = tag_end + 1;
+            // e.g. `<script src="./foo.js" />`
+            if bytes[tag_end - 1] == b'/' {
+                continue;
+            }
+            let Some(offset) = self.source_text[pos..].find(SCRIPT_END) else { break };
+            spans.push(span(pos, pos + offset));
+            pos += offset + SCRIPT_END.len();
+        }
+        spans
+    }
+}
+
+#[allow(clippy::cast_possible_truncation)]
+fn span(start: usize, end: usize) -> Span {
+    Span::new(start as u32, end as u32)
+}
+
+/// The offset after the closing quote, or of the end of the line for an unterminated string
+fn skip_string(bytes: &[u8], start: usize) -> usize {
+    let quote = bytes[start];
+    let mut pos = start + 1;
+    while pos < bytes.len() {
+        match bytes[pos] {
+            b'\\' => pos += 2,
+            b'\n' => return pos,
+            b if b == quote => return pos + 1,
+            _ => pos += 1,
+        }
+    }
+    pos
+}
+
+/// The offset after the closing backtick
+fn skip_template_literal(bytes: &[u8], start: usize) -> usize {
+    let mut pos = start + 1;
+    while pos < bytes.len() {
+        match bytes[pos] {
+            b'\\' => pos += 2,
+            b'`' => return pos + 1,
+            b'$' if bytes.get(pos + 1) == Some(&b'{') => {
+                pos = skip_template_expression(bytes, pos + 2);
+            }
+            _ => pos += 1,
+        }
+    }
+    pos
+}
+
+/// The offset after the `}` closing a `${` in a template literal
+fn skip_template_expression(bytes: &[u8], start: usize) -> usize {
+    let mut brace_depth = 0;
+    let mut pos = start;
+    while pos < bytes.len() {
+        match bytes[pos] {
+            b'{' => {
+                brace_depth += 1;
+                pos += 1;
+            }
+            b'}' if brace_depth == 0 => return pos + 1,
+            b'}' => {
+                brace_depth -= 1;
+                pos += 1;
+            }
+            b'\'' | b'"' => pos = skip_string(bytes, pos),
+            b'`' => pos = skip_template_literal(bytes, pos),
+            _ => pos += 1,
+        }
+    }
+    pos
+}
+
+#[cfg(test)]
+mod test {
+    use super::AstroPartialLoader;
+
+    fn parse(source_text: &str) -> String {
+        let loader_value = AstroPartialLoader::from(source_text).build();
+        assert!(loader_value.source_type.is_typescript());
+        assert!(loader_value.source_type.is_module());
+        assert_eq!(loader_value.source_text.len(), source_text.len());
+        loader_value.source_text
+    }
+
+    /// The extracted code without the blank space around it
+    fn code(source_text: &str) -> Vec<String> {
+        parse(source_text).split_whitespace().map(ToString::to_string).collect()
+    }
+
+    #[test]
+    fn test_frontmatter_and_scripts() {
+        let source_text = include_str!("../../fixtures/astro/index.astro");
+        let loader_value = parse(source_text);
+        // offsets are preserved
+        for needle in ["const title: string", "console.log(document.title)", "window.title"] {
+            assert_eq!(loader_value.find(needle), source_text.find(needle), "{needle}");
+        }
+        assert!(!loader_value.contains("<h1>"));
+        assert!(!loader_value.contains("---"));
+        assert_eq!(loader_value.lines().count(), source_text.lines().count());
+    }
+
+    #[test]
+    fn test_no_frontmatter() {
+        let source_text = include_str!("../../fixtures/astro/no_frontmatter.astro");
+        assert_eq!(code(source_text), vec!["console.log(1);"]);
+    }
+
+    #[test]
+    fn test_inline_script() {
+        let source_text = include_str!("../../fixtures/astro/inline_script.astro");
+        assert_eq!(code(source_text), vec!["let", "a", "=", "1;", "let", "b", "=", "2;"]);
+    }
+
+    #[test]
+    fn test_fence_in_template_literal() {
+        let source_text = include_str!("../../fixtures/astro/fence_in_string.astro");
+        let loader_value = parse(source_text);
+        assert!(loader_value.contains("const separator = `\n---\n`;"));
+        assert!(loader_value.contains("/*\n---\n*/"));
+        assert!(loader_value.contains("const b = 1;"));
+        assert!(!loader_value.contains("<p>"));
+    }
+
+    #[test]
+    fn test_ignored_tags() {
+        let source_text = r#"
+            <scripts>let a = 1;</scripts>
+            <script src="./foo.js" />
+            <script>let b = 2;</script>
+        "#;
+        assert_eq!(code(source_text), vec!["let", "b", "=", "2;"]);
+    }
+
+    #[test]
+    fn test_unterminated_frontmatter() {
+        assert_eq!(code("---\nlet a = 1;\n"), Vec::<String>::new());
+    }
+}
@@ -0,0 +1,184 @@
+use oxc_span::SourceType;
+
+use super::PartialLoaderValue;
+
+const SCRIPT_START: &str = "<script";
+const SCRIPT_END: &str = "</script";
+const COMMENT_START: &str = "<!--";
+const COMMENT_END: &str = "-->";
+
+/// Extracts the inline `<script>` elements of an `.html` file.
+///
+/// Scripts with a `src` attribute are loaded from another file, and scripts of other types than
+/// JavaScript (e.g. `application/json` or `importmap`) are data blocks, neither is linted.
+/// `type="module"` scripts are modules, the others are classic scripts.
+pub struct HtmlPartialLoader<'a> {
+    source_text: &'a str,
+    /// The lowercase `source_text`, tag names are case insensitive
+    lowercase_text: String,
+}
+
+impl<'a> HtmlPartialLoader<'a> {
+    pub fn from(source_text: &'a str) -> Self {
+        Self { source_text, lowercase_text: source_text.to_ascii_lowercase() }
+    }
+
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn build(self) -> Vec<PartialLoaderValue> {
+        let mut values = vec![];
+        let mut pos = 0;
+        while let Some(tag_start) = self.find_script(pos) {
+            let attributes_start = tag_start + SCRIPT_START.len();
+            let Some(tag_end) = find_tag_end(self.source_text, attributes_start) else { break };
+            let attributes = &self.source_text[attributes_start..tag_end];
+            pos = tag_end + 1;
+            let Some(offset) = self.lowercase_text[pos..].find(SCRIPT_END) else { break };
+            let (start, end) = (pos, pos + offset);
+            pos = end + SCRIPT_END.len();
+            if let Some(source_type) = script_source_type(attributes) {
+                values.push(PartialLoaderValue {
+                    source_text: self.source_text[start..end].to_string(),
+                    source_type,
+                    start: start as u32,
+                });
+            }
+        }
+        values
+    }
+
+    /// The offset of the next `<script` tag from `pos`, outside of comments
+    fn find_script(&self, mut pos: usize) -> Option<usize> {
+        loop {
+            let text = &self.lowercase_text[pos..];
+            let script = text.find(SCRIPT_START)?;
+            match text.find(COMMENT_START) {
+                Some(comment) if comment < script => {
+                    let comment_start = pos + comment + COMMENT_START.len();
+                    let comment_end = self.lowercase_text[comment_start..].find(COMMENT_END)?;
+                    pos = comment_start + comment_end + COMMENT_END.len();
+                }
+                _ => {
+                    let tag_start = pos + script;
+                    // e.g. `<scripts>`
+                    let after_tag = self.source_text.as_bytes().get(tag_start + SCRIPT_START.len());
+                    if after_tag.is_some_and(|&b| b == b'>' || b == b'/' || b.is_ascii_whitespace())
+                    {
+                        return Some(tag_start);
+                    }
+                    pos = tag_start + SCRIPT_START.len();
+                }
+            }
+        }
+    }
+}
+
+/// The offset of the `>` closing the tag, skipping quoted attribute values
+fn find_tag_end(source_text: &str, start: usize) -> Option<usize> {
+    let mut quote = None;
+    for (i, b) in source_text.bytes().enumerate().skip(start) {
+        match (quote, b) {
+            (None, b'"' | b'\'') => quote = Some(b),
+            (None, b'>') => return Some(i),
+            (Some(q), b) if q == b => quote = None,
+            _ => {}
+        }
+    }
+    None
+}
+
+/// The source type of a script with `attributes`, `None` if it is not linted
+fn script_source_type(attributes: &str) -> Option<SourceType> {
+    let attributes = parse_attributes(attributes);
+    let value = |name: &str| attributes.iter().find(|(n, _)| n == name).map(|(_, v)| *v);
+    if value("src").is_some() {
+        return None;
+    }
+    let script_type = value("type").unwrap_or_default().trim().to_ascii_lowercase();
+    match script_type.as_str() {
+        "module" => Some(SourceType::default().with_module(true)),
+        ""
+        | "text/javascript"
+        | "application/javascript"
+        | "application/ecmascript"
+        | "text/ecmascript" => Some(SourceType::default().with_module(false)),
+        _ => None,
+    }
+}
+
+/// The lowercase names and the values of the attributes of a tag, `""` for attributes without
+/// a value
+fn parse_attributes(text: &str) -> Vec<(String, &str)> {
+    let mut attributes = vec![];
+    let mut rest = text.trim_start_matches(|c: char| c.is_ascii_whitespace() || c == '/');
+    while !rest.is_empty() {
+        let name_end = rest
+            .find(|c: char| c.is_ascii_whitespace() || c == '=' || c == '/')
+            .unwrap_or(rest.len());
+        let name = rest[..name_end].to_ascii_lowercase();
+        rest = rest[name_end..].trim_start();
+        let mut value = "";
+        if let Some(after_eq) = rest.strip_prefix('=') {
+            let after_eq = after_eq.trim_start();
+            let (v, r) = if let Some(quote @ ('"' | '\'')) = after_eq.chars().next() {
+                let inner = &after_eq[1..];
+                let end = inner.find(quote).unwrap_or(inner.len());
+                (&inner[..end], inner.get(end + 1..).unwrap_or_default())
+            } else {
+                let end =
+                    after_eq.find(|c: char| c.is_ascii_whitespace()).unwrap_or(after_eq.len());
+                (&after_eq[..end], &after_eq[end..])
+            };
+            value = v;
+            rest = r;
+        }
+        if !name.is_empty() {
+            attributes.push((name, value));
+        }
+        rest = rest.trim_start_matches(|c: char| c.is_ascii_whitespace() || c == '/');
+    }
+    attributes
+}
+
+#[cfg(test)]
+mod test {
+    use super::HtmlPartialLoader;
+
+    #[test]
+    fn test_module_and_classic_scripts() {
+        let source_text = include_str!("../../fixtures/html/index.html");
+        let values = HtmlPartialLoader::from(source_text).build();
+        assert_eq!(values.len(), 2);
+
+        assert!(values[0].source_type.is_module());
+        assert!(values[0].source_text.contains("import { a } from './a.js';"));
+        assert!(!values[1].source_type.is_module());
+        assert!(values[1].source_text.contains("var b = NaN;"));
+
+        for value in &values {
+            let start = value.start as usize;
+            assert_eq!(&source_text[start..value.end() as usize], value.source_text);
+            assert!(!value.source_type.is_typescript());
+        }
+    }
+
+    #[test]
+    fn test_ignored_scripts() {
+        let source_text = r#"
+            <script src="./a.js"></script>
+            <script type="application/json">{ "a": 1 }</script>
+            <script type="importmap">{ "imports": {} }</script>
+            <!-- <script>let a = 1;</script> -->
+            <scripts>let b = 2;</scripts>
+            <SCRIPT TYPE='text/javascript' data-x="a > b">let c = 3;</SCRIPT>
+        "#;
+        let values = HtmlPartialLoader::from(source_text).build();
+        assert_eq!(values.len(), 1);
+        assert_eq!(values[0].source_text, "let c = 3;");
+        assert_eq!(values[0].start as usize, source_text.find("let c").unwrap());
+    }
+
+    #[test]
+    fn test_unterminated_script() {
+        assert!(HtmlPartialLoader::from("<script>let a = 1;").build().is_empty());
+    }
+}
@@ -0,0 +1,198 @@
+use oxc_span::SourceType;
+
+use super::PartialLoaderValue;
+
+/// Extracts the fenced code blocks of a Markdown file written in JavaScript or TypeScript.
+///
+/// Each block is the text between its fences, the indentation of fences nested in list items is
+/// kept, so the offsets of the block are the same as in the original file.
+pub struct MarkdownPartialLoader<'a> {
+    source_text: &'a str,
+}
+
+impl<'a> MarkdownPartialLoader<'a> {
+    pub fn from(source_text: &'a str) -> Self {
+        Self { source_text }
+    }
+
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn build(self) -> Vec<PartialLoaderValue> {
+        let mut values = vec![];
+        let mut lines = self.lines();
+        while let Some((offset, line)) = lines.next() {
+            let Some(fence) = Fence::parse(line) else { continue };
+            let start = offset + line.len();
+            // An unclosed fence runs to the end of the file
+            let end = lines
+                .by_ref()
+                .find(|(_, line)| fence.is_closed_by(line))
+                .map_or(self.source_text.len(), |(offset, _)| offset);
+            let Some(source_type) = fence.source_type else { continue };
+            values.push(PartialLoaderValue {
+                source_text: self.source_text[start..end].to_string(),
+                source_type,
+                start: start as u32,
+            });
+        }
+        values
+    }
+
+    /// Each line with its line break, and the offset where it starts
+    fn lines(&self) -> impl Iterator<Item = (usize, &'a str)> {
+        self.source_text.split_inclusive('\n').scan(0, |offset, line| {
+            let start = *offset;
+            *offset += line.len();
+            Some((start, line))
+        })
+    }
+}
+
+/// The opening line of a fenced code block, e.g. ```` ```ts ```` or `~~~~jsx`
+struct Fence {
+    char: char,
+    len: usize,
+    /// `None` for languages other than JavaScript and TypeScript
+    source_type: Option<SourceType>,
+}
+
+impl Fence {
+    fn parse(line: &str) -> Option<Self> {
+        let line = line.trim();
+        let char = line.chars().next().filter(|c| matches!(c, '`' | '~'))?;
+        let len = line.chars().take_while(|&c| c == char).count();
+        if len < 3 {
+            return None;
+        }
+        let info = &line[len..];
+        // e.g. ``` `code` ``` is inline code
+        if char == '`' && info.contains('`') {
+            return None;
+        }
+        let language = info.split_whitespace().next().unwrap_or_default();
+        let extension = match language {
+            "js" | "javascript" => "js",
+            "jsx" => "jsx",
+            "ts" | "typescript" => "ts",
+            "tsx" => "tsx",
+            _ => return Some(Self { char, len, source_type: None }),
+        };
+        // `module_kind` should be `ModuleKind::Module` for allow `import`
+        let source_type =
+            SourceType::from_path(format!("block.{extension}")).ok().map(|t| t.with_module(true));
+        Some(Self { char, len, source_type })
+    }
+
+    /// The closing fence is made of at least as many of the same characters as the opening fence
+    fn is_closed_by(&self, line: &str) -> bool {
+        let line = line.trim();
+        line.len() >= self.len && line.chars().all(|c| c == self.char)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::MarkdownPartialLoader;
+
+    #[test]
+    fn test_multiple_fences() {
+        let source_text = r"
+# Title
+
+```js
+let a = 1;
+```
+
+Some text.
+
+```ts
+let b: number = 2;
+```
+
+```tsx
+const c = <div />;
+```
+";
+        let values = MarkdownPartialLoader::from(source_text).build();
+        assert_eq!(values.len(), 3);
+
+        assert_eq!(values[0].source_text, "let a = 1;\n");
+        assert!(!values[0].source_type.is_typescript());
+        assert!(values[0].source_type.is_module());
+
+        assert_eq!(values[1].source_text, "let b: number = 2;\n");
+        assert!(values[1].source_type.is_typescript());
+        assert!(!values[1].source_type.is_jsx());
+
+        assert_eq!(values[2].source_text, "const c = <div />;\n");
+        assert!(values[2].source_type.is_typescript());
+        assert!(values[2].source_type.is_jsx());
+
+        for value in &values {
+            let start = value.start as usize;
+            assert_eq!(&source_text[start..value.end() as usize], value.source_text);
+        }
+    }
+
+    #[test]
+    fn test_indented_fences_in_list_items() {
+        let source_text = r"
+1. Install it
+
+   ```javascript
+   import a from 'a';
+   ```
+
+   - Use it
+
+     ```typescript
+     a(1 as number);
+     ```
+";
+        let values = MarkdownPartialLoader::from(source_text).build();
+        assert_eq!(values.len(), 2);
+        assert_eq!(values[0].source_text.trim(), "import a from 'a';");
+        assert_eq!(values[0].start as usize, source_text.find("   import").unwrap());
+        assert_eq!(values[1].source_text.trim(), "a(1 as number);");
+        assert!(values[1].source_type.is_typescript());
+        assert_eq!(values[1].start as usize, source_text.find("     a(1").unwrap());
+    }
+
+    #[test]
+    fn test_other_languages() {
+        let source_text = r"
+```sh
+npm install
+```
+
+````md
+```js
+let a = 1;
+```
+````
+
+```
+let b = 2;
+```
+
+~~~jsx
+let c = <a />;
+~~~
+";
+        let values = MarkdownPartialLoader::from(source_text).build();
+        assert_eq!(values.len(), 1);
+        assert_eq!(values[0].source_text, "let c = <a />;\n");
+    }
+
+    #[test]
+    fn test_unclosed_fence() {
+        let values = MarkdownPartialLoader::from("```js\nlet a = 1;\n").build();
+        assert_eq!(values.len(), 1);
+        assert_eq!(values[0].source_text, "let a = 1;\n");
+        assert_eq!(values[0].start, 6);
+    }
+
+    #[test]
+    fn test_inline_code() {
+        assert!(MarkdownPartialLoader::from("``` `js` ```\nlet a = 1;\n").build().is_empty());
+    }
+}
@@ -1,35 +1,74 @@
 use oxc_span::SourceType;
 
-use self::vue_partial_loader::VuePartialLoader;
+use crate::LintOptions;
 
+use self::{
+    astro_partial_loader::AstroPartialLoader, html_partial_loader::HtmlPartialLoader,
+    markdown_partial_loader::MarkdownPartialLoader, vue_partial_loader::VuePartialLoader,
+};
+
+pub mod astro_partial_loader;
+pub mod html_partial_loader;
+pub mod markdown_partial_loader;
 pub mod vue_partial_loader;
 
-pub const LINT_PARTIAL_LOADER_EXT: &[&str] = &["vue"];
+pub const LINT_PARTIAL_LOADER_EXT: &[&str] = &["vue", "astro", "html", "htm"];
+
+/// Markdown files are only linted with [`LintOptions::markdown_plugin`]
+pub const LINT_MARKDOWN_EXT: &[&str] = &["md", "markdown"];
 
 pub enum PartialLoader {
     Vue,
+    Astro,
+    Html,
+    Markdown,
 }
 
 #[derive(Default)]
 pub struct PartialLoaderValue {
     pub source_text: String,
     pub source_type: SourceType,
+    /// Byte offset of `source_text` in the original file
+    pub start: u32,
 }
 
 impl PartialLoaderValue {
-    pub fn from(source_text: String, is_ts: bool, is_jsx: bool) -> Self {
+    pub fn from(source_text: String, is_ts: bool, is_jsx: bool, start: u32) -> Self {
         // `module_kind`  should be `ModuleKind::Module` for allow `import`
         let source_type =
             SourceType::default().with_typescript(is_ts).with_module(true).with_jsx(is_jsx);
-        Self { source_text, source_type }
+        Self { source_text, source_type, start }
+    }
+
+    /// The end of `source_text` in the original file
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn end(&self) -> u32 {
+        self.start + self.source_text.len() as u32
     }
 }
 
 impl PartialLoader {
-    pub fn parse(&self, source_text: &str) -> PartialLoaderValue {
-        if matches!(self, Self::Vue) {
-            return VuePartialLoader::from(source_text).build();
+    /// The loader of files with the extension `ext`, one of [`LINT_PARTIAL_LOADER_EXT`], or of
+    /// [`LINT_MARKDOWN_EXT`] if it is enabled in `options`
+    pub fn from_extension(ext: &str, options: &LintOptions) -> Option<Self> {
+        match ext {
+            "vue" => Some(Self::Vue),
+            "astro" => Some(Self::Astro),
+            "html" | "htm" => Some(Self::Html),
+            _ if options.markdown_plugin && LINT_MARKDOWN_EXT.contains(&ext) => {
+                Some(Self::Markdown)
+            }
+            _ => None,
+        }
+    }
+
+    /// The blocks of code in `source_text`, each one is linted on its own
+    pub fn parse(&self, source_text: &str) -> Vec<PartialLoaderValue> {
+        match self {
+            Self::Vue => VuePartialLoader::from(source_text).build(),
+            Self::Astro => vec![AstroPartialLoader::from(source_text).build()],
+            Self::Html => HtmlPartialLoader::from(source_text).build(),
+            Self::Markdown => MarkdownPartialLoader::from(source_text).build(),
         }
-        PartialLoaderValue::default()
     }
 }
@@ -7,10 +7,8 @@ use super::PartialLoaderValue;
 pub struct VuePartialLoader<'a> {
     source_text: &'a str,
     chars: Chars<'a>,
-    code: Vec<u8>,
     is_ts: bool,
     is_jsx: bool,
-    is_reading_js: bool,
     /// Record current <template> 's depth
     template_depth: u32,
 }
@@ -20,35 +18,33 @@ impl<'a> VuePartialLoader<'a> {
         Self {
             source_text,
             chars: source_text.chars(),
-            code: vec![],
             is_ts: false,
             is_jsx: false,
-            is_reading_js: false,
             template_depth: 0,
         }
     }
-    pub fn build(mut self) -> PartialLoaderValue {
-        self.parse();
-        // SAFETY: criteria of `from_utf8_unchecked`.are met.
-        let js_content = unsafe { String::from_utf8_unchecked(self.code) };
-        PartialLoaderValue::from(js_content, self.is_ts, self.is_jsx)
+    /// All `<script>` blocks, e.g. both `<script>` and `<script setup>`
+    pub fn build(mut self) -> Vec<PartialLoaderValue> {
+        let mut values = vec![];
+        while let Some(span) = self.parse() {
+            let js_content = span.source_text(self.source_text).to_string();
+            values.push(PartialLoaderValue::from(js_content, self.is_ts, self.is_jsx, span.start));
+        }
+        values
     }
-    fn parse(&mut self) {
+    /// The span of the content of the next `<script>` block
+    fn parse(&mut self) -> Option<Span> {
+        let mut script_start = None;
         while let Some(ch) = self.advance() {
-            // if ch equals '<', we should check if it is the started char of </script>
-            let may_end_script_tag = self.is_reading_js && ch == '<';
-            if !may_end_script_tag {
-                self.push_ch_or_space(ch);
-            }
-
-            if self.is_reading_js {
+            if let Some(start) = script_start {
                 match ch {
                     '<' => {
                         if self.can_eat("/script>") {
-                            self.is_reading_js = false;
-                            break;
+                            // the content ends before the `<`
+                            let end = self.offset() - 1;
+                            self.eat("/script>");
+                            return Some(Span::new(start, end));
                         }
-                        self.push_ch_or_space(ch);
                     }
                     '\'' | '"' => {
                         self.skip_until_next_delimiter(ch);
@@ -62,12 +58,15 @@ impl<'a> VuePartialLoader<'a> {
                     _ => {}
                 }
             } else if ch == '<' {
-                self.try_read_tag_name();
+                script_start = self.try_read_tag_name();
             }
         }
+        // unterminated `<script>`
+        script_start.map(|start| Span::new(start, self.offset()))
     }
 
-    fn try_read_tag_name(&mut self) {
+    /// The offset after the `>` of a `<script>` tag
+    fn try_read_tag_name(&mut self) -> Option<u32> {
         if self.eat("template") {
             self.template_depth += 1;
         } else if self.eat("/template>") {
@@ -81,9 +80,10 @@ impl<'a> VuePartialLoader<'a> {
                     Span::new(open_tag_start, open_tag_end).source_text(self.source_text);
                 self.is_ts = VuePartialLoader::contains_ts_flag(attributes_text);
                 self.is_jsx = VuePartialLoader::contains_jsx_flag(attributes_text);
-                self.is_reading_js = true;
+                return Some(open_tag_end);
             }
         }
+        None
     }
 
     fn try_read_comment(&mut self) {
@@ -114,10 +114,8 @@ impl<'a> VuePartialLoader<'a> {
     }
     fn skip_to_end_of_template_literal(&mut self) {
         let mut last_is_escape = false;
-        let mut chars = vec![];
 
         while let Some(c) = self.advance() {
-            chars.push(c as u8);
             if last_is_escape {
                 last_is_escape = false;
                 continue;
@@ -129,24 +127,19 @@ impl<'a> VuePartialLoader<'a> {
                 }
                 '$' => {
                     if self.peek() == Some('{') {
-                        let sub_chars = self.skip_to_end_dollar_brace();
-                        chars.extend(sub_chars);
+                        self.skip_to_end_dollar_brace();
                     }
                 }
                 _ => last_is_escape = false,
             }
         }
-
-        self.push_str_or_multi_space(chars);
     }
-    fn skip_to_end_dollar_brace(&mut self) -> Vec<u8> {
+    fn skip_to_end_dollar_brace(&mut self) {
         self.advance();
-        let mut chars = vec![b'{'];
         let mut brace_depth = 0;
         let mut last_is_escape = false;
 
         while let Some(c) = self.advance() {
-            chars.push(c as u8);
             if last_is_escape {
                 last_is_escape = false;
                 continue;
@@ -167,14 +160,11 @@ impl<'a> VuePartialLoader<'a> {
                 _ => {}
             }
         }
-        chars
     }
     fn skip_until_next_delimiter(&mut self, delimiter: char) {
         let mut last_is_escape = false;
-        let mut chars = vec![];
 
         for c in self.chars.by_ref() {
-            chars.push(c as u8);
             if last_is_escape {
                 last_is_escape = false;
                 continue;
@@ -189,57 +179,23 @@ impl<'a> VuePartialLoader<'a> {
                 _ => last_is_escape = false,
             }
         }
-
-        self.push_str_or_multi_space(chars);
     }
     #[allow(clippy::cast_possible_truncation)]
     fn offset(&self) -> u32 {
         (self.source_text.len() - self.chars.as_str().len()) as u32
     }
     fn eat(&mut self, target: &str) -> bool {
-        let mut chars = self.chars.clone();
-        let mut code = vec![];
-        for ch in target.chars() {
-            code.push(ch as u8);
-            if let Some(c) = chars.next() {
-                if c != ch {
-                    return false;
-                }
-            } else {
-                return false;
-            }
+        if !self.can_eat(target) {
+            return false;
         }
-
-        self.push_str_or_multi_space(code);
-        self.chars = chars;
+        self.chars = self.chars.as_str()[target.len()..].chars();
         true
     }
     fn can_eat(&self, target: &str) -> bool {
-        let mut chars = self.chars.clone();
-        for ch in target.chars() {
-            if let Some(c) = chars.next() {
-                if c != ch {
-                    return false;
-                }
-            } else {
-                return false;
-            }
-        }
-
-        true
+        self.chars.as_str().starts_with(target)
     }
     fn eat_to(&mut self, target: char) -> bool {
-        let mut chars = vec![];
-        for ch in self.chars.by_ref() {
-            chars.push(ch as u8);
-            if target == ch {
-                self.push_str_or_multi_space(chars);
-                return true;
-            }
-        }
-
-        self.push_str_or_multi_space(chars);
-        false
+        self.chars.by_ref().any(|ch| ch == target)
     }
 
     fn contains_ts_flag(s: &str) -> bool {
@@ -253,39 +209,17 @@ impl<'a> VuePartialLoader<'a> {
             .iter()
             .any(|flag| s.contains(flag))
     }
-
-    fn push_str_or_multi_space(&mut self, s: Vec<u8>) {
-        for byte in s {
-            self.push_ch_or_space(byte as char);
-        }
-    }
-
-    fn push_ch_or_space(&mut self, ch: char) {
-        if self.is_reading_js {
-            self.code.push(ch as u8);
-        } else {
-            self.code.push(if ch == '\n' { b'\n' } else { b' ' });
-        }
-    }
 }
 
 #[cfg(test)]
 mod test {
-    use itertools::Itertools;
-
-    use super::VuePartialLoader;
-
-    fn visualize_empty_line(source: &str) -> String {
-        source
-            .lines()
-            .map(|line| {
-                if matches!(line.trim(), "" | "\n") {
-                    "-".repeat(line.len())
-                } else {
-                    line.to_string()
-                }
-            })
-            .join("\n")
+    use super::{PartialLoaderValue, VuePartialLoader};
+
+    /// The only `<script>` block of `source_text`
+    fn build_one(source_text: &str) -> PartialLoaderValue {
+        let mut values = VuePartialLoader::from(source_text).build();
+        assert_eq!(values.len(), 1);
+        values.remove(0)
     }
 
     #[test]
@@ -297,14 +231,13 @@ mod test {
         <script> console.log("hi") </script>
         "#;
 
-        let loader_value = VuePartialLoader::from(source_text).build();
+        let loader_value = build_one(source_text);
+        assert_eq!(loader_value.source_text, r#" console.log("hi") "#);
+        let start = source_text.find(r#" console.log("hi")"#).unwrap();
+        assert_eq!(loader_value.start as usize, start);
         assert_eq!(
-            visualize_empty_line(&loader_value.source_text),
-            r#"
-------------------
-------------------------------
--------------------
-                 console.log("hi") "#
+            &source_text[start..loader_value.end() as usize],
+            loader_value.source_text.as_str()
         );
     }
 
@@ -323,22 +256,28 @@ mod test {
         </script>
         "#;
 
-        let loader_value = VuePartialLoader::from(source_text).build();
+        let loader_value = build_one(source_text);
         assert!(!loader_value.source_type.is_typescript());
         assert_eq!(
-            visualize_empty_line(&loader_value.source_text),
+            loader_value.source_text,
             r#"
-------------------
-------------------------------
--------------------
-----------------
             console.log("hi")
             console.log("I am multi line")
             console.log("<script></script>")
             console.log(`<script></script>`)
             console.log('<script></script>')
---------"#
+        "#
         );
+        assert_eq!(loader_value.start as usize, source_text.find("<script>").unwrap() + 8);
+    }
+
+    #[test]
+    fn test_offset_after_multi_byte_characters() {
+        let source_text = "<template>🦀🦀</template>\n<script>let crab = '🦀';</script>";
+
+        let loader_value = build_one(source_text);
+        assert_eq!(loader_value.source_text, "let crab = '🦀';");
+        assert_eq!(loader_value.start as usize, source_text.find("let").unwrap());
     }
 
     #[test]
@@ -349,7 +288,7 @@ mod test {
         </script>
         "#;
 
-        let loader_value = VuePartialLoader::from(source_text).build();
+        let loader_value = build_one(source_text);
         assert!(loader_value.source_type.is_typescript());
         assert_eq!(loader_value.source_text.trim(), "1/1");
     }
@@ -362,7 +301,7 @@ mod test {
         </script>
         ";
 
-        let loader_value = VuePartialLoader::from(source_text).build();
+        let loader_value = build_one(source_text);
         assert!(loader_value.source_type.is_typescript());
         assert_eq!(loader_value.source_text.trim(), "1/1");
     }
@@ -375,7 +314,7 @@ mod test {
         </script>
         ";
 
-        let loader_value = VuePartialLoader::from(source_text).build();
+        let loader_value = build_one(source_text);
         assert!(loader_value.source_type.is_typescript());
         assert_eq!(loader_value.source_text.trim(), "1/1");
     }
@@ -388,7 +327,7 @@ mod test {
         </script>
         ";
 
-        let loader_value = VuePartialLoader::from(source_text).build();
+        let loader_value = build_one(source_text);
         assert!(loader_value.source_type.is_jsx());
         assert!(loader_value.source_type.is_typescript());
         assert_eq!(loader_value.source_text.trim(), "1/1");
@@ -403,7 +342,7 @@ mod test {
         <template> </template>
         ";
 
-        let loader_value = VuePartialLoader::from(source_text).build();
+        let loader_value = build_one(source_text);
         assert!(!loader_value.source_type.is_typescript());
         assert_eq!(loader_value.source_text.trim(), r"a.replace(/&#39;/g, '\''))");
     }
@@ -416,7 +355,7 @@ mod test {
         </script>
         ";
 
-        let loader_value = VuePartialLoader::from(source_text).build();
+        let loader_value = build_one(source_text);
         assert_eq!(loader_value.source_text.trim(), r"`a${b( `c \`${d}\``)}`");
     }
 
@@ -430,7 +369,7 @@ mod test {
         </script>
         ";
 
-        let loader_value = VuePartialLoader::from(source_text).build();
+        let loader_value = build_one(source_text);
         assert_eq!(loader_value.source_text.trim(), r"`${/{/}`");
     }
 
@@ -445,7 +384,7 @@ mod test {
         </script>
         ";
 
-        let loader_value = VuePartialLoader::from(source_text).build();
+        let loader_value = build_one(source_text);
         assert!(!loader_value.source_type.is_typescript());
         assert_eq!(loader_value.source_text.trim(), "console.log('success')");
     }
@@ -466,7 +405,7 @@ mod test {
         </script>
         ";
 
-        let loader_value = VuePartialLoader::from(source_text).build();
+        let loader_value = build_one(source_text);
         assert!(!loader_value.source_type.is_typescript());
         assert_eq!(loader_value.source_text.trim(), "console.log('success')");
     }
@@ -477,9 +416,7 @@ mod test {
             <template></template>
         ";
 
-        let loader_value = VuePartialLoader::from(source_text).build();
-        assert!(!loader_value.source_type.is_typescript());
-        assert_eq!(loader_value.source_text.trim(), "");
+        assert!(VuePartialLoader::from(source_text).build().is_empty());
     }
 
     #[test]
@@ -488,8 +425,41 @@ mod test {
         <script>
             console.log('error')
         ";
-        let loader_value = VuePartialLoader::from(source_text).build();
+        let loader_value = build_one(source_text);
         assert!(!loader_value.source_type.is_typescript());
         assert_eq!(loader_value.source_text.trim(), "console.log('error')");
     }
+
+    #[test]
+    fn test_multiple_scripts() {
+        let source_text = r#"
+        <script lang="ts">
+            export default { name: 'App' } as const
+        </script>
+        <template>
+            <script>console.log('error')</script>
+        </template>
+        <script setup lang="tsx">
+            const a = <div />
+        </script>
+        <script setup>
+            const b = 1
+        </script>
+        "#;
+
+        let values = VuePartialLoader::from(source_text).build();
+        assert_eq!(values.len(), 3);
+        assert_eq!(values[0].source_text.trim(), "export default { name: 'App' } as const");
+        assert!(values[0].source_type.is_typescript());
+        assert!(!values[0].source_type.is_jsx());
+        assert_eq!(values[1].source_text.trim(), "const a = <div />");
+        assert!(values[1].source_type.is_typescript());
+        assert!(values[1].source_type.is_jsx());
+        assert_eq!(values[2].source_text.trim(), "const b = 1");
+        assert!(!values[2].source_type.is_typescript());
+        for value in &values {
+            let (start, end) = (value.start as usize, value.end() as usize);
+            assert_eq!(&source_text[start..end], value.source_text);
+        }
+    }
 }
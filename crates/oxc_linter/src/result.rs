@@ -0,0 +1,65 @@
+use std::ops::AddAssign;
+
+use oxc_diagnostics::{Error, Severity};
+
+/// Number of problems reported by a lint run.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct LintResult {
+    pub errors: usize,
+    pub warnings: usize,
+    /// Reported problems which have a fix available
+    pub fixables: usize,
+}
+
+impl LintResult {
+    /// Count a reported diagnostic. Advices are not counted.
+    pub fn add(&mut self, diagnostic: &Error, fixable: bool) {
+        match diagnostic.severity() {
+            Some(Severity::Warning) => self.warnings += 1,
+            Some(Severity::Error) | None => self.errors += 1,
+            Some(Severity::Advice) => return,
+        }
+        if fixable {
+            self.fixables += 1;
+        }
+    }
+
+    /// Whether there are more warnings than allowed by ESLint's `--max-warnings`.
+    pub fn max_warnings_exceeded(&self, max_warnings: Option<usize>) -> bool {
+        max_warnings.map_or(false, |max_warnings| self.warnings > max_warnings)
+    }
+}
+
+impl AddAssign for LintResult {
+    fn add_assign(&mut self, other: Self) {
+        self.errors += other.errors;
+        self.warnings += other.warnings;
+        self.fixables += other.fixables;
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_diagnostics::miette::miette;
+
+    use super::LintResult;
+
+    #[test]
+    fn count() {
+        let mut result = LintResult::default();
+        result.add(&miette!(severity = miette::Severity::Warning, "warning"), true);
+        result.add(&miette!(severity = miette::Severity::Error, "error"), false);
+        result.add(&miette!("no severity is an error"), true);
+        result.add(&miette!(severity = miette::Severity::Advice, "advice"), true);
+        assert_eq!(result, LintResult { errors: 2, warnings: 1, fixables: 2 });
+    }
+
+    #[test]
+    fn max_warnings() {
+        let result = LintResult { errors: 0, warnings: 3, fixables: 0 };
+        assert!(!result.max_warnings_exceeded(None));
+        assert!(!result.max_warnings_exceeded(Some(4)));
+        assert!(!result.max_warnings_exceeded(Some(3)));
+        assert!(result.max_warnings_exceeded(Some(2)));
+    }
+}
@@ -1,8 +1,9 @@
 use std::fmt;
 
+use once_cell::sync::Lazy;
 use oxc_semantic::SymbolId;
 
-use crate::{context::LintContext, AstNode};
+use crate::{context::LintContext, rules::RULES, AstNode, RuleEnum};
 
 pub trait Rule: Sized + Default + fmt::Debug {
     /// Initialize from eslint json configuration
@@ -25,6 +26,9 @@ pub trait RuleMeta {
 
     const CATEGORY: RuleCategory;
 
+    /// The rule fixes (some of) the problems it reports
+    const FIXABLE: bool = false;
+
     fn documentation() -> Option<&'static str> {
         None
     }
@@ -82,9 +86,68 @@ impl fmt::Display for RuleCategory {
     }
 }
 
+/// Metadata of a registered rule, see [`crate::Linter::rules_metadata`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RuleMetadata {
+    /// Rule name with its plugin scope as used in diagnostics and configuration files,
+    /// e.g. `no-debugger`, `@typescript-eslint/no-explicit-any` or `jest/no-focused-tests`
+    pub scoped_name: String,
+    pub name: &'static str,
+    pub plugin: &'static str,
+    pub category: RuleCategory,
+    /// The rule fixes (some of) the problems it reports
+    pub fixable: bool,
+    /// First paragraph of the documentation
+    pub summary: String,
+    /// Markdown documentation
+    pub documentation: &'static str,
+}
+
+impl From<&RuleEnum> for RuleMetadata {
+    fn from(rule: &RuleEnum) -> Self {
+        let scoped_name = match rule.plugin_name() {
+            "eslint" => rule.name().to_string(),
+            "typescript" => format!("@typescript-eslint/{}", rule.name()),
+            plugin_name => format!("{}/{}", plugin_name.replace('_', "-"), rule.name()),
+        };
+        let documentation = rule.documentation().unwrap_or_default();
+        let summary = documentation
+            .lines()
+            .map(str::trim)
+            .skip_while(|line| line.is_empty() || line.starts_with('#'))
+            .take_while(|line| !line.is_empty() && !line.starts_with('#'))
+            .collect::<Vec<_>>()
+            .join(" ");
+        Self {
+            scoped_name,
+            name: rule.name(),
+            plugin: rule.plugin_name(),
+            category: rule.category(),
+            fixable: rule.fixable(),
+            summary,
+            documentation,
+        }
+    }
+}
+
+impl From<&RuleMetadata> for oxc_diagnostics::RuleMetadata {
+    fn from(rule: &RuleMetadata) -> Self {
+        Self {
+            id: rule.scoped_name.clone(),
+            category: rule.category.to_string().to_lowercase(),
+            documentation: Some(rule.documentation.to_string()),
+        }
+    }
+}
+
+/// Metadata of all registered rules, in the order they are declared.
+pub static RULES_METADATA: Lazy<Vec<RuleMetadata>> =
+    Lazy::new(|| RULES.iter().map(RuleMetadata::from).collect());
+
 #[cfg(test)]
 mod test {
-    use crate::RULES;
+    use super::{RuleCategory, RULES_METADATA};
+    use crate::{Linter, RULES};
 
     #[test]
     fn ensure_documentation() {
@@ -93,4 +156,51 @@ mod test {
             assert!(rule.documentation().is_some_and(|s| !s.is_empty()), "{}", rule.name());
         }
     }
+
+    #[test]
+    fn rules_metadata() {
+        assert_eq!(RULES_METADATA.len(), RULES.len());
+
+        let find = |scoped_name: &str| {
+            RULES_METADATA.iter().find(|rule| rule.scoped_name == scoped_name).unwrap()
+        };
+
+        let no_debugger = find("no-debugger");
+        assert_eq!(no_debugger.name, "no-debugger");
+        assert_eq!(no_debugger.plugin, "eslint");
+        assert_eq!(no_debugger.category, RuleCategory::Correctness);
+        assert!(no_debugger.fixable);
+        assert_eq!(no_debugger.summary, "Checks for usage of the `debugger` statement");
+
+        let no_explicit_any = find("@typescript-eslint/no-explicit-any");
+        assert_eq!(no_explicit_any.plugin, "typescript");
+        assert!(no_explicit_any.fixable);
+
+        let no_focused_tests = find("jest/no-focused-tests");
+        assert_eq!(no_focused_tests.plugin, "jest");
+        assert!(no_focused_tests.fixable);
+
+        let alt_text = find("jsx-a11y/alt-text");
+        assert_eq!(alt_text.plugin, "jsx_a11y");
+        assert!(!alt_text.fixable);
+
+        assert!(!find("no-undef").fixable);
+        assert!(!find("unicorn/filename-case").fixable);
+        assert!(find("unicorn/no-null").fixable);
+
+        for rule in RULES_METADATA.iter() {
+            assert!(!rule.summary.is_empty(), "{}", rule.scoped_name);
+        }
+    }
+
+    #[test]
+    fn rules_markdown() {
+        let markdown = Linter::rules_markdown();
+        assert!(markdown.starts_with("# Rules\n"));
+        assert!(markdown.contains(
+            "| [no-debugger](#no-debugger) | Correctness | 🛠️ | Checks for usage of the `debugger` statement |"
+        ));
+        assert!(markdown.contains("| [jsx-a11y/alt-text](#jsx-a11yalt-text) | Correctness |  |"));
+        assert!(markdown.contains("\n## no-debugger\n"));
+    }
 }
@@ -9,9 +9,13 @@
 mod import {
     pub mod default;
     pub mod export;
+    pub mod first;
     pub mod named;
     pub mod no_amd;
     pub mod no_cycle;
+    pub mod no_default_export;
+    pub mod no_duplicates;
+    pub mod no_named_as_default_member;
     pub mod no_self_import;
 }
 
@@ -35,6 +39,9 @@ mod eslint {
     pub mod eqeqeq;
     pub mod for_direction;
     pub mod getter_return;
+    pub mod guard_for_in;
+    pub mod max_depth;
+    pub mod max_params;
     pub mod no_array_constructor;
     pub mod no_async_promise_executor;
     pub mod no_bitwise;
@@ -54,53 +61,72 @@ mod eslint {
     pub mod no_dupe_else_if;
     pub mod no_dupe_keys;
     pub mod no_duplicate_case;
+    pub mod no_duplicate_imports;
     pub mod no_empty;
     pub mod no_empty_character_class;
     pub mod no_empty_pattern;
     pub mod no_empty_static_block;
     pub mod no_eval;
     pub mod no_ex_assign;
+    pub mod no_extend_native;
     pub mod no_extra_boolean_cast;
     pub mod no_fallthrough;
     pub mod no_func_assign;
     pub mod no_global_assign;
     pub mod no_import_assign;
     pub mod no_inner_declarations;
+    pub mod no_labels;
+    pub mod no_lone_blocks;
     pub mod no_loss_of_precision;
     pub mod no_mixed_operators;
     pub mod no_new_symbol;
+    pub mod no_new_wrappers;
     pub mod no_obj_calls;
     pub mod no_prototype_builtins;
     pub mod no_redeclare;
     pub mod no_regex_spaces;
+    pub mod no_restricted_globals;
     pub mod no_return_await;
     pub mod no_self_assign;
     pub mod no_self_compare;
+    pub mod no_sequences;
     pub mod no_setter_return;
     pub mod no_shadow_restricted_names;
     pub mod no_sparse_arrays;
+    pub mod no_throw_literal;
     pub mod no_undef;
+    pub mod no_undef_init;
     pub mod no_unsafe_finally;
     pub mod no_unsafe_negation;
     pub mod no_unsafe_optional_chaining;
     pub mod no_unused_labels;
     pub mod no_unused_private_class_members;
     pub mod no_useless_catch;
+    pub mod no_useless_concat;
     pub mod no_useless_escape;
+    pub mod prefer_const;
+    pub mod prefer_exponentiation_operator;
+    pub mod prefer_object_spread;
+    pub mod radix;
     pub mod require_yield;
+    pub mod symbol_description;
     pub mod use_isnan;
     pub mod valid_typeof;
 }
 
 mod typescript {
     pub mod adjacent_overload_signatures;
+    pub mod array_type;
     pub mod ban_ts_comment;
     pub mod ban_types;
+    pub mod consistent_type_definitions;
     pub mod consistent_type_exports;
+    pub mod consistent_type_imports;
     pub mod no_duplicate_enum_values;
     pub mod no_empty_interface;
     pub mod no_explicit_any;
     pub mod no_extra_non_null_assertion;
+    pub mod no_inferrable_types;
     pub mod no_misused_new;
     pub mod no_namespace;
     pub mod no_non_null_asserted_optional_chain;
@@ -130,6 +156,8 @@ mod jest {
     pub mod no_mocks_import;
     pub mod no_standalone_expect;
     pub mod no_test_prefixes;
+    pub mod prefer_to_be;
+    pub mod prefer_to_have_length;
     pub mod prefer_todo;
     pub mod valid_describe_callback;
     pub mod valid_expect;
@@ -138,11 +166,13 @@ mod jest {
 
 mod react {
     pub mod button_has_type;
+    pub mod exhaustive_deps;
     pub mod jsx_key;
     pub mod jsx_no_comment_text_nodes;
     pub mod jsx_no_duplicate_props;
     pub mod jsx_no_undef;
     pub mod jsx_no_useless_fragment;
+    pub mod no_array_index_key;
     pub mod no_children_prop;
     pub mod no_dangerously_set_inner_html;
     pub mod no_find_dom_node;
@@ -151,6 +181,7 @@ mod react {
     pub mod no_string_refs;
     pub mod no_unescaped_entities;
     pub mod react_in_jsx_scope;
+    pub mod void_dom_elements_no_children;
 }
 
 mod unicorn {
@@ -190,6 +221,7 @@ mod unicorn {
     pub mod no_useless_promise_resolve_reject;
     pub mod no_useless_spread;
     pub mod no_useless_switch_case;
+    pub mod no_useless_undefined;
     pub mod no_zero_fractions;
     pub mod number_literal_case;
     pub mod numeric_separators_style;
@@ -280,6 +312,9 @@ oxc_macros::declare_all_lint_rules! {
     eslint::eqeqeq,
     eslint::for_direction,
     eslint::getter_return,
+    eslint::guard_for_in,
+    eslint::max_depth,
+    eslint::max_params,
     eslint::no_array_constructor,
     eslint::no_async_promise_executor,
     eslint::no_bitwise,
@@ -299,50 +334,69 @@ oxc_macros::declare_all_lint_rules! {
     eslint::no_dupe_else_if,
     eslint::no_dupe_keys,
     eslint::no_duplicate_case,
+    eslint::no_duplicate_imports,
     eslint::no_empty,
     eslint::no_empty_character_class,
     eslint::no_empty_pattern,
     eslint::no_empty_static_block,
     eslint::no_eval,
     eslint::no_ex_assign,
+    eslint::no_extend_native,
     eslint::no_extra_boolean_cast,
     eslint::no_fallthrough,
     eslint::no_func_assign,
     eslint::no_global_assign,
     eslint::no_import_assign,
     eslint::no_inner_declarations,
+    eslint::no_labels,
+    eslint::no_lone_blocks,
     eslint::no_loss_of_precision,
     eslint::no_mixed_operators,
+    eslint::no_new_wrappers,
     eslint::no_new_symbol,
     eslint::no_obj_calls,
     eslint::no_prototype_builtins,
     eslint::no_redeclare,
     eslint::no_regex_spaces,
+    eslint::no_restricted_globals,
     eslint::no_return_await,
     eslint::no_self_assign,
     eslint::no_self_compare,
+    eslint::no_sequences,
     eslint::no_setter_return,
     eslint::no_shadow_restricted_names,
     eslint::no_sparse_arrays,
+    eslint::no_throw_literal,
     eslint::no_undef,
+    eslint::no_undef_init,
     eslint::no_unsafe_finally,
     eslint::no_unsafe_negation,
     eslint::no_unsafe_optional_chaining,
     eslint::no_unused_labels,
     eslint::no_unused_private_class_members,
     eslint::no_useless_catch,
+    eslint::no_useless_concat,
     eslint::no_useless_escape,
+    eslint::prefer_const,
+    eslint::prefer_exponentiation_operator,
+    eslint::prefer_object_spread,
+    eslint::radix,
     eslint::require_yield,
+    eslint::symbol_description,
     eslint::use_isnan,
     eslint::valid_typeof,
     typescript::adjacent_overload_signatures,
+    typescript::array_type,
     typescript::ban_ts_comment,
     typescript::ban_types,
+    typescript::consistent_type_definitions,
     typescript::consistent_type_exports,
+    typescript::consistent_type_imports,
     typescript::no_duplicate_enum_values,
     typescript::no_empty_interface,
     typescript::no_explicit_any,
     typescript::no_extra_non_null_assertion,
+    typescript::no_inferrable_types,
     typescript::no_misused_new,
     typescript::no_namespace,
     typescript::no_non_null_asserted_optional_chain,
@@ -369,6 +423,8 @@ oxc_macros::declare_all_lint_rules! {
     jest::no_mocks_import,
     jest::no_standalone_expect,
     jest::no_test_prefixes,
+    jest::prefer_to_be,
+    jest::prefer_to_have_length,
     jest::prefer_todo,
     jest::valid_describe_callback,
     jest::valid_expect,
@@ -409,6 +465,7 @@ oxc_macros::declare_all_lint_rules! {
     unicorn::no_useless_length_check,
     unicorn::no_useless_promise_resolve_reject,
     unicorn::no_useless_switch_case,
+    unicorn::no_useless_undefined,
     unicorn::no_zero_fractions,
     unicorn::number_literal_case,
     unicorn::numeric_separators_style,
@@ -450,12 +507,14 @@ oxc_macros::declare_all_lint_rules! {
     unicorn::text_encoding_identifier_case,
     unicorn::throw_new_error,
     react::button_has_type,
+    react::exhaustive_deps,
     react::jsx_key,
     react::jsx_no_comment_text_nodes,
     react::jsx_no_duplicate_props,
     react::jsx_no_useless_fragment,
     react::jsx_no_undef,
     react::react_in_jsx_scope,
+    react::no_array_index_key,
     react::no_children_prop,
     react::no_dangerously_set_inner_html,
     react::no_find_dom_node,
@@ -463,12 +522,17 @@ oxc_macros::declare_all_lint_rules! {
     react::no_string_refs,
     react::no_unescaped_entities,
     react::no_is_mounted,
+    react::void_dom_elements_no_children,
     import::default,
     import::named,
     import::no_cycle,
+    import::no_duplicates,
+    import::no_default_export,
+    import::no_named_as_default_member,
     import::no_self_import,
     import::no_amd,
     import::export,
+    import::first,
     jsx_a11y::alt_text,
     jsx_a11y::anchor_has_content,
     jsx_a11y::anchor_is_valid,
@@ -37,7 +37,8 @@ declare_oxc_lint!(
     /// a == b
     /// ```
     Eqeqeq,
-    pedantic
+    pedantic,
+    fix
 );
 
 impl Rule for Eqeqeq {
@@ -195,7 +196,7 @@ fn is_null_check(binary_expr: &BinaryExpression) -> bool {
 fn test() {
     use serde_json::json;
 
-    use crate::tester::Tester;
+    use crate::{tester::Tester, FixKind};
 
     let pass = vec![
         ("typeof foo == 'undefined'", Some(json!(["smart"]))),
@@ -237,5 +238,8 @@ fn test() {
         ("a == b", "a == b", None),
     ];
 
-    Tester::new(Eqeqeq::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
+    Tester::new(Eqeqeq::NAME, pass, fail)
+        .expect_fix(fix)
+        .expect_fix_kind(FixKind::Dangerous)
+        .test_and_snapshot();
 }
@@ -0,0 +1,106 @@
+use oxc_ast::{ast::Statement, AstKind};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+
+use crate::{context::LintContext, rule::Rule, AstNode};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint(guard-for-in): The body of a for-in should be wrapped in an if statement to filter unwanted properties from the prototype.")]
+#[diagnostic(
+    severity(warning),
+    help("Check the property with `if (Object.hasOwn(obj, key))` before using it")
+)]
+struct GuardForInDiagnostic(#[label] Span);
+
+#[derive(Debug, Default, Clone)]
+pub struct GuardForIn;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Require `for-in` loops to include an `if` statement
+    ///
+    /// ### Why is this bad?
+    ///
+    /// A `for-in` loop also iterates over the inherited properties of the object, which may
+    /// include unexpected methods added to its prototype. The body of the loop should be an `if`
+    /// statement filtering the properties, or start with one skipping them with `continue`.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// // fail
+    /// for (key in foo) {
+    ///     doSomething(key);
+    /// }
+    ///
+    /// // pass
+    /// for (key in foo) {
+    ///     if (Object.hasOwn(foo, key)) {
+    ///         doSomething(key);
+    ///     }
+    /// }
+    ///
+    /// for (key in foo) {
+    ///     if (!Object.hasOwn(foo, key)) continue;
+    ///     doSomething(key);
+    /// }
+    /// ```
+    GuardForIn,
+    nursery
+);
+
+impl Rule for GuardForIn {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::ForInStatement(stmt) = node.kind() else { return };
+        let is_guarded = match &stmt.body {
+            Statement::EmptyStatement(_) | Statement::IfStatement(_) => true,
+            Statement::BlockStatement(block) => match block.body.as_slice() {
+                [] | [Statement::IfStatement(_)] => true,
+                // `if (!Object.hasOwn(obj, key)) continue;`
+                [Statement::IfStatement(if_stmt), ..] => match &if_stmt.consequent {
+                    Statement::ContinueStatement(_) => true,
+                    Statement::BlockStatement(block) => {
+                        matches!(block.body.as_slice(), [Statement::ContinueStatement(_)])
+                    }
+                    _ => false,
+                },
+                _ => false,
+            },
+            _ => false,
+        };
+        if !is_guarded {
+            ctx.diagnostic(GuardForInDiagnostic(stmt.span));
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "for (var x in o);",
+        "for (var x in o) {}",
+        "for (var x in o) if (x) f();",
+        "for (var x in o) { if (x) { f(); } }",
+        "for (var x in o) { if (x) continue; f(); }",
+        "for (var x in o) { if (x) { continue; } f(); }",
+        "for (const key in obj) { if (!Object.hasOwn(obj, key)) continue; use(key); }",
+        "for (var x of o) { f(); }",
+    ];
+
+    let fail = vec![
+        "for (var x in o) { if (x) { f(); continue; } g(); }",
+        "for (var x in o) { if (x) { continue; f(); } g(); }",
+        "for (var x in o) { if (x) { f(); } g(); }",
+        "for (var x in o) { if (x) f(); g(); }",
+        "for (var x in o) { foo() }",
+        "for (var x in o) foo();",
+    ];
+
+    Tester::new_without_config(GuardForIn::NAME, pass, fail).test_and_snapshot();
+}
@@ -0,0 +1,235 @@
+use oxc_ast::AstKind;
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+
+use crate::{context::LintContext, rule::Rule, AstNode};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint(max-depth): Blocks are nested too deeply ({0}). Maximum allowed is {1}.")]
+#[diagnostic(
+    severity(warning),
+    help("Reduce the nesting with early returns, or move the inner blocks into functions")
+)]
+struct MaxDepthDiagnostic(usize, usize, #[label] Span);
+
+#[derive(Debug, Clone)]
+pub struct MaxDepth {
+    max: usize,
+}
+
+impl Default for MaxDepth {
+    fn default() -> Self {
+        Self { max: 4 }
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Enforce a maximum depth that blocks can be nested, 4 by default
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Deeply nested code is hard to read and to follow. The `if`, `switch`, `try`, `with`, and
+    /// loop statements are counted, from the start of the function or static block they are in.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// // fail
+    /// function foo() {
+    ///     for (;;) { // Nested 1 deep
+    ///         while (true) { // Nested 2 deep
+    ///             if (true) { // Nested 3 deep
+    ///                 if (true) { // Nested 4 deep
+    ///                     if (true) { // Nested 5 deep
+    ///                     }
+    ///                 }
+    ///             }
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// // pass
+    /// function foo() {
+    ///     for (;;) { // Nested 1 deep
+    ///         while (true) { // Nested 2 deep
+    ///             if (true) { // Nested 3 deep
+    ///                 if (true) { // Nested 4 deep
+    ///                 }
+    ///             }
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    MaxDepth,
+    nursery
+);
+
+impl Rule for MaxDepth {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        // `4`, `{ "max": 4 }`, or the deprecated `{ "maximum": 4 }`
+        let config = value.get(0);
+        let max = config
+            .and_then(serde_json::Value::as_u64)
+            .or_else(|| {
+                let config = config?;
+                config.get("max").or_else(|| config.get("maximum"))?.as_u64()
+            })
+            .and_then(|max| usize::try_from(max).ok());
+        max.map_or_else(Self::default, |max| Self { max })
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        if !is_nested_block(node, ctx) {
+            return;
+        }
+        let depth = ctx
+            .nodes()
+            .iter_parents(node.id())
+            .take_while(|node| {
+                !matches!(
+                    node.kind(),
+                    AstKind::Program(_)
+                        | AstKind::Function(_)
+                        | AstKind::ArrowExpression(_)
+                        | AstKind::StaticBlock(_)
+                )
+            })
+            .filter(|node| is_nested_block(node, ctx))
+            .count();
+        if depth > self.max {
+            ctx.diagnostic(MaxDepthDiagnostic(depth, self.max, node.kind().span()));
+        }
+    }
+}
+
+/// The statements adding a level of nesting, an `else if` is at the level of its `if`
+fn is_nested_block(node: &AstNode, ctx: &LintContext) -> bool {
+    match node.kind() {
+        AstKind::IfStatement(_) => {
+            !matches!(ctx.nodes().parent_kind(node.id()), Some(AstKind::IfStatement(_)))
+        }
+        AstKind::SwitchStatement(_)
+        | AstKind::TryStatement(_)
+        | AstKind::DoWhileStatement(_)
+        | AstKind::WhileStatement(_)
+        | AstKind::WithStatement(_)
+        | AstKind::ForStatement(_)
+        | AstKind::ForInStatement(_)
+        | AstKind::ForOfStatement(_) => true,
+        _ => false,
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        (
+            "function foo() { if (true) { if (false) { if (true) { } } } }",
+            Some(serde_json::json!([3])),
+        ),
+        (
+            "function foo() { if (true) { } else if (false) { } else if (true) { } else if (false) {} }",
+            Some(serde_json::json!([3])),
+        ),
+        (
+            "var foo = () => { if (true) { if (false) { if (true) { } } } }",
+            Some(serde_json::json!([3])),
+        ),
+        ("function foo() { if (true) { if (false) { if (true) { } } } }", None),
+        (
+            "function foo() { if (true) { if (false) { if (true) { } } } }",
+            Some(serde_json::json!([{ "max": 3 }])),
+        ),
+        ("class C { static { if (1) { if (2) {} } } }", Some(serde_json::json!([2]))),
+        (
+            "class C { static { if (1) { if (2) {} } if (1) { if (2) {} } } }",
+            Some(serde_json::json!([2])),
+        ),
+        (
+            "class C { static { if (1) { if (2) {} } } static { if (1) { if (2) {} } } }",
+            Some(serde_json::json!([2])),
+        ),
+        ("if (1) { class C { static { if (1) { if (2) {} } } } }", Some(serde_json::json!([2]))),
+        (
+            "function foo() { if (1) { class C { static { if (1) { if (2) {} } } } } }",
+            Some(serde_json::json!([2])),
+        ),
+        (
+            "function foo() { if (1) { class C { static { if (1) { if (2) {} } } } } if (1) { if (2) {} } }",
+            Some(serde_json::json!([2])),
+        ),
+        (
+            "function foo() { if (true) { if (false) { if (true) { } } } }",
+            Some(serde_json::json!([{ "maximum": 3 }])),
+        ),
+        (
+            "function foo() { if (true) { if (false) { if (true) { } } } }",
+            Some(serde_json::json!([{ "maximum": 2, "max": 3 }])),
+        ),
+        ("if (a) { function foo() { if (b) {} } }", Some(serde_json::json!([1]))),
+        ("if (a) { if (b) {} } if (c) { if (d) {} }", Some(serde_json::json!([2]))),
+    ];
+
+    let fail = vec![
+        (
+            "function foo() { if (true) { if (false) { if (true) { } } } }",
+            Some(serde_json::json!([2])),
+        ),
+        (
+            "var foo = () => { if (true) { if (false) { if (true) { } } } }",
+            Some(serde_json::json!([2])),
+        ),
+        ("function foo() { if (true) {} else { for(;;) {} } }", Some(serde_json::json!([1]))),
+        ("function foo() { while (true) { if (true) {} } }", Some(serde_json::json!([1]))),
+        ("function foo() { for (let x of foo) { if (true) {} } }", Some(serde_json::json!([1]))),
+        (
+            "function foo() { while (true) { if (true) { if (false) { } } } }",
+            Some(serde_json::json!([1])),
+        ),
+        (
+            "function foo() { if (true) { if (false) { if (true) { if (false) { if (true) { } } } } } }",
+            None,
+        ),
+        (
+            "function foo() { if (true) { if (false) { if (true) { } } } }",
+            Some(serde_json::json!([{ "max": 2 }])),
+        ),
+        (
+            "function foo() { if (a) { if (b) { if (c) { if (d) { if (e) {} } } } } }",
+            Some(serde_json::json!([{}])),
+        ),
+        ("function foo() { if (true) {} }", Some(serde_json::json!([{ "max": 0 }]))),
+        ("class C { static { if (1) { if (2) { if (3) {} } } } }", Some(serde_json::json!([2]))),
+        (
+            "if (1) { class C { static { if (1) { if (2) { if (3) {} } } } } }",
+            Some(serde_json::json!([2])),
+        ),
+        (
+            "function foo() { if (1) { class C { static { if (1) { if (2) { if (3) {} } } } } } }",
+            Some(serde_json::json!([2])),
+        ),
+        (
+            "function foo() { if (1) { class C { static { if (1) { if (2) {} } } } } if (1) { if (2) { if (3) {} } } }",
+            Some(serde_json::json!([2])),
+        ),
+        (
+            "function foo() { if (true) { if (false) { if (true) { } } } }",
+            Some(serde_json::json!([{ "maximum": 2 }])),
+        ),
+        (
+            "function foo() { if (true) { if (false) { if (true) { } } } }",
+            Some(serde_json::json!([{ "maximum": 3, "max": 2 }])),
+        ),
+        ("switch (a) { case 1: try { do {} while (b) } catch {} }", Some(serde_json::json!([2]))),
+        ("if (a) {} else if (b) { if (c) {} }", Some(serde_json::json!([1]))),
+    ];
+
+    Tester::new(MaxDepth::NAME, pass, fail).test_and_snapshot();
+}
@@ -0,0 +1,162 @@
+use oxc_ast::{ast::MethodDefinitionKind, AstKind};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+
+use crate::{context::LintContext, rule::Rule, AstNode};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint(max-params): {0} has too many parameters ({1}). Maximum allowed is {2}.")]
+#[diagnostic(severity(warning), help("Group the related parameters into an object"))]
+struct MaxParamsDiagnostic(String, usize, usize, #[label] Span);
+
+#[derive(Debug, Clone)]
+pub struct MaxParams {
+    max: usize,
+}
+
+impl Default for MaxParams {
+    fn default() -> Self {
+        Self { max: 3 }
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Enforce a maximum number of parameters in function definitions, 3 by default
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Functions taking many parameters are hard to read and to call, as the order of the
+    /// arguments has to be remembered. The `this` parameter of TypeScript functions is not
+    /// counted.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// // fail
+    /// function foo(bar, baz, qux, qxx) {
+    ///     doSomething();
+    /// }
+    ///
+    /// // pass
+    /// function foo({ bar, baz, qux, qxx }) {
+    ///     doSomething();
+    /// }
+    /// ```
+    MaxParams,
+    nursery
+);
+
+impl Rule for MaxParams {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        // `3`, `{ "max": 3 }`, or the deprecated `{ "maximum": 3 }`
+        let config = value.get(0);
+        let max = config
+            .and_then(serde_json::Value::as_u64)
+            .or_else(|| {
+                let config = config?;
+                config.get("max").or_else(|| config.get("maximum"))?.as_u64()
+            })
+            .and_then(|max| usize::try_from(max).ok());
+        max.map_or_else(Self::default, |max| Self { max })
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let params = match node.kind() {
+            AstKind::Function(func) => &func.params,
+            AstKind::ArrowExpression(arrow) => &arrow.params,
+            _ => return,
+        };
+        let count = params.parameters_count();
+        if count > self.max {
+            ctx.diagnostic(MaxParamsDiagnostic(
+                function_name(node, ctx),
+                count,
+                self.max,
+                params.span,
+            ));
+        }
+    }
+}
+
+/// The kind of a function followed by its name, e.g. `Method 'foo'`
+fn function_name(node: &AstNode, ctx: &LintContext) -> String {
+    let AstKind::Function(func) = node.kind() else { return "Arrow function".to_string() };
+    let (kind, key) = match ctx.nodes().parent_kind(node.id()) {
+        Some(AstKind::MethodDefinition(def)) => {
+            let kind = match def.kind {
+                MethodDefinitionKind::Constructor => return "Constructor".to_string(),
+                MethodDefinitionKind::Method => "method",
+                MethodDefinitionKind::Get => "getter",
+                MethodDefinitionKind::Set => "setter",
+            };
+            let kind = if def.r#static { format!("Static {kind}") } else { capitalize(kind) };
+            (kind, &def.key)
+        }
+        Some(AstKind::ObjectProperty(prop)) if prop.method => ("Method".to_string(), &prop.key),
+        _ => {
+            let name = func.id.as_ref().map(|id| id.name.clone());
+            return name
+                .map_or_else(|| "Function".to_string(), |name| format!("Function '{name}'"));
+        }
+    };
+    let name =
+        key.static_name().or_else(|| key.private_name().map(|name| format!("#{name}").into()));
+    match name {
+        Some(name) => format!("{kind} '{name}'"),
+        None => kind,
+    }
+}
+
+fn capitalize(s: &str) -> String {
+    let mut chars = s.chars();
+    chars.next().map_or_else(String::new, |c| c.to_uppercase().chain(chars).collect())
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("function test(d, e, f) {}", None),
+        ("var test = function(a, b, c) {};", Some(serde_json::json!([3]))),
+        ("var test = (a, b, c) => {};", Some(serde_json::json!([3]))),
+        ("var test = function test(a, b, c) {};", Some(serde_json::json!([3]))),
+        ("var test = function(a, b, c) {};", Some(serde_json::json!([{ "max": 3 }]))),
+        ("var test = function(a, b, c) {};", Some(serde_json::json!([{ "maximum": 3 }]))),
+        ("function test(this: Foo, a, b, c) {}", None),
+        ("function test(a, b, ...c) {}", None),
+        ("function test({ a, b, c, d }) {}", None),
+    ];
+
+    let fail = vec![
+        ("function test(a, b, c) {}", Some(serde_json::json!([2]))),
+        ("function test(a, b, c, d) {}", None),
+        ("var test = function(a, b, c, d) {};", Some(serde_json::json!([3]))),
+        ("var test = (a, b, c) => {};", Some(serde_json::json!([2]))),
+        ("(function(a, b, c) {});", Some(serde_json::json!([2]))),
+        ("var test = function test(a, b, c) {};", Some(serde_json::json!([1]))),
+        ("function test(a, b, c) {}", Some(serde_json::json!([{ "max": 2 }]))),
+        ("function test(a, b, c, d) {}", Some(serde_json::json!([{}]))),
+        ("function test(a) {}", Some(serde_json::json!([{ "max": 0 }]))),
+        ("function test(a, b, c) {}", Some(serde_json::json!([{ "maximum": 2 }]))),
+        ("function test(a, b, c) {}", Some(serde_json::json!([{ "maximum": 3, "max": 2 }]))),
+        ("function test(a, b, ...c) {}", Some(serde_json::json!([2]))),
+        ("function test(this: Foo, a, b, c, d) {}", None),
+        ("class A { foo(a, b, c, d) {} }", None),
+        ("class A { static foo(a, b, c, d) {} }", None),
+        ("class A { #foo(a, b, c, d) {} }", None),
+        ("class A { constructor(a, b, c, d) {} }", None),
+        ("class A { [foo](a, b, c, d) {} }", None),
+        ("var a = { foo(a, b, c, d) {} };", None),
+        ("var a = { foo: function(a, b, c, d) {} };", None),
+        ("class A { set foo(a) {} }", Some(serde_json::json!([0]))),
+        ("declare function test(a, b, c, d): void;", None),
+    ];
+
+    Tester::new(MaxParams::NAME, pass, fail).test_and_snapshot();
+}
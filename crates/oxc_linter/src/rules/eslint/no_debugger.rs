@@ -6,7 +6,12 @@ use oxc_diagnostics::{
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
 
-use crate::{context::LintContext, fixer::Fix, rule::Rule, AstNode};
+use crate::{
+    context::LintContext,
+    fixer::{Fix, FixKind},
+    rule::Rule,
+    AstNode,
+};
 
 #[derive(Debug, Error, Diagnostic)]
 #[error("eslint(no-debugger): `debugger` statement is not allowed")]
@@ -31,13 +36,16 @@ declare_oxc_lint!(
     /// debugger;
     /// ```
     NoDebugger,
-    correctness
+    correctness,
+    fix
 );
 
 impl Rule for NoDebugger {
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         if let AstKind::DebuggerStatement(stmt) = node.kind() {
-            ctx.diagnostic_with_fix(NoDebuggerDiagnostic(stmt.span), || Fix::delete(stmt.span));
+            ctx.diagnostic_with_fix(NoDebuggerDiagnostic(stmt.span), || {
+                Fix::delete(stmt.span).with_kind(FixKind::Safe)
+            });
         }
     }
 }
@@ -50,5 +58,14 @@ fn test() {
 
     let fail = vec![("if (foo) debugger", None)];
 
-    Tester::new(NoDebugger::NAME, pass, fail).test_and_snapshot();
+    let fix = vec![
+        ("debugger;", "", None),
+        ("if (foo) { debugger; }", "if (foo) {  }", None),
+        ("function foo() { debugger; return 1; }", "function foo() {  return 1; }", None),
+    ];
+
+    Tester::new(NoDebugger::NAME, pass, fail)
+        .expect_fix(fix)
+        .expect_fix_kind(FixKind::Safe)
+        .test_and_snapshot();
 }
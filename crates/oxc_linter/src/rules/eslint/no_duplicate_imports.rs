@@ -0,0 +1,278 @@
+use oxc_ast::{
+    ast::{ImportDeclarationSpecifier, ModuleDeclaration, Statement},
+    AstKind,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{Atom, Span};
+
+use crate::{context::LintContext, rule::Rule};
+
+#[derive(Debug, Error, Diagnostic)]
+enum NoDuplicateImportsDiagnostic {
+    #[error("eslint(no-duplicate-imports): '{0}' import is duplicated.")]
+    #[diagnostic(severity(warning), help("Merge the imports of this module into a single one"))]
+    Import(Atom, #[label] Span),
+    #[error("eslint(no-duplicate-imports): '{0}' import is duplicated as export.")]
+    #[diagnostic(severity(warning))]
+    ImportAs(Atom, #[label] Span),
+    #[error("eslint(no-duplicate-imports): '{0}' export is duplicated.")]
+    #[diagnostic(severity(warning), help("Merge the re-exports of this module into a single one"))]
+    Export(Atom, #[label] Span),
+    #[error("eslint(no-duplicate-imports): '{0}' export is duplicated as import.")]
+    #[diagnostic(severity(warning))]
+    ExportAs(Atom, #[label] Span),
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoDuplicateImports {
+    /// Also check the re-exports, `export ... from`
+    include_exports: bool,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow duplicate module imports
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Importing the same module in several statements makes it harder to see what is imported
+    /// from it, the imports which can be written as a single statement should be merged. With
+    /// `includeExports`, the re-exports of an imported module are checked as well.
+    ///
+    /// Unlike `import/no-duplicates`, the modules are compared by their specifiers, without
+    /// resolving them. Type only imports and exports are distinct from the value ones.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// // fail
+    /// import { merge } from 'module';
+    /// import something from 'another-module';
+    /// import { find } from 'module';
+    ///
+    /// // pass
+    /// import { merge, find } from 'module';
+    /// import something from 'another-module';
+    /// import * as namespace from 'module';
+    /// ```
+    NoDuplicateImports,
+    nursery
+);
+
+/// The shape of an import or a re-export, which decides whether two of them can be merged
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum DeclarationKind {
+    /// `import 'a'`, `import {} from 'a'`, `export {} from 'a'`
+    SideEffect,
+    /// `import a, { b } from 'a'`, `export { b } from 'a'`
+    Named,
+    /// `import * as a from 'a'`, `export * as a from 'a'`
+    Namespace,
+    /// `export * from 'a'`
+    ExportAll,
+}
+
+impl DeclarationKind {
+    fn can_merge(self, other: Self) -> bool {
+        match (self, other) {
+            (Self::ExportAll, other) | (other, Self::ExportAll) => {
+                matches!(other, Self::ExportAll | Self::SideEffect)
+            }
+            (Self::Namespace, other) | (other, Self::Namespace) => other == Self::Namespace,
+            _ => true,
+        }
+    }
+}
+
+struct Declaration<'a> {
+    source: &'a Atom,
+    is_type: bool,
+    is_import: bool,
+    kind: DeclarationKind,
+    span: Span,
+}
+
+impl Rule for NoDuplicateImports {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        Self {
+            include_exports: value
+                .get(0)
+                .and_then(|v| v.get("includeExports"))
+                .and_then(serde_json::Value::as_bool)
+                .unwrap_or_default(),
+        }
+    }
+
+    fn run_once(&self, ctx: &LintContext<'_>) {
+        let Some(root) = ctx.nodes().iter().next() else { return };
+        let AstKind::Program(program) = root.kind() else { return };
+
+        let mut seen: Vec<Declaration> = vec![];
+        for stmt in &program.body {
+            let Statement::ModuleDeclaration(decl) = stmt else { continue };
+            let Some(declaration) = self.declaration(decl) else { continue };
+            // Type only imports and exports are only duplicates of other type only ones
+            let can_merge = |is_import: bool| {
+                seen.iter().any(|other| {
+                    other.is_import == is_import
+                        && other.source == declaration.source
+                        && other.is_type == declaration.is_type
+                        && other.kind.can_merge(declaration.kind)
+                })
+            };
+            let source = declaration.source.clone();
+            let span = declaration.span;
+            if declaration.is_import {
+                if can_merge(true) {
+                    ctx.diagnostic(NoDuplicateImportsDiagnostic::Import(source.clone(), span));
+                }
+                if self.include_exports && can_merge(false) {
+                    ctx.diagnostic(NoDuplicateImportsDiagnostic::ImportAs(source, span));
+                }
+            } else {
+                if can_merge(false) {
+                    ctx.diagnostic(NoDuplicateImportsDiagnostic::Export(source.clone(), span));
+                }
+                if can_merge(true) {
+                    ctx.diagnostic(NoDuplicateImportsDiagnostic::ExportAs(source, span));
+                }
+            }
+            seen.push(declaration);
+        }
+    }
+}
+
+impl NoDuplicateImports {
+    /// The imports, and the re-exports with `includeExports`
+    fn declaration<'a>(&self, decl: &'a ModuleDeclaration) -> Option<Declaration<'a>> {
+        match decl {
+            ModuleDeclaration::ImportDeclaration(import) => {
+                let kind = match &import.specifiers {
+                    Some(specifiers) if !specifiers.is_empty() => {
+                        if specifiers.iter().any(|specifier| {
+                            matches!(
+                                specifier,
+                                ImportDeclarationSpecifier::ImportNamespaceSpecifier(_)
+                            )
+                        }) {
+                            DeclarationKind::Namespace
+                        } else {
+                            DeclarationKind::Named
+                        }
+                    }
+                    _ => DeclarationKind::SideEffect,
+                };
+                Some(Declaration {
+                    source: &import.source.value,
+                    is_type: import.import_kind.is_type(),
+                    is_import: true,
+                    kind,
+                    span: import.span,
+                })
+            }
+            ModuleDeclaration::ExportNamedDeclaration(export) if self.include_exports => {
+                let source = export.source.as_ref()?;
+                let kind = if export.specifiers.is_empty() {
+                    DeclarationKind::SideEffect
+                } else {
+                    DeclarationKind::Named
+                };
+                Some(Declaration {
+                    source: &source.value,
+                    is_type: export.export_kind.is_type(),
+                    is_import: false,
+                    kind,
+                    span: export.span,
+                })
+            }
+            ModuleDeclaration::ExportAllDeclaration(export) if self.include_exports => {
+                let kind = if export.exported.is_some() {
+                    DeclarationKind::Namespace
+                } else {
+                    DeclarationKind::ExportAll
+                };
+                Some(Declaration {
+                    source: &export.source.value,
+                    is_type: export.export_kind.is_type(),
+                    is_import: false,
+                    kind,
+                    span: export.span,
+                })
+            }
+            _ => None,
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let include_exports = || Some(serde_json::json!([{ "includeExports": true }]));
+
+    let pass = vec![
+        ("import os from \"os\";\nimport fs from \"fs\";", None),
+        ("import { merge } from \"lodash-es\";", None),
+        ("import _, { merge } from \"lodash-es\";", None),
+        ("import * as Foobar from \"async\";", None),
+        ("import \"foo\"", None),
+        ("import os from \"os\";\nexport { something } from \"os\";", None),
+        ("import * as bar from \"os\";\nimport { baz } from \"os\";", None),
+        ("import foo, * as bar from \"os\";\nimport { baz } from \"os\";", None),
+        ("import foo, { bar } from \"os\";\nimport * as baz from \"os\";", None),
+        ("export { a } from \"os\";\nexport { b } from \"os\";", None),
+        ("import os from \"os\";\nexport { hello } from \"hello\";", include_exports()),
+        ("import os from \"os\";\nexport * from \"hello\";", include_exports()),
+        ("import os from \"os\";\nexport { hello as hi } from \"hello\";", include_exports()),
+        ("import os from \"os\";\nexport default function(){};", include_exports()),
+        (
+            "import { merge } from \"lodash-es\";\nexport { merge as lodashMerge }",
+            include_exports(),
+        ),
+        ("export { something } from \"os\";\nexport * as os from \"os\";", include_exports()),
+        ("import { something } from \"os\";\nexport * as os from \"os\";", include_exports()),
+        ("import * as os from \"os\";\nexport { something } from \"os\";", include_exports()),
+        ("import os from \"os\";\nexport * from \"os\";", include_exports()),
+        ("export { something } from \"os\";\nexport * from \"os\";", include_exports()),
+        // Type only imports and exports are distinct from the value ones
+        ("import type { A } from \"a\";\nimport { B } from \"a\";", None),
+        ("import { A } from \"a\";\nimport type { B } from \"a\";", None),
+        ("import { type A } from \"a\";\nimport type { B } from \"a\";", None),
+        ("import { A } from \"a\";\nexport type { B } from \"a\";", include_exports()),
+        ("export type { A } from \"a\";\nexport { B } from \"a\";", include_exports()),
+        ("export type * from \"a\";\nexport * from \"a\";", include_exports()),
+    ];
+
+    let fail = vec![
+        ("import \"fs\"; import \"fs\"", None),
+        ("import { merge } from \"lodash-es\"; import { find } from \"lodash-es\";", None),
+        ("import { merge } from \"lodash-es\"; import _ from \"lodash-es\";", None),
+        (
+            "import os from \"os\"; import { something } from \"os\"; import * as foobar from \"os\";",
+            None,
+        ),
+        (
+            "import * as modns from \"lodash-es\"; import { merge } from \"lodash-es\"; import { baz } from \"lodash-es\";",
+            None,
+        ),
+        ("export { os } from \"os\"; export { something } from \"os\";", include_exports()),
+        (
+            "import os from \"os\"; export { os as foobar } from \"os\"; export { something } from \"os\";",
+            include_exports(),
+        ),
+        ("import os from \"os\"; export { something } from \"os\";", include_exports()),
+        ("export { something } from \"os\"; import os from \"os\";", include_exports()),
+        ("import * as modns from \"mod\"; export * as modns from \"mod\";", include_exports()),
+        ("export * from \"os\"; export * from \"os\";", include_exports()),
+        ("import \"os\"; export * from \"os\";", include_exports()),
+        ("import type { A } from \"a\"; import type { B } from \"a\";", None),
+        ("import type { A } from \"a\"; export type { B } from \"a\";", include_exports()),
+        ("export type * from \"a\"; export type * from \"a\";", include_exports()),
+    ];
+
+    Tester::new(NoDuplicateImports::NAME, pass, fail).test_and_snapshot();
+}
@@ -0,0 +1,213 @@
+use oxc_ast::{ast::Expression, AstKind};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{Atom, GetSpan, Span};
+
+use crate::{context::LintContext, globals::BUILTINS, rule::Rule, AstNode};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint(no-extend-native): {0} prototype is read only, properties should not be added.")]
+#[diagnostic(severity(warning))]
+struct NoExtendNativeDiagnostic(Atom, #[label] Span);
+
+#[derive(Debug, Default, Clone)]
+pub struct NoExtendNative(Box<NoExtendNativeConfig>);
+
+#[derive(Debug, Default, Clone)]
+pub struct NoExtendNativeConfig {
+    /// The built-in objects whose prototype may be extended
+    exceptions: Vec<String>,
+}
+
+impl std::ops::Deref for NoExtendNative {
+    type Target = NoExtendNativeConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow extending native types
+    ///
+    /// ### Why is this bad?
+    ///
+    /// The properties added to the prototype of a built-in object are available on all its
+    /// instances, they may conflict with the properties added by other libraries or future
+    /// versions of the language, e.g. they show up in the `for-in` loops over every object when
+    /// added to `Object.prototype`.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// // fail
+    /// Object.prototype.a = "a";
+    /// Object.defineProperty(Array.prototype, "times", { value: 999 });
+    ///
+    /// // pass
+    /// class MyArray extends Array {
+    ///     times() {}
+    /// }
+    /// ```
+    NoExtendNative,
+    nursery
+);
+
+impl Rule for NoExtendNative {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        Self(Box::new(NoExtendNativeConfig {
+            exceptions: value
+                .get(0)
+                .and_then(|v| v.get("exceptions"))
+                .and_then(serde_json::Value::as_array)
+                .map(|v| {
+                    v.iter()
+                        .filter_map(serde_json::Value::as_str)
+                        .map(ToString::to_string)
+                        .collect()
+                })
+                .unwrap_or_default(),
+        }))
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        // `Array.prototype`
+        let AstKind::MemberExpression(member_expr) = node.kind() else { return };
+        let Expression::Identifier(ident) = member_expr.object() else { return };
+        if member_expr.static_property_name() != Some("prototype")
+            || !self.is_restricted(&ident.name)
+            || !ctx.semantic().is_reference_to_global_variable(ident)
+        {
+            return;
+        }
+
+        // The prototype, with the optional chain and parentheses around it
+        let mut prototype = node;
+        let mut parent = ctx.nodes().parent_node(prototype.id());
+        while let Some(node) = parent {
+            if !matches!(
+                node.kind(),
+                AstKind::ChainExpression(_) | AstKind::ParenthesizedExpression(_)
+            ) {
+                break;
+            }
+            prototype = node;
+            parent = ctx.nodes().parent_node(node.id());
+        }
+        let Some(parent) = parent else { return };
+        let prototype_span = prototype.kind().span();
+
+        match parent.kind() {
+            // `Array.prototype.p = 0`
+            AstKind::MemberExpression(member_expr)
+                if member_expr.object().span() == prototype_span =>
+            {
+                let Some(assignment) = ctx.nodes().iter_parents(parent.id()).skip(1).find(|node| {
+                    !matches!(
+                        node.kind(),
+                        AstKind::SimpleAssignmentTarget(_) | AstKind::AssignmentTarget(_)
+                    )
+                }) else {
+                    return;
+                };
+                if let AstKind::AssignmentExpression(assignment_expr) = assignment.kind() {
+                    if assignment_expr.left.span() == member_expr.span() {
+                        ctx.diagnostic(NoExtendNativeDiagnostic(
+                            ident.name.clone(),
+                            assignment_expr.span,
+                        ));
+                    }
+                }
+            }
+            // `Object.defineProperty(Array.prototype, 'p', { value: 0 })`
+            AstKind::Argument(_) => {
+                let Some(AstKind::CallExpression(call_expr)) = ctx.nodes().parent_kind(parent.id())
+                else {
+                    return;
+                };
+                let is_first_argument =
+                    call_expr.arguments.first().is_some_and(|arg| arg.span() == prototype_span);
+                if is_first_argument
+                    && (call_expr.callee.is_specific_member_access("Object", "defineProperty")
+                        || call_expr.callee.is_specific_member_access("Object", "defineProperties"))
+                {
+                    ctx.diagnostic(NoExtendNativeDiagnostic(ident.name.clone(), call_expr.span));
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+impl NoExtendNative {
+    /// The built-in constructors, e.g. `Array`, which are not in the exceptions
+    fn is_restricted(&self, name: &str) -> bool {
+        name.starts_with(char::is_uppercase)
+            && BUILTINS.contains_key(name)
+            && !self.exceptions.iter().any(|exception| exception == name)
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("x.prototype.p = 0", None),
+        ("x.prototype['p'] = function() {}", None),
+        ("Object.p = 0", None),
+        ("Object.toString.bind = 0", None),
+        ("Object['toString'].bind = 0", None),
+        ("Object.defineProperty(x, 'p', {value: 0})", None),
+        ("Object.defineProperties(x, {p: {value: 0}})", None),
+        ("global.Object.prototype.toString = 0", None),
+        ("this.Object.prototype.toString = 0", None),
+        ("o = Object; o.prototype.toString = 0", None),
+        ("eval('Object.prototype.toString = 0')", None),
+        ("parseFloat.prototype.x = 1", None),
+        ("Object.prototype.g = 0", Some(serde_json::json!([{ "exceptions": ["Object"] }]))),
+        ("obj[Object.prototype] = 0", None),
+        ("Object.defineProperty()", None),
+        ("Object.defineProperties()", None),
+        ("function foo() { var Object = function() {}; Object.prototype.p = 0 }", None),
+        ("{ let Object = function() {}; Object.prototype.p = 0 }", None),
+        ("class Array {} Array.prototype.p = 0", None),
+        ("Object.defineProperty(x, 'p', Object.prototype)", None),
+        ("[Array.prototype.p] = foo", None),
+        ("Array.prototype.p", None),
+    ];
+
+    let fail = vec![
+        ("Object.prototype.p = 0", None),
+        ("BigInt.prototype.p = 0", None),
+        ("WeakRef.prototype.p = 0", None),
+        ("FinalizationRegistry.prototype.p = 0", None),
+        ("AggregateError.prototype.p = 0", None),
+        ("Function.prototype['p'] = 0", None),
+        ("String['prototype'].p = 0", None),
+        ("Number['prototype']['p'] = 0", None),
+        ("Object.defineProperty(Array.prototype, 'p', {value: 0})", None),
+        ("Object.defineProperties(Array.prototype, {p: {value: 0}})", None),
+        ("Object.defineProperties(Array.prototype, {p: {value: 0}, q: {value: 0}})", None),
+        ("Object.prototype.p = 0; Object.prototype.q = 0", None),
+        ("function foo() { Object.prototype.p = 0 }", None),
+        ("(Object?.prototype).p = 0", None),
+        ("(Object.prototype).p = 0", None),
+        ("Object.defineProperty(Object?.prototype, 'p', { value: 0 })", None),
+        ("Object?.defineProperty(Object.prototype, 'p', { value: 0 })", None),
+        ("(Object?.defineProperty)(Object.prototype, 'p', { value: 0 })", None),
+        ("Array.prototype.p &&= 0", None),
+        ("Array.prototype.p ||= 0", None),
+        ("Array.prototype.p ??= 0", None),
+        (
+            "Object.prototype.p = 0; Array.prototype.q = 0",
+            Some(serde_json::json!([{ "exceptions": ["Object"] }])),
+        ),
+    ];
+
+    Tester::new(NoExtendNative::NAME, pass, fail).test_and_snapshot();
+}
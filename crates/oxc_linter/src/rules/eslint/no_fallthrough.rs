@@ -1,41 +1,271 @@
+use lazy_static::lazy_static;
+use oxc_ast::{
+    ast::{Expression, Statement, SwitchCase},
+    AstKind,
+};
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
-    thiserror::Error,
+    thiserror::{self, Error},
 };
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
+use regex::Regex;
 
 use crate::{context::LintContext, rule::Rule, AstNode};
 
 // Ported from https://github.com/eslint/eslint/blob/main/lib/rules/no-fallthrough.js
 #[derive(Debug, Error, Diagnostic)]
-#[error("")]
-#[diagnostic(severity(warning), help(""))]
-struct NoFallthroughDiagnostic(#[label] pub Span);
+#[error("eslint(no-fallthrough): Expected a 'break' statement before '{1}'.")]
+#[diagnostic(
+    severity(warning),
+    help("Add a `break` statement, or a `/* falls through */` comment if it is intentional")
+)]
+struct NoFallthroughDiagnostic(#[label] pub Span, &'static str);
+
+#[derive(Debug, Default, Clone)]
+pub struct NoFallthrough(Box<NoFallthroughConfig>);
 
 #[derive(Debug, Default, Clone)]
-pub struct NoFallthrough;
+pub struct NoFallthroughConfig {
+    /// Replaces the default `falls?\s?through` pattern of the fallthrough comments
+    comment_pattern: Option<Regex>,
+    /// Allow empty cases separated by blank lines to fall through
+    allow_empty_case: bool,
+}
+
+impl std::ops::Deref for NoFallthrough {
+    type Target = NoFallthroughConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
 
 declare_oxc_lint!(
     /// ### What it does
     ///
+    /// Disallow fallthrough of `case` statements
     ///
     /// ### Why is this bad?
     ///
+    /// A `case` without a `break` continues into the next `case`, which is often a mistake.
+    /// Intentional fallthroughs should be marked with a comment matching `falls?\s?through`
+    /// right before the next `case`.
+    ///
+    /// ### Options
+    ///
+    /// * `commentPattern`: a regular expression for the fallthrough comments, instead of
+    ///   `falls?\s?through`.
+    /// * `allowEmptyCase`: allow empty cases to fall through even when they are followed by
+    ///   blank lines.
     ///
     /// ### Example
     /// ```javascript
+    /// switch (foo) {
+    ///   case 1:
+    ///     doSomething(); // reported
+    ///
+    ///   case 2:
+    ///     doSomething();
+    ///     // falls through
+    ///
+    ///   case 3:
+    ///     doSomething();
+    /// }
     /// ```
     NoFallthrough,
     nursery
 );
 
 impl Rule for NoFallthrough {
-    fn run<'a>(&self, _node: &AstNode<'a>, _ctx: &LintContext<'a>) {
-        // TODO
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let config = value.get(0);
+        let comment_pattern = config
+            .and_then(|config| config.get("commentPattern"))
+            .and_then(serde_json::Value::as_str)
+            .and_then(|pattern| Regex::new(pattern).ok());
+        let allow_empty_case = config
+            .and_then(|config| config.get("allowEmptyCase"))
+            .and_then(serde_json::Value::as_bool)
+            .unwrap_or_default();
+        Self(Box::new(NoFallthroughConfig { comment_pattern, allow_empty_case }))
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::SwitchStatement(switch) = node.kind() else { return };
+        for (case, next_case) in switch.cases.iter().zip(switch.cases.iter().skip(1)) {
+            if !statements_can_complete(&case.consequent) {
+                continue;
+            }
+            if case.consequent.is_empty()
+                && (self.allow_empty_case || !has_blank_lines_between(case, next_case, ctx))
+            {
+                continue;
+            }
+            if self.has_fallthrough_comment(case, next_case, ctx) {
+                continue;
+            }
+            let keyword = if next_case.test.is_some() { "case" } else { "default" };
+            ctx.diagnostic(NoFallthroughDiagnostic(next_case.span, keyword));
+        }
+    }
+}
+
+impl NoFallthrough {
+    /// The last comment of a block making up the whole case, or the last comment before the
+    /// next case
+    fn has_fallthrough_comment(
+        &self,
+        case: &SwitchCase,
+        next_case: &SwitchCase,
+        ctx: &LintContext<'_>,
+    ) -> bool {
+        if let [Statement::BlockStatement(block)] = case.consequent.as_slice() {
+            let comment = comment_before(block.span.end - 1, ctx);
+            if comment.is_some_and(|comment| self.is_fallthrough_comment(comment)) {
+                return true;
+            }
+        }
+        comment_before(next_case.span.start, ctx)
+            .is_some_and(|comment| self.is_fallthrough_comment(comment))
+    }
+
+    fn is_fallthrough_comment(&self, comment: &str) -> bool {
+        lazy_static! {
+            static ref DEFAULT_COMMENT_PATTERN: Regex =
+                Regex::new(r"(?i)falls?\s?through").unwrap();
+            static ref DIRECTIVE_PATTERN: Regex = Regex::new(
+                r"^(eslint(?:-env|-enable|-disable(?:(?:-next)?-line)?)?|exported|globals?)(?:\s|$)"
+            )
+            .unwrap();
+        }
+        let pattern = self.comment_pattern.as_ref().unwrap_or(&*DEFAULT_COMMENT_PATTERN);
+        pattern.is_match(comment) && !DIRECTIVE_PATTERN.is_match(comment.trim())
+    }
+}
+
+/// The text of the comment ending right before `end`, with only whitespace between them
+fn comment_before<'a>(end: u32, ctx: &LintContext<'a>) -> Option<&'a str> {
+    let (start, comment) = ctx.semantic().trivias().comments().range(..end).next_back()?;
+    let source_text = ctx.source_text();
+    // The span of a comment excludes the `/*` and `*/`
+    let comment_end = if comment.is_multi_line() { comment.end() + 2 } else { comment.end() };
+    source_text
+        .get(comment_end as usize..end as usize)?
+        .trim()
+        .is_empty()
+        .then(|| &source_text[*start as usize..comment.end() as usize])
+}
+
+/// Whether there are blank lines between the end of `case` and the next case, e.g. an empty
+/// case followed by blank lines is not a list of cases sharing the same body
+fn has_blank_lines_between(
+    case: &SwitchCase,
+    next_case: &SwitchCase,
+    ctx: &LintContext<'_>,
+) -> bool {
+    let text = &ctx.source_text()[case.span.end as usize..next_case.span.start as usize];
+    text.matches('\n').count() > 1
+}
+
+/// Whether the end of a list of statements can be reached, i.e. it does not end with a
+/// `break`, `continue`, `return` or `throw` in all of its branches, and is not an infinite loop
+fn statements_can_complete(stmts: &[Statement]) -> bool {
+    stmts.iter().all(can_complete)
+}
+
+fn can_complete(stmt: &Statement) -> bool {
+    match stmt {
+        Statement::BreakStatement(_)
+        | Statement::ContinueStatement(_)
+        | Statement::ReturnStatement(_)
+        | Statement::ThrowStatement(_) => false,
+        Statement::BlockStatement(block) => statements_can_complete(&block.body),
+        Statement::IfStatement(stmt) => {
+            can_complete(&stmt.consequent) || stmt.alternate.as_ref().map_or(true, can_complete)
+        }
+        Statement::TryStatement(stmt) => {
+            if stmt.finalizer.as_ref().is_some_and(|block| !statements_can_complete(&block.body)) {
+                return false;
+            }
+            statements_can_complete(&stmt.block.body)
+                || stmt
+                    .handler
+                    .as_ref()
+                    .is_some_and(|handler| statements_can_complete(&handler.body.body))
+        }
+        Statement::LabeledStatement(stmt) => {
+            can_complete(&stmt.body) || breaks_out(&stmt.body, Some(stmt.label.name.as_str()))
+        }
+        Statement::WhileStatement(stmt) => {
+            !is_true(Some(&stmt.test)) || breaks_out(&stmt.body, None)
+        }
+        Statement::ForStatement(stmt) => {
+            !is_true(stmt.test.as_ref()) || breaks_out(&stmt.body, None)
+        }
+        Statement::DoWhileStatement(stmt) => {
+            (can_complete(&stmt.body) && !is_true(Some(&stmt.test))) || breaks_out(&stmt.body, None)
+        }
+        Statement::SwitchStatement(stmt) => {
+            // Without a `default`, none of the cases may match
+            !stmt.cases.iter().any(SwitchCase::is_default_case)
+                || stmt.cases.last().map_or(true, |case| statements_can_complete(&case.consequent))
+                || stmt
+                    .cases
+                    .iter()
+                    .flat_map(|case| case.consequent.iter())
+                    .any(|stmt| breaks_out(stmt, None))
+        }
+        _ => true,
+    }
+}
+
+/// Whether `stmt` contains a `break` exiting the statement labeled `label`, or exiting the
+/// enclosing loop or switch if `label` is `None`
+fn breaks_out(stmt: &Statement, label: Option<&str>) -> bool {
+    match stmt {
+        Statement::BreakStatement(stmt) => match (&stmt.label, label) {
+            (None, None) => true,
+            (Some(break_label), Some(label)) => break_label.name.as_str() == label,
+            _ => false,
+        },
+        Statement::BlockStatement(block) => block.body.iter().any(|stmt| breaks_out(stmt, label)),
+        Statement::IfStatement(stmt) => {
+            breaks_out(&stmt.consequent, label)
+                || stmt.alternate.as_ref().is_some_and(|stmt| breaks_out(stmt, label))
+        }
+        Statement::TryStatement(stmt) => stmt
+            .block
+            .body
+            .iter()
+            .chain(stmt.handler.iter().flat_map(|handler| handler.body.body.iter()))
+            .chain(stmt.finalizer.iter().flat_map(|block| block.body.iter()))
+            .any(|stmt| breaks_out(stmt, label)),
+        Statement::LabeledStatement(stmt) => breaks_out(&stmt.body, label),
+        Statement::WithStatement(stmt) => breaks_out(&stmt.body, label),
+        // An unlabeled `break` in a nested loop or switch exits that loop or switch
+        Statement::WhileStatement(stmt) => label.is_some() && breaks_out(&stmt.body, label),
+        Statement::DoWhileStatement(stmt) => label.is_some() && breaks_out(&stmt.body, label),
+        Statement::ForStatement(stmt) => label.is_some() && breaks_out(&stmt.body, label),
+        Statement::ForInStatement(stmt) => label.is_some() && breaks_out(&stmt.body, label),
+        Statement::ForOfStatement(stmt) => label.is_some() && breaks_out(&stmt.body, label),
+        Statement::SwitchStatement(stmt) => {
+            label.is_some()
+                && stmt
+                    .cases
+                    .iter()
+                    .flat_map(|case| case.consequent.iter())
+                    .any(|stmt| breaks_out(stmt, label))
+        }
+        _ => false,
     }
 }
 
+/// `true` and a missing `for` test make an infinite loop
+fn is_true(test: Option<&Expression>) -> bool {
+    test.map_or(true, |test| matches!(test, Expression::BooleanLiteral(lit) if lit.value))
+}
+
 #[test]
 fn test() {
     use crate::tester::Tester;
@@ -135,60 +365,60 @@ fn test() {
     ];
 
     let fail = vec![
-        // ("switch(foo) { case 0: a();\ncase 1: b() }", None),
-        // ("switch(foo) { case 0: a();\ndefault: b() }", None),
-        // ("switch(foo) { case 0: a(); default: b() }", None),
-        // ("switch(foo) { case 0: if (a) { break; } default: b() }", None),
-        // ("switch(foo) { case 0: try { throw 0; } catch (err) {} default: b() }", None),
-        // ("switch(foo) { case 0: while (a) { break; } default: b() }", None),
-        // ("switch(foo) { case 0: do { break; } while (a); default: b() }", None),
-        // ("switch(foo) { case 0:\n\n default: b() }", None),
-        // ("switch(foo) { case 0: {} default: b() }", None),
-        // ("switch(foo) { case 0: a(); { /* falls through */ } default: b() }", None),
-        // ("switch(foo) { case 0: { /* falls through */ } a(); default: b() }", None),
-        // ("switch(foo) { case 0: if (a) { /* falls through */ } default: b() }", None),
-        // ("switch(foo) { case 0: { { /* falls through */ } } default: b() }", None),
-        // ("switch(foo) { case 0: { /* comment */ } default: b() }", None),
-        // ("switch(foo) { case 0:\n // comment\n default: b() }", None),
-        // ("switch(foo) { case 0: a(); /* falling through */ default: b() }", None),
-        // (
-        //     "switch(foo) { case 0: a();\n/* no break */\ncase 1: b(); }",
-        //     Some(serde_json::json!([{
-        //         "commentPattern": "break omitted"
-        //     }])),
-        // ),
-        // (
-        //     "switch(foo) { case 0: a();\n/* no break */\n/* todo: fix readability */\ndefault: b() }",
-        //     Some(serde_json::json!([{
-        //         "commentPattern": "no break"
-        //     }])),
-        // ),
-        // (
-        //     "switch(foo) { case 0: { a();\n/* no break */\n/* todo: fix readability */ }\ndefault: b() }",
-        //     Some(serde_json::json!([{
-        //         "commentPattern": "no break"
-        //     }])),
-        // ),
-        // ("switch(foo) { case 0: \n /* with comments */  \ncase 1: b(); }", None),
-        // (
-        //     "switch(foo) { case 0:\n\ncase 1: b(); }",
-        //     Some(serde_json::json!([{
-        //         "allowEmptyCase": false
-        //     }])),
-        // ),
-        // ("switch(foo) { case 0:\n\ncase 1: b(); }", Some(serde_json::json!([{}]))),
-        // (
-        //     "switch (a) { case 1: \n ; case 2:  }",
-        //     Some(serde_json::json!([{ "allowEmptyCase": false }])),
-        // ),
-        // (
-        //     "switch (a) { case 1: ; case 2: ; case 3: }",
-        //     Some(serde_json::json!([{ "allowEmptyCase": true }])),
-        // ),
-        // (
-        //     "switch (foo) { case 0: a(); \n// eslint-enable no-fallthrough\n case 1: }",
-        //     Some(serde_json::json!([{}])),
-        // ),
+        ("switch(foo) { case 0: a();\ncase 1: b() }", None),
+        ("switch(foo) { case 0: a();\ndefault: b() }", None),
+        ("switch(foo) { case 0: a(); default: b() }", None),
+        ("switch(foo) { case 0: if (a) { break; } default: b() }", None),
+        ("switch(foo) { case 0: try { throw 0; } catch (err) {} default: b() }", None),
+        ("switch(foo) { case 0: while (a) { break; } default: b() }", None),
+        ("switch(foo) { case 0: do { break; } while (a); default: b() }", None),
+        ("switch(foo) { case 0:\n\n default: b() }", None),
+        ("switch(foo) { case 0: {} default: b() }", None),
+        ("switch(foo) { case 0: a(); { /* falls through */ } default: b() }", None),
+        ("switch(foo) { case 0: { /* falls through */ } a(); default: b() }", None),
+        ("switch(foo) { case 0: if (a) { /* falls through */ } default: b() }", None),
+        ("switch(foo) { case 0: { { /* falls through */ } } default: b() }", None),
+        ("switch(foo) { case 0: { /* comment */ } default: b() }", None),
+        ("switch(foo) { case 0:\n // comment\n default: b() }", None),
+        ("switch(foo) { case 0: a(); /* falling through */ default: b() }", None),
+        (
+            "switch(foo) { case 0: a();\n/* no break */\ncase 1: b(); }",
+            Some(serde_json::json!([{
+                "commentPattern": "break omitted"
+            }])),
+        ),
+        (
+            "switch(foo) { case 0: a();\n/* no break */\n/* todo: fix readability */\ndefault: b() }",
+            Some(serde_json::json!([{
+                "commentPattern": "no break"
+            }])),
+        ),
+        (
+            "switch(foo) { case 0: { a();\n/* no break */\n/* todo: fix readability */ }\ndefault: b() }",
+            Some(serde_json::json!([{
+                "commentPattern": "no break"
+            }])),
+        ),
+        ("switch(foo) { case 0: \n /* with comments */  \ncase 1: b(); }", None),
+        (
+            "switch(foo) { case 0:\n\ncase 1: b(); }",
+            Some(serde_json::json!([{
+                "allowEmptyCase": false
+            }])),
+        ),
+        ("switch(foo) { case 0:\n\ncase 1: b(); }", Some(serde_json::json!([{}]))),
+        (
+            "switch (a) { case 1: \n ; case 2:  }",
+            Some(serde_json::json!([{ "allowEmptyCase": false }])),
+        ),
+        (
+            "switch (a) { case 1: ; case 2: ; case 3: }",
+            Some(serde_json::json!([{ "allowEmptyCase": true }])),
+        ),
+        (
+            "switch (foo) { case 0: a(); \n// eslint-enable no-fallthrough\n case 1: }",
+            Some(serde_json::json!([{}])),
+        ),
     ];
 
     Tester::new(NoFallthrough::NAME, pass, fail).test();
@@ -0,0 +1,183 @@
+use oxc_ast::{
+    ast::{LabelIdentifier, Statement},
+    AstKind,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+
+use crate::{context::LintContext, rule::Rule, AstNode};
+
+#[derive(Debug, Error, Diagnostic)]
+enum NoLabelsDiagnostic {
+    #[error("eslint(no-labels): Unexpected labeled statement.")]
+    #[diagnostic(severity(warning))]
+    Label(#[label] Span),
+    #[error("eslint(no-labels): Unexpected label in break statement.")]
+    #[diagnostic(severity(warning))]
+    Break(#[label] Span),
+    #[error("eslint(no-labels): Unexpected label in continue statement.")]
+    #[diagnostic(severity(warning))]
+    Continue(#[label] Span),
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoLabels {
+    /// Allow the labels of loops
+    allow_loop: bool,
+    /// Allow the labels of `switch` statements
+    allow_switch: bool,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow labeled statements
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Labels are rarely used, and make the control flow harder to follow as `break` and
+    /// `continue` jump to them like a `goto`. The labels of loops and `switch` statements are
+    /// allowed with the `allowLoop` and `allowSwitch` options.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// // fail
+    /// label:
+    ///     while (true) {
+    ///         break label;
+    ///     }
+    ///
+    /// // pass
+    /// while (true) {
+    ///     break;
+    /// }
+    /// ```
+    NoLabels,
+    nursery
+);
+
+impl Rule for NoLabels {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let option = |name: &str| {
+            value
+                .get(0)
+                .and_then(|v| v.get(name))
+                .and_then(serde_json::Value::as_bool)
+                .unwrap_or_default()
+        };
+        Self { allow_loop: option("allowLoop"), allow_switch: option("allowSwitch") }
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        match node.kind() {
+            AstKind::LabeledStatement(stmt) => {
+                if !self.is_allowed(&stmt.body) {
+                    ctx.diagnostic(NoLabelsDiagnostic::Label(stmt.label.span));
+                }
+            }
+            AstKind::BreakStatement(stmt) => {
+                let Some(label) = &stmt.label else { return };
+                if !self.is_allowed_label(label, node, ctx) {
+                    ctx.diagnostic(NoLabelsDiagnostic::Break(stmt.span));
+                }
+            }
+            AstKind::ContinueStatement(stmt) => {
+                let Some(label) = &stmt.label else { return };
+                if !self.is_allowed_label(label, node, ctx) {
+                    ctx.diagnostic(NoLabelsDiagnostic::Continue(stmt.span));
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+impl NoLabels {
+    /// Whether the label of `body` is allowed by the options
+    fn is_allowed(&self, body: &Statement) -> bool {
+        match body {
+            Statement::ForStatement(_)
+            | Statement::ForInStatement(_)
+            | Statement::ForOfStatement(_)
+            | Statement::WhileStatement(_)
+            | Statement::DoWhileStatement(_) => self.allow_loop,
+            Statement::SwitchStatement(_) => self.allow_switch,
+            _ => false,
+        }
+    }
+
+    /// Whether the nearest enclosing statement with `label` is allowed, the labels are never
+    /// allowed when they are not found, e.g. outside of the enclosing function
+    fn is_allowed_label(&self, label: &LabelIdentifier, node: &AstNode, ctx: &LintContext) -> bool {
+        ctx.nodes()
+            .iter_parents(node.id())
+            .take_while(|node| {
+                !matches!(
+                    node.kind(),
+                    AstKind::Function(_) | AstKind::ArrowExpression(_) | AstKind::StaticBlock(_)
+                )
+            })
+            .find_map(|node| match node.kind() {
+                AstKind::LabeledStatement(stmt) if stmt.label.name == label.name => Some(stmt),
+                _ => None,
+            })
+            .is_some_and(|stmt| self.is_allowed(&stmt.body))
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let allow_loop = || Some(serde_json::json!([{ "allowLoop": true }]));
+    let allow_switch = || Some(serde_json::json!([{ "allowSwitch": true }]));
+
+    let pass = vec![
+        ("var f = { label: foo ()}", None),
+        ("while (true) {}", None),
+        ("while (true) { break; }", None),
+        ("while (true) { continue; }", None),
+        ("A: while (a) { break A; }", allow_loop()),
+        ("A: do { if (b) { break A; } } while (a);", allow_loop()),
+        ("A: for (var a in obj) { for (;;) { switch (a) { case 0: continue A; } } }", allow_loop()),
+        ("A: switch (a) { case 0: break A; }", allow_switch()),
+        ("A: while (a) { A: while (b) { break A; } }", allow_loop()),
+    ];
+
+    let fail = vec![
+        ("label: while(true) {}", None),
+        ("label: while (true) { break label; }", None),
+        ("label: while (true) { continue label; }", None),
+        ("A: var foo = 0;", None),
+        ("A: break A;", None),
+        ("A: { if (foo()) { break A; } bar(); };", None),
+        ("A: if (a) { if (foo()) { break A; } bar(); };", None),
+        ("A: switch (a) { case 0: break A; default: break; };", None),
+        ("A: switch (a) { case 0: B: { break A; } default: break; };", None),
+        ("A: var foo = 0;", allow_loop()),
+        ("A: break A;", allow_loop()),
+        ("A: { if (foo()) { break A; } bar(); };", allow_loop()),
+        ("A: if (a) { if (foo()) { break A; } bar(); };", allow_loop()),
+        ("A: switch (a) { case 0: break A; default: break; };", allow_loop()),
+        ("A: var foo = 0;", allow_switch()),
+        ("A: break A;", allow_switch()),
+        ("A: { if (foo()) { break A; } bar(); };", allow_switch()),
+        ("A: if (a) { if (foo()) { break A; } bar(); };", allow_switch()),
+        ("A: while (a) { break A; }", allow_switch()),
+        ("A: do { if (b) { break A; } } while (a);", allow_switch()),
+        ("A: for (var a in obj) { for (;;) { switch (a) { case 0: break A; } } }", allow_switch()),
+        // The nearest label with the same name is used
+        ("A: while (a) { A: { break A; } }", allow_loop()),
+        ("A: { A: while (a) { break A; } }", allow_loop()),
+        // A syntax error, the labels outside of the function are not visible
+        ("A: while (a) { function f() { break A; } }", allow_loop()),
+        ("A: while (a) { () => { continue A; } }", allow_loop()),
+        ("A: { function f() { A: while (a) { continue A; } } }", allow_loop()),
+    ];
+
+    Tester::new(NoLabels::NAME, pass, fail).test_and_snapshot();
+}
@@ -0,0 +1,151 @@
+use oxc_ast::AstKind;
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_semantic::SymbolFlags;
+use oxc_span::Span;
+
+use crate::{context::LintContext, rule::Rule, AstNode};
+
+#[derive(Debug, Error, Diagnostic)]
+enum NoLoneBlocksDiagnostic {
+    #[error("eslint(no-lone-blocks): Block is redundant.")]
+    #[diagnostic(severity(warning), help("Remove the braces of this block"))]
+    Redundant(#[label] Span),
+    #[error("eslint(no-lone-blocks): Nested block is redundant.")]
+    #[diagnostic(severity(warning), help("Remove the braces of this block"))]
+    RedundantNested(#[label] Span),
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoLoneBlocks;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow unnecessary nested blocks
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Blocks which are not the body of a statement only limit the scope of the `let`, `const`,
+    /// `class` and, in strict mode, `function` declarations in them. Without such declarations,
+    /// they are redundant and may be confused with object literals or statement bodies.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// // fail
+    /// {}
+    ///
+    /// if (foo) {
+    ///     bar();
+    ///     {
+    ///         baz();
+    ///     }
+    /// }
+    ///
+    /// // pass
+    /// {
+    ///     let x = 1;
+    /// }
+    ///
+    /// switch (foo) {
+    ///     case 1: {
+    ///         bar();
+    ///     }
+    /// }
+    /// ```
+    NoLoneBlocks,
+    nursery
+);
+
+impl Rule for NoLoneBlocks {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::BlockStatement(block) = node.kind() else { return };
+        let Some(parent) = ctx.nodes().parent_node(node.id()) else { return };
+        let is_nested = match parent.kind() {
+            AstKind::Program(_) => false,
+            AstKind::BlockStatement(_) | AstKind::FunctionBody(_) | AstKind::StaticBlock(_) => true,
+            // The block of a case is only redundant if it is not its single statement
+            AstKind::SwitchCase(case) => {
+                if case.consequent.len() == 1 {
+                    return;
+                }
+                false
+            }
+            _ => return,
+        };
+
+        // The scope of a block statement is the one it creates, function declarations are only
+        // scoped to the block in strict mode
+        let declares_bindings =
+            ctx.scopes().get_bindings(node.scope_id()).values().any(|symbol_id| {
+                ctx.symbols()
+                    .get_flag(*symbol_id)
+                    .intersects(SymbolFlags::BlockScopedVariable | SymbolFlags::Class)
+            });
+        if declares_bindings {
+            return;
+        }
+
+        ctx.diagnostic(if is_nested {
+            NoLoneBlocksDiagnostic::RedundantNested(block.span)
+        } else {
+            NoLoneBlocksDiagnostic::Redundant(block.span)
+        });
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "if (foo) { if (bar) { baz(); } }",
+        "do { bar(); } while (foo)",
+        "function foo() { while (bar) { baz() } }",
+        "{ let x = 1; }",
+        "{ const y = 1; }",
+        "{ class Bar {} }",
+        "{ {let y = 1;} let x = 1; }",
+        "switch (foo) { case bar: { baz; } }",
+        "switch (foo) { case bar: { baz; } case qux: { boop; } }",
+        "switch (foo) { case bar: { baz; } default: { boop; } }",
+        "function foo() { { const x = 4 } const x = 3 }",
+        // Reported by ESLint, but without the braces `x` would be declared twice
+        "function foo () { {const x = 4;} const x = 3}",
+        "{ function bar() {} }",
+        "'use strict'; { function bar() {} }",
+        "function foo() { 'use strict'; { function bar() {} } }",
+        "foo: { bar(); }",
+        "try { foo(); } catch (e) { bar(); } finally { baz(); }",
+        "class C { static { if (foo) { block; } } }",
+        "class C { static { lbl: { block; } } }",
+        "class C { static { { let block; } something; } }",
+        "class C { static { something; { const block = 1; } } }",
+        "class C { static { { function block(){} } something; } }",
+        "class C { static { something; { class block {}  } } }",
+    ];
+
+    let fail = vec![
+        "{}",
+        "{var x = 1;}",
+        "foo(); {} bar();",
+        "if (foo) { bar(); {} baz(); }",
+        "{ { } }",
+        "function foo() { bar(); {} baz(); }",
+        "while (foo) { {} }",
+        "{ {var x = 1;} let y = 2; } {let z = 1;}",
+        "{ {let x = 1;} var y = 2; } {let z = 1;}",
+        "{ {var x = 1;} var y = 2; } {var z = 1;}",
+        "switch (foo) { case 1: foo(); { a; } }",
+        "switch (foo) { case 1: { a; } foo(); }",
+        "class C { static { something; { block; } } }",
+        "class C { static { { block; } something; } }",
+        "class C { static { { block; { other; } } something; } }",
+        "class C { static { { var block; } something; } }",
+    ];
+
+    Tester::new_without_config(NoLoneBlocks::NAME, pass, fail).test_and_snapshot();
+}
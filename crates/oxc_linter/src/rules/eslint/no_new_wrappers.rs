@@ -0,0 +1,116 @@
+use oxc_ast::{ast::Expression, AstKind};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{Atom, GetSpan, Span};
+
+use crate::{context::LintContext, rule::Rule, AstNode, Fix};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint(no-new-wrappers): Do not use {0} as a constructor.")]
+#[diagnostic(
+    severity(warning),
+    help("Call it without `new` to convert the value to a primitive instead of wrapping it in an object")
+)]
+struct NoNewWrappersDiagnostic(Atom, #[label] Span);
+
+#[derive(Debug, Default, Clone)]
+pub struct NoNewWrappers;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow `new` operators with the `String`, `Number`, and `Boolean` objects
+    ///
+    /// ### Why is this bad?
+    ///
+    /// The primitive wrapper constructors create objects instead of primitive values, which
+    /// behave unexpectedly, e.g. `typeof new String("a")` is `"object"` and
+    /// `new Boolean(false)` is truthy.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// // fail
+    /// var stringObject = new String("Hello world");
+    /// var numberObject = new Number(33);
+    /// var booleanObject = new Boolean(false);
+    ///
+    /// // pass
+    /// var text = String(someValue);
+    /// var num = Number(someValue);
+    /// var object = new MyString();
+    /// ```
+    NoNewWrappers,
+    nursery
+);
+
+impl Rule for NoNewWrappers {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::NewExpression(new_expr) = node.kind() else { return };
+        let Expression::Identifier(ident) = new_expr.callee.without_parenthesized() else {
+            return;
+        };
+        if !matches!(ident.name.as_str(), "String" | "Number" | "Boolean")
+            || !ctx.semantic().is_reference_to_global_variable(ident)
+        {
+            return;
+        }
+
+        let diagnostic = NoNewWrappersDiagnostic(ident.name.clone(), new_expr.span);
+        // `new String(a)` to `String(a)`, and `new String` to `String()`
+        let callee_span = new_expr.callee.span();
+        let text = if new_expr.span.end == callee_span.end {
+            format!("{}()", callee_span.source_text(ctx.source_text()))
+        } else {
+            Span::new(callee_span.start, new_expr.span.end)
+                .source_text(ctx.source_text())
+                .to_string()
+        };
+        // The result is a primitive value instead of an object, so it is only a suggestion
+        let remove_new = Fix::new(text, new_expr.span).with_message(
+            "Remove `new` to convert the value to a primitive. This changes the type of the value.",
+        );
+        ctx.diagnostic_with_suggestions(diagnostic, vec![remove_new]);
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("var a = new Object();", None),
+        ("var a = String('test'), b = String.fromCharCode(32);", None),
+        ("function test(Number) { return new Number; }", None),
+        ("var String = function() {}; var a = new String('a');", None),
+        ("function foo() { let Boolean = class {}; return new Boolean(); }", None),
+        ("class Number {} new Number(1);", None),
+        ("new foo.String('a');", None),
+        ("new window.Number(1);", None),
+    ];
+
+    let fail = vec![
+        ("var a = new String('hello');", None),
+        ("var a = new Number(10);", None),
+        ("var a = new Boolean(false);", None),
+        ("var a = new (String)('hello');", None),
+        ("var a = new Number;", None),
+        ("var a = new Boolean(new String('a'));", None),
+        ("function foo() { return new String(a); }", None),
+    ];
+
+    let suggestions = vec![
+        ("var a = new String('hello');", vec!["var a = String('hello');"], None),
+        ("var a = new Number(10);", vec!["var a = Number(10);"], None),
+        ("var a = new (String)('hello');", vec!["var a = (String)('hello');"], None),
+        ("var a = new Number;", vec!["var a = Number();"], None),
+        ("var a = new Boolean ( a , b );", vec!["var a = Boolean ( a , b );"], None),
+        ("new String('a').length", vec!["String('a').length"], None),
+    ];
+
+    Tester::new(NoNewWrappers::NAME, pass, fail)
+        .expect_suggestions(suggestions)
+        .test_and_snapshot();
+}
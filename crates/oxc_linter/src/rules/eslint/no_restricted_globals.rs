@@ -0,0 +1,158 @@
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{Atom, Span};
+
+use crate::{context::LintContext, rule::Rule};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint(no-restricted-globals): Unexpected use of '{0}'.")]
+#[diagnostic(severity(warning))]
+struct NoRestrictedGlobalsDiagnostic(Atom, #[help] Option<String>, #[label] Span);
+
+#[derive(Debug, Default, Clone)]
+pub struct NoRestrictedGlobals(Box<NoRestrictedGlobalsConfig>);
+
+#[derive(Debug, Default, Clone)]
+pub struct NoRestrictedGlobalsConfig {
+    /// The restricted global names, with the message explaining why
+    ///
+    /// ```json
+    /// ["event", { "name": "fdescribe", "message": "Do not commit fdescribe. Use describe instead." }]
+    /// ```
+    pub restricted_globals: Vec<(String, Option<String>)>,
+}
+
+impl std::ops::Deref for NoRestrictedGlobals {
+    type Target = NoRestrictedGlobalsConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow specified global variables
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Some global variables should not be used, e.g. the browser's `event` is easily mistaken
+    /// for a missing parameter. This rule reports the references to the configured globals,
+    /// variables declared with the same names are not reported.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// // "no-restricted-globals": ["error", "event", "fdescribe"]
+    ///
+    /// // fail
+    /// function onClick() {
+    ///     console.log(event);
+    /// }
+    ///
+    /// fdescribe("foo", function() {});
+    ///
+    /// // pass
+    /// function onClick(event) {
+    ///     console.log(event);
+    /// }
+    /// ```
+    NoRestrictedGlobals,
+    nursery
+);
+
+impl Rule for NoRestrictedGlobals {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let restricted_globals = value
+            .as_array()
+            .map(|globals| {
+                globals
+                    .iter()
+                    .filter_map(|global| match global {
+                        serde_json::Value::String(name) => Some((name.clone(), None)),
+                        serde_json::Value::Object(global) => {
+                            let name = global.get("name")?.as_str()?.to_string();
+                            let message = global
+                                .get("message")
+                                .and_then(serde_json::Value::as_str)
+                                .map(ToString::to_string);
+                            Some((name, message))
+                        }
+                        _ => None,
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+        Self(Box::new(NoRestrictedGlobalsConfig { restricted_globals }))
+    }
+
+    fn run_once(&self, ctx: &LintContext) {
+        let unresolved_references = ctx.scopes().root_unresolved_references();
+        for (name, message) in &self.restricted_globals {
+            let Some(reference_ids) = unresolved_references.get(name.as_str()) else { continue };
+            for &reference_id in reference_ids {
+                let reference = ctx.symbols().get_reference(reference_id);
+                ctx.diagnostic(NoRestrictedGlobalsDiagnostic(
+                    reference.name().clone(),
+                    message.clone(),
+                    reference.span(),
+                ));
+            }
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("foo", None),
+        ("foo", Some(serde_json::json!(["bar"]))),
+        ("var foo = 1;", Some(serde_json::json!(["foo"]))),
+        ("event", None),
+        ("import foo from 'bar';", Some(serde_json::json!(["foo"]))),
+        ("function foo() {}", Some(serde_json::json!(["foo"]))),
+        ("function fn() { var foo; }", Some(serde_json::json!(["foo"]))),
+        ("foo.bar", Some(serde_json::json!(["bar"]))),
+        ("foo", Some(serde_json::json!([{ "name": "bar", "message": "Use baz instead." }]))),
+        ("let event; event = 1;", Some(serde_json::json!(["event"]))),
+        ("function onClick(event) { console.log(event); }", Some(serde_json::json!(["event"]))),
+        ("function f() { let event = 1; return event; }", Some(serde_json::json!(["event"]))),
+        ("const obj = { event: 1 }; obj.event;", Some(serde_json::json!(["event"]))),
+    ];
+
+    let fail = vec![
+        ("foo", Some(serde_json::json!(["foo"]))),
+        ("function fn() { foo; }", Some(serde_json::json!(["foo"]))),
+        ("foo = 'bar';", Some(serde_json::json!(["foo"]))),
+        ("foo.bar", Some(serde_json::json!(["foo"]))),
+        ("typeof foo", Some(serde_json::json!(["foo"]))),
+        ("function onClick() { console.log(event); }", Some(serde_json::json!(["event"]))),
+        ("{ let event = 1; } event;", Some(serde_json::json!(["event"]))),
+        ("fdescribe('foo', function() {});", Some(serde_json::json!(["event", "fdescribe"]))),
+        (
+            "fdescribe('foo', function() {});",
+            Some(serde_json::json!([{
+                "name": "fdescribe",
+                "message": "Do not commit fdescribe. Use describe instead."
+            }])),
+        ),
+        (
+            "event.target; fdescribe('foo', function() {});",
+            Some(serde_json::json!([
+                "event",
+                { "name": "fdescribe", "message": "Do not commit fdescribe. Use describe instead." }
+            ])),
+        ),
+        (
+            "foo(event);",
+            Some(serde_json::json!([{ "name": "event", "message": "Use local event parameter." }])),
+        ),
+    ];
+
+    Tester::new(NoRestrictedGlobals::NAME, pass, fail).test_and_snapshot();
+}
@@ -0,0 +1,211 @@
+use oxc_ast::{
+    ast::{Expression, SequenceExpression},
+    AstKind,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+
+use crate::{context::LintContext, rule::Rule, AstNode};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint(no-sequences): Unexpected use of comma operator.")]
+#[diagnostic(severity(warning))]
+struct NoSequencesDiagnostic(#[label] Span);
+
+#[derive(Debug, Clone)]
+pub struct NoSequences {
+    /// Allow the sequences wrapped in parentheses, e.g. `a = (b, c)`. Default is true.
+    allow_in_parentheses: bool,
+}
+
+impl Default for NoSequences {
+    fn default() -> Self {
+        Self { allow_in_parentheses: true }
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow comma operators
+    ///
+    /// ### Why is this bad?
+    ///
+    /// The comma operator evaluates its operands and only returns the last one, which hides
+    /// side effects and is often a typo. It is allowed in the initialization and update of `for`
+    /// statements, and when the sequence is explicitly wrapped in parentheses, which take an
+    /// extra pair in the test of statements and the body of arrow functions.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// // fail
+    /// foo = doSomething(), val;
+    /// while (val = foo(), val < 42);
+    /// const fn = () => (doSomething(), val);
+    ///
+    /// // pass
+    /// foo = (doSomething(), val);
+    /// while ((val = foo(), val < 42));
+    /// const fn = () => ((doSomething(), val));
+    /// for (i = 0, j = 10; i < j; i++, j--);
+    /// ```
+    NoSequences,
+    nursery
+);
+
+impl Rule for NoSequences {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        Self {
+            allow_in_parentheses: value
+                .get(0)
+                .and_then(|v| v.get("allowInParentheses"))
+                .and_then(serde_json::Value::as_bool)
+                .unwrap_or(true),
+        }
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::SequenceExpression(expr) = node.kind() else { return };
+        if is_for_init_or_update(expr, node, ctx) {
+            return;
+        }
+        if self.allow_in_parentheses {
+            let parentheses = count_parentheses(node, ctx);
+            if parentheses > 0 && parentheses >= required_parentheses(node, ctx) {
+                return;
+            }
+        }
+        let Some(first) = expr.expressions.first() else { return };
+        ctx.diagnostic(NoSequencesDiagnostic(first_comma(first.span(), ctx)));
+    }
+}
+
+/// `for (a, b; ; c, d)`
+fn is_for_init_or_update(expr: &SequenceExpression, node: &AstNode, ctx: &LintContext<'_>) -> bool {
+    match ctx.nodes().parent_kind(node.id()) {
+        Some(AstKind::ForStatementInit(_)) => true,
+        Some(AstKind::ForStatement(stmt)) => {
+            matches!(&stmt.update, Some(Expression::SequenceExpression(update)) if update.span == expr.span)
+        }
+        _ => false,
+    }
+}
+
+/// The number of parentheses wrapping `node`
+fn count_parentheses(node: &AstNode, ctx: &LintContext<'_>) -> usize {
+    ctx.nodes()
+        .iter_parents(node.id())
+        .skip(1)
+        .take_while(|parent| matches!(parent.kind(), AstKind::ParenthesizedExpression(_)))
+        .count()
+}
+
+/// The number of parentheses which make the sequence explicitly parenthesized. The parentheses of
+/// the test of statements are part of their syntax, and those of arrow functions are needed to
+/// return the sequence, so the sequence needs an extra pair there.
+fn required_parentheses(node: &AstNode, ctx: &LintContext<'_>) -> usize {
+    let Some(parent) = ctx
+        .nodes()
+        .iter_parents(node.id())
+        .skip(1)
+        .find(|parent| !matches!(parent.kind(), AstKind::ParenthesizedExpression(_)))
+    else {
+        return 1;
+    };
+    match parent.kind() {
+        AstKind::ExpressionStatement(_) => {
+            // The expression body of an arrow function is an expression statement
+            let is_arrow_body = ctx.nodes().parent_node(parent.id()).is_some_and(|body| {
+                matches!(
+                    ctx.nodes().parent_kind(body.id()),
+                    Some(AstKind::ArrowExpression(arrow)) if arrow.expression
+                )
+            });
+            if is_arrow_body {
+                2
+            } else {
+                1
+            }
+        }
+        _ => 1,
+    }
+}
+
+/// The span of the first comma after `first`, skipping its closing parentheses and comments
+fn first_comma(first: Span, ctx: &LintContext<'_>) -> Span {
+    let source_text = ctx.source_text();
+    let mut offset = first.end as usize;
+    while let Some(rest) = source_text.get(offset..) {
+        if rest.starts_with(',') {
+            break;
+        }
+        if rest.starts_with("//") {
+            offset += rest.find('\n').unwrap_or(rest.len());
+        } else if rest.starts_with("/*") {
+            offset += rest.find("*/").map_or(rest.len(), |end| end + 2);
+        } else if let Some(c) = rest.chars().next() {
+            offset += c.len_utf8();
+        } else {
+            break;
+        }
+    }
+    #[allow(clippy::cast_possible_truncation)]
+    let start = offset as u32;
+    Span::new(start, start + 1)
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let allow_in_parentheses_false = || Some(serde_json::json!([{ "allowInParentheses": false }]));
+
+    let pass = vec![
+        ("var arr = [1, 2];", None),
+        ("var obj = {a: 1, b: 2};", None),
+        ("var a = 1, b = 2;", None),
+        ("var foo = (1, 2);", None),
+        ("(0,eval)(\"foo()\");", None),
+        ("for (i = 1, j = 2;; i++, j++);", None),
+        ("foo(a, (b, c), d);", None),
+        ("do {} while ((doSomething(), !!test));", None),
+        ("for ((doSomething(), somethingElse()); (doSomething(), !!test); );", None),
+        ("if ((doSomething(), !!test));", None),
+        ("switch ((doSomething(), val)) {}", None),
+        ("while ((doSomething(), !!test));", None),
+        ("a => ((doSomething(), a))", None),
+        ("for (i = 1, j = 2;; i++, j++);", allow_in_parentheses_false()),
+        ("let a = (1, 2), b = 3;", None),
+        ("const fn = () => { return (doSomething(), a); };", None),
+    ];
+
+    let fail = vec![
+        ("1, 2;", None),
+        ("a = 1, 2", None),
+        ("do {} while (doSomething(), !!test);", None),
+        ("for (; doSomething(), !!test; );", None),
+        ("if (doSomething(), !!test);", None),
+        ("switch (doSomething(), val) {}", None),
+        ("while (doSomething(), !!test);", None),
+        ("a => (doSomething(), a)", None),
+        ("(1), 2", None),
+        ("((1)) , (2)", None),
+        ("while((1) , 2);", None),
+        ("a /* , */ , b", None),
+        ("var foo = (1, 2);", allow_in_parentheses_false()),
+        ("(0,eval)(\"foo()\");", allow_in_parentheses_false()),
+        ("foo(a, (b, c), d);", allow_in_parentheses_false()),
+        ("do {} while ((doSomething(), !!test));", allow_in_parentheses_false()),
+        ("for (; (doSomething(), !!test); );", allow_in_parentheses_false()),
+        ("if ((doSomething(), !!test));", allow_in_parentheses_false()),
+        ("switch ((doSomething(), val)) {}", allow_in_parentheses_false()),
+        ("while ((doSomething(), !!test));", allow_in_parentheses_false()),
+        ("a => ((doSomething(), a));", allow_in_parentheses_false()),
+    ];
+
+    Tester::new(NoSequences::NAME, pass, fail).test_and_snapshot();
+}
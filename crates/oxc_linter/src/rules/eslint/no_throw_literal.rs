@@ -0,0 +1,159 @@
+use oxc_ast::{ast::Expression, AstKind};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+use oxc_syntax::operator::{AssignmentOperator, LogicalOperator};
+
+use crate::{context::LintContext, rule::Rule, AstNode};
+
+#[derive(Debug, Error, Diagnostic)]
+enum NoThrowLiteralDiagnostic {
+    #[error("eslint(no-throw-literal): Expected an error object to be thrown.")]
+    #[diagnostic(severity(warning), help("Throw an `Error` object, e.g. `new Error('message')`"))]
+    Object(#[label] Span),
+    #[error("eslint(no-throw-literal): Do not throw undefined.")]
+    #[diagnostic(severity(warning), help("Throw an `Error` object, e.g. `new Error('message')`"))]
+    Undefined(#[label] Span),
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoThrowLiteral;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow throwing literals as exceptions
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Only `Error` objects have a stack trace and the properties expected by the code catching
+    /// them. This rule only reports the expressions which cannot be an `Error` object, e.g.
+    /// literals and string concatenations, identifiers and calls may be errors.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// // fail
+    /// throw "error";
+    /// throw 0;
+    /// throw undefined;
+    /// throw "an " + err;
+    ///
+    /// // pass
+    /// throw new Error("error");
+    /// throw err;
+    /// ```
+    NoThrowLiteral,
+    nursery
+);
+
+impl Rule for NoThrowLiteral {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::ThrowStatement(stmt) = node.kind() else { return };
+        if !could_be_error(&stmt.argument) {
+            ctx.diagnostic(NoThrowLiteralDiagnostic::Object(stmt.span));
+        } else if matches!(&stmt.argument, Expression::Identifier(ident) if ident.name == "undefined")
+        {
+            ctx.diagnostic(NoThrowLiteralDiagnostic::Undefined(stmt.span));
+        }
+    }
+}
+
+/// Whether the value of `expr` may be an `Error` object
+fn could_be_error(expr: &Expression) -> bool {
+    match expr {
+        Expression::Identifier(_)
+        | Expression::CallExpression(_)
+        | Expression::NewExpression(_)
+        | Expression::MemberExpression(_)
+        | Expression::TaggedTemplateExpression(_)
+        | Expression::YieldExpression(_)
+        | Expression::AwaitExpression(_)
+        | Expression::ChainExpression(_)
+        | Expression::ImportExpression(_) => true,
+        Expression::AssignmentExpression(expr) => match expr.operator {
+            AssignmentOperator::Assign | AssignmentOperator::LogicalAnd => {
+                could_be_error(&expr.right)
+            }
+            // The target keeps its value if it is not nullish or falsy
+            AssignmentOperator::LogicalOr | AssignmentOperator::LogicalNullish => true,
+            _ => false,
+        },
+        Expression::SequenceExpression(expr) => expr.expressions.last().is_some_and(could_be_error),
+        Expression::LogicalExpression(expr) => {
+            if expr.operator == LogicalOperator::And {
+                could_be_error(&expr.right)
+            } else {
+                could_be_error(&expr.left) || could_be_error(&expr.right)
+            }
+        }
+        Expression::ConditionalExpression(expr) => {
+            could_be_error(&expr.consequent) || could_be_error(&expr.alternate)
+        }
+        Expression::ParenthesizedExpression(expr) => could_be_error(&expr.expression),
+        Expression::TSAsExpression(expr) => could_be_error(&expr.expression),
+        Expression::TSSatisfiesExpression(expr) => could_be_error(&expr.expression),
+        Expression::TSTypeAssertion(expr) => could_be_error(&expr.expression),
+        Expression::TSNonNullExpression(expr) => could_be_error(&expr.expression),
+        _ => false,
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "throw new Error();",
+        "throw new Error('error');",
+        "throw Error('error');",
+        "var e = new Error(); throw e;",
+        "try {throw new Error();} catch (e) {throw e;};",
+        "throw a;",
+        "throw foo();",
+        "throw new foo();",
+        "throw foo.bar;",
+        "throw foo[bar];",
+        "class C { #field; foo() { throw foo.#field; } }",
+        "throw foo = new Error();",
+        "throw foo.bar ||= 'literal'",
+        "throw foo[bar] ??= 'literal'",
+        "throw 1, 2, new Error();",
+        "throw 'literal' && new Error();",
+        "throw new Error() || 'literal';",
+        "throw foo ? new Error() : 'literal';",
+        "throw foo ? 'literal' : new Error();",
+        "throw tag `${foo}`;",
+        "function* foo() { var index = 0; throw yield index; }",
+        "async function foo() { throw await bar; }",
+        "throw obj?.foo",
+        "throw obj?.foo()",
+        "throw (new Error());",
+        "throw err as Error;",
+    ];
+
+    let fail = vec![
+        "throw 'error';",
+        "throw 0;",
+        "throw false;",
+        "throw null;",
+        "throw {};",
+        "throw undefined;",
+        "throw 'a' + 'b';",
+        "var b = new Error(); throw 'a' + b;",
+        "throw foo = 'error';",
+        "throw foo += new Error();",
+        "throw foo &= new Error();",
+        "throw foo &&= 'literal'",
+        "throw new Error(), 1, 2, 3;",
+        "throw 'literal' && 'not an Error';",
+        "throw foo && 'literal'",
+        "throw foo ? 'not an Error' : 'literal';",
+        "throw `${err}`;",
+        "throw ('error');",
+    ];
+
+    Tester::new_without_config(NoThrowLiteral::NAME, pass, fail).test_and_snapshot();
+}
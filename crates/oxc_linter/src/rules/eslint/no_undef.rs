@@ -7,7 +7,7 @@ use oxc_macros::declare_oxc_lint;
 use oxc_span::{Atom, Span};
 use oxc_syntax::operator::UnaryOperator;
 
-use crate::{context::LintContext, globals::BUILTINS, rule::Rule, AstNode};
+use crate::{context::LintContext, rule::Rule, AstNode};
 
 #[derive(Debug, Error, Diagnostic)]
 #[error("eslint(no-undef): Disallow the use of undeclared variables")]
@@ -53,13 +53,13 @@ impl Rule for NoUndef {
         for reference_id_list in ctx.scopes().root_unresolved_references().values() {
             for &reference_id in reference_id_list {
                 let reference = symbol_table.get_reference(reference_id);
-                if BUILTINS.contains_key(reference.name().as_str()) {
-                    return;
+                if ctx.global_value(reference.name().as_str()).is_some() {
+                    continue;
                 }
 
                 let node = ctx.nodes().get_node(reference.node_id());
                 if !self.type_of && has_typeof_operator(node, ctx) {
-                    return;
+                    continue;
                 }
 
                 ctx.diagnostic(NoUndefDiagnostic(reference.name().clone(), reference.span()));
@@ -0,0 +1,161 @@
+use oxc_ast::{
+    ast::{BindingPatternKind, Expression, VariableDeclarationKind, VariableDeclarator},
+    AstKind,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+
+use crate::{context::LintContext, rule::Rule, AstNode, Fix, FixKind};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint(no-undef-init): It's not necessary to initialize '{0}' to undefined.")]
+#[diagnostic(severity(warning), help("Remove the initializer, variables are undefined by default"))]
+struct NoUndefInitDiagnostic(String, #[label] Span);
+
+#[derive(Debug, Default, Clone)]
+pub struct NoUndefInit;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow initializing variables to `undefined`
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Variables declared with `let` or `var` without an initializer are already `undefined`,
+    /// initializing them to `undefined` is redundant.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// // fail
+    /// var foo = undefined;
+    /// let bar = undefined;
+    ///
+    /// // pass
+    /// var foo;
+    /// let bar;
+    /// const baz = undefined;
+    /// ```
+    NoUndefInit,
+    nursery,
+    fix
+);
+
+impl Rule for NoUndefInit {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::VariableDeclarator(declarator) = node.kind() else { return };
+        if declarator.kind == VariableDeclarationKind::Const {
+            return;
+        }
+        let Some(Expression::Identifier(init)) = &declarator.init else { return };
+        if init.name != "undefined" || !ctx.semantic().is_reference_to_global_variable(init) {
+            return;
+        }
+
+        let id_span = declarator.id.span();
+        let name = id_span.source_text(ctx.source_text()).to_string();
+        let diagnostic = NoUndefInitDiagnostic(name, declarator.span);
+        // ` = undefined` in `let a = undefined`
+        let initializer = Span::new(id_span.end, declarator.span.end);
+        if !is_fixable(declarator, node, initializer, ctx) {
+            ctx.diagnostic(diagnostic);
+            return;
+        }
+        ctx.diagnostic_with_fix(diagnostic, || Fix::delete(initializer).with_kind(FixKind::Safe));
+    }
+}
+
+/// Removing the initializer changes the behavior of `var` declarations which run more than once,
+/// e.g. in loops, the bound names of patterns, and the definite assignment of typed variables.
+/// The initializers with comments are kept as well.
+fn is_fixable(
+    declarator: &VariableDeclarator,
+    node: &AstNode,
+    initializer: Span,
+    ctx: &LintContext,
+) -> bool {
+    if declarator.kind == VariableDeclarationKind::Var
+        || !matches!(declarator.id.kind, BindingPatternKind::BindingIdentifier(_))
+        || declarator.id.type_annotation.is_some()
+        || initializer.source_text(ctx.source_text()).contains('/')
+    {
+        return false;
+    }
+    // `for (let a = undefined in b)`
+    let declaration = ctx.nodes().parent_node(node.id());
+    let head = declaration.and_then(|declaration| ctx.nodes().parent_kind(declaration.id()));
+    !matches!(head, Some(AstKind::ForInStatement(_) | AstKind::ForOfStatement(_)))
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "var a;",
+        "const foo = undefined",
+        "var undefined = 5; var foo = undefined;",
+        "class C { field = undefined; }",
+        "let a = void 0;",
+        "let { a = undefined } = b;",
+        "let [a = undefined] = b;",
+        "function foo(undefined) { let a = undefined; }",
+        "let a: number | undefined;",
+    ];
+
+    let fail = vec![
+        "var a = undefined;",
+        "var a = undefined, b = 1;",
+        "var a = 1, b = undefined, c = 5;",
+        "var [a] = undefined;",
+        "var {a} = undefined;",
+        "for(var i in [1,2,3]){var a = undefined; for(var j in [1,2,3]){}}",
+        "let a = undefined;",
+        "let a = undefined, b = 1;",
+        "let a = 1, b = undefined, c = 5;",
+        "let [a] = undefined;",
+        "let {a} = undefined;",
+        "for(var i in [1,2,3]){let a = undefined; for(var j in [1,2,3]){}}",
+        "let /* comment */a = undefined;",
+        "let a/**/ = undefined;",
+        "let a = /**/undefined;",
+        "let a = undefined/* comment */;",
+        "let a = undefined/* comment */, b;",
+        "let a: string | undefined = undefined;",
+    ];
+
+    let fix = vec![
+        ("var a = undefined;", "var a = undefined;", None),
+        ("var a = undefined, b = 1;", "var a = undefined, b = 1;", None),
+        ("var [a] = undefined;", "var [a] = undefined;", None),
+        ("let a = undefined;", "let a;", None),
+        ("let a = undefined, b = 1;", "let a, b = 1;", None),
+        ("let a = 1, b = undefined, c = 5;", "let a = 1, b, c = 5;", None),
+        ("let [a] = undefined;", "let [a] = undefined;", None),
+        ("let {a} = undefined;", "let {a} = undefined;", None),
+        (
+            "for(var i in [1,2,3]){let a = undefined; for(var j in [1,2,3]){}}",
+            "for(var i in [1,2,3]){let a; for(var j in [1,2,3]){}}",
+            None,
+        ),
+        ("let /* comment */a = undefined;", "let /* comment */a;", None),
+        ("let a/**/ = undefined;", "let a/**/ = undefined;", None),
+        ("let a /**/ = undefined;", "let a /**/ = undefined;", None),
+        ("let a//\n= undefined;", "let a//\n= undefined;", None),
+        ("let a = /**/undefined;", "let a = /**/undefined;", None),
+        ("let a = //\nundefined;", "let a = //\nundefined;", None),
+        ("let a = undefined/* comment */;", "let a/* comment */;", None),
+        ("let a = undefined/* comment */, b;", "let a/* comment */, b;", None),
+        ("let a = undefined//comment\n, b;", "let a//comment\n, b;", None),
+        ("let a: string | undefined = undefined;", "let a: string | undefined = undefined;", None),
+    ];
+
+    Tester::new_without_config(NoUndefInit::NAME, pass, fail)
+        .expect_fix(fix)
+        .expect_fix_kind(FixKind::Safe)
+        .test_and_snapshot();
+}
@@ -1,12 +1,11 @@
 use oxc_ast::{
-    ast::{BinaryExpression, Expression},
+    ast::{BinaryExpression, Expression, UnaryExpression},
     AstKind,
 };
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
     thiserror::{self, Error},
 };
-use oxc_formatter::Gen;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::{GetSpan, Span};
 use oxc_syntax::operator::{BinaryOperator, UnaryOperator};
@@ -69,7 +68,7 @@ impl Rule for NoUnsafeNegation {
                 return;
             };
             if left.operator == UnaryOperator::LogicalNot {
-                Self::report_with_fix(expr, ctx);
+                Self::report_with_suggestions(expr, left, ctx);
             }
         }
     }
@@ -80,29 +79,28 @@ impl NoUnsafeNegation {
         op.is_relational() || (self.enforce_for_ordering_relations && op.is_compare())
     }
 
-    /// Precondition:
-    /// expr.left is `UnaryExpression` whose operator is '!'
-    fn report_with_fix(expr: &BinaryExpression, ctx: &LintContext<'_>) {
+    fn report_with_suggestions<'a>(
+        expr: &BinaryExpression<'a>,
+        left: &UnaryExpression<'a>,
+        ctx: &LintContext<'a>,
+    ) {
         // Diagnostic points at the unexpected negation
-        let diagnostic = NoUnsafeNegationDiagnostic(expr.operator.as_str(), expr.left.span());
+        let operator = expr.operator.as_str();
+        let diagnostic = NoUnsafeNegationDiagnostic(operator, expr.left.span());
+        let source_text = ctx.source_text();
 
-        let fix_producer = || {
-            // modify `!a instance of B` to `!(a instanceof B)`
-            let modified_code = {
-                let mut formatter = ctx.formatter();
-                formatter.print(b'!');
-                let Expression::UnaryExpression(left) = &expr.left else { unreachable!() };
-                formatter.print(b'(');
-                left.argument.gen(&mut formatter);
-                expr.operator.gen(&mut formatter);
-                expr.right.gen(&mut formatter);
-                formatter.print(b')');
-                formatter.into_code()
-            };
-            Fix::new(modified_code, expr.span)
-        };
+        // `!a in b` to `!(a in b)`
+        let negated = Span::new(left.argument.span().start, expr.span.end).source_text(source_text);
+        let negate_expression = Fix::new(format!("!({negated})"), expr.span).with_message(format!(
+            "Negate '{operator}' expression instead of its left operand. This changes the current behavior."
+        ));
+        // `!a in b` to `(!a) in b`
+        let wrap_negation =
+            Fix::new(format!("({})", left.span.source_text(source_text)), left.span).with_message(
+                "Wrap negation in '()' to make the intention explicit. This preserves the current behavior.",
+            );
 
-        ctx.diagnostic_with_fix(diagnostic, fix_producer);
+        ctx.diagnostic_with_suggestions(diagnostic, vec![negate_expression, wrap_negation]);
     }
 }
 
@@ -145,5 +143,19 @@ fn test() {
         ("! a <= b", Some(serde_json::json!([{ "enforceForOrderingRelations": true }]))),
     ];
 
-    Tester::new(NoUnsafeNegation::NAME, pass, fail).test_and_snapshot();
+    let suggestions = vec![
+        ("!a in b", vec!["!(a in b)", "(!a) in b"], None),
+        ("(!a in b)", vec!["(!(a in b))", "((!a) in b)"], None),
+        ("!(a) in b", vec!["!((a) in b)", "(!(a)) in b"], None),
+        ("!a instanceof b", vec!["!(a instanceof b)", "(!a) instanceof b"], None),
+        (
+            "! a <= b",
+            vec!["!(a <= b)", "(! a) <= b"],
+            Some(serde_json::json!([{ "enforceForOrderingRelations": true }])),
+        ),
+    ];
+
+    Tester::new(NoUnsafeNegation::NAME, pass, fail)
+        .expect_suggestions(suggestions)
+        .test_and_snapshot();
 }
@@ -1,12 +1,19 @@
-use oxc_ast::AstKind;
+use oxc_ast::{
+    ast::{Expression, Statement},
+    AstKind,
+};
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
     thiserror::Error,
 };
 use oxc_macros::declare_oxc_lint;
-use oxc_span::{Atom, Span};
+use oxc_span::{Atom, GetSpan, Span};
 
-use crate::{context::LintContext, fixer::Fix, rule::Rule};
+use crate::{
+    context::LintContext,
+    fixer::{Fix, FixKind},
+    rule::Rule,
+};
 
 #[derive(Debug, Error, Diagnostic)]
 #[error("eslint(no-unused-labels): Disallow unused labels")]
@@ -37,7 +44,8 @@ declare_oxc_lint!(
     /// }
     /// ```
     NoUnusedLabels,
-    correctness
+    correctness,
+    fix
 );
 
 impl Rule for NoUnusedLabels {
@@ -45,17 +53,32 @@ impl Rule for NoUnusedLabels {
         for id in ctx.semantic().unused_labels() {
             let node = ctx.semantic().nodes().get_node(*id);
             if let AstKind::LabeledStatement(stmt) = node.kind() {
-                // TODO: Ignore fix where comments exist between label and statement
-                // e.g. A: /* Comment */ function foo(){}
-                ctx.diagnostic_with_fix(
-                    NoUnusedLabelsDiagnostic(stmt.label.name.clone(), stmt.label.span),
-                    || Fix::delete(stmt.label.span),
-                );
+                // Remove the label and the colon, up to the labeled statement.
+                let span = Span::new(stmt.span.start, stmt.body.span().start);
+                let diagnostic = NoUnusedLabelsDiagnostic(stmt.label.name.clone(), stmt.label.span);
+                // Keep the comments between the label and the statement, e.g.
+                // `A: /* Comment */ function foo(){}`, and the strings which could become
+                // directives, e.g. `A: 'use strict'`
+                if ctx.semantic().trivias().has_comments_between(span)
+                    || is_string_statement(&stmt.body)
+                {
+                    ctx.diagnostic(diagnostic);
+                    continue;
+                }
+                ctx.diagnostic_with_fix(diagnostic, || Fix::delete(span).with_kind(FixKind::Safe));
             }
         }
     }
 }
 
+fn is_string_statement(stmt: &Statement) -> bool {
+    matches!(
+        stmt,
+        Statement::ExpressionStatement(expr_stmt)
+            if matches!(expr_stmt.expression, Expression::StringLiteral(_))
+    )
+}
+
 #[test]
 fn test() {
     use crate::tester::Tester;
@@ -83,7 +106,24 @@ fn test() {
         ("A: { var A = 0; console.log(A); }", None),
         ("A: /* comment */ foo", None),
         ("A /* comment */: foo", None),
+        ("A: 'use strict'", None),
+        ("A: { A: { break A; } }", None),
+        ("A: { function f() { A: { break A; } } }", None),
+    ];
+
+    let fix = vec![
+        ("A: var foo = 0;", "var foo = 0;", None),
+        ("A: { foo(); bar(); }", "{ foo(); bar(); }", None),
+        (
+            "A: for (var i = 0; i < 10; ++i) { B: break A; }",
+            "A: for (var i = 0; i < 10; ++i) { break A; }",
+            None,
+        ),
+        ("A: /* comment */ foo", "A: /* comment */ foo", None),
+        ("A /* comment */: foo", "A /* comment */: foo", None),
+        ("A: 'use strict'", "A: 'use strict'", None),
+        ("A: { A: { break A; } }", "{ A: { break A; } }", None),
     ];
 
-    Tester::new(NoUnusedLabels::NAME, pass, fail).test_and_snapshot();
+    Tester::new(NoUnusedLabels::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
 }
@@ -0,0 +1,227 @@
+use oxc_ast::{ast::Expression, AstKind};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+use oxc_syntax::operator::BinaryOperator;
+
+use crate::{context::LintContext, rule::Rule, AstNode, Fix, FixKind};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint(no-useless-concat): Unexpected string concatenation of literals.")]
+#[diagnostic(severity(warning), help("Merge the literals into a single one"))]
+struct NoUselessConcatDiagnostic(#[label] Span);
+
+#[derive(Debug, Default, Clone)]
+pub struct NoUselessConcat;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow unnecessary concatenation of literals or template literals
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Concatenating two literals on the same line is pointless, they can be combined into a
+    /// single literal. Concatenations spanning multiple lines are allowed, they are often used
+    /// to break long strings.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// // fail
+    /// var a = "some" + "string";
+    /// var b = `some` + "string";
+    /// var c = "a" + "b" + "c";
+    ///
+    /// // pass
+    /// var d = "some string";
+    /// var e = foo + "bar";
+    /// var f = "some" +
+    ///     "string";
+    /// ```
+    NoUselessConcat,
+    nursery,
+    fix
+);
+
+impl Rule for NoUselessConcat {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::BinaryExpression(expr) = node.kind() else { return };
+        if expr.operator != BinaryOperator::Addition {
+            return;
+        }
+        // The literals next to the operator, e.g. `"a"` and `"b"` in `foo + "a" + "b"`
+        let left = rightmost_operand(&expr.left);
+        let right = leftmost_operand(&expr.right);
+        if !left.is_string_literal() || !right.is_string_literal() {
+            return;
+        }
+        let between = Span::new(left.span().end, right.span().start);
+        let between_text = between.source_text(ctx.source_text());
+        if between_text.contains('\n') {
+            return;
+        }
+
+        let diagnostic = NoUselessConcatDiagnostic(operator_span(between, ctx));
+        // Only fix `"a" + "b"`, without parentheses or comments between the literals
+        if between_text.trim() != "+" {
+            ctx.diagnostic(diagnostic);
+            return;
+        }
+        let span = Span::new(left.span().start, right.span().end);
+        let text = merge_literals(left, right, ctx);
+        ctx.diagnostic_with_fix(diagnostic, || Fix::new(text, span).with_kind(FixKind::Safe));
+    }
+}
+
+/// `b` in `a + b`, through the concatenations on the left
+fn rightmost_operand<'a, 'b>(expr: &'b Expression<'a>) -> &'b Expression<'a> {
+    let expr = expr.without_parenthesized();
+    match expr {
+        Expression::BinaryExpression(expr) if expr.operator == BinaryOperator::Addition => {
+            rightmost_operand(&expr.right)
+        }
+        _ => expr,
+    }
+}
+
+/// `a` in `a + b`, through the concatenations on the right
+fn leftmost_operand<'a, 'b>(expr: &'b Expression<'a>) -> &'b Expression<'a> {
+    let expr = expr.without_parenthesized();
+    match expr {
+        Expression::BinaryExpression(expr) if expr.operator == BinaryOperator::Addition => {
+            leftmost_operand(&expr.left)
+        }
+        _ => expr,
+    }
+}
+
+/// The span of the `+` operator between the operands, skipping parentheses and comments
+fn operator_span(between: Span, ctx: &LintContext<'_>) -> Span {
+    let text = between.source_text(ctx.source_text());
+    let mut offset = 0;
+    while let Some(rest) = text.get(offset..) {
+        if rest.is_empty() || rest.starts_with('+') {
+            break;
+        }
+        if rest.starts_with("/*") {
+            offset += rest.find("*/").map_or(rest.len(), |end| end + 2);
+        } else {
+            offset += rest.chars().next().map_or(1, char::len_utf8);
+        }
+    }
+    #[allow(clippy::cast_possible_truncation)]
+    let start = between.start + offset as u32;
+    Span::new(start, start + 1)
+}
+
+/// A single literal with the contents of both, a template literal if any of them is one,
+/// otherwise a string literal quoted like `left`
+fn merge_literals(left: &Expression, right: &Expression, ctx: &LintContext<'_>) -> String {
+    let source_text = ctx.source_text();
+    let contents = |expr: &Expression| {
+        let raw = expr.span().source_text(source_text);
+        &raw[1..raw.len() - 1]
+    };
+    let is_template = |expr: &Expression| matches!(expr, Expression::TemplateLiteral(_));
+
+    if is_template(left) || is_template(right) {
+        let to_template = |expr: &Expression| {
+            if is_template(expr) {
+                contents(expr).to_string()
+            } else {
+                escape(contents(expr), |c, next| c == '`' || (c == '$' && next == Some('{')))
+            }
+        };
+        return format!("`{}{}`", to_template(left), to_template(right));
+    }
+
+    let quote = source_text[left.span().start as usize..].chars().next().unwrap_or('"');
+    format!("{quote}{}{}{quote}", contents(left), escape(contents(right), |c, _| c == quote))
+}
+
+/// Escape the characters of the raw contents of a literal matching `needs_escape`, which is given
+/// the next character
+fn escape(contents: &str, needs_escape: impl Fn(char, Option<char>) -> bool) -> String {
+    let mut escaped = String::with_capacity(contents.len());
+    let mut chars = contents.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c == '\\' {
+            escaped.push(c);
+            if let Some(next) = chars.next() {
+                escaped.push(next);
+            }
+            continue;
+        }
+        if needs_escape(c, chars.peek().copied()) {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "var a = 1 + 1;",
+        "var a = 1 * '2';",
+        "var a = 1 - 2;",
+        "var a = foo + bar;",
+        "var a = 'foo' + bar;",
+        "var foo = 'foo' +\n 'bar';",
+        "var string = (number + 1) + 'px';",
+        "'a' + 1",
+        "1 + '1'",
+        "1 + `1`",
+        "`1` + 1",
+        "(1 + +2) + `b`",
+        "var a = 'a' + 1 + 'b';",
+        "var a = `a` +\n `b`;",
+    ];
+
+    let fail = vec![
+        "'a' + 'b'",
+        "foo + 'a' + 'b'",
+        "'a' + 'b' + 'c'",
+        "(foo + 'a') + ('b' + 'c')",
+        "`a` + 'b'",
+        "`a` + `b`",
+        "foo + `a` + `b`",
+        "'a' + /* comment */ 'b'",
+        "('a') + 'b'",
+        "\"a\" + 'b'",
+        "'a' + \"b\"",
+    ];
+
+    let fix = vec![
+        ("'a' + 'b'", "'ab'", None),
+        ("'a' +\t'b'", "'ab'", None),
+        ("foo + 'a' + 'b'", "foo + 'ab'", None),
+        ("'a' + 'b' + 'c'", "'ab' + 'c'", None),
+        ("(foo + 'a') + ('b' + 'c')", "(foo + 'a') + ('bc')", None),
+        ("`a` + 'b'", "`ab`", None),
+        ("`a` + `b`", "`ab`", None),
+        ("`a${x}` + `b`", "`a${x}b`", None),
+        ("'a' + `b${x}`", "`ab${x}`", None),
+        ("'`' + `b`", "`\\`b`", None),
+        ("'${a}' + `b`", "`\\${a}b`", None),
+        ("'\\`' + `b`", "`\\`b`", None),
+        ("'a' + /* comment */ 'b'", "'a' + /* comment */ 'b'", None),
+        ("('a') + 'b'", "('a') + 'b'", None),
+        ("\"a\" + 'b'", "\"ab\"", None),
+        ("'a' + \"b\"", "'ab'", None),
+        ("'a' + \"b'c\"", "'ab\\'c'", None),
+        ("'a' + \"b\\\"c\"", "'ab\\\"c'", None),
+        ("'a' + 'b\\'c'", "'ab\\'c'", None),
+    ];
+
+    Tester::new_without_config(NoUselessConcat::NAME, pass, fail)
+        .expect_fix(fix)
+        .expect_fix_kind(FixKind::Safe)
+        .test_and_snapshot();
+}
@@ -9,7 +9,7 @@ use oxc_macros::declare_oxc_lint;
 use oxc_semantic::AstNodeId;
 use oxc_span::Span;
 
-use crate::{context::LintContext, rule::Rule, AstNode, Fix};
+use crate::{context::LintContext, rule::Rule, AstNode, Fix, FixKind};
 
 #[derive(Debug, Error, Diagnostic)]
 #[error("eslint(no-useless-escape): Unnecessary escape character {0:?}")]
@@ -31,7 +31,8 @@ declare_oxc_lint!(
     /// ```javascript
     /// ```
     NoUselessEscape,
-    correctness
+    correctness,
+    fix
 );
 
 impl Rule for NoUselessEscape {
@@ -88,7 +89,7 @@ fn check(ctx: &LintContext<'_>, node_id: AstNodeId, start: u32, offsets: &[usize
         if !is_within_jsx_attribute_item(node_id, ctx) {
             let span = Span::new(offset - 1, offset + len);
             ctx.diagnostic_with_fix(NoUselessEscapeDiagnostic(c, span), || {
-                Fix::new(c.to_string(), span)
+                Fix::new(c.to_string(), span).with_kind(FixKind::Safe)
             });
         }
     }
@@ -0,0 +1,480 @@
+use oxc_ast::{
+    ast::{
+        AssignmentTarget, AssignmentTargetMaybeDefault, AssignmentTargetPattern,
+        AssignmentTargetProperty, IdentifierReference, SimpleAssignmentTarget, VariableDeclaration,
+        VariableDeclarationKind,
+    },
+    syntax_directed_operations::BoundNames,
+    AstKind,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_semantic::{Reference, ScopeId, SymbolFlags, SymbolId};
+use oxc_span::{Atom, GetSpan, Span};
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use crate::{context::LintContext, rule::Rule, AstNode, Fix, FixKind};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint(prefer-const): `{0}` is never reassigned.")]
+#[diagnostic(severity(warning), help("Use `const` instead."))]
+struct PreferConstDiagnostic(Atom, #[label] Span);
+
+#[derive(Debug, Default, Clone)]
+pub struct PreferConst {
+    /// Only report the bindings of a destructuring if all of them should be `const`
+    destructuring_all: bool,
+    /// Do not report the variables read before their assignment
+    ignore_read_before_assign: bool,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Require `const` declarations for variables that are never reassigned after declared
+    ///
+    /// ### Why is this bad?
+    ///
+    /// A variable which is never reassigned is easier to understand when it is declared with
+    /// `const`, readers do not have to look for the places where it may be changed.
+    ///
+    /// ### Options
+    ///
+    /// * `destructuring`: `"any"` (default) reports any binding of a destructuring which should be
+    ///   `const`, `"all"` only reports them if all the bindings of the destructuring should be
+    ///   `const`.
+    /// * `ignoreReadBeforeAssign`: do not report a variable declared without an initializer if it
+    ///   is read before its only assignment, e.g. in a function called before it.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// // fail
+    /// let a = 3;
+    /// console.log(a);
+    ///
+    /// let b;
+    /// b = 0;
+    ///
+    /// for (let i in [1, 2, 3]) {
+    ///     console.log(i);
+    /// }
+    ///
+    /// // pass
+    /// let c = 3;
+    /// c = 4;
+    ///
+    /// for (let i = 0, l = list.length; i < l; i++) {}
+    /// ```
+    PreferConst,
+    nursery,
+    fix
+);
+
+/// A binding of a `let` declaration
+struct Binding {
+    name: Atom,
+    /// Where it should be reported, `None` if it should not be `const`
+    report: Option<Span>,
+}
+
+impl Rule for PreferConst {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let config = value.get(0);
+        let destructuring_all = config
+            .and_then(|config| config.get("destructuring"))
+            .and_then(serde_json::Value::as_str)
+            .is_some_and(|value| value == "all");
+        let ignore_read_before_assign = config
+            .and_then(|config| config.get("ignoreReadBeforeAssign"))
+            .and_then(serde_json::Value::as_bool)
+            .unwrap_or_default();
+        Self { destructuring_all, ignore_read_before_assign }
+    }
+
+    fn run_once(&self, ctx: &LintContext<'_>) {
+        // The `let` declarations, and the bindings they declare
+        let mut declarations: Vec<(&VariableDeclaration, bool, Vec<SymbolId>)> = vec![];
+        let mut bindings: FxHashMap<SymbolId, Binding> = FxHashMap::default();
+        // The bindings written by the same declarator or destructuring assignment, keyed by span
+        let mut groups: Vec<Vec<SymbolId>> = vec![];
+        let mut group_ids: FxHashMap<(u32, u32), usize> = FxHashMap::default();
+        let mut add_to_group = |span: Span, symbol_id: SymbolId| {
+            let index = *group_ids.entry((span.start, span.end)).or_insert_with(|| {
+                groups.push(vec![]);
+                groups.len() - 1
+            });
+            groups[index].push(symbol_id);
+        };
+
+        for node in ctx.nodes().iter() {
+            let AstKind::VariableDeclaration(decl) = node.kind() else { continue };
+            if decl.kind != VariableDeclarationKind::Let {
+                continue;
+            }
+            // `for (let i = 0; ...)` is checked by the update of the loop
+            let is_for_in_of = match ctx.nodes().parent_kind(node.id()) {
+                Some(AstKind::ForStatementInit(_)) => continue,
+                Some(AstKind::ForInStatement(_) | AstKind::ForOfStatement(_)) => true,
+                _ => false,
+            };
+            let mut symbol_ids = vec![];
+            for declarator in &decl.declarations {
+                let initialized = is_for_in_of || declarator.init.is_some();
+                declarator.id.bound_names(&mut |ident| {
+                    let Some(symbol_id) = ident.symbol_id.get() else { return };
+                    symbol_ids.push(symbol_id);
+                    let report = self.should_be_const(symbol_id, ident.span, initialized, ctx);
+                    bindings.insert(symbol_id, Binding { name: ident.name.clone(), report });
+                    if initialized {
+                        add_to_group(declarator.span, symbol_id);
+                    }
+                    for reference in ctx.symbols().get_resolved_references(symbol_id) {
+                        if let Some(assignment) = assignment_of(reference, ctx) {
+                            add_to_group(assignment.kind().span(), symbol_id);
+                        }
+                    }
+                });
+            }
+            declarations.push((decl, is_for_in_of, symbol_ids));
+        }
+
+        let mut reported = FxHashSet::default();
+        for group in &groups {
+            let should_be_const = |symbol_id: &SymbolId| {
+                bindings.get(symbol_id).is_some_and(|binding| binding.report.is_some())
+            };
+            if self.destructuring_all && !group.iter().all(should_be_const) {
+                continue;
+            }
+            reported.extend(group.iter().copied().filter(should_be_const));
+        }
+
+        for (decl, is_for_in_of, symbol_ids) in declarations {
+            let mut fix = const_fix(decl, is_for_in_of, &symbol_ids, &reported, ctx);
+            for symbol_id in &symbol_ids {
+                if !reported.contains(symbol_id) {
+                    continue;
+                }
+                let Some(Binding { name, report: Some(span) }) = bindings.get(symbol_id) else {
+                    continue;
+                };
+                let diagnostic = PreferConstDiagnostic(name.clone(), *span);
+                match fix.take() {
+                    Some(fix) => ctx.diagnostic_with_fix(diagnostic, || fix),
+                    None => ctx.diagnostic(diagnostic),
+                }
+            }
+        }
+    }
+}
+
+impl PreferConst {
+    /// Where to report the binding declared at `span` if it is assigned exactly once, at its
+    /// declaration if `initialized`, otherwise by an assignment which could be its declaration.
+    fn should_be_const(
+        &self,
+        symbol_id: SymbolId,
+        span: Span,
+        initialized: bool,
+        ctx: &LintContext<'_>,
+    ) -> Option<Span> {
+        let scope_id = ctx.symbols().get_scope_id(symbol_id);
+        let mut writer: Option<&Reference> = None;
+        let mut is_read_before_init = false;
+        // References are grouped by the scope resolving them, not in source order
+        let mut references = ctx.symbols().get_resolved_references(symbol_id).collect::<Vec<_>>();
+        references.sort_by_key(|reference| reference.span().start);
+        for reference in references {
+            if reference.is_write() {
+                if initialized || writer.is_some() {
+                    return None;
+                }
+                writer = Some(reference);
+            } else if reference.is_read() && writer.is_none() && !initialized {
+                if self.ignore_read_before_assign {
+                    return None;
+                }
+                is_read_before_init = true;
+            }
+        }
+        if initialized {
+            return Some(span);
+        }
+
+        let writer = writer?;
+        // The assignment must be a statement in the scope of the declaration
+        if ctx.nodes().get_node(writer.node_id()).scope_id() != scope_id {
+            return None;
+        }
+        let assignment = assignment_of(writer, ctx)?;
+        let AstKind::AssignmentExpression(assignment_expr) = assignment.kind() else {
+            return None;
+        };
+        if !is_statement(assignment, ctx) {
+            return None;
+        }
+        // All the targets of a destructuring must be variables of the same scope to be declared
+        // together, e.g. not `({ a, b: obj.b } = c)`
+        if !targets_are_declarable(&assignment_expr.left, scope_id, ctx) {
+            return None;
+        }
+        Some(if is_read_before_init { span } else { writer.span() })
+    }
+}
+
+/// Replace `let` with `const` if all the bindings of `decl` are reported and initialized
+fn const_fix<'a>(
+    decl: &VariableDeclaration,
+    is_for_in_of: bool,
+    symbol_ids: &[SymbolId],
+    reported: &FxHashSet<SymbolId>,
+    ctx: &LintContext<'a>,
+) -> Option<Fix<'a>> {
+    if !is_for_in_of && decl.declarations.iter().any(|declarator| declarator.init.is_none()) {
+        return None;
+    }
+    if symbol_ids.is_empty() || !symbol_ids.iter().all(|symbol_id| reported.contains(symbol_id)) {
+        return None;
+    }
+    let keyword = Span::new(decl.span.start, decl.span.start + 3);
+    if keyword.source_text(ctx.source_text()) != "let" {
+        return None;
+    }
+    Some(Fix::new("const", keyword).with_kind(FixKind::Safe))
+}
+
+/// The assignment expression writing `reference`, through the destructuring patterns around it
+fn assignment_of<'a, 'b>(
+    reference: &Reference,
+    ctx: &'b LintContext<'a>,
+) -> Option<&'b AstNode<'a>> {
+    if !reference.is_write() {
+        return None;
+    }
+    let mut node = ctx.nodes().parent_node(reference.node_id())?;
+    while matches!(
+        node.kind(),
+        AstKind::SimpleAssignmentTarget(_)
+            | AstKind::AssignmentTarget(_)
+            | AstKind::AssignmentTargetWithDefault(_)
+    ) {
+        node = ctx.nodes().parent_node(node.id())?;
+    }
+    matches!(node.kind(), AstKind::AssignmentExpression(_)).then_some(node)
+}
+
+/// Whether `node` is an expression statement directly in a block, which could be replaced with a
+/// declaration
+fn is_statement(node: &AstNode, ctx: &LintContext<'_>) -> bool {
+    let mut parent = ctx.nodes().parent_node(node.id());
+    while let Some(AstKind::ParenthesizedExpression(_)) = parent.map(AstNode::kind) {
+        parent = parent.and_then(|node| ctx.nodes().parent_node(node.id()));
+    }
+    let Some(statement) =
+        parent.filter(|node| matches!(node.kind(), AstKind::ExpressionStatement(_)))
+    else {
+        return false;
+    };
+    matches!(
+        ctx.nodes().parent_kind(statement.id()),
+        Some(
+            AstKind::Program(_)
+                | AstKind::BlockStatement(_)
+                | AstKind::StaticBlock(_)
+                | AstKind::SwitchCase(_)
+                | AstKind::FunctionBody(_)
+        )
+    )
+}
+
+/// Whether the targets of an assignment are all `let` or `const` variables of `scope_id`, or
+/// undeclared
+fn targets_are_declarable(
+    target: &AssignmentTarget,
+    scope_id: ScopeId,
+    ctx: &LintContext<'_>,
+) -> bool {
+    let is_declarable = |ident: &IdentifierReference| {
+        let Some(reference_id) = ident.reference_id.get() else { return true };
+        let Some(symbol_id) = ctx.symbols().get_reference(reference_id).symbol_id() else {
+            return true;
+        };
+        ctx.symbols().get_flag(symbol_id).contains(SymbolFlags::BlockScopedVariable)
+            && ctx.symbols().get_scope_id(symbol_id) == scope_id
+    };
+    let maybe_default = |target: &AssignmentTargetMaybeDefault| match target {
+        AssignmentTargetMaybeDefault::AssignmentTarget(target) => {
+            targets_are_declarable(target, scope_id, ctx)
+        }
+        AssignmentTargetMaybeDefault::AssignmentTargetWithDefault(target) => {
+            targets_are_declarable(&target.binding, scope_id, ctx)
+        }
+    };
+    match target {
+        AssignmentTarget::SimpleAssignmentTarget(
+            SimpleAssignmentTarget::AssignmentTargetIdentifier(ident),
+        ) => is_declarable(ident),
+        AssignmentTarget::SimpleAssignmentTarget(_) => false,
+        AssignmentTarget::AssignmentTargetPattern(
+            AssignmentTargetPattern::ArrayAssignmentTarget(target),
+        ) => {
+            target.elements.iter().flatten().all(maybe_default)
+                && target.rest.iter().all(|rest| targets_are_declarable(rest, scope_id, ctx))
+        }
+        AssignmentTarget::AssignmentTargetPattern(
+            AssignmentTargetPattern::ObjectAssignmentTarget(target),
+        ) => {
+            target.properties.iter().all(|property| match property {
+                AssignmentTargetProperty::AssignmentTargetPropertyIdentifier(property) => {
+                    is_declarable(&property.binding)
+                }
+                AssignmentTargetProperty::AssignmentTargetPropertyProperty(property) => {
+                    maybe_default(&property.binding)
+                }
+            }) && target.rest.iter().all(|rest| targets_are_declarable(rest, scope_id, ctx))
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let destructuring_all = || Some(serde_json::json!([{ "destructuring": "all" }]));
+    let ignore_read_before_assign =
+        || Some(serde_json::json!([{ "ignoreReadBeforeAssign": true }]));
+
+    let pass = vec![
+        ("var x = 0;", None),
+        ("let x;", None),
+        ("let x; { x = 0; } foo(x);", None),
+        ("let x = 0; x = 1;", None),
+        ("const x = 0;", None),
+        ("for (let i = 0, end = 10; i < end; ++i) {}", None),
+        ("for (let i in [1,2,3]) { i = 0; }", None),
+        ("for (let x of [1,2,3]) { x = 0; }", None),
+        ("(function() { var x = 0; })();", None),
+        ("(function() { let x; })();", None),
+        ("(function() { let x; { x = 0; } foo(x); })();", None),
+        ("(function() { let x = 0; x = 1; })();", None),
+        ("(function() { const x = 0; })();", None),
+        ("(function() { for (let i = 0, end = 10; i < end; ++i) {} })();", None),
+        ("(function() { for (let i in [1,2,3]) { i = 0; } })();", None),
+        ("(function() { for (let x of [1,2,3]) { x = 0; } })();", None),
+        ("(function(x = 0) { })();", None),
+        ("let a; while (a = foo());", None),
+        ("let a; do {} while (a = foo());", None),
+        ("let a; for (; a = foo(); );", None),
+        ("let a; for (;; ++a);", None),
+        ("let a; for (const {b = ++a} in foo());", None),
+        ("let a; for (const {b = ++a} of foo());", None),
+        ("let a; for (const x of [1,2,3]) { if (a) {} a = foo(); }", None),
+        ("let a; for (const x of [1,2,3]) { a = a || foo(); bar(a); }", None),
+        ("let a; for (const x of [1,2,3]) { foo(++a); }", None),
+        ("let a; function foo() { if (a) {} a = bar(); }", None),
+        ("let a; function foo() { a = a || bar(); baz(a); }", None),
+        ("let a; function foo() { bar(++a); }", None),
+        (
+            "let id; function foo() { if (typeof id !== 'undefined') { return; } id = setInterval(() => {}, 250); } foo();",
+            None,
+        ),
+        ("let a; if (true) a = 0; foo(a);", None),
+        ("(function (a) { let b; ({ a, b } = obj); })();", None),
+        ("(function (a) { let b; ([ a, b ] = obj); })();", None),
+        ("var a; { var b; ({ a, b } = obj); }", None),
+        ("let a; { let b; ({ a, b } = obj); }", None),
+        ("var a; { var b; ([ a, b ] = obj); }", None),
+        ("let a; { let b; ([ a, b ] = obj); }", None),
+        ("let x; { x = 0; foo(x); }", None),
+        ("(function() { let x; { x = 0; foo(x); } })();", None),
+        ("let x; for (const a of [1,2,3]) { x = foo(); bar(x); }", None),
+        ("(function() { let x; for (const a of [1,2,3]) { x = foo(); bar(x); } })();", None),
+        ("let x; for (x of array) { x; }", None),
+        ("let {a, b} = obj; b = 0;", destructuring_all()),
+        ("let a, b; ({a, b} = obj); b++;", destructuring_all()),
+        ("let { name, ...otherStuff } = obj; otherStuff = {};", destructuring_all()),
+        ("let predicate; [typeNode.returnType, predicate] = foo();", None),
+        ("let predicate; [typeNode.returnType, ...predicate] = foo();", None),
+        ("let predicate; [typeNode.returnType,, predicate] = foo();", None),
+        ("let predicate; [typeNode.returnType=5, predicate] = foo();", None),
+        ("let predicate; [[typeNode.returnType=5], predicate] = foo();", None),
+        ("let predicate; [[typeNode.returnType, predicate]] = foo();", None),
+        ("let predicate; [typeNode.returnType, [predicate]] = foo();", None),
+        ("let predicate; [, [typeNode.returnType, predicate]] = foo();", None),
+        ("let predicate; [, {foo:typeNode.returnType, predicate}] = foo();", None),
+        ("let predicate; [, {foo:typeNode.returnType, ...predicate}] = foo();", None),
+        ("let a; const b = {}; ({ a, c: b.c } = func());", None),
+        ("let x; function foo() { bar(x); } x = 0;", ignore_read_before_assign()),
+        ("const x = [1,2,3]; let y; [,y] = x; y = 0;", None),
+        ("const x = [1,2,3]; let y; [,,...y] = x; y = 0;", None),
+        ("let a = 0; a += 1;", None),
+        ("let a = 0; a++;", None),
+    ];
+
+    let fail = vec![
+        ("let x = 1; foo(x);", None),
+        ("for (let i in [1,2,3]) { foo(i); }", None),
+        ("for (let x of [1,2,3]) { foo(x); }", None),
+        ("let [x = -1, y] = [1,2]; y = 0;", None),
+        ("let {a: x = -1, b: y} = {a:1,b:2}; y = 0;", None),
+        ("(function() { let x = 1; foo(x); })();", None),
+        ("(function() { for (let i in [1,2,3]) { foo(i); } })();", None),
+        ("(function() { for (let x of [1,2,3]) { foo(x); } })();", None),
+        ("let f = (function() { let g = x; })(); f = 1;", None),
+        ("let x = 0; { let x = 1; foo(x); } x = 0;", None),
+        ("for (let i = 0; i < 10; ++i) { let x = 1; foo(x); }", None),
+        ("for (let i in [1,2,3]) { let x = 1; foo(x); }", None),
+        ("var foo = function() { for (const b of c) { let a; a = 1; } };", None),
+        ("var foo = function() { for (const b of c) { let a; ({a} = 1); } };", None),
+        ("let x; x = 0;", None),
+        ("switch (a) { case 0: let x; x = 0; }", None),
+        ("(function() { let x; x = 1; })();", None),
+        ("let {a = 0, b} = obj; b = 0; foo(a, b);", None),
+        ("let {a: {b, c}} = {a: {b: 1, c: 2}}; b = 3;", None),
+        ("let {a: {b, c}} = {a: {b: 1, c: 2}}", destructuring_all()),
+        ("let a, b; ({a = 0, b} = obj); b = 0; foo(a, b);", None),
+        ("let { name, ...otherStuff } = obj; otherStuff = {};", None),
+        ("let x; function foo() { bar(x); } x = 0;", None),
+        ("let { foo, bar } = baz;", None),
+        ("const x = [1,2,3]; let [,y] = x;", None),
+        ("const x = [1,2,3]; let [,,...y] = x;", None),
+        ("let foo = 1, bar = 2;", None),
+        ("let a, b; ({a, b} = obj);", destructuring_all()),
+        ("export let a = 1;", None),
+    ];
+
+    let fix = vec![
+        ("let x = 1; foo(x);", "const x = 1; foo(x);", None),
+        ("for (let i in [1,2,3]) { foo(i); }", "for (const i in [1,2,3]) { foo(i); }", None),
+        ("for (let x of [1,2,3]) { foo(x); }", "for (const x of [1,2,3]) { foo(x); }", None),
+        ("let [x = -1, y] = [1,2]; y = 0;", "let [x = -1, y] = [1,2]; y = 0;", None),
+        (
+            "(function() { let x = 1; foo(x); })();",
+            "(function() { const x = 1; foo(x); })();",
+            None,
+        ),
+        (
+            "let f = (function() { let g = x; })(); f = 1;",
+            "let f = (function() { const g = x; })(); f = 1;",
+            None,
+        ),
+        (
+            "let x = 0; { let x = 1; foo(x); } x = 0;",
+            "let x = 0; { const x = 1; foo(x); } x = 0;",
+            None,
+        ),
+        ("let x; x = 0;", "let x; x = 0;", None),
+        ("let { foo, bar } = baz;", "const { foo, bar } = baz;", None),
+        ("let foo = 1, bar = 2;", "const foo = 1, bar = 2;", None),
+        ("let {a: {b, c}} = {a: {b: 1, c: 2}}", "const {a: {b, c}} = {a: {b: 1, c: 2}}", None),
+        ("export let a = 1;", "export const a = 1;", None),
+    ];
+
+    Tester::new(PreferConst::NAME, pass, fail)
+        .expect_fix(fix)
+        .expect_fix_kind(FixKind::Safe)
+        .test_and_snapshot();
+}
@@ -0,0 +1,307 @@
+use oxc_ast::{
+    ast::{Argument, CallExpression, ChainElement, Expression},
+    AstKind,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+use oxc_syntax::{
+    identifier::is_identifier_part,
+    operator::BinaryOperator,
+    precedence::{GetPrecedence, Precedence},
+};
+
+use crate::{context::LintContext, rule::Rule, AstNode, Fix, FixKind};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint(prefer-exponentiation-operator): Use the '**' operator instead of 'Math.pow'.")]
+#[diagnostic(severity(warning), help("Replace `Math.pow(a, b)` with `a ** b`"))]
+struct PreferExponentiationOperatorDiagnostic(#[label] Span);
+
+#[derive(Debug, Default, Clone)]
+pub struct PreferExponentiationOperator;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow the use of `Math.pow` in favor of the `**` operator
+    ///
+    /// ### Why is this bad?
+    ///
+    /// The `**` operator is equivalent to `Math.pow`, and easier to read as it does not need
+    /// a function call.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// // fail
+    /// const foo = Math.pow(2, 8);
+    /// const bar = Math.pow(a + b, c);
+    ///
+    /// // pass
+    /// const foo = 2 ** 8;
+    /// const bar = (a + b) ** c;
+    /// ```
+    PreferExponentiationOperator,
+    nursery,
+    fix
+);
+
+impl Rule for PreferExponentiationOperator {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::CallExpression(call_expr) = node.kind() else { return };
+        if !is_math_pow(call_expr, ctx) {
+            return;
+        }
+
+        let diagnostic = PreferExponentiationOperatorDiagnostic(call_expr.span);
+        match fix_text(call_expr, node, ctx) {
+            Some(text) => ctx.diagnostic_with_fix(diagnostic, || {
+                Fix::new(text, call_expr.span).with_kind(FixKind::Safe)
+            }),
+            None => ctx.diagnostic(diagnostic),
+        }
+    }
+}
+
+/// `Math.pow(...)`, where `Math` is the global
+fn is_math_pow(call_expr: &CallExpression, ctx: &LintContext<'_>) -> bool {
+    let member_expr = match call_expr.callee.without_parenthesized() {
+        Expression::MemberExpression(member_expr) => member_expr,
+        // `(Math?.pow)(...)`
+        Expression::ChainExpression(chain_expr) => {
+            let ChainElement::MemberExpression(member_expr) = &chain_expr.expression else {
+                return false;
+            };
+            member_expr
+        }
+        _ => return false,
+    };
+    let Expression::Identifier(ident) = member_expr.object().without_parenthesized() else {
+        return false;
+    };
+    ident.name == "Math"
+        && member_expr.static_property_name() == Some("pow")
+        && ctx.semantic().is_reference_to_global_variable(ident)
+}
+
+/// The exponentiation replacing the call, `None` if it has comments which would be lost
+fn fix_text(call_expr: &CallExpression, node: &AstNode, ctx: &LintContext<'_>) -> Option<String> {
+    let [Argument::Expression(base), Argument::Expression(exponent)] =
+        call_expr.arguments.as_slice()
+    else {
+        return None;
+    };
+    if ctx.semantic().trivias().has_comments_between(call_expr.span) {
+        return None;
+    }
+
+    let source_text = ctx.source_text();
+    // `**` is right-associative, and its base cannot be a unary expression
+    let base_needs_parens = precedence(base) <= Precedence::Exponential
+        || matches!(base, Expression::UnaryExpression(_) | Expression::AwaitExpression(_));
+    let exponent_needs_parens = precedence(exponent) < Precedence::Exponential;
+    let parenthesize = |expr: &Expression, needs_parens: bool| {
+        let text = expr.span().source_text(source_text);
+        if needs_parens {
+            format!("({text})")
+        } else {
+            text.to_string()
+        }
+    };
+    let text = format!(
+        "{} ** {}",
+        parenthesize(base, base_needs_parens),
+        parenthesize(exponent, exponent_needs_parens)
+    );
+    if needs_parens(call_expr, node, ctx) {
+        return Some(format!("({text})"));
+    }
+
+    // Keep the tokens around the call apart, e.g. `a+Math.pow(++b, c)` to `a+ ++b ** c`
+    let before = source_text[..call_expr.span.start as usize].chars().next_back();
+    let after = source_text[call_expr.span.end as usize..].chars().next();
+    let prefix = if needs_space(before, text.chars().next()) { " " } else { "" };
+    let suffix = if needs_space(text.chars().next_back(), after) { " " } else { "" };
+    Some(format!("{prefix}{text}{suffix}"))
+}
+
+/// The precedence of an expression, the primary expressions bind the tightest
+fn precedence(expr: &Expression) -> Precedence {
+    match expr {
+        Expression::SequenceExpression(_)
+        | Expression::AssignmentExpression(_)
+        | Expression::YieldExpression(_)
+        | Expression::ArrowExpression(_)
+        | Expression::ConditionalExpression(_)
+        | Expression::LogicalExpression(_)
+        | Expression::BinaryExpression(_)
+        | Expression::UnaryExpression(_)
+        | Expression::UpdateExpression(_)
+        | Expression::AwaitExpression(_)
+        | Expression::NewExpression(_)
+        | Expression::CallExpression(_)
+        | Expression::MemberExpression(_) => expr.precedence(),
+        Expression::TSAsExpression(_) | Expression::TSSatisfiesExpression(_) => {
+            Precedence::Relational
+        }
+        _ => Precedence::Grouping,
+    }
+}
+
+/// Whether the exponentiation binds looser than its parent, e.g. `-Math.pow(a, b)` to
+/// `-(a ** b)`
+fn needs_parens(call_expr: &CallExpression, node: &AstNode, ctx: &LintContext<'_>) -> bool {
+    let mut parent = ctx.nodes().parent_node(node.id());
+    // `Math.pow?.(a, b)`
+    if let Some(chain) =
+        parent.filter(|parent| matches!(parent.kind(), AstKind::ChainExpression(_)))
+    {
+        parent = ctx.nodes().parent_node(chain.id());
+    }
+    let Some(parent) = parent else { return false };
+    let is_call = |expr: &Expression| expr.span() == call_expr.span;
+    match parent.kind() {
+        // `a ** Math.pow(b, c)` is `a ** b ** c`
+        AstKind::BinaryExpression(expr) => {
+            expr.operator == BinaryOperator::Exponential && is_call(&expr.left)
+        }
+        AstKind::UnaryExpression(_)
+        | AstKind::AwaitExpression(_)
+        | AstKind::TSNonNullExpression(_)
+        | AstKind::ClassHeritage(_) => true,
+        AstKind::MemberExpression(expr) => is_call(expr.object()),
+        AstKind::CallExpression(expr) => is_call(&expr.callee),
+        AstKind::NewExpression(expr) => is_call(&expr.callee),
+        AstKind::TaggedTemplateExpression(expr) => is_call(&expr.tag),
+        _ => false,
+    }
+}
+
+/// Whether two characters would be part of the same token without a space between them
+fn needs_space(left: Option<char>, right: Option<char>) -> bool {
+    let (Some(left), Some(right)) = (left, right) else { return false };
+    (is_identifier_part(left) && is_identifier_part(right))
+        || (left == right && matches!(left, '+' | '-'))
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "Object.pow(a, b)",
+        "Math.max(a, b)",
+        "Math",
+        "Math(a, b)",
+        "pow",
+        "pow(a, b)",
+        "Math.cos",
+        "Math.cos(a, b)",
+        "foo.Math.pow(a, b)",
+        "new Math.pow(a, b)",
+        "Math[pow](a, b)",
+        "globalThis.Object.pow(a, b)",
+        "globalThis.Math.max(a, b)",
+        "let Math; Math.pow(a, b);",
+        "if (foo) { const Math = 1; Math.pow(a, b); }",
+        "var x = function Math() { Math.pow(a, b); }",
+        "function foo(Math) { Math.pow(a, b); }",
+        "function foo() { Math.pow(a, b); var Math; }",
+        "a ** b",
+        "(a ** b)",
+    ];
+
+    let fail = vec![
+        "Math.pow(a, b)",
+        "(Math).pow(a, b)",
+        "Math['pow'](a, b)",
+        "(Math)['pow'](a, b)",
+        "var x = Math.pow(a, b)",
+        "Math.pow(a, b) + Math.pow(c, d)",
+        "Math.pow(Math.pow(a, b), Math.pow(c, d))",
+        "Math.pow(a)",
+        "Math.pow(a, b, c)",
+        "Math.pow(...foo)",
+        "Math.pow(a, /* comment */ b)",
+        "Math.pow?.(a, b)",
+        "Math?.pow(a, b)",
+        "(Math?.pow)(a, b)",
+        "function foo() { return Math.pow(a, b); }",
+    ];
+
+    let fix = vec![
+        ("Math.pow(a, b)", "a ** b", None),
+        ("(Math).pow(a, b)", "a ** b", None),
+        ("Math['pow'](a, b)", "a ** b", None),
+        ("(Math)['pow'](a, b)", "a ** b", None),
+        ("var x=Math\n.  pow( a, \n b )", "var x=a ** b", None),
+        ("Math.pow(a, b) + Math.pow(c,\n d)", "a ** b + c ** d", None),
+        ("Math.pow(Math.pow(a, b), Math.pow(c, d))", "Math.pow(a, b) ** Math.pow(c, d)", None),
+        ("Math.pow(a)", "Math.pow(a)", None),
+        ("Math.pow(a, b, c)", "Math.pow(a, b, c)", None),
+        ("Math.pow(...foo)", "Math.pow(...foo)", None),
+        ("Math.pow(a, ...b)", "Math.pow(a, ...b)", None),
+        ("Math.pow(a, /* comment */ b)", "Math.pow(a, /* comment */ b)", None),
+        ("Math.pow(a, b // comment\n)", "Math.pow(a, b // comment\n)", None),
+        ("Math.pow?.(a, b)", "a ** b", None),
+        ("Math?.pow(a, b)", "a ** b", None),
+        ("(Math?.pow)(a, b)", "a ** b", None),
+        // the base
+        ("Math.pow(a + b, c)", "(a + b) ** c", None),
+        ("Math.pow(a * b, c)", "(a * b) ** c", None),
+        ("Math.pow(a ** b, c)", "(a ** b) ** c", None),
+        ("Math.pow(a ? b : c, d)", "(a ? b : c) ** d", None),
+        ("Math.pow(a = b, c)", "(a = b) ** c", None),
+        ("Math.pow(a || b, c)", "(a || b) ** c", None),
+        ("Math.pow(-a, b)", "(-a) ** b", None),
+        ("Math.pow(+a, b)", "(+a) ** b", None),
+        ("Math.pow(typeof a, b)", "(typeof a) ** b", None),
+        ("async () => Math.pow(await a, b)", "async () => (await a) ** b", None),
+        ("Math.pow(++a, b)", "++a ** b", None),
+        ("Math.pow(a++, b)", "a++ ** b", None),
+        ("Math.pow((a, b), c)", "(a, b) ** c", None),
+        ("Math.pow(a.b, c)", "a.b ** c", None),
+        ("Math.pow(a(), b)", "a() ** b", None),
+        ("Math.pow(2, b)", "2 ** b", None),
+        ("Math.pow(a as number, b)", "(a as number) ** b", None),
+        // the exponent
+        ("Math.pow(a, b + c)", "a ** (b + c)", None),
+        ("Math.pow(a, b * c)", "a ** (b * c)", None),
+        ("Math.pow(a, b ** c)", "a ** b ** c", None),
+        ("Math.pow(a, b ? c : d)", "a ** (b ? c : d)", None),
+        ("Math.pow(a, -b)", "a ** -b", None),
+        ("async () => Math.pow(a, await b)", "async () => a ** await b", None),
+        ("Math.pow(a, b, )", "a ** b", None),
+        // the exponentiation
+        ("-Math.pow(a, b)", "-(a ** b)", None),
+        ("typeof Math.pow(a, b)", "typeof (a ** b)", None),
+        ("async () => await Math.pow(a, b)", "async () => await (a ** b)", None),
+        ("Math.pow(a, b) ** c", "(a ** b) ** c", None),
+        ("c ** Math.pow(a, b)", "c ** a ** b", None),
+        ("c * Math.pow(a, b)", "c * a ** b", None),
+        ("Math.pow(a, b).toString()", "(a ** b).toString()", None),
+        ("Math.pow(a, b)?.toString()", "(a ** b)?.toString()", None),
+        ("Math.pow(a, b)()", "(a ** b)()", None),
+        ("Math.pow(a, b)``", "(a ** b)``", None),
+        ("new (Math.pow(a, b))", "new (a ** b)", None),
+        ("class C extends Math.pow(a, b) {}", "class C extends (a ** b) {}", None),
+        ("Math.pow(a, b)!", "(a ** b)!", None),
+        ("(Math.pow(a, b)).toString()", "(a ** b).toString()", None),
+        ("x[Math.pow(a, b)]", "x[a ** b]", None),
+        ("foo(Math.pow(a, b))", "foo(a ** b)", None),
+        ("[Math.pow(a, b)]", "[a ** b]", None),
+        // the tokens around the call
+        ("a+Math.pow(++b, c)", "a+ ++b ** c", None),
+        ("a-Math.pow(--b, c)", "a- --b ** c", None),
+        ("a+Math.pow(b, c)", "a+b ** c", None),
+        ("Math.pow(a, b)in c", "a ** b in c", None),
+    ];
+
+    Tester::new_without_config(PreferExponentiationOperator::NAME, pass, fail)
+        .expect_fix(fix)
+        .expect_fix_kind(FixKind::Safe)
+        .test_and_snapshot();
+}
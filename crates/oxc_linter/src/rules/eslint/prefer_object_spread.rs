@@ -0,0 +1,253 @@
+use oxc_ast::{
+    ast::{Argument, CallExpression, Expression, ObjectPropertyKind, PropertyKind},
+    AstKind,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+
+use crate::{context::LintContext, rule::Rule, AstNode, Fix, FixKind};
+
+#[derive(Debug, Error, Diagnostic)]
+enum PreferObjectSpreadDiagnostic {
+    #[error("eslint(prefer-object-spread): Use an object spread instead of `Object.assign`.")]
+    #[diagnostic(severity(warning), help("Replace it with an object spread, e.g. `{{ ...foo }}`"))]
+    UseSpread(#[label] Span),
+    #[error("eslint(prefer-object-spread): Use an object literal instead of `Object.assign`.")]
+    #[diagnostic(
+        severity(warning),
+        help("Replace it with an object literal, e.g. `{{ foo: bar }}`")
+    )]
+    UseLiteral(#[label] Span),
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct PreferObjectSpread;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow using `Object.assign` with an object literal as the first argument and prefer
+    /// the use of object spread instead.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// When `Object.assign` is called with an object literal as the first argument, it creates
+    /// a new object, which the object spread syntax does in a more concise way.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// // fail
+    /// Object.assign({}, foo);
+    /// Object.assign({ foo: 'bar' }, baz);
+    /// Object.assign({ foo: 'bar' });
+    ///
+    /// // pass
+    /// ({ ...foo });
+    /// ({ foo: 'bar', ...baz });
+    /// Object.assign(foo, bar);
+    /// ```
+    PreferObjectSpread,
+    nursery,
+    fix
+);
+
+impl Rule for PreferObjectSpread {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::CallExpression(call_expr) = node.kind() else { return };
+        if !is_object_assign(call_expr, ctx) {
+            return;
+        }
+        let Some(Argument::Expression(first)) = call_expr.arguments.first() else { return };
+        if !matches!(first.without_parenthesized(), Expression::ObjectExpression(_)) {
+            return;
+        }
+        // The properties of a spread cannot be spread again, and the getters and setters of
+        // object literals are called by `Object.assign` but defined by an object spread
+        if call_expr.arguments.iter().any(|arg| matches!(arg, Argument::SpreadElement(_))) {
+            return;
+        }
+        if call_expr.arguments.len() > 1 && has_accessors(call_expr) {
+            return;
+        }
+
+        let diagnostic = if call_expr.arguments.len() == 1 {
+            PreferObjectSpreadDiagnostic::UseLiteral(call_expr.span)
+        } else {
+            PreferObjectSpreadDiagnostic::UseSpread(call_expr.span)
+        };
+        match fix_text(call_expr, node, ctx) {
+            Some(text) => ctx.diagnostic_with_fix(diagnostic, || {
+                Fix::new(text, call_expr.span).with_kind(FixKind::Safe)
+            }),
+            None => ctx.diagnostic(diagnostic),
+        }
+    }
+}
+
+/// `Object.assign(...)`, where `Object` is the global
+fn is_object_assign(call_expr: &CallExpression, ctx: &LintContext<'_>) -> bool {
+    let Expression::MemberExpression(member_expr) = call_expr.callee.without_parenthesized() else {
+        return false;
+    };
+    let Expression::Identifier(ident) = member_expr.object().without_parenthesized() else {
+        return false;
+    };
+    ident.name == "Object"
+        && member_expr.static_property_name() == Some("assign")
+        && ctx.semantic().is_reference_to_global_variable(ident)
+}
+
+fn has_accessors(call_expr: &CallExpression) -> bool {
+    call_expr.arguments.iter().any(|arg| {
+        let Argument::Expression(Expression::ObjectExpression(object_expr)) = arg else {
+            return false;
+        };
+        object_expr.properties.iter().any(|prop| {
+            matches!(
+                prop,
+                ObjectPropertyKind::ObjectProperty(prop)
+                    if matches!(prop.kind, PropertyKind::Get | PropertyKind::Set)
+            )
+        })
+    })
+}
+
+/// The object literal replacing the call, `None` if it has comments which would be lost
+fn fix_text(call_expr: &CallExpression, node: &AstNode, ctx: &LintContext<'_>) -> Option<String> {
+    if ctx.semantic().trivias().has_comments_between(call_expr.span) {
+        return None;
+    }
+    let source_text = ctx.source_text();
+    let mut parts = vec![];
+    for arg in &call_expr.arguments {
+        let Argument::Expression(expr) = arg else { return None };
+        match expr.without_parenthesized() {
+            // The properties of object literals are merged in place
+            Expression::ObjectExpression(object_expr) => {
+                if object_expr.properties.is_empty() {
+                    continue;
+                }
+                let end = object_expr.trailing_comma.map_or(object_expr.span.end - 1, |c| c.start);
+                let properties = Span::new(object_expr.span.start + 1, end);
+                parts.push(properties.source_text(source_text).trim().to_string());
+            }
+            Expression::AssignmentExpression(_)
+            | Expression::ArrowExpression(_)
+            | Expression::ConditionalExpression(_)
+                if !matches!(expr, Expression::ParenthesizedExpression(_)) =>
+            {
+                parts.push(format!("...({})", expr.span().source_text(source_text)));
+            }
+            _ => parts.push(format!("...{}", expr.span().source_text(source_text))),
+        }
+    }
+
+    let object =
+        if parts.is_empty() { "{}".to_string() } else { format!("{{ {} }}", parts.join(", ")) };
+    // e.g. at the start of an expression statement, the object would be a block statement
+    let needs_parens = !matches!(
+        ctx.nodes().parent_kind(node.id()),
+        Some(
+            AstKind::VariableDeclarator(_)
+                | AstKind::ExpressionArrayElement(_)
+                | AstKind::ReturnStatement(_)
+                | AstKind::Argument(_)
+                | AstKind::ObjectProperty(_)
+                | AstKind::AssignmentExpression(_)
+                | AstKind::ParenthesizedExpression(_)
+        )
+    );
+    Some(if needs_parens { format!("({object})") } else { object })
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "Object.assign()",
+        "let a = Object.assign(a, b)",
+        "Object.assign(a, b)",
+        "let a = Object.assign(b, { c: 1 })",
+        "const bar = { ...foo }",
+        "Object.assign(...foo)",
+        "Object.assign(foo, { bar: baz })",
+        "Object.assign({}, ...objects)",
+        "Object.assign({ foo: 'bar' }, ...objects)",
+        "foo({ foo: 'bar' })",
+        "const Object = {}; Object.assign({}, foo)",
+        "function f(Object) { return Object.assign({}, foo) }",
+        "Object.assign({ get a() {} }, {})",
+        "Object.assign({ set a(val) {} }, {})",
+        "Object.assign({ get a() {} }, foo)",
+        "Object.assign({ set a(val) {} }, foo)",
+        "Object.assign({ foo: 'bar', get a() {}, baz: 'quux' }, quuux)",
+        "Object.assign({ foo: 'bar', set a(val) {} }, { baz: 'quux' })",
+        "Object.assign({}, { get a() {} })",
+        "Object.assign({}, { set a(val) {} })",
+        "Object.assign({}, { foo: 'bar', get a() {} }, {})",
+        "Object.assign({ foo }, bar, {}, { baz: 'quux', set a(val) {}, quuux }, {})",
+        "Object.foo({}, bar)",
+        "Object[assign]({}, bar)",
+        "Foo.assign({}, bar)",
+    ];
+
+    let fail = vec![
+        "Object.assign({}, foo)",
+        "Object.assign  ({}, foo)",
+        "Object.assign({}, { foo: 'bar' })",
+        "Object.assign({}, baz, { foo: 'bar' })",
+        "Object.assign({ foo: 'bar' }, cats, dogs, trees, birds)",
+        "const a = Object.assign({}, b)",
+        "Object.assign({})",
+        "Object.assign({ foo: bar })",
+        "Object.assign({}, /* comment */ foo)",
+        "Object['assign']({}, foo)",
+        "(Object).assign({}, foo)",
+    ];
+
+    let fix = vec![
+        ("Object.assign({}, foo)", "({ ...foo })", None),
+        ("Object.assign  ({}, foo)", "({ ...foo })", None),
+        ("Object.assign({}, { foo: 'bar' })", "({ foo: 'bar' })", None),
+        ("Object.assign({}, baz, { foo: 'bar' })", "({ ...baz, foo: 'bar' })", None),
+        ("Object.assign({}, { foo: 'bar', baz: 'foo' })", "({ foo: 'bar', baz: 'foo' })", None),
+        ("Object.assign({ foo: 'bar', }, baz)", "({ foo: 'bar', ...baz })", None),
+        (
+            "Object.assign({ foo: 'bar' }, cats, dogs, trees, birds)",
+            "({ foo: 'bar', ...cats, ...dogs, ...trees, ...birds })",
+            None,
+        ),
+        ("Object.assign({}, {}, {})", "({})", None),
+        ("Object.assign({})", "({})", None),
+        ("Object.assign({ foo: bar })", "({ foo: bar })", None),
+        ("const a = Object.assign({}, b)", "const a = { ...b }", None),
+        ("a = Object.assign({}, b)", "a = { ...b }", None),
+        ("foo(Object.assign({}, b))", "foo({ ...b })", None),
+        ("const a = [Object.assign({}, b)]", "const a = [{ ...b }]", None),
+        ("const a = { b: Object.assign({}, c) }", "const a = { b: { ...c } }", None),
+        ("function f() { return Object.assign({}, a) }", "function f() { return { ...a } }", None),
+        ("const f = () => Object.assign({}, a)", "const f = () => ({ ...a })", None),
+        ("const a = (Object.assign({}, b))", "const a = ({ ...b })", None),
+        ("Object.assign({}, a).b", "({ ...a }).b", None),
+        ("const a = Object.assign({}, a = b)", "const a = { ...(a = b) }", None),
+        ("const a = Object.assign({}, a ? b : c)", "const a = { ...(a ? b : c) }", None),
+        ("const a = Object.assign({}, () => {})", "const a = { ...(() => {}) }", None),
+        ("const a = Object.assign({}, (a, b))", "const a = { ...(a, b) }", None),
+        ("const a = Object.assign({}, foo.bar, baz())", "const a = { ...foo.bar, ...baz() }", None),
+        (
+            "const a = Object.assign({}, /* comment */ foo)",
+            "const a = Object.assign({}, /* comment */ foo)",
+            None,
+        ),
+    ];
+
+    Tester::new_without_config(PreferObjectSpread::NAME, pass, fail)
+        .expect_fix(fix)
+        .expect_fix_kind(FixKind::Safe)
+        .test_and_snapshot();
+}
@@ -0,0 +1,231 @@
+use oxc_ast::{
+    ast::{Argument, CallExpression, ChainElement, Expression},
+    AstKind,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+
+use crate::{context::LintContext, rule::Rule, AstNode};
+
+#[derive(Debug, Error, Diagnostic)]
+enum RadixDiagnostic {
+    #[error("eslint(radix): Missing parameters.")]
+    #[diagnostic(
+        severity(warning),
+        help("Add the string to parse and its radix, e.g. `parseInt(value, 10)`")
+    )]
+    MissingParameters(#[label] Span),
+    #[error("eslint(radix): Missing radix parameter.")]
+    #[diagnostic(severity(warning), help("Add the radix, e.g. `parseInt(value, 10)`"))]
+    MissingRadix(#[label] Span),
+    #[error("eslint(radix): Redundant radix parameter.")]
+    #[diagnostic(severity(warning), help("Remove the radix, it is 10 by default"))]
+    RedundantRadix(#[label] Span),
+    #[error("eslint(radix): Invalid radix parameter, must be an integer between 2 and 36.")]
+    #[diagnostic(severity(warning))]
+    InvalidRadix(#[label] Span),
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct Radix {
+    radix_type: RadixType,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Enforce the consistent use of the radix argument when using `parseInt()`
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Without a radix, `parseInt` guesses it from the string, e.g. strings starting with `0x`
+    /// are parsed as hexadecimal numbers. With the `"as-needed"` option, the radix is only
+    /// required when it is not 10, and a radix of 10 is reported as redundant.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// // fail
+    /// var num = parseInt("071");
+    /// var num = parseInt(someValue);
+    /// var num = parseInt("071", "abc");
+    /// var num = parseInt("071", 37);
+    /// var num = parseInt();
+    ///
+    /// // pass
+    /// var num = parseInt("071", 10);
+    /// var num = parseInt("071", 8);
+    /// var num = parseFloat(someValue);
+    /// ```
+    Radix,
+    nursery
+);
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+enum RadixType {
+    #[default]
+    Always,
+    AsNeeded,
+}
+
+impl RadixType {
+    pub fn from(raw: &str) -> Self {
+        match raw {
+            "as-needed" => Self::AsNeeded,
+            _ => Self::Always,
+        }
+    }
+}
+
+impl Rule for Radix {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        Self {
+            radix_type: value
+                .get(0)
+                .and_then(serde_json::Value::as_str)
+                .map(RadixType::from)
+                .unwrap_or_default(),
+        }
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::CallExpression(call_expr) = node.kind() else { return };
+        if !is_parse_int(call_expr, ctx) {
+            return;
+        }
+
+        match call_expr.arguments.as_slice() {
+            [] => ctx.diagnostic(RadixDiagnostic::MissingParameters(call_expr.span)),
+            [_] => {
+                if self.radix_type == RadixType::Always {
+                    ctx.diagnostic(RadixDiagnostic::MissingRadix(call_expr.span));
+                }
+            }
+            [_, radix, ..] => {
+                let Argument::Expression(radix) = radix else { return };
+                if self.radix_type == RadixType::AsNeeded && is_default_radix(radix) {
+                    ctx.diagnostic(RadixDiagnostic::RedundantRadix(call_expr.span));
+                } else if !is_valid_radix(radix) {
+                    ctx.diagnostic(RadixDiagnostic::InvalidRadix(call_expr.span));
+                }
+            }
+        }
+    }
+}
+
+/// `parseInt(...)` or `Number.parseInt(...)`, where `parseInt` and `Number` are the globals
+fn is_parse_int(call_expr: &CallExpression, ctx: &LintContext<'_>) -> bool {
+    let member_expr = match call_expr.callee.without_parenthesized() {
+        Expression::Identifier(ident) => {
+            return ident.name == "parseInt"
+                && ctx.semantic().is_reference_to_global_variable(ident);
+        }
+        Expression::MemberExpression(member_expr) => member_expr,
+        // `(Number?.parseInt)(...)`
+        Expression::ChainExpression(chain_expr) => {
+            let ChainElement::MemberExpression(member_expr) = &chain_expr.expression else {
+                return false;
+            };
+            member_expr
+        }
+        _ => return false,
+    };
+    let Expression::Identifier(ident) = member_expr.object().without_parenthesized() else {
+        return false;
+    };
+    ident.name == "Number"
+        && member_expr.static_property_name() == Some("parseInt")
+        && ctx.semantic().is_reference_to_global_variable(ident)
+}
+
+#[allow(clippy::float_cmp)]
+fn is_default_radix(radix: &Expression) -> bool {
+    matches!(radix, Expression::NumberLiteral(lit) if lit.value == 10.0)
+}
+
+/// The literals which are not integers between 2 and 36, and `undefined`, are invalid
+fn is_valid_radix(radix: &Expression) -> bool {
+    match radix {
+        Expression::NumberLiteral(lit) => {
+            lit.value.fract() == 0.0 && (2.0..=36.0).contains(&lit.value)
+        }
+        Expression::Identifier(ident) => ident.name != "undefined",
+        Expression::StringLiteral(_)
+        | Expression::BooleanLiteral(_)
+        | Expression::NullLiteral(_)
+        | Expression::BigintLiteral(_)
+        | Expression::RegExpLiteral(_) => false,
+        _ => true,
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let as_needed = || Some(serde_json::json!(["as-needed"]));
+
+    let pass = vec![
+        ("parseInt(\"10\", 10);", None),
+        ("parseInt(\"10\", 2);", None),
+        ("parseInt(\"10\", 36);", None),
+        ("parseInt(\"10\", 0x10);", None),
+        ("parseInt(\"10\", 1.6e1);", None),
+        ("parseInt(\"10\", 10.0);", None),
+        ("parseInt(\"10\", foo);", None),
+        ("Number.parseInt(\"10\", foo);", None),
+        ("parseInt(\"10\", 10);", Some(serde_json::json!(["always"]))),
+        ("parseInt(\"10\");", as_needed()),
+        ("parseInt(\"10\", 8);", as_needed()),
+        ("parseInt(\"10\", foo);", as_needed()),
+        ("parseInt", None),
+        ("Number.foo();", None),
+        ("Number[parseInt]();", None),
+        ("class C { #parseInt; foo() { Number.#parseInt(); } }", None),
+        ("class C { #parseInt; foo() { Number.#parseInt(foo); } }", None),
+        ("class C { #parseInt; foo() { Number.#parseInt(foo, 'bar'); } }", None),
+        ("class C { #parseInt; foo() { Number.#parseInt(foo, 10); } }", as_needed()),
+        ("var parseInt; parseInt();", None),
+        ("var parseInt; parseInt(foo);", None),
+        ("var parseInt; parseInt(foo, 10);", as_needed()),
+        ("var Number; Number.parseInt();", None),
+        ("var Number; Number.parseInt(foo);", None),
+        ("var Number; Number.parseInt(foo, 10);", as_needed()),
+        ("function f(parseInt) { parseInt(foo); }", None),
+    ];
+
+    let fail = vec![
+        ("parseInt();", None),
+        ("parseInt();", as_needed()),
+        ("parseInt(\"10\");", None),
+        ("parseInt(\"10\",);", None),
+        ("parseInt((0, \"10\"));", None),
+        ("parseInt((0, \"10\"),);", None),
+        ("parseInt(\"10\", null);", None),
+        ("parseInt(\"10\", undefined);", None),
+        ("parseInt(\"10\", true);", None),
+        ("parseInt(\"10\", \"foo\");", None),
+        ("parseInt(\"10\", \"123\");", None),
+        ("parseInt(\"10\", 1);", None),
+        ("parseInt(\"10\", 37);", None),
+        ("parseInt(\"10\", 10.5);", None),
+        ("Number.parseInt();", None),
+        ("Number.parseInt();", as_needed()),
+        ("Number.parseInt(\"10\");", None),
+        ("Number.parseInt(\"10\", 1);", None),
+        ("Number.parseInt(\"10\", 37);", None),
+        ("Number.parseInt(\"10\", 10.5);", None),
+        ("parseInt(\"10\", 10);", as_needed()),
+        ("parseInt(\"10\", 10.0);", as_needed()),
+        ("Number.parseInt(\"10\", 10);", as_needed()),
+        ("parseInt?.(\"10\");", None),
+        ("Number.parseInt?.(\"10\");", None),
+        ("Number?.parseInt(\"10\");", None),
+        ("(Number?.parseInt)(\"10\");", None),
+    ];
+
+    Tester::new(Radix::NAME, pass, fail).test_and_snapshot();
+}
@@ -0,0 +1,80 @@
+use oxc_ast::{ast::Expression, AstKind};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+
+use crate::{context::LintContext, rule::Rule, AstNode};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint(symbol-description): Expected Symbol to have a description.")]
+#[diagnostic(severity(warning), help("Add a description, e.g. `Symbol('foo')`"))]
+struct SymbolDescriptionDiagnostic(#[label] Span);
+
+#[derive(Debug, Default, Clone)]
+pub struct SymbolDescription;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Require symbol descriptions
+    ///
+    /// ### Why is this bad?
+    ///
+    /// The description of a symbol is shown when it is converted to a string or logged, without
+    /// one, symbols are hard to tell apart while debugging.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// // fail
+    /// var foo = Symbol();
+    ///
+    /// // pass
+    /// var foo = Symbol("some description");
+    /// ```
+    SymbolDescription,
+    nursery
+);
+
+impl Rule for SymbolDescription {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::CallExpression(call_expr) = node.kind() else { return };
+        if !call_expr.arguments.is_empty() {
+            return;
+        }
+        let Expression::Identifier(ident) = call_expr.callee.without_parenthesized() else {
+            return;
+        };
+        if ident.name == "Symbol" && ctx.semantic().is_reference_to_global_variable(ident) {
+            ctx.diagnostic(SymbolDescriptionDiagnostic(call_expr.span));
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "Symbol('Foo');",
+        "var foo = 'foo'; Symbol(foo);",
+        "Symbol.for('foo');",
+        "new Symbol();",
+        "var Symbol = function () {}; Symbol();",
+        "Symbol(); var Symbol = function () {};",
+        "function bar() { var Symbol = function () {}; Symbol(); }",
+        "function bar(Symbol) { Symbol(); }",
+        "function bar() { let Symbol = () => {}; return Symbol(); }",
+    ];
+
+    let fail = vec![
+        "Symbol();",
+        "Symbol(); Symbol = function () {};",
+        "var foo = Symbol();",
+        "function bar() { return Symbol(); }",
+    ];
+
+    Tester::new_without_config(SymbolDescription::NAME, pass, fail).test_and_snapshot();
+}
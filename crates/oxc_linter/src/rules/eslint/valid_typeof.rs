@@ -46,6 +46,7 @@ declare_oxc_lint!(
     /// ```
     ValidTypeof,
     correctness,
+    fix
 );
 
 impl Rule for ValidTypeof {
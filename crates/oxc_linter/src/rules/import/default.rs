@@ -37,7 +37,7 @@ declare_oxc_lint!(
 
 impl Rule for Default {
     fn run_once(&self, ctx: &LintContext<'_>) {
-        let module_record = ctx.semantic().module_record();
+        let module_record = ctx.module_record();
         for import_entry in &module_record.import_entries {
             let ImportImportName::Default(_) = import_entry.import_name else { continue };
 
@@ -0,0 +1,158 @@
+use oxc_ast::{
+    ast::{Declaration, ModuleDeclaration, Statement, TSModuleReference},
+    AstKind,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+
+use crate::{context::LintContext, rule::Rule};
+
+#[derive(Debug, Error, Diagnostic)]
+enum FirstDiagnostic {
+    #[error("eslint-plugin-import(first): Import in body of module; reorder to top.")]
+    #[diagnostic(
+        severity(warning),
+        help("Move this import above the other statements of the module")
+    )]
+    ImportInBody(#[label] Span),
+    #[error("eslint-plugin-import(first): Absolute imports should come before relative imports.")]
+    #[diagnostic(severity(warning), help("Move this import above the relative imports"))]
+    AbsoluteAfterRelative(#[label] Span),
+}
+
+/// <https://github.com/import-js/eslint-plugin-import/blob/main/docs/rules/first.md>
+#[derive(Debug, Default, Clone)]
+pub struct First {
+    /// Also report absolute imports after relative imports
+    absolute_first: bool,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Reports any imports that come after non-import statements. Directives like
+    /// `"use client"` are allowed before the imports.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Imports are hoisted, so code before them runs after the imported modules are evaluated.
+    /// Keeping all imports at the top makes this order obvious.
+    ///
+    /// ### Options
+    ///
+    /// * `"absolute-first"`: also report absolute imports (e.g. `'lodash'`) that come after
+    ///   relative imports (e.g. `'./foo'`).
+    ///
+    /// ### Example
+    /// ```javascript
+    /// import foo from './foo';
+    ///
+    /// // some module-level initializer
+    /// initWith(foo);
+    ///
+    /// import bar from './bar'; // reported
+    /// ```
+    First,
+    nursery
+);
+
+impl Rule for First {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let absolute_first =
+            value.get(0).and_then(serde_json::Value::as_str).is_some_and(|s| s == "absolute-first");
+        Self { absolute_first }
+    }
+
+    fn run_once(&self, ctx: &LintContext<'_>) {
+        let Some(root) = ctx.nodes().iter().next() else { return };
+        let AstKind::Program(program) = root.kind() else { return };
+
+        // A string after an import is parsed as a directive, it is a statement all the same
+        let misplaced_directive =
+            program.directives.iter().map(|directive| directive.span.start).find(|&start| {
+                program.body.first().is_some_and(|first| first.span().start < start)
+            });
+
+        let mut any_statement = false;
+        let mut any_relative = false;
+        for stmt in &program.body {
+            if misplaced_directive.is_some_and(|start| start < stmt.span().start) {
+                any_statement = true;
+            }
+            // The module specifier, `None` for `import a = B.C`
+            let source = match stmt {
+                Statement::ModuleDeclaration(decl) => {
+                    let ModuleDeclaration::ImportDeclaration(import) = &**decl else {
+                        any_statement = true;
+                        continue;
+                    };
+                    Some(&import.source)
+                }
+                Statement::Declaration(Declaration::TSImportEqualsDeclaration(decl)) => {
+                    match &*decl.module_reference {
+                        TSModuleReference::ExternalModuleReference(reference) => {
+                            Some(&reference.expression)
+                        }
+                        TSModuleReference::TypeName(_) => None,
+                    }
+                }
+                _ => {
+                    any_statement = true;
+                    continue;
+                }
+            };
+
+            if self.absolute_first {
+                if let Some(source) = source {
+                    if source.value.starts_with('.') {
+                        any_relative = true;
+                    } else if any_relative {
+                        ctx.diagnostic(FirstDiagnostic::AbsoluteAfterRelative(source.span));
+                    }
+                }
+            }
+            if any_statement {
+                ctx.diagnostic(FirstDiagnostic::ImportInBody(stmt.span()));
+            }
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let absolute_first = || Some(serde_json::json!(["absolute-first"]));
+
+    let pass = vec![
+        ("import { x } from './foo'; import { y } from './bar';\nexport { x, y }", None),
+        ("import { x } from 'foo'; import { y } from './bar'", None),
+        ("import { x } from './foo'; import { y } from 'bar'", None),
+        ("import { x } from 'foo'; import { y } from './bar'", absolute_first()),
+        ("'use directive';\nimport { x } from 'foo';", None),
+        ("'use client';\n'use strict';\nimport { x } from 'foo';", None),
+        ("import y = require('bar');\nimport { x } from 'foo';\nimport z = require('baz');", None),
+    ];
+
+    let fail = vec![
+        ("import { x } from './foo'; export { x }; import { y } from './bar';", None),
+        (
+            "import { x } from './foo'; export { x }; import { y } from './bar'; import { z } from './baz';",
+            None,
+        ),
+        ("import { x } from './foo'; import { y } from 'bar'", absolute_first()),
+        ("import { x } from 'foo'; 'use directive'; import { y } from 'bar';", None),
+        (
+            "var a = 1; import { y } from './bar'; if (true) { x() }; import { x } from './foo'; import { z } from './baz';",
+            None,
+        ),
+        ("if (true) { console.log(1) }import a from 'b'", None),
+        ("export {}; import y = require('bar');", None),
+    ];
+
+    Tester::new(First::NAME, pass, fail).test_and_snapshot();
+}
@@ -31,14 +31,12 @@ declare_oxc_lint!(
 
 impl Rule for Named {
     fn run_once(&self, ctx: &LintContext<'_>) {
-        let semantic = ctx.semantic();
-
         // This rule is disabled in the typescript config.
-        if semantic.source_type().is_typescript() {
+        if ctx.source_type().is_typescript() {
             return;
         }
 
-        let module_record = semantic.module_record();
+        let module_record = ctx.module_record();
 
         for import_entry in &module_record.import_entries {
             // Get named import
@@ -1,7 +1,6 @@
 use std::{
     collections::HashSet,
     path::{Path, PathBuf},
-    sync::Arc,
 };
 
 use oxc_diagnostics::{
@@ -10,7 +9,7 @@ use oxc_diagnostics::{
 };
 use oxc_macros::declare_oxc_lint;
 use oxc_span::{Atom, Span};
-use oxc_syntax::module_record::ModuleRecord;
+use oxc_syntax::module_record::{ModuleRecord, RequestedModule};
 
 use crate::{context::LintContext, rule::Rule};
 
@@ -20,8 +19,19 @@ use crate::{context::LintContext, rule::Rule};
 struct NoCycleDiagnostic(#[label] Span, String);
 
 /// <https://github.com/import-js/eslint-plugin-import/blob/main/docs/rules/no-cycle.md>
-#[derive(Debug, Default, Clone)]
-pub struct NoCycle;
+#[derive(Debug, Clone)]
+pub struct NoCycle {
+    /// maximum dependency depth to traverse
+    max_depth: u32,
+    /// ignore type only imports
+    ignore_types: bool,
+}
+
+impl Default for NoCycle {
+    fn default() -> Self {
+        Self { max_depth: u32::MAX, ignore_types: false }
+    }
+}
 
 declare_oxc_lint!(
     /// ### What it does
@@ -31,6 +41,12 @@ declare_oxc_lint!(
     /// This includes cycles of depth 1 (imported module imports me) to "∞" (or Infinity),
     /// if the maxDepth option is not set.
     ///
+    /// ### Options
+    ///
+    /// * `maxDepth`: the maximum number of modules between this module and the import back to it,
+    ///   e.g. `1` only reports cycles where an imported module imports this module.
+    /// * `ignoreTypes`: ignore `import type`, and imports of which all specifiers are types.
+    ///
     /// ### Why is this bad?
     ///
     /// Dependency cycles lead to confusing architectures where bugs become hard to find.
@@ -53,16 +69,33 @@ declare_oxc_lint!(
 );
 
 impl Rule for NoCycle {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let config = value.get(0);
+        let max_depth = config
+            .and_then(|config| config.get("maxDepth"))
+            .and_then(serde_json::Value::as_u64)
+            .map_or(u32::MAX, |v| u32::try_from(v).unwrap_or(u32::MAX));
+        let ignore_types = config
+            .and_then(|config| config.get("ignoreTypes"))
+            .and_then(serde_json::Value::as_bool)
+            .unwrap_or_default();
+        Self { max_depth, ignore_types }
+    }
+
     fn run_once(&self, ctx: &LintContext<'_>) {
-        let module_record = ctx.semantic().module_record();
+        let module_record = ctx.module_record();
 
         let mut visited: HashSet<PathBuf> = HashSet::new();
         let mut stack: Vec<(Atom, PathBuf)> = Vec::new();
         let cwd = std::env::current_dir().unwrap();
 
         let needle = &module_record.resolved_absolute_path;
-        if visit(module_record, &mut visited, &mut stack, needle) {
-            let span = module_record.requested_modules.get(&stack[0].0).unwrap()[0];
+        if self.visit(module_record, 0, &mut visited, &mut stack, needle) {
+            let span = module_record.requested_modules[&stack[0].0]
+                .iter()
+                .find(|request| !(self.ignore_types && request.is_type()))
+                .unwrap()
+                .span();
             let help = stack
                 .into_iter()
                 .map(|(specifier, path)| {
@@ -77,36 +110,56 @@ impl Rule for NoCycle {
     }
 }
 
-/// Walks ModuleRecord and returns the path stack
-/// if there is a cycle
-fn visit(
-    module_record: &Arc<ModuleRecord>,
-    visited: &mut HashSet<PathBuf>,
-    stack: &mut Vec<(Atom, PathBuf)>,
-    needle: &Path,
-) -> bool {
-    let path = &module_record.resolved_absolute_path;
-    if path.components().any(|c| match c {
-        std::path::Component::Normal(p) => p == std::ffi::OsStr::new("node_modules"),
-        _ => false,
-    }) {
-        return false;
-    }
-    for module_record_ref in &module_record.loaded_modules {
-        let resolved_absolute_path = &module_record_ref.resolved_absolute_path;
-        if !visited.insert(resolved_absolute_path.clone()) {
-            continue;
+impl NoCycle {
+    /// Walks ModuleRecord and returns the path stack
+    /// if there is a cycle
+    ///
+    /// `depth` is the number of modules between the linted module and `module_record`.
+    fn visit(
+        &self,
+        module_record: &ModuleRecord,
+        depth: u32,
+        visited: &mut HashSet<PathBuf>,
+        stack: &mut Vec<(Atom, PathBuf)>,
+        needle: &Path,
+    ) -> bool {
+        if depth > self.max_depth {
+            return false;
         }
-        stack.push((module_record_ref.key().clone(), resolved_absolute_path.clone()));
-        if needle == resolved_absolute_path {
-            return true;
+        let path = &module_record.resolved_absolute_path;
+        if path.components().any(|c| match c {
+            std::path::Component::Normal(p) => p == std::ffi::OsStr::new("node_modules"),
+            _ => false,
+        }) {
+            return false;
         }
-        if visit(module_record_ref.value(), visited, stack, needle) {
-            return true;
+        for module_record_ref in &module_record.loaded_modules {
+            if self.ignore_types && is_type_only(module_record, module_record_ref.key()) {
+                continue;
+            }
+            let resolved_absolute_path = &module_record_ref.resolved_absolute_path;
+            if !visited.insert(resolved_absolute_path.clone()) {
+                continue;
+            }
+            stack.push((module_record_ref.key().clone(), resolved_absolute_path.clone()));
+            if needle == resolved_absolute_path {
+                return true;
+            }
+            if self.visit(module_record_ref.value(), depth + 1, visited, stack, needle) {
+                return true;
+            }
+            stack.pop();
         }
-        stack.pop();
+        false
     }
-    false
+}
+
+/// All the imports of `specifier` are type only
+fn is_type_only(module_record: &ModuleRecord, specifier: &Atom) -> bool {
+    module_record
+        .requested_modules
+        .get(specifier)
+        .is_some_and(|requests| requests.iter().all(RequestedModule::is_type))
 }
 
 #[test]
@@ -141,4 +194,28 @@ fn test() {
         .change_rule_path("cycles/depth-zero.js")
         .with_import_plugin(true)
         .test_and_snapshot();
+
+    // `two.ts` imports `index.ts`, `three-a.ts` imports `three-b.ts` which imports `index.ts`
+    let path = || Some(PathBuf::from("cycles/typescript/index.ts"));
+    let ignore_types = || Some(serde_json::json!([{ "ignoreTypes": true }]));
+    let max_depth = |depth: u32| Some(serde_json::json!([{ "maxDepth": depth }]));
+
+    let pass = vec![
+        ("import type { two } from './two'", ignore_types(), None, path()),
+        ("import { type two } from './two'", ignore_types(), None, path()),
+        ("export type { two } from './two'", ignore_types(), None, path()),
+        ("import { a } from './three-a'", max_depth(1), None, path()),
+    ];
+
+    let fail = vec![
+        ("import { two } from './two'", None, None, path()),
+        ("import type { two } from './two'", None, None, path()),
+        ("import { two } from './two'", ignore_types(), None, path()),
+        ("import type { a } from './two'; import { b } from './two'", ignore_types(), None, path()),
+        ("import { two } from './two'", max_depth(1), None, path()),
+        ("import { a } from './three-a'", None, None, path()),
+        ("import { a } from './three-a'", max_depth(2), None, path()),
+    ];
+
+    Tester::new(NoCycle::NAME, pass, fail).with_import_plugin(true).test();
 }
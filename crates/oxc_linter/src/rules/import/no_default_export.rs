@@ -0,0 +1,115 @@
+use oxc_ast::{ast::ModuleDeclaration, AstKind};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{Atom, GetSpan, Span};
+
+use crate::{context::LintContext, rule::Rule, AstNode};
+
+#[derive(Debug, Error, Diagnostic)]
+enum NoDefaultExportDiagnostic {
+    #[error("eslint-plugin-import(no-default-export): Prefer named exports.")]
+    #[diagnostic(severity(warning), help("Replace the default export with a named export"))]
+    DefaultExport(#[label] Span),
+    #[error("eslint-plugin-import(no-default-export): Do not alias `{1}` as `default`.")]
+    #[diagnostic(severity(warning), help("Just export `{1}` itself instead"))]
+    AliasAsDefault(#[label] Span, Atom),
+}
+
+/// <https://github.com/import-js/eslint-plugin-import/blob/main/docs/rules/no-default-export.md>
+#[derive(Debug, Default, Clone)]
+pub struct NoDefaultExport;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Forbids a module from having a default export.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Default exports can be imported under any name, named exports keep the same name in every
+    /// module that imports them, which makes them easier to find and refactor.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// // fail
+    /// export default 'bar';
+    /// const foo = 'foo';
+    /// export { foo as default }
+    ///
+    /// // pass
+    /// export const foo = 'foo';
+    /// ```
+    NoDefaultExport,
+    nursery
+);
+
+impl Rule for NoDefaultExport {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::ModuleDeclaration(decl) = node.kind() else { return };
+        match decl {
+            ModuleDeclaration::ExportDefaultDeclaration(decl) => {
+                ctx.diagnostic(NoDefaultExportDiagnostic::DefaultExport(decl.exported.span()));
+            }
+            ModuleDeclaration::ExportNamedDeclaration(decl) => {
+                for specifier in &decl.specifiers {
+                    if specifier.exported.name().as_str() != "default" {
+                        continue;
+                    }
+                    // `export { default } from './foo'`
+                    let diagnostic = if specifier.local.name().as_str() == "default" {
+                        NoDefaultExportDiagnostic::DefaultExport(specifier.span)
+                    } else {
+                        NoDefaultExportDiagnostic::AliasAsDefault(
+                            specifier.span,
+                            specifier.local.name().clone(),
+                        )
+                    };
+                    ctx.diagnostic(diagnostic);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "export const foo = 'foo'; export const bar = 'bar';",
+        "export const foo = 'foo'; export function bar() {};",
+        "export const foo = 'foo';",
+        "const foo = 'foo'; export { foo };",
+        "let foo, bar; export { foo, bar }",
+        "export const { foo, bar } = item;",
+        "export const { foo, bar: baz } = item;",
+        "export const { foo: { bar, baz } } = item;",
+        "let item; export const foo = item; export { item };",
+        "export * from './foo';",
+        "export const { foo } = { foo: 'bar' };",
+        "export const { foo: { bar } } = { foo: { bar: 'baz' } };",
+        "export { a, b } from 'foo.js'",
+        "import * as foo from './foo';",
+        "import foo from './foo';",
+        "import {default as foo} from './foo';",
+        "export type UserId = number;",
+        "export { foo as bar } from './foo';",
+    ];
+
+    let fail = vec![
+        "export default function bar() {};",
+        "export const foo = 'foo'; export default bar;",
+        "export default class Bar {};",
+        "export default interface Bar {};",
+        "let foo; export { foo as default }",
+        "let foo; export { foo as 'default' }",
+        "export { default } from './foo';",
+        "export { foo as default } from './foo';",
+    ];
+
+    Tester::new_without_config(NoDefaultExport::NAME, pass, fail).test_and_snapshot();
+}
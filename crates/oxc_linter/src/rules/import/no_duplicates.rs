@@ -0,0 +1,322 @@
+use std::path::PathBuf;
+
+use oxc_ast::{
+    ast::{ImportDeclaration, ImportDeclarationSpecifier, ModuleDeclaration, Statement},
+    AstKind,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{Atom, Span};
+use rustc_hash::FxHashMap;
+
+use crate::{
+    context::LintContext,
+    fixer::{Fix, FixKind},
+    rule::Rule,
+};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint-plugin-import(no-duplicates): '{1}' imported multiple times.")]
+#[diagnostic(severity(warning), help("Merge the imports of this module into the first one"))]
+struct NoDuplicatesDiagnostic(#[label] Span, Atom);
+
+/// <https://github.com/import-js/eslint-plugin-import/blob/main/docs/rules/no-duplicates.md>
+#[derive(Debug, Default, Clone)]
+pub struct NoDuplicates {
+    /// Merge type only imports into value imports with inline `type` specifiers
+    prefer_inline: bool,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Reports if a resolved path is imported more than once.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Importing a module in several statements makes it harder to see what is imported from it.
+    ///
+    /// ### Options
+    ///
+    /// * `prefer-inline`: `import type` is merged with the value imports of the same module as
+    ///   inline `type` specifiers, instead of being a distinct import.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// import { a } from './foo';
+    /// import { b } from './foo'; // reported, merged into `import { a, b } from './foo';`
+    ///
+    /// import type { C } from './foo'; // not reported without `prefer-inline`
+    /// ```
+    NoDuplicates,
+    nursery,
+    fix
+);
+
+/// The resolved module, whether the imports are type only and whether they are namespace imports
+type GroupKey = (PathBuf, bool, bool);
+
+impl Rule for NoDuplicates {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let prefer_inline = value
+            .get(0)
+            .and_then(|config| config.get("prefer-inline"))
+            .and_then(serde_json::Value::as_bool)
+            .unwrap_or_default();
+        Self { prefer_inline }
+    }
+
+    fn run_once(&self, ctx: &LintContext<'_>) {
+        let Some(root) = ctx.nodes().iter().next() else { return };
+        let AstKind::Program(program) = root.kind() else { return };
+        // The import declarations by the start of their module specifier, requested modules also
+        // come from `require` calls and `export ... from`.
+        let imports = program
+            .body
+            .iter()
+            .filter_map(|stmt| match stmt {
+                Statement::ModuleDeclaration(decl) => match &**decl {
+                    ModuleDeclaration::ImportDeclaration(import) => {
+                        Some((import.source.span.start, &**import))
+                    }
+                    _ => None,
+                },
+                _ => None,
+            })
+            .collect::<FxHashMap<_, _>>();
+
+        let module_record = ctx.module_record();
+        // Modules are the same if they resolve to the same file, and type only imports are
+        // distinct from value imports unless `prefer-inline` is set. Namespace imports can only
+        // duplicate other namespace imports.
+        let mut groups: Vec<(GroupKey, Vec<&ImportDeclaration>)> = vec![];
+        for (specifier, requested_modules) in &module_record.requested_modules {
+            let path = module_record.loaded_modules.get(specifier).map_or_else(
+                || PathBuf::from(specifier.as_str()),
+                |module| module.resolved_absolute_path.clone(),
+            );
+            for requested_module in requested_modules {
+                let Some(import) = imports.get(&requested_module.span().start) else { continue };
+                let is_namespace = import.specifiers.iter().flatten().any(|specifier| {
+                    matches!(specifier, ImportDeclarationSpecifier::ImportNamespaceSpecifier(_))
+                });
+                let key = (
+                    path.clone(),
+                    !self.prefer_inline && import.import_kind.is_type(),
+                    is_namespace,
+                );
+                match groups.iter_mut().find(|(k, _)| *k == key) {
+                    Some((_, group)) => group.push(import),
+                    None => groups.push((key, vec![import])),
+                }
+            }
+        }
+
+        for (_, mut group) in groups {
+            if group.len() < 2 {
+                continue;
+            }
+            group.sort_by_key(|import| import.span.start);
+            let mut fix = merge(ctx, &group);
+            for import in &group[1..] {
+                let diagnostic =
+                    NoDuplicatesDiagnostic(import.source.span, import.source.value.clone());
+                match fix.take() {
+                    Some(fix) => ctx.diagnostic_with_fix(diagnostic, || fix),
+                    None => ctx.diagnostic(diagnostic),
+                }
+            }
+        }
+    }
+}
+
+/// The first import with the specifiers of the others, followed by the code between the
+/// imports. `None` if the imports cannot be merged, e.g. with namespace imports.
+fn merge<'a>(ctx: &LintContext<'a>, group: &[&ImportDeclaration]) -> Option<Fix<'a>> {
+    let source_text = ctx.source_text();
+    let trivias = ctx.semantic().trivias();
+    if group
+        .iter()
+        .any(|import| import.with_clause.is_some() || trivias.has_comments_between(import.span))
+    {
+        return None;
+    }
+
+    let all_types = group.iter().all(|import| import.import_kind.is_type());
+    let mut default: Option<&Atom> = None;
+    let mut named: Vec<String> = vec![];
+    for import in group {
+        // `import type { a }` becomes `type a` with `prefer-inline`
+        let inline_type = import.import_kind.is_type() && !all_types;
+        for specifier in import.specifiers.iter().flatten() {
+            match specifier {
+                ImportDeclarationSpecifier::ImportSpecifier(specifier) => {
+                    let text = specifier.span.source_text(source_text);
+                    let text = if inline_type { format!("type {text}") } else { text.to_string() };
+                    if !named.contains(&text) {
+                        named.push(text);
+                    }
+                }
+                ImportDeclarationSpecifier::ImportDefaultSpecifier(specifier) => {
+                    if inline_type {
+                        return None;
+                    }
+                    match default {
+                        Some(name) if *name != specifier.local.name => return None,
+                        _ => default = Some(&specifier.local.name),
+                    }
+                }
+                ImportDeclarationSpecifier::ImportNamespaceSpecifier(_) => return None,
+            }
+        }
+    }
+
+    let first = group[0];
+    let mut clauses = vec![];
+    if let Some(default) = default {
+        clauses.push(default.to_string());
+    }
+    if !named.is_empty() {
+        clauses.push(format!("{{ {} }}", named.join(", ")));
+    }
+    let mut content = String::from(if all_types { "import type " } else { "import " });
+    if !clauses.is_empty() {
+        content.push_str(&clauses.join(", "));
+        content.push_str(" from ");
+    }
+    content.push_str(first.source.span.source_text(source_text));
+    if first.span.source_text(source_text).ends_with(';') {
+        content.push(';');
+    }
+
+    // Remove the other imports, with their line break
+    let mut end = first.span.end as usize;
+    for import in &group[1..] {
+        content.push_str(&source_text[end..import.span.start as usize]);
+        end = import.span.end as usize;
+        let rest = &source_text[end..];
+        if rest.starts_with("\r\n") {
+            end += 2;
+        } else if rest.starts_with('\n') {
+            end += 1;
+        } else if let Some(stripped) = content.strip_suffix('\n') {
+            // Without a line break after the import, remove the one before it
+            content.truncate(stripped.strip_suffix('\r').unwrap_or(stripped).len());
+        }
+    }
+    #[allow(clippy::cast_possible_truncation)]
+    let span = Span::new(first.span.start, end as u32);
+    Some(Fix::new(content, span).with_kind(FixKind::Safe))
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        (r"import { a } from './foo'; import { b } from './bar';", None),
+        (r"import type { a } from './foo'; import { b } from './foo';", None),
+        (r"import { a } from './foo'; export { b } from './foo';", None),
+        (r"import { a } from './foo'; const b = require('./foo');", None),
+        (r"import * as ns from './foo'; import type { b } from './foo';", None),
+        (r"import * as ns from './foo'; import { b } from './foo';", None),
+        (r"import a, * as ns from './foo'; import { b } from './foo';", None),
+    ];
+
+    let fail = vec![
+        (r"import { a } from './foo'; import { b } from './foo';", None),
+        (r"import a from './foo'; import { b } from './foo'; import './foo';", None),
+        (r"import type { a } from './foo'; import type { b } from './foo';", None),
+        (
+            r"import type { a } from './foo'; import { b } from './foo';",
+            Some(serde_json::json!([{ "prefer-inline": true }])),
+        ),
+        (r"import * as ns from './foo'; import * as ns2 from './foo';", None),
+    ];
+
+    let fix = vec![
+        (
+            "import { a } from './foo';\nimport { b } from './foo';\n",
+            "import { a, b } from './foo';\n",
+            None,
+        ),
+        (
+            "import a from \"./foo\"\nlet x = 1;\nimport { b, c as d } from './foo';\nimport './foo';\nx;",
+            "import a, { b, c as d } from \"./foo\"\nlet x = 1;\nx;",
+            None,
+        ),
+        (
+            "import { a } from './foo';\r\nimport { b } from './foo';\r\n",
+            "import { a, b } from './foo';\r\n",
+            None,
+        ),
+        // `import { a, b } from './foo'; import { b, c } from './foo'` in `test_merge_same_specifiers`
+        (
+            "import type { A } from './foo';\nimport type { B } from './foo';",
+            "import type { A, B } from './foo';",
+            None,
+        ),
+        (
+            "import { a } from './foo';\nimport type { B } from './foo';",
+            "import { a, type B } from './foo';",
+            Some(serde_json::json!([{ "prefer-inline": true }])),
+        ),
+        // not mergeable
+        (
+            "import * as ns from './foo';\nimport * as ns2 from './foo';",
+            "import * as ns from './foo';\nimport * as ns2 from './foo';",
+            None,
+        ),
+        (
+            "import a from './foo';\nimport b from './foo';",
+            "import a from './foo';\nimport b from './foo';",
+            None,
+        ),
+        (
+            "import { a } from './foo';\nimport { /* b */ b } from './foo';",
+            "import { a } from './foo';\nimport { /* b */ b } from './foo';",
+            None,
+        ),
+    ];
+
+    Tester::new(NoDuplicates::NAME, pass, fail)
+        .expect_fix(fix)
+        .expect_fix_kind(FixKind::Safe)
+        .test_and_snapshot();
+}
+
+/// Imports of the same name are a redeclaration error, which the `Tester` reports without
+/// running the rule.
+#[test]
+fn test_merge_same_specifiers() {
+    use std::rc::Rc;
+
+    use oxc_allocator::Allocator;
+    use oxc_parser::Parser;
+    use oxc_semantic::SemanticBuilder;
+    use oxc_span::SourceType;
+
+    use crate::{fixer::Fixer, LintOptions, LintSettings, Linter, RULES};
+
+    let allocator = Allocator::default();
+    let source_text = "import { a, b } from './foo';\nimport { b, c } from './foo';";
+    let source_type = SourceType::default().with_module(true);
+    let ret = Parser::new(&allocator, source_text, source_type).parse();
+    let program = allocator.alloc(ret.program);
+    let semantic_ret = SemanticBuilder::new(source_text, source_type)
+        .with_trivias(ret.trivias)
+        .build_module_record(PathBuf::from("test.js"), program)
+        .build(program);
+    assert!(!semantic_ret.errors.is_empty());
+    let semantic = Rc::new(semantic_ret.semantic);
+
+    let rule = RULES.iter().find(|rule| rule.name() == NoDuplicates::NAME).unwrap().clone();
+    let linter =
+        Linter::from_options(LintOptions::default().with_fix(true)).unwrap().with_rules(vec![rule]);
+    let ctx = LintContext::new(PathBuf::from("test.js").into(), &semantic, LintSettings::default());
+    let fixed = Fixer::new(source_text, linter.run(ctx)).fix().fixed_code;
+    assert_eq!(fixed, "import { a, b, c } from './foo';");
+}
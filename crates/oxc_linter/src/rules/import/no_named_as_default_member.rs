@@ -0,0 +1,147 @@
+use oxc_ast::{
+    ast::{BindingPatternKind, Expression},
+    AstKind,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{Atom, GetSpan, Span};
+use oxc_syntax::module_record::{ImportImportName, ModuleRecord};
+
+use crate::{context::LintContext, rule::Rule};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint-plugin-import(no-named-as-default-member): `{1}` also has a named export `{2}`")]
+#[diagnostic(
+    severity(warning),
+    help("Check if you meant to write `import {{{2}}} from '{3}'` instead")
+)]
+struct NoNamedAsDefaultMemberDiagnostic(#[label] Span, Atom, Atom, Atom);
+
+/// <https://github.com/import-js/eslint-plugin-import/blob/main/docs/rules/no-named-as-default-member.md>
+#[derive(Debug, Default, Clone)]
+pub struct NoNamedAsDefaultMember;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Reports the use of an exported name as a property of the default export.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Accessing a property of the default export with the name of a named export is likely a
+    /// mistake, the named export is probably what was meant.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// // ./foo.js
+    /// export default 'foo';
+    /// export const bar = 'baz';
+    ///
+    /// // fail
+    /// import foo from './foo';
+    /// const bar = foo.bar;
+    /// const { bar } = foo;
+    ///
+    /// // pass
+    /// import { bar } from './foo';
+    /// ```
+    NoNamedAsDefaultMember,
+    nursery
+);
+
+impl Rule for NoNamedAsDefaultMember {
+    fn run_once(&self, ctx: &LintContext<'_>) {
+        let module_record = ctx.module_record();
+        for import_entry in &module_record.import_entries {
+            let ImportImportName::Default(_) = import_entry.import_name else { continue };
+
+            let specifier = import_entry.module_request.name();
+            let Some(remote_module_record_ref) = module_record.loaded_modules.get(specifier) else {
+                continue;
+            };
+            let local_name = import_entry.local_name.name();
+            let Some(symbol_id) =
+                ctx.scopes().get_binding(ctx.scopes().root_scope_id(), local_name)
+            else {
+                continue;
+            };
+
+            let report = |span: Span, name: &str| {
+                if is_named_export(&remote_module_record_ref, name) {
+                    ctx.diagnostic(NoNamedAsDefaultMemberDiagnostic(
+                        span,
+                        local_name.clone(),
+                        Atom::from(name),
+                        specifier.clone(),
+                    ));
+                }
+            };
+
+            for reference in ctx.symbols().get_resolved_references(symbol_id) {
+                let Some(parent) = ctx.nodes().parent_node(reference.node_id()) else { continue };
+                match parent.kind() {
+                    // `foo.bar`
+                    AstKind::MemberExpression(member_expr) => {
+                        let Expression::Identifier(ident) = member_expr.object() else { continue };
+                        if ident.span != reference.span() {
+                            continue;
+                        }
+                        if let Some(name) = member_expr.static_property_name() {
+                            report(member_expr.span(), name);
+                        }
+                    }
+                    // `const { bar } = foo`
+                    AstKind::VariableDeclarator(decl) => {
+                        let BindingPatternKind::ObjectPattern(pattern) = &decl.id.kind else {
+                            continue;
+                        };
+                        for property in &pattern.properties {
+                            if let Some(name) = property.key.static_name() {
+                                report(property.span, &name);
+                            }
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+}
+
+fn is_named_export(module_record: &ModuleRecord, name: &str) -> bool {
+    name != "default" && module_record.exported_bindings.contains_key(name)
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        r#"import bar, {foo} from "./bar";"#,
+        r#"import bar from "./bar"; const baz = bar.baz"#,
+        r#"import {foo} from "./bar"; const baz = foo.baz;"#,
+        r#"import * as named from "./named-exports"; const a = named.a"#,
+        r#"import foo from "./default-export-default-property"; const a = foo.default"#,
+        r#"import bar, { foo } from "./export-default-string-and-named""#,
+        r#"import bar from "./bar"; const { baz } = bar;"#,
+        r#"import bar from "./bar"; function f(bar) { return bar.foo }"#,
+        r#"import bar from "./bar"; const foo = baz[bar];"#,
+    ];
+
+    let fail = vec![
+        r#"import bar from "./bar"; const foo = bar.foo;"#,
+        r#"import bar from "./bar"; bar.foo();"#,
+        r#"import bar from "./bar"; const {foo} = bar;"#,
+        r#"import bar from "./bar"; const {foo: foo2, baz} = bar;"#,
+        r#"import bar from "./export-default-string-and-named"; const foo = bar.foo;"#,
+        r#"import bar from "./bar"; const foo = bar["foo"];"#,
+    ];
+
+    Tester::new_without_config(NoNamedAsDefaultMember::NAME, pass, fail)
+        .change_rule_path("index.js")
+        .with_import_plugin(true)
+        .test_and_snapshot();
+}
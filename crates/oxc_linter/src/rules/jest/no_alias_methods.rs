@@ -8,7 +8,7 @@ use oxc_span::Span;
 
 use crate::{
     context::LintContext,
-    fixer::Fix,
+    fixer::{Fix, FixKind},
     rule::Rule,
     utils::{collect_possible_jest_call_node, parse_expect_jest_fn_call, PossibleJestNode},
 };
@@ -46,7 +46,8 @@ declare_oxc_lint!(
     /// expect(a).toThrowError();
     /// ```
     NoAliasMethods,
-    style
+    style,
+    fix
 );
 
 impl Rule for NoAliasMethods {
@@ -82,7 +83,7 @@ fn run<'a>(possible_jest_node: &PossibleJestNode<'a, '_>, ctx: &LintContext<'a>)
 
                 ctx.diagnostic_with_fix(
                     NoAliasMethodsDiagnostic(name, canonical_name, matcher.span),
-                    || Fix::new(canonical_name, Span { start, end }),
+                    || Fix::new(canonical_name, Span { start, end }).with_kind(FixKind::Safe),
                 );
             }
         }
@@ -184,5 +185,6 @@ fn test() {
     Tester::new(NoAliasMethods::NAME, pass, fail)
         .with_jest_plugin(true)
         .expect_fix(fix)
+        .expect_fix_kind(FixKind::Safe)
         .test_and_snapshot();
 }
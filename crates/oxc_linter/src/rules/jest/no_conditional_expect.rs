@@ -101,6 +101,12 @@ fn check_parents<'a>(
 
     match parent_node.kind() {
         AstKind::CallExpression(call_expr) => {
+            if let Expression::MemberExpression(member_expr) = &call_expr.callee {
+                if member_expr.static_property_name() == Some("catch") {
+                    return check_parents(parent_node, id_nodes_mapping, ctx, true);
+                }
+            }
+
             let Some(parent) = id_nodes_mapping.get(&parent_node.id()) else {
                 return check_parents(parent_node, id_nodes_mapping, ctx, in_conditional);
             };
@@ -112,18 +118,11 @@ fn check_parents<'a>(
             ) {
                 return in_conditional;
             }
-
-            if let Expression::MemberExpression(member_expr) = &call_expr.callee {
-                if member_expr.static_property_name() == Some("catch") {
-                    return check_parents(parent_node, id_nodes_mapping, ctx, true);
-                }
-            }
         }
         AstKind::CatchClause(_)
         | AstKind::SwitchStatement(_)
         | AstKind::IfStatement(_)
         | AstKind::ConditionalExpression(_)
-        | AstKind::AwaitExpression(_)
         | AstKind::LogicalExpression(_) => {
             return check_parents(parent_node, id_nodes_mapping, ctx, true)
         }
@@ -404,6 +403,15 @@ fn test() {
             ",
             None,
         ),
+        (
+            "
+                it('works', async () => {
+                    await expect(Promise.resolve(2)).resolves.toBe(2);
+                    await expect(Promise.reject(new Error())).rejects.toThrow();
+                });
+            ",
+            None,
+        ),
     ];
 
     let fail = vec![
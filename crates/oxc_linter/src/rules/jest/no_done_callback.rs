@@ -1,5 +1,5 @@
 use oxc_ast::{
-    ast::{Argument, CallExpression, Expression, FormalParameters},
+    ast::{Argument, BindingPatternKind, CallExpression, Expression, FormalParameters},
     AstKind,
 };
 use oxc_diagnostics::{
@@ -11,6 +11,7 @@ use oxc_span::Span;
 
 use crate::{
     context::LintContext,
+    fixer::Fix,
     rule::Rule,
     utils::{
         collect_possible_jest_call_node, get_node_name, parse_general_jest_fn_call, JestFnKind,
@@ -77,7 +78,6 @@ declare_oxc_lint!(
     /// });
     /// ```
     NoDoneCallback,
-    // TODO: add suggestion (see jest-community/eslint-plugin-jest#586)
     style
 );
 
@@ -133,7 +133,7 @@ fn run<'a>(possible_jest_node: &PossibleJestNode<'a, '_>, ctx: &LintContext<'a>)
                         return;
                     }
 
-                    ctx.diagnostic(NoDoneCallbackDiagnostic::NoDoneCallback(span));
+                    report(span, func_expr.span.start, &func_expr.params, callback_arg_index, ctx);
                 }
                 Expression::ArrowExpression(arrow_expr) => {
                     if arrow_expr.params.parameters_count() != 1 + callback_arg_index {
@@ -149,7 +149,13 @@ fn run<'a>(possible_jest_node: &PossibleJestNode<'a, '_>, ctx: &LintContext<'a>)
                         return;
                     }
 
-                    ctx.diagnostic(NoDoneCallbackDiagnostic::NoDoneCallback(span));
+                    report(
+                        span,
+                        arrow_expr.span.start,
+                        &arrow_expr.params,
+                        callback_arg_index,
+                        ctx,
+                    );
                 }
                 _ => {}
             }
@@ -157,6 +163,50 @@ fn run<'a>(possible_jest_node: &PossibleJestNode<'a, '_>, ctx: &LintContext<'a>)
     }
 }
 
+fn report<'a>(
+    span: Span,
+    callback_start: u32,
+    params: &FormalParameters<'a>,
+    callback_arg_index: usize,
+    ctx: &LintContext<'a>,
+) {
+    let diagnostic = NoDoneCallbackDiagnostic::NoDoneCallback(span);
+    if callback_arg_index != 0 {
+        ctx.diagnostic(diagnostic);
+        return;
+    }
+    match make_async_suggestion(callback_start, params, ctx) {
+        Some(suggestion) => ctx.diagnostic_with_suggestions(diagnostic, vec![suggestion]),
+        None => ctx.diagnostic(diagnostic),
+    }
+}
+
+/// `done => {}` -> `async () => {}`, only offered when `done` is never used.
+fn make_async_suggestion<'a>(
+    callback_start: u32,
+    params: &FormalParameters<'a>,
+    ctx: &LintContext<'a>,
+) -> Option<Fix<'a>> {
+    if params.rest.is_some() {
+        return None;
+    }
+    let BindingPatternKind::BindingIdentifier(ident) = &params.items.first()?.pattern.kind else {
+        return None;
+    };
+    let symbol_id = ident.symbol_id.get()?;
+    if ctx.symbols().get_resolved_references(symbol_id).next().is_some() {
+        return None;
+    }
+
+    let head = Span::new(callback_start, params.span.start).source_text(ctx.source_text());
+    let message =
+        format!("Remove the unused `{}` parameter and make the callback async", ident.name);
+    Some(
+        Fix::new(format!("async {head}()"), Span::new(callback_start, params.span.end))
+            .with_message(message),
+    )
+}
+
 fn get_span_of_first_parameter(params: &FormalParameters) -> Option<Span> {
     let span = params.items.first().map(|param| param.span);
     if span.is_none() {
@@ -291,7 +341,34 @@ fn test() {
         ),
         ("test.each``('something', ({ a, b }, done) => { done(); })", None),
         ("it.each``('something', ({ a, b }, done) => { done(); })", None),
+        ("test('something', ({ done }) => { done(); })", None),
+        ("test('something', done => { expect(1).toBe(1); })", None),
+        ("test('something', (done) => expect(1).toBe(1))", None),
+        ("test('something', function foo(done) { expect(1).toBe(1); })", None),
+        ("beforeEach(function (done) {})", None),
+    ];
+
+    let suggestions = vec![
+        (
+            "test('something', done => { expect(1).toBe(1); })",
+            vec!["test('something', async () => { expect(1).toBe(1); })"],
+            None,
+        ),
+        (
+            "test('something', (done) => expect(1).toBe(1))",
+            vec!["test('something', async () => expect(1).toBe(1))"],
+            None,
+        ),
+        (
+            "test('something', function foo(done) { expect(1).toBe(1); })",
+            vec!["test('something', async function foo() { expect(1).toBe(1); })"],
+            None,
+        ),
+        ("beforeEach(function (done) {})", vec!["beforeEach(async function () {})"], None),
     ];
 
-    Tester::new(NoDoneCallback::NAME, pass, fail).with_jest_plugin(true).test_and_snapshot();
+    Tester::new(NoDoneCallback::NAME, pass, fail)
+        .with_jest_plugin(true)
+        .expect_suggestions(suggestions)
+        .test_and_snapshot();
 }
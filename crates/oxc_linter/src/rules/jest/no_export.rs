@@ -44,11 +44,11 @@ impl Rule for NoExport {
             return;
         }
 
-        for span in ctx.semantic().module_record().exported_bindings.values() {
+        for span in ctx.module_record().exported_bindings.values() {
             ctx.diagnostic(NoExportDiagnostic(*span));
         }
 
-        if let Some(span) = ctx.semantic().module_record().export_default {
+        if let Some(span) = ctx.module_record().export_default {
             ctx.diagnostic(NoExportDiagnostic(span));
         }
     }
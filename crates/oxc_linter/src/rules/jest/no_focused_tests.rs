@@ -29,6 +29,9 @@ declare_oxc_lint!(
     /// This rule reminds you to remove `.only` from your tests by raising a warning
     /// whenever you are using the exclusivity feature.
     ///
+    /// Only test files are checked: files in a `__tests__` directory, files named like
+    /// `*.test.*` or `*.spec.*`, or any file when the `jest` environment is enabled.
+    ///
     /// ### Why is this bad?
     ///
     /// Jest has a feature that allows you to focus tests by appending `.only` or
@@ -53,11 +56,15 @@ declare_oxc_lint!(
     /// `();
     /// ```
     NoFocusedTests,
-    correctness
+    correctness,
+    fix
 );
 
 impl Rule for NoFocusedTests {
     fn run_once(&self, ctx: &LintContext) {
+        if !ctx.is_test_file() {
+            return;
+        }
         for node in &collect_possible_jest_call_node(ctx) {
             run(node, ctx);
         }
@@ -150,6 +157,11 @@ fn test() {
 
     Tester::new(NoFocusedTests::NAME, pass, fail)
         .with_jest_plugin(true)
+        .change_rule_path("no_focused_tests.test.tsx")
         .expect_fix(fix)
         .test_and_snapshot();
+
+    // Focused tests outside of test files are not reported.
+    let pass = vec![("describe.only()", None), ("it.only()", None), ("fit()", None)];
+    Tester::new(NoFocusedTests::NAME, pass, vec![]).with_jest_plugin(true).test();
 }
@@ -8,7 +8,7 @@ use oxc_span::{Atom, GetSpan, Span};
 
 use crate::{
     context::LintContext,
-    fixer::Fix,
+    fixer::{Fix, FixKind},
     rule::Rule,
     utils::{
         collect_possible_jest_call_node, parse_general_jest_fn_call, JestGeneralFnKind,
@@ -47,7 +47,8 @@ declare_oxc_lint!(
     /// xdescribe('foo'); // invalid
     /// ```
     NoTestPrefixes,
-    style
+    style,
+    fix
 );
 
 impl Rule for NoTestPrefixes {
@@ -87,7 +88,7 @@ fn run<'a>(possible_jest_node: &PossibleJestNode<'a, '_>, ctx: &LintContext<'a>)
     let preferred_node_name_cloned = preferred_node_name.clone();
 
     ctx.diagnostic_with_fix(NoTestPrefixesDiagnostic(preferred_node_name, span), || {
-        Fix::new(preferred_node_name_cloned.to_string(), span)
+        Fix::new(preferred_node_name_cloned.to_string(), span).with_kind(FixKind::Safe)
     });
 }
 
@@ -0,0 +1,429 @@
+use oxc_ast::{
+    ast::{Argument, Expression},
+    AstKind,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+use oxc_syntax::operator::UnaryOperator;
+
+use crate::{
+    context::LintContext,
+    fixer::{Fix, FixKind},
+    rule::Rule,
+    utils::{
+        collect_possible_jest_call_node, parse_expect_jest_fn_call, KnownMemberExpressionProperty,
+        ParsedExpectFnCall, PossibleJestNode,
+    },
+};
+
+#[derive(Debug, Error, Diagnostic)]
+enum PreferToBeDiagnostic {
+    #[error("eslint-plugin-jest(prefer-to-be): Use `toBe` when expecting primitive literals.")]
+    #[diagnostic(severity(warning), help("Replace the matcher with `toBe`."))]
+    UseToBe(#[label] Span),
+
+    #[error("eslint-plugin-jest(prefer-to-be): Use `toBeUndefined` instead.")]
+    #[diagnostic(severity(warning), help("Replace the matcher with `toBeUndefined()`."))]
+    UseToBeUndefined(#[label] Span),
+
+    #[error("eslint-plugin-jest(prefer-to-be): Use `toBeDefined` instead.")]
+    #[diagnostic(severity(warning), help("Replace the matcher with `toBeDefined()`."))]
+    UseToBeDefined(#[label] Span),
+
+    #[error("eslint-plugin-jest(prefer-to-be): Use `toBeNull` instead.")]
+    #[diagnostic(severity(warning), help("Replace the matcher with `toBeNull()`."))]
+    UseToBeNull(#[label] Span),
+
+    #[error("eslint-plugin-jest(prefer-to-be): Use `toBeNaN` instead.")]
+    #[diagnostic(severity(warning), help("Replace the matcher with `toBeNaN()`."))]
+    UseToBeNaN(#[label] Span),
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct PreferToBe;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Recommends using `toBe()` when expecting primitive literals, and `toBeNull()`,
+    /// `toBeUndefined()`, `toBeDefined()` or `toBeNaN()` for the matching values.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// When asserting against primitive literals such as numbers and strings, the
+    /// equality matchers all operate the same, but read slightly differently in code.
+    /// The specific matchers for `null`, `undefined` and `NaN` also give better error
+    /// messages when they fail.
+    ///
+    /// ### Example
+    ///
+    /// ```javascript
+    /// // invalid
+    /// expect(value).not.toEqual(5);
+    /// expect(getMessage()).toStrictEqual('hello world');
+    /// expect(loadMessage()).resolves.toEqual('hello world');
+    /// expect(value).toBe(null);
+    /// expect(value).not.toBe(undefined);
+    /// expect(value).toEqual(NaN);
+    ///
+    /// // valid
+    /// expect(value).not.toBe(5);
+    /// expect(getMessage()).toBe('hello world');
+    /// expect(loadMessage()).resolves.toBe('hello world');
+    /// expect(didError).not.toBe(true);
+    /// expect(catchError()).toStrictEqual({ message: 'oh noes!' });
+    /// expect(value).toBeNull();
+    /// expect(value).toBeDefined();
+    /// expect(value).toBeNaN();
+    /// ```
+    PreferToBe,
+    nursery,
+    fix
+);
+
+impl Rule for PreferToBe {
+    fn run_once(&self, ctx: &LintContext) {
+        for possible_jest_node in &collect_possible_jest_call_node(ctx) {
+            run(possible_jest_node, ctx);
+        }
+    }
+}
+
+fn run<'a>(possible_jest_node: &PossibleJestNode<'a, '_>, ctx: &LintContext<'a>) {
+    let node = possible_jest_node.node;
+    let AstKind::CallExpression(call_expr) = node.kind() else { return };
+    let Some(jest_fn_call) = parse_expect_jest_fn_call(call_expr, possible_jest_node, ctx) else {
+        return;
+    };
+    let Some(matcher) = jest_fn_call.matcher() else { return };
+    let Some(matcher_name) = matcher.name() else { return };
+    let not_modifier =
+        jest_fn_call.modifiers().into_iter().find(|modifier| modifier.is_name_equal("not"));
+
+    if not_modifier.is_some() && matches!(matcher_name.as_ref(), "toBeUndefined" | "toBeDefined") {
+        let what_to_be = if matcher_name == "toBeDefined" { "Undefined" } else { "Defined" };
+        report(&jest_fn_call, matcher, what_to_be, not_modifier, call_expr.span, ctx);
+        return;
+    }
+
+    if !matches!(matcher_name.as_ref(), "toBe" | "toEqual" | "toStrictEqual") {
+        return;
+    }
+    let Some(Argument::Expression(first_argument)) = jest_fn_call.args.first() else {
+        return;
+    };
+
+    match first_argument.get_inner_expression() {
+        Expression::NullLiteral(_) => {
+            report(&jest_fn_call, matcher, "Null", None, call_expr.span, ctx);
+        }
+        Expression::Identifier(ident) if ident.name == "undefined" => {
+            let what_to_be = if not_modifier.is_some() { "Defined" } else { "Undefined" };
+            report(&jest_fn_call, matcher, what_to_be, not_modifier, call_expr.span, ctx);
+        }
+        Expression::Identifier(ident) if ident.name == "NaN" => {
+            report(&jest_fn_call, matcher, "NaN", None, call_expr.span, ctx);
+        }
+        expr if matcher_name != "toBe" && is_primitive_literal(expr) => {
+            report(&jest_fn_call, matcher, "", None, call_expr.span, ctx);
+        }
+        _ => {}
+    }
+}
+
+/// Regular expressions are literals too, but `toBe` compares them by reference.
+fn is_primitive_literal(expr: &Expression) -> bool {
+    let expr = match expr {
+        Expression::UnaryExpression(unary_expr)
+            if unary_expr.operator == UnaryOperator::UnaryNegation =>
+        {
+            &unary_expr.argument
+        }
+        _ => expr,
+    };
+    matches!(
+        expr,
+        Expression::StringLiteral(_)
+            | Expression::NumberLiteral(_)
+            | Expression::BigintLiteral(_)
+            | Expression::BooleanLiteral(_)
+            | Expression::TemplateLiteral(_)
+    )
+}
+
+/// Replaces the matcher with `toBe{what_to_be}`, removing `modifier` and, for the
+/// argument-less matchers, the arguments of the matcher call.
+fn report<'a>(
+    jest_fn_call: &ParsedExpectFnCall<'a>,
+    matcher: &KnownMemberExpressionProperty<'a>,
+    what_to_be: &str,
+    modifier: Option<&KnownMemberExpressionProperty<'a>>,
+    call_span: Span,
+    ctx: &LintContext<'a>,
+) {
+    let diagnostic = match what_to_be {
+        "Undefined" => PreferToBeDiagnostic::UseToBeUndefined(matcher.span),
+        "Defined" => PreferToBeDiagnostic::UseToBeDefined(matcher.span),
+        "Null" => PreferToBeDiagnostic::UseToBeNull(matcher.span),
+        "NaN" => PreferToBeDiagnostic::UseToBeNaN(matcher.span),
+        _ => PreferToBeDiagnostic::UseToBe(matcher.span),
+    };
+
+    ctx.diagnostic_with_fix(diagnostic, || {
+        let source_text = ctx.source_text();
+        let mut content = String::new();
+
+        // `.not` or `["not"]`, up to the matcher.
+        let start = modifier.map_or(matcher.span.start, |modifier| {
+            let end = if modifier.element.is_string_literal() {
+                modifier.span.end + 1
+            } else {
+                modifier.span.end
+            };
+            content.push_str(Span::new(end, matcher.span.start).source_text(source_text));
+            modifier.span.start - 1
+        });
+
+        let new_matcher = format!("toBe{what_to_be}");
+        if matcher.element.is_string_literal() {
+            // keep the quotes of `["toEqual"]`
+            let quote = &source_text[matcher.span.start as usize..=matcher.span.start as usize];
+            content.push_str(&format!("{quote}{new_matcher}{quote}"));
+        } else {
+            content.push_str(&new_matcher);
+        }
+
+        let end = match jest_fn_call.args.first() {
+            Some(first_argument) if !what_to_be.is_empty() => {
+                let arguments_start = first_argument.span().start;
+                content.push_str(
+                    Span::new(matcher.span.end, arguments_start).source_text(source_text),
+                );
+                content.push(')');
+                call_span.end
+            }
+            _ => matcher.span.end,
+        };
+
+        Fix::new(content, Span::new(start, end)).with_kind(FixKind::Safe)
+    });
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("expect(null).toBeNull();", None),
+        ("expect(null).not.toBeNull();", None),
+        ("expect(null).toBe(1);", None),
+        ("expect(null).toBe(-1);", None),
+        ("expect(null).toBe(...1);", None),
+        ("expect(obj).toStrictEqual([ x, 1 ]);", None),
+        ("expect(obj).toStrictEqual({ x: 1 });", None),
+        ("expect(obj).not.toStrictEqual({ x: 1 });", None),
+        ("expect(value).toMatchSnapshot();", None),
+        ("expect(catchError()).toStrictEqual({ message: 'oh noes!' })", None),
+        ("expect(\"something\");", None),
+        ("expect(token).toStrictEqual(/[abc]+/g);", None),
+        ("expect(token).toStrictEqual(new RegExp('[abc]+', 'g'));", None),
+        ("expect(value).toEqual(dedent`my string`);", None),
+        // undefined
+        ("expect(undefined).toBeUndefined();", None),
+        ("expect(true).toBeDefined();", None),
+        ("expect({}).toEqual({});", None),
+        ("expect(something).toBe()", None),
+        ("expect(something).toBe(somethingElse)", None),
+        ("expect(something).toEqual(somethingElse)", None),
+        ("expect(something).not.toBe(somethingElse)", None),
+        ("expect(something).not.toEqual(somethingElse)", None),
+        ("expect(undefined).toBe", None),
+        // null
+        ("expect(null).not.toEqual();", None),
+        ("expect(null).toBe();", None),
+        ("expect(null).toMatchSnapshot();", None),
+        ("expect(\"a string\").toMatchSnapshot(null);", None),
+        ("expect(\"a string\").not.toMatchSnapshot();", None),
+        ("expect(null).toBe", None),
+        // NaN
+        ("expect(NaN).toBeNaN();", None),
+        ("expect(true).not.toBeNaN();", None),
+    ];
+
+    let fail = vec![
+        ("expect(value).toEqual(\"my string\");", None),
+        ("expect(value).toStrictEqual(\"my string\");", None),
+        ("expect(value).toStrictEqual(1);", None),
+        ("expect(value).toStrictEqual(1,);", None),
+        ("expect(value).toStrictEqual(-1,);", None),
+        ("expect(value).toEqual(`my string`);", None),
+        ("expect(value)[\"toEqual\"](`my string`);", None),
+        ("expect(value).toStrictEqual(`my ${string}`);", None),
+        ("expect(loadMessage()).resolves.toStrictEqual(\"hello world\");", None),
+        ("expect(loadMessage()).resolves[\"toStrictEqual\"](\"hello world\");", None),
+        ("expect(loadMessage())[\"resolves\"].toStrictEqual(\"hello world\");", None),
+        ("expect(loadMessage()).resolves.toStrictEqual(false);", None),
+        // undefined
+        ("expect(undefined).toBe(undefined);", None),
+        ("expect(undefined).toEqual(undefined);", None),
+        ("expect(undefined).toStrictEqual(undefined);", None),
+        ("expect(\"a string\").not.toBe(undefined);", None),
+        ("expect(\"a string\").rejects.not.toBe(undefined);", None),
+        ("expect(\"a string\").rejects.not[\"toBe\"](undefined);", None),
+        ("expect(\"a string\").not.toEqual(undefined);", None),
+        ("expect(\"a string\").not.toStrictEqual(undefined);", None),
+        ("expect(\"a string\").not.toBeUndefined();", None),
+        ("expect(\"a string\")[\"not\"][\"toBeDefined\"]();", None),
+        // null
+        ("expect(null).toBe(null);", None),
+        ("expect(null).toEqual(null);", None),
+        ("expect(null).toEqual(null,);", None),
+        ("expect(null).toStrictEqual(null);", None),
+        ("expect(\"a string\").not.toBe(null);", None),
+        ("expect(\"a string\").not[\"toBe\"](null);", None),
+        ("expect(\"a string\")[\"not\"][\"toBe\"](null);", None),
+        ("expect(\"a string\").not.toEqual(null);", None),
+        ("expect(\"a string\").not.toStrictEqual(null);", None),
+        // NaN
+        ("expect(NaN).toBe(NaN);", None),
+        ("expect(NaN).toEqual(NaN);", None),
+        ("expect(NaN).toStrictEqual(NaN);", None),
+        ("expect(\"a string\").not.toBe(NaN);", None),
+        ("expect(\"a string\").rejects.not.toBe(NaN);", None),
+        ("expect(\"a string\")[\"rejects\"].not.toBe(NaN);", None),
+        ("expect(\"a string\").not.toEqual(NaN);", None),
+        ("expect(\"a string\").not.toStrictEqual(NaN);", None),
+        // typescript
+        ("expect(\"a string\").not.toStrictEqual(\"string\" as number);", None),
+        ("expect(undefined).toBe(undefined as unknown as string as any);", None),
+        ("expect(\"a string\").toEqual(undefined as number);", None),
+        ("expect(null).toEqual(null as unknown as string as unknown as any);", None),
+    ];
+
+    let fix = vec![
+        ("expect(value).toEqual(\"my string\");", "expect(value).toBe(\"my string\");", None),
+        ("expect(value).toStrictEqual(\"my string\");", "expect(value).toBe(\"my string\");", None),
+        ("expect(value).toStrictEqual(1);", "expect(value).toBe(1);", None),
+        ("expect(value).toStrictEqual(1,);", "expect(value).toBe(1,);", None),
+        ("expect(value).toStrictEqual(-1,);", "expect(value).toBe(-1,);", None),
+        ("expect(value).toEqual(`my string`);", "expect(value).toBe(`my string`);", None),
+        ("expect(value)[\"toEqual\"](`my string`);", "expect(value)[\"toBe\"](`my string`);", None),
+        (
+            "expect(value).toStrictEqual(`my ${string}`);",
+            "expect(value).toBe(`my ${string}`);",
+            None,
+        ),
+        (
+            "expect(loadMessage()).resolves.toStrictEqual(\"hello world\");",
+            "expect(loadMessage()).resolves.toBe(\"hello world\");",
+            None,
+        ),
+        (
+            "expect(loadMessage()).resolves[\"toStrictEqual\"](\"hello world\");",
+            "expect(loadMessage()).resolves[\"toBe\"](\"hello world\");",
+            None,
+        ),
+        (
+            "expect(loadMessage())[\"resolves\"].toStrictEqual(\"hello world\");",
+            "expect(loadMessage())[\"resolves\"].toBe(\"hello world\");",
+            None,
+        ),
+        (
+            "expect(loadMessage()).resolves.toStrictEqual(false);",
+            "expect(loadMessage()).resolves.toBe(false);",
+            None,
+        ),
+        // undefined
+        ("expect(undefined).toBe(undefined);", "expect(undefined).toBeUndefined();", None),
+        ("expect(undefined).toEqual(undefined);", "expect(undefined).toBeUndefined();", None),
+        ("expect(undefined).toStrictEqual(undefined);", "expect(undefined).toBeUndefined();", None),
+        ("expect(\"a string\").not.toBe(undefined);", "expect(\"a string\").toBeDefined();", None),
+        (
+            "expect(\"a string\").rejects.not.toBe(undefined);",
+            "expect(\"a string\").rejects.toBeDefined();",
+            None,
+        ),
+        (
+            "expect(\"a string\").rejects.not[\"toBe\"](undefined);",
+            "expect(\"a string\").rejects[\"toBeDefined\"]();",
+            None,
+        ),
+        (
+            "expect(\"a string\").not.toEqual(undefined);",
+            "expect(\"a string\").toBeDefined();",
+            None,
+        ),
+        (
+            "expect(\"a string\").not.toStrictEqual(undefined);",
+            "expect(\"a string\").toBeDefined();",
+            None,
+        ),
+        ("expect(\"a string\").not.toBeUndefined();", "expect(\"a string\").toBeDefined();", None),
+        (
+            "expect(\"a string\")[\"not\"][\"toBeDefined\"]();",
+            "expect(\"a string\")[\"toBeUndefined\"]();",
+            None,
+        ),
+        // null
+        ("expect(null).toBe(null);", "expect(null).toBeNull();", None),
+        ("expect(null).toEqual(null);", "expect(null).toBeNull();", None),
+        ("expect(null).toEqual(null,);", "expect(null).toBeNull();", None),
+        ("expect(null).toStrictEqual(null);", "expect(null).toBeNull();", None),
+        ("expect(\"a string\").not.toBe(null);", "expect(\"a string\").not.toBeNull();", None),
+        (
+            "expect(\"a string\").not[\"toBe\"](null);",
+            "expect(\"a string\").not[\"toBeNull\"]();",
+            None,
+        ),
+        (
+            "expect(\"a string\")[\"not\"][\"toBe\"](null);",
+            "expect(\"a string\")[\"not\"][\"toBeNull\"]();",
+            None,
+        ),
+        // NaN
+        ("expect(NaN).toBe(NaN);", "expect(NaN).toBeNaN();", None),
+        ("expect(NaN).toEqual(NaN);", "expect(NaN).toBeNaN();", None),
+        ("expect(\"a string\").not.toBe(NaN);", "expect(\"a string\").not.toBeNaN();", None),
+        (
+            "expect(\"a string\").rejects.not.toBe(NaN);",
+            "expect(\"a string\").rejects.not.toBeNaN();",
+            None,
+        ),
+        (
+            "expect(\"a string\")[\"rejects\"].not.toBe(NaN);",
+            "expect(\"a string\")[\"rejects\"].not.toBeNaN();",
+            None,
+        ),
+        // typescript
+        (
+            "expect(\"a string\").not.toStrictEqual(\"string\" as number);",
+            "expect(\"a string\").not.toBe(\"string\" as number);",
+            None,
+        ),
+        (
+            "expect(undefined).toBe(undefined as unknown as string as any);",
+            "expect(undefined).toBeUndefined();",
+            None,
+        ),
+        (
+            "expect(\"a string\").toEqual(undefined as number);",
+            "expect(\"a string\").toBeUndefined();",
+            None,
+        ),
+        (
+            "expect(null).toEqual(null as unknown as string as unknown as any);",
+            "expect(null).toBeNull();",
+            None,
+        ),
+    ];
+
+    Tester::new(PreferToBe::NAME, pass, fail)
+        .with_jest_plugin(true)
+        .expect_fix(fix)
+        .expect_fix_kind(FixKind::Safe)
+        .test_and_snapshot();
+}
@@ -0,0 +1,157 @@
+use oxc_ast::{
+    ast::{Argument, Expression},
+    AstKind,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+
+use crate::{
+    context::LintContext,
+    fixer::{Fix, FixKind},
+    rule::Rule,
+    utils::{collect_possible_jest_call_node, parse_expect_jest_fn_call, PossibleJestNode},
+};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint-plugin-jest(prefer-to-have-length): Suggest using `toHaveLength()`.")]
+#[diagnostic(severity(warning), help("Use `toHaveLength()` instead of asserting on `length`."))]
+struct PreferToHaveLengthDiagnostic(#[label] pub Span);
+
+#[derive(Debug, Default, Clone)]
+pub struct PreferToHaveLength;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// In order to have a better failure message, `toHaveLength()` should be used
+    /// upon asserting expectations on objects length property.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// This rule triggers a warning if `toBe()`, `toEqual()` or `toStrictEqual()` is
+    /// used to assert objects length property.
+    ///
+    /// ### Example
+    ///
+    /// ```javascript
+    /// // valid
+    /// expect(files).toHaveLength(1);
+    ///
+    /// // invalid
+    /// expect(files.length).toBe(1);
+    /// expect(files.length).toEqual(1);
+    /// expect(files.length).toStrictEqual(1);
+    /// ```
+    PreferToHaveLength,
+    nursery,
+    fix
+);
+
+impl Rule for PreferToHaveLength {
+    fn run_once(&self, ctx: &LintContext) {
+        for possible_jest_node in &collect_possible_jest_call_node(ctx) {
+            run(possible_jest_node, ctx);
+        }
+    }
+}
+
+fn run<'a>(possible_jest_node: &PossibleJestNode<'a, '_>, ctx: &LintContext<'a>) {
+    let node = possible_jest_node.node;
+    let AstKind::CallExpression(call_expr) = node.kind() else { return };
+    let Some(jest_fn_call) = parse_expect_jest_fn_call(call_expr, possible_jest_node, ctx) else {
+        return;
+    };
+    let Some(matcher) = jest_fn_call.matcher() else { return };
+    if !matcher
+        .name()
+        .is_some_and(|name| matches!(name.as_ref(), "toBe" | "toEqual" | "toStrictEqual"))
+    {
+        return;
+    }
+    let Some(Argument::Expression(Expression::MemberExpression(argument))) =
+        jest_fn_call.expect_argument()
+    else {
+        return;
+    };
+    if argument.static_property_name() != Some("length") {
+        return;
+    }
+    let (
+        Some(Expression::CallExpression(expect_call)),
+        Some(Expression::MemberExpression(matcher_member)),
+    ) = (jest_fn_call.head.parent, matcher.parent)
+    else {
+        return;
+    };
+
+    ctx.diagnostic_with_fix(PreferToHaveLengthDiagnostic(matcher.span), || {
+        // `expect(files.length).not.toBe(1)` -> `expect(files).not.toHaveLength(1)`,
+        // keeping the modifiers between the `expect` call and the matcher.
+        let modifiers = Span::new(expect_call.span.end, matcher_member.object().span().end);
+        let span = Span::new(argument.object().span().end, matcher_member.span().end);
+        let content = format!("){}.toHaveLength", modifiers.source_text(ctx.source_text()));
+        Fix::new(content, span).with_kind(FixKind::Safe)
+    });
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("expect.hasAssertions", None),
+        ("expect.hasAssertions()", None),
+        ("expect(files).toHaveLength(1);", None),
+        ("expect(files.name).toBe('file');", None),
+        ("expect(files[`name`]).toBe('file');", None),
+        ("expect(users[0]?.permissions?.length).toBe(1);", None),
+        ("expect(result).toBe(true);", None),
+        ("expect(user.getUserName(5)).resolves.toEqual('Paul')", None),
+        ("expect(user.getUserName(5)).rejects.toEqual('Paul')", None),
+        ("expect(a);", None),
+    ];
+
+    let fail = vec![
+        ("expect(files[\"length\"]).toBe(1);", None),
+        ("expect(files[\"length\"]).toBe(1,);", None),
+        ("expect(files[\"length\"])[\"not\"].toBe(1);", None),
+        ("expect(files[\"length\"])[\"toBe\"](1);", None),
+        ("expect(files[\"length\"]).not[\"toBe\"](1);", None),
+        ("expect(files[\"length\"])[\"not\"][\"toBe\"](1);", None),
+        ("expect(files.length).toBe(1);", None),
+        ("expect(files.length).toEqual(1);", None),
+        ("expect(files.length).toStrictEqual(1);", None),
+        ("expect(files.length).not.toStrictEqual(1);", None),
+    ];
+
+    let fix = vec![
+        ("expect(files[\"length\"]).toBe(1);", "expect(files).toHaveLength(1);", None),
+        ("expect(files[\"length\"]).toBe(1,);", "expect(files).toHaveLength(1,);", None),
+        (
+            "expect(files[\"length\"])[\"not\"].toBe(1);",
+            "expect(files)[\"not\"].toHaveLength(1);",
+            None,
+        ),
+        ("expect(files[\"length\"])[\"toBe\"](1);", "expect(files).toHaveLength(1);", None),
+        ("expect(files[\"length\"]).not[\"toBe\"](1);", "expect(files).not.toHaveLength(1);", None),
+        (
+            "expect(files[\"length\"])[\"not\"][\"toBe\"](1);",
+            "expect(files)[\"not\"].toHaveLength(1);",
+            None,
+        ),
+        ("expect(files.length).toBe(1);", "expect(files).toHaveLength(1);", None),
+        ("expect(files.length).toEqual(1);", "expect(files).toHaveLength(1);", None),
+        ("expect(files.length).toStrictEqual(1);", "expect(files).toHaveLength(1);", None),
+        ("expect(files.length).not.toStrictEqual(1);", "expect(files).not.toHaveLength(1);", None),
+    ];
+
+    Tester::new(PreferToHaveLength::NAME, pass, fail)
+        .with_jest_plugin(true)
+        .expect_fix(fix)
+        .expect_fix_kind(FixKind::Safe)
+        .test_and_snapshot();
+}
@@ -143,21 +143,21 @@ impl ValidExpect {
 
         if call_expr.arguments.len() < self.min_args {
             let error = Atom::from(format!(
-                "Expect takes at most {} argument{} ",
+                "Expect requires at least {} argument{}.",
                 self.min_args,
                 if self.min_args > 1 { "s" } else { "" }
             ));
-            let help = "Remove the extra arguments.";
+            let help = "Add the missing arguments.";
             ctx.diagnostic(ValidExpectDiagnostic(error, help, call_expr.span));
             return;
         }
         if call_expr.arguments.len() > self.max_args {
             let error = Atom::from(format!(
-                "Expect requires at least {} argument{} ",
+                "Expect takes at most {} argument{}.",
                 self.max_args,
                 if self.max_args > 1 { "s" } else { "" }
             ));
-            let help = "Add the missing arguments.";
+            let help = "Remove the extra arguments.";
             ctx.diagnostic(ValidExpectDiagnostic(error, help, call_expr.span));
             return;
         }
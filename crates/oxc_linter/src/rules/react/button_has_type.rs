@@ -15,7 +15,7 @@ use oxc_span::{GetSpan, Span};
 use crate::{
     context::LintContext,
     rule::Rule,
-    utils::{get_prop_value, has_jsx_prop_lowercase, is_create_element_call},
+    utils::{get_prop_value, has_jsx_prop_lowercase, is_react_create_element_call},
     AstNode,
 };
 
@@ -93,7 +93,7 @@ impl Rule for ButtonHasType {
                 );
             }
             AstKind::CallExpression(call_expr) => {
-                if is_create_element_call(call_expr) {
+                if is_react_create_element_call(call_expr, ctx) {
                     let Some(Argument::Expression(Expression::StringLiteral(str))) =
                         call_expr.arguments.first()
                     else {
@@ -266,6 +266,9 @@ fn test() {
 			      "#,
             None,
         ),
+        (r#"document.createElement("button")"#, None),
+        (r#"Foo.createElement("button")"#, None),
+        (r#"import { createElement } from "preact"; createElement("button")"#, None),
     ];
 
     let fail = vec![
@@ -309,7 +312,8 @@ fn test() {
             r#"React.createElement("button", {type: condition ? "reset" : "button"})"#,
             Some(serde_json::json!([{ "reset": false }])),
         ),
-        (r#"Foo.createElement("button")"#, None),
+        (r#"import { createElement } from "react"; createElement("button")"#, None),
+        (r#"import { createElement } from "react"; createElement("button", {type: "foo"})"#, None),
         (
             r"function Button({ type, ...extraProps }) { const button = type; return <button type={button} {...extraProps} />; }",
             None,
@@ -0,0 +1,485 @@
+use oxc_ast::{
+    ast::{
+        Argument, ArrayExpressionElement, BindingPatternKind, Expression, IdentifierReference,
+        MemberExpression, VariableDeclarationKind,
+    },
+    AstKind,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_semantic::{Reference, SymbolId};
+use oxc_span::{GetSpan, Span};
+
+use crate::{context::LintContext, rule::Rule, AstNode, Fix};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint-plugin-react-hooks(exhaustive-deps): React Hook {0} has {1}")]
+#[diagnostic(severity(warning), help("Update the dependencies array to be: [{2}]"))]
+struct ExhaustiveDepsDiagnostic(String, String, String, #[label] pub Span);
+
+#[derive(Debug, Default, Clone)]
+pub struct ExhaustiveDeps;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Verifies the dependency array of `useEffect`, `useLayoutEffect`, `useInsertionEffect`,
+    /// `useCallback`, `useMemo` and `useImperativeHandle` against the values used by the callback.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// A callback that uses props, state or other values declared in the component body without
+    /// listing them as dependencies sees stale values after they change. Dependencies that the
+    /// callback doesn't use, or that can't change between renders, re-run it for nothing.
+    ///
+    /// Setters from `useState` and `useReducer`, refs from `useRef` and primitive constants are
+    /// stable and don't need to be listed.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// // Bad
+    /// function Counter({ step }) {
+    ///   const [count, setCount] = useState(0);
+    ///   useEffect(() => {
+    ///     const id = setInterval(() => setCount(count + step), 1000);
+    ///     return () => clearInterval(id);
+    ///   }, []);
+    /// }
+    ///
+    /// // Good
+    /// function Counter({ step }) {
+    ///   const [count, setCount] = useState(0);
+    ///   useEffect(() => {
+    ///     const id = setInterval(() => setCount(count + step), 1000);
+    ///     return () => clearInterval(id);
+    ///   }, [count, step]);
+    /// }
+    /// ```
+    ExhaustiveDeps,
+    nursery
+);
+
+const NOT_VALID_DEPENDENCY: &str =
+    "aren't valid dependencies because mutating them doesn't re-render the component.";
+
+/// A value used by the callback, e.g. `props.foo` for `props.foo.bar()`
+struct Dependency {
+    path: String,
+    /// Known not to change between renders, e.g. the setter returned by `useState`
+    stable: bool,
+}
+
+impl Rule for ExhaustiveDeps {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::CallExpression(call) = node.kind() else { return };
+        let Some(hook) = hook_name(&call.callee) else { return };
+        let Some((callback_index, deps_index)) = hook_arguments(hook) else { return };
+        let Some(Argument::Expression(callback)) = call.arguments.get(callback_index) else {
+            return;
+        };
+        if !callback.without_parenthesized().is_function() {
+            return;
+        }
+        let Some(Argument::Expression(Expression::ArrayExpression(deps))) =
+            call.arguments.get(deps_index)
+        else {
+            return;
+        };
+
+        // Values declared in the component (or custom hook) calling the hook are reactive
+        let Some(component) =
+            ctx.nodes().iter_parents(node.id()).skip(1).find_map(|parent| match parent.kind() {
+                AstKind::Function(func) => Some(Component {
+                    span: func.span,
+                    id: func.id.as_ref().map(|id| id.span),
+                    callback: callback.span(),
+                }),
+                AstKind::ArrowExpression(arrow) => {
+                    Some(Component { span: arrow.span, id: None, callback: callback.span() })
+                }
+                _ => None,
+            })
+        else {
+            return;
+        };
+
+        let mut dependencies: Vec<Dependency> = vec![];
+        for reference in &ctx.symbols().references {
+            if !contains(component.callback, reference.span()) || !reference.is_read() {
+                continue;
+            }
+            let Some(symbol_id) = reference.symbol_id() else { continue };
+            if !component.is_reactive(symbol_id, ctx) {
+                continue;
+            }
+            let path = dependency_path(reference, ctx);
+            if dependencies.iter().all(|dependency| dependency.path != path) {
+                dependencies.push(Dependency { path, stable: is_stable_value(symbol_id, ctx) });
+            }
+        }
+
+        let is_effect = matches!(hook, "useEffect" | "useLayoutEffect" | "useInsertionEffect");
+        let mut declared: Vec<String> = vec![];
+        let mut suggestion: Vec<String> = vec![];
+        let mut unnecessary: Vec<String> = vec![];
+        let mut duplicate: Vec<String> = vec![];
+        let mut external: Vec<String> = vec![];
+        for element in &deps.elements {
+            let expr = match element {
+                ArrayExpressionElement::Expression(expr) => expr,
+                ArrayExpressionElement::SpreadElement(spread) => {
+                    suggestion.push(spread.span.source_text(ctx.source_text()).to_string());
+                    continue;
+                }
+                ArrayExpressionElement::Elision(_) => continue,
+            };
+            let Some((path, root)) = declared_dependency(expr) else {
+                suggestion.push(expr.span().source_text(ctx.source_text()).to_string());
+                continue;
+            };
+            if declared.contains(&path) {
+                duplicate.push(path);
+                continue;
+            }
+            declared.push(path.clone());
+
+            let is_used = dependencies.iter().any(|dependency| is_covered(&dependency.path, &path));
+            let is_reactive = root
+                .reference_id
+                .get()
+                .and_then(|reference_id| ctx.symbols().get_reference(reference_id).symbol_id())
+                .is_some_and(|symbol_id| component.is_reactive(symbol_id, ctx));
+            // Effects may list more reactive values than they use to re-run when those change
+            if !is_used && (!is_effect || !is_reactive || path.ends_with(".current")) {
+                if !is_reactive {
+                    external.push(path.clone());
+                }
+                unnecessary.push(path);
+                continue;
+            }
+            suggestion.push(path);
+        }
+
+        let missing = dependencies
+            .iter()
+            .filter(|dependency| {
+                !dependency.stable
+                    && !declared.iter().any(|path| is_covered(&dependency.path, path))
+                    // `props` is reported instead of `props.foo` when both are used
+                    && !dependencies.iter().any(|other| {
+                        !other.stable
+                            && other.path != dependency.path
+                            && is_covered(&dependency.path, &other.path)
+                    })
+            })
+            .map(|dependency| dependency.path.clone())
+            .collect::<Vec<_>>();
+
+        let Some(warning) = warning_message(&missing, "a", "missing", "include")
+            .or_else(|| warning_message(&unnecessary, "an", "unnecessary", "exclude"))
+            .or_else(|| warning_message(&duplicate, "a", "duplicate", "omit"))
+        else {
+            return;
+        };
+
+        let mutable = unnecessary.iter().find(|path| path.ends_with(".current"));
+        let extra_warning = match (mutable, external.first()) {
+            (Some(path), _) => format!(" Mutable values like '{path}' {NOT_VALID_DEPENDENCY}"),
+            (None, Some(path)) => {
+                format!(" Outer scope values like '{path}' {NOT_VALID_DEPENDENCY}")
+            }
+            (None, None) => String::new(),
+        };
+
+        let is_sorted = declared.windows(2).all(|paths| paths[0] <= paths[1]);
+        suggestion.extend(missing);
+        if is_sorted {
+            suggestion.sort();
+        }
+        let suggestion = suggestion.join(", ");
+
+        let hook_source = call.callee.span().source_text(ctx.source_text()).to_string();
+        let fix = Fix::new(format!("[{suggestion}]"), deps.span)
+            .with_message(format!("Update the dependencies array to be: [{suggestion}]"));
+        ctx.diagnostic_with_suggestions(
+            ExhaustiveDepsDiagnostic(
+                hook_source,
+                format!("{warning}{extra_warning}"),
+                suggestion,
+                deps.span,
+            ),
+            vec![fix],
+        );
+    }
+}
+
+/// The function calling the hook
+struct Component {
+    span: Span,
+    /// The name of a function declaration, which isn't declared in its body
+    id: Option<Span>,
+    callback: Span,
+}
+
+impl Component {
+    /// Declared in the component but outside of the callback, e.g. props, state or local values
+    fn is_reactive(&self, symbol_id: SymbolId, ctx: &LintContext) -> bool {
+        let span = ctx.symbols().get_span(symbol_id);
+        contains(self.span, span) && self.id != Some(span) && !contains(self.callback, span)
+    }
+}
+
+fn contains(outer: Span, inner: Span) -> bool {
+    outer.start <= inner.start && inner.end <= outer.end
+}
+
+/// `useEffect` or `React.useEffect`
+fn hook_name<'a>(callee: &'a Expression<'a>) -> Option<&'a str> {
+    match callee.without_parenthesized() {
+        Expression::Identifier(ident) => Some(ident.name.as_str()),
+        Expression::MemberExpression(member) if member.object().is_specific_id("React") => {
+            member.static_property_name()
+        }
+        _ => None,
+    }
+}
+
+/// The position of the callback and of the dependency array of the hook
+fn hook_arguments(hook: &str) -> Option<(usize, usize)> {
+    match hook {
+        "useEffect" | "useLayoutEffect" | "useInsertionEffect" | "useCallback" | "useMemo" => {
+            Some((0, 1))
+        }
+        "useImperativeHandle" => Some((1, 2)),
+        _ => None,
+    }
+}
+
+/// Whether listing `declared` covers `used`, e.g. `props` covers `props.foo`
+fn is_covered(used: &str, declared: &str) -> bool {
+    used.strip_prefix(declared).is_some_and(|rest| rest.is_empty() || rest.starts_with('.'))
+}
+
+/// The longest static member chain read from the reference, e.g. `props.foo` for
+/// `props.foo.bar`. Calls stop the chain as the method may use its object through `this`,
+/// and so does `.current` which is mutated without re-rendering.
+fn dependency_path(reference: &Reference, ctx: &LintContext) -> String {
+    let mut path = reference.name().to_string();
+    let mut node = ctx.nodes().get_node(reference.node_id());
+    if !matches!(node.kind(), AstKind::IdentifierReference(_)) {
+        return path;
+    }
+
+    while let Some(parent) = ctx.nodes().parent_node(node.id()) {
+        let AstKind::MemberExpression(MemberExpression::StaticMemberExpression(member)) =
+            parent.kind()
+        else {
+            break;
+        };
+        if member.optional
+            || member.object.span() != node.kind().span()
+            || member.property.name == "current"
+        {
+            break;
+        }
+        match ctx.nodes().parent_kind(parent.id()) {
+            Some(AstKind::CallExpression(call)) if call.callee.span() == member.span => break,
+            Some(AstKind::SimpleAssignmentTarget(_)) => break,
+            _ => {}
+        }
+        path.push('.');
+        path.push_str(&member.property.name);
+        node = parent;
+    }
+
+    path
+}
+
+/// The path of an element of the dependency array, and the identifier it starts with
+fn declared_dependency<'b>(expr: &'b Expression<'_>) -> Option<(String, &'b IdentifierReference)> {
+    match expr {
+        Expression::Identifier(ident) => Some((ident.name.to_string(), &**ident)),
+        Expression::MemberExpression(member) => match &**member {
+            MemberExpression::StaticMemberExpression(member) if !member.optional => {
+                let (object, root) = declared_dependency(&member.object)?;
+                Some((format!("{object}.{}", member.property.name), root))
+            }
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Setters of `useState` and `useReducer`, `startTransition` of `useTransition`, refs and
+/// primitive constants are the same on every render
+fn is_stable_value(symbol_id: SymbolId, ctx: &LintContext) -> bool {
+    let node = ctx.nodes().get_node(ctx.symbols().get_declaration(symbol_id));
+    let AstKind::VariableDeclarator(decl) = node.kind() else { return false };
+    let Some(init) = &decl.init else { return false };
+
+    match init.without_parenthesized() {
+        Expression::StringLiteral(_)
+        | Expression::NumberLiteral(_)
+        | Expression::NullLiteral(_) => decl.kind == VariableDeclarationKind::Const,
+        Expression::CallExpression(call) => match (hook_name(&call.callee), &decl.id.kind) {
+            (Some("useRef"), BindingPatternKind::BindingIdentifier(_)) => true,
+            (
+                Some("useState" | "useReducer" | "useTransition"),
+                BindingPatternKind::ArrayPattern(pattern),
+            ) => {
+                pattern.elements.len() == 2
+                    && matches!(
+                        &pattern.elements[1],
+                        Some(element) if matches!(&element.kind,
+                            BindingPatternKind::BindingIdentifier(ident)
+                                if ident.symbol_id.get() == Some(symbol_id))
+                    )
+            }
+            _ => false,
+        },
+        _ => false,
+    }
+}
+
+/// e.g. "missing dependencies: 'a' and 'b'. Either include them or remove the dependency array."
+fn warning_message(
+    paths: &[String],
+    single_prefix: &str,
+    label: &str,
+    fix_verb: &str,
+) -> Option<String> {
+    if paths.is_empty() {
+        return None;
+    }
+
+    let mut paths = paths.iter().map(|path| format!("'{path}'")).collect::<Vec<_>>();
+    paths.sort();
+    let list = match paths.as_slice() {
+        [single] => single.clone(),
+        [first, second] => format!("{first} and {second}"),
+        [rest @ .., last] => format!("{}, and {last}", rest.join(", ")),
+        [] => unreachable!(),
+    };
+
+    let (dependencies, pronoun) = if paths.len() == 1 {
+        (format!("{single_prefix} {label} dependency"), "it")
+    } else {
+        (format!("{label} dependencies"), "them")
+    };
+    Some(format!(
+        "{dependencies}: {list}. Either {fix_verb} {pronoun} or remove the dependency array."
+    ))
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "function MyComponent() { const local = {}; useEffect(() => { console.log(local); }); }",
+        "function MyComponent() { useEffect(() => { const local = {}; console.log(local); }, []); }",
+        "function MyComponent() { const local = someFunc(); useEffect(() => { console.log(local); }, [local]); }",
+        "function MyComponent() { useEffect(() => { console.log(props.foo); }, []); }",
+        "function MyComponent() { const local1 = {}; { const local2 = {}; useEffect(() => { console.log(local1); console.log(local2); }, [local1, local2]); } }",
+        "function MyComponent(props) { useEffect(() => { console.log(props.foo); }, [props.foo]); }",
+        "function MyComponent(props) { useEffect(() => { console.log(props.foo); console.log(props.bar); }, [props.bar, props.foo]); }",
+        "function MyComponent(props) { useEffect(() => { console.log(props.foo); }, [props]); }",
+        "function MyComponent(props) { const local = {}; useEffect(() => { console.log(props.foo); console.log(local); }, [props, local]); }",
+        "function MyComponent({ foo }) { useEffect(() => { console.log(foo.length); }, [foo]); }",
+        "function MyComponent({ foo }) { useEffect(() => { console.log(foo.slice(0)); }, [foo]); }",
+        "function MyComponent(props) { useEffect(() => { console.log(props.foo?.bar); }, [props.foo]); }",
+        "function MyComponent() { const [state, setState] = useState(); useEffect(() => { setState(state + 1); }, [state]); }",
+        "function MyComponent() { const [state, dispatch] = useReducer(); useEffect(() => { dispatch(1); }, []); }",
+        "function MyComponent() { const [isPending, startTransition] = useTransition(); useEffect(() => { startTransition(() => {}); }, []); }",
+        "function MyComponent() { const [state, setState] = React.useState(); useEffect(() => { setState(1); }, [setState]); }",
+        "function MyComponent() { const ref = useRef(); useEffect(() => { ref.current = 1; }, []); }",
+        "function MyComponent() { const ref = React.useRef(); useEffect(() => { console.log(ref.current); }, []); }",
+        "function MyComponent() { const ref = useRef(); useEffect(() => { console.log(ref.current); }, [ref]); }",
+        "const local = {}; function MyComponent() { useEffect(() => { console.log(local); }, []); }",
+        "function MyComponent() { const local = 42; useEffect(() => { console.log(local); }, []); }",
+        "function MyComponent(props) { const fn = useCallback(() => { console.log(props.foo); }, [props.foo]); }",
+        "function MyComponent(props) { const value = useMemo(() => props.foo * 2, [props.foo]); }",
+        "function MyComponent(props) { useEffect(() => { function handler() { console.log(props.foo); } handler(); }, [props.foo]); }",
+        "function MyComponent(props) { useEffect(() => { console.log(props.foo); }, [props.foo, props.bar]); }",
+        "function MyComponent(props, ref) { useImperativeHandle(ref, () => ({ focus: props.focus }), [props.focus]); }",
+        "function MyComponent({ history }) { useEffect(() => { return history.listen(); }, [history]); }",
+        "function MyComponent() { const [count, setCount] = useState(0); useEffect(() => { const id = setInterval(() => setCount((c) => c + 1), 1000); return () => clearInterval(id); }, []); }",
+        "function MyComponent() { let local = {}; useEffect(() => { local = {}; }, []); }",
+        "const MyComponent = (props) => { const value = useMemo(() => props.foo, [props.foo]); }",
+        "function useCustomHook(value) { useEffect(() => { console.log(value); }, [value]); }",
+        "function MyComponent({ Child }) { const element = useMemo(() => <Child />, [Child]); }",
+        "function MyComponent(props) { useEffect(() => { console.log(props.foo); }, [props.foo, ...rest]); }",
+    ];
+
+    let fail = vec![
+        "function MyComponent() { const local = {}; useEffect(() => { console.log(local); }, []); }",
+        "function MyComponent(props) { useEffect(() => { console.log(props.foo); }, []); }",
+        "function MyComponent(props) { useEffect(() => { console.log(props.foo); console.log(props.bar); }, [props.foo]); }",
+        "function MyComponent() { const local = {}; const fn = useCallback(() => {}, [local]); }",
+        "function MyComponent() { const local = {}; useEffect(() => { console.log(local); }, [local, local]); }",
+        "const local = {}; function MyComponent() { useEffect(() => { console.log(local); }, [local]); }",
+        "function MyComponent() { const ref = useRef(); useEffect(() => { console.log(ref.current); }, [ref.current]); }",
+        "function MyComponent(props) { const value = useMemo(() => props.foo * 2, [props.foo, props.bar]); }",
+        "function MyComponent({ foo, bar }) { useEffect(() => { console.log(foo, bar); }, []); }",
+        "function MyComponent() { const [state, setState] = useState(); useEffect(() => { setState(state + 1); }, []); }",
+        "function MyComponent({ a, b, c }) { useEffect(() => { console.log(a, b, c); }, [c]); }",
+        "function MyComponent({ a, b, c, d }) { useEffect(() => { console.log(a, b, c, d); }, []); }",
+        "function MyComponent(props) { useEffect(() => { props.onChange(); }, []); }",
+        "function MyComponent(props) { const local = {}; useEffect(() => { console.log(local); }, [props.foo]); }",
+        "function MyComponent({ a, b, c }) { useEffect(() => { console.log(a, b, c); }, [c, a]); }",
+        "function MyComponent(props) { React.useEffect(() => { console.log(props.foo); }, []); }",
+        "function MyComponent(props, ref) { useImperativeHandle(ref, () => ({ focus: props.focus }), []); }",
+        "const MyComponent = () => { const local = {}; const value = useMemo(() => local, []); }",
+        "function MyComponent(props) { useEffect(() => { function handler() { console.log(props.foo); } handler(); }, []); }",
+        "function MyComponent({ Child }) { const element = useMemo(() => <Child />, []); }",
+        "function MyComponent(props) { useEffect(() => { console.log(props.foo, props.foo.bar); }, []); }",
+        "function MyComponent(props) { const fn = useCallback(() => { console.log(props.foo); }, [props.foo.bar]); }",
+        "function MyComponent() { let local = 42; useEffect(() => { console.log(local); }, []); }",
+        "function useCustomHook(value) { useLayoutEffect(() => { console.log(value); }, []); }",
+    ];
+
+    let suggestions = vec![
+        (
+            "function MyComponent() { const local = {}; useEffect(() => { console.log(local); }, []); }",
+            vec!["function MyComponent() { const local = {}; useEffect(() => { console.log(local); }, [local]); }"],
+            None,
+        ),
+        (
+            "function MyComponent(props) { useEffect(() => { console.log(props.foo); console.log(props.bar); }, [props.foo]); }",
+            vec!["function MyComponent(props) { useEffect(() => { console.log(props.foo); console.log(props.bar); }, [props.bar, props.foo]); }"],
+            None,
+        ),
+        (
+            "function MyComponent() { const local = {}; useEffect(() => { console.log(local); }, [local, local]); }",
+            vec!["function MyComponent() { const local = {}; useEffect(() => { console.log(local); }, [local]); }"],
+            None,
+        ),
+        (
+            "const local = {}; function MyComponent() { useEffect(() => { console.log(local); }, [local]); }",
+            vec!["const local = {}; function MyComponent() { useEffect(() => { console.log(local); }, []); }"],
+            None,
+        ),
+        (
+            "function MyComponent() { const ref = useRef(); useEffect(() => { console.log(ref.current); }, [ref.current]); }",
+            vec!["function MyComponent() { const ref = useRef(); useEffect(() => { console.log(ref.current); }, []); }"],
+            None,
+        ),
+        (
+            "function MyComponent({ a, b, c }) { useEffect(() => { console.log(a, b, c); }, [c, a]); }",
+            vec!["function MyComponent({ a, b, c }) { useEffect(() => { console.log(a, b, c); }, [c, a, b]); }"],
+            None,
+        ),
+        (
+            "function MyComponent(props) { useEffect(() => { props.onChange(); }, []); }",
+            vec!["function MyComponent(props) { useEffect(() => { props.onChange(); }, [props]); }"],
+            None,
+        ),
+    ];
+
+    Tester::new_without_config(ExhaustiveDeps::NAME, pass, fail)
+        .expect_suggestions(suggestions)
+        .test_and_snapshot();
+}
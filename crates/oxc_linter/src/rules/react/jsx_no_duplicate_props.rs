@@ -23,7 +23,10 @@ use crate::{context::LintContext, rule::Rule, AstNode};
 struct JsxNoDuplicatePropsDiagnostic(Atom, #[label] pub Span, #[label] pub Span);
 
 #[derive(Debug, Default, Clone)]
-pub struct JsxNoDuplicateProps;
+pub struct JsxNoDuplicateProps {
+    /// Treat props differing only in case, e.g. `onclick` and `onClick`, as duplicates.
+    ignore_case: bool,
+}
 
 declare_oxc_lint!(
     /// ### What it does
@@ -46,11 +49,26 @@ declare_oxc_lint!(
     /// <App bar baz foo={3} />;
     ///
     /// ```
+    ///
+    /// ### Options
+    ///
+    /// `ignoreCase` (default `false`): when enabled, props whose names differ only in case are
+    /// considered duplicates, e.g. `<App onclick onClick />`.
     JsxNoDuplicateProps,
     correctness
 );
 
 impl Rule for JsxNoDuplicateProps {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let ignore_case = value
+            .get(0)
+            .and_then(|config| config.get("ignoreCase"))
+            .and_then(serde_json::Value::as_bool)
+            .unwrap_or(false);
+
+        Self { ignore_case }
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::JSXOpeningElement(jsx_opening_elem) = node.kind() else { return };
 
@@ -59,14 +77,18 @@ impl Rule for JsxNoDuplicateProps {
         for attr in &jsx_opening_elem.attributes {
             let JSXAttributeItem::Attribute(jsx_attr) = attr else { continue };
 
-            let JSXAttributeName::Identifier(ident) = &jsx_attr.name else { continue };
+            let (name, span) = match &jsx_attr.name {
+                JSXAttributeName::Identifier(ident) => (ident.name.clone(), ident.span),
+                // `xlink:href` is a different prop from `href`, so the namespace is kept in the key
+                JSXAttributeName::NamespacedName(namespaced) => {
+                    (Atom::from(namespaced.to_string()), namespaced.span)
+                }
+            };
+
+            let key = if self.ignore_case { Atom::from(name.to_lowercase()) } else { name.clone() };
 
-            if let Some(old_span) = props.insert(ident.name.clone(), ident.span) {
-                ctx.diagnostic(JsxNoDuplicatePropsDiagnostic(
-                    ident.name.clone(),
-                    old_span,
-                    ident.span,
-                ));
+            if let Some(old_span) = props.insert(key, span) {
+                ctx.diagnostic(JsxNoDuplicatePropsDiagnostic(name, old_span, span));
             }
         }
     }
@@ -89,6 +111,10 @@ fn test() {
         ("<App A a />;", None),
         ("<App A b a />;", None),
         (r#"<App A="a" b="b" B="B" />;"#, None),
+        (r#"<App a:b="c" />;"#, Some(serde_json::json!([{ "ignoreCase": true }]))),
+        (r#"<App xlink:href="a" href="b" />;"#, None),
+        (r#"<App xlink:href="a" xml:href="b" />;"#, None),
+        ("<App onclick onClick />;", Some(serde_json::json!([{ "ignoreCase": false }]))),
     ];
 
     let fail = vec![
@@ -111,6 +137,15 @@ fn test() {
         "#,
             None,
         ),
+        ("<App A a />;", Some(serde_json::json!([{ "ignoreCase": true }]))),
+        ("<App a b c A />;", Some(serde_json::json!([{ "ignoreCase": true }]))),
+        (r#"<App A="a" b="b" B="B" />;"#, Some(serde_json::json!([{ "ignoreCase": true }]))),
+        ("<App onclick onClick />;", Some(serde_json::json!([{ "ignoreCase": true }]))),
+        (r#"<App xlink:href="a" xlink:href="b" />;"#, None),
+        (
+            r#"<App xlink:href="a" XLink:Href="b" />;"#,
+            Some(serde_json::json!([{ "ignoreCase": true }])),
+        ),
     ];
 
     Tester::new(JsxNoDuplicateProps::NAME, pass, fail).test_and_snapshot();
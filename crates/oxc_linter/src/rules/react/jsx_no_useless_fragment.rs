@@ -13,7 +13,7 @@ use oxc_macros::declare_oxc_lint;
 use oxc_semantic::AstNodeId;
 use oxc_span::Span;
 
-use crate::{context::LintContext, rule::Rule, AstNode};
+use crate::{context::LintContext, rule::Rule, AstNode, Fix};
 
 #[derive(Debug, Error, Diagnostic)]
 enum JsxNoUselessFragmentDiagnostic {
@@ -51,7 +51,8 @@ declare_oxc_lint!(
     /// <div>foo</div>
     /// ```
     JsxNoUselessFragment,
-    correctness
+    correctness,
+    fix
 );
 
 impl Rule for JsxNoUselessFragment {
@@ -79,40 +80,137 @@ impl Rule for JsxNoUselessFragment {
 }
 
 impl JsxNoUselessFragment {
-    fn check_element(&self, node: &AstNode, elem: &JSXElement, ctx: &LintContext) {
+    fn check_element<'a>(&self, node: &AstNode<'a>, elem: &JSXElement<'a>, ctx: &LintContext<'a>) {
         if jsx_elem_has_key_attr(elem) {
             return;
         }
 
+        // `<React.Fragment foo={bar}>` can't be unwrapped without dropping the attributes
+        let can_fix =
+            elem.opening_element.attributes.is_empty() && can_fix(node, &elem.children, ctx);
+        let fix = || {
+            let closing_start = elem.closing_element.as_ref().map(|closing| closing.span.start);
+            unwrap_fragment(elem.span, elem.opening_element.span.end, closing_start, ctx)
+        };
+
         if has_less_than_two_children(&elem.children)
             && !is_fragment_with_only_text_and_is_not_child(node.id(), &elem.children, ctx)
             && !(self.allow_expressions && is_fragment_with_single_expression(&elem.children))
         {
             let span = elem.opening_element.span;
-            ctx.diagnostic(JsxNoUselessFragmentDiagnostic::NeedsMoreChildren(span));
+            report(ctx, JsxNoUselessFragmentDiagnostic::NeedsMoreChildren(span), can_fix, fix);
         }
 
         if is_child_of_html_element(node, ctx) {
             let span = elem.opening_element.span;
-            ctx.diagnostic(JsxNoUselessFragmentDiagnostic::ChildOfHtmlElement(span));
+            report(ctx, JsxNoUselessFragmentDiagnostic::ChildOfHtmlElement(span), can_fix, fix);
         }
     }
-    fn check_fragment(&self, node: &AstNode, elem: &JSXFragment, ctx: &LintContext) {
+    fn check_fragment<'a>(
+        &self,
+        node: &AstNode<'a>,
+        elem: &JSXFragment<'a>,
+        ctx: &LintContext<'a>,
+    ) {
+        let can_fix = can_fix(node, &elem.children, ctx);
+        let fix = || {
+            let closing_start = Some(elem.closing_fragment.span.start);
+            unwrap_fragment(elem.span, elem.opening_fragment.span.end, closing_start, ctx)
+        };
+
         if has_less_than_two_children(&elem.children)
             && !is_fragment_with_only_text_and_is_not_child(node.id(), &elem.children, ctx)
             && !(self.allow_expressions && is_fragment_with_single_expression(&elem.children))
         {
             let span = elem.opening_fragment.span;
-            ctx.diagnostic(JsxNoUselessFragmentDiagnostic::NeedsMoreChildren(span));
+            report(ctx, JsxNoUselessFragmentDiagnostic::NeedsMoreChildren(span), can_fix, fix);
         }
 
         if is_child_of_html_element(node, ctx) {
             let span = elem.opening_fragment.span;
-            ctx.diagnostic(JsxNoUselessFragmentDiagnostic::ChildOfHtmlElement(span));
+            report(ctx, JsxNoUselessFragmentDiagnostic::ChildOfHtmlElement(span), can_fix, fix);
         }
     }
 }
 
+fn report<'a>(
+    ctx: &LintContext<'a>,
+    diagnostic: JsxNoUselessFragmentDiagnostic,
+    can_fix: bool,
+    fix: impl FnOnce() -> Fix<'a>,
+) {
+    if can_fix {
+        ctx.diagnostic_with_fix(diagnostic, fix);
+    } else {
+        ctx.diagnostic(diagnostic);
+    }
+}
+
+/// Whether the fragment can be replaced by its children without changing what is rendered
+fn can_fix<'a>(
+    node: &AstNode<'a>,
+    children: &oxc_allocator::Vec<'a, JSXChild<'a>>,
+    ctx: &LintContext<'a>,
+) -> bool {
+    match ctx.nodes().parent_kind(node.id()) {
+        // `<Eeee><>foo</></Eeee>`, `Eeee` might require its children to be a single element
+        Some(AstKind::JSXElement(parent)) => {
+            if is_user_defined_element(&parent.opening_element.name) {
+                return false;
+            }
+        }
+        Some(AstKind::JSXFragment(_)) => {}
+        // Outside of JSX the children have to stay a single element,
+        // `const a = <></>` or `const a = <>cat {meow}</>` can't be unwrapped
+        _ => {
+            if children.is_empty() {
+                return false;
+            }
+            let has_text_or_expression = children.iter().any(|child| match child {
+                JSXChild::Text(text) => !text.value.trim().is_empty(),
+                JSXChild::ExpressionContainer(_) => true,
+                _ => false,
+            });
+            if has_text_or_expression {
+                return false;
+            }
+        }
+    }
+
+    // `<div><> foo </></div>`, the whitespace around a lone string is rendered
+    if let [JSXChild::Text(text)] = children.as_slice() {
+        let value = text.value.as_str();
+        let leading = &value[..value.len() - value.trim_start().len()];
+        let trailing = &value[value.trim_end().len()..];
+        let is_significant = |spaces: &str| !spaces.is_empty() && !spaces.contains('\n');
+        if !value.trim().is_empty() && (is_significant(leading) || is_significant(trailing)) {
+            return false;
+        }
+    }
+
+    true
+}
+
+/// Replace the fragment with the source text of its children, dropping the whitespace that
+/// React trims, i.e. leading and trailing whitespace containing a line break.
+fn unwrap_fragment<'a>(
+    span: Span,
+    opening_end: u32,
+    closing_start: Option<u32>,
+    ctx: &LintContext<'a>,
+) -> Fix<'a> {
+    let Some(closing_start) = closing_start else { return Fix::delete(span) };
+
+    let text = Span::new(opening_end, closing_start).source_text(ctx.source_text());
+    let trimmed_start = text.trim_start();
+    let text =
+        if text[..text.len() - trimmed_start.len()].contains('\n') { trimmed_start } else { text };
+    let trimmed_end = text.trim_end();
+    let text = if text[trimmed_end.len()..].contains('\n') { trimmed_end } else { text };
+
+    Fix::new(text, span)
+}
+
 fn jsx_elem_has_key_attr(elem: &JSXElement) -> bool {
     elem.opening_element.attributes.iter().any(|attr| {
         let JSXAttributeItem::Attribute(attr) = attr else { return false };
@@ -147,6 +245,12 @@ fn is_child_of_html_element(node: &AstNode, ctx: &LintContext) -> bool {
     false
 }
 
+fn is_user_defined_element(elem_name: &JSXElementName) -> bool {
+    let JSXElementName::Identifier(ident) = elem_name else { return false };
+
+    ident.name.starts_with(char::is_uppercase)
+}
+
 fn is_html_element(elem_name: &JSXElementName) -> bool {
     let JSXElementName::Identifier(ident) = elem_name else { return false };
 
@@ -297,7 +401,103 @@ fn test() {
             None,
         ),
         (r"<><Foo>{moo}</Foo></>", None),
+        (r"<div><> foo </></div>", None),
+        (r"<div><Fragment foo={bar}><Foo /></Fragment></div>", None),
+    ];
+
+    let fix = vec![
+        (r"<></>", r"<></>", None),
+        (r"<>{meow}</>", r"<>{meow}</>", None),
+        (r"<Fragment />", r"<Fragment />", None),
+        (r"<p>moo<>foo</></p>", r"<p>moofoo</p>", None),
+        (r"<p><>{meow}</></p>", r"<p>{meow}</p>", None),
+        (r"<><div/></>", r"<div/>", None),
+        (
+            r"
+            <>
+              <div/>
+            </>
+        ",
+            r"
+            <div/>
+        ",
+            None,
+        ),
+        (
+            r"
+                <React.Fragment>
+                  <Foo />
+                </React.Fragment>
+            ",
+            r"
+                <Foo />
+            ",
+            None,
+        ),
+        (r"<Eeee><>foo</></Eeee>", r"<Eeee><>foo</></Eeee>", None),
+        (r"<div><>foo</></div>", r"<div>foo</div>", None),
+        (r#"<div><>{"a"}{"b"}</></div>"#, r#"<div>{"a"}{"b"}</div>"#, None),
+        (
+            r#"
+            <section>
+              <Eeee />
+              <Eeee />
+              <>{"a"}{"b"}</>
+            </section>"#,
+            r#"
+            <section>
+              <Eeee />
+              <Eeee />
+              {"a"}{"b"}
+            </section>"#,
+            None,
+        ),
+        (r#"<div><Fragment>{"a"}{"b"}</Fragment></div>"#, r#"<div>{"a"}{"b"}</div>"#, None),
+        (
+            r"
+            <section>
+              git<>
+                <b>hub</b>.
+              </>
+
+              git<> <b>hub</b></>
+            </section>
+            ",
+            r"
+            <section>
+              git<b>hub</b>.
+
+              git <b>hub</b>
+            </section>
+            ",
+            None,
+        ),
+        (r#"<div>a <>{""}{""}</> a</div>"#, r#"<div>a {""}{""} a</div>"#, None),
+        (
+            r"
+            const Comp = () => (
+              <html>
+                <React.Fragment />
+              </html>
+            );
+        ",
+            r"
+            const Comp = () => (
+              <html>
+                
+              </html>
+            );
+        ",
+            None,
+        ),
+        (r"<><Foo>{moo}</Foo></>", r"<Foo>{moo}</Foo>", None),
+        (r"<div><> foo </></div>", r"<div><> foo </></div>", None),
+        (
+            r"<div><Fragment foo={bar}><Foo /></Fragment></div>",
+            r"<div><Fragment foo={bar}><Foo /></Fragment></div>",
+            None,
+        ),
     ];
 
-    Tester::new(JsxNoUselessFragment::NAME, pass, fail).test_and_snapshot();
+    Tester::new(JsxNoUselessFragment::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
 }
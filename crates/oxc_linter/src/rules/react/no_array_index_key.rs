@@ -0,0 +1,288 @@
+use oxc_ast::{
+    ast::{
+        Argument, BindingPatternKind, CallExpression, Expression, JSXAttributeItem,
+        JSXAttributeName, JSXAttributeValue, JSXExpression, ObjectPropertyKind,
+    },
+    AstKind,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+
+use crate::{ast_util::get_declaration_of_variable, context::LintContext, rule::Rule, AstNode};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint-plugin-react(no-array-index-key): Do not use Array index in keys")]
+#[diagnostic(
+    severity(warning),
+    help("Use a stable identifier of the item instead, the index changes when the items are reordered")
+)]
+struct NoArrayIndexKeyDiagnostic(#[label] pub Span);
+
+#[derive(Debug, Default, Clone)]
+pub struct NoArrayIndexKey;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Prevent usage of Array index in keys
+    ///
+    /// ### Why is this bad?
+    ///
+    /// React uses the keys to decide which elements were changed, added or removed. The index of
+    /// an item changes when the items are reordered, inserted or removed, which leads to
+    /// needless re-renders and to issues with the state of the components.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// // Bad
+    /// things.map((thing, index) => <Hello key={index} />);
+    /// things.map((thing, index) => React.cloneElement(thing, { key: `thing-${index}` }));
+    ///
+    /// // Good
+    /// things.map((thing) => <Hello key={thing.id} />);
+    /// ```
+    NoArrayIndexKey,
+    nursery
+);
+
+/// The methods taking a callback, with the position of the index parameter of the callback
+const ITERATOR_METHODS: [(&str, usize); 12] = [
+    ("every", 1),
+    ("filter", 1),
+    ("find", 1),
+    ("findIndex", 1),
+    ("findLast", 1),
+    ("findLastIndex", 1),
+    ("flatMap", 1),
+    ("forEach", 1),
+    ("map", 1),
+    ("reduce", 2),
+    ("reduceRight", 2),
+    ("some", 1),
+];
+
+impl Rule for NoArrayIndexKey {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        match node.kind() {
+            // `<Foo key={index} />`
+            AstKind::JSXAttributeItem(JSXAttributeItem::Attribute(attr)) => {
+                let JSXAttributeName::Identifier(name) = &attr.name else { return };
+                if name.name != "key" {
+                    return;
+                }
+                let Some(JSXAttributeValue::ExpressionContainer(container)) = &attr.value else {
+                    return;
+                };
+                if let JSXExpression::Expression(expr) = &container.expression {
+                    check_key_value(expr, ctx);
+                }
+            }
+            // `React.cloneElement(child, { key: index })`
+            AstKind::CallExpression(call) => {
+                let Some(member) = call.callee.get_member_expr() else { return };
+                if !matches!(member.static_property_name(), Some("createElement" | "cloneElement"))
+                {
+                    return;
+                }
+                let Some(Argument::Expression(Expression::ObjectExpression(props))) =
+                    call.arguments.get(1)
+                else {
+                    return;
+                };
+                for prop in &props.properties {
+                    let ObjectPropertyKind::ObjectProperty(prop) = prop else { continue };
+                    if prop.key.is_specific_static_name("key") {
+                        check_key_value(&prop.value, ctx);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+fn check_key_value(value: &Expression, ctx: &LintContext) {
+    match value.without_parenthesized() {
+        // `key={index}`
+        expr @ Expression::Identifier(_) => {
+            if is_array_index(expr, ctx) {
+                ctx.diagnostic(NoArrayIndexKeyDiagnostic(expr.span()));
+            }
+        }
+        // `key={`foo-${index}`}`
+        Expression::TemplateLiteral(lit) => {
+            for _ in lit.expressions.iter().filter(|expr| is_array_index(expr, ctx)) {
+                ctx.diagnostic(NoArrayIndexKeyDiagnostic(lit.span));
+            }
+        }
+        // `key={'foo-' + index + '-bar'}`
+        expr @ Expression::BinaryExpression(_) => {
+            let mut operands = vec![];
+            collect_binary_operands(expr, &mut operands);
+            for _ in operands.into_iter().filter(|expr| is_array_index(expr, ctx)) {
+                ctx.diagnostic(NoArrayIndexKeyDiagnostic(expr.span()));
+            }
+        }
+        Expression::CallExpression(call) => {
+            // `key={index.toString()}`
+            let is_index_to_string = call.callee.get_member_expr().is_some_and(|member| {
+                member.static_property_name() == Some("toString")
+                    && is_array_index(member.object(), ctx)
+            });
+            if is_index_to_string {
+                ctx.diagnostic(NoArrayIndexKeyDiagnostic(call.span));
+            }
+            // `key={String(index)}`
+            if call.callee.is_specific_id("String") {
+                if let Some(Argument::Expression(arg)) = call.arguments.first() {
+                    if is_array_index(arg, ctx) {
+                        ctx.diagnostic(NoArrayIndexKeyDiagnostic(arg.span()));
+                    }
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+/// The operands of nested binary expressions, e.g. `a`, `b` and `c` of `a + b + c`
+fn collect_binary_operands<'a, 'b>(
+    expr: &'b Expression<'a>,
+    operands: &mut Vec<&'b Expression<'a>>,
+) {
+    if let Expression::BinaryExpression(expr) = expr {
+        collect_binary_operands(&expr.left, operands);
+        collect_binary_operands(&expr.right, operands);
+    } else {
+        operands.push(expr);
+    }
+}
+
+/// Whether `expr` refers to the index parameter of the callback of an iterator method, e.g.
+/// `index` of `things.map((thing, index) => ...)`
+fn is_array_index(expr: &Expression, ctx: &LintContext) -> bool {
+    let Expression::Identifier(ident) = expr else { return false };
+    let Some(decl) = get_declaration_of_variable(ident, ctx) else { return false };
+    let AstKind::FormalParameters(params) = decl.kind() else { return false };
+    let Some(position) = params.items.iter().position(|param| match &param.pattern.kind {
+        BindingPatternKind::BindingIdentifier(id) => id.name == ident.name,
+        _ => false,
+    }) else {
+        return false;
+    };
+
+    let nodes = ctx.nodes();
+    let Some(callback) = nodes.parent_node(decl.id()) else { return false };
+    if !matches!(callback.kind(), AstKind::Function(_) | AstKind::ArrowExpression(_)) {
+        return false;
+    }
+    let Some(argument) = nodes.parent_node(callback.id()) else { return false };
+    let (AstKind::Argument(argument), Some(AstKind::CallExpression(call))) =
+        (argument.kind(), nodes.parent_kind(argument.id()))
+    else {
+        return false;
+    };
+    index_param_position(call, argument.span()) == Some(position)
+}
+
+/// The position of the index parameter of the callback at `callback_span` of `call`
+fn index_param_position(call: &CallExpression, callback_span: Span) -> Option<usize> {
+    let member = call.callee.get_member_expr()?;
+    let method = member.static_property_name()?;
+    let (_, position) = ITERATOR_METHODS.iter().find(|(name, _)| *name == method)?;
+    // `Children.map(children, (child, index) => ...)`
+    let callback_index = usize::from(is_react_children_call(member.object(), method));
+    let callback = call.arguments.get(callback_index)?;
+    (callback.span() == callback_span).then_some(*position)
+}
+
+/// `Children.map`, `Children.forEach` and the same methods of `React.Children`
+fn is_react_children_call(object: &Expression, method: &str) -> bool {
+    if !matches!(method, "map" | "forEach") {
+        return false;
+    }
+    match object {
+        Expression::Identifier(ident) => ident.name == "Children",
+        Expression::MemberExpression(member) => {
+            member.object().is_specific_id("React")
+                && member.static_property_name() == Some("Children")
+        }
+        _ => false,
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        r#"<Foo key="foo" />"#,
+        "<Foo key={i} />",
+        "<Foo key />",
+        "<Foo key={`foo-${i}`} />",
+        "<Foo key={'foo-' + i} />",
+        "foo.bar((baz, i) => <Foo key={i} />)",
+        "foo.bar((bar, i) => <Foo key={`foo-${i}`} />)",
+        "foo.bar((bar, i) => <Foo key={'foo-' + i} />)",
+        "foo.map((baz) => <Foo key={baz.id} />)",
+        "foo.map((baz, i) => <Foo key={baz.id} />)",
+        "foo.map((baz, i) => <Foo key={'foo' + baz.id} />)",
+        "foo.map((baz, i) => React.cloneElement(someChild, { ...someChild.props }))",
+        "foo.map((item, i) => React.cloneElement(someChild, { key: item.id }))",
+        "foo.map((baz, i) => <Foo key />)",
+        "foo.reduce((a, b) => a.concat(<Foo key={b.id} />), [])",
+        "foo.reduce((a, b, i) => a.concat(<Foo key={b.id} />), [])",
+        "foo.reduceRight((a, b) => a.concat(<Foo key={b.id} />), [])",
+        "foo.reduceRight((a, b, i) => a.concat(<Foo key={b.id} />), [])",
+        "React.Children.map(this.props.children, (child, index, arr) => { return React.cloneElement(child, { key: child.id }); })",
+        "Children.forEach(this.props.children, (child, index, arr) => { return React.cloneElement(child, { key: child.id }); })",
+        "foo?.map(child => <Foo key={child.i} />)",
+        // Not the index parameter of the callback
+        "foo.map((i) => <Foo key={i} />)",
+        "foo.reduce((a, i) => a.concat(<Foo key={i} />), [])",
+        "foo.map(bar, (baz, i) => <Foo key={i} />)",
+        "Children.map((child, index) => <Foo key={index} />)",
+        "foo.map((bar, i) => bar.items.map((i) => <Foo key={i} />))",
+        "function Foo(i) { return foo.map((bar) => <Foo key={i} />); }",
+        "foo.map((bar, i) => { const index = bar.id; return <Foo key={index} />; })",
+    ];
+
+    let fail = vec![
+        "foo.map((bar, i) => <Foo key={i} />)",
+        "[{}, {}].map((bar, i) => <Foo key={i} />)",
+        "foo.map((bar, anything) => <Foo key={anything} />)",
+        "foo.map((bar, i) => <Foo key={`foo-${i}`} />)",
+        "foo.map((bar, i) => <Foo key={'foo-' + i} />)",
+        "foo.map((bar, i) => <Foo key={'foo-' + i + '-bar'} />)",
+        "foo.map((bar, i) => <Foo key={bar.id + i} />)",
+        "foo.map((baz, i) => React.cloneElement(someChild, { ...someChild.props, key: i }))",
+        "foo.map((item, i) => React.cloneElement(someChild, { key: i }))",
+        "foo.map((item, i) => React.createElement('Foo', { key: i }))",
+        "foo.forEach((bar, i) => { baz.push(<Foo key={i} />); })",
+        "foo.filter((bar, i) => { baz.push(<Foo key={i} />); })",
+        "foo.some((bar, i) => { baz.push(<Foo key={i} />); })",
+        "foo.every((bar, i) => { baz.push(<Foo key={i} />); })",
+        "foo.find((bar, i) => { baz.push(<Foo key={i} />); })",
+        "foo.findIndex((bar, i) => { baz.push(<Foo key={i} />); })",
+        "foo.findLast((bar, i) => { baz.push(<Foo key={i} />); })",
+        "foo.findLastIndex((bar, i) => { baz.push(<Foo key={i} />); })",
+        "foo.flatMap((bar, i) => [<Foo key={i} />])",
+        "foo.reduce((a, b, i) => a.concat(<Foo key={i} />), [])",
+        "foo.reduceRight((a, b, i) => a.concat(<Foo key={i} />), [])",
+        "React.Children.map(this.props.children, (child, index) => (React.cloneElement(child, { key: index })))",
+        "React.Children.forEach(this.props.children, function (child, index) { return React.cloneElement(child, { key: index }) })",
+        "Children.map(this.props.children, (child, index) => (React.cloneElement(child, { key: index })))",
+        "Children.forEach(this.props.children, function (child, index) { return React.cloneElement(child, { key: index }) })",
+        "foo?.map((child, i) => <Foo key={i} />)",
+        "foo.map((bar, index) => <Foo key={index.toString()} />)",
+        "foo.map((bar, index) => <Foo key={String(index)} />)",
+        "foo.map(function (bar, i) { return <Foo key={i} />; })",
+        "foo.map((bar, i) => bar.items.map((item) => <Foo key={i} />))",
+    ];
+
+    Tester::new_without_config(NoArrayIndexKey::NAME, pass, fail).test_and_snapshot();
+}
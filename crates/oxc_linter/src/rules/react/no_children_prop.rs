@@ -1,5 +1,8 @@
 use oxc_ast::{
-    ast::{Argument, Expression, JSXAttributeItem, JSXAttributeName, ObjectPropertyKind},
+    ast::{
+        Argument, Expression, JSXAttributeItem, JSXAttributeName, JSXAttributeValue, JSXChild,
+        JSXExpression, ObjectPropertyKind,
+    },
     AstKind,
 };
 use oxc_diagnostics::{
@@ -9,18 +12,29 @@ use oxc_diagnostics::{
 use oxc_macros::declare_oxc_lint;
 use oxc_span::{GetSpan, Span};
 
-use crate::{context::LintContext, rule::Rule, utils::is_create_element_call, AstNode};
+use crate::{context::LintContext, rule::Rule, utils::is_react_create_element_call, AstNode};
 
 #[derive(Debug, Error, Diagnostic)]
-#[error("eslint-plugin-react(no-children-prop): Avoid passing children using a prop.")]
-#[diagnostic(
-    severity(warning),
-    help("The canonical way to pass children in React is to use JSX elements")
-)]
-struct NoChildrenPropDiagnostic(#[label] pub Span);
+enum NoChildrenPropDiagnostic {
+    #[error("eslint-plugin-react(no-children-prop): Avoid passing children using a prop.")]
+    #[diagnostic(
+        severity(warning),
+        help("The canonical way to pass children in React is to use JSX elements")
+    )]
+    ChildrenProp(#[label] Span),
+    #[error("eslint-plugin-react(no-children-prop): Avoid passing a function as children.")]
+    #[diagnostic(
+        severity(warning),
+        help("Functions are allowed as the `children` prop, pass the function using the prop instead")
+    )]
+    FunctionAsChildren(#[label] Span),
+}
 
 #[derive(Debug, Default, Clone)]
-pub struct NoChildrenProp;
+pub struct NoChildrenProp {
+    /// Allow functions as the `children` prop, e.g. for render props.
+    allow_functions: bool,
+}
 
 declare_oxc_lint!(
     /// ### What it does
@@ -55,34 +69,88 @@ declare_oxc_lint!(
     ///
     ///
     /// ```
+    ///
+    /// ### Options
+    ///
+    /// `allowFunctions` (default `false`): allow a function as the `children` prop,
+    /// e.g. `<MyComponent children={(value) => value} />`. Functions nested as children or passed
+    /// as additional arguments to `React.createElement` are reported instead.
     NoChildrenProp,
     correctness
 );
 
 impl Rule for NoChildrenProp {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let allow_functions = value
+            .get(0)
+            .and_then(|config| config.get("allowFunctions"))
+            .and_then(serde_json::Value::as_bool)
+            .unwrap_or(false);
+
+        Self { allow_functions }
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         match node.kind() {
             AstKind::JSXAttributeItem(JSXAttributeItem::Attribute(attr)) => {
                 let JSXAttributeName::Identifier(attr_ident) = &attr.name else { return };
-                if attr_ident.name == "children" {
-                    ctx.diagnostic(NoChildrenPropDiagnostic(attr_ident.span));
+                if attr_ident.name != "children" {
+                    return;
+                }
+                if self.allow_functions {
+                    if let Some(JSXAttributeValue::ExpressionContainer(container)) = &attr.value {
+                        if let JSXExpression::Expression(expr) = &container.expression {
+                            if expr.is_function() {
+                                return;
+                            }
+                        }
+                    }
+                }
+                ctx.diagnostic(NoChildrenPropDiagnostic::ChildrenProp(attr_ident.span));
+            }
+            // `<MyComponent>{() => {}}</MyComponent>`
+            AstKind::JSXElement(elem) if self.allow_functions => {
+                let [JSXChild::ExpressionContainer(container)] = elem.children.as_slice() else {
+                    return;
+                };
+                if let JSXExpression::Expression(expr) = &container.expression {
+                    if expr.is_function() {
+                        ctx.diagnostic(NoChildrenPropDiagnostic::FunctionAsChildren(expr.span()));
+                    }
                 }
             }
             AstKind::CallExpression(call_expr) => {
-                if is_create_element_call(call_expr) {
-                    if let Some(Argument::Expression(Expression::ObjectExpression(obj_expr))) =
-                        call_expr.arguments.get(1)
+                if !is_react_create_element_call(call_expr, ctx) {
+                    return;
+                }
+                let Some(Argument::Expression(Expression::ObjectExpression(obj_expr))) =
+                    call_expr.arguments.get(1)
+                else {
+                    return;
+                };
+
+                let children_prop = obj_expr.properties.iter().find_map(|prop| match prop {
+                    ObjectPropertyKind::ObjectProperty(prop)
+                        if prop.key.is_specific_static_name("children") =>
                     {
-                        if let Some(span) = obj_expr.properties.iter().find_map(|prop| {
-                            if let ObjectPropertyKind::ObjectProperty(prop) = prop {
-                                if prop.key.is_specific_static_name("children") {
-                                    return Some(prop.key.span());
-                                }
-                            }
+                        Some(prop)
+                    }
+                    _ => None,
+                });
 
-                            None
-                        }) {
-                            ctx.diagnostic(NoChildrenPropDiagnostic(span));
+                if let Some(prop) = children_prop {
+                    if !(self.allow_functions && prop.value.is_function()) {
+                        ctx.diagnostic(NoChildrenPropDiagnostic::ChildrenProp(prop.key.span()));
+                    }
+                    return;
+                }
+                if self.allow_functions && call_expr.arguments.len() == 3 {
+                    // `React.createElement(MyComponent, {}, () => {})`
+                    if let Some(Argument::Expression(expr)) = call_expr.arguments.get(2) {
+                        if expr.is_function() {
+                            ctx.diagnostic(NoChildrenPropDiagnostic::FunctionAsChildren(
+                                expr.span(),
+                            ));
                         }
                     }
                 }
@@ -136,6 +204,18 @@ fn test() {
         (r#"<MyComponent className="class-name" {...props} />;"#, None),
         (r#"foo(MyComponent, {...props, children: "Children"})"#, None),
         (r#"React.createElement(MyComponent, {className: "class-name", ...props});"#, None),
+        (r"<MyComponent children={() => {}} />;", Some(serde_json::json!([{ "allowFunctions": true }]))),
+        (r"<MyComponent children={function() {}} />;", Some(serde_json::json!([{ "allowFunctions": true }]))),
+        (r"<MyComponent children={async function() {}} />;", Some(serde_json::json!([{ "allowFunctions": true }]))),
+        (r"<MyComponent children={function* () {}} />;", Some(serde_json::json!([{ "allowFunctions": true }]))),
+        (r"React.createElement(MyComponent, {children: () => {}});", Some(serde_json::json!([{ "allowFunctions": true }]))),
+        (r"React.createElement(MyComponent, {children: function() {}});", Some(serde_json::json!([{ "allowFunctions": true }]))),
+        (r"React.createElement(MyComponent, {children: async function() {}});", Some(serde_json::json!([{ "allowFunctions": true }]))),
+        (r"React.createElement(MyComponent, {children: function* () {}});", Some(serde_json::json!([{ "allowFunctions": true }]))),
+        (r#"foo.createElement("div", {children: "Children"});"#, None),
+        (r#"createElement("div", {children: "Children"});"#, None),
+        (r#"import { createElement } from 'preact'; createElement("div", {children: "Children"});"#, None),
+        (r#"const { createElement } = Preact; createElement("div", {children: "Children"});"#, None),
     ];
 
     #[rustfmt::skip]
@@ -155,6 +235,26 @@ fn test() {
         (r#"React.createElement(MyComponent, {children: "Children", className: "class-name"});"#, None),
         (r#"<MyComponent {...props} children="Children" />;"#, None),
         (r#"React.createElement(MyComponent, {...props, children: "Children"})"#, None),
+        (r"<MyComponent children={() => {}} />;", None),
+        (r"<MyComponent children={function() {}} />;", None),
+        (r"<MyComponent children={async function() {}} />;", None),
+        (r"<MyComponent children={function* () {}} />;", None),
+        (r"React.createElement(MyComponent, {children: () => {}});", None),
+        (r"React.createElement(MyComponent, {children: function() {}});", None),
+        (r"React.createElement(MyComponent, {children: async function() {}});", None),
+        (r"React.createElement(MyComponent, {children: function* () {}});", None),
+        (r"<MyComponent>{() => {}}</MyComponent>;", Some(serde_json::json!([{ "allowFunctions": true }]))),
+        (r"<MyComponent>{function() {}}</MyComponent>;", Some(serde_json::json!([{ "allowFunctions": true }]))),
+        (r"<MyComponent>{async function() {}}</MyComponent>;", Some(serde_json::json!([{ "allowFunctions": true }]))),
+        (r"<MyComponent>{function* () {}}</MyComponent>;", Some(serde_json::json!([{ "allowFunctions": true }]))),
+        (r"React.createElement(MyComponent, {}, () => {});", Some(serde_json::json!([{ "allowFunctions": true }]))),
+        (r"React.createElement(MyComponent, {}, function() {});", Some(serde_json::json!([{ "allowFunctions": true }]))),
+        (r"React.createElement(MyComponent, {}, async function() {});", Some(serde_json::json!([{ "allowFunctions": true }]))),
+        (r"React.createElement(MyComponent, {}, function* () {});", Some(serde_json::json!([{ "allowFunctions": true }]))),
+        (r#"import { createElement } from 'react'; createElement("div", {children: "Children"});"#, None),
+        (r#"import { createElement as h } from 'react'; h("div", {children: "Children"});"#, None),
+        (r#"const { createElement } = React; createElement("div", {children: "Children"});"#, None),
+        (r#"const { createElement } = require('react'); createElement("div", {children: "Children"});"#, None),
     ];
 
     Tester::new(NoChildrenProp::NAME, pass, fail).test_and_snapshot();
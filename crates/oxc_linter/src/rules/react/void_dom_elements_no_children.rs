@@ -0,0 +1,175 @@
+use oxc_ast::{
+    ast::{
+        Argument, Expression, JSXAttributeItem, JSXAttributeName, JSXElementName,
+        ObjectPropertyKind,
+    },
+    AstKind,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{Atom, Span};
+
+use crate::{
+    context::LintContext, globals::VOID_DOM_ELEMENTS, rule::Rule,
+    utils::is_react_create_element_call, AstNode,
+};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint-plugin-react(void-dom-elements-no-children): Void DOM element <{0} /> cannot receive children.")]
+#[diagnostic(
+    severity(warning),
+    help("Remove the children, `children` or `dangerouslySetInnerHTML` of the element")
+)]
+struct VoidDomElementsNoChildrenDiagnostic(Atom, #[label] pub Span);
+
+#[derive(Debug, Default, Clone)]
+pub struct VoidDomElementsNoChildren;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow void DOM elements (e.g. `<img />`, `<br />`) from receiving children.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// There are some HTML elements that are only self-closing (e.g. `img`, `br`, `hr`).
+    /// These are collectively known as void DOM elements. React throws when such an element
+    /// is given children, either nested, as the `children` prop or as `dangerouslySetInnerHTML`.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// // Bad
+    /// <br>Children</br>
+    /// <br children="Children" />
+    /// <br dangerouslySetInnerHTML={{ __html: "HTML" }} />
+    /// React.createElement("br", undefined, "Children")
+    /// React.createElement("br", { children: "Children" })
+    ///
+    /// // Good
+    /// <div>Children</div>
+    /// <div children="Children" />
+    /// <div dangerouslySetInnerHTML={{ __html: "HTML" }} />
+    /// React.createElement("div", undefined, "Children")
+    /// React.createElement("div", { children: "Children" })
+    /// ```
+    VoidDomElementsNoChildren,
+    nursery
+);
+
+fn is_children_prop_name(name: &str) -> bool {
+    matches!(name, "children" | "dangerouslySetInnerHTML")
+}
+
+impl Rule for VoidDomElementsNoChildren {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        match node.kind() {
+            AstKind::JSXElement(elem) => {
+                let JSXElementName::Identifier(ident) = &elem.opening_element.name else {
+                    return;
+                };
+                if !VOID_DOM_ELEMENTS.contains(ident.name.as_str()) {
+                    return;
+                }
+
+                let has_children_prop = elem.opening_element.attributes.iter().any(|attr| {
+                    let JSXAttributeItem::Attribute(attr) = attr else { return false };
+                    let JSXAttributeName::Identifier(attr_ident) = &attr.name else {
+                        return false;
+                    };
+                    is_children_prop_name(&attr_ident.name)
+                });
+
+                if !elem.children.is_empty() || has_children_prop {
+                    ctx.diagnostic(VoidDomElementsNoChildrenDiagnostic(
+                        ident.name.clone(),
+                        elem.span,
+                    ));
+                }
+            }
+            AstKind::CallExpression(call_expr) => {
+                if !is_react_create_element_call(call_expr, ctx) {
+                    return;
+                }
+                let Some(Argument::Expression(Expression::StringLiteral(element_name))) =
+                    call_expr.arguments.first()
+                else {
+                    return;
+                };
+                if !VOID_DOM_ELEMENTS.contains(element_name.value.as_str()) {
+                    return;
+                }
+                // The props can't be checked unless they are an object literal
+                let Some(Argument::Expression(Expression::ObjectExpression(props))) =
+                    call_expr.arguments.get(1)
+                else {
+                    return;
+                };
+
+                let has_children_prop = props.properties.iter().any(|prop| {
+                    let ObjectPropertyKind::ObjectProperty(prop) = prop else { return false };
+                    prop.key.static_name().is_some_and(|name| is_children_prop_name(&name))
+                });
+
+                if call_expr.arguments.len() > 2 || has_children_prop {
+                    ctx.diagnostic(VoidDomElementsNoChildrenDiagnostic(
+                        element_name.value.clone(),
+                        call_expr.span,
+                    ));
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "<div>Foo</div>;",
+        r#"<div children="Foo" />;"#,
+        r#"<div dangerouslySetInnerHTML={{ __html: "Foo" }} />;"#,
+        r#"React.createElement("div", {}, "Foo");"#,
+        r#"React.createElement("div", { children: "Foo" });"#,
+        r#"React.createElement("div", { dangerouslySetInnerHTML: { __html: "Foo" } });"#,
+        r#"document.createElement("img");"#,
+        r#"React.createElement("img");"#,
+        "React.createElement();",
+        "document.createElement();",
+        r#"const props = {}; React.createElement("img", props);"#,
+        r#"import React, { createElement } from "react"; createElement("div");"#,
+        r#"import React, { createElement } from "react"; createElement("img");"#,
+        r#"
+            import React, { createElement, PureComponent } from "react";
+            class Button extends PureComponent {
+                handleClick(ev) {
+                    ev.preventDefault();
+                }
+                render() {
+                    return <div onClick={this.handleClick}>Hello</div>;
+                }
+            }
+        "#,
+        r#"document.createElement("img", {}, "Foo");"#,
+        r#"createElement("img", { children: "Foo" });"#,
+    ];
+
+    let fail = vec![
+        "<br>Foo</br>;",
+        r#"<br children="Foo" />;"#,
+        r#"<img {...props} children="Foo" />;"#,
+        r#"<br dangerouslySetInnerHTML={{ __html: "Foo" }} />;"#,
+        r#"React.createElement("br", {}, "Foo");"#,
+        r#"React.createElement("br", { children: "Foo" });"#,
+        r#"React.createElement("br", { dangerouslySetInnerHTML: { __html: "Foo" } });"#,
+        r#"import React, { createElement } from "react"; createElement("img", {}, "Foo");"#,
+        r#"import React, { createElement } from "react"; createElement("img", { children: "Foo" });"#,
+        r#"import React, { createElement } from "react"; createElement("img", { dangerouslySetInnerHTML: { __html: "Foo" } });"#,
+    ];
+
+    Tester::new_without_config(VoidDomElementsNoChildren::NAME, pass, fail).test_and_snapshot();
+}
@@ -118,39 +118,43 @@ impl Method {
     }
 }
 
+/// The name of `key`, the source text of the computed keys which are not literals
+fn get_name_from_key(key: &PropertyKey, source_text: &str) -> Atom {
+    get_name_from_property_key(key)
+        .unwrap_or_else(|| Atom::from(key.span().source_text(source_text)))
+}
+
 trait GetMethod {
-    fn get_method(&self) -> Option<Method>;
+    fn get_method(&self, source_text: &str) -> Option<Method>;
 }
 
 impl GetMethod for ClassElement<'_> {
-    fn get_method(&self) -> Option<Method> {
-        match self {
-            ClassElement::MethodDefinition(def) => {
-                get_name_from_property_key(&def.key).map(|name| Method {
-                    name,
-                    r#static: def.r#static,
-                    call_signature: false,
-                    kind: get_kind_from_key(&def.key),
-                    span: Span::new(def.span.start, def.key.span().end),
-                })
-            }
-            _ => None,
-        }
+    fn get_method(&self, source_text: &str) -> Option<Method> {
+        let def = match self {
+            ClassElement::MethodDefinition(def) => def,
+            ClassElement::TSAbstractMethodDefinition(def) => &def.method_definition,
+            _ => return None,
+        };
+        Some(Method {
+            name: get_name_from_key(&def.key, source_text),
+            r#static: def.r#static,
+            call_signature: false,
+            kind: get_kind_from_key(&def.key),
+            span: Span::new(def.span.start, def.key.span().end),
+        })
     }
 }
 
 impl GetMethod for TSSignature<'_> {
-    fn get_method(&self) -> Option<Method> {
+    fn get_method(&self, source_text: &str) -> Option<Method> {
         match self {
-            TSSignature::TSMethodSignature(sig) => {
-                get_name_from_property_key(&sig.key).map(|name| Method {
-                    name,
-                    r#static: false,
-                    call_signature: false,
-                    kind: get_kind_from_key(&sig.key),
-                    span: sig.key.span(),
-                })
-            }
+            TSSignature::TSMethodSignature(sig) => Some(Method {
+                name: get_name_from_key(&sig.key, source_text),
+                r#static: false,
+                call_signature: false,
+                kind: get_kind_from_key(&sig.key),
+                span: sig.key.span(),
+            }),
             TSSignature::TSCallSignatureDeclaration(sig) => Some(Method {
                 name: Atom::from("call"),
                 r#static: false,
@@ -171,7 +175,7 @@ impl GetMethod for TSSignature<'_> {
 }
 
 impl GetMethod for ModuleDeclaration<'_> {
-    fn get_method(&self) -> Option<Method> {
+    fn get_method(&self, _source_text: &str) -> Option<Method> {
         match self {
             ModuleDeclaration::ExportDefaultDeclaration(default_decl) => {
                 let decl_kind = &default_decl.declaration;
@@ -214,7 +218,7 @@ impl GetMethod for ModuleDeclaration<'_> {
 }
 
 impl GetMethod for Declaration<'_> {
-    fn get_method(&self) -> Option<Method> {
+    fn get_method(&self, _source_text: &str) -> Option<Method> {
         match self {
             Declaration::FunctionDeclaration(func_decl) => {
                 if matches!(
@@ -238,10 +242,10 @@ impl GetMethod for Declaration<'_> {
 }
 
 impl GetMethod for Statement<'_> {
-    fn get_method(&self) -> Option<Method> {
+    fn get_method(&self, source_text: &str) -> Option<Method> {
         match self {
-            Statement::ModuleDeclaration(decl) => decl.get_method(),
-            Statement::Declaration(decl) => decl.get_method(),
+            Statement::ModuleDeclaration(decl) => decl.get_method(source_text),
+            Statement::Declaration(decl) => decl.get_method(source_text),
             _ => None,
         }
     }
@@ -285,35 +289,55 @@ impl Rule for AdjacentOverloadSignatures {
         match node.kind() {
             AstKind::Class(class) => {
                 let members = &class.body.body;
-                let methods = members.iter().map(GetMethod::get_method).collect();
+                let methods =
+                    members.iter().map(|member| member.get_method(ctx.source_text())).collect();
                 check_and_report(&methods, ctx);
             }
             AstKind::TSTypeLiteral(literal) => {
-                let methods = literal.members.iter().map(GetMethod::get_method).collect();
+                let methods = literal
+                    .members
+                    .iter()
+                    .map(|member| member.get_method(ctx.source_text()))
+                    .collect();
                 check_and_report(&methods, ctx);
             }
             AstKind::Program(program) => {
-                let methods = program.body.iter().map(GetMethod::get_method).collect();
+                let methods = program
+                    .body
+                    .iter()
+                    .map(|member| member.get_method(ctx.source_text()))
+                    .collect();
 
                 check_and_report(&methods, ctx);
             }
             AstKind::TSModuleBlock(block) => {
-                let methods = block.body.iter().map(GetMethod::get_method).collect();
+                let methods =
+                    block.body.iter().map(|member| member.get_method(ctx.source_text())).collect();
 
                 check_and_report(&methods, ctx);
             }
             AstKind::TSInterfaceDeclaration(decl) => {
-                let methods = decl.body.body.iter().map(GetMethod::get_method).collect();
+                let methods = decl
+                    .body
+                    .body
+                    .iter()
+                    .map(|member| member.get_method(ctx.source_text()))
+                    .collect();
 
                 check_and_report(&methods, ctx);
             }
             AstKind::BlockStatement(stmt) => {
-                let methods = stmt.body.iter().map(GetMethod::get_method).collect();
+                let methods =
+                    stmt.body.iter().map(|member| member.get_method(ctx.source_text())).collect();
 
                 check_and_report(&methods, ctx);
             }
             AstKind::FunctionBody(body) => {
-                let methods = body.statements.iter().map(GetMethod::get_method).collect();
+                let methods = body
+                    .statements
+                    .iter()
+                    .map(|member| member.get_method(ctx.source_text()))
+                    .collect();
 
                 check_and_report(&methods, ctx);
             }
@@ -516,6 +540,9 @@ fn test() {
         function foo(n: number);
         function foo(sn: string | number) {}
       }",
+        "abstract class Foo { abstract foo(s: string): void; abstract foo(n: number): void; abstract bar(): void; }",
+        "interface Foo { [Symbol.iterator](): void; [Symbol.iterator](n: number): void; bar(): void; }",
+        "class Foo { [foo](): void; [foo](n: number): void {} [bar](): void {} }",
     ];
 
     let fail = vec![
@@ -756,6 +783,9 @@ fn test() {
         #private(arg: number): void {}
         '#private'(arg: number): void {}
       }",
+        "abstract class Foo { abstract foo(s: string): void; bar(): void {} abstract foo(n: number): void; }",
+        "interface Foo { [Symbol.iterator](): void; bar(): void; [Symbol.iterator](n: number): void; }",
+        "class Foo { [foo](): void; [bar](): void; [foo](n: number): void {} }",
     ];
 
     Tester::new_without_config(AdjacentOverloadSignatures::NAME, pass, fail).test_and_snapshot();
@@ -0,0 +1,623 @@
+use oxc_ast::{
+    ast::{TSArrayType, TSType, TSTypeName, TSTypeOperator, TSTypeReference},
+    AstKind,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+
+use crate::{
+    context::LintContext,
+    fixer::{Fix, FixKind},
+    rule::Rule,
+    AstNode,
+};
+
+#[derive(Debug, Error, Diagnostic)]
+enum ArrayTypeDiagnostic {
+    #[error(
+        "typescript-eslint(array-type): Array type using '{0}{1}[]' is forbidden. Use '{2}<{1}>' instead."
+    )]
+    #[diagnostic(severity(warning))]
+    Generic(&'static str, String, &'static str, #[label] Span),
+    #[error("typescript-eslint(array-type): Array type using '{0}{1}[]' is forbidden for non-simple types. Use '{2}<{1}>' instead.")]
+    #[diagnostic(severity(warning))]
+    GenericSimple(&'static str, String, &'static str, #[label] Span),
+    #[error(
+        "typescript-eslint(array-type): Array type using '{2}<{1}>' is forbidden. Use '{0}{1}[]' instead."
+    )]
+    #[diagnostic(severity(warning))]
+    Array(&'static str, String, &'static str, #[label] Span),
+    #[error("typescript-eslint(array-type): Array type using '{2}<{1}>' is forbidden for simple types. Use '{0}{1}[]' instead.")]
+    #[diagnostic(severity(warning))]
+    ArraySimple(&'static str, String, &'static str, #[label] Span),
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct ArrayType {
+    /// The style of the mutable arrays
+    default: ArrayOption,
+    /// The style of the readonly arrays, the `default` one when not configured
+    readonly: ArrayOption,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+enum ArrayOption {
+    /// `T[]`
+    #[default]
+    Array,
+    /// `T[]` for the simple types, `Array<T>` for the others
+    ArraySimple,
+    /// `Array<T>`
+    Generic,
+}
+
+impl ArrayOption {
+    fn from_value(value: Option<&serde_json::Value>) -> Option<Self> {
+        match value?.as_str()? {
+            "array" => Some(Self::Array),
+            "array-simple" => Some(Self::ArraySimple),
+            "generic" => Some(Self::Generic),
+            _ => None,
+        }
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Require consistently using either `T[]` or `Array<T>` for arrays
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Both forms are equivalent, mixing them makes the types harder to read. The `default`
+    /// option is one of `"array"` (`T[]`, the default), `"generic"` (`Array<T>`) or
+    /// `"array-simple"` (`T[]` for the simple types only), the `readonly` option configures the
+    /// readonly arrays in the same way.
+    ///
+    /// ### Example
+    /// ```typescript
+    /// // fail, with the default options
+    /// const x: Array<string> = ['a', 'b'];
+    /// const y: ReadonlyArray<string> = ['a', 'b'];
+    ///
+    /// // pass
+    /// const x: string[] = ['a', 'b'];
+    /// const y: readonly string[] = ['a', 'b'];
+    /// ```
+    ArrayType,
+    nursery,
+    fix
+);
+
+impl Rule for ArrayType {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let config = value.get(0);
+        let default =
+            ArrayOption::from_value(config.and_then(|v| v.get("default"))).unwrap_or_default();
+        let readonly =
+            ArrayOption::from_value(config.and_then(|v| v.get("readonly"))).unwrap_or(default);
+        Self { default, readonly }
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        match node.kind() {
+            AstKind::TSArrayType(array) => self.check_array_type(array, node, ctx),
+            AstKind::TSTypeReference(reference) => self.check_type_reference(reference, node, ctx),
+            _ => {}
+        }
+    }
+}
+
+impl ArrayType {
+    /// `T[]` and `readonly T[]`
+    fn check_array_type(&self, array: &TSArrayType, node: &AstNode, ctx: &LintContext) {
+        let readonly = match ctx.nodes().parent_kind(node.id()) {
+            Some(AstKind::TSTypeOperatorType(operator))
+                if matches!(operator.operator, TSTypeOperator::Readonly) =>
+            {
+                Some(operator.span)
+            }
+            _ => None,
+        };
+        let option = if readonly.is_some() { self.readonly } else { self.default };
+        let element = &array.element_type;
+        if option == ArrayOption::Array
+            || (option == ArrayOption::ArraySimple && is_simple_type(element))
+        {
+            return;
+        }
+
+        let (prefix, class_name) =
+            if readonly.is_some() { ("readonly ", "ReadonlyArray") } else { ("", "Array") };
+        let span = readonly.unwrap_or(array.span);
+        let message_type = message_type(element, ctx);
+        let diagnostic = if option == ArrayOption::Generic {
+            ArrayTypeDiagnostic::Generic(prefix, message_type, class_name, span)
+        } else {
+            ArrayTypeDiagnostic::GenericSimple(prefix, message_type, class_name, span)
+        };
+        ctx.diagnostic_with_fix(diagnostic, || {
+            let element = element.span().source_text(ctx.source_text());
+            Fix::new(format!("{class_name}<{element}>"), span).with_kind(FixKind::Safe)
+        });
+    }
+
+    /// `Array<T>` and `ReadonlyArray<T>`
+    fn check_type_reference(&self, reference: &TSTypeReference, node: &AstNode, ctx: &LintContext) {
+        let TSTypeName::IdentifierReference(name) = &reference.type_name else { return };
+        let (option, prefix, class_name) = match name.name.as_str() {
+            "Array" => (self.default, "", "Array"),
+            "ReadonlyArray" => (self.readonly, "readonly ", "ReadonlyArray"),
+            _ => return,
+        };
+        if option == ArrayOption::Generic {
+            return;
+        }
+        // `Array` and `Array<A, B>` are not arrays of a single type
+        let Some(params) = &reference.type_parameters else { return };
+        let [element] = params.params.as_slice() else { return };
+        if option == ArrayOption::ArraySimple && !is_simple_type(element) {
+            return;
+        }
+
+        let message_type = message_type(element, ctx);
+        let diagnostic = if option == ArrayOption::Array {
+            ArrayTypeDiagnostic::Array(prefix, message_type, class_name, reference.span)
+        } else {
+            ArrayTypeDiagnostic::ArraySimple(prefix, message_type, class_name, reference.span)
+        };
+        ctx.diagnostic_with_fix(diagnostic, || {
+            let source_text = ctx.source_text();
+            let element_parens = type_needs_parentheses(element);
+            // `ReadonlyArray<T>[]` to `(readonly T[])[]`
+            let parent_parens = !prefix.is_empty()
+                && match ctx.nodes().parent_kind(node.id()) {
+                    Some(AstKind::TSArrayType(array)) => {
+                        !Span::new(array.span.start, reference.span.start)
+                            .source_text(source_text)
+                            .contains('(')
+                    }
+                    _ => false,
+                };
+            let element = element.span().source_text(source_text);
+            let element = if element_parens { format!("({element})") } else { element.to_string() };
+            let content = if parent_parens {
+                format!("({prefix}{element}[])")
+            } else {
+                format!("{prefix}{element}[]")
+            };
+            Fix::new(content, reference.span).with_kind(FixKind::Safe)
+        });
+    }
+}
+
+/// The element type in the messages, `T` when it is not a simple type
+fn message_type(ty: &TSType, ctx: &LintContext) -> String {
+    if is_simple_type(ty) {
+        ty.span().source_text(ctx.source_text()).to_string()
+    } else {
+        "T".to_string()
+    }
+}
+
+/// The keywords, the type names without type arguments, and the arrays of simple types
+fn is_simple_type(ty: &TSType) -> bool {
+    match ty {
+        TSType::TSAnyKeyword(_)
+        | TSType::TSBooleanKeyword(_)
+        | TSType::TSNeverKeyword(_)
+        | TSType::TSNumberKeyword(_)
+        | TSType::TSBigIntKeyword(_)
+        | TSType::TSObjectKeyword(_)
+        | TSType::TSStringKeyword(_)
+        | TSType::TSSymbolKeyword(_)
+        | TSType::TSUnknownKeyword(_)
+        | TSType::TSVoidKeyword(_)
+        | TSType::TSNullKeyword(_)
+        | TSType::TSArrayType(_)
+        | TSType::TSUndefinedKeyword(_)
+        | TSType::TSThisKeyword(_)
+        | TSType::TSQualifiedName(_) => true,
+        TSType::TSTypeReference(reference) => {
+            let is_array = matches!(
+                &reference.type_name,
+                TSTypeName::IdentifierReference(name) if name.name == "Array"
+            );
+            match &reference.type_parameters {
+                None => true,
+                Some(params) if is_array => {
+                    matches!(params.params.as_slice(), [element] if is_simple_type(element))
+                }
+                Some(_) => false,
+            }
+        }
+        _ => false,
+    }
+}
+
+/// The element types which need parentheses in `T[]`
+fn type_needs_parentheses(ty: &TSType) -> bool {
+    match ty {
+        TSType::TSTypeReference(reference) => matches!(
+            &reference.type_name,
+            TSTypeName::IdentifierReference(name) if name.name == "ReadonlyArray"
+        ),
+        TSType::TSUnionType(_)
+        | TSType::TSFunctionType(_)
+        | TSType::TSIntersectionType(_)
+        | TSType::TSTypeOperatorType(_)
+        | TSType::TSInferType(_)
+        | TSType::TSConstructorType(_)
+        | TSType::TSConditionalType(_) => true,
+        _ => false,
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let options = |default: &str, readonly: &str| {
+        Some(serde_json::json!([{ "default": default, "readonly": readonly }]))
+    };
+
+    let pass = vec![
+        ("let a: number[] = [];", options("array", "array")),
+        ("let a: (string | number)[] = [];", options("array", "array")),
+        ("let a: readonly number[] = [];", options("array", "array")),
+        ("let a: readonly (string | number)[] = [];", options("array", "array")),
+        ("let a: number[] = [];", options("array", "array-simple")),
+        ("let a: (string | number)[] = [];", options("array", "array-simple")),
+        ("let a: readonly number[] = [];", options("array", "array-simple")),
+        ("let a: ReadonlyArray<string | number> = [];", options("array", "array-simple")),
+        ("let a: number[] = [];", options("array", "generic")),
+        ("let a: (string | number)[] = [];", options("array", "generic")),
+        ("let a: ReadonlyArray<number> = [];", options("array", "generic")),
+        ("let a: ReadonlyArray<string | number> = [];", options("array", "generic")),
+        ("let a: number[] = [];", options("array-simple", "array")),
+        ("let a: Array<string | number> = [];", options("array-simple", "array")),
+        ("let a: readonly number[] = [];", options("array-simple", "array")),
+        ("let a: readonly (string | number)[] = [];", options("array-simple", "array")),
+        ("let a: number[] = [];", options("array-simple", "array-simple")),
+        ("let a: Array<string | number> = [];", options("array-simple", "array-simple")),
+        ("let a: readonly number[] = [];", options("array-simple", "array-simple")),
+        ("let a: ReadonlyArray<string | number> = [];", options("array-simple", "array-simple")),
+        ("let a: number[] = [];", options("array-simple", "generic")),
+        ("let a: Array<string | number> = [];", options("array-simple", "generic")),
+        ("let a: ReadonlyArray<number> = [];", options("array-simple", "generic")),
+        ("let a: ReadonlyArray<string | number> = [];", options("array-simple", "generic")),
+        ("let a: Array<number> = [];", options("generic", "array")),
+        ("let a: Array<string | number> = [];", options("generic", "array")),
+        ("let a: readonly number[] = [];", options("generic", "array")),
+        ("let a: readonly (string | number)[] = [];", options("generic", "array")),
+        ("let a: Array<number> = [];", options("generic", "array-simple")),
+        ("let a: Array<string | number> = [];", options("generic", "array-simple")),
+        ("let a: readonly number[] = [];", options("generic", "array-simple")),
+        ("let a: ReadonlyArray<string | number> = [];", options("generic", "array-simple")),
+        ("let a: Array<number> = [];", options("generic", "generic")),
+        ("let a: Array<string | number> = [];", options("generic", "generic")),
+        ("let a: ReadonlyArray<number> = [];", options("generic", "generic")),
+        ("let a: ReadonlyArray<string | number> = [];", options("generic", "generic")),
+        ("let a: bigint[] = [];", options("array-simple", "array-simple")),
+        ("let a: (string | bigint)[] = [];", options("array", "array")),
+        ("let a: ReadonlyArray<string | bigint> = [];", options("array-simple", "array-simple")),
+        ("let a: readonly bigint[] = [];", options("generic", "array")),
+        ("let a: string[] = [];", None),
+        ("let a: readonly string[] = [];", None),
+        ("let a: ReadonlyArray<string> = [];", Some(serde_json::json!([{ "default": "generic" }]))),
+        ("let a: readonly string[] = [];", Some(serde_json::json!([{ "default": "array" }]))),
+        ("let a: { foo: Bar[] }[] = [];", options("array", "array")),
+        ("function foo(a: Array<Bar>): Array<Bar> {}", options("generic", "generic")),
+        ("let yy: number[][] = [[4, 5], [6]];", options("array-simple", "array-simple")),
+        ("let ya = [[1, '2']] as [number, string][];", options("array", "array")),
+        (
+            "function barFunction(bar: ArrayClass<String>[]) { return bar.map(e => e.bar); }",
+            options("array", "array"),
+        ),
+        (
+            "function bazFunction(baz: Arr<ArrayClass<String>>) { return baz.map(e => e.baz); }",
+            options("array-simple", "array-simple"),
+        ),
+        ("let fooVar: Array<(c: number) => number>;", options("array-simple", "array-simple")),
+        (
+            "type fooUnion = Array<string | number | boolean>;",
+            options("array-simple", "array-simple"),
+        ),
+        ("type fooIntersection = Array<string & number>;", options("array-simple", "array-simple")),
+        (
+            "namespace fooName { type BarType = { bar: string }; type BazType<T> = Arr<T>; }",
+            options("array-simple", "array-simple"),
+        ),
+        (
+            "interface FooInterface { '.bar': { baz: string[] }; }",
+            options("array-simple", "array-simple"),
+        ),
+        ("interface ArrayClass<T> { xyz: this[]; }", options("array-simple", "array-simple")),
+        ("type Unwrap<T> = T extends (infer E)[] ? E : T;", options("array", "array")),
+        ("type Unwrap<T> = T extends Array<infer E> ? E : T;", options("generic", "generic")),
+        // `Array` without a single type argument is not an array of a single type
+        ("let z: Array = [3, '4'];", options("array", "array")),
+        ("let z: Array = [3, '4'];", options("array-simple", "array-simple")),
+        ("let a: Array<string, number> = [];", options("array", "array")),
+        ("let a: ReadonlyArray = [];", options("array", "array")),
+        ("let a: ReadonlyArray<string, number> = [];", options("array-simple", "array-simple")),
+        // Not the global `Array`
+        ("let a: Foo.Array<string> = [];", options("array", "array")),
+        ("let a: MyArray<string> = [];", options("array", "array")),
+    ];
+
+    let fail = vec![
+        ("let a: Array<number> = [];", options("array", "array")),
+        ("let a: Array<string | number> = [];", options("array", "array")),
+        ("let a: ReadonlyArray<number> = [];", options("array", "array")),
+        ("let a: ReadonlyArray<string | number> = [];", options("array", "array")),
+        ("let a: Array<number> = [];", options("array", "array-simple")),
+        ("let a: Array<string | number> = [];", options("array", "array-simple")),
+        ("let a: ReadonlyArray<number> = [];", options("array", "array-simple")),
+        ("let a: readonly (string | number)[] = [];", options("array", "array-simple")),
+        ("let a: Array<number> = [];", options("array", "generic")),
+        ("let a: Array<string | number> = [];", options("array", "generic")),
+        ("let a: readonly number[] = [];", options("array", "generic")),
+        ("let a: readonly (string | number)[] = [];", options("array", "generic")),
+        ("let a: Array<number> = [];", options("array-simple", "array")),
+        ("let a: (string | number)[] = [];", options("array-simple", "array")),
+        ("let a: ReadonlyArray<number> = [];", options("array-simple", "array")),
+        ("let a: ReadonlyArray<string | number> = [];", options("array-simple", "array")),
+        ("let a: Array<number> = [];", options("array-simple", "array-simple")),
+        ("let a: (string | number)[] = [];", options("array-simple", "array-simple")),
+        ("let a: ReadonlyArray<number> = [];", options("array-simple", "array-simple")),
+        ("let a: readonly (string | number)[] = [];", options("array-simple", "array-simple")),
+        ("let a: Array<number> = [];", options("array-simple", "generic")),
+        ("let a: (string | number)[] = [];", options("array-simple", "generic")),
+        ("let a: readonly number[] = [];", options("array-simple", "generic")),
+        ("let a: readonly (string | number)[] = [];", options("array-simple", "generic")),
+        ("let a: number[] = [];", options("generic", "array")),
+        ("let a: (string | number)[] = [];", options("generic", "array")),
+        ("let a: ReadonlyArray<number> = [];", options("generic", "array")),
+        ("let a: ReadonlyArray<string | number> = [];", options("generic", "array")),
+        ("let a: number[] = [];", options("generic", "array-simple")),
+        ("let a: (string | number)[] = [];", options("generic", "array-simple")),
+        ("let a: ReadonlyArray<number> = [];", options("generic", "array-simple")),
+        ("let a: readonly (string | number)[] = [];", options("generic", "array-simple")),
+        ("let a: number[] = [];", options("generic", "generic")),
+        ("let a: (string | number)[] = [];", options("generic", "generic")),
+        ("let a: readonly number[] = [];", options("generic", "generic")),
+        ("let a: readonly (string | number)[] = [];", options("generic", "generic")),
+        ("let a: bigint[] = [];", options("generic", "generic")),
+        ("let a: (string | number | bigint)[] = [];", options("array-simple", "array-simple")),
+        ("let a: ReadonlyArray<bigint> = [];", options("array-simple", "array-simple")),
+        ("let a: readonly (string | bigint)[] = [];", options("generic", "generic")),
+        ("let a: Array<string> = [];", None),
+        ("let a: ReadonlyArray<string> = [];", Some(serde_json::json!([{ "default": "array" }]))),
+        ("let a: readonly string[] = [];", Some(serde_json::json!([{ "default": "generic" }]))),
+        ("let a: { foo: Array<Bar> }[] = [];", options("array", "array")),
+        ("let a: Array<{ foo: Bar[] }> = [];", options("array", "array")),
+        ("let a: Array<{ foo: Foo | Bar[] }> = [];", options("array", "array")),
+        ("function foo(a: Array<Bar>): Array<Bar> {}", options("array", "array")),
+        (
+            "let x: Array<undefined> = [undefined] as undefined[];",
+            options("array-simple", "array-simple"),
+        ),
+        ("let ya = [[1, '2']] as [number, string][];", options("generic", "generic")),
+        ("type Arr<T> = Array<T>;", options("array", "array")),
+        (
+            "let yyyy: Arr<Array<Arr<string>>[]> = [[[['2']]]];",
+            options("array-simple", "array-simple"),
+        ),
+        (
+            "interface ArrayClass<T> { foo: Array<T>; bar: T[]; baz: Arr<T>; xyz: this[]; }",
+            options("array-simple", "array-simple"),
+        ),
+        (
+            "function barFunction(bar: ArrayClass<String>[]) { return bar.map(e => e.bar); }",
+            options("array-simple", "array-simple"),
+        ),
+        ("let barVar: ((c: number) => number)[];", options("array-simple", "array-simple")),
+        ("type barUnion = (string | number | boolean)[];", options("array-simple", "array-simple")),
+        ("type barIntersection = (string & number)[];", options("array-simple", "array-simple")),
+        (
+            "let v: Array<fooName.BarType> = [{ bar: 'bar' }];",
+            options("array-simple", "array-simple"),
+        ),
+        ("let w: fooName.BazType<string>[] = [['baz']];", options("array-simple", "array-simple")),
+        ("let x: Array<undefined> = [undefined] as undefined[];", options("array", "array")),
+        ("let xx: Array<Array<number>> = [[1, 2], [3]];", options("array", "array")),
+        ("let yy: number[][] = [[4, 5], [6]];", options("generic", "generic")),
+        (
+            "function fooFunction(foo: Array<ArrayClass<string>>) { return foo.map(e => e.foo); }",
+            options("array", "array"),
+        ),
+        ("let fooVar: Array<(c: number) => number>;", options("array", "array")),
+        ("type fooUnion = Array<string | number | boolean>;", options("array", "array")),
+        ("type fooIntersection = Array<string & number>;", options("array", "array")),
+        ("type Unwrap<T> = T extends Array<infer E> ? E : T;", options("array", "array")),
+        ("type Keys = Array<keyof Foo>;", options("array", "array")),
+        ("const foo: Array<new (...args: any[]) => void> = [];", options("array", "array")),
+        ("let a: ReadonlyArray<number>[] = [];", options("array", "array")),
+        ("let a: Array<ReadonlyArray<number>> = [];", options("array", "array")),
+        ("let a: Promise<string[]> = Promise.resolve([]);", options("generic", "generic")),
+        (
+            "let x: Array<string | number> = [1, 'a'] as (string | number)[];",
+            options("generic", "generic"),
+        ),
+    ];
+
+    let fix = vec![
+        ("let a: Array<number> = [];", "let a: number[] = [];", options("array", "array")),
+        (
+            "let a: Array<string | number> = [];",
+            "let a: (string | number)[] = [];",
+            options("array", "array"),
+        ),
+        (
+            "let a: ReadonlyArray<number> = [];",
+            "let a: readonly number[] = [];",
+            options("array", "array"),
+        ),
+        (
+            "let a: ReadonlyArray<string | number> = [];",
+            "let a: readonly (string | number)[] = [];",
+            options("array", "array"),
+        ),
+        (
+            "let a: ReadonlyArray<number> = [];",
+            "let a: readonly number[] = [];",
+            options("array", "array-simple"),
+        ),
+        (
+            "let a: readonly (string | number)[] = [];",
+            "let a: ReadonlyArray<string | number> = [];",
+            options("array", "array-simple"),
+        ),
+        (
+            "let a: readonly number[] = [];",
+            "let a: ReadonlyArray<number> = [];",
+            options("array", "generic"),
+        ),
+        (
+            "let a: (string | number)[] = [];",
+            "let a: Array<string | number> = [];",
+            options("array-simple", "array"),
+        ),
+        ("let a: Array<number> = [];", "let a: number[] = [];", options("array-simple", "array")),
+        ("let a: number[] = [];", "let a: Array<number> = [];", options("generic", "array")),
+        (
+            "let a: (string | number)[] = [];",
+            "let a: Array<string | number> = [];",
+            options("generic", "generic"),
+        ),
+        (
+            "let a: readonly (string | number)[] = [];",
+            "let a: ReadonlyArray<string | number> = [];",
+            options("generic", "generic"),
+        ),
+        ("let a: bigint[] = [];", "let a: Array<bigint> = [];", options("generic", "generic")),
+        (
+            "let a: ReadonlyArray<bigint> = [];",
+            "let a: readonly bigint[] = [];",
+            options("array-simple", "array-simple"),
+        ),
+        ("let a: Array<string> = [];", "let a: string[] = [];", None),
+        (
+            "let a: { foo: Array<Bar> }[] = [];",
+            "let a: { foo: Bar[] }[] = [];",
+            options("array", "array"),
+        ),
+        (
+            "let a: Array<{ foo: Bar[] }> = [];",
+            "let a: { foo: Bar[] }[] = [];",
+            options("array", "array"),
+        ),
+        (
+            "function foo(a: Array<Bar>): Array<Bar> {}",
+            "function foo(a: Bar[]): Bar[] {}",
+            options("array", "array"),
+        ),
+        (
+            "let x: Array<undefined> = [undefined] as undefined[];",
+            "let x: undefined[] = [undefined] as undefined[];",
+            options("array-simple", "array-simple"),
+        ),
+        (
+            "let ya = [[1, '2']] as [number, string][];",
+            "let ya = [[1, '2']] as Array<[number, string]>;",
+            options("generic", "generic"),
+        ),
+        (
+            "let yyyy: Arr<Array<Arr<string>>[]> = [[[['2']]]];",
+            "let yyyy: Arr<Array<Array<Arr<string>>>> = [[[['2']]]];",
+            options("array-simple", "array-simple"),
+        ),
+        (
+            "interface ArrayClass<T> { foo: Array<T>; bar: T[]; baz: Arr<T>; xyz: this[]; }",
+            "interface ArrayClass<T> { foo: T[]; bar: T[]; baz: Arr<T>; xyz: this[]; }",
+            options("array-simple", "array-simple"),
+        ),
+        (
+            "let barVar: ((c: number) => number)[];",
+            "let barVar: Array<(c: number) => number>;",
+            options("array-simple", "array-simple"),
+        ),
+        (
+            "type barUnion = (string | number | boolean)[];",
+            "type barUnion = Array<string | number | boolean>;",
+            options("array-simple", "array-simple"),
+        ),
+        (
+            "let v: Array<fooName.BarType> = [{ bar: 'bar' }];",
+            "let v: fooName.BarType[] = [{ bar: 'bar' }];",
+            options("array-simple", "array-simple"),
+        ),
+        (
+            "let w: fooName.BazType<string>[] = [['baz']];",
+            "let w: Array<fooName.BazType<string>> = [['baz']];",
+            options("array-simple", "array-simple"),
+        ),
+        (
+            "let fooVar: Array<(c: number) => number>;",
+            "let fooVar: ((c: number) => number)[];",
+            options("array", "array"),
+        ),
+        (
+            "type fooUnion = Array<string | number | boolean>;",
+            "type fooUnion = (string | number | boolean)[];",
+            options("array", "array"),
+        ),
+        (
+            "type fooIntersection = Array<string & number>;",
+            "type fooIntersection = (string & number)[];",
+            options("array", "array"),
+        ),
+        (
+            "type Unwrap<T> = T extends Array<infer E> ? E : T;",
+            "type Unwrap<T> = T extends (infer E)[] ? E : T;",
+            options("array", "array"),
+        ),
+        ("type Keys = Array<keyof Foo>;", "type Keys = (keyof Foo)[];", options("array", "array")),
+        (
+            "const foo: Array<new (...args: any[]) => void> = [];",
+            "const foo: (new (...args: any[]) => void)[] = [];",
+            options("array", "array"),
+        ),
+        (
+            "let a: ReadonlyArray<number>[] = [];",
+            "let a: (readonly number[])[] = [];",
+            options("array", "array"),
+        ),
+        (
+            "let a: (ReadonlyArray<number>)[] = [];",
+            "let a: (readonly number[])[] = [];",
+            options("array", "array"),
+        ),
+        (
+            "let a: Promise<string[]> = Promise.resolve([]);",
+            "let a: Promise<Array<string>> = Promise.resolve([]);",
+            options("generic", "generic"),
+        ),
+        // Only one of the nested types is fixed in a single pass, the first one in the source
+        (
+            "let xx: Array<Array<number>> = [[1, 2], [3]];",
+            "let xx: Array<number>[] = [[1, 2], [3]];",
+            options("array", "array"),
+        ),
+        (
+            "let yy: number[][] = [[4, 5], [6]];",
+            "let yy: Array<number>[] = [[4, 5], [6]];",
+            options("generic", "generic"),
+        ),
+        (
+            "let a: Array<ReadonlyArray<number>> = [];",
+            "let a: (ReadonlyArray<number>)[] = [];",
+            options("array", "array"),
+        ),
+    ];
+
+    Tester::new(ArrayType::NAME, pass, fail)
+        .expect_fix(fix)
+        .expect_fix_kind(FixKind::Safe)
+        .test_and_snapshot();
+}
@@ -0,0 +1,322 @@
+use oxc_ast::{
+    ast::{
+        ExportDefaultDeclarationKind, ModifierKind, ModuleDeclaration, TSInterfaceDeclaration,
+        TSModuleDeclarationName, TSType, TSTypeAliasDeclaration,
+    },
+    AstKind,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+
+use crate::{context::LintContext, fixer::Fix, rule::Rule, AstNode};
+
+#[derive(Debug, Error, Diagnostic)]
+enum ConsistentTypeDefinitionsDiagnostic {
+    #[error(
+        "typescript-eslint(consistent-type-definitions): Use an `interface` instead of a `type`."
+    )]
+    #[diagnostic(severity(warning))]
+    InterfaceOverType(#[label] Span),
+    #[error(
+        "typescript-eslint(consistent-type-definitions): Use a `type` instead of an `interface`."
+    )]
+    #[diagnostic(severity(warning))]
+    TypeOverInterface(#[label] Span),
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct ConsistentTypeDefinitions {
+    /// Prefer the type aliases, `"type"`, instead of the interfaces
+    prefer_type: bool,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Enforce type definitions to consistently use either `interface` or `type`
+    ///
+    /// ### Why is this bad?
+    ///
+    /// An object type can be defined with an interface or a type alias of a type literal,
+    /// mixing both makes the code inconsistent. The interfaces are preferred by default, the
+    /// type aliases with the `"type"` option. The declarations in `declare global` are reported
+    /// but not fixed, as the interfaces there usually augment the global ones.
+    ///
+    /// ### Example
+    /// ```typescript
+    /// // fail
+    /// type T = { x: number };
+    ///
+    /// // pass
+    /// interface T {
+    ///     x: number;
+    /// }
+    /// ```
+    ConsistentTypeDefinitions,
+    nursery,
+    fix
+);
+
+impl Rule for ConsistentTypeDefinitions {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        Self { prefer_type: value.get(0).and_then(serde_json::Value::as_str) == Some("type") }
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        match node.kind() {
+            AstKind::TSTypeAliasDeclaration(decl) if !self.prefer_type => {
+                if !matches!(decl.type_annotation, TSType::TSTypeLiteral(_)) {
+                    return;
+                }
+                let diagnostic =
+                    ConsistentTypeDefinitionsDiagnostic::InterfaceOverType(decl.id.span);
+                match fix_to_interface(decl, node, ctx) {
+                    Some(fix) => ctx.diagnostic_with_fix(diagnostic, || fix),
+                    None => ctx.diagnostic(diagnostic),
+                }
+            }
+            AstKind::TSInterfaceDeclaration(decl) if self.prefer_type => {
+                check_interface(decl, None, node, ctx);
+            }
+            // `export default interface A {}` is not visited as an interface
+            AstKind::ModuleDeclaration(ModuleDeclaration::ExportDefaultDeclaration(export))
+                if self.prefer_type =>
+            {
+                if let ExportDefaultDeclarationKind::TSInterfaceDeclaration(decl) =
+                    &export.declaration
+                {
+                    check_interface(decl, Some(export.span), node, ctx);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+fn check_interface(
+    decl: &TSInterfaceDeclaration,
+    export_default: Option<Span>,
+    node: &AstNode,
+    ctx: &LintContext,
+) {
+    let diagnostic = ConsistentTypeDefinitionsDiagnostic::TypeOverInterface(decl.id.span);
+    match fix_to_type_alias(decl, export_default, node, ctx) {
+        Some(fix) => ctx.diagnostic_with_fix(diagnostic, || fix),
+        None => ctx.diagnostic(diagnostic),
+    }
+}
+
+/// `type A = { x: number };` to `interface A { x: number }`
+fn fix_to_interface<'a>(
+    decl: &TSTypeAliasDeclaration,
+    node: &AstNode,
+    ctx: &LintContext<'a>,
+) -> Option<Fix<'a>> {
+    if is_in_declare_global(node, ctx) {
+        return None;
+    }
+    let source_text = ctx.source_text();
+    let keyword = keyword_before(decl.id.span.start, "type", source_text)?;
+    let type_end = decl.type_parameters.as_ref().map_or(decl.id.span.end, |params| params.span.end);
+    let literal = decl.type_annotation.span();
+    // The trailing semicolon is not needed after the body of the interface
+    let rest = &source_text[literal.end as usize..];
+    let end = if rest.trim_start().starts_with(';') {
+        literal.end + u32::try_from(rest.len() - rest.trim_start().len() + 1).ok()?
+    } else {
+        literal.end
+    };
+    let span = Span::new(keyword.start, end);
+    if ctx.semantic().trivias().has_comments_between(span) {
+        return None;
+    }
+    let name = Span::new(keyword.end, type_end).source_text(source_text);
+    let body = literal.source_text(source_text);
+    Some(Fix::new(format!("interface{name} {body}"), span))
+}
+
+/// `interface A extends B { x: number }` to `type A = { x: number } & B`
+fn fix_to_type_alias<'a>(
+    decl: &TSInterfaceDeclaration,
+    export_default: Option<Span>,
+    node: &AstNode,
+    ctx: &LintContext<'a>,
+) -> Option<Fix<'a>> {
+    if is_in_declare_global(node, ctx) {
+        return None;
+    }
+    // `interface A extends B, C {}` is not equivalent to `type A = {} & B & C` when `B` and `C`
+    // have conflicting members
+    let heritages = decl.extends.iter().flat_map(|extends| extends.iter());
+    if heritages.clone().count() > 1 {
+        return None;
+    }
+    let source_text = ctx.source_text();
+    let keyword = keyword_before(decl.id.span.start, "interface", source_text)?;
+    let type_end = decl.type_parameters.as_ref().map_or(decl.id.span.end, |params| params.span.end);
+    let start = export_default.map_or(keyword.start, |span| span.start);
+    let span = Span::new(start, decl.body.span.end);
+    if ctx.semantic().trivias().has_comments_between(span) {
+        return None;
+    }
+    let name = Span::new(keyword.end, type_end).source_text(source_text);
+    let body = decl.body.span.source_text(source_text);
+    let heritage = heritages.fold(String::new(), |mut heritage, item| {
+        heritage.push_str(" & ");
+        heritage.push_str(item.span.source_text(source_text));
+        heritage
+    });
+    let export =
+        export_default.map_or_else(String::new, |_| format!("\nexport default {}", decl.id.name));
+    Some(Fix::new(format!("type{name} = {body}{heritage}{export}"), span))
+}
+
+/// The span of `keyword` right before `offset`
+fn keyword_before(offset: u32, keyword: &str, source_text: &str) -> Option<Span> {
+    let before = source_text[..offset as usize].trim_end();
+    if !before.ends_with(keyword) {
+        return None;
+    }
+    let end = u32::try_from(before.len()).ok()?;
+    let start = end - u32::try_from(keyword.len()).ok()?;
+    Some(Span::new(start, end))
+}
+
+/// The interfaces in `declare global` augment the global ones, they can not be type aliases
+fn is_in_declare_global(node: &AstNode, ctx: &LintContext) -> bool {
+    ctx.nodes().iter_parents(node.id()).any(|node| {
+        let AstKind::TSModuleDeclaration(module) = node.kind() else { return false };
+        let TSModuleDeclarationName::Identifier(id) = &module.id else { return false };
+        id.name == "global" && module.modifiers.contains(ModifierKind::Declare)
+    })
+}
+
+#[test]
+fn test() {
+    use crate::{tester::Tester, FixKind};
+
+    let interface = || Some(serde_json::json!(["interface"]));
+    let prefer_type = || Some(serde_json::json!(["type"]));
+
+    let pass = vec![
+        ("var foo = {};", interface()),
+        ("interface A {}", interface()),
+        ("interface A extends B { x: number; }", interface()),
+        ("type U = string;", interface()),
+        ("type V = { x: number } | { y: string };", interface()),
+        ("type Record<T, U> = { [K in T]: U };", interface()),
+        ("interface A {}", None),
+        ("type T = { x: number };", prefer_type()),
+        ("type A = { x: number } & B & C;", prefer_type()),
+        ("type A = { x: number } & B<T1> & C<T2>;", prefer_type()),
+        ("export type W<T> = { x: T };", prefer_type()),
+    ];
+
+    let fail = vec![
+        ("type T = { x: number; };", interface()),
+        ("type T={ x: number; };", interface()),
+        ("type T=                         { x: number; };", interface()),
+        ("type T = { x: number; };", None),
+        ("export type W<T> = { x: T; };", interface()),
+        ("namespace JSX { type IntrinsicElements = { div: string; }; }", interface()),
+        ("declare global { namespace Foo { type Bar = {}; } }", interface()),
+        ("export declare type Test = { foo: string; bar: string; };", interface()),
+        ("type T = { /* x */ x: number; };", interface()),
+        ("interface T { x: number; }", prefer_type()),
+        ("interface T{ x: number; }", prefer_type()),
+        ("interface T                          { x: number; }", prefer_type()),
+        ("interface A extends B, C { x: number; };", prefer_type()),
+        ("interface A extends B<T1>, C<T2> { x: number; };", prefer_type()),
+        ("interface A extends B { x: number; };", prefer_type()),
+        ("export interface W<T> { x: T; }", prefer_type()),
+        ("namespace JSX { interface Array<T> { foo(x: (x: number) => T): T[]; } }", prefer_type()),
+        ("declare global { interface Array<T> { foo(x: (x: number) => T): T[]; } }", prefer_type()),
+        ("declare global { namespace Foo { interface Bar {} } }", prefer_type()),
+        ("export default interface Test { bar(): string; foo(): number; }", prefer_type()),
+        ("export declare interface Test { foo: string; bar: string; }", prefer_type()),
+    ];
+
+    let fix = vec![
+        ("type T = { x: number; };", "interface T { x: number; }", interface()),
+        ("type T={ x: number; };", "interface T { x: number; }", interface()),
+        (
+            "type T=                         { x: number; };",
+            "interface T { x: number; }",
+            interface(),
+        ),
+        ("type T = { x: number; }", "interface T { x: number; }", None),
+        ("export type W<T> = { x: T; };", "export interface W<T> { x: T; }", interface()),
+        (
+            "namespace JSX { type IntrinsicElements = { div: string; }; }",
+            "namespace JSX { interface IntrinsicElements { div: string; } }",
+            interface(),
+        ),
+        (
+            "export declare type Test = { foo: string; bar: string; };",
+            "export declare interface Test { foo: string; bar: string; }",
+            interface(),
+        ),
+        (
+            "type Foo = {\n  a: string;\n};\nlet foo: Foo;",
+            "interface Foo {\n  a: string;\n}\nlet foo: Foo;",
+            interface(),
+        ),
+        ("interface T { x: number; }", "type T = { x: number; }", prefer_type()),
+        ("interface T{ x: number; }", "type T = { x: number; }", prefer_type()),
+        (
+            "interface T                          { x: number; }",
+            "type T = { x: number; }",
+            prefer_type(),
+        ),
+        ("interface A extends B { x: number; };", "type A = { x: number; } & B;", prefer_type()),
+        (
+            "interface A extends B<T1> { x: number; };",
+            "type A = { x: number; } & B<T1>;",
+            prefer_type(),
+        ),
+        ("export interface W<T> { x: T; }", "export type W<T> = { x: T; }", prefer_type()),
+        (
+            "namespace JSX { interface Array<T> { foo(x: (x: number) => T): T[]; } }",
+            "namespace JSX { type Array<T> = { foo(x: (x: number) => T): T[]; } }",
+            prefer_type(),
+        ),
+        (
+            "export default interface Test { bar(): string; foo(): number; }",
+            "type Test = { bar(): string; foo(): number; }\nexport default Test",
+            prefer_type(),
+        ),
+        (
+            "export declare interface Test { foo: string; bar: string; }",
+            "export declare type Test = { foo: string; bar: string; }",
+            prefer_type(),
+        ),
+        // Not fixed
+        (
+            "interface A extends B, C { x: number; };",
+            "interface A extends B, C { x: number; };",
+            prefer_type(),
+        ),
+        (
+            "declare global { interface Array<T> { foo(x: (x: number) => T): T[]; } }",
+            "declare global { interface Array<T> { foo(x: (x: number) => T): T[]; } }",
+            prefer_type(),
+        ),
+        (
+            "declare global { namespace Foo { type Bar = {}; } }",
+            "declare global { namespace Foo { type Bar = {}; } }",
+            interface(),
+        ),
+        ("type T = { /* x */ x: number; };", "type T = { /* x */ x: number; };", interface()),
+    ];
+
+    // Interfaces merge with the declarations of the same name, and unlike type aliases they are
+    // not assignable to index signatures
+    Tester::new(ConsistentTypeDefinitions::NAME, pass, fail)
+        .expect_fix(fix)
+        .expect_fix_kind(FixKind::Dangerous)
+        .test_and_snapshot();
+}
@@ -0,0 +1,873 @@
+use oxc_ast::{
+    ast::{
+        BindingIdentifier, ImportDeclaration, ImportDeclarationSpecifier, ImportDefaultSpecifier,
+        ImportNamespaceSpecifier, ImportSpecifier, ModuleDeclaration, Program, Statement,
+    },
+    AstKind,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{Atom, GetSpan, Span};
+
+use crate::{context::LintContext, fixer::Fix, rule::Rule};
+
+#[derive(Debug, Error, Diagnostic)]
+enum ConsistentTypeImportsDiagnostic {
+    #[error(
+        "typescript-eslint(consistent-type-imports): All imports in the declaration are only used as types."
+    )]
+    #[diagnostic(severity(warning), help("Use `import type`"))]
+    TypeOverValue(#[label] Span),
+    #[error("typescript-eslint(consistent-type-imports): Import {0} is only used as types.")]
+    #[diagnostic(severity(warning), help("Import it with `import type`"))]
+    AImportIsOnlyTypes(String, #[label] Span),
+    #[error("typescript-eslint(consistent-type-imports): Imports {0} are only used as types.")]
+    #[diagnostic(severity(warning), help("Import them with `import type`"))]
+    SomeImportsAreOnlyTypes(String, #[label] Span),
+    #[error(
+        "typescript-eslint(consistent-type-imports): Use an `import` instead of an `import type`."
+    )]
+    #[diagnostic(severity(warning))]
+    AvoidImportType(#[label] Span),
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct ConsistentTypeImports {
+    prefer: Prefer,
+    fix_style: FixStyle,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+enum Prefer {
+    /// `import type { A } from 'a'`
+    #[default]
+    TypeImports,
+    /// `import { A } from 'a'`
+    NoTypeImports,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+enum FixStyle {
+    /// `import type { A } from 'a'`
+    #[default]
+    SeparateTypeImports,
+    /// `import { type A } from 'a'`
+    InlineTypeImports,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Enforce consistent usage of type imports
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Importing the bindings only used as types with `import type` makes it clear that the
+    /// import is elided from the emitted code. With `prefer: "no-type-imports"`, the type
+    /// imports are reported instead. The bindings used in decorator metadata are not taken
+    /// into account.
+    ///
+    /// ### Example
+    /// ```typescript
+    /// // fail
+    /// import { Foo } from 'foo';
+    /// let foo: Foo;
+    ///
+    /// // pass
+    /// import type { Foo } from 'foo';
+    /// let foo: Foo;
+    /// ```
+    ConsistentTypeImports,
+    nursery,
+    fix
+);
+
+/// How the bindings of an import are used
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Usage {
+    /// `import { type A } from 'a'`
+    InlineType,
+    Unused,
+    Type,
+    Value,
+}
+
+/// The first imports of a source which the type specifiers can be moved to
+#[derive(Default)]
+struct SourceImports<'s, 'a> {
+    /// `import type { A } from 'a'`
+    type_only_named: Option<&'s ImportDeclaration<'a>>,
+    /// `import { A } from 'a'`
+    value_only_named: Option<&'s ImportDeclaration<'a>>,
+    value: Option<&'s ImportDeclaration<'a>>,
+}
+
+/// The specifiers of an import declaration by kind
+struct Specifiers<'s> {
+    default: Option<&'s ImportDefaultSpecifier>,
+    namespace: Option<&'s ImportNamespaceSpecifier>,
+    named: Vec<&'s ImportSpecifier>,
+}
+
+impl<'s> Specifiers<'s> {
+    fn new(import: &'s ImportDeclaration) -> Self {
+        let mut specifiers = Self { default: None, namespace: None, named: vec![] };
+        for specifier in import.specifiers.iter().flatten() {
+            match specifier {
+                ImportDeclarationSpecifier::ImportDefaultSpecifier(default) => {
+                    specifiers.default = Some(default);
+                }
+                ImportDeclarationSpecifier::ImportNamespaceSpecifier(namespace) => {
+                    specifiers.namespace = Some(namespace);
+                }
+                ImportDeclarationSpecifier::ImportSpecifier(named) => specifiers.named.push(named),
+            }
+        }
+        specifiers
+    }
+}
+
+impl Rule for ConsistentTypeImports {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let config = value.get(0);
+        let option = |name: &str| config.and_then(|v| v.get(name)).and_then(|v| v.as_str());
+        let prefer = match option("prefer") {
+            Some("no-type-imports") => Prefer::NoTypeImports,
+            _ => Prefer::TypeImports,
+        };
+        let fix_style = match option("fixStyle") {
+            Some("inline-type-imports") => FixStyle::InlineTypeImports,
+            _ => FixStyle::SeparateTypeImports,
+        };
+        Self { prefer, fix_style }
+    }
+
+    fn run_once(&self, ctx: &LintContext<'_>) {
+        let Some(root) = ctx.nodes().iter().next() else { return };
+        let AstKind::Program(program) = root.kind() else { return };
+
+        let imports = program
+            .body
+            .iter()
+            .filter_map(|stmt| match stmt {
+                Statement::ModuleDeclaration(decl) => match &**decl {
+                    ModuleDeclaration::ImportDeclaration(import) => Some(&**import),
+                    _ => None,
+                },
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+
+        match self.prefer {
+            Prefer::TypeImports => self.check_type_imports(program, &imports, ctx),
+            Prefer::NoTypeImports => check_no_type_imports(&imports, ctx),
+        }
+    }
+}
+
+impl ConsistentTypeImports {
+    fn check_type_imports<'a>(
+        &self,
+        program: &Program<'a>,
+        imports: &[&ImportDeclaration<'a>],
+        ctx: &LintContext<'a>,
+    ) {
+        // The local exports, `export { A }` uses `A` as a value and `export type { A }` as a type
+        let exports = program
+            .body
+            .iter()
+            .filter_map(|stmt| match stmt {
+                Statement::ModuleDeclaration(decl) => match &**decl {
+                    ModuleDeclaration::ExportNamedDeclaration(export)
+                        if export.source.is_none() =>
+                    {
+                        Some(&**export)
+                    }
+                    _ => None,
+                },
+                _ => None,
+            })
+            .flat_map(|export| {
+                export.specifiers.iter().map(|specifier| {
+                    let is_type = export.export_kind.is_type() || specifier.export_kind.is_type();
+                    (specifier.local.name(), is_type)
+                })
+            })
+            .collect::<Vec<_>>();
+
+        for import in imports {
+            if import.import_kind.is_type() {
+                continue;
+            }
+            let Some(specifiers) = &import.specifiers else { continue };
+            let usages = specifiers
+                .iter()
+                .map(|specifier| (specifier.span(), specifier_usage(specifier, &exports, ctx)))
+                .collect::<Vec<_>>();
+            let type_specifiers = usages
+                .iter()
+                .filter(|(_, usage)| *usage == Usage::Type)
+                .map(|(span, _)| *span)
+                .collect::<Vec<_>>();
+            if type_specifiers.is_empty() {
+                continue;
+            }
+
+            let all_types =
+                usages.iter().all(|(_, usage)| matches!(usage, Usage::Type | Usage::InlineType));
+            let diagnostic = if all_types {
+                // `import type` can not be used with import attributes
+                if import.with_clause.is_some() {
+                    continue;
+                }
+                ConsistentTypeImportsDiagnostic::TypeOverValue(import.span)
+            } else {
+                let names = specifiers
+                    .iter()
+                    .filter(|specifier| type_specifiers.contains(&specifier.span()))
+                    .map(|specifier| format!("\"{}\"", local_binding(specifier).name))
+                    .collect::<Vec<_>>();
+                if names.len() == 1 {
+                    ConsistentTypeImportsDiagnostic::AImportIsOnlyTypes(
+                        format_word_list(&names),
+                        import.span,
+                    )
+                } else {
+                    ConsistentTypeImportsDiagnostic::SomeImportsAreOnlyTypes(
+                        format_word_list(&names),
+                        import.span,
+                    )
+                }
+            };
+            let source_imports = source_imports(imports, &import.source.value);
+            let mut edits = Edits::new(ctx.source_text());
+            self.fix_to_type_import(&mut edits, import, &type_specifiers, &source_imports);
+            match edits.into_fix(ctx) {
+                Some(fix) => ctx.diagnostic_with_fix(diagnostic, || fix),
+                None => ctx.diagnostic(diagnostic),
+            }
+        }
+    }
+
+    /// Move the specifiers only used as types to a type import
+    fn fix_to_type_import(
+        &self,
+        edits: &mut Edits,
+        import: &ImportDeclaration,
+        type_specifiers: &[Span],
+        source_imports: &SourceImports,
+    ) {
+        let is_type = |span: Span| type_specifiers.contains(&span);
+        let inline = self.fix_style == FixStyle::InlineTypeImports;
+        let Specifiers { default, namespace, named } = Specifiers::new(import);
+
+        match (default, namespace) {
+            // import * as A from 'a'
+            (None, Some(_)) => {
+                insert_type_keyword(edits, import, false);
+                return;
+            }
+            (Some(default), None) => {
+                // import A from 'a'
+                if is_type(default.span) && named.is_empty() {
+                    insert_type_keyword(edits, import, true);
+                    return;
+                }
+                // import A, { B, C } from 'a'
+                if inline && !is_type(default.span) && !named.is_empty() {
+                    insert_inline_type_keywords(edits, &named, type_specifiers, source_imports);
+                    return;
+                }
+            }
+            (None, None) => {
+                // import { A, B } from 'a'
+                if inline && named.iter().any(|specifier| is_type(specifier.span)) {
+                    insert_inline_type_keywords(edits, &named, type_specifiers, source_imports);
+                    return;
+                }
+                if named
+                    .iter()
+                    .all(|specifier| is_type(specifier.span) || specifier.import_kind.is_type())
+                {
+                    insert_type_keyword(edits, import, false);
+                    return;
+                }
+            }
+            (Some(_), Some(_)) => {}
+        }
+
+        let source = edits.text(import.source.span).to_string();
+        let type_named =
+            named.iter().filter(|specifier| is_type(specifier.span)).copied().collect::<Vec<_>>();
+        let (type_named_text, remove_type_named) =
+            named_specifiers_fixes(edits, &type_named, &named);
+        let mut after_edits = vec![];
+        if !type_named.is_empty() {
+            if let Some(type_import) = source_imports.type_only_named {
+                let edit = insert_named_specifiers(edits, type_import, &type_named_text);
+                if type_import.span.end <= import.span.start {
+                    edits.push(edit);
+                } else {
+                    after_edits.push(edit);
+                }
+            } else if inline {
+                let specifiers = type_named
+                    .iter()
+                    .map(|specifier| format!("type {}", edits.text(specifier.span)))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                edits
+                    .insert(import.span.start, format!("import {{{specifiers}}} from {source};\n"));
+            } else {
+                edits.insert(
+                    import.span.start,
+                    format!("import type {{{type_named_text}}} from {source};\n"),
+                );
+            }
+        }
+
+        let mut remove_namespace = None;
+        if let Some(namespace) = namespace.filter(|namespace| is_type(namespace.span)) {
+            // import A, * as B from 'a'
+            //         ^^^^^^^^ remove
+            let comma = edits.token_before(namespace.span.start);
+            remove_namespace = Some((Span::new(comma, namespace.span.end), String::new()));
+            let text = edits.text(namespace.span).to_string();
+            edits.insert(import.span.start, format!("import type {text} from {source};\n"));
+        }
+
+        if let Some(default) = default.filter(|default| is_type(default.span)) {
+            if type_specifiers.len() == import.specifiers.as_ref().map_or(0, |s| s.len()) {
+                edits.insert(import.span.start + 6, " type".to_string());
+            } else {
+                // import A, { B } from 'a'
+                //        ^^^ remove
+                let comma = edits.token_after(default.span.end);
+                let text = edits.text(Span::new(default.span.start, comma)).trim().to_string();
+                edits.insert(import.span.start, format!("import type {text} from {source};\n"));
+                let after = edits.token_after(comma + 1);
+                edits.remove(Span::new(default.span.start, after));
+            }
+        }
+
+        edits.extend(remove_type_named);
+        edits.extend(remove_namespace);
+        edits.extend(after_edits);
+    }
+}
+
+/// Report the type imports, and the inline type specifiers of the value imports
+fn check_no_type_imports(imports: &[&ImportDeclaration], ctx: &LintContext) {
+    for import in imports {
+        if import.import_kind.is_type() {
+            // import type A from 'a'
+            //        ^^^^^ remove
+            let mut edits = Edits::new(ctx.source_text());
+            let type_start = edits.token_after(import.span.start + 6);
+            let after = edits.token_after(type_start + 4);
+            edits.remove(Span::new(type_start, after));
+            let diagnostic = ConsistentTypeImportsDiagnostic::AvoidImportType(import.span);
+            match edits.into_fix(ctx) {
+                Some(fix) => ctx.diagnostic_with_fix(diagnostic, || fix),
+                None => ctx.diagnostic(diagnostic),
+            }
+            continue;
+        }
+        for specifier in import.specifiers.iter().flatten() {
+            let ImportDeclarationSpecifier::ImportSpecifier(specifier) = specifier else {
+                continue;
+            };
+            if !specifier.import_kind.is_type() {
+                continue;
+            }
+            let diagnostic = ConsistentTypeImportsDiagnostic::AvoidImportType(specifier.span);
+            let mut edits = Edits::new(ctx.source_text());
+            remove_inline_type_keyword(&mut edits, specifier);
+            match edits.into_fix(ctx) {
+                Some(fix) => ctx.diagnostic_with_fix(diagnostic, || fix),
+                None => ctx.diagnostic(diagnostic),
+            }
+        }
+    }
+}
+
+/// How the binding of `specifier` is used by the references and the local exports
+fn specifier_usage(
+    specifier: &ImportDeclarationSpecifier,
+    exports: &[(&Atom, bool)],
+    ctx: &LintContext,
+) -> Usage {
+    if let ImportDeclarationSpecifier::ImportSpecifier(specifier) = specifier {
+        if specifier.import_kind.is_type() {
+            return Usage::InlineType;
+        }
+    }
+    let local = local_binding(specifier);
+    let mut has_type = false;
+    let mut has_value = false;
+    if let Some(symbol_id) = local.symbol_id.get() {
+        for reference in ctx.semantic().symbol_references(symbol_id) {
+            if matches!(
+                ctx.nodes().parent_kind(reference.node_id()),
+                Some(AstKind::TSTypeReference(_))
+            ) {
+                has_type = true;
+            } else {
+                has_value = true;
+            }
+        }
+    }
+    for (_, is_type) in exports.iter().filter(|(name, _)| **name == local.name) {
+        if *is_type {
+            has_type = true;
+        } else {
+            has_value = true;
+        }
+    }
+    if has_value {
+        Usage::Value
+    } else if has_type {
+        Usage::Type
+    } else {
+        Usage::Unused
+    }
+}
+
+fn local_binding(specifier: &ImportDeclarationSpecifier) -> &BindingIdentifier {
+    match specifier {
+        ImportDeclarationSpecifier::ImportSpecifier(specifier) => &specifier.local,
+        ImportDeclarationSpecifier::ImportDefaultSpecifier(specifier) => &specifier.local,
+        ImportDeclarationSpecifier::ImportNamespaceSpecifier(specifier) => &specifier.local,
+    }
+}
+
+fn source_imports<'s, 'a>(
+    imports: &[&'s ImportDeclaration<'a>],
+    source: &Atom,
+) -> SourceImports<'s, 'a> {
+    let mut source_imports = SourceImports::default();
+    for import in imports.iter().filter(|import| import.source.value == *source) {
+        let specifiers = Specifiers::new(import);
+        let only_named = specifiers.default.is_none() && specifiers.namespace.is_none();
+        if import.import_kind.is_type() {
+            if source_imports.type_only_named.is_none() && only_named {
+                source_imports.type_only_named = Some(import);
+            }
+        } else if source_imports.value_only_named.is_none()
+            && only_named
+            && !specifiers.named.is_empty()
+        {
+            source_imports.value_only_named = Some(import);
+            source_imports.value = Some(import);
+        } else if source_imports.value.is_none() && specifiers.default.is_some() {
+            source_imports.value = Some(import);
+        }
+    }
+    source_imports
+}
+
+/// `import { A } from 'a'` to `import type { A } from 'a'`
+fn insert_type_keyword(edits: &mut Edits, import: &ImportDeclaration, is_default: bool) {
+    edits.insert(import.span.start + 6, " type".to_string());
+    if is_default {
+        // import A, {} from 'a'
+        //         ^^^^ remove
+        let between = Span::new(import.span.start + 6, import.source.span.start);
+        if let Some(offset) = edits.text(between).find('{') {
+            let open = between.start + u32::try_from(offset).unwrap_or_default();
+            let comma = edits.token_before(open);
+            let close = edits.token_after(open + 1);
+            edits.remove(Span::new(comma, close + 1));
+        }
+    }
+    // Avoid `import type { type A } from 'a'`
+    for specifier in Specifiers::new(import).named {
+        if specifier.import_kind.is_type() {
+            remove_inline_type_keyword(edits, specifier);
+        }
+    }
+}
+
+/// `import { A, B } from 'a'` to `import { type A, B } from 'a'`
+fn insert_inline_type_keywords(
+    edits: &mut Edits,
+    named: &[&ImportSpecifier],
+    type_specifiers: &[Span],
+    source_imports: &SourceImports,
+) {
+    let Some(value_import) = source_imports.value else { return };
+    if source_imports.value_only_named.is_none() && Specifiers::new(value_import).named.is_empty() {
+        return;
+    }
+    for specifier in named.iter().filter(|specifier| type_specifiers.contains(&specifier.span)) {
+        edits.insert(specifier.span.start, "type ".to_string());
+    }
+}
+
+fn remove_inline_type_keyword(edits: &mut Edits, specifier: &ImportSpecifier) {
+    edits.remove(Span::new(specifier.span.start, specifier.imported.span().start));
+}
+
+/// The text of the `subset` of the `named` specifiers, and the edits removing them
+fn named_specifiers_fixes(
+    edits: &Edits,
+    subset: &[&ImportSpecifier],
+    named: &[&ImportSpecifier],
+) -> (String, Vec<(Span, String)>) {
+    let (Some(first), Some(last)) = (named.first(), named.last()) else {
+        return (String::new(), vec![]);
+    };
+    if subset.len() == named.len() {
+        // import A, { B, C } from 'a'
+        //         ^^^^^^^^^^ remove
+        let open = edits.token_before(first.span.start);
+        let comma = edits.token_before(open);
+        let mut close = edits.token_after(last.span.end);
+        if edits.char_at(close) == ',' {
+            close = edits.token_after(close + 1);
+        }
+        let text = edits.text(Span::new(open + 1, close)).to_string();
+        return (text, vec![(Span::new(comma, close + 1), String::new())]);
+    }
+
+    let mut groups: Vec<Vec<&ImportSpecifier>> = vec![];
+    let mut group = vec![];
+    for specifier in named {
+        if subset.iter().any(|s| s.span == specifier.span) {
+            group.push(*specifier);
+        } else if !group.is_empty() {
+            groups.push(std::mem::take(&mut group));
+        }
+    }
+    if !group.is_empty() {
+        groups.push(group);
+    }
+
+    let mut texts = vec![];
+    let mut removals = vec![];
+    for group in groups {
+        let (group_first, group_last) = (group[0], group[group.len() - 1]);
+        let before = edits.token_before(group_first.span.start);
+        let text_start = before + 1;
+        let remove_start = if edits.char_at(before) == ',' { before } else { before + 1 };
+        let after = edits.token_after(group_last.span.end);
+        let remove_end = if (group_first.span == first.span || group_last.span == last.span)
+            && edits.char_at(after) == ','
+        {
+            after + 1
+        } else {
+            group_last.span.end
+        };
+        texts.push(edits.text(Span::new(text_start, after)).to_string());
+        removals.push((Span::new(remove_start, remove_end), String::new()));
+    }
+    (texts.join(","), removals)
+}
+
+/// Append `text` to the named specifiers of `import`
+fn insert_named_specifiers(
+    edits: &Edits,
+    import: &ImportDeclaration,
+    text: &str,
+) -> (Span, String) {
+    let between = Span::new(import.span.start, import.source.span.start);
+    let offset = edits.text(between).rfind('}').unwrap_or_default();
+    let close = between.start + u32::try_from(offset).unwrap_or_default();
+    let before_offset = edits.token_before(close);
+    let before = edits.char_at(before_offset);
+    if before == ',' || before == '{' {
+        return (Span::new(close, close), text.to_string());
+    }
+    // `{ A }` to `{ A, B}`, the comma right after the last specifier
+    let offset = before_offset + u32::try_from(before.len_utf8()).unwrap_or_default();
+    (Span::new(offset, close), format!(",{text}"))
+}
+
+/// `"A"`, `"A" and "B"`, `"A", "B" and "C"`
+fn format_word_list(words: &[String]) -> String {
+    match words.split_last() {
+        Some((last, rest)) if !rest.is_empty() => format!("{} and {last}", rest.join(", ")),
+        Some((last, _)) => last.clone(),
+        None => String::new(),
+    }
+}
+
+/// Text edits of a fix, merged into a single replacement
+struct Edits<'a> {
+    source_text: &'a str,
+    edits: Vec<(Span, String)>,
+}
+
+impl<'a> Edits<'a> {
+    fn new(source_text: &'a str) -> Self {
+        Self { source_text, edits: vec![] }
+    }
+
+    fn text(&self, span: Span) -> &'a str {
+        span.source_text(self.source_text)
+    }
+
+    fn char_at(&self, offset: u32) -> char {
+        self.source_text[offset as usize..].chars().next().unwrap_or_default()
+    }
+
+    /// The offset of the first token starting at or after `offset`
+    fn token_after(&self, offset: u32) -> u32 {
+        let rest = &self.source_text[offset as usize..];
+        let skipped = rest.len() - rest.trim_start().len();
+        offset + u32::try_from(skipped).unwrap_or_default()
+    }
+
+    /// The offset of the last character of the token ending at or before `offset`
+    fn token_before(&self, offset: u32) -> u32 {
+        let before = self.source_text[..offset as usize].trim_end();
+        let last = before.chars().next_back().map_or(0, char::len_utf8);
+        u32::try_from(before.len() - last).unwrap_or_default()
+    }
+
+    fn push(&mut self, edit: (Span, String)) {
+        self.edits.push(edit);
+    }
+
+    fn extend<I: IntoIterator<Item = (Span, String)>>(&mut self, edits: I) {
+        self.edits.extend(edits);
+    }
+
+    fn insert(&mut self, offset: u32, text: String) {
+        self.edits.push((Span::new(offset, offset), text));
+    }
+
+    fn remove(&mut self, span: Span) {
+        self.edits.push((span, String::new()));
+    }
+
+    /// `None` when the edited code has comments, which could be moved or removed
+    fn into_fix(mut self, ctx: &LintContext) -> Option<Fix<'a>> {
+        self.edits.sort_by_key(|(span, _)| span.start);
+        let start = self.edits.iter().map(|(span, _)| span.start).min()?;
+        let end = self.edits.iter().map(|(span, _)| span.end).max()?;
+        let span = Span::new(start, end);
+        if ctx.semantic().trivias().has_comments_between(span) {
+            return None;
+        }
+        let mut content = String::new();
+        let mut cursor = start;
+        for (span, text) in &self.edits {
+            if span.start > cursor {
+                content.push_str(&self.source_text[cursor as usize..span.start as usize]);
+            }
+            content.push_str(text);
+            cursor = cursor.max(span.end);
+        }
+        content.push_str(&self.source_text[cursor as usize..end as usize]);
+        Some(Fix::new(content, span))
+    }
+}
+
+#[test]
+fn test() {
+    use crate::{tester::Tester, FixKind};
+
+    let no_type_imports = || Some(serde_json::json!([{ "prefer": "no-type-imports" }]));
+    let inline = || Some(serde_json::json!([{ "fixStyle": "inline-type-imports" }]));
+
+    let pass = vec![
+        ("import Foo from 'foo'; const foo: Foo = new Foo();", None),
+        ("import foo from 'foo'; const bar: foo.Foo = foo.fn();", None),
+        ("import { A, B } from 'foo'; const foo: A = B(); const bar = new A();", None),
+        ("import Foo from 'foo';", None),
+        ("import Foo from 'foo'; function fn() { type Foo = {}; let foo: Foo; }", None),
+        ("import type Type from 'foo'; type T = typeof Type;", None),
+        ("import type { Type } from 'foo'; let t: Type;", None),
+        ("import { type Type } from 'foo'; let t: Type;", None),
+        ("import * as Type from 'foo'; let t: Type.Foo = Type.bar;", None),
+        ("import { A } from 'foo'; class B extends A {}", None),
+        ("import { A } from 'foo'; let a: A = A;", None),
+        ("import Foo from 'foo'; const foo = <Foo />;", None),
+        // The local exports are value uses, unless they are type only
+        ("import Type from 'foo'; export { Type };", None),
+        ("import Type from 'foo'; export default Type;", None),
+        ("import type Type from 'foo'; export { Type };", None),
+        ("import type Type from 'foo'; export type { Type };", None),
+        // `import type` can not be used with import attributes
+        ("import * as Type from 'foo' with { type: 'json' }; let t: Type;", None),
+        ("import Foo from 'foo'; let foo: Foo;", no_type_imports()),
+        ("import { A, B } from 'foo'; let a: A; B();", no_type_imports()),
+        ("import * as Type from 'foo'; let t: Type;", no_type_imports()),
+    ];
+
+    let fail = vec![
+        ("import Foo from 'foo'; let foo: Foo; type Bar = Foo;", None),
+        ("import { A, B } from 'foo'; let foo: A; let bar: B;", None),
+        ("import { A as B } from 'foo'; let b: B;", None),
+        ("import { A } from 'foo'; let a: Array<A>;", None),
+        ("import * as A from 'foo'; let a: A;", None),
+        ("import Foo, {} from 'foo'; let foo: Foo;", None),
+        ("import Foo, { Bar } from 'foo'; let a: Foo; let b: Bar;", None),
+        ("import Foo, * as Bar from 'foo'; let a: Foo; let b: Bar;", None),
+        ("import { type A, B } from 'foo'; let b: B;", None),
+        ("import Type from 'foo'; export type { Type };", None),
+        ("import Type from 'foo'; export { type Type };", None),
+        ("import { A, B } from 'foo'; let foo: A; B();", None),
+        ("import { A, B } from 'foo'; let a: A;", None),
+        ("import { A, B, C } from 'foo'; let a: A; let c: C; B();", None),
+        ("import Foo, { Bar } from 'foo'; let a: Foo; Bar();", None),
+        ("import Foo, { Bar } from 'foo'; Foo(); let b: Bar;", None),
+        ("import Foo, * as Bar from 'foo'; Foo(); let b: Bar;", None),
+        ("import Foo, * as Bar from 'foo'; let a: Foo; Bar.baz();", None),
+        ("import type { A } from 'foo'; import { B, C } from 'foo'; let b: B; C();", None),
+        ("import { B, C } from 'foo'; import type { A } from 'foo'; let b: B; C();", None),
+        ("import type { A } from 'foo'; import { B } from 'foo'; let b: B;", None),
+        ("import { A, /* B */ C } from 'foo'; let a: A; C();", None),
+        ("import { A, B } from 'foo'; let a: A; B();", inline()),
+        ("import { A, B } from 'foo'; let a: A; let b: B;", inline()),
+        ("import A, { B, C } from 'foo'; A(); let b: B; C();", inline()),
+        ("import A, { B } from 'foo'; let a: A; B();", inline()),
+        ("import A, { B, C } from 'foo'; let a: A; let b: B; C();", inline()),
+        ("import type Foo from 'foo'; let foo: Foo;", no_type_imports()),
+        ("import type { Foo } from 'foo'; let foo: Foo;", no_type_imports()),
+        ("import type * as Foo from 'foo'; let foo: Foo;", no_type_imports()),
+        ("import { type Foo, Bar } from 'foo'; let foo: Foo; Bar();", no_type_imports()),
+    ];
+
+    let fix = vec![
+        (
+            "import Foo from 'foo'; let foo: Foo; type Bar = Foo;",
+            "import type Foo from 'foo'; let foo: Foo; type Bar = Foo;",
+            None,
+        ),
+        (
+            "import { A, B } from 'foo'; let foo: A; let bar: B;",
+            "import type { A, B } from 'foo'; let foo: A; let bar: B;",
+            None,
+        ),
+        ("import { A as B } from 'foo'; let b: B;", "import type { A as B } from 'foo'; let b: B;", None),
+        ("import * as A from 'foo'; let a: A;", "import type * as A from 'foo'; let a: A;", None),
+        ("import Foo, {} from 'foo'; let foo: Foo;", "import type Foo from 'foo'; let foo: Foo;", None),
+        (
+            "import Foo, { Bar } from 'foo'; let a: Foo; let b: Bar;",
+            "import type { Bar } from 'foo';\nimport type Foo from 'foo'; let a: Foo; let b: Bar;",
+            None,
+        ),
+        (
+            "import Foo, * as Bar from 'foo'; let a: Foo; let b: Bar;",
+            "import type * as Bar from 'foo';\nimport type Foo from 'foo'; let a: Foo; let b: Bar;",
+            None,
+        ),
+        (
+            "import { type A, B } from 'foo'; let b: B;",
+            "import type { A, B } from 'foo'; let b: B;",
+            None,
+        ),
+        (
+            "import Type from 'foo'; export type { Type };",
+            "import type Type from 'foo'; export type { Type };",
+            None,
+        ),
+        (
+            "import { A, B } from 'foo'; let foo: A; B();",
+            "import type { A} from 'foo';\nimport { B } from 'foo'; let foo: A; B();",
+            None,
+        ),
+        (
+            "import { A, B } from 'foo'; let a: A;",
+            "import type { A} from 'foo';\nimport { B } from 'foo'; let a: A;",
+            None,
+        ),
+        (
+            "import { A, B, C } from 'foo'; let a: A; let c: C; B();",
+            "import type { A, C } from 'foo';\nimport { B } from 'foo'; let a: A; let c: C; B();",
+            None,
+        ),
+        (
+            "import Foo, { Bar } from 'foo'; let a: Foo; Bar();",
+            "import type Foo from 'foo';\nimport { Bar } from 'foo'; let a: Foo; Bar();",
+            None,
+        ),
+        (
+            "import Foo, { Bar } from 'foo'; Foo(); let b: Bar;",
+            "import type { Bar } from 'foo';\nimport Foo from 'foo'; Foo(); let b: Bar;",
+            None,
+        ),
+        (
+            "import Foo, * as Bar from 'foo'; Foo(); let b: Bar;",
+            "import type * as Bar from 'foo';\nimport Foo from 'foo'; Foo(); let b: Bar;",
+            None,
+        ),
+        (
+            "import Foo, * as Bar from 'foo'; let a: Foo; Bar.baz();",
+            "import type Foo from 'foo';\nimport * as Bar from 'foo'; let a: Foo; Bar.baz();",
+            None,
+        ),
+        (
+            "import type { A } from 'foo'; import { B, C } from 'foo'; let b: B; C();",
+            "import type { A, B} from 'foo'; import { C } from 'foo'; let b: B; C();",
+            None,
+        ),
+        (
+            "import { B, C } from 'foo'; import type { A } from 'foo'; let b: B; C();",
+            "import { C } from 'foo'; import type { A, B} from 'foo'; let b: B; C();",
+            None,
+        ),
+        (
+            "import type { A } from 'foo'; import { B } from 'foo'; let b: B;",
+            "import type { A } from 'foo'; import type { B } from 'foo'; let b: B;",
+            None,
+        ),
+        (
+            "import { A, B } from 'foo'; let a: A; B();",
+            "import { type A, B } from 'foo'; let a: A; B();",
+            inline(),
+        ),
+        (
+            "import { A, B } from 'foo'; let a: A; let b: B;",
+            "import { type A, type B } from 'foo'; let a: A; let b: B;",
+            inline(),
+        ),
+        (
+            "import A, { B, C } from 'foo'; A(); let b: B; C();",
+            "import A, { type B, C } from 'foo'; A(); let b: B; C();",
+            inline(),
+        ),
+        (
+            "import A, { B } from 'foo'; let a: A; B();",
+            "import type A from 'foo';\nimport { B } from 'foo'; let a: A; B();",
+            inline(),
+        ),
+        (
+            "import A, { B, C } from 'foo'; let a: A; let b: B; C();",
+            "import {type B} from 'foo';\nimport type A from 'foo';\nimport { C } from 'foo'; let a: A; let b: B; C();",
+            inline(),
+        ),
+        (
+            "import type Foo from 'foo'; let foo: Foo;",
+            "import Foo from 'foo'; let foo: Foo;",
+            no_type_imports(),
+        ),
+        (
+            "import type { Foo } from 'foo'; let foo: Foo;",
+            "import { Foo } from 'foo'; let foo: Foo;",
+            no_type_imports(),
+        ),
+        (
+            "import type * as Foo from 'foo'; let foo: Foo;",
+            "import * as Foo from 'foo'; let foo: Foo;",
+            no_type_imports(),
+        ),
+        (
+            "import { type Foo, Bar } from 'foo'; let foo: Foo; Bar();",
+            "import { Foo, Bar } from 'foo'; let foo: Foo; Bar();",
+            no_type_imports(),
+        ),
+    ];
+
+    // With `verbatimModuleSyntax`, type imports are removed from the output and value imports are
+    // kept, so changing the kind of an import drops or adds the loading of its module
+    Tester::new(ConsistentTypeImports::NAME, pass, fail)
+        .expect_fix(fix)
+        .expect_fix_kind(FixKind::Dangerous)
+        .test_and_snapshot();
+}
@@ -0,0 +1,497 @@
+use oxc_ast::{
+    ast::{
+        BindingPatternKind, ChainElement, Expression, TSLiteral, TSType, TSTypeAnnotation,
+        TSTypeName,
+    },
+    AstKind,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+use oxc_syntax::operator::UnaryOperator;
+
+use crate::{
+    context::LintContext,
+    fixer::{Fix, FixKind},
+    rule::Rule,
+    AstNode,
+};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error(
+    "typescript-eslint(no-inferrable-types): Type {0} trivially inferred from a {0} literal, remove type annotation."
+)]
+#[diagnostic(severity(warning))]
+struct NoInferrableTypesDiagnostic(&'static str, #[label] Span);
+
+#[derive(Debug, Default, Clone)]
+pub struct NoInferrableTypes {
+    /// Ignore the parameters with a default value
+    ignore_parameters: bool,
+    /// Ignore the class properties
+    ignore_properties: bool,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow explicit type declarations for variables or parameters initialized to a number,
+    /// string, or boolean
+    ///
+    /// ### Why is this bad?
+    ///
+    /// The type of a variable, a parameter with a default value, or a class property initialized
+    /// to a literal is trivially inferred by TypeScript, the explicit annotation only adds
+    /// noise. The parameters and the properties are ignored with the `ignoreParameters` and
+    /// `ignoreProperties` options.
+    ///
+    /// ### Example
+    /// ```typescript
+    /// // fail
+    /// const a: bigint = 10n;
+    /// const b: number = -10;
+    /// const c: string = 'str';
+    /// function fn(d: boolean = true) {}
+    ///
+    /// // pass
+    /// const a = 10n;
+    /// const b = -10;
+    /// const c = 'str';
+    /// function fn(d = true) {}
+    /// ```
+    NoInferrableTypes,
+    nursery,
+    fix
+);
+
+impl Rule for NoInferrableTypes {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let option = |name: &str| {
+            value
+                .get(0)
+                .and_then(|v| v.get(name))
+                .and_then(serde_json::Value::as_bool)
+                .unwrap_or_default()
+        };
+        Self {
+            ignore_parameters: option("ignoreParameters"),
+            ignore_properties: option("ignoreProperties"),
+        }
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        match node.kind() {
+            AstKind::VariableDeclarator(decl) => {
+                let (Some(annotation), Some(init)) = (&decl.id.type_annotation, &decl.init) else {
+                    return;
+                };
+                report_inferrable_type(decl.span, annotation, init, false, ctx);
+            }
+            AstKind::FormalParameter(param) if !self.ignore_parameters => {
+                let BindingPatternKind::AssignmentPattern(pattern) = &param.pattern.kind else {
+                    return;
+                };
+                let Some(annotation) = &pattern.left.type_annotation else { return };
+                let optional = pattern.left.optional;
+                report_inferrable_type(pattern.span, annotation, &pattern.right, optional, ctx);
+            }
+            AstKind::PropertyDefinition(prop) if !self.ignore_properties => {
+                if prop.readonly || prop.optional {
+                    return;
+                }
+                let (Some(annotation), Some(value)) = (&prop.type_annotation, &prop.value) else {
+                    return;
+                };
+                report_inferrable_type(prop.span, annotation, value, prop.definite, ctx);
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Report `annotation` if it is inferrable from `init`, the fix also removes the `?` or `!`
+/// before the annotation when `has_modifier`
+fn report_inferrable_type(
+    span: Span,
+    annotation: &TSTypeAnnotation,
+    init: &Expression,
+    has_modifier: bool,
+    ctx: &LintContext,
+) {
+    let Some(type_name) = inferrable_type_name(&annotation.type_annotation, init) else { return };
+    ctx.diagnostic_with_fix(NoInferrableTypesDiagnostic(type_name, span), || {
+        let start = if has_modifier { annotation.span.start - 1 } else { annotation.span.start };
+        Fix::delete(Span::new(start, annotation.span.end)).with_kind(FixKind::Safe)
+    });
+}
+
+/// The name of `ty` if it is inferred from `init`
+fn inferrable_type_name(ty: &TSType, init: &Expression) -> Option<&'static str> {
+    let init = init.without_parenthesized();
+    match ty {
+        TSType::TSBigIntKeyword(_) => {
+            let init = unary_argument(init, &[UnaryOperator::UnaryNegation]).unwrap_or(init);
+            (matches!(init, Expression::BigintLiteral(_)) || is_function_call(init, "BigInt"))
+                .then_some("bigint")
+        }
+        TSType::TSBooleanKeyword(_) => {
+            let is_boolean = unary_argument(init, &[UnaryOperator::LogicalNot]).is_some()
+                || is_function_call(init, "Boolean")
+                || matches!(init, Expression::BooleanLiteral(_));
+            is_boolean.then_some("boolean")
+        }
+        TSType::TSNumberKeyword(_) => {
+            let operators = [UnaryOperator::UnaryPlus, UnaryOperator::UnaryNegation];
+            let init = unary_argument(init, &operators).unwrap_or(init);
+            let is_number = is_function_call(init, "Number")
+                || matches!(init, Expression::NumberLiteral(_))
+                || init.is_specific_id("Infinity")
+                || init.is_specific_id("NaN");
+            is_number.then_some("number")
+        }
+        TSType::TSNullKeyword(_) => init.is_null().then_some("null"),
+        // `null` is parsed as a literal type
+        TSType::TSLiteralType(literal) if matches!(literal.literal, TSLiteral::NullLiteral(_)) => {
+            init.is_null().then_some("null")
+        }
+        TSType::TSStringKeyword(_) => {
+            (is_function_call(init, "String") || init.is_string_literal()).then_some("string")
+        }
+        TSType::TSSymbolKeyword(_) => is_function_call(init, "Symbol").then_some("symbol"),
+        TSType::TSTypeReference(reference) => {
+            let TSTypeName::IdentifierReference(name) = &reference.type_name else { return None };
+            if name.name != "RegExp" {
+                return None;
+            }
+            let is_regexp = match init {
+                Expression::RegExpLiteral(_) => true,
+                Expression::NewExpression(expr) => expr.callee.is_specific_id("RegExp"),
+                _ => is_function_call(init, "RegExp"),
+            };
+            is_regexp.then_some("RegExp")
+        }
+        TSType::TSUndefinedKeyword(_) => (unary_argument(init, &[UnaryOperator::Void]).is_some()
+            || init.is_undefined())
+        .then_some("undefined"),
+        _ => None,
+    }
+}
+
+/// The argument of `expr` if it is a unary expression with one of `operators`
+fn unary_argument<'a, 'b>(
+    expr: &'b Expression<'a>,
+    operators: &[UnaryOperator],
+) -> Option<&'b Expression<'a>> {
+    match expr {
+        Expression::UnaryExpression(expr) if operators.contains(&expr.operator) => {
+            Some(expr.argument.without_parenthesized())
+        }
+        _ => None,
+    }
+}
+
+/// `name(...)` or `name?.(...)`
+fn is_function_call(expr: &Expression, name: &str) -> bool {
+    match expr {
+        Expression::CallExpression(call) => call.callee.is_specific_id(name),
+        Expression::ChainExpression(chain) => match &chain.expression {
+            ChainElement::CallExpression(call) => call.callee.is_specific_id(name),
+            ChainElement::MemberExpression(_) => false,
+        },
+        _ => false,
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("const a = 10n;", None),
+        ("const fn = (a = 10n) => {};", None),
+        ("class Foo { a = 10n; }", None),
+        ("const a = -10n;", None),
+        ("const fn = (a = -10n) => {};", None),
+        ("class Foo { a = -10n; }", None),
+        ("const a = BigInt(10);", None),
+        ("const fn = (a = BigInt(10)) => {};", None),
+        ("class Foo { a = BigInt(10); }", None),
+        ("const a = -BigInt(10);", None),
+        ("const fn = (a = -BigInt(10)) => {};", None),
+        ("class Foo { a = -BigInt(10); }", None),
+        ("const a = BigInt?.(10);", None),
+        ("const fn = (a = BigInt?.(10)) => {};", None),
+        ("class Foo { a = BigInt?.(10); }", None),
+        ("const a = -BigInt?.(10);", None),
+        ("const fn = (a = -BigInt?.(10)) => {};", None),
+        ("class Foo { a = -BigInt?.(10); }", None),
+        ("const a = false;", None),
+        ("const fn = (a = false) => {};", None),
+        ("class Foo { a = false; }", None),
+        ("const a = true;", None),
+        ("const fn = (a = true) => {};", None),
+        ("class Foo { a = true; }", None),
+        ("const a = Boolean(null);", None),
+        ("const fn = (a = Boolean(null)) => {};", None),
+        ("class Foo { a = Boolean(null); }", None),
+        ("const a = Boolean?.(null);", None),
+        ("const fn = (a = Boolean?.(null)) => {};", None),
+        ("class Foo { a = Boolean?.(null); }", None),
+        ("const a = !0;", None),
+        ("const fn = (a = !0) => {};", None),
+        ("class Foo { a = !0; }", None),
+        ("const a = 10;", None),
+        ("const fn = (a = 10) => {};", None),
+        ("class Foo { a = 10; }", None),
+        ("const a = +10;", None),
+        ("const fn = (a = +10) => {};", None),
+        ("class Foo { a = +10; }", None),
+        ("const a = -10;", None),
+        ("const fn = (a = -10) => {};", None),
+        ("class Foo { a = -10; }", None),
+        ("const a = Number(\"1\");", None),
+        ("const fn = (a = Number(\"1\")) => {};", None),
+        ("class Foo { a = Number(\"1\"); }", None),
+        ("const a = +Number(\"1\");", None),
+        ("const fn = (a = +Number(\"1\")) => {};", None),
+        ("class Foo { a = +Number(\"1\"); }", None),
+        ("const a = -Number(\"1\");", None),
+        ("const fn = (a = -Number(\"1\")) => {};", None),
+        ("class Foo { a = -Number(\"1\"); }", None),
+        ("const a = Number?.(\"1\");", None),
+        ("const fn = (a = Number?.(\"1\")) => {};", None),
+        ("class Foo { a = Number?.(\"1\"); }", None),
+        ("const a = +Number?.(\"1\");", None),
+        ("const fn = (a = +Number?.(\"1\")) => {};", None),
+        ("class Foo { a = +Number?.(\"1\"); }", None),
+        ("const a = -Number?.(\"1\");", None),
+        ("const fn = (a = -Number?.(\"1\")) => {};", None),
+        ("class Foo { a = -Number?.(\"1\"); }", None),
+        ("const a = Infinity;", None),
+        ("const fn = (a = Infinity) => {};", None),
+        ("class Foo { a = Infinity; }", None),
+        ("const a = +Infinity;", None),
+        ("const fn = (a = +Infinity) => {};", None),
+        ("class Foo { a = +Infinity; }", None),
+        ("const a = -Infinity;", None),
+        ("const fn = (a = -Infinity) => {};", None),
+        ("class Foo { a = -Infinity; }", None),
+        ("const a = NaN;", None),
+        ("const fn = (a = NaN) => {};", None),
+        ("class Foo { a = NaN; }", None),
+        ("const a = +NaN;", None),
+        ("const fn = (a = +NaN) => {};", None),
+        ("class Foo { a = +NaN; }", None),
+        ("const a = -NaN;", None),
+        ("const fn = (a = -NaN) => {};", None),
+        ("class Foo { a = -NaN; }", None),
+        ("const a = null;", None),
+        ("const fn = (a = null) => {};", None),
+        ("class Foo { a = null; }", None),
+        ("const a = /a/;", None),
+        ("const fn = (a = /a/) => {};", None),
+        ("class Foo { a = /a/; }", None),
+        ("const a = RegExp(\"a\");", None),
+        ("const fn = (a = RegExp(\"a\")) => {};", None),
+        ("class Foo { a = RegExp(\"a\"); }", None),
+        ("const a = RegExp?.(\"a\");", None),
+        ("const fn = (a = RegExp?.(\"a\")) => {};", None),
+        ("class Foo { a = RegExp?.(\"a\"); }", None),
+        ("const a = new RegExp(\"a\");", None),
+        ("const fn = (a = new RegExp(\"a\")) => {};", None),
+        ("class Foo { a = new RegExp(\"a\"); }", None),
+        ("const a = \"str\";", None),
+        ("const fn = (a = \"str\") => {};", None),
+        ("class Foo { a = \"str\"; }", None),
+        ("const a = 'str';", None),
+        ("const fn = (a = 'str') => {};", None),
+        ("class Foo { a = 'str'; }", None),
+        ("const a = `str`;", None),
+        ("const fn = (a = `str`) => {};", None),
+        ("class Foo { a = `str`; }", None),
+        ("const a = String(1);", None),
+        ("const fn = (a = String(1)) => {};", None),
+        ("class Foo { a = String(1); }", None),
+        ("const a = String?.(1);", None),
+        ("const fn = (a = String?.(1)) => {};", None),
+        ("class Foo { a = String?.(1); }", None),
+        ("const a = Symbol(\"a\");", None),
+        ("const fn = (a = Symbol(\"a\")) => {};", None),
+        ("class Foo { a = Symbol(\"a\"); }", None),
+        ("const a = Symbol?.(\"a\");", None),
+        ("const fn = (a = Symbol?.(\"a\")) => {};", None),
+        ("class Foo { a = Symbol?.(\"a\"); }", None),
+        ("const a = undefined;", None),
+        ("const fn = (a = undefined) => {};", None),
+        ("class Foo { a = undefined; }", None),
+        ("const a = void someValue;", None),
+        ("const fn = (a = void someValue) => {};", None),
+        ("class Foo { a = void someValue; }", None),
+        ("const a: any = 5;", None),
+        ("const fn = function (a: any = 5, b: any = true, c: any = 'foo') {};", None),
+        ("const a: number = someValue;", None),
+        ("const a: string = foo();", None),
+        ("const a: number = ~10;", None),
+        ("const a: number = Number.parseInt('1');", None),
+        ("const a: undefined = null;", None),
+        ("const a: string = 10;", None),
+        ("const a: Foo = new Foo();", None),
+        ("const a: RegExp = new Foo('a');", None),
+        ("let a: number;", None),
+        ("class Foo { a: number; }", None),
+        ("class Foo { readonly a: number = 5; }", None),
+        ("class Foo { a?: number = 5; }", None),
+        (
+            "const fn = (a: number = 5, b: boolean = true, c: string = 'foo') => {};",
+            Some(serde_json::json!([{ "ignoreParameters": true }])),
+        ),
+        (
+            "function fn(a: number = 5, b: boolean = true, c: string = 'foo') {}",
+            Some(serde_json::json!([{ "ignoreParameters": true }])),
+        ),
+        (
+            "class Foo { constructor(public a: boolean = true) {} }",
+            Some(serde_json::json!([{ "ignoreParameters": true }])),
+        ),
+        (
+            "class Foo { a: number = 5; b: boolean = true; c: string = 'foo'; }",
+            Some(serde_json::json!([{ "ignoreProperties": true }])),
+        ),
+    ];
+
+    let fail = vec![
+        ("const a: bigint = 10n;", None),
+        ("const a: bigint = -10n;", None),
+        ("const a: bigint = BigInt(10);", None),
+        ("const a: bigint = -BigInt(10);", None),
+        ("const a: bigint = BigInt?.(10);", None),
+        ("const a: bigint = -BigInt?.(10);", None),
+        ("const a: boolean = false;", None),
+        ("const a: boolean = true;", None),
+        ("const a: boolean = Boolean(null);", None),
+        ("const a: boolean = Boolean?.(null);", None),
+        ("const a: boolean = !0;", None),
+        ("const a: number = 10;", None),
+        ("const a: number = +10;", None),
+        ("const a: number = -10;", None),
+        ("const a: number = Number(\"1\");", None),
+        ("const a: number = +Number(\"1\");", None),
+        ("const a: number = -Number(\"1\");", None),
+        ("const a: number = Number?.(\"1\");", None),
+        ("const a: number = +Number?.(\"1\");", None),
+        ("const a: number = -Number?.(\"1\");", None),
+        ("const a: number = Infinity;", None),
+        ("const a: number = +Infinity;", None),
+        ("const a: number = -Infinity;", None),
+        ("const a: number = NaN;", None),
+        ("const a: number = +NaN;", None),
+        ("const a: number = -NaN;", None),
+        ("const a: null = null;", None),
+        ("const a: RegExp = /a/;", None),
+        ("const a: RegExp = RegExp(\"a\");", None),
+        ("const a: RegExp = RegExp?.(\"a\");", None),
+        ("const a: RegExp = new RegExp(\"a\");", None),
+        ("const a: string = \"str\";", None),
+        ("const a: string = 'str';", None),
+        ("const a: string = `str`;", None),
+        ("const a: string = String(1);", None),
+        ("const a: string = String?.(1);", None),
+        ("const a: symbol = Symbol(\"a\");", None),
+        ("const a: symbol = Symbol?.(\"a\");", None),
+        ("const a: undefined = undefined;", None),
+        ("const a: undefined = void someValue;", None),
+        ("const a: number = (-10);", None),
+        ("const a: number = -(10);", None),
+        ("const a: boolean = !!foo;", None),
+        ("const fn = (a?: number = 5) => {};", None),
+        ("class A { a!: number = 1; }", None),
+        (
+            "const fn = (a: number = 5, b: boolean = true, c: string = 'foo') => {};",
+            Some(serde_json::json!([{ "ignoreParameters": false, "ignoreProperties": true }])),
+        ),
+        (
+            "function fn(a: number = 5, b: boolean = true) {}",
+            Some(serde_json::json!([{ "ignoreProperties": true }])),
+        ),
+        ("class Foo { constructor(public a: boolean = true) {} }", None),
+        (
+            "class Foo { a: number = 5; b: boolean = true; c: string = 'foo'; }",
+            Some(serde_json::json!([{ "ignoreParameters": true, "ignoreProperties": false }])),
+        ),
+        ("class Foo { static a: number = 5; }", None),
+    ];
+
+    let fix = vec![
+        ("const a: bigint = 10n;", "const a = 10n;", None),
+        ("const a: bigint = -10n;", "const a = -10n;", None),
+        ("const a: bigint = BigInt(10);", "const a = BigInt(10);", None),
+        ("const a: bigint = -BigInt(10);", "const a = -BigInt(10);", None),
+        ("const a: bigint = BigInt?.(10);", "const a = BigInt?.(10);", None),
+        ("const a: bigint = -BigInt?.(10);", "const a = -BigInt?.(10);", None),
+        ("const a: boolean = false;", "const a = false;", None),
+        ("const a: boolean = true;", "const a = true;", None),
+        ("const a: boolean = Boolean(null);", "const a = Boolean(null);", None),
+        ("const a: boolean = Boolean?.(null);", "const a = Boolean?.(null);", None),
+        ("const a: boolean = !0;", "const a = !0;", None),
+        ("const a: number = 10;", "const a = 10;", None),
+        ("const a: number = +10;", "const a = +10;", None),
+        ("const a: number = -10;", "const a = -10;", None),
+        ("const a: number = Number(\"1\");", "const a = Number(\"1\");", None),
+        ("const a: number = +Number(\"1\");", "const a = +Number(\"1\");", None),
+        ("const a: number = -Number(\"1\");", "const a = -Number(\"1\");", None),
+        ("const a: number = Number?.(\"1\");", "const a = Number?.(\"1\");", None),
+        ("const a: number = +Number?.(\"1\");", "const a = +Number?.(\"1\");", None),
+        ("const a: number = -Number?.(\"1\");", "const a = -Number?.(\"1\");", None),
+        ("const a: number = Infinity;", "const a = Infinity;", None),
+        ("const a: number = +Infinity;", "const a = +Infinity;", None),
+        ("const a: number = -Infinity;", "const a = -Infinity;", None),
+        ("const a: number = NaN;", "const a = NaN;", None),
+        ("const a: number = +NaN;", "const a = +NaN;", None),
+        ("const a: number = -NaN;", "const a = -NaN;", None),
+        ("const a: null = null;", "const a = null;", None),
+        ("const a: RegExp = /a/;", "const a = /a/;", None),
+        ("const a: RegExp = RegExp(\"a\");", "const a = RegExp(\"a\");", None),
+        ("const a: RegExp = RegExp?.(\"a\");", "const a = RegExp?.(\"a\");", None),
+        ("const a: RegExp = new RegExp(\"a\");", "const a = new RegExp(\"a\");", None),
+        ("const a: string = \"str\";", "const a = \"str\";", None),
+        ("const a: string = 'str';", "const a = 'str';", None),
+        ("const a: string = `str`;", "const a = `str`;", None),
+        ("const a: string = String(1);", "const a = String(1);", None),
+        ("const a: string = String?.(1);", "const a = String?.(1);", None),
+        ("const a: symbol = Symbol(\"a\");", "const a = Symbol(\"a\");", None),
+        ("const a: symbol = Symbol?.(\"a\");", "const a = Symbol?.(\"a\");", None),
+        ("const a: undefined = undefined;", "const a = undefined;", None),
+        ("const a: undefined = void someValue;", "const a = void someValue;", None),
+        ("const a: number = (-10);", "const a = (-10);", None),
+        ("const a: number = -(10);", "const a = -(10);", None),
+        ("const a: boolean = !!foo;", "const a = !!foo;", None),
+        ("const fn = (a?: number = 5) => {};", "const fn = (a = 5) => {};", None),
+        ("class A { a!: number = 1; }", "class A { a = 1; }", None),
+        (
+            "const fn = (a: number = 5, b: boolean = true, c: string = 'foo') => {};",
+            "const fn = (a = 5, b = true, c = 'foo') => {};",
+            Some(serde_json::json!([{ "ignoreParameters": false, "ignoreProperties": true }])),
+        ),
+        (
+            "function fn(a: number = 5, b: boolean = true) {}",
+            "function fn(a = 5, b = true) {}",
+            Some(serde_json::json!([{ "ignoreProperties": true }])),
+        ),
+        (
+            "class Foo { constructor(public a: boolean = true) {} }",
+            "class Foo { constructor(public a = true) {} }",
+            None,
+        ),
+        (
+            "class Foo { a: number = 5; b: boolean = true; c: string = 'foo'; }",
+            "class Foo { a = 5; b = true; c = 'foo'; }",
+            Some(serde_json::json!([{ "ignoreParameters": true, "ignoreProperties": false }])),
+        ),
+        ("class Foo { static a: number = 5; }", "class Foo { static a = 5; }", None),
+    ];
+
+    Tester::new(NoInferrableTypes::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
+}
@@ -1,3 +1,5 @@
+use std::path::Path;
+
 use oxc_ast::{
     ast::{ModifierKind, TSModuleDeclarationName},
     AstKind,
@@ -19,12 +21,20 @@ use crate::{context::LintContext, rule::Rule, AstNode};
 )]
 struct NoNamespaceDiagnostic(#[label] pub Span);
 
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Clone)]
 pub struct NoNamespace {
+    /// Allow the namespaces declared with `declare`, and the namespaces inside of them
     allow_declarations: bool,
+    /// Allow the namespaces in the `.d.ts` files
     allow_definition_files: bool,
 }
 
+impl Default for NoNamespace {
+    fn default() -> Self {
+        Self { allow_declarations: false, allow_definition_files: true }
+    }
+}
+
 declare_oxc_lint!(
     /// ### What it does
     /// Disallow TypeScript namespaces.
@@ -41,6 +51,10 @@ declare_oxc_lint!(
     /// declare module foo {}
     /// declare namespace foo {}
     /// ```
+    ///
+    /// The `declare` namespaces are allowed with the `allowDeclarations` option, and the
+    /// namespaces in the `.d.ts` files are allowed unless `allowDefinitionFiles` is `false`.
+    /// `declare global {}` and `declare module 'foo' {}` are always allowed.
     NoNamespace,
     correctness
 );
@@ -57,7 +71,7 @@ impl Rule for NoNamespace {
                 .get(0)
                 .and_then(|x| x.get("allowDefinitionFiles"))
                 .and_then(serde_json::Value::as_bool)
-                .unwrap_or(false),
+                .unwrap_or(true),
         }
     }
 
@@ -79,7 +93,7 @@ impl Rule for NoNamespace {
             return;
         }
 
-        if self.allow_definition_files && ctx.source_type().is_typescript_definition() {
+        if self.allow_definition_files && is_definition_file(ctx.file_path()) {
             return;
         }
 
@@ -102,15 +116,35 @@ fn is_declaration(node: &AstNode, ctx: &LintContext) -> bool {
     })
 }
 
+fn is_definition_file(path: &Path) -> bool {
+    let Some(file_name) = path.file_name().and_then(|name| name.to_str()) else { return false };
+    let file_name = file_name.to_ascii_lowercase();
+    [".d.ts", ".d.mts", ".d.cts"].iter().any(|extension| file_name.ends_with(extension))
+}
+
 #[test]
 fn test() {
+    use std::path::PathBuf;
+
     use crate::tester::Tester;
 
+    let definition_file = || Some(PathBuf::from("test.d.ts"));
+
     let pass = vec![
-        ("declare global {}", None),
-        ("declare module 'foo' {}", None),
-        ("declare module foo {}", Some(serde_json::json!([{ "allowDeclarations": true }]))),
-        ("declare namespace foo {}", Some(serde_json::json!([{ "allowDeclarations": true }]))),
+        ("declare global {}", None, None, None),
+        ("declare module 'foo' {}", None, None, None),
+        (
+            "declare module foo {}",
+            Some(serde_json::json!([{ "allowDeclarations": true }])),
+            None,
+            None,
+        ),
+        (
+            "declare namespace foo {}",
+            Some(serde_json::json!([{ "allowDeclarations": true }])),
+            None,
+            None,
+        ),
         (
             "
     		declare global {
@@ -118,6 +152,8 @@ fn test() {
     		}
     		      ",
             Some(serde_json::json!([{ "allowDeclarations": true }])),
+            None,
+            None,
         ),
         (
             "
@@ -126,6 +162,8 @@ fn test() {
     		}
     		      ",
             Some(serde_json::json!([{ "allowDeclarations": true }])),
+            None,
+            None,
         ),
         (
             "
@@ -136,6 +174,8 @@ fn test() {
     		}
     		      ",
             Some(serde_json::json!([{ "allowDeclarations": true }])),
+            None,
+            None,
         ),
         (
             "
@@ -146,6 +186,8 @@ fn test() {
     		}
     		      ",
             Some(serde_json::json!([{ "allowDeclarations": true }])),
+            None,
+            None,
         ),
         (
             "
@@ -156,21 +198,54 @@ fn test() {
     		}
     		      ",
             Some(serde_json::json!([{ "allowDeclarations": true }])),
+            None,
+            None,
         ),
+        (
+            "namespace foo {}",
+            Some(serde_json::json!([{ "allowDefinitionFiles": true }])),
+            None,
+            definition_file(),
+        ),
+        (
+            "module foo {}",
+            Some(serde_json::json!([{ "allowDefinitionFiles": true }])),
+            None,
+            definition_file(),
+        ),
+        ("namespace foo {}", None, None, definition_file()),
+        ("namespace foo { namespace bar {} }", None, None, definition_file()),
+        ("declare namespace foo {}", None, None, Some(PathBuf::from("test.d.mts"))),
+        ("declare module foo {}", None, None, Some(PathBuf::from("test.d.cts"))),
     ];
 
     let fail = vec![
-        ("module foo {}", None),
-        ("namespace foo {}", None),
-        ("module foo {}", Some(serde_json::json!([{ "allowDeclarations": false }]))),
-        ("namespace foo {}", Some(serde_json::json!([{ "allowDeclarations": false }]))),
-        ("module foo {}", Some(serde_json::json!([{ "allowDeclarations": true }]))),
-        ("namespace foo {}", Some(serde_json::json!([{ "allowDeclarations": true }]))),
-        ("declare module foo {}", None),
-        ("declare namespace foo {}", None),
-        ("declare module foo {}", Some(serde_json::json!([{ "allowDeclarations": false }]))),
-        ("declare namespace foo {}", Some(serde_json::json!([{ "allowDeclarations": false }]))),
-        ("namespace Foo.Bar {}", Some(serde_json::json!([{ "allowDeclarations": false }]))),
+        ("module foo {}", None, None, None),
+        ("namespace foo {}", None, None, None),
+        ("module foo {}", Some(serde_json::json!([{ "allowDeclarations": false }])), None, None),
+        ("namespace foo {}", Some(serde_json::json!([{ "allowDeclarations": false }])), None, None),
+        ("module foo {}", Some(serde_json::json!([{ "allowDeclarations": true }])), None, None),
+        ("namespace foo {}", Some(serde_json::json!([{ "allowDeclarations": true }])), None, None),
+        ("declare module foo {}", None, None, None),
+        ("declare namespace foo {}", None, None, None),
+        (
+            "declare module foo {}",
+            Some(serde_json::json!([{ "allowDeclarations": false }])),
+            None,
+            None,
+        ),
+        (
+            "declare namespace foo {}",
+            Some(serde_json::json!([{ "allowDeclarations": false }])),
+            None,
+            None,
+        ),
+        (
+            "namespace Foo.Bar {}",
+            Some(serde_json::json!([{ "allowDeclarations": false }])),
+            None,
+            None,
+        ),
         (
             "
     		namespace Foo.Bar {
@@ -180,6 +255,8 @@ fn test() {
     		}
     		      ",
             None,
+            None,
+            None,
         ),
         (
             "
@@ -190,6 +267,8 @@ fn test() {
     		}
     		      ",
             Some(serde_json::json!([{ "allowDeclarations": true }])),
+            None,
+            None,
         ),
         (
             "
@@ -200,6 +279,8 @@ fn test() {
     		}
     		      ",
             Some(serde_json::json!([{ "allowDeclarations": true }])),
+            None,
+            None,
         ),
         (
             "
@@ -210,6 +291,8 @@ fn test() {
     		}
     		      ",
             Some(serde_json::json!([{ "allowDeclarations": true }])),
+            None,
+            None,
         ),
         (
             "
@@ -220,6 +303,8 @@ fn test() {
     		}
     		      ",
             Some(serde_json::json!([{ "allowDeclarations": true }])),
+            None,
+            None,
         ),
         (
             "
@@ -230,6 +315,8 @@ fn test() {
     		}
     		      ",
             Some(serde_json::json!([{ "allowDeclarations": true }])),
+            None,
+            None,
         ),
         (
             "
@@ -240,6 +327,8 @@ fn test() {
     		}
     		      ",
             Some(serde_json::json!([{ "allowDeclarations": true }])),
+            None,
+            None,
         ),
         (
             "
@@ -250,6 +339,8 @@ fn test() {
     		}
     		      ",
             Some(serde_json::json!([{ "allowDeclarations": true }])),
+            None,
+            None,
         ),
         (
             "
@@ -260,6 +351,8 @@ fn test() {
     		}
     		      ",
             Some(serde_json::json!([{ "allowDeclarations": true }])),
+            None,
+            None,
         ),
         (
             "
@@ -270,6 +363,8 @@ fn test() {
     		}
     		      ",
             Some(serde_json::json!([{ "allowDeclarations": true }])),
+            None,
+            None,
         ),
         (
             "
@@ -280,6 +375,8 @@ fn test() {
     		}
     		      ",
             Some(serde_json::json!([{ "allowDeclarations": true }])),
+            None,
+            None,
         ),
         (
             "
@@ -290,6 +387,8 @@ fn test() {
     		}
     		      ",
             Some(serde_json::json!([{ "allowDeclarations": true }])),
+            None,
+            None,
         ),
         (
             "
@@ -300,6 +399,8 @@ fn test() {
     		}
     		      ",
             Some(serde_json::json!([{ "allowDeclarations": true }])),
+            None,
+            None,
         ),
         (
             "
@@ -310,6 +411,8 @@ fn test() {
     		}
     		      ",
             Some(serde_json::json!([{ "allowDeclarations": true }])),
+            None,
+            None,
         ),
         (
             "
@@ -320,6 +423,8 @@ fn test() {
     		}
     		      ",
             Some(serde_json::json!([{ "allowDeclarations": true }])),
+            None,
+            None,
         ),
         (
             "
@@ -330,6 +435,8 @@ fn test() {
     		}
     		      ",
             Some(serde_json::json!([{ "allowDeclarations": true }])),
+            None,
+            None,
         ),
         (
             "
@@ -340,6 +447,45 @@ fn test() {
     		}
     		      ",
             Some(serde_json::json!([{ "allowDeclarations": true }])),
+            None,
+            None,
+        ),
+        (
+            "namespace foo {}",
+            Some(serde_json::json!([{ "allowDefinitionFiles": false }])),
+            None,
+            definition_file(),
+        ),
+        (
+            "module foo {}",
+            Some(serde_json::json!([{ "allowDefinitionFiles": false }])),
+            None,
+            definition_file(),
+        ),
+        (
+            "declare module foo {}",
+            Some(serde_json::json!([{ "allowDefinitionFiles": false }])),
+            None,
+            definition_file(),
+        ),
+        (
+            "declare namespace foo {}",
+            Some(serde_json::json!([{ "allowDefinitionFiles": false }])),
+            None,
+            definition_file(),
+        ),
+        // Only the `.d.ts` files are definition files
+        (
+            "namespace foo {}",
+            Some(serde_json::json!([{ "allowDefinitionFiles": true }])),
+            None,
+            Some(PathBuf::from("test.ts")),
+        ),
+        (
+            "namespace foo {}",
+            Some(serde_json::json!([{ "allowDefinitionFiles": true }])),
+            None,
+            Some(PathBuf::from("test.d.ts.tsx")),
         ),
     ];
 
@@ -1,4 +1,4 @@
-use oxc_ast::ast::{Expression, TSLiteral, TSType};
+use oxc_ast::ast::{Expression, TSLiteral, TSType, TSTypeAnnotation};
 use oxc_ast::AstKind;
 
 use oxc_diagnostics::{
@@ -6,14 +6,29 @@ use oxc_diagnostics::{
     thiserror::Error,
 };
 use oxc_macros::declare_oxc_lint;
-use oxc_span::Span;
+use oxc_span::{GetSpan, Span};
 
-use crate::{context::LintContext, fixer::Fix, rule::Rule, AstNode};
+use crate::{
+    context::LintContext,
+    fixer::{Fix, FixKind},
+    rule::Rule,
+    AstNode,
+};
 
 #[derive(Debug, Error, Diagnostic)]
-#[error("typescript-eslint(prefer-as-const): Expected a `const` assertion instead of a literal type annotation.")]
-#[diagnostic(severity(warning), help("You should use `as const` instead of type annotation."))]
-struct PreferAsConstDiagnostic(#[label] pub Span);
+enum PreferAsConstDiagnostic {
+    #[error(
+        "typescript-eslint(prefer-as-const): Expected a `const` instead of a literal type assertion."
+    )]
+    #[diagnostic(
+        severity(warning),
+        help("You should use `as const` instead of the literal type.")
+    )]
+    Assertion(#[label] Span),
+    #[error("typescript-eslint(prefer-as-const): Expected a `const` assertion instead of a literal type annotation.")]
+    #[diagnostic(severity(warning), help("You should use `as const` instead of type annotation."))]
+    Annotation(#[label] Span),
+}
 
 #[derive(Debug, Default, Clone)]
 pub struct PreferAsConst;
@@ -36,7 +51,8 @@ declare_oxc_lint!(
     /// let foo = { bar: 'baz' as 'baz' };
     /// ```
     PreferAsConst,
-    correctness
+    correctness,
+    fix
 );
 
 impl Rule for PreferAsConst {
@@ -49,12 +65,7 @@ impl Rule for PreferAsConst {
                 let Some(initial_value_expression) = &variable_declarator.init else {
                     return;
                 };
-                check_and_report(
-                    &type_annotation.type_annotation,
-                    initial_value_expression,
-                    ctx,
-                    false,
-                );
+                check_annotation(type_annotation, initial_value_expression, ctx);
             }
             AstKind::PropertyDefinition(property_definition) => {
                 let Some(type_annotation) = &property_definition.type_annotation else {
@@ -63,72 +74,62 @@ impl Rule for PreferAsConst {
                 let Some(initial_value_expression) = &property_definition.value else {
                     return;
                 };
-                check_and_report(
-                    &type_annotation.type_annotation,
-                    initial_value_expression,
-                    ctx,
-                    false,
-                );
+                check_annotation(type_annotation, initial_value_expression, ctx);
             }
             AstKind::TSAsExpression(as_expression) => {
-                check_and_report(
-                    &as_expression.type_annotation,
-                    &as_expression.expression,
-                    ctx,
-                    true,
-                );
+                check_assertion(&as_expression.type_annotation, &as_expression.expression, ctx);
+            }
+            AstKind::TSTypeAssertion(type_assertion) => {
+                check_assertion(&type_assertion.type_annotation, &type_assertion.expression, ctx);
             }
             _ => {}
         }
     }
 }
 
-fn check_and_report(
-    ts_type: &TSType,
-    initial_value_expression: &Expression,
+/// `'bar' as 'bar'` and `<'bar'>'bar'`, fixed to `'bar' as const` and `<const>'bar'`
+fn check_assertion(ts_type: &TSType, expression: &Expression, ctx: &LintContext) {
+    let Some(span) = same_literal_type(ts_type, expression, ctx) else { return };
+    ctx.diagnostic_with_fix(PreferAsConstDiagnostic::Assertion(span), || {
+        Fix::new("const", span).with_kind(FixKind::Safe)
+    });
+}
+
+/// `let foo: 'bar' = 'bar'`, with a suggestion to use `let foo = 'bar' as const` instead
+fn check_annotation(
+    type_annotation: &TSTypeAnnotation,
+    expression: &Expression,
     ctx: &LintContext,
-    can_fix: bool,
 ) {
-    if let TSType::TSLiteralType(literal_type) = &ts_type {
-        let error_span = match &literal_type.literal {
-            TSLiteral::StringLiteral(string_literal) => match initial_value_expression {
-                Expression::StringLiteral(initial_string) => {
-                    if string_literal.value.eq(&initial_string.value) {
-                        Some(string_literal.span)
-                    } else {
-                        None
-                    }
-                }
-                _ => None,
-            },
-            TSLiteral::NullLiteral(null_literal) => match initial_value_expression {
-                Expression::NullLiteral(_) => Some(null_literal.span),
-                _ => None,
-            },
-            TSLiteral::NumberLiteral(number_literal) => match initial_value_expression {
-                Expression::NumberLiteral(initial_number) => {
-                    if (number_literal.value - initial_number.value).abs() < f64::EPSILON {
-                        Some(number_literal.span)
-                    } else {
-                        None
-                    }
-                }
-                _ => None,
-            },
-            _ => None,
-        };
-        if let Some(span) = error_span {
-            if can_fix {
-                ctx.diagnostic_with_fix(PreferAsConstDiagnostic(span), || {
-                    let start = span.start;
-                    let end = span.end;
-                    Fix::new("const", Span { start, end })
-                });
-            } else {
-                ctx.diagnostic(PreferAsConstDiagnostic(span));
-            }
-        }
+    let Some(span) = same_literal_type(&type_annotation.type_annotation, expression, ctx) else {
+        return;
+    };
+    let diagnostic = PreferAsConstDiagnostic::Annotation(span);
+    let fix_span = Span::new(type_annotation.span.start, expression.span().end);
+    if ctx.semantic().trivias().has_comments_between(fix_span) {
+        ctx.diagnostic(diagnostic);
+        return;
     }
+    let value = Span::new(type_annotation.span.end, fix_span.end).source_text(ctx.source_text());
+    let suggestion = Fix::new(format!("{value} as const"), fix_span)
+        .with_message("You should use `as const` instead of type annotation.");
+    ctx.diagnostic_with_suggestions(diagnostic, vec![suggestion]);
+}
+
+/// The span of `ts_type` when it is the literal type of `expression`, written the same way,
+/// e.g. `1 as 1` but not `1 as 1.0`
+fn same_literal_type(ts_type: &TSType, expression: &Expression, ctx: &LintContext) -> Option<Span> {
+    let TSType::TSLiteralType(literal_type) = ts_type else { return None };
+    let value_span = match (&literal_type.literal, expression) {
+        (TSLiteral::StringLiteral(_), Expression::StringLiteral(value)) => value.span,
+        (TSLiteral::NumberLiteral(_), Expression::NumberLiteral(value)) => value.span,
+        (TSLiteral::BooleanLiteral(_), Expression::BooleanLiteral(value)) => value.span,
+        (TSLiteral::BigintLiteral(_), Expression::BigintLiteral(value)) => value.span,
+        _ => return None,
+    };
+    let source_text = ctx.source_text();
+    (literal_type.span.source_text(source_text) == value_span.source_text(source_text))
+        .then_some(literal_type.span)
 }
 
 #[test]
@@ -164,10 +165,12 @@ fn test() {
         "class foo { bar = () => 'bar' as const; }",
         "type BazFunction = () => 'baz'; class foo { bar: BazFunction = () => 'bar'; }",
         "class foo { bar(): void {} }",
-        // NOTE: OXC does not parse these format yet.
-        // "let foo = <bar>'bar';",
-        // "let foo = <string>'bar';",
-        // "class foo { bar = <baz>'baz'; }",
+        // The literals are compared as they are written
+        "let foo = 1 as 1.0;",
+        "let foo = 0x10 as 16;",
+        "let foo = 'bar' as \"bar\";",
+        "let foo = -1 as -1;",
+        "let foo: null = null;",
     ];
 
     let fail = vec![
@@ -176,6 +179,15 @@ fn test() {
         "let foo: 2 = 2;",
         "class foo { bar: 'baz' = 'baz';}",
         "class foo { bar: 2 = 2;}",
+        "let foo = { bar: 'baz' as 'baz' };",
+        "let foo = { bar: 1 as 1 };",
+        "let foo: 'bar' = 'bar' as 'bar';",
+        "let foo = 'bar' as 'bar';",
+        "let foo = 5 as 5;",
+        "class foo { foo = 'bar' as 'bar'; }",
+        "class foo { foo = 5 as 5; }",
+        "let foo = true as true;",
+        "let foo = 1n as 1n;",
     ];
 
     let fix = vec![
@@ -186,11 +198,40 @@ fn test() {
         ("let foo = 5 as 5;", "let foo = 5 as const;", None),
         ("class foo { foo = 'bar' as 'bar'; }", "class foo { foo = 'bar' as const; }", None),
         ("class foo { foo = 5 as 5; }", "class foo { foo = 5 as const; }", None),
-        // NOTE: OXC does not parse these format yet.
-        // ("let foo = <4>4;", "let foo = <const>4;", None),
-        // ("let foo = <'bar'>'bar';", "let foo = <const>'bar';", None),
-        // ("class foo { foo = <'bar'>'bar'; }", "class foo { foo = <const>'bar'; }", None),
+        ("let foo = true as true;", "let foo = true as const;", None),
+    ];
+
+    let suggestions = vec![
+        ("let []: 'bar' = 'bar';", vec!["let [] = 'bar' as const;"], None),
+        ("let foo: 'bar' = 'bar';", vec!["let foo = 'bar' as const;"], None),
+        ("let foo: 2 = 2;", vec!["let foo = 2 as const;"], None),
+        ("class foo { bar: 'baz' = 'baz';}", vec!["class foo { bar = 'baz' as const;}"], None),
+        ("class foo { bar: 2 = 2;}", vec!["class foo { bar = 2 as const;}"], None),
+    ];
+
+    Tester::new_without_config(PreferAsConst::NAME, pass, fail)
+        .expect_fix(fix)
+        .expect_suggestions(suggestions)
+        .test_and_snapshot();
+
+    // The angle bracket assertions are not allowed in `.tsx` files
+    let pass = vec![
+        "let foo = <bar>'bar';",
+        "let foo = <string>'bar';",
+        "class foo { bar = <baz>'baz'; }",
+    ];
+
+    let fail =
+        vec!["let foo = <'bar'>'bar';", "let foo = <4>4;", "class foo { foo = <'bar'>'bar'; }"];
+
+    let fix = vec![
+        ("let foo = <4>4;", "let foo = <const>4;", None),
+        ("let foo = <'bar'>'bar';", "let foo = <const>'bar';", None),
+        ("class foo { foo = <'bar'>'bar'; }", "class foo { foo = <const>'bar'; }", None),
     ];
 
-    Tester::new_without_config(PreferAsConst::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
+    Tester::new_without_config(PreferAsConst::NAME, pass, fail)
+        .change_rule_path("prefer_as_const.ts")
+        .expect_fix(fix)
+        .test();
 }
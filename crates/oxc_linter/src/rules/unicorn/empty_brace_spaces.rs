@@ -6,7 +6,7 @@ use oxc_diagnostics::{
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
 
-use crate::{context::LintContext, rule::Rule, AstNode, Fix};
+use crate::{context::LintContext, rule::Rule, AstNode, Fix, FixKind};
 
 #[derive(Debug, Error, Diagnostic)]
 #[error("eslint-plugin-unicorn(empty-brace-spaces): No spaces inside empty pair of braces allowed")]
@@ -30,7 +30,8 @@ declare_oxc_lint!(
     /// }
     /// ```
     EmptyBraceSpaces,
-    style
+    style,
+    fix
 );
 
 impl Rule for EmptyBraceSpaces {
@@ -46,7 +47,7 @@ impl Rule for EmptyBraceSpaces {
                     && !ctx.semantic().trivias().has_comments_between(static_block.span)
                 {
                     ctx.diagnostic_with_fix(EmptyBraceSpacesDiagnostic(static_block.span), || {
-                        Fix::new("static {}", static_block.span)
+                        Fix::new("static {}", static_block.span).with_kind(FixKind::Safe)
                     });
                 }
             }
@@ -80,7 +81,9 @@ fn remove_empty_braces_spaces(ctx: &LintContext, is_empty_body: bool, span: Span
 
     if is_empty_body && end - start > 2 && !ctx.semantic().trivias().has_comments_between(span) {
         // length of "{}"
-        ctx.diagnostic_with_fix(EmptyBraceSpacesDiagnostic(span), || Fix::new("{}", span));
+        ctx.diagnostic_with_fix(EmptyBraceSpacesDiagnostic(span), || {
+            Fix::new("{}", span).with_kind(FixKind::Safe)
+        });
     }
 }
 
@@ -11,7 +11,7 @@ use oxc_diagnostics::{
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
 
-use crate::{context::LintContext, rule::Rule, AstNode, Fix};
+use crate::{context::LintContext, rule::Rule, AstNode, Fix, FixKind};
 
 #[derive(Debug, Error, Diagnostic)]
 #[error("eslint-plugin-unicorn(escape-case): Use uppercase characters for the value of the escape sequence.")]
@@ -40,7 +40,8 @@ declare_oxc_lint!(
     /// const foo = '\cA';
     /// ```
     EscapeCase,
-    pedantic
+    pedantic,
+    fix
 );
 
 fn is_hex_char(c: char) -> bool {
@@ -128,7 +129,9 @@ impl Rule for EscapeCase {
             AstKind::StringLiteral(StringLiteral { span, .. }) => {
                 let text = span.source_text(ctx.source_text());
                 if let Some(fixed) = check_case(text, false) {
-                    ctx.diagnostic_with_fix(EscapeCaseDiagnostic(*span), || Fix::new(fixed, *span));
+                    ctx.diagnostic_with_fix(EscapeCaseDiagnostic(*span), || {
+                        Fix::new(fixed, *span).with_kind(FixKind::Safe)
+                    });
                 }
             }
             AstKind::TemplateLiteral(TemplateLiteral { quasis, .. }) => {
@@ -137,7 +140,7 @@ impl Rule for EscapeCase {
                         check_case(quasi.span.source_text(ctx.source_text()), false)
                     {
                         ctx.diagnostic_with_fix(EscapeCaseDiagnostic(quasi.span), || {
-                            Fix::new(fixed, quasi.span)
+                            Fix::new(fixed, quasi.span).with_kind(FixKind::Safe)
                         });
                     }
                 });
@@ -146,7 +149,7 @@ impl Rule for EscapeCase {
                 let text = regex.span.source_text(ctx.source_text());
                 if let Some(fixed) = check_case(text, true) {
                     ctx.diagnostic_with_fix(EscapeCaseDiagnostic(regex.span), || {
-                        Fix::new(fixed, regex.span)
+                        Fix::new(fixed, regex.span).with_kind(FixKind::Safe)
                     });
                 }
             }
@@ -36,11 +36,13 @@ enum NonZero {
     #[default]
     GreaterThan,
     NotEqual,
+    GreaterThanOrEqual,
 }
 impl NonZero {
     pub fn from(raw: &str) -> Self {
         match raw {
             "not-equal" => Self::NotEqual,
+            "greater-than-or-equal" => Self::GreaterThanOrEqual,
             _ => Self::GreaterThan,
         }
     }
@@ -59,6 +61,8 @@ declare_oxc_lint!(
     ///     Enforces non-zero to be checked with: foo.length > 0
     /// not-equal
     ///     Enforces non-zero to be checked with: foo.length !== 0
+    /// greater-than-or-equal
+    ///     Enforces non-zero to be checked with: foo.length >= 1
     /// ### Example
     /// ```javascript
     /// // fail
@@ -75,7 +79,8 @@ declare_oxc_lint!(
     /// const isEmpty = foo.length === 0;
     /// ```
     ExplicitLengthCheck,
-    pedantic
+    pedantic,
+    fix
 );
 fn is_literal(expr: &Expression, value: f64) -> bool {
     matches!(expr, Expression::NumberLiteral(lit) if (lit.value - value).abs() < f64::EPSILON)
@@ -151,6 +156,25 @@ fn get_length_check_node<'a, 'b>(
 }
 
 impl ExplicitLengthCheck {
+    /// The explicit comparison to replace the check with, `None` if it is already used
+    fn check_code(&self, kind: &AstKind, is_zero_length_check: bool) -> Option<&'static str> {
+        let (operator, value, check_code) = if is_zero_length_check {
+            (BinaryOperator::StrictEquality, 0.0, "=== 0")
+        } else {
+            match self.non_zero {
+                NonZero::GreaterThan => (BinaryOperator::GreaterThan, 0.0, "> 0"),
+                NonZero::NotEqual => (BinaryOperator::StrictInequality, 0.0, "!== 0"),
+                NonZero::GreaterThanOrEqual => (BinaryOperator::GreaterEqualThan, 1.0, ">= 1"),
+            }
+        };
+        let is_explicit = matches!(
+            kind,
+            AstKind::BinaryExpression(expr)
+                if expr.operator == operator && expr.right.is_number(value)
+        );
+        (!is_explicit).then_some(check_code)
+    }
+
     fn report<'a>(
         &self,
         ctx: &LintContext<'a>,
@@ -167,30 +191,7 @@ impl ExplicitLengthCheck {
             AstKind::MemberExpression(MemberExpression::StaticMemberExpression(expr)) => expr.span,
             _ => unreachable!(),
         };
-        let check_code = if is_zero_length_check {
-            if matches!(kind, AstKind::BinaryExpression(BinaryExpression{operator:BinaryOperator::StrictEquality,right,..}) if right.is_number_0())
-            {
-                return;
-            }
-            "=== 0"
-        } else {
-            match self.non_zero {
-                NonZero::GreaterThan => {
-                    if matches!(kind, AstKind::BinaryExpression(BinaryExpression{operator:BinaryOperator::GreaterThan,right,..}) if right.is_number_0())
-                    {
-                        return;
-                    }
-                    "> 0"
-                }
-                NonZero::NotEqual => {
-                    if matches!(kind, AstKind::BinaryExpression(BinaryExpression{operator:BinaryOperator::StrictInequality,right,..}) if right.is_number_0())
-                    {
-                        return;
-                    }
-                    "!== 0"
-                }
-            }
-        };
+        let Some(check_code) = self.check_code(&kind, is_zero_length_check) else { return };
         let mut need_pad_start = false;
         let mut need_pad_end = false;
         let parent = ctx.nodes().parent_kind(node.id());
@@ -302,7 +303,7 @@ impl Rule for ExplicitLengthCheck {
 
 #[test]
 fn test() {
-    use crate::tester::Tester;
+    use crate::{tester::Tester, FixKind};
 
     let pass = vec![
         // Not `.length`
@@ -325,6 +326,10 @@ fn test() {
         ("if (foo.length > 0) {}", None),
         ("if (foo.length > 0) {}", Some(serde_json::json!([{"non-zero": "greater-than"}]))),
         ("if (foo.length !== 0) {}", Some(serde_json::json!([{"non-zero": "not-equal"}]))),
+        (
+            "if (foo.length >= 1) {}",
+            Some(serde_json::json!([{"non-zero": "greater-than-or-equal"}])),
+        ),
         // Checking "non-zero"
         ("if (foo.length === 0) {}", None),
         // `ConditionalExpression`
@@ -361,6 +366,10 @@ fn test() {
         ("const x = foo.length || bar()", None),
         ("() => foo.length && bar()", None),
         ("alert(foo.length && bar())", None),
+        (
+            "const x = foo.length || bar()",
+            Some(serde_json::json!([{"non-zero": "greater-than-or-equal"}])),
+        ),
     ];
     let fixes = vec![
         (
@@ -382,6 +391,16 @@ fn test() {
             "const foo = { length: 123 }; if (foo.length !== 0) {}",
             Some(serde_json::json!([{"non-zero": "not-equal"}]))
         ),
+        (
+            "if (foo.length || foo.length > 0 || foo.length !== 0 || 0 < foo.length) {}",
+            "if (foo.length >= 1 || foo.length >= 1 || foo.length >= 1 || foo.length >= 1) {}",
+            Some(serde_json::json!([{"non-zero": "greater-than-or-equal"}])),
+        ),
+        (
+            "if (!foo.length || foo.length < 1) {}",
+            "if (foo.length === 0 || foo.length === 0) {}",
+            Some(serde_json::json!([{"non-zero": "greater-than-or-equal"}])),
+        ),
         ("if (foo.bar && foo.bar.length) {}", "if (foo.bar && foo.bar.length > 0) {}", None),
         ("if (foo.length || foo.bar()) {}", "if (foo.length > 0 || foo.bar()) {}", None),
         ("if (!!(!!foo.length)) {}", "if (foo.length > 0) {}", None),
@@ -438,7 +457,9 @@ fn test() {
         ("for(const a of!foo.length);", "for(const a of foo.length === 0);", None),
         ("for(const a in!foo.length);", "for(const a in foo.length === 0);", None),
     ];
-    Tester::new::<&'static str>(ExplicitLengthCheck::NAME, pass, fail)
+    // A `length` which is not a number is compared differently than it is coerced to a boolean
+    Tester::new(ExplicitLengthCheck::NAME, pass, fail)
         .expect_fix(fixes)
+        .expect_fix_kind(FixKind::Dangerous)
         .test_and_snapshot();
 }
@@ -49,7 +49,9 @@ declare_oxc_lint!(
 
 impl Rule for FilenameCase {
     fn from_configuration(value: serde_json::Value) -> Self {
-        let Some(case_type) = value.get("cases") else { return Self::default() };
+        let Some(case_type) = value.get(0).and_then(|config| config.get("cases")) else {
+            return Self::default();
+        };
 
         match case_type {
             Value::String(s) => match s.as_str() {
@@ -103,3 +105,37 @@ impl Rule for FilenameCase {
         ctx.diagnostic(FilenameCaseDiagnostic(Span::default(), case_name));
     }
 }
+
+#[test]
+fn test() {
+    use std::path::PathBuf;
+
+    use serde_json::json;
+
+    use crate::tester::Tester;
+
+    let kebab_case =
+        Some(json!([{ "cases": { "kebabCase": true, "camelCase": false, "pascalCase": false } }]));
+
+    let pass = vec![
+        ("", None, None, Some(PathBuf::from("src/foo/fooBar.js"))),
+        ("", None, None, Some(PathBuf::from("src/foo/FooBar.js"))),
+        ("", None, None, Some(PathBuf::from("src/foo/foo.js"))),
+        ("", None, None, Some(PathBuf::from("src/foo/fooBar.test.js"))),
+        ("", kebab_case.clone(), None, Some(PathBuf::from("src/foo/foo-bar.js"))),
+        (
+            "",
+            Some(json!([{ "cases": "snakeCase" }])),
+            None,
+            Some(PathBuf::from("src/foo/foo_bar.js")),
+        ),
+    ];
+
+    let fail = vec![
+        ("", None, None, Some(PathBuf::from("src/foo/foo_bar.js"))),
+        ("", None, None, Some(PathBuf::from("src/foo/foo-bar.js"))),
+        ("", kebab_case, None, Some(PathBuf::from("src/foo/fooBar.js"))),
+    ];
+
+    Tester::new(FilenameCase::NAME, pass, fail).test_and_snapshot();
+}
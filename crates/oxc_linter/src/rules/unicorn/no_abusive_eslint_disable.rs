@@ -52,7 +52,9 @@ impl Rule for NoAbusiveEslintDisable {
             ctx.diagnostic(NoAbusiveEslintDisableDiagnostic(*span));
         }
 
-        for DisableRuleComment { span, rules } in ctx.disable_directives().disable_rule_comments() {
+        for DisableRuleComment { span, rules, .. } in
+            ctx.disable_directives().disable_rule_comments()
+        {
             if rules.is_empty() || !is_valid_rule_name(rules[0]) {
                 ctx.diagnostic(NoAbusiveEslintDisableDiagnostic(*span));
             }
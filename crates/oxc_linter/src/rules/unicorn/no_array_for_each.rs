@@ -1,12 +1,17 @@
-use oxc_ast::{ast::Expression, AstKind};
+use oxc_ast::{
+    ast::{Argument, BindingPattern, BindingPatternKind, CallExpression, Expression, Statement},
+    syntax_directed_operations::BoundNames,
+    AstKind,
+};
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
     thiserror::Error,
 };
 use oxc_macros::declare_oxc_lint;
-use oxc_span::Span;
+use oxc_semantic::Reference;
+use oxc_span::{GetSpan, Span};
 
-use crate::{ast_util::is_method_call, context::LintContext, rule::Rule, AstNode};
+use crate::{ast_util::is_method_call, context::LintContext, fixer::Fix, rule::Rule, AstNode};
 
 use phf::phf_set;
 
@@ -45,6 +50,7 @@ declare_oxc_lint!(
     /// ```
     NoArrayForEach,
     restriction,
+    fix
 );
 
 impl Rule for NoArrayForEach {
@@ -86,11 +92,206 @@ impl Rule for NoArrayForEach {
                 return;
             };
 
-            ctx.diagnostic(NoArrayForEachDiagnostic(span));
+            let diagnostic = NoArrayForEachDiagnostic(span);
+            match get_fix(node, call_expr, object, ctx) {
+                Some(fix) => ctx.diagnostic_with_fix(diagnostic, || fix),
+                None => ctx.diagnostic(diagnostic),
+            }
         }
     }
 }
 
+/// Converts `array.forEach((element, index) => {})` to
+/// `for (const [index, element] of array.entries()) {}` if the behavior is kept.
+fn get_fix<'a>(
+    node: &AstNode<'a>,
+    call_expr: &CallExpression<'a>,
+    object: &Expression<'a>,
+    ctx: &LintContext<'a>,
+) -> Option<Fix<'a>> {
+    // The return value of `forEach` must not be used.
+    let statement_node = ctx.nodes().parent_node(node.id())?;
+    let AstKind::ExpressionStatement(statement) = statement_node.kind() else { return None };
+    // `() => array.forEach(fn)` has no statement to replace.
+    let function_body = ctx.nodes().parent_node(statement_node.id())?;
+    if matches!(
+        ctx.nodes().parent_kind(function_body.id()),
+        Some(AstKind::ArrowExpression(arrow)) if arrow.expression
+    ) {
+        return None;
+    }
+    if call_expr.optional || call_expr.arguments.len() != 1 {
+        return None;
+    }
+    let Argument::Expression(callback) = &call_expr.arguments[0] else { return None };
+    let (params, body, is_expression_body, is_arrow) = match callback {
+        Expression::ArrowExpression(arrow) if !arrow.r#async && !arrow.generator => {
+            (&arrow.params, &arrow.body, arrow.expression, true)
+        }
+        // A named function expression may call itself.
+        Expression::FunctionExpression(func)
+            if !func.r#async && !func.generator && func.id.is_none() =>
+        {
+            (&func.params, func.body.as_ref()?, false, false)
+        }
+        _ => return None,
+    };
+    if params.rest.is_some()
+        || !(1..=2).contains(&params.items.len())
+        || !body.directives.is_empty()
+        || params.items.iter().any(|param| !is_simple_pattern(&param.pattern))
+    {
+        return None;
+    }
+
+    let mut names = vec![];
+    let mut is_reassigned = false;
+    for param in &params.items {
+        param.pattern.bound_names(&mut |ident| {
+            names.push(ident.name.clone());
+            is_reassigned |= ident.symbol_id.get().is_some_and(|symbol_id| {
+                ctx.symbols().get_resolved_references(symbol_id).any(Reference::is_write)
+            });
+        });
+    }
+    if names.iter().enumerate().any(|(i, name)| names[..i].contains(name)) {
+        return None;
+    }
+    // `element.forEach(element => {})` can not declare `element` while iterating over it.
+    let object_span = object.span();
+    if ctx.symbols().references.iter().any(|reference| {
+        let span = reference.span();
+        object_span.start <= span.start
+            && span.end <= object_span.end
+            && names.contains(reference.name())
+    }) {
+        return None;
+    }
+
+    let callback_span = callback.span();
+    let mut returns = vec![];
+    for descendant in ctx.nodes().iter() {
+        let kind = descendant.kind();
+        let span = kind.span();
+        if span.start < body.span.start || body.span.end < span.end {
+            continue;
+        }
+        match kind {
+            // `this` and `arguments` of a function expression callback can not be moved out of it.
+            AstKind::ThisExpression(_) if !is_arrow => {
+                if get_function_scope(descendant, ctx, true)?.0 == callback_span {
+                    return None;
+                }
+            }
+            AstKind::IdentifierReference(ident) if !is_arrow && ident.name == "arguments" => {
+                if get_function_scope(descendant, ctx, true)?.0 == callback_span {
+                    return None;
+                }
+            }
+            // `return` becomes `continue`, which would apply to an inner loop.
+            AstKind::ReturnStatement(return_statement) => {
+                let (function_span, in_loop) = get_function_scope(descendant, ctx, false)?;
+                if function_span == callback_span {
+                    if return_statement.argument.is_some() || in_loop {
+                        return None;
+                    }
+                    returns.push(return_statement.span);
+                }
+            }
+            AstKind::VariableDeclaration(declaration) if declaration.kind.is_var() => {
+                if get_function_scope(descendant, ctx, false)?.0 == callback_span {
+                    return None;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    let source_text = ctx.source_text();
+    let body_text = if is_expression_body {
+        let Some(Statement::ExpressionStatement(expression)) = body.statements.first() else {
+            return None;
+        };
+        if matches!(
+            expression.expression,
+            Expression::FunctionExpression(_) | Expression::ClassExpression(_)
+        ) {
+            return None;
+        }
+        format!("{};", expression.expression.span().source_text(source_text))
+    } else {
+        returns.sort_unstable_by_key(|span| span.start);
+        let mut text = String::new();
+        let mut start = body.span.start;
+        for span in returns {
+            text.push_str(Span::new(start, span.start).source_text(source_text));
+            text.push_str("continue");
+            // skip the `return` keyword
+            start = span.start + 6;
+        }
+        text.push_str(Span::new(start, body.span.end).source_text(source_text));
+        text
+    };
+
+    let declaration = if is_reassigned { "let" } else { "const" };
+    let iterable = object_span.source_text(source_text);
+    let (binding, iterable) = match params.items.as_slice() {
+        [element] => (element.span.source_text(source_text).to_string(), iterable.to_string()),
+        [element, index] => (
+            format!(
+                "[{}, {}]",
+                index.span.source_text(source_text),
+                element.span.source_text(source_text)
+            ),
+            format!("{iterable}.entries()"),
+        ),
+        _ => return None,
+    };
+
+    Some(Fix::new(
+        format!("for ({declaration} {binding} of {iterable}) {body_text}"),
+        statement.span,
+    ))
+}
+
+/// Identifiers and destructuring without default values, which may refer to other parameters.
+fn is_simple_pattern(pattern: &BindingPattern) -> bool {
+    if pattern.type_annotation.is_some() {
+        return false;
+    }
+    match &pattern.kind {
+        BindingPatternKind::BindingIdentifier(_) => true,
+        BindingPatternKind::ObjectPattern(object) => {
+            object.properties.iter().all(|property| is_simple_pattern(&property.value))
+                && object.rest.as_ref().map_or(true, |rest| is_simple_pattern(&rest.argument))
+        }
+        BindingPatternKind::ArrayPattern(array) => {
+            array.elements.iter().flatten().all(is_simple_pattern)
+                && array.rest.as_ref().map_or(true, |rest| is_simple_pattern(&rest.argument))
+        }
+        BindingPatternKind::AssignmentPattern(_) => false,
+    }
+}
+
+/// The span of the function `node` belongs to, skipping arrow functions if `skip_arrows`,
+/// and whether `node` is inside a loop within that function.
+fn get_function_scope(
+    node: &AstNode,
+    ctx: &LintContext,
+    skip_arrows: bool,
+) -> Option<(Span, bool)> {
+    let mut in_loop = false;
+    for ancestor in ctx.nodes().ancestors(node.id()).skip(1) {
+        match ctx.nodes().kind(ancestor) {
+            AstKind::Function(func) => return Some((func.span, in_loop)),
+            AstKind::ArrowExpression(arrow) if !skip_arrows => return Some((arrow.span, in_loop)),
+            kind if kind.is_iteration_statement() => in_loop = true,
+            _ => {}
+        }
+    }
+    None
+}
+
 pub const IGNORED_OBJECTS: phf::Set<&'static str> = phf_set! {
     "Children",
     "r",
@@ -99,7 +300,7 @@ pub const IGNORED_OBJECTS: phf::Set<&'static str> = phf_set! {
 
 #[test]
 fn test() {
-    use crate::tester::Tester;
+    use crate::{tester::Tester, FixKind};
 
     let pass = vec![
         r"new foo.forEach(element => bar())",
@@ -122,7 +323,145 @@ fn test() {
         r"foo.forEach(function element(element, element) {})",
         r"this._listeners.forEach((listener: () => void) => listener());",
         r"return foo.forEach(element => {bar(element)});",
+        r"foo.forEach(element => bar(element));",
+        r"foo.forEach((element, index) => { bar(element, index); });",
+        r"foo.forEach(function (element) { bar(element); });",
+        r"foo.forEach(({a, b}) => { bar(a, b); });",
+        r"foo.forEach(([a, b], index) => { bar(a, b, index); });",
+        r"foo.forEach(element => { if (element) return; bar(element); });",
+        r"foo.forEach(element => { element = element + 1; bar(element); });",
+        r"this.items.forEach(item => { item.update(this); });",
+        r"foo.bar().forEach(element => { bar(element); })",
+        r"foo.forEach(element => { const fn = () => { return; }; fn(element); });",
+        r"if (foo) foo.forEach(element => bar(element));",
+        r"foo.forEach(function (element) { bar(this, element); });",
+        r"foo.forEach(function (element) { bar(arguments); });",
+        r"foo.forEach(element => { return element; });",
+        r"foo.forEach(element => { for (const x of element) { if (x) return; } });",
+        r"foo.forEach(element => { var x = element; });",
+        r"foo.forEach(element => bar(element), thisArg);",
+        r"const result = foo.forEach(element => bar(element));",
+        r"element.forEach(element => bar(element));",
+        r"foo.forEach(async element => { await bar(element); });",
+        r"foo.forEach(bar);",
+        r"foo.forEach(() => bar());",
+        r"foo.forEach((element, index = 0) => bar(element, index));",
+    ];
+
+    let fix = vec![
+        (
+            r"foo.forEach(element => bar(element));",
+            r"for (const element of foo) bar(element);",
+            None,
+        ),
+        (
+            r"foo.forEach((element, index) => { bar(element, index); });",
+            r"for (const [index, element] of foo.entries()) { bar(element, index); }",
+            None,
+        ),
+        (
+            r"foo.forEach(function (element) { bar(element); });",
+            r"for (const element of foo) { bar(element); }",
+            None,
+        ),
+        (
+            r"foo.forEach(({a, b}) => { bar(a, b); });",
+            r"for (const {a, b} of foo) { bar(a, b); }",
+            None,
+        ),
+        (
+            r"foo.forEach(([a, b], index) => { bar(a, b, index); });",
+            r"for (const [index, [a, b]] of foo.entries()) { bar(a, b, index); }",
+            None,
+        ),
+        (
+            r"foo.forEach(element => { if (element) return; bar(element); });",
+            r"for (const element of foo) { if (element) continue; bar(element); }",
+            None,
+        ),
+        (
+            r"foo.forEach(element => { element = element + 1; bar(element); });",
+            r"for (let element of foo) { element = element + 1; bar(element); }",
+            None,
+        ),
+        (
+            r"this.items.forEach(item => { item.update(this); });",
+            r"for (const item of this.items) { item.update(this); }",
+            None,
+        ),
+        (
+            r"foo.bar().forEach(element => { bar(element); })",
+            r"for (const element of foo.bar()) { bar(element); }",
+            None,
+        ),
+        (
+            r"foo.forEach(element => { const fn = () => { return; }; fn(element); });",
+            r"for (const element of foo) { const fn = () => { return; }; fn(element); }",
+            None,
+        ),
+        (
+            r"if (foo) foo.forEach(element => bar(element));",
+            r"if (foo) for (const element of foo) bar(element);",
+            None,
+        ),
+        // not fixable
+        (
+            r"foo.forEach(function (element) { bar(this, element); });",
+            r"foo.forEach(function (element) { bar(this, element); });",
+            None,
+        ),
+        (
+            r"foo.forEach(function (element) { bar(arguments); });",
+            r"foo.forEach(function (element) { bar(arguments); });",
+            None,
+        ),
+        (
+            r"foo.forEach(element => { return element; });",
+            r"foo.forEach(element => { return element; });",
+            None,
+        ),
+        (
+            r"foo.forEach(element => { for (const x of element) { if (x) return; } });",
+            r"foo.forEach(element => { for (const x of element) { if (x) return; } });",
+            None,
+        ),
+        (
+            r"foo.forEach(element => { var x = element; });",
+            r"foo.forEach(element => { var x = element; });",
+            None,
+        ),
+        (
+            r"foo.forEach(element => bar(element), thisArg);",
+            r"foo.forEach(element => bar(element), thisArg);",
+            None,
+        ),
+        (
+            r"const result = foo.forEach(element => bar(element));",
+            r"const result = foo.forEach(element => bar(element));",
+            None,
+        ),
+        (
+            r"element.forEach(element => bar(element));",
+            r"element.forEach(element => bar(element));",
+            None,
+        ),
+        (
+            r"foo.forEach(async element => { await bar(element); });",
+            r"foo.forEach(async element => { await bar(element); });",
+            None,
+        ),
+        (r"foo.forEach(bar);", r"foo.forEach(bar);", None),
+        (r"foo.forEach(() => bar());", r"foo.forEach(() => bar());", None),
+        (
+            r"foo.forEach((element, index = 0) => bar(element, index));",
+            r"foo.forEach((element, index = 0) => bar(element, index));",
+            None,
+        ),
     ];
 
-    Tester::new_without_config(NoArrayForEach::NAME, pass, fail).test_and_snapshot();
+    // `for-of` loops visit the holes of sparse arrays, and only work on iterables
+    Tester::new_without_config(NoArrayForEach::NAME, pass, fail)
+        .expect_fix(fix)
+        .expect_fix_kind(FixKind::Dangerous)
+        .test_and_snapshot();
 }
@@ -46,7 +46,8 @@ declare_oxc_lint!(
     ///
     /// ```
     NoConsoleSpaces,
-    style
+    style,
+    fix
 );
 
 impl Rule for NoConsoleSpaces {
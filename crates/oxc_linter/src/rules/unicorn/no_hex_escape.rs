@@ -9,7 +9,7 @@ use oxc_diagnostics::{
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
 
-use crate::{context::LintContext, rule::Rule, AstNode, Fix};
+use crate::{context::LintContext, rule::Rule, AstNode, Fix, FixKind};
 
 #[derive(Debug, Error, Diagnostic)]
 #[error(
@@ -37,7 +37,8 @@ declare_oxc_lint!(
     /// const foo = `\u001B${bar}`;
     /// ```
     NoHexEscape,
-    pedantic
+    pedantic,
+    fix
 );
 
 // \x -> \u00
@@ -76,7 +77,7 @@ impl Rule for NoHexEscape {
                 let text = span.source_text(ctx.source_text());
                 if let Some(fixed) = check_escape(&text[1..text.len() - 1]) {
                     ctx.diagnostic_with_fix(NoHexEscapeDiagnostic(*span), || {
-                        Fix::new(format!("'{fixed}'"), *span)
+                        Fix::new(format!("'{fixed}'"), *span).with_kind(FixKind::Safe)
                     });
                 }
             }
@@ -84,7 +85,7 @@ impl Rule for NoHexEscape {
                 quasis.iter().for_each(|quasi| {
                     if let Some(fixed) = check_escape(quasi.span.source_text(ctx.source_text())) {
                         ctx.diagnostic_with_fix(NoHexEscapeDiagnostic(quasi.span), || {
-                            Fix::new(fixed, quasi.span)
+                            Fix::new(fixed, quasi.span).with_kind(FixKind::Safe)
                         });
                     }
                 });
@@ -93,7 +94,7 @@ impl Rule for NoHexEscape {
                 let text = regex.span.source_text(ctx.source_text());
                 if let Some(fixed) = check_escape(&text[1..text.len() - 1]) {
                     ctx.diagnostic_with_fix(NoHexEscapeDiagnostic(regex.span), || {
-                        Fix::new(format!("/{fixed}/"), regex.span)
+                        Fix::new(format!("/{fixed}/"), regex.span).with_kind(FixKind::Safe)
                     });
                 }
             }
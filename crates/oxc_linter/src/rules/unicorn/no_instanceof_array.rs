@@ -4,9 +4,8 @@ use oxc_diagnostics::{
     miette::{self, Diagnostic},
     thiserror::Error,
 };
-use oxc_formatter::Gen;
 use oxc_macros::declare_oxc_lint;
-use oxc_span::Span;
+use oxc_span::{GetSpan, Span};
 use oxc_syntax::operator::BinaryOperator;
 
 use crate::{context::LintContext, fixer::Fix, rule::Rule, AstNode};
@@ -32,7 +31,8 @@ declare_oxc_lint!(
     /// [1,2,3] instanceof Array;
     /// ```
     NoInstanceofArray,
-    pedantic
+    pedantic,
+    fix
 );
 
 impl Rule for NoInstanceofArray {
@@ -42,27 +42,58 @@ impl Rule for NoInstanceofArray {
             return;
         }
 
-        match &expr.right.without_parenthesized() {
-            Expression::Identifier(identifier) if identifier.name == "Array" => {
-                ctx.diagnostic_with_fix(NoInstanceofArrayDiagnostic(expr.span), || {
-                    let modified_code = {
-                        let mut formatter = ctx.formatter();
-                        formatter.print_str(b"Array.isArray(");
-                        expr.left.gen(&mut formatter);
-                        formatter.print(b')');
-                        formatter.into_code()
-                    };
-                    Fix::new(modified_code, expr.span)
-                });
+        let Expression::Identifier(identifier) = &expr.right.without_parenthesized() else {
+            return;
+        };
+        if identifier.name != "Array" || !ctx.semantic().is_reference_to_global_variable(identifier)
+        {
+            return;
+        }
+
+        let diagnostic = NoInstanceofArrayDiagnostic(expr.span);
+
+        // `!(foo instanceof Array)` to `!Array.isArray(foo)`
+        let mut replaced_node = node;
+        while let Some(parent) = ctx.nodes().parent_node(replaced_node.id()) {
+            if !matches!(parent.kind(), AstKind::ParenthesizedExpression(_)) {
+                break;
             }
-            _ => {}
+            replaced_node = parent;
         }
+        let replaced_span = replaced_node.kind().span();
+
+        // Only the text of the left operand is kept
+        let left = expr.left.without_parenthesized();
+        let trivias = ctx.semantic().trivias();
+        if trivias.has_comments_between(Span::new(replaced_span.start, left.span().start))
+            || trivias.has_comments_between(Span::new(left.span().end, replaced_span.end))
+        {
+            ctx.diagnostic(diagnostic);
+            return;
+        }
+
+        ctx.diagnostic_with_fix(diagnostic, || {
+            let source_text = ctx.source_text();
+            let left_text = left.span().source_text(source_text);
+            let argument = if matches!(left, Expression::SequenceExpression(_)) {
+                format!("({left_text})")
+            } else {
+                left_text.to_string()
+            };
+            // `return[]instanceof Array`
+            let needs_space = source_text[..replaced_span.start as usize]
+                .chars()
+                .next_back()
+                .is_some_and(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '$'));
+            let space = if needs_space { " " } else { "" };
+            Fix::new(format!("{space}Array.isArray({argument})"), replaced_span)
+        });
     }
 }
 
 #[test]
 fn test() {
-    use crate::tester::Tester;
+    use crate::{tester::Tester, FixKind};
 
     let pass = vec![
         ("Array.isArray(arr)", None),
@@ -73,6 +104,9 @@ fn test() {
         ("a.x[2] instanceof foo()", None),
         ("Array.isArray([1,2,3]) === true", None),
         ("\"arr instanceof Array\"", None),
+        ("function foo(Array) { return arr instanceof Array }", None),
+        ("let Array; arr instanceof Array", None),
+        ("arr instanceof window.Array", None),
     ];
 
     let fail = vec![
@@ -85,12 +119,19 @@ fn test() {
         ("foo.bar[2] instanceof Array", None),
         ("(0, array) instanceof Array", None),
         ("function foo(){return [] instanceof Array}", None),
+        ("function foo(){return[]instanceof Array}", None),
+        ("!(arr instanceof Array)", None),
+        ("!((arr) instanceof Array)", None),
+        ("(a = b) instanceof Array", None),
+        ("((0, array)) instanceof Array", None),
+        ("arr /* comment */ instanceof Array", None),
     ];
 
     let fix = vec![
         ("arr instanceof Array", "Array.isArray(arr)", None),
         ("[] instanceof Array", "Array.isArray([])", None),
-        ("[1,2,3] instanceof Array === true", "Array.isArray([1, 2, 3]) === true", None),
+        ("[] instanceof (Array)", "Array.isArray([])", None),
+        ("[1,2,3] instanceof Array === true", "Array.isArray([1,2,3]) === true", None),
         ("fun.call(1, 2, 3) instanceof Array", "Array.isArray(fun.call(1, 2, 3))", None),
         ("obj.arr instanceof Array", "Array.isArray(obj.arr)", None),
         ("foo.bar[2] instanceof Array", "Array.isArray(foo.bar[2])", None),
@@ -100,7 +141,21 @@ fn test() {
             "function foo(){return Array.isArray([])}",
             None,
         ),
+        (
+            "function foo(){return[]instanceof Array}",
+            "function foo(){return Array.isArray([])}",
+            None,
+        ),
+        ("!(arr instanceof Array)", "!Array.isArray(arr)", None),
+        ("!((arr) instanceof Array)", "!Array.isArray(arr)", None),
+        ("(a = b) instanceof Array", "Array.isArray(a = b)", None),
+        ("((0, array)) instanceof Array", "Array.isArray((0, array))", None),
+        ("arr /* comment */ instanceof Array", "arr /* comment */ instanceof Array", None),
     ];
 
-    Tester::new(NoInstanceofArray::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
+    // `instanceof Array` is false for arrays of other realms
+    Tester::new(NoInstanceofArray::NAME, pass, fail)
+        .expect_fix(fix)
+        .expect_fix_kind(FixKind::Dangerous)
+        .test_and_snapshot();
 }
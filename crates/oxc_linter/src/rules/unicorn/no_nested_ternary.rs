@@ -6,7 +6,7 @@ use oxc_diagnostics::{
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
 
-use crate::{context::LintContext, rule::Rule, AstNode, Fix};
+use crate::{context::LintContext, rule::Rule, AstNode, Fix, FixKind};
 
 #[derive(Debug, Error, Diagnostic)]
 enum NoNestedTernaryDiagnostic {
@@ -45,7 +45,8 @@ declare_oxc_lint!(
     /// const foo = i > 5 ? (i < 100 ? true : false) : (i < 100 ? true : false);
     /// ```
     NoNestedTernary,
-    restriction
+    restriction,
+    fix
 );
 
 impl Rule for NoNestedTernary {
@@ -95,6 +96,7 @@ impl Rule for NoNestedTernary {
                             format!("({})", cond_expr.span.source_text(ctx.source_text())),
                             cond_expr.span,
                         )
+                        .with_kind(FixKind::Safe)
                     },
                 );
             }
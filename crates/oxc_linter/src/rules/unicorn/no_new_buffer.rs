@@ -1,12 +1,22 @@
-use oxc_ast::{ast::Expression, AstKind};
+use oxc_ast::{
+    ast::{Argument, Expression},
+    AstKind,
+};
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
     thiserror::Error,
 };
 use oxc_macros::declare_oxc_lint;
-use oxc_span::Span;
+use oxc_span::{GetSpan, Span};
+use oxc_syntax::operator::{BinaryOperator, UnaryOperator};
 
-use crate::{context::LintContext, rule::Rule, AstNode};
+use crate::{
+    context::LintContext,
+    fixer::{Fix, FixKind},
+    rule::Rule,
+    utils::needs_leading_semicolon,
+    AstNode,
+};
 
 #[derive(Debug, Error, Diagnostic)]
 #[error("eslint-plugin-unicorn(no-new-buffer): Use `Buffer.alloc()` or `Buffer.from()` instead of the deprecated `new Buffer()` constructor.")]
@@ -32,25 +42,117 @@ declare_oxc_lint!(
     /// ```javascript
     /// // Bad
     /// const buffer = new Buffer(10);
+    /// const buffer = Buffer('string');
     ///
     /// // Good
     /// const buffer = Buffer.alloc(10);
+    /// const buffer = Buffer.from('string');
     /// ```
     NoNewBuffer,
-    pedantic
+    pedantic,
+    fix
 );
 
 impl Rule for NoNewBuffer {
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
-        let AstKind::NewExpression(new_expr) = node.kind() else { return };
+        // `new Buffer()` and `Buffer()`
+        let (span, callee, arguments, is_new) = match node.kind() {
+            AstKind::NewExpression(new_expr) => {
+                (new_expr.span, &new_expr.callee, &new_expr.arguments, true)
+            }
+            AstKind::CallExpression(call_expr) if !call_expr.optional => {
+                (call_expr.span, &call_expr.callee, &call_expr.arguments, false)
+            }
+            _ => return,
+        };
 
-        let Expression::Identifier(ident) = &new_expr.callee.without_parenthesized() else {
+        let Expression::Identifier(ident) = &callee.without_parenthesized() else {
             return;
         };
         if ident.name != "Buffer" {
             return;
         }
-        ctx.diagnostic(NoNewBufferDiagnostic(ident.span));
+
+        let diagnostic = NoNewBufferDiagnostic(ident.span);
+        let Some(method) = infer_method(arguments) else {
+            ctx.diagnostic(diagnostic);
+            return;
+        };
+
+        ctx.diagnostic_with_fix(diagnostic, || {
+            let source_text = ctx.source_text();
+            let callee_span = callee.span();
+            // Comments between `new` and `Buffer` are kept
+            let leading = if is_new {
+                Span::new(span.start + 3, callee_span.start).source_text(source_text).trim_start()
+            } else {
+                ""
+            };
+            let callee = callee_span.source_text(source_text);
+            let arguments = match Span::new(callee_span.end, span.end).source_text(source_text) {
+                // `new Buffer`
+                "" => "()",
+                arguments => arguments,
+            };
+            let mut fixed = format!("{leading}{callee}.{method}{arguments}");
+            if fixed.starts_with('(') && needs_leading_semicolon(node, ctx) {
+                fixed.insert(0, ';');
+            }
+            Fix::new(fixed, span).with_kind(FixKind::Safe)
+        });
+    }
+}
+
+/// `Buffer.alloc()` for a size, `Buffer.from()` for anything else that is known
+fn infer_method(arguments: &[Argument]) -> Option<&'static str> {
+    let [argument] = arguments else { return Some("from") };
+    let Argument::Expression(expr) = argument else { return None };
+    match expr.without_parenthesized() {
+        Expression::ArrayExpression(_)
+        | Expression::TemplateLiteral(_)
+        | Expression::StringLiteral(_) => Some("from"),
+        expr if is_number(expr) => Some("alloc"),
+        _ => None,
+    }
+}
+
+fn is_number(expr: &Expression) -> bool {
+    match expr.without_parenthesized() {
+        Expression::NumberLiteral(_) => true,
+        Expression::UnaryExpression(unary_expr) => match unary_expr.operator {
+            UnaryOperator::UnaryPlus | UnaryOperator::BitwiseNot => true,
+            UnaryOperator::UnaryNegation => is_number(&unary_expr.argument),
+            _ => false,
+        },
+        Expression::BinaryExpression(bin_expr) => match bin_expr.operator {
+            BinaryOperator::Addition => is_number(&bin_expr.left) && is_number(&bin_expr.right),
+            operator => operator.is_arithmetic() || operator.is_bitwise() || operator.is_bitshift(),
+        },
+        Expression::ConditionalExpression(cond_expr) => {
+            is_number(&cond_expr.consequent) && is_number(&cond_expr.alternate)
+        }
+        // `foo.length`, `Number.MAX_SAFE_INTEGER`
+        Expression::MemberExpression(member_expr) => {
+            matches!(member_expr.static_property_name(), Some("length"))
+                || member_expr.object().is_specific_id("Number")
+                || member_expr.object().is_specific_id("Math")
+        }
+        // `Number(foo)`, `parseInt(foo)`, `Math.min(foo, bar)`
+        Expression::CallExpression(call_expr) => match &call_expr.callee {
+            Expression::Identifier(ident) => {
+                matches!(ident.name.as_str(), "Number" | "parseInt" | "parseFloat")
+            }
+            Expression::MemberExpression(member_expr) => {
+                member_expr.object().is_specific_id("Math")
+                    || (member_expr.object().is_specific_id("Number")
+                        && matches!(
+                            member_expr.static_property_name(),
+                            Some("parseInt" | "parseFloat")
+                        ))
+            }
+            _ => false,
+        },
+        _ => false,
     }
 }
 
@@ -65,6 +167,9 @@ fn test() {
         r"const buffer = Buffer.from('7468697320697320612074c3a97374', 'hex')",
         r"const buffer = Buffer.from([0x62, 0x75, 0x66, 0x66, 0x65, 0x72])",
         r"const buffer = Buffer.alloc(10)",
+        r"const buffer = new foo.Buffer(10)",
+        r"const buffer = foo.Buffer(10)",
+        r"const buffer = Buffer?.(10)",
     ];
 
     let fail = vec![
@@ -82,7 +187,70 @@ fn test() {
         r"const buffer = new /* comment */ Buffer()",
         r"const buffer = new /* comment */ Buffer",
         r"new Buffer(input, encoding);",
+        r"const buffer = Buffer(10)",
+        r#"const buffer = Buffer("string")"#,
+        r"const buffer = Buffer(unknown)",
+        r"foo
+new (Buffer)(10)",
+    ];
+
+    let fix = vec![
+        (
+            r"const buffer = new Buffer([0x62, 0x75, 0x66, 0x66, 0x65, 0x72])",
+            r"const buffer = Buffer.from([0x62, 0x75, 0x66, 0x66, 0x65, 0x72])",
+            None,
+        ),
+        (
+            r"const buffer = new Buffer([0x62, bar])",
+            r"const buffer = Buffer.from([0x62, bar])",
+            None,
+        ),
+        (r"const buffer = new Buffer(10);", r"const buffer = Buffer.alloc(10);", None),
+        (r"new Buffer(foo.length)", r"Buffer.alloc(foo.length)", None),
+        (r"new Buffer(Math.min(foo, bar))", r"Buffer.alloc(Math.min(foo, bar))", None),
+        (
+            r#"const buffer = new Buffer("string");"#,
+            r#"const buffer = Buffer.from("string");"#,
+            None,
+        ),
+        (
+            r#"const buffer = new Buffer("7468697320697320612074c3a97374", "hex")"#,
+            r#"const buffer = Buffer.from("7468697320697320612074c3a97374", "hex")"#,
+            None,
+        ),
+        (
+            r"const buffer = new Buffer(`${unknown}`)",
+            r"const buffer = Buffer.from(`${unknown}`)",
+            None,
+        ),
+        (r"const buffer = new (Buffer)(unknown)", r"const buffer = new (Buffer)(unknown)", None),
+        (r"const buffer = new Buffer(unknown, 2)", r"const buffer = Buffer.from(unknown, 2)", None),
+        (r"const buffer = new Buffer(...unknown)", r"const buffer = new Buffer(...unknown)", None),
+        (
+            r"const buffer = new /* comment */ Buffer()",
+            r"const buffer = /* comment */ Buffer.from()",
+            None,
+        ),
+        (
+            r"const buffer = new /* comment */ Buffer",
+            r"const buffer = /* comment */ Buffer.from()",
+            None,
+        ),
+        (r"new Buffer(input, encoding);", r"Buffer.from(input, encoding);", None),
+        (r"const buffer = Buffer(10)", r"const buffer = Buffer.alloc(10)", None),
+        (r#"const buffer = Buffer("string")"#, r#"const buffer = Buffer.from("string")"#, None),
+        (r"const buffer = Buffer(unknown)", r"const buffer = Buffer(unknown)", None),
+        (
+            r"foo
+new (Buffer)(10)",
+            r"foo
+;(Buffer).alloc(10)",
+            None,
+        ),
     ];
 
-    Tester::new_without_config(NoNewBuffer::NAME, pass, fail).test_and_snapshot();
+    Tester::new_without_config(NoNewBuffer::NAME, pass, fail)
+        .expect_fix(fix)
+        .expect_fix_kind(FixKind::Safe)
+        .test_and_snapshot();
 }
@@ -29,7 +29,8 @@ declare_oxc_lint!(
     /// await await promise;
     /// ```
     NoUnnecessaryAwait,
-    correctness
+    correctness,
+    fix
 );
 
 impl Rule for NoUnnecessaryAwait {
@@ -0,0 +1,489 @@
+use oxc_ast::{
+    ast::{
+        Argument, AssignmentTarget, AssignmentTargetPattern, AssignmentTargetProperty,
+        CallExpression, Expression, VariableDeclarationKind,
+    },
+    AstKind,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+
+use crate::{
+    context::LintContext,
+    fixer::{Fix, FixKind},
+    rule::Rule,
+    AstNode,
+};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint-plugin-unicorn(no-useless-undefined): Do not use useless `undefined`.")]
+#[diagnostic(severity(warning), help("Remove the `undefined`, it is the default value."))]
+struct NoUselessUndefinedDiagnostic(#[label] pub Span);
+
+#[derive(Debug, Clone)]
+pub struct NoUselessUndefined {
+    check_arguments: bool,
+    check_arrow_function_body: bool,
+}
+
+impl Default for NoUselessUndefined {
+    fn default() -> Self {
+        Self { check_arguments: true, check_arrow_function_body: true }
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow useless `undefined`.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// `undefined` is the default value for new variables, parameters, return statements, etc…
+    /// so specifying it doesn't make any difference.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// // bad
+    /// let foo = undefined;
+    /// const {foo = undefined} = bar;
+    /// const noop = () => undefined;
+    /// function foo() { return undefined; }
+    /// function* foo() { yield undefined; }
+    /// function foo(bar = undefined) {}
+    /// foo(undefined);
+    ///
+    /// // good
+    /// let foo;
+    /// const {foo} = bar;
+    /// const noop = () => {};
+    /// function foo() { return; }
+    /// function* foo() { yield; }
+    /// function foo(bar) {}
+    /// foo();
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// `checkArguments`: whether to check `undefined` as the last arguments of a call,
+    /// default `true`.
+    ///
+    /// `checkArrowFunctionBody`: whether to check `() => undefined`, default `true`.
+    NoUselessUndefined,
+    nursery,
+    fix
+);
+
+/// Functions whose `undefined` arguments are meaningful, e.g. `expect(foo).toBe(undefined)`
+fn is_ignored_callee(name: &str) -> bool {
+    matches!(
+        name,
+        "is" | "equal"
+            | "notEqual"
+            | "strictEqual"
+            | "notStrictEqual"
+            | "propertyVal"
+            | "notPropertyVal"
+            | "not"
+            | "include"
+            | "property"
+            | "toBe"
+            | "toHaveBeenCalledWith"
+            | "toContain"
+            | "toContainEqual"
+            | "toEqual"
+            | "same"
+            | "notSame"
+            | "strictSame"
+            | "strictNotSame"
+            // `array.push(undefined)`, `set.add(undefined)`, `map.set(foo, undefined)`, ...
+            | "push"
+            | "unshift"
+            | "includes"
+            | "add"
+            | "has"
+            | "set"
+            // React and Vue
+            | "createContext"
+            | "useState"
+            | "useRef"
+            | "ref"
+    ) || name
+        .strip_prefix("set")
+        .is_some_and(|rest| rest.starts_with(|c: char| c.is_ascii_uppercase()))
+}
+
+impl Rule for NoUselessUndefined {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let config = value.get(0);
+        let get_bool = |name: &str| {
+            config.and_then(|config| config.get(name)).and_then(serde_json::Value::as_bool)
+        };
+        Self {
+            check_arguments: get_bool("checkArguments").unwrap_or(true),
+            check_arrow_function_body: get_bool("checkArrowFunctionBody").unwrap_or(true),
+        }
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        match node.kind() {
+            // `return undefined`
+            AstKind::ReturnStatement(stmt) => {
+                let Some(argument) = stmt.argument.as_ref().filter(|expr| expr.is_undefined())
+                else {
+                    return;
+                };
+                if !has_return_type(node, ctx) {
+                    report_removal(
+                        argument.span(),
+                        remove_with_leading_space(argument, ctx),
+                        FixKind::Safe,
+                        ctx,
+                    );
+                }
+            }
+            // `yield undefined`
+            AstKind::YieldExpression(yield_expr) if !yield_expr.delegate => {
+                let Some(argument) =
+                    yield_expr.argument.as_ref().filter(|expr| expr.is_undefined())
+                else {
+                    return;
+                };
+                if !has_return_type(node, ctx) {
+                    report_removal(
+                        argument.span(),
+                        remove_with_leading_space(argument, ctx),
+                        FixKind::Safe,
+                        ctx,
+                    );
+                }
+            }
+            // `() => undefined`
+            AstKind::ArrowExpression(arrow) if self.check_arrow_function_body => {
+                let Some(body) = arrow.get_expression().filter(|expr| expr.is_undefined()) else {
+                    return;
+                };
+                if arrow.return_type.is_some() {
+                    return;
+                }
+                ctx.diagnostic_with_fix(NoUselessUndefinedDiagnostic(body.span()), || {
+                    Fix::new(" {}", remove_with_leading_space(body, ctx)).with_kind(FixKind::Safe)
+                });
+            }
+            // `let foo = undefined`
+            AstKind::VariableDeclarator(decl) if decl.kind != VariableDeclarationKind::Const => {
+                let Some(init) = decl.init.as_ref().filter(|expr| expr.is_undefined()) else {
+                    return;
+                };
+                // `var foo = undefined` resets `foo` when it is declared again, e.g. in a loop
+                let kind = if decl.kind == VariableDeclarationKind::Var {
+                    FixKind::Dangerous
+                } else {
+                    FixKind::Safe
+                };
+                report_removal(init.span(), remove_initializer(init, ctx), kind, ctx);
+            }
+            // `function foo(bar = undefined) {}` and `const {foo = undefined} = bar`
+            AstKind::AssignmentPattern(pattern) if pattern.right.is_undefined() => {
+                // The default value of a parameter changes the `length` of its function
+                let kind = if matches!(
+                    ctx.nodes().parent_kind(node.id()),
+                    Some(AstKind::FormalParameter(_))
+                ) {
+                    FixKind::Dangerous
+                } else {
+                    FixKind::Safe
+                };
+                report_removal(
+                    pattern.right.span(),
+                    remove_initializer(&pattern.right, ctx),
+                    kind,
+                    ctx,
+                );
+            }
+            // `({foo: bar = undefined} = baz)` and `[foo = undefined] = bar`
+            AstKind::AssignmentTargetWithDefault(target) if target.init.is_undefined() => {
+                report_removal(
+                    target.init.span(),
+                    remove_initializer(&target.init, ctx),
+                    FixKind::Safe,
+                    ctx,
+                );
+            }
+            // `({foo = undefined} = bar)`
+            AstKind::AssignmentTarget(AssignmentTarget::AssignmentTargetPattern(
+                AssignmentTargetPattern::ObjectAssignmentTarget(target),
+            )) => {
+                for property in &target.properties {
+                    let AssignmentTargetProperty::AssignmentTargetPropertyIdentifier(ident) =
+                        property
+                    else {
+                        continue;
+                    };
+                    let Some(init) = ident.init.as_ref().filter(|init| init.is_undefined()) else {
+                        continue;
+                    };
+                    report_removal(init.span(), remove_initializer(init, ctx), FixKind::Safe, ctx);
+                }
+            }
+            // `foo(bar, undefined)`
+            AstKind::CallExpression(call_expr) if self.check_arguments => {
+                check_arguments(call_expr, ctx);
+            }
+            _ => {}
+        }
+    }
+}
+
+fn check_arguments(call_expr: &CallExpression, ctx: &LintContext) {
+    let callee_name = match &call_expr.callee {
+        Expression::Identifier(ident) => Some(ident.name.as_str()),
+        Expression::MemberExpression(member_expr) if !member_expr.is_computed() => {
+            member_expr.static_property_name()
+        }
+        _ => None,
+    };
+    if callee_name.is_some_and(is_ignored_callee) {
+        return;
+    }
+    // `foo.bind(undefined)` binds `this`
+    if callee_name == Some("bind") {
+        return;
+    }
+
+    let arguments = &call_expr.arguments;
+    let undefined_count = arguments
+        .iter()
+        .rev()
+        .take_while(
+            |argument| matches!(argument, Argument::Expression(expr) if expr.is_undefined()),
+        )
+        .count();
+    if undefined_count == 0 {
+        return;
+    }
+    let first_undefined = arguments[arguments.len() - undefined_count].span();
+    let last_undefined = arguments[arguments.len() - 1].span();
+
+    let removed_span = if undefined_count < arguments.len() {
+        // `foo(bar, undefined)` to `foo(bar)`
+        let previous = arguments[arguments.len() - undefined_count - 1].span();
+        Span::new(previous.end, last_undefined.end)
+    } else {
+        // `foo(undefined,)` to `foo()`
+        let text_after =
+            &ctx.source_text()[last_undefined.end as usize..call_expr.span.end as usize];
+        let end = text_after
+            .find(|c: char| !c.is_whitespace())
+            .filter(|&offset| text_after[offset..].starts_with(','))
+            .map_or(last_undefined.end, |offset| {
+                // `,` is a single byte
+                last_undefined.end + u32::try_from(offset).unwrap() + 1
+            });
+        Span::new(first_undefined.start, end)
+    };
+
+    ctx.diagnostic_with_fix(
+        NoUselessUndefinedDiagnostic(Span::new(first_undefined.start, last_undefined.end)),
+        || Fix::delete(removed_span),
+    );
+}
+
+fn report_removal(span: Span, removed_span: Span, kind: FixKind, ctx: &LintContext) {
+    ctx.diagnostic_with_fix(NoUselessUndefinedDiagnostic(span), || {
+        Fix::delete(removed_span).with_kind(kind)
+    });
+}
+
+/// Whether the function containing `node` declares its return type, e.g. `(): undefined`
+fn has_return_type(node: &AstNode, ctx: &LintContext) -> bool {
+    ctx.nodes()
+        .iter_parents(node.id())
+        .find_map(|parent| match parent.kind() {
+            AstKind::Function(func) => Some(func.return_type.is_some()),
+            AstKind::ArrowExpression(arrow) => Some(arrow.return_type.is_some()),
+            _ => None,
+        })
+        .unwrap_or(false)
+}
+
+/// `return undefined` to `return`
+fn remove_with_leading_space(expr: &Expression, ctx: &LintContext) -> Span {
+    let span = expr.span();
+    let text_before = ctx.source_text()[..span.start as usize].trim_end();
+    Span::new(u32::try_from(text_before.len()).unwrap(), span.end)
+}
+
+/// `foo = undefined` to `foo`
+fn remove_initializer(init: &Expression, ctx: &LintContext) -> Span {
+    let span = init.span();
+    let text_before = ctx.source_text()[..span.start as usize].trim_end();
+    let text_before = text_before.strip_suffix('=').unwrap_or(text_before).trim_end();
+    Span::new(u32::try_from(text_before.len()).unwrap(), span.end)
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("function foo() {return;}", None),
+        ("const foo = () => {};", None),
+        ("let foo;", None),
+        ("var foo;", None),
+        ("const foo = undefined;", None),
+        ("foo();", None),
+        ("foo(bar,);", None),
+        ("foo(undefined, bar);", None),
+        ("const {foo} = {};", None),
+        ("function foo({bar}) {}", None),
+        ("function foo([bar]) {}", None),
+        ("if (foo === undefined) {}", None),
+        ("function* foo() {yield* undefined;}", None),
+        ("function foo() {return undefined + 1;}", None),
+        ("function foo(): undefined {return undefined;}", None),
+        ("function* foo(): Generator<undefined> {yield undefined;}", None),
+        ("const foo = (): undefined => undefined;", None),
+        ("const foo = (): undefined => {return undefined};", None),
+        // Ignored callees
+        ("is(foo, undefined);", None),
+        ("t.is(foo, undefined);", None),
+        ("assert.equal(foo, undefined, message);", None),
+        ("assert.notEqual(foo, undefined, message);", None),
+        ("assert.strictEqual(foo, undefined, message);", None),
+        ("assert.notStrictEqual(foo, undefined, message);", None),
+        ("assert.propertyVal(foo, \"bar\", undefined, message);", None),
+        ("assert.notPropertyVal(foo, \"bar\", undefined, message);", None),
+        ("expect(foo).not(undefined);", None),
+        ("expect(foo).to.have.property(\"bar\", undefined);", None),
+        ("expect(foo).toBe(undefined);", None),
+        ("expect(foo).toContain(undefined);", None),
+        ("expect(foo).toContainEqual(undefined);", None),
+        ("expect(foo).toEqual(undefined);", None),
+        ("t.same(foo, undefined);", None),
+        ("t.notSame(foo, undefined);", None),
+        ("t.strictSame(foo, undefined);", None),
+        ("t.strictNotSame(foo, undefined);", None),
+        ("expect(someFunction).toHaveBeenCalledWith(1, 2, undefined);", None),
+        ("set.add(undefined);", None),
+        ("map.set(foo, undefined);", None),
+        ("array.push(foo, undefined);", None),
+        ("array.push(undefined);", None),
+        ("array.unshift(foo, undefined);", None),
+        ("array.unshift(undefined);", None),
+        ("array.includes(undefined);", None),
+        ("set.has(undefined);", None),
+        ("createContext(undefined);", None),
+        ("React.createContext(undefined);", None),
+        ("setState(undefined)", None),
+        ("setState?.(undefined)", None),
+        ("props.setState(undefined)", None),
+        ("props.setState?.(undefined)", None),
+        ("useState(undefined)", None),
+        ("React.useState(undefined)", None),
+        ("useRef(undefined)", None),
+        ("React.useRef(undefined)", None),
+        ("ref(undefined)", None),
+        ("foo.bind(undefined)", None),
+        ("foo.bind(bar, undefined)", None),
+        // Options
+        ("foo(undefined, undefined);", Some(serde_json::json!([{ "checkArguments": false }]))),
+        (
+            "const foo = () => undefined",
+            Some(serde_json::json!([{ "checkArrowFunctionBody": false }])),
+        ),
+    ];
+
+    let fail = vec![
+        ("function foo() {return undefined;}", None),
+        ("const foo = () => undefined;", None),
+        ("const foo = () => {return undefined;};", None),
+        ("function foo() {return       undefined;}", None),
+        ("function foo() {return /* comment */ undefined;}", None),
+        ("function* foo() {yield undefined;}", None),
+        ("function* foo() {yield                 undefined;}", None),
+        ("let a = undefined;", None),
+        ("let a = undefined, b = 2;", None),
+        ("var a = undefined;", None),
+        ("var a = undefined, b = 2;", None),
+        ("let a: string | undefined = undefined;", None),
+        ("foo(undefined);", None),
+        ("foo(undefined, undefined);", None),
+        ("foo(undefined,);", None),
+        ("foo(undefined, undefined,);", None),
+        ("foo(bar, undefined);", None),
+        ("foo(bar, undefined, undefined);", None),
+        ("foo(undefined, bar, undefined);", None),
+        ("foo(bar, undefined,);", None),
+        ("foo(undefined, bar, undefined,);", None),
+        ("foo(bar, undefined, undefined,);", None),
+        ("foo(undefined, bar, undefined, undefined,);", None),
+        ("foo?.(undefined);", None),
+        ("foo.bar(undefined);", None),
+        ("const {foo = undefined} = {};", None),
+        ("const [foo = undefined] = [];", None),
+        ("function foo(bar = undefined) {}", None),
+        ("function foo({bar = undefined}) {}", None),
+        ("function foo({bar = undefined} = {}) {}", None),
+        ("function foo([bar = undefined]) {}", None),
+        ("function foo([bar = undefined] = []) {}", None),
+        ("({foo = undefined} = bar);", None),
+        ("const foo = function (): string {return () => undefined}", None),
+    ];
+
+    let fix = vec![
+        ("function foo() {return undefined;}", "function foo() {return;}", None),
+        ("const foo = () => undefined;", "const foo = () => {};", None),
+        ("const foo = () => {return undefined;};", "const foo = () => {return;};", None),
+        ("function foo() {return       undefined;}", "function foo() {return;}", None),
+        ("function* foo() {yield undefined;}", "function* foo() {yield;}", None),
+        ("function* foo() {yield                 undefined;}", "function* foo() {yield;}", None),
+        ("let a = undefined;", "let a;", None),
+        ("let a = undefined, b = 2;", "let a, b = 2;", None),
+        ("let a: string | undefined = undefined;", "let a: string | undefined;", None),
+        ("const {foo = undefined} = {};", "const {foo} = {};", None),
+        ("const [foo = undefined] = [];", "const [foo] = [];", None),
+        ("function foo({bar = undefined}) {}", "function foo({bar}) {}", None),
+        ("function foo({bar = undefined} = {}) {}", "function foo({bar} = {}) {}", None),
+        ("function foo([bar = undefined]) {}", "function foo([bar]) {}", None),
+        ("({foo = undefined} = bar);", "({foo} = bar);", None),
+        (
+            "const foo = function (): string {return () => undefined}",
+            "const foo = function (): string {return () => {}}",
+            None,
+        ),
+    ];
+
+    let dangerous_fix = vec![
+        ("var a = undefined;", "var a;", None),
+        ("foo(undefined);", "foo();", None),
+        ("foo(undefined, undefined);", "foo();", None),
+        ("foo(undefined,);", "foo();", None),
+        ("foo(undefined, undefined,);", "foo();", None),
+        ("foo(bar, undefined);", "foo(bar);", None),
+        ("foo(bar, undefined, undefined);", "foo(bar);", None),
+        ("foo(undefined, bar, undefined);", "foo(undefined, bar);", None),
+        ("foo(bar, undefined,);", "foo(bar,);", None),
+        ("foo(undefined, bar, undefined,);", "foo(undefined, bar,);", None),
+        ("foo(bar, undefined, undefined,);", "foo(bar,);", None),
+        ("foo?.(undefined);", "foo?.();", None),
+        ("foo.bar(undefined);", "foo.bar();", None),
+        ("function foo(bar = undefined) {}", "function foo(bar) {}", None),
+    ];
+
+    Tester::new(NoUselessUndefined::NAME, pass, fail)
+        .expect_fix(fix)
+        .expect_fix_kind(FixKind::Safe)
+        .test_and_snapshot();
+
+    // Removing an argument changes `arguments.length`, removing the default value of a parameter
+    // changes the `length` of its function, and `var` declarations may be declared again
+    Tester::new_without_config::<String>(NoUselessUndefined::NAME, vec![], vec![])
+        .expect_fix(dangerous_fix)
+        .expect_fix_kind(FixKind::Dangerous)
+        .test();
+}
@@ -4,9 +4,11 @@ use oxc_diagnostics::{
     thiserror::Error,
 };
 use oxc_macros::declare_oxc_lint;
-use oxc_span::Span;
+use oxc_span::{GetSpan, Span};
 
-use crate::{context::LintContext, rule::Rule, AstNode, Fix};
+use crate::{
+    context::LintContext, rule::Rule, utils::needs_leading_semicolon, AstNode, Fix, FixKind,
+};
 
 #[derive(Debug, Error, Diagnostic)]
 enum NoZeroFractionsDiagnostic {
@@ -44,7 +46,8 @@ declare_oxc_lint!(
     /// const foo = 1.1;
     /// ```
     NoZeroFractions,
-    style
+    style,
+    fix
 );
 
 impl Rule for NoZeroFractions {
@@ -57,23 +60,47 @@ impl Rule for NoZeroFractions {
         if fmt == number_literal.raw {
             return;
         };
+        // `1.0.toFixed()` can not become `1.toFixed()`, the dot would be part of the number.
+        let is_member_object = matches!(
+            ctx.nodes().parent_kind(node.id()),
+            Some(AstKind::MemberExpression(member))
+                if !member.is_computed() && member.object().span() == number_literal.span
+        );
+        let mut fixed = if is_member_object && fmt.bytes().all(|b| b.is_ascii_digit() || b == b'_')
+        {
+            // `foo\n1.0.toFixed()` to `foo\n;(1).toFixed()`
+            if needs_leading_semicolon(node, ctx) {
+                format!(";({fmt})")
+            } else {
+                format!("({fmt})")
+            }
+        } else {
+            fmt.clone()
+        };
+        // `return.0` to `return 0` instead of `return0`
+        let before = &ctx.source_text()[..number_literal.span.start as usize];
+        if before.ends_with(|c: char| c.is_ascii_alphanumeric() || c == '_' || c == '$') {
+            fixed.insert(0, ' ');
+        }
 
         ctx.diagnostic_with_fix(
             if is_dangling_dot {
-                NoZeroFractionsDiagnostic::DanglingDot(number_literal.span, fmt.clone())
+                NoZeroFractionsDiagnostic::DanglingDot(number_literal.span, fmt)
             } else {
-                NoZeroFractionsDiagnostic::ZeroFraction(number_literal.span, fmt.clone())
+                NoZeroFractionsDiagnostic::ZeroFraction(number_literal.span, fmt)
             },
-            || Fix::new(fmt, number_literal.span),
+            || Fix::new(fixed, number_literal.span).with_kind(FixKind::Safe),
         );
     }
 }
 
 fn format_raw(raw: &str) -> Option<(String, bool)> {
     let (before, after_and_dot) = raw.split_once('.')?;
-    let mut after_parts = after_and_dot.splitn(2, |c: char| !c.is_ascii_digit() && c != '_');
-    let dot_and_fractions = after_parts.next()?;
-    let after = after_parts.next().unwrap_or("");
+    // The exponent or member access following the fraction is kept as is.
+    let fraction_end = after_and_dot
+        .find(|c: char| !c.is_ascii_digit() && c != '_')
+        .unwrap_or(after_and_dot.len());
+    let (dot_and_fractions, after) = after_and_dot.split_at(fraction_end);
 
     let fixed_dot_and_fractions =
         dot_and_fractions.trim_end_matches(|c: char| c == '0' || c == '.' || c == '_');
@@ -131,7 +158,43 @@ fn test() {
         r"function foo(){return.0}",
         r"function foo(){return.0.toString()}",
         r"function foo(){return.0+.1}",
+        r"const foo = 1.0e10",
+        r"const foo = 1.50",
+        "foo\n1.0.toFixed()",
+    ];
+
+    let fix = vec![
+        (r"const foo = 1.0", r"const foo = 1", None),
+        (r"const foo = 1.0 + 1", r"const foo = 1 + 1", None),
+        (r"foo(1.0 + 1)", r"foo(1 + 1)", None),
+        (r"const foo = 1.00", r"const foo = 1", None),
+        (r"const foo = 1.00000", r"const foo = 1", None),
+        (r"const foo = -1.0", r"const foo = -1", None),
+        (r"const foo = 123123123.0", r"const foo = 123123123", None),
+        (r"const foo = 123.11100000000", r"const foo = 123.111", None),
+        (r"const foo = 1.", r"const foo = 1", None),
+        (r"const foo = +1.", r"const foo = +1", None),
+        (r"const foo = -1.", r"const foo = -1", None),
+        (r"const foo = 1.e10", r"const foo = 1e10", None),
+        (r"const foo = +1.e-10", r"const foo = +1e-10", None),
+        (r"const foo = -1.e+10", r"const foo = -1e+10", None),
+        (r"const foo = (1.).toString()", r"const foo = (1).toString()", None),
+        (r"1.00.toFixed(2)", r"(1).toFixed(2)", None),
+        (r"1.00 .toFixed(2)", r"(1) .toFixed(2)", None),
+        (r"(1.00).toFixed(2)", r"(1).toFixed(2)", None),
+        (r"1.00?.toFixed(2)", r"(1)?.toFixed(2)", None),
+        (r"a = .0;", r"a = 0;", None),
+        (r"a = .0.toString()", r"a = (0).toString()", None),
+        (r"function foo(){return.0}", r"function foo(){return 0}", None),
+        (r"function foo(){return.0.toString()}", r"function foo(){return (0).toString()}", None),
+        (r"function foo(){return.0+.1}", r"function foo(){return 0+.1}", None),
+        (r"const foo = 1.0e10", r"const foo = 1e10", None),
+        (r"const foo = 1.50", r"const foo = 1.5", None),
+        ("foo\n1.0.toFixed()", "foo\n;(1).toFixed()", None),
     ];
 
-    Tester::new_without_config(NoZeroFractions::NAME, pass, fail).test_and_snapshot();
+    Tester::new_without_config(NoZeroFractions::NAME, pass, fail)
+        .expect_fix(fix)
+        .expect_fix_kind(FixKind::Safe)
+        .test_and_snapshot();
 }
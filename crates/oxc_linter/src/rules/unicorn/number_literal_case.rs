@@ -6,7 +6,7 @@ use oxc_diagnostics::{
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
 
-use crate::{context::LintContext, rule::Rule, AstNode, Fix};
+use crate::{context::LintContext, rule::Rule, AstNode, Fix, FixKind};
 
 #[derive(Debug, Error, Diagnostic)]
 enum NumberLiteralCaseDiagnostic {
@@ -64,7 +64,8 @@ declare_oxc_lint!(
     /// const foo = 2e+5;
     /// ```
     NumberLiteralCase,
-    style
+    style,
+    fix
 );
 
 impl Rule for NumberLiteralCase {
@@ -79,7 +80,9 @@ impl Rule for NumberLiteralCase {
         };
 
         if let Some((diagnostic, fixed_literal)) = check_number_literal(raw_literal, raw_span) {
-            ctx.diagnostic_with_fix(diagnostic, || Fix::new(fixed_literal, raw_span));
+            ctx.diagnostic_with_fix(diagnostic, || {
+                Fix::new(fixed_literal, raw_span).with_kind(FixKind::Safe)
+            });
         }
     }
 }
@@ -11,7 +11,12 @@ use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
 use regex::Regex;
 
-use crate::{context::LintContext, fixer::Fix, rule::Rule, AstNode};
+use crate::{
+    context::LintContext,
+    fixer::{Fix, FixKind},
+    rule::Rule,
+    AstNode,
+};
 
 #[derive(Debug, Error, Diagnostic)]
 #[error("eslint-plugin-unicorn(numeric-separators-style): Invalid group length in numeric value.")]
@@ -45,10 +50,10 @@ impl Default for NumericSeparatorsStyleConfig {
     fn default() -> Self {
         Self {
             only_if_contains_separator: false,
-            binary: NumericBaseConfig { group_length: 4, minimum_digits: 0 },
-            hexadecimal: NumericBaseConfig { group_length: 2, minimum_digits: 0 },
-            number: NumericBaseConfig { group_length: 3, minimum_digits: 5 },
-            octal: NumericBaseConfig { group_length: 4, minimum_digits: 0 },
+            binary: NumericBaseConfig::new(4, 0),
+            hexadecimal: NumericBaseConfig::new(2, 0),
+            number: NumericBaseConfig::new(3, 5),
+            octal: NumericBaseConfig::new(4, 0),
         }
     }
 }
@@ -84,14 +89,17 @@ declare_oxc_lint!(
     /// ];
     /// ```
     NumericSeparatorsStyle,
-    style
+    style,
+    fix
 );
 
 impl Rule for NumericSeparatorsStyle {
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         match node.kind() {
             AstKind::NumberLiteral(number) => {
-                if self.only_if_contains_separator && !number.raw.contains('_') {
+                if self.only_if_contains_separator(self.number_base_config(number))
+                    && !number.raw.contains('_')
+                {
                     return;
                 }
 
@@ -99,22 +107,24 @@ impl Rule for NumericSeparatorsStyle {
 
                 if formatted != number.raw {
                     ctx.diagnostic_with_fix(NumericSeparatorsStyleDiagnostic(number.span), || {
-                        Fix::new(formatted, number.span)
+                        Fix::new(formatted, number.span).with_kind(FixKind::Safe)
                     });
                 }
             }
             AstKind::BigintLiteral(number) => {
                 let raw = number.span.source_text(ctx.source_text());
 
-                if self.only_if_contains_separator && !raw.contains('_') {
+                if self.only_if_contains_separator(self.bigint_base_config(number))
+                    && !raw.contains('_')
+                {
                     return;
                 }
 
                 let formatted = self.format_bigint(number, raw);
 
-                if formatted.len() != number.span.size() as usize {
+                if formatted != raw {
                     ctx.diagnostic_with_fix(NumericSeparatorsStyleDiagnostic(number.span), || {
-                        Fix::new(formatted, number.span)
+                        Fix::new(formatted, number.span).with_kind(FixKind::Safe)
                     });
                 }
             }
@@ -151,6 +161,33 @@ impl Rule for NumericSeparatorsStyle {
 }
 
 impl NumericSeparatorsStyle {
+    fn number_base_config(&self, number: &NumberLiteral) -> &NumericBaseConfig {
+        use oxc_syntax::NumberBase;
+
+        match number.base {
+            NumberBase::Binary => &self.binary,
+            NumberBase::Decimal | NumberBase::Float => &self.number,
+            NumberBase::Hex => &self.hexadecimal,
+            NumberBase::Octal => &self.octal,
+        }
+    }
+
+    fn bigint_base_config(&self, number: &BigintLiteral) -> &NumericBaseConfig {
+        use oxc_syntax::BigintBase;
+
+        match number.base {
+            BigintBase::Binary => &self.binary,
+            BigintBase::Decimal => &self.number,
+            BigintBase::Hex => &self.hexadecimal,
+            BigintBase::Octal => &self.octal,
+        }
+    }
+
+    /// The option of a base takes precedence over the top-level `onlyIfContainsSeparator`
+    fn only_if_contains_separator(&self, config: &NumericBaseConfig) -> bool {
+        config.only_if_contains_separator.unwrap_or(self.only_if_contains_separator)
+    }
+
     fn format_number(&self, number: &NumberLiteral) -> String {
         use oxc_syntax::NumberBase;
 
@@ -266,8 +303,13 @@ impl NumericSeparatorsStyle {
 struct NumericBaseConfig {
     group_length: usize,
     minimum_digits: usize,
+    only_if_contains_separator: Option<bool>,
 }
 impl NumericBaseConfig {
+    const fn new(group_length: usize, minimum_digits: usize) -> Self {
+        Self { group_length, minimum_digits, only_if_contains_separator: None }
+    }
+
     pub(self) fn set_numeric_base_from_config(&mut self, val: &serde_json::Value) {
         if let Some(group_length) = val.get("groupLength").and_then(serde_json::Value::as_u64) {
             self.group_length = usize::try_from(group_length).unwrap();
@@ -275,6 +317,11 @@ impl NumericBaseConfig {
         if let Some(minimum_digits) = val.get("minimumDigits").and_then(serde_json::Value::as_u64) {
             self.minimum_digits = usize::try_from(minimum_digits).unwrap();
         }
+        if let Some(only_if_contains_separator) =
+            val.get("onlyIfContainsSeparator").and_then(serde_json::Value::as_bool)
+        {
+            self.only_if_contains_separator = Some(only_if_contains_separator);
+        }
     }
 }
 
@@ -384,7 +431,10 @@ fn test_number_binary() {
         ("const foo = 0B10101010101010", "const foo = 0B10_1010_1010_1010", None),
     ];
 
-    Tester::new_without_config(NumericSeparatorsStyle::NAME, pass, fail).expect_fix(fix).test();
+    Tester::new_without_config(NumericSeparatorsStyle::NAME, pass, fail)
+        .expect_fix(fix)
+        .expect_fix_kind(FixKind::Safe)
+        .test();
 }
 
 #[test]
@@ -408,9 +458,17 @@ fn test_number_hexadecimal() {
         "const foo = 0XAB_C_D",
     ];
 
-    let fix = vec![("const foo = 0xA_B_CDE_F0", "const foo = 0xA_BC_DE_F0", None)];
+    let fix = vec![
+        ("const foo = 0xA_B_CDE_F0", "const foo = 0xA_BC_DE_F0", None),
+        ("const foo = 0xABCDEF", "const foo = 0xAB_CD_EF", None),
+        ("const foo = 0xA_B", "const foo = 0xAB", None),
+        ("const foo = 0XAB_C_D", "const foo = 0XAB_CD", None),
+    ];
 
-    Tester::new_without_config(NumericSeparatorsStyle::NAME, pass, fail).expect_fix(fix).test();
+    Tester::new_without_config(NumericSeparatorsStyle::NAME, pass, fail)
+        .expect_fix(fix)
+        .expect_fix_kind(FixKind::Safe)
+        .test();
 }
 
 #[test]
@@ -435,9 +493,17 @@ fn test_number_octal() {
         "const foo = 0O010101010101",
     ];
 
-    let fix = vec![("const foo = 0o12_34_5670", "const foo = 0o1234_5670", None)];
+    let fix = vec![
+        ("const foo = 0o12_34_5670", "const foo = 0o1234_5670", None),
+        ("const foo = 0o7_7_77", "const foo = 0o7777", None),
+        ("const foo = 0o010101010101", "const foo = 0o0101_0101_0101", None),
+        ("const foo = 0O010101010101", "const foo = 0O0101_0101_0101", None),
+    ];
 
-    Tester::new_without_config(NumericSeparatorsStyle::NAME, pass, fail).expect_fix(fix).test();
+    Tester::new_without_config(NumericSeparatorsStyle::NAME, pass, fail)
+        .expect_fix(fix)
+        .expect_fix_kind(FixKind::Safe)
+        .test();
 }
 
 #[test]
@@ -466,7 +532,10 @@ fn test_bigint_binary() {
         ("const foo = 0B10101010101010n", "const foo = 0B10_1010_1010_1010n", None),
     ];
 
-    Tester::new_without_config(NumericSeparatorsStyle::NAME, pass, fail).expect_fix(fix).test();
+    Tester::new_without_config(NumericSeparatorsStyle::NAME, pass, fail)
+        .expect_fix(fix)
+        .expect_fix_kind(FixKind::Safe)
+        .test();
 }
 
 #[test]
@@ -487,11 +556,20 @@ fn test_bigint() {
         "const foo = 1_9_223n",
         "const foo = 80_7n",
         "const foo = 123456789_100n",
+        "const foo = 12_34_567n",
     ];
 
-    let fix = vec![("const foo = 1_9_223n", "const foo = 19_223n", None)];
+    let fix = vec![
+        ("const foo = 1_9_223n", "const foo = 19_223n", None),
+        ("const foo = 80_7n", "const foo = 807n", None),
+        ("const foo = 123456789_100n", "const foo = 123_456_789_100n", None),
+        ("const foo = 12_34_567n", "const foo = 1_234_567n", None),
+    ];
 
-    Tester::new_without_config(NumericSeparatorsStyle::NAME, pass, fail).expect_fix(fix).test();
+    Tester::new_without_config(NumericSeparatorsStyle::NAME, pass, fail)
+        .expect_fix(fix)
+        .expect_fix_kind(FixKind::Safe)
+        .test();
 }
 
 #[test]
@@ -533,7 +611,10 @@ fn test_number_decimal_exponential() {
         ("const foo = 3.65432E12000", "const foo = 3.654_32E12_000", None),
     ];
 
-    Tester::new_without_config(NumericSeparatorsStyle::NAME, pass, fail).expect_fix(fix).test();
+    Tester::new_without_config(NumericSeparatorsStyle::NAME, pass, fail)
+        .expect_fix(fix)
+        .expect_fix_kind(FixKind::Safe)
+        .test();
 }
 
 #[test]
@@ -558,9 +639,21 @@ fn test_number_decimal_float() {
         "const foo = 0.00000",
     ];
 
-    let fix = vec![("const foo = 9807.1234567", "const foo = 9807.123_456_7", None)];
+    let fix = vec![
+        ("const foo = 9807.1234567", "const foo = 9807.123_456_7", None),
+        ("const foo = 3819.123_4325", "const foo = 3819.123_432_5", None),
+        ("const foo = 138789.12343_2_42", "const foo = 138_789.123_432_42", None),
+        ("const foo = .000000_1", "const foo = .000_000_1", None),
+        ("const foo = 12345678..toString()", "const foo = 12_345_678..toString()", None),
+        ("const foo = 12345678 .toString()", "const foo = 12_345_678 .toString()", None),
+        ("const foo = .00000", "const foo = .000_00", None),
+        ("const foo = 0.00000", "const foo = 0.000_00", None),
+    ];
 
-    Tester::new_without_config(NumericSeparatorsStyle::NAME, pass, fail).expect_fix(fix).test();
+    Tester::new_without_config(NumericSeparatorsStyle::NAME, pass, fail)
+        .expect_fix(fix)
+        .expect_fix_kind(FixKind::Safe)
+        .test();
 }
 
 #[test]
@@ -592,11 +685,16 @@ fn test_number_decimal_integer() {
     ];
 
     let fix = vec![
+        ("const foo = 1_2_345_678", "const foo = 12_345_678", None),
+        ("const foo = 12_3", "const foo = 123", None),
         ("const foo = 1234567890", "const foo = 1_234_567_890", None),
         ("const foo = -100000_1", "const foo = -1_000_001", None),
     ];
 
-    Tester::new_without_config(NumericSeparatorsStyle::NAME, pass, fail).expect_fix(fix).test();
+    Tester::new_without_config(NumericSeparatorsStyle::NAME, pass, fail)
+        .expect_fix(fix)
+        .expect_fix_kind(FixKind::Safe)
+        .test();
 }
 
 #[test]
@@ -623,11 +721,65 @@ fn test_with_config() {
         ("0b1_1_1_1_1n", Some(json!([{ "binary": { "groupLength": 1 } }]))),
         ("0o7_7_7_7_7n", Some(json!([{ "octal": { "groupLength": 1 } }]))),
         ("0xf_f_f_f_fn", Some(json!([{ "hexadecimal": { "groupLength": 1 } }]))),
+        ("1234567890", Some(json!([{ "number": { "onlyIfContainsSeparator": true } }]))),
+        (
+            "0xffffffff",
+            Some(json!([{
+                "onlyIfContainsSeparator": false,
+                "hexadecimal": { "onlyIfContainsSeparator": true }
+            }])),
+        ),
     ];
 
-    let fail = vec![];
+    let fail = vec![
+        ("12_34_56", Some(json!([{ "number": { "groupLength": 3 } }]))),
+        ("0b11111111", Some(json!([{ "number": { "onlyIfContainsSeparator": true } }]))),
+        (
+            "0xffffffff",
+            Some(json!([{
+                "onlyIfContainsSeparator": true,
+                "hexadecimal": { "onlyIfContainsSeparator": false }
+            }])),
+        ),
+        (
+            "0xffffffffn",
+            Some(json!([{
+                "onlyIfContainsSeparator": true,
+                "hexadecimal": { "onlyIfContainsSeparator": false }
+            }])),
+        ),
+        ("1_234_567", Some(json!([{ "number": { "groupLength": 2 } }]))),
+    ];
+
+    let fix = vec![
+        (
+            "0b11111111",
+            "0b1111_1111",
+            Some(json!([{ "number": { "onlyIfContainsSeparator": true } }])),
+        ),
+        (
+            "0xffffffff",
+            "0xff_ff_ff_ff",
+            Some(json!([{
+                "onlyIfContainsSeparator": true,
+                "hexadecimal": { "onlyIfContainsSeparator": false }
+            }])),
+        ),
+        (
+            "0xffffffffn",
+            "0xff_ff_ff_ffn",
+            Some(json!([{
+                "onlyIfContainsSeparator": true,
+                "hexadecimal": { "onlyIfContainsSeparator": false }
+            }])),
+        ),
+        ("1_234_567", "1_23_45_67", Some(json!([{ "number": { "groupLength": 2 } }]))),
+    ];
 
-    Tester::new(NumericSeparatorsStyle::NAME, pass, fail).test();
+    Tester::new(NumericSeparatorsStyle::NAME, pass, fail)
+        .expect_fix(fix)
+        .expect_fix_kind(FixKind::Safe)
+        .test();
 }
 
 #[test]
@@ -675,6 +827,21 @@ mod internal_tests {
         assert!(rule.only_if_contains_separator);
     }
 
+    #[test]
+    fn test_from_base_only_if_contains_separator_configuration() {
+        let config = json!([{
+                "binary": {"onlyIfContainsSeparator": false},
+                "number": {"onlyIfContainsSeparator": true},
+                "onlyIfContainsSeparator": true
+        }]);
+        let rule = NumericSeparatorsStyle::from_configuration(config);
+
+        assert!(!rule.only_if_contains_separator(&rule.binary));
+        assert!(rule.only_if_contains_separator(&rule.number));
+        assert!(rule.only_if_contains_separator(&rule.hexadecimal));
+        assert!(rule.only_if_contains_separator(&rule.octal));
+    }
+
     #[test]
     fn test_from_empty_configuration() {
         let rule = NumericSeparatorsStyle::from_configuration(json!([]));
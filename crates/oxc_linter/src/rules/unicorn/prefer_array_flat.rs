@@ -1,6 +1,7 @@
 use oxc_ast::{
     ast::{
-        Argument, ArrayExpressionElement, BindingPatternKind, CallExpression, Expression, Statement,
+        Argument, ArrayExpressionElement, BindingPatternKind, CallExpression, Expression,
+        MemberExpression, Statement,
     },
     AstKind,
 };
@@ -9,14 +10,19 @@ use oxc_diagnostics::{
     thiserror::Error,
 };
 use oxc_macros::declare_oxc_lint;
-use oxc_span::Span;
+use oxc_span::{GetSpan, Span};
+use oxc_syntax::identifier::is_identifier_part;
 
 use crate::{
     ast_util::is_method_call,
     context::LintContext,
+    fixer::Fix,
     rule::Rule,
     utils::get_first_parameter_name,
-    utils::{get_return_identifier_name, is_empty_array_expression, is_prototype_property},
+    utils::{
+        get_return_identifier_name, is_empty_array_expression, is_prototype_property,
+        needs_leading_semicolon,
+    },
     AstNode,
 };
 
@@ -26,12 +32,27 @@ use crate::{
 struct PreferArrayFlatDiagnostic(#[label] pub Span);
 
 #[derive(Debug, Default, Clone)]
-pub struct PreferArrayFlat;
+pub struct PreferArrayFlat(Box<PreferArrayFlatConfig>);
+
+#[derive(Debug, Default, Clone)]
+pub struct PreferArrayFlatConfig {
+    functions: Vec<String>,
+}
+
+impl std::ops::Deref for PreferArrayFlat {
+    type Target = PreferArrayFlatConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+const DEFAULT_FUNCTIONS: [&str; 3] = ["_.flatten", "lodash.flatten", "underscore.flatten"];
 
 declare_oxc_lint!(
     /// ### What it does
     ///
-    /// Prefers `Array#flat()` over legacy techniques to flatten arrays.    ///
+    /// Prefers `Array#flat()` over legacy techniques to flatten arrays.
     ///
     /// ### Why is this bad?
     ///
@@ -56,200 +77,295 @@ declare_oxc_lint!(
     /// const foo = array.flat();
     /// const foo = [maybeArray].flat();
     /// ```
+    ///
+    /// ### Options
+    ///
+    /// `functions`: additional functions which flatten an array, e.g. `["utils.flat"]`.
+    /// `_.flatten`, `lodash.flatten` and `underscore.flatten` are always checked.
     PreferArrayFlat,
-    pedantic
+    pedantic,
+    fix
 );
 
 impl Rule for PreferArrayFlat {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let functions = value
+            .get(0)
+            .and_then(|config| config.get("functions"))
+            .and_then(serde_json::Value::as_array)
+            .map(|v| {
+                v.iter().filter_map(serde_json::Value::as_str).map(ToString::to_string).collect()
+            })
+            .unwrap_or_default();
+
+        Self(Box::new(PreferArrayFlatConfig { functions }))
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::CallExpression(call_expr) = node.kind() else {
             return;
         };
 
-        check_array_flat_map_case(call_expr, ctx);
-        check_array_reduce_case(call_expr, ctx);
-        check_array_concat_case(call_expr, ctx);
-        check_array_prototype_concat_case(call_expr, ctx);
+        let Some((array, is_optional_array)) = check_array_flat_map_case(call_expr)
+            .or_else(|| check_array_reduce_case(call_expr))
+            .or_else(|| check_array_concat_case(call_expr))
+            .or_else(|| check_array_prototype_concat_case(call_expr))
+            .or_else(|| self.check_flatten_function_case(call_expr))
+        else {
+            return;
+        };
+
+        report(node, call_expr, array, is_optional_array, ctx);
+    }
+}
+
+impl PreferArrayFlat {
+    // `_.flatten(array)`
+    fn check_flatten_function_case<'a, 'b>(
+        &self,
+        call_expr: &'b CallExpression<'a>,
+    ) -> Option<(&'b Expression<'a>, bool)> {
+        if call_expr.optional || call_expr.arguments.len() != 1 {
+            return None;
+        }
+        let Argument::Expression(first_argument) = &call_expr.arguments[0] else {
+            return None;
+        };
+        DEFAULT_FUNCTIONS
+            .iter()
+            .copied()
+            .chain(self.functions.iter().map(String::as_str))
+            .any(|path| is_path(&call_expr.callee, path))
+            .then_some((first_argument, false))
+    }
+}
+
+/// Whether `expr` is the identifier or the chain of static members `path`, e.g. `_.flatten`
+fn is_path(expr: &Expression, path: &str) -> bool {
+    match (expr.without_parenthesized(), path.rsplit_once('.')) {
+        (Expression::Identifier(ident), None) => ident.name.as_str() == path,
+        (Expression::MemberExpression(member_expr), Some((object, property))) => {
+            !member_expr.optional()
+                && !member_expr.is_computed()
+                && member_expr.static_property_name() == Some(property)
+                && is_path(member_expr.object(), object)
+        }
+        _ => false,
     }
 }
 
 // `array.flatMap(x => x)`
-fn check_array_flat_map_case<'a>(call_expr: &CallExpression<'a>, ctx: &LintContext<'a>) {
+fn check_array_flat_map_case<'a, 'b>(
+    call_expr: &'b CallExpression<'a>,
+) -> Option<(&'b Expression<'a>, bool)> {
     if !is_method_call(call_expr, None, Some(&["flatMap"]), Some(1), Some(1)) {
-        return;
+        return None;
     }
 
-    let Argument::Expression(first_argument) = call_expr.arguments.first().unwrap() else {
-        return;
-    };
-
-    let Expression::ArrowExpression(first_argument) = first_argument else {
-        return;
+    let Argument::Expression(Expression::ArrowExpression(first_argument)) = &call_expr.arguments[0]
+    else {
+        return None;
     };
 
     if first_argument.r#async || first_argument.params.parameters_count() != 1 {
-        return;
+        return None;
     }
 
-    let Some(first_param_name) = get_first_parameter_name(&first_argument.params) else {
-        return;
-    };
-
-    let Some(return_param_name) = get_return_identifier_name(&first_argument.body) else {
-        return;
-    };
+    let first_param_name = get_first_parameter_name(&first_argument.params)?;
+    let return_param_name = get_return_identifier_name(&first_argument.body)?;
 
     if first_param_name != return_param_name {
-        return;
+        return None;
     }
 
-    ctx.diagnostic(PreferArrayFlatDiagnostic(call_expr.span));
+    Some((call_expr.callee.get_member_expr()?.object(), false))
 }
 
 // `array.reduce((a, b) => a.concat(b), [])`
 // `array.reduce((a, b) => [...a, ...b], [])`
-fn check_array_reduce_case<'a>(call_expr: &CallExpression<'a>, ctx: &LintContext<'a>) {
+fn check_array_reduce_case<'a, 'b>(
+    call_expr: &'b CallExpression<'a>,
+) -> Option<(&'b Expression<'a>, bool)> {
     if !is_method_call(call_expr, None, Some(&["reduce"]), Some(2), Some(2)) {
-        return;
+        return None;
     }
-    let Argument::Expression(Expression::ArrowExpression(first_argument)) =
-        call_expr.arguments.first().unwrap()
+    let Argument::Expression(Expression::ArrowExpression(first_argument)) = &call_expr.arguments[0]
     else {
-        return;
+        return None;
     };
-    let Argument::Expression(second_argument) = call_expr.arguments.get(1).unwrap() else {
-        return;
+    let Argument::Expression(second_argument) = &call_expr.arguments[1] else {
+        return None;
     };
 
     if first_argument.r#async
         || first_argument.params.parameters_count() != 2
         || !is_empty_array_expression(second_argument)
     {
-        return;
+        return None;
     }
 
-    let Some((first_parameter, second_parameter)) = ({
-        match (
-            &first_argument.params.items[0].pattern.kind,
-            &first_argument.params.items[1].pattern.kind,
-        ) {
-            (
-                BindingPatternKind::BindingIdentifier(ref first_param),
-                BindingPatternKind::BindingIdentifier(second_param),
-            ) => Some((&first_param.name, &second_param.name)),
-
-            _ => None,
-        }
-    }) else {
-        return;
+    let [first_parameter, second_parameter] = first_argument.params.items.as_slice() else {
+        return None;
+    };
+    let (
+        BindingPatternKind::BindingIdentifier(first_parameter),
+        BindingPatternKind::BindingIdentifier(second_parameter),
+    ) = (&first_parameter.pattern.kind, &second_parameter.pattern.kind)
+    else {
+        return None;
     };
 
     let Some(Statement::ExpressionStatement(expr_stmt)) = first_argument.body.statements.first()
     else {
-        return;
+        return None;
     };
 
-    // `array.reduce((a, b) => a.concat(b), [])`
-    if let Expression::CallExpression(concat_call_expr) = &expr_stmt.expression {
-        if is_method_call(concat_call_expr, None, Some(&["concat"]), Some(1), Some(1)) {
-            if let Argument::Expression(Expression::Identifier(first_argument_ident)) =
-                &concat_call_expr.arguments[0]
-            {
-                if first_argument_ident.name != second_parameter {
-                    return;
-                }
-
-                let Expression::Identifier(second_argument_ident) =
-                    concat_call_expr.callee.get_member_expr().unwrap().object()
-                else {
-                    return;
-                };
-
-                if second_argument_ident.name != first_parameter {
-                    return;
-                }
-
-                ctx.diagnostic(PreferArrayFlatDiagnostic(call_expr.span));
-            }
+    let is_flattening = match &expr_stmt.expression {
+        // `array.reduce((a, b) => a.concat(b), [])`
+        Expression::CallExpression(concat_call_expr) => {
+            is_method_call(concat_call_expr, None, Some(&["concat"]), Some(1), Some(1))
+                && matches!(&concat_call_expr.arguments[0], Argument::Expression(expr)
+                    if is_identifier_named(expr, &second_parameter.name))
+                && concat_call_expr.callee.get_member_expr().is_some_and(|member_expr| {
+                    is_identifier_named(member_expr.object(), &first_parameter.name)
+                })
         }
-    }
-
-    if let Expression::ArrayExpression(array_expr) = &expr_stmt.expression {
-        if array_expr.elements.len() != 2 {
-            return;
+        // `array.reduce((a, b) => [...a, ...b], [])`
+        Expression::ArrayExpression(array_expr) => {
+            let is_spread_of = |element: &ArrayExpressionElement, name: &str| {
+                matches!(element, ArrayExpressionElement::SpreadElement(spread)
+                    if is_identifier_named(&spread.argument, name))
+            };
+            array_expr.elements.len() == 2
+                && is_spread_of(&array_expr.elements[0], &first_parameter.name)
+                && is_spread_of(&array_expr.elements[1], &second_parameter.name)
         }
+        _ => false,
+    };
 
-        let Some((first_element, second_element)) = ({
-            match (&array_expr.elements[0], &array_expr.elements[1]) {
-                (
-                    ArrayExpressionElement::SpreadElement(first_element),
-                    ArrayExpressionElement::SpreadElement(second_element),
-                ) => match (&first_element.argument, &second_element.argument) {
-                    (
-                        Expression::Identifier(first_element),
-                        Expression::Identifier(second_element),
-                    ) => Some((first_element, second_element)),
-                    _ => None,
-                },
-                _ => None,
-            }
-        }) else {
-            return;
-        };
+    if !is_flattening {
+        return None;
+    }
 
-        if first_element.name != first_parameter || second_element.name != second_parameter {
-            return;
-        }
+    Some((call_expr.callee.get_member_expr()?.object(), false))
+}
 
-        ctx.diagnostic(PreferArrayFlatDiagnostic(call_expr.span));
-    };
+fn is_identifier_named(expr: &Expression, name: &str) -> bool {
+    matches!(expr, Expression::Identifier(ident) if ident.name == name)
 }
 
 // `[].concat(maybeArray)`
 // `[].concat(...array)`
-fn check_array_concat_case<'a>(call_expr: &CallExpression<'a>, ctx: &LintContext<'a>) {
-    if is_method_call(call_expr, None, Some(&["concat"]), Some(1), Some(1)) {
-        // `array.concat(maybeArray)`
-        if let Expression::ArrayExpression(array_expr) =
-            call_expr.callee.get_member_expr().unwrap().object()
-        {
-            if !array_expr.elements.is_empty() {
-                return;
-            }
-            ctx.diagnostic(PreferArrayFlatDiagnostic(call_expr.span));
-        }
+fn check_array_concat_case<'a, 'b>(
+    call_expr: &'b CallExpression<'a>,
+) -> Option<(&'b Expression<'a>, bool)> {
+    if !is_method_call(call_expr, None, Some(&["concat"]), Some(1), Some(1)) {
+        return None;
+    }
+    if !is_empty_array_expression(call_expr.callee.get_member_expr()?.object()) {
+        return None;
+    }
+    match &call_expr.arguments[0] {
+        Argument::Expression(expr) => Some((expr, true)),
+        Argument::SpreadElement(spread) => Some((&spread.argument, false)),
     }
 }
 
 // - `[].concat.apply([], array)` and `Array.prototype.concat.apply([], array)`
 // - `[].concat.call([], maybeArray)` and `Array.prototype.concat.call([], maybeArray)`
 // - `[].concat.call([], ...array)` and `Array.prototype.concat.call([], ...array)`
-fn check_array_prototype_concat_case<'a>(call_expr: &CallExpression<'a>, ctx: &LintContext<'a>) {
-    let Some(member_expr) = call_expr.callee.get_member_expr() else {
-        return;
+fn check_array_prototype_concat_case<'a, 'b>(
+    call_expr: &'b CallExpression<'a>,
+) -> Option<(&'b Expression<'a>, bool)> {
+    let Expression::MemberExpression(member_expr_obj) =
+        call_expr.callee.get_member_expr()?.object()
+    else {
+        return None;
+    };
+
+    let is_call_call = is_method_call(call_expr, None, Some(&["call"]), Some(2), Some(2));
+    if !(is_call_call || is_method_call(call_expr, None, Some(&["apply"]), Some(2), Some(2)))
+        || !is_prototype_property(member_expr_obj, "concat", Some("Array"))
+    {
+        return None;
+    }
+    let Argument::Expression(first_argument) = &call_expr.arguments[0] else {
+        return None;
     };
+    if !is_empty_array_expression(first_argument) {
+        return None;
+    }
+    match &call_expr.arguments[1] {
+        Argument::Expression(expr) => Some((expr, is_call_call)),
+        Argument::SpreadElement(spread) if is_call_call => Some((&spread.argument, false)),
+        Argument::SpreadElement(_) => None,
+    }
+}
 
-    if let Expression::MemberExpression(member_expr_obj) = member_expr.object() {
-        let is_call_call = is_method_call(call_expr, None, Some(&["call"]), Some(2), Some(2));
+/// Report `call_expr`, fixed to `array.flat()`, or `[array].flat()` if `array` may not be an
+/// array.
+fn report<'a>(
+    node: &AstNode<'a>,
+    call_expr: &CallExpression<'a>,
+    array: &Expression<'a>,
+    is_optional_array: bool,
+    ctx: &LintContext<'a>,
+) {
+    let diagnostic = PreferArrayFlatDiagnostic(call_expr.span);
+    let is_optional_chain = call_expr.optional
+        || call_expr.callee.get_member_expr().is_some_and(MemberExpression::optional);
+    // Comments outside of `array` would be lost
+    let comments = ctx.semantic().trivias().comments();
+    let count_comments = |span: Span| comments.range(span.start..span.end).count();
+    if is_optional_chain || count_comments(call_expr.span) != count_comments(array.span()) {
+        ctx.diagnostic(diagnostic);
+        return;
+    }
 
-        if (is_call_call || is_method_call(call_expr, None, Some(&["apply"]), Some(2), Some(2)))
-            && is_prototype_property(member_expr_obj, "concat", Some("Array"))
+    ctx.diagnostic_with_fix(diagnostic, || {
+        let source_text = ctx.source_text();
+        let array_text = array.span().source_text(source_text);
+        let fixed = if is_optional_array {
+            format!("[{array_text}]")
+        } else if needs_parens(array) {
+            format!("({array_text})")
+        } else {
+            array_text.to_string()
+        };
+        let preceding_text = &source_text[..call_expr.span.start as usize];
+        let prefix = if fixed.starts_with(['[', '(']) && needs_leading_semicolon(node, ctx) {
+            ";"
+        } else if preceding_text.ends_with(is_identifier_part)
+            && fixed.starts_with(is_identifier_part)
         {
-            if let Argument::Expression(first_argument) = &call_expr.arguments[0] {
-                if is_empty_array_expression(first_argument)
-                    && (is_call_call
-                        || !matches!(call_expr.arguments.get(1), Some(Argument::SpreadElement(_))))
-                {
-                    ctx.diagnostic(PreferArrayFlatDiagnostic(call_expr.span));
-                }
-            }
-        }
-    }
+            // `return[].concat(...array)` to `return array.flat()`
+            " "
+        } else {
+            ""
+        };
+        Fix::new(format!("{prefix}{fixed}.flat()"), call_expr.span)
+    });
+}
+
+/// Whether `array` must be wrapped in parentheses to call `.flat()` on it, e.g. `(a, b).flat()`
+fn needs_parens(array: &Expression) -> bool {
+    !matches!(
+        array,
+        Expression::Identifier(_)
+            | Expression::MemberExpression(_)
+            | Expression::CallExpression(_)
+            | Expression::ArrayExpression(_)
+            | Expression::ThisExpression(_)
+            | Expression::StringLiteral(_)
+            | Expression::TemplateLiteral(_)
+            | Expression::TaggedTemplateExpression(_)
+            | Expression::ParenthesizedExpression(_)
+    )
 }
 
 #[test]
 fn test() {
-    use crate::tester::Tester;
+    use crate::{tester::Tester, FixKind};
 
     let pass = vec![
         r"array.flatMap",
@@ -347,6 +463,17 @@ fn test() {
         r"NotArray.prototype.concat.apply([], array)",
         r"Array.prototype?.concat.apply([], array)",
         r"object.Array.prototype.concat.apply([], array)",
+        r"_.flatten",
+        r"_.flatten(array, depth)",
+        r"_.flatten(...array)",
+        r"_.flatten()",
+        r"_?.flatten(array)",
+        r"_.flatten?.(array)",
+        r"_[flatten](array)",
+        r"new _.flatten(array)",
+        r"lodash.notFlatten(array)",
+        r"flatten(array)",
+        r"utils.flat(array)",
     ];
 
     let fail = vec![
@@ -410,7 +537,97 @@ fn test() {
         r"[].concat(some./**/array)",
         r"[/**/].concat(some./**/array)",
         r"[/**/].concat(some.array)",
+        r"async function a() { return [].concat(...await getArray()); }",
+        r"[].concat(...(a ? b : c))",
+        r"Array.prototype.concat.apply([], a || b)",
+        r"foo?.flatMap(x => x)",
+        r"_.flatten(array)",
+        r"lodash.flatten(array)",
+        r"underscore.flatten(array)",
+        r"_.flatten(() => array)",
+    ];
+
+    let fix = vec![
+        (r"array.flatMap(x => x)", r"array.flat()", None),
+        (r"function foo(){return[].flatMap(x => x)}", r"function foo(){return[].flat()}", None),
+        (r"foo.flatMap(x => x) instanceof Array", r"foo.flat() instanceof Array", None),
+        (r"array.reduce((a, b) => a.concat(b), [])", r"array.flat()", None),
+        (
+            r"function foo(){return[].reduce((a, b) => a.concat(b), [])}",
+            r"function foo(){return[].flat()}",
+            None,
+        ),
+        (r"array.reduce((a, b) => [...a, ...b], [])", r"array.flat()", None),
+        (r"array.reduce((a, b) => [...a, ...b,], [])", r"array.flat()", None),
+        (r"[].concat(maybeArray)", r"[maybeArray].flat()", None),
+        (r"[].concat( ((0, maybeArray)) )", r"[((0, maybeArray))].flat()", None),
+        (r"[].concat( [foo] )", r"[[foo]].flat()", None),
+        (r"[].concat(...array)", r"array.flat()", None),
+        (r"[].concat(...(( array )))", r"(( array )).flat()", None),
+        (r"[].concat(...(( [[foo]] )))", r"(( [[foo]] )).flat()", None),
+        (
+            r"function foo(){return[].concat(...array)}",
+            r"function foo(){return array.flat()}",
+            None,
+        ),
+        (r"class A extends[].concat(...array){}", r"class A extends array.flat(){}", None),
+        (r"[].concat.apply([], array)", r"array.flat()", None),
+        (r"[].concat.apply([], ((0, array)))", r"((0, array)).flat()", None),
+        (r"[].concat.call([], maybeArray)", r"[maybeArray].flat()", None),
+        (r"[].concat.call([], ...array)", r"array.flat()", None),
+        (r"Array.prototype.concat.apply([], array)", r"array.flat()", None),
+        (r"Array.prototype.concat.call([], maybeArray)", r"[maybeArray].flat()", None),
+        (r"Array.prototype.concat.call([], ...[foo])", r"[foo].flat()", None),
+        (r"Array.prototype.concat.call([], (0, array))", r"[(0, array)].flat()", None),
+        (
+            r"async function a() { return [].concat(await getArray()); }",
+            r"async function a() { return [await getArray()].flat(); }",
+            None,
+        ),
+        (
+            r"async function a() { return [].concat(...await getArray()); }",
+            r"async function a() { return (await getArray()).flat(); }",
+            None,
+        ),
+        (r"[].concat(...(a ? b : c))", r"(a ? b : c).flat()", None),
+        (r"Array.prototype.concat.apply([], a || b)", r"(a || b).flat()", None),
+        (r"[].concat(some./**/array)", r"[some./**/array].flat()", None),
+        (r"_.flatten(array)", r"array.flat()", None),
+        (r"lodash.flatten(array)", r"array.flat()", None),
+        (r"underscore.flatten(array)", r"array.flat()", None),
+        (r"_.flatten(() => array)", r"(() => array).flat()", None),
+        ("foo\nArray.prototype.concat.call([], bar)", "foo\n;[bar].flat()", None),
+        ("foo;\nArray.prototype.concat.call([], bar)", "foo;\n[bar].flat()", None),
+        ("if (test) Array.prototype.concat.call([], bar)", "if (test) [bar].flat()", None),
+        // Comments outside of the array would be lost
+        (r"[/**/].concat(some./**/array)", r"[/**/].concat(some./**/array)", None),
+        (r"[/**/].concat(some.array)", r"[/**/].concat(some.array)", None),
+        (r"foo?.flatMap(x => x)", r"foo?.flatMap(x => x)", None),
+        (
+            r"utils.flat(array)",
+            r"array.flat()",
+            Some(serde_json::json!([{ "functions": ["utils.flat"] }])),
+        ),
+    ];
+
+    // `flat` is only available on arrays, while spreading in `concat` accepts any iterable
+    Tester::new_without_config(PreferArrayFlat::NAME, pass, fail)
+        .expect_fix(fix)
+        .expect_fix_kind(FixKind::Dangerous)
+        .test_and_snapshot();
+
+    let pass = vec![
+        ("utils.flat(array, depth)", Some(serde_json::json!([{ "functions": ["utils.flat"] }]))),
+        ("utils?.flat(array)", Some(serde_json::json!([{ "functions": ["utils.flat"] }]))),
+        ("flat(array)", Some(serde_json::json!([{ "functions": ["utils.flat"] }]))),
+        ("utils.flat.flat(array)", Some(serde_json::json!([{ "functions": ["utils.flat"] }]))),
+    ];
+
+    let fail = vec![
+        ("utils.flat(array)", Some(serde_json::json!([{ "functions": ["utils.flat"] }]))),
+        ("flatten(array)", Some(serde_json::json!([{ "functions": ["flatten"] }]))),
+        ("_.flatten(array)", Some(serde_json::json!([{ "functions": ["utils.flat"] }]))),
     ];
 
-    Tester::new_without_config(PreferArrayFlat::NAME, pass, fail).test_and_snapshot();
+    Tester::new(PreferArrayFlat::NAME, pass, fail).test();
 }
@@ -7,9 +7,15 @@ use oxc_diagnostics::{
     thiserror::Error,
 };
 use oxc_macros::declare_oxc_lint;
-use oxc_span::Span;
+use oxc_span::{GetSpan, Span};
 
-use crate::{ast_util::is_method_call, context::LintContext, rule::Rule, AstNode};
+use crate::{
+    ast_util::is_method_call,
+    context::LintContext,
+    fixer::{Fix, FixKind},
+    rule::Rule,
+    AstNode,
+};
 
 #[derive(Debug, Error, Diagnostic)]
 #[error("eslint-plugin-unicorn(prefer-array-flat-map): `Array.flatMap` performs `Array.map` and `Array.flat` in one step.")]
@@ -35,7 +41,8 @@ declare_oxc_lint!(
     /// const bar = [1,2,3].flatMap(i => [i]); // ✓ pass
     /// ```
     PreferArrayFlatMap,
-    style
+    style,
+    fix
 );
 
 impl Rule for PreferArrayFlatMap {
@@ -68,7 +75,27 @@ impl Rule for PreferArrayFlatMap {
             }
         }
 
-        ctx.diagnostic(PreferArrayFlatMapDiagnostic(flat_call_expr.span));
+        let diagnostic = PreferArrayFlatMapDiagnostic(flat_call_expr.span);
+        // `.flat()` is removed, keeping the parentheses of `(foo.map(fn)).flat()`
+        let removed_span = Span::new(member_expr.object().span().end, flat_call_expr.span.end);
+        let map_span = match &call_expr.callee {
+            Expression::MemberExpression(map_member_expr) if !map_member_expr.is_computed() => {
+                map_member_expr.static_property_info().map(|(span, _)| span)
+            }
+            _ => None,
+        };
+        let Some(map_span) =
+            map_span.filter(|_| !ctx.semantic().trivias().has_comments_between(removed_span))
+        else {
+            ctx.diagnostic(diagnostic);
+            return;
+        };
+
+        ctx.diagnostic_with_fix(diagnostic, || {
+            let kept = Span::new(map_span.end, removed_span.start).source_text(ctx.source_text());
+            let span = Span::new(map_span.start, flat_call_expr.span.end);
+            Fix::new(format!("flatMap{kept}"), span).with_kind(FixKind::Safe)
+        });
     }
 }
 
@@ -112,7 +139,70 @@ fn test() {
         ("const bar = (([1,2,3].map(i => [i]))).flat()", None),
         ("let bar = [1,2,3] . map( x => y ) . flat () // 🤪", None),
         ("const bar = [1,2,3].map(i => [i]).flat(1);", None),
+        ("const bar = foo?.map(i => [i]).flat()", None),
+        ("const bar = foo.map(i => [i])?.flat()", None),
+        ("const bar = foo?.map(i => [i])?.flat()", None),
+        ("const bar = foo.map?.(i => [i]).flat()", None),
+        ("const bar = foo.map(i => [i]) /* comment */ .flat()", None),
+        ("const bar = foo[\"map\"](i => [i]).flat()", None),
+    ];
+
+    let fix = vec![
+        (
+            "const bar = [[1],[2],[3]].map(i => [i]).flat()",
+            "const bar = [[1],[2],[3]].flatMap(i => [i])",
+            None,
+        ),
+        (
+            "const bar = [[1],[2],[3]].map(i => [i]).flat(1,)",
+            "const bar = [[1],[2],[3]].flatMap(i => [i])",
+            None,
+        ),
+        (
+            "const bar = [1,2,3].map((i) => { return [i]; }).flat()",
+            "const bar = [1,2,3].flatMap((i) => { return [i]; })",
+            None,
+        ),
+        ("const bar = [1,2,3].map(foo).flat()", "const bar = [1,2,3].flatMap(foo)", None),
+        (
+            "const bar = [1,2,3].map(i => i).map(i => [i]).flat()",
+            "const bar = [1,2,3].map(i => i).flatMap(i => [i])",
+            None,
+        ),
+        (
+            "const bar = (([1,2,3].map(i => [i]))).flat()",
+            "const bar = (([1,2,3].flatMap(i => [i])))",
+            None,
+        ),
+        (
+            "let bar = [1,2,3] . map( x => y ) . flat () // 🤪",
+            "let bar = [1,2,3] . flatMap( x => y ) // 🤪",
+            None,
+        ),
+        (
+            "const bar = [1,2,3].map(i => [i]).flat(1);",
+            "const bar = [1,2,3].flatMap(i => [i]);",
+            None,
+        ),
+        ("const bar = foo?.map(i => [i]).flat()", "const bar = foo?.flatMap(i => [i])", None),
+        ("const bar = foo.map(i => [i])?.flat()", "const bar = foo.flatMap(i => [i])", None),
+        ("const bar = foo?.map(i => [i])?.flat()", "const bar = foo?.flatMap(i => [i])", None),
+        ("const bar = foo.map?.(i => [i]).flat()", "const bar = foo.flatMap?.(i => [i])", None),
+        // comments would be lost
+        (
+            "const bar = foo.map(i => [i]) /* comment */ .flat()",
+            "const bar = foo.map(i => [i]) /* comment */ .flat()",
+            None,
+        ),
+        (
+            "const bar = foo[\"map\"](i => [i]).flat()",
+            "const bar = foo[\"map\"](i => [i]).flat()",
+            None,
+        ),
     ];
 
-    Tester::new(PreferArrayFlatMap::NAME, pass, fail).test_and_snapshot();
+    Tester::new(PreferArrayFlatMap::NAME, pass, fail)
+        .expect_fix(fix)
+        .expect_fix_kind(FixKind::Safe)
+        .test_and_snapshot();
 }
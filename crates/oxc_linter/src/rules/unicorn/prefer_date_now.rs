@@ -10,7 +10,12 @@ use oxc_macros::declare_oxc_lint;
 use oxc_span::{Atom, GetSpan, Span};
 use oxc_syntax::operator::{AssignmentOperator, BinaryOperator, UnaryOperator};
 
-use crate::{context::LintContext, rule::Rule, AstNode};
+use crate::{
+    context::LintContext,
+    fixer::{Fix, FixKind},
+    rule::Rule,
+    AstNode,
+};
 
 #[derive(Debug, Error, Diagnostic)]
 #[allow(clippy::enum_variant_names)]
@@ -53,7 +58,8 @@ declare_oxc_lint!(
     /// const ts = Date.now();
     /// ```
     PreferDateNow,
-    pedantic
+    pedantic,
+    fix
 );
 
 impl Rule for PreferDateNow {
@@ -69,10 +75,13 @@ impl Rule for PreferDateNow {
                         && matches!(member_expr.static_property_name(), Some("getTime" | "valueOf"))
                         && is_new_date(member_expr.object().without_parenthesized())
                     {
-                        ctx.diagnostic(PreferDateNowDiagnostic::PreferDateNowOverMethods(
-                            call_expr.span,
-                            member_expr.static_property_name().unwrap().into(),
-                        ));
+                        ctx.diagnostic_with_fix(
+                            PreferDateNowDiagnostic::PreferDateNowOverMethods(
+                                call_expr.span,
+                                member_expr.static_property_name().unwrap().into(),
+                            ),
+                            || fix_with_date_now(call_expr.span, ctx),
+                        );
                     }
                 }
 
@@ -83,10 +92,17 @@ impl Rule for PreferDateNow {
                     {
                         if let Some(Argument::Expression(expr)) = call_expr.arguments.first() {
                             if is_new_date(expr.without_parenthesized()) {
-                                ctx.diagnostic(
+                                // `BigInt(Date.now())` still needs the conversion
+                                let fix_span = if ident.name == "Number" {
+                                    call_expr.span
+                                } else {
+                                    expr.span()
+                                };
+                                ctx.diagnostic_with_fix(
                                     PreferDateNowDiagnostic::PreferDateNowOverNumberDateObject(
                                         call_expr.span,
                                     ),
+                                    || fix_with_date_now(fix_span, ctx),
                                 );
                             }
                         }
@@ -101,9 +117,16 @@ impl Rule for PreferDateNow {
                     return;
                 }
                 if is_new_date(&unary_expr.argument) {
-                    ctx.diagnostic(PreferDateNowDiagnostic::PreferDateNow(
-                        unary_expr.argument.span(),
-                    ));
+                    // `-Date.now()` keeps the negation
+                    let fix_span = if unary_expr.operator == UnaryOperator::UnaryPlus {
+                        unary_expr.span
+                    } else {
+                        unary_expr.argument.span()
+                    };
+                    ctx.diagnostic_with_fix(
+                        PreferDateNowDiagnostic::PreferDateNow(unary_expr.argument.span()),
+                        || fix_with_date_now(fix_span, ctx),
+                    );
                 }
             }
             AstKind::AssignmentExpression(assignment_expr) => {
@@ -119,9 +142,10 @@ impl Rule for PreferDateNow {
                 }
 
                 if is_new_date(&assignment_expr.right) {
-                    ctx.diagnostic(PreferDateNowDiagnostic::PreferDateNow(
-                        assignment_expr.right.span(),
-                    ));
+                    let span = assignment_expr.right.span();
+                    ctx.diagnostic_with_fix(PreferDateNowDiagnostic::PreferDateNow(span), || {
+                        fix_with_date_now(span, ctx)
+                    });
                 }
             }
             AstKind::BinaryExpression(bin_expr) => {
@@ -136,11 +160,14 @@ impl Rule for PreferDateNow {
                     return;
                 }
 
-                if is_new_date(&bin_expr.left) {
-                    ctx.diagnostic(PreferDateNowDiagnostic::PreferDateNow(bin_expr.left.span()));
-                }
-                if is_new_date(&bin_expr.right) {
-                    ctx.diagnostic(PreferDateNowDiagnostic::PreferDateNow(bin_expr.right.span()));
+                for operand in [&bin_expr.left, &bin_expr.right] {
+                    if is_new_date(operand) {
+                        let span = operand.span();
+                        ctx.diagnostic_with_fix(
+                            PreferDateNowDiagnostic::PreferDateNow(span),
+                            || fix_with_date_now(span, ctx),
+                        );
+                    }
                 }
             }
             _ => {}
@@ -148,6 +175,15 @@ impl Rule for PreferDateNow {
     }
 }
 
+/// Replaces `span` with `Date.now()`, keeping it apart from a preceding keyword, e.g. `return+new Date`
+fn fix_with_date_now<'a>(span: Span, ctx: &LintContext<'a>) -> Fix<'a> {
+    let needs_space = ctx.source_text()[..span.start as usize]
+        .chars()
+        .next_back()
+        .is_some_and(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '$'));
+    Fix::new(if needs_space { " Date.now()" } else { "Date.now()" }, span).with_kind(FixKind::Safe)
+}
+
 fn is_new_date(expr: &Expression) -> bool {
     let Expression::NewExpression(new_expr) = expr.without_parenthesized() else { return false };
 
@@ -221,5 +257,48 @@ fn test() {
         r"function foo(){return-new Date}",
     ];
 
-    Tester::new_without_config(PreferDateNow::NAME, pass, fail).test_and_snapshot();
+    let fix = vec![
+        (r"const ts = new Date().getTime();", r"const ts = Date.now();", None),
+        (r"const ts = (new Date).getTime();", r"const ts = Date.now();", None),
+        (r"const ts = (new Date()).getTime();", r"const ts = Date.now();", None),
+        (r"const ts = new Date().valueOf();", r"const ts = Date.now();", None),
+        (r"const ts = (new Date).valueOf();", r"const ts = Date.now();", None),
+        (r"const ts = (new Date()).valueOf();", r"const ts = Date.now();", None),
+        (
+            r"const ts = /* 1 */ Number(/* 2 */ new /* 3 */ Date( /* 4 */ ) /* 5 */) /* 6 */",
+            r"const ts = /* 1 */ Date.now() /* 6 */",
+            None,
+        ),
+        (
+            r"const tsBigInt = /* 1 */ BigInt(/* 2 */ new /* 3 */ Date( /* 4 */ ) /* 5 */) /* 6 */",
+            r"const tsBigInt = /* 1 */ BigInt(/* 2 */ Date.now() /* 5 */) /* 6 */",
+            None,
+        ),
+        (r"const ts = + /* 1 */ new Date;", r"const ts = Date.now();", None),
+        (r"const ts = - /* 1 */ new Date();", r"const ts = - /* 1 */ Date.now();", None),
+        (r"const ts = new Date() - 0", r"const ts = Date.now() - 0", None),
+        (r"const foo = bar - new Date", r"const foo = bar - Date.now()", None),
+        (r"const foo = new Date() * bar", r"const foo = Date.now() * bar", None),
+        (r"const ts = new Date() / 1", r"const ts = Date.now() / 1", None),
+        (r"const ts = new Date() % Infinity", r"const ts = Date.now() % Infinity", None),
+        (r"const ts = new Date() ** 1", r"const ts = Date.now() ** 1", None),
+        (
+            r"const zero = (new Date(/* 1 */) /* 2 */) /* 3 */ - /* 4 */new Date",
+            r"const zero = Date.now() /* 3 */ - /* 4 */Date.now()",
+            None,
+        ),
+        (r"foo -= new Date()", r"foo -= Date.now()", None),
+        (r"foo *= new Date()", r"foo *= Date.now()", None),
+        (r"foo /= new Date", r"foo /= Date.now()", None),
+        (r"foo %= new Date()", r"foo %= Date.now()", None),
+        (r"foo **= new Date()", r"foo **= Date.now()", None),
+        (r"foo **= (new Date())", r"foo **= Date.now()", None),
+        (r"function foo(){return+new Date}", r"function foo(){return Date.now()}", None),
+        (r"function foo(){return-new Date}", r"function foo(){return-Date.now()}", None),
+    ];
+
+    Tester::new_without_config(PreferDateNow::NAME, pass, fail)
+        .expect_fix(fix)
+        .expect_fix_kind(FixKind::Safe)
+        .test_and_snapshot();
 }
@@ -1,23 +1,34 @@
-use oxc_ast::{ast::Expression, AstKind};
+use oxc_ast::{
+    ast::{
+        Argument, BinaryExpression, BindingPatternKind, CallExpression, ChainElement, Expression,
+    },
+    AstKind,
+};
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
     thiserror::Error,
 };
 use oxc_macros::declare_oxc_lint;
-use oxc_span::Span;
+use oxc_span::{GetSpan, Span};
 use oxc_syntax::operator::{BinaryOperator, UnaryOperator};
 
 use crate::{
     ast_util::{call_expr_method_callee_info, is_method_call},
     context::LintContext,
+    fixer::Fix,
     rule::Rule,
     AstNode,
 };
 
 #[derive(Debug, Error, Diagnostic)]
-#[error("eslint-plugin-unicorn(prefer-includes): Prefer `includes()` over `indexOf()` when checking for existence or non-existence.")]
-#[diagnostic(severity(warning))]
-struct PreferIncludesDiagnostic(#[label] pub Span);
+enum PreferIncludesDiagnostic {
+    #[error("eslint-plugin-unicorn(prefer-includes): Prefer `includes()` over `indexOf()` when checking for existence or non-existence.")]
+    #[diagnostic(severity(warning))]
+    IndexOf(#[label] Span),
+    #[error("eslint-plugin-unicorn(prefer-includes): Prefer `includes()` over `some()` when checking value existence.")]
+    #[diagnostic(severity(warning))]
+    ArraySome(#[label] Span),
+}
 
 #[derive(Debug, Default, Clone)]
 pub struct PreferIncludes;
@@ -25,7 +36,8 @@ pub struct PreferIncludes;
 declare_oxc_lint!(
     /// ### What it does
     ///
-    /// Prefer `includes()` over `indexOf()` when checking for existence or non-existence.
+    /// Prefer `includes()` over `indexOf()` when checking for existence or non-existence,
+    /// and over `Array#some()` when the callback only compares the element with a value.
     ///
     /// All built-ins have `.includes()` in addition to `.indexOf()`.
     ///
@@ -37,60 +49,162 @@ declare_oxc_lint!(
     /// ```javascript
     /// // bad
     /// if (str.indexOf('foo') !== -1) { }
+    /// if (array.some(x => x === 'foo')) { }
     ///
     /// // good
     /// if (str.includes('foo')) { }
+    /// if (array.includes('foo')) { }
     /// ```
     PreferIncludes,
-    style
+    style,
+    fix
 );
 
 impl Rule for PreferIncludes {
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
-        let AstKind::BinaryExpression(bin_expr) = node.kind() else {
-            return;
-        };
+        match node.kind() {
+            AstKind::BinaryExpression(bin_expr) => check_index_of(bin_expr, ctx),
+            AstKind::CallExpression(call_expr) => check_some(call_expr, ctx),
+            _ => {}
+        }
+    }
+}
 
-        let Expression::CallExpression(left_call_expr) = &bin_expr.left.without_parenthesized()
-        else {
-            return;
-        };
+/// `foo.indexOf(bar) !== -1` to `foo.includes(bar)`
+fn check_index_of<'a>(bin_expr: &'a BinaryExpression<'a>, ctx: &LintContext<'a>) {
+    // Normalize `-1 < foo.indexOf(bar)` to `foo.indexOf(bar) > -1`
+    let (index_of_expr, call_expr, operator, value) = if let Some((expr, call_expr)) =
+        get_index_of_call(&bin_expr.left)
+    {
+        (expr, call_expr, bin_expr.operator, &bin_expr.right)
+    } else if let Some((expr, call_expr)) = get_index_of_call(&bin_expr.right) {
+        let operator = bin_expr.operator.compare_inverse_operator().unwrap_or(bin_expr.operator);
+        (expr, call_expr, operator, &bin_expr.left)
+    } else {
+        return;
+    };
 
-        if !is_method_call(left_call_expr, None, Some(&["indexOf"]), None, Some(2)) {
-            return;
+    let value = value.without_parenthesized();
+    let negated = match operator {
+        BinaryOperator::StrictInequality
+        | BinaryOperator::Inequality
+        | BinaryOperator::GreaterThan
+            if is_negative_one(value) =>
+        {
+            false
         }
-
-        if matches!(
-            bin_expr.operator,
-            BinaryOperator::StrictInequality
-                | BinaryOperator::Inequality
-                | BinaryOperator::GreaterThan
-                | BinaryOperator::StrictEquality
-                | BinaryOperator::Equality
-        ) {
-            if !is_negative_one(bin_expr.right.without_parenthesized()) {
-                return;
-            }
-
-            ctx.diagnostic(PreferIncludesDiagnostic(
-                call_expr_method_callee_info(left_call_expr).unwrap().0,
-            ));
+        BinaryOperator::GreaterEqualThan if is_zero(value) => false,
+        BinaryOperator::StrictEquality
+        | BinaryOperator::Equality
+        | BinaryOperator::LessEqualThan
+            if is_negative_one(value) =>
+        {
+            true
         }
+        BinaryOperator::LessThan if is_zero(value) => true,
+        _ => return,
+    };
+
+    let Some((property_span, _)) = call_expr_method_callee_info(call_expr) else { return };
+    let diagnostic = PreferIncludesDiagnostic::IndexOf(property_span);
 
-        if matches!(bin_expr.operator, BinaryOperator::GreaterEqualThan | BinaryOperator::LessThan)
+    let trivias = ctx.semantic().trivias();
+    let index_of_span = index_of_expr.span();
+    if trivias.has_comments_between(Span::new(bin_expr.span.start, index_of_span.start))
+        || trivias.has_comments_between(Span::new(index_of_span.end, bin_expr.span.end))
+    {
+        ctx.diagnostic(diagnostic);
+        return;
+    }
+
+    ctx.diagnostic_with_fix(diagnostic, || {
+        let source_text = ctx.source_text();
+        let object = Span::new(index_of_span.start, property_span.start).source_text(source_text);
+        let arguments = Span::new(property_span.end, index_of_span.end).source_text(source_text);
+        let negation = if negated { "!" } else { "" };
+        Fix::new(format!("{negation}{object}includes{arguments}"), bin_expr.span)
+    });
+}
+
+/// `foo.some(x => x === bar)` to `foo.includes(bar)`
+fn check_some<'a>(call_expr: &'a CallExpression<'a>, ctx: &LintContext<'a>) {
+    if call_expr.optional || !is_method_call(call_expr, None, Some(&["some"]), Some(1), Some(1)) {
+        return;
+    }
+    let Expression::MemberExpression(member_expr) = call_expr.callee.without_parenthesized() else {
+        return;
+    };
+    if member_expr.optional() {
+        return;
+    }
+    let Some(Argument::Expression(Expression::ArrowExpression(arrow))) =
+        call_expr.arguments.first()
+    else {
+        return;
+    };
+    if arrow.r#async || arrow.params.items.len() != 1 || arrow.params.rest.is_some() {
+        return;
+    }
+    let BindingPatternKind::BindingIdentifier(param) = &arrow.params.items[0].pattern.kind else {
+        return;
+    };
+    let Some(Expression::BinaryExpression(compare)) =
+        arrow.get_expression().map(Expression::without_parenthesized)
+    else {
+        return;
+    };
+    if compare.operator != BinaryOperator::StrictEquality {
+        return;
+    }
+    let value = match (&compare.left, &compare.right) {
+        (Expression::Identifier(ident), value) | (value, Expression::Identifier(ident))
+            if ident.name == param.name =>
         {
-            let Expression::NumberLiteral(num_lit) = bin_expr.right.without_parenthesized() else {
-                return;
-            };
-
-            if num_lit.raw != "0" {
-                return;
-            }
-            ctx.diagnostic(PreferIncludesDiagnostic(
-                call_expr_method_callee_info(left_call_expr).unwrap().0,
-            ));
+            value
         }
+        _ => return,
+    };
+    // The value must not depend on the element, e.g. `x => x === x.foo`
+    let Some(symbol_id) = param.symbol_id.get() else { return };
+    if ctx.semantic().symbol_references(symbol_id).count() != 1 {
+        return;
+    }
+
+    let Some((property_span, _)) = call_expr_method_callee_info(call_expr) else { return };
+    let diagnostic = PreferIncludesDiagnostic::ArraySome(property_span);
+
+    if ctx
+        .semantic()
+        .trivias()
+        .has_comments_between(Span::new(property_span.end, call_expr.span.end))
+    {
+        ctx.diagnostic(diagnostic);
+        return;
     }
+
+    ctx.diagnostic_with_fix(diagnostic, || {
+        let source_text = ctx.source_text();
+        let object = Span::new(call_expr.span.start, property_span.start).source_text(source_text);
+        let value = value.span().source_text(source_text);
+        Fix::new(format!("{object}includes({value})"), call_expr.span)
+    });
+}
+
+/// Returns the `foo.indexOf(bar)` call, together with the expression wrapping it if it is an
+/// optional chain
+fn get_index_of_call<'a>(
+    expr: &'a Expression<'a>,
+) -> Option<(&'a Expression<'a>, &'a CallExpression<'a>)> {
+    let expr = expr.without_parenthesized();
+    let call_expr = match expr {
+        Expression::CallExpression(call_expr) => call_expr,
+        Expression::ChainExpression(chain_expr) => match &chain_expr.expression {
+            ChainElement::CallExpression(call_expr) => call_expr,
+            ChainElement::MemberExpression(_) => return None,
+        },
+        _ => return None,
+    };
+    is_method_call(call_expr, None, Some(&["indexOf"]), None, Some(2)).then_some((expr, call_expr))
 }
 
 fn is_negative_one(expr: &Expression) -> bool {
@@ -109,9 +223,13 @@ fn is_negative_one(expr: &Expression) -> bool {
     num_lit.raw == "1"
 }
 
+fn is_zero(expr: &Expression) -> bool {
+    matches!(expr, Expression::NumberLiteral(num_lit) if num_lit.raw == "0")
+}
+
 #[test]
 fn test() {
-    use crate::tester::Tester;
+    use crate::{tester::Tester, FixKind};
 
     let pass = vec![
         r"str.indexOf('foo') !== -n",
@@ -125,6 +243,26 @@ fn test() {
         r"null.indexOf('foo') !== 1",
         r"f(0) < 0",
         r"something.indexOf(foo, 0, another) !== -1",
+        r"-1 === str.indexOf",
+        r"0 < str.indexOf('foo')",
+        r"str.indexOf('foo') <= 0",
+        r"str.indexOf('foo') > 0",
+        r"const some = foo.some(fn);",
+        r"foo.some(x => x == bar)",
+        r"foo.some(x => x !== bar)",
+        r"foo.some(x => bar)",
+        r"foo.some((x, index) => x === index)",
+        r"foo.some(x => x === x.bar)",
+        r"foo.some(x => x === fn(x))",
+        r"foo.some(async x => x === bar)",
+        r"foo.some(function (x) { return x === bar })",
+        r"foo.some(x => { return x === bar })",
+        r"foo.some(({x}) => x === bar)",
+        r"foo.some(x => x === bar, thisArg)",
+        r"foo.some(...[x => x === bar])",
+        r"foo?.some(x => x === bar)",
+        r"foo.some?.(x => x === bar)",
+        r"foo[some](x => x === bar)",
     ];
 
     let fail = vec![
@@ -139,7 +277,65 @@ fn test() {
         r"(a || b).indexOf('foo') === -1",
         r"foo.indexOf(bar, 0) !== -1",
         r"foo.indexOf(bar, 1) !== -1",
+        r"-1 !== str.indexOf('foo')",
+        r"-1 < str.indexOf('foo')",
+        r"0 <= str.indexOf('foo')",
+        r"0 > str.indexOf('foo')",
+        r"-1 == str.indexOf('foo')",
+        r"str.indexOf('foo') <= -1",
+        r"-1 >= str.indexOf('foo')",
+        r"a?.indexOf(b) === -1",
+        r"a?.indexOf(b) !== -1",
+        r"(str.indexOf('foo')) !== (-1)",
+        r"if (str.indexOf('foo') /* comment */ !== -1) {}",
+        r"foo.some(x => x === bar)",
+        r"foo.some(x => bar === x)",
+        r"foo.some((x) => (x === 'bar'))",
+        r"foo.bar.some(element => element === bar.baz)",
+        r"foo.some(x => x === bar /* comment */)",
+    ];
+
+    let fix = vec![
+        (r"'foobar'.indexOf('foo') !== -1", r"'foobar'.includes('foo')", None),
+        (r"str.indexOf('foo') != -1", r"str.includes('foo')", None),
+        (r"str.indexOf('foo') > -1", r"str.includes('foo')", None),
+        (r"str.indexOf('foo') == -1", r"!str.includes('foo')", None),
+        (r"'foobar'.indexOf('foo') >= 0", r"'foobar'.includes('foo')", None),
+        (r"[1,2,3].indexOf(4) !== -1", r"[1,2,3].includes(4)", None),
+        (r"str.indexOf('foo') < 0", r"!str.includes('foo')", None),
+        (r"''.indexOf('foo') < 0", r"!''.includes('foo')", None),
+        (r"(a || b).indexOf('foo') === -1", r"!(a || b).includes('foo')", None),
+        (r"foo.indexOf(bar, 0) !== -1", r"foo.includes(bar, 0)", None),
+        (r"foo.indexOf(bar, 1) !== -1", r"foo.includes(bar, 1)", None),
+        (r"-1 !== str.indexOf('foo')", r"str.includes('foo')", None),
+        (r"-1 < str.indexOf('foo')", r"str.includes('foo')", None),
+        (r"0 <= str.indexOf('foo')", r"str.includes('foo')", None),
+        (r"0 > str.indexOf('foo')", r"!str.includes('foo')", None),
+        (r"-1 == str.indexOf('foo')", r"!str.includes('foo')", None),
+        (r"str.indexOf('foo') <= -1", r"!str.includes('foo')", None),
+        (r"-1 >= str.indexOf('foo')", r"!str.includes('foo')", None),
+        (r"a?.indexOf(b) === -1", r"!a?.includes(b)", None),
+        (r"a?.indexOf(b) !== -1", r"a?.includes(b)", None),
+        (r"(str.indexOf('foo')) !== (-1)", r"str.includes('foo')", None),
+        (
+            r"if (str.indexOf('foo') /* comment */ !== -1) {}",
+            r"if (str.indexOf('foo') /* comment */ !== -1) {}",
+            None,
+        ),
+        (r"foo.some(x => x === bar)", r"foo.includes(bar)", None),
+        (r"foo.some(x => bar === x)", r"foo.includes(bar)", None),
+        (r"foo.some((x) => (x === 'bar'))", r"foo.includes('bar')", None),
+        (r"foo.bar.some(element => element === bar.baz)", r"foo.bar.includes(bar.baz)", None),
+        (
+            r"foo.some(x => x === bar /* comment */)",
+            r"foo.some(x => x === bar /* comment */)",
+            None,
+        ),
     ];
 
-    Tester::new_without_config(PreferIncludes::NAME, pass, fail).test_and_snapshot();
+    // `includes` finds `NaN`, unlike `indexOf` and `===`
+    Tester::new_without_config(PreferIncludes::NAME, pass, fail)
+        .expect_fix(fix)
+        .expect_fix_kind(FixKind::Dangerous)
+        .test_and_snapshot();
 }
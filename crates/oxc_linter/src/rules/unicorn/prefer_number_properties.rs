@@ -5,18 +5,29 @@ use oxc_diagnostics::{
 };
 use oxc_macros::declare_oxc_lint;
 use oxc_span::{GetSpan, Span};
+use oxc_syntax::operator::UnaryOperator;
 
-use crate::{context::LintContext, globals::GLOBAL_OBJECT_NAMES, rule::Rule, AstNode};
+use crate::{
+    context::LintContext, globals::GLOBAL_OBJECT_NAMES, rule::Rule, AstNode, Fix, FixKind,
+};
 
 #[derive(Debug, Error, Diagnostic)]
 #[error(
-    "eslint-plugin-unicorn(prefer-number-properties): Use `Number.{1}` instead of the global `{1}`"
+    "eslint-plugin-unicorn(prefer-number-properties): Use `Number.{2}` instead of the global `{1}`"
 )]
-#[diagnostic(severity(warning), help("Replace it with `Number.{1}`"))]
-struct PreferNumberPropertiesDiagnostic(#[label] pub Span, pub String);
+#[diagnostic(severity(warning), help("Replace it with `Number.{2}`"))]
+struct PreferNumberPropertiesDiagnostic(#[label] pub Span, pub &'static str, pub &'static str);
+
+#[derive(Debug, Clone)]
+pub struct PreferNumberProperties {
+    check_infinity: bool,
+}
 
-#[derive(Debug, Default, Clone)]
-pub struct PreferNumberProperties;
+impl Default for PreferNumberProperties {
+    fn default() -> Self {
+        Self { check_infinity: true }
+    }
+}
 
 declare_oxc_lint!(
     /// ### What it does
@@ -35,6 +46,12 @@ declare_oxc_lint!(
     /// - [`Number.POSITIVE_INFINITY`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Number/POSITIVE_INFINITY) over [`Infinity`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Infinity)
     /// - [`Number.NEGATIVE_INFINITY`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Number/NEGATIVE_INFINITY) over [`-Infinity`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Infinity)
     ///
+    /// `isNaN()` and `isFinite()` coerce their argument to a number first, so they are only suggested to be replaced.
+    ///
+    /// ### Options
+    ///
+    /// `checkInfinity`: `true` by default, pass `false` to allow `Infinity` and `-Infinity`.
+    ///
     /// ### Example
     /// ```javascript
     /// // bad
@@ -47,91 +64,143 @@ declare_oxc_lint!(
     /// ```
     PreferNumberProperties,
     restriction,
+    fix
 );
 
 impl Rule for PreferNumberProperties {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        Self {
+            check_infinity: value
+                .get(0)
+                .and_then(|config| config.get("checkInfinity"))
+                .and_then(serde_json::Value::as_bool)
+                .unwrap_or(true),
+        }
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         match node.kind() {
-            AstKind::MemberExpression(member_expr) => {
-                let Expression::Identifier(ident_name) = member_expr.object() else {
-                    return;
-                };
-
-                if GLOBAL_OBJECT_NAMES.contains(ident_name.name.as_str()) {
-                    match member_expr.static_property_name() {
-                        Some("NaN") => {
-                            ctx.diagnostic(PreferNumberPropertiesDiagnostic(
-                                member_expr.span(),
-                                "NaN".to_string(),
-                            ));
-                        }
-                        Some("Infinity") => {
-                            ctx.diagnostic(PreferNumberPropertiesDiagnostic(
-                                member_expr.span(),
-                                "Infinity".to_string(),
-                            ));
-                        }
-                        _ => {}
-                    }
+            // `NaN`
+            AstKind::IdentifierReference(ident) => {
+                if ctx.semantic().is_reference_to_global_variable(ident) {
+                    self.check_value(node, ident.span, ident.name.as_str(), ctx);
                 }
             }
-            AstKind::IdentifierReference(ident_ref) => match ident_ref.name.as_str() {
-                "NaN" | "Infinity" => {
-                    ctx.diagnostic(PreferNumberPropertiesDiagnostic(
-                        ident_ref.span,
-                        ident_ref.name.to_string(),
-                    ));
-                }
-                _ => {}
-            },
-            AstKind::IdentifierName(ident_name) => {
-                if matches!(
-                    ctx.nodes().parent_kind(node.id()),
-                    Some(AstKind::MemberExpression(_) | AstKind::PropertyKey(_))
-                ) {
+            // `globalThis.NaN`
+            AstKind::MemberExpression(member_expr) => {
+                if !is_global_object(member_expr.object(), ctx) {
                     return;
-                };
-
-                match ident_name.name.as_str() {
-                    "NaN" | "Infinity" => {
-                        ctx.diagnostic(PreferNumberPropertiesDiagnostic(
-                            ident_name.span,
-                            ident_name.name.to_string(),
-                        ));
-                    }
-                    _ => {}
+                }
+                if let Some(name) = member_expr.static_property_name() {
+                    self.check_value(node, member_expr.span(), name, ctx);
                 }
             }
+            // `parseInt(foo)`, `globalThis.isNaN(foo)`
             AstKind::CallExpression(call_expr) => {
-                let Some(ident_name) = extract_ident_from_expression(&call_expr.callee) else {
+                let Some(name) = extract_global_name(&call_expr.callee, ctx) else {
                     return;
                 };
 
-                if matches!(ident_name, "isNaN" | "isFinite" | "parseFloat" | "parseInt") {
-                    ctx.diagnostic(PreferNumberPropertiesDiagnostic(
-                        call_expr.callee.span(),
-                        ident_name.to_string(),
-                    ));
+                let (name, is_safe) = match name {
+                    "parseInt" => ("parseInt", true),
+                    "parseFloat" => ("parseFloat", true),
+                    "isNaN" => ("isNaN", false),
+                    "isFinite" => ("isFinite", false),
+                    _ => return,
+                };
+                report(node, call_expr.callee.span(), name, name, is_safe, ctx);
+            }
+            _ => {}
+        }
+    }
+}
+
+impl PreferNumberProperties {
+    fn check_value<'a>(&self, node: &AstNode<'a>, span: Span, name: &str, ctx: &LintContext<'a>) {
+        match name {
+            "NaN" => report(node, span, "NaN", "NaN", true, ctx),
+            "Infinity" if self.check_infinity => {
+                // `-Infinity` is replaced as a whole
+                let negation = ctx.nodes().parent_node(node.id()).filter(|parent| {
+                    matches!(
+                        parent.kind(),
+                        AstKind::UnaryExpression(unary_expr)
+                            if unary_expr.operator == UnaryOperator::UnaryNegation
+                    )
+                });
+                if let Some(parent) = negation {
+                    report(
+                        parent,
+                        parent.kind().span(),
+                        "-Infinity",
+                        "NEGATIVE_INFINITY",
+                        true,
+                        ctx,
+                    );
+                    return;
                 }
+                report(node, span, "Infinity", "POSITIVE_INFINITY", true, ctx);
             }
             _ => {}
         }
     }
 }
 
-fn extract_ident_from_expression<'b>(expr: &'b Expression<'_>) -> Option<&'b str> {
-    match expr {
-        Expression::Identifier(ident_name) => Some(ident_name.name.as_str()),
-        Expression::MemberExpression(member_expr) => {
-            let Expression::Identifier(ident_name) = member_expr.object() else {
-                return None;
-            };
+/// Unsafe replacements, `isNaN()` and `isFinite()`, are only reported with a suggestion.
+fn report<'a>(
+    node: &AstNode<'a>,
+    span: Span,
+    description: &'static str,
+    property: &'static str,
+    is_safe: bool,
+    ctx: &LintContext<'a>,
+) {
+    let diagnostic = PreferNumberPropertiesDiagnostic(span, description, property);
+    let fix = || {
+        let mut replacement = format!("Number.{property}");
+        // `{NaN}` to `{NaN: Number.NaN}`
+        if matches!(
+            ctx.nodes().parent_kind(node.id()),
+            Some(AstKind::ObjectProperty(prop)) if prop.shorthand
+        ) {
+            replacement = format!("{description}: {replacement}");
+        }
+        // `return-Infinity` to `return Number.NEGATIVE_INFINITY`
+        let before = &ctx.source_text()[..span.start as usize];
+        if before.ends_with(|c: char| c.is_ascii_alphanumeric() || c == '_' || c == '$') {
+            replacement.insert(0, ' ');
+        }
+        Fix::new(replacement, span)
+    };
 
-            if GLOBAL_OBJECT_NAMES.contains(ident_name.name.as_str()) {
-                member_expr.static_property_name()
-            } else {
-                None
-            }
+    if is_safe {
+        ctx.diagnostic_with_fix(diagnostic, || fix().with_kind(FixKind::Safe));
+    } else {
+        ctx.diagnostic_with_suggestions(
+            diagnostic,
+            vec![fix().with_message(format!("Replace `{description}` with `Number.{property}`"))],
+        );
+    }
+}
+
+fn is_global_object(expr: &Expression, ctx: &LintContext) -> bool {
+    matches!(
+        expr,
+        Expression::Identifier(ident)
+            if GLOBAL_OBJECT_NAMES.contains(ident.name.as_str())
+                && ctx.semantic().is_reference_to_global_variable(ident)
+    )
+}
+
+fn extract_global_name<'b>(expr: &'b Expression<'_>, ctx: &LintContext) -> Option<&'b str> {
+    match expr {
+        Expression::Identifier(ident) => {
+            ctx.semantic().is_reference_to_global_variable(ident).then_some(ident.name.as_str())
+        }
+        Expression::MemberExpression(member_expr)
+            if is_global_object(member_expr.object(), ctx) =>
+        {
+            member_expr.static_property_name()
         }
         _ => None,
     }
@@ -206,12 +275,18 @@ fn test() {
         (r"function Infinity() {}", None),
         (r"class Infinity {}", None),
         (r"class Foo { Infinity(){}}", None),
-        // (r#"const foo = Infinity;"#, Some(serde_json::json!([{"checkInfinity": false}]))),
-        // (r#"const foo = -Infinity;"#, Some(serde_json::json!([{"checkInfinity": false}]))),
+        (r"const foo = Infinity;", Some(serde_json::json!([{"checkInfinity": false}]))),
+        (r"const foo = -Infinity;", Some(serde_json::json!([{"checkInfinity": false}]))),
         (r"class Foo2 {NaN = 1}", None),
         (r"declare var NaN: number;", None),
         (r"declare function NaN(s: string, radix?: number): number;", None),
         (r"class Foo {NaN = 1}", None),
+        (r"const NaN = 1; const foo = NaN;", None),
+        (r"function foo(Infinity) { return -Infinity; }", None),
+        (r"const parseInt = () => {}; parseInt(foo);", None),
+        (r"import {isNaN} from 'foo'; isNaN(foo);", None),
+        (r"const window = {}; window.parseFloat(foo);", None),
+        (r"foo.parseInt(bar);", None),
     ];
 
     let fail = vec![
@@ -270,7 +345,100 @@ fn test() {
         (r"self.parseFloat(foo);", None),
         (r"globalThis.NaN", None),
         (r"-globalThis.Infinity", None),
+        (r#"parseInt("10", 2);"#, None),
+        (r#"parseFloat("10.5");"#, None),
+        (r"isNaN(foo);", None),
+        (r"isFinite(foo);", None),
+        (r"const foo = [NaN, Infinity];", Some(serde_json::json!([{"checkInfinity": false}]))),
+    ];
+
+    let fix = vec![
+        (r"const foo = NaN;", r"const foo = Number.NaN;", None),
+        (r"if (Number.isNaN(NaN)) {}", r"if (Number.isNaN(Number.NaN)) {}", None),
+        (r"if (Object.is(foo, NaN)) {}", r"if (Object.is(foo, Number.NaN)) {}", None),
+        (r"const foo = bar[NaN];", r"const foo = bar[Number.NaN];", None),
+        (r"const foo = {NaN};", r"const foo = {NaN: Number.NaN};", None),
+        (r"const foo = {NaN: NaN};", r"const foo = {NaN: Number.NaN};", None),
+        (r"const {foo = NaN} = {};", r"const {foo = Number.NaN} = {};", None),
+        (r"const foo = NaN.toString();", r"const foo = Number.NaN.toString();", None),
+        (r"class Foo {[NaN] = 1}", r"class Foo {[Number.NaN] = 1}", None),
+        (r"const foo = {[NaN]() {}}", r"const foo = {[Number.NaN]() {}}", None),
+        (r"foo[NaN] = 1;", r"foo[Number.NaN] = 1;", None),
+        (r"const foo = Infinity;", r"const foo = Number.POSITIVE_INFINITY;", None),
+        (r"const foo = {Infinity};", r"const foo = {Infinity: Number.POSITIVE_INFINITY};", None),
+        (
+            r"const foo = {[Infinity]: -Infinity};",
+            r"const foo = {[Number.POSITIVE_INFINITY]: Number.NEGATIVE_INFINITY};",
+            None,
+        ),
+        (
+            r"const foo = {[-Infinity]: Infinity};",
+            r"const foo = {[Number.NEGATIVE_INFINITY]: Number.POSITIVE_INFINITY};",
+            None,
+        ),
+        (r"const {foo = -Infinity} = {};", r"const {foo = Number.NEGATIVE_INFINITY} = {};", None),
+        (
+            r"const foo = Infinity.toString();",
+            r"const foo = Number.POSITIVE_INFINITY.toString();",
+            None,
+        ),
+        (
+            r"const foo = -Infinity.toString();",
+            r"const foo = -Number.POSITIVE_INFINITY.toString();",
+            None,
+        ),
+        (
+            r"const foo = (-Infinity).toString();",
+            r"const foo = (Number.NEGATIVE_INFINITY).toString();",
+            None,
+        ),
+        (r"const foo = +Infinity;", r"const foo = +Number.POSITIVE_INFINITY;", None),
+        (r"const foo = +-Infinity;", r"const foo = +Number.NEGATIVE_INFINITY;", None),
+        (r"const foo = -Infinity;", r"const foo = Number.NEGATIVE_INFINITY;", None),
+        (r"const foo = -(-Infinity);", r"const foo = -(Number.NEGATIVE_INFINITY);", None),
+        (r"const foo = 1 - Infinity;", r"const foo = 1 - Number.POSITIVE_INFINITY;", None),
+        (r"const foo = 1 - -Infinity;", r"const foo = 1 - Number.NEGATIVE_INFINITY;", None),
+        (
+            r"const isNegativeZero = value => value === 0 && 1 / value === -Infinity;",
+            r"const isNegativeZero = value => value === 0 && 1 / value === Number.NEGATIVE_INFINITY;",
+            None,
+        ),
+        (r"const {[NaN]: a = NaN} = {};", r"const {[Number.NaN]: a = Number.NaN} = {};", None),
+        (r"function foo([a = NaN]) {}", r"function foo([a = Number.NaN]) {}", None),
+        (
+            r"function foo() {return-Infinity}",
+            r"function foo() {return Number.NEGATIVE_INFINITY}",
+            None,
+        ),
+        (r"globalThis.parseFloat(foo);", r"Number.parseFloat(foo);", None),
+        (r"self.parseFloat(foo);", r"Number.parseFloat(foo);", None),
+        (r"globalThis.NaN", r"Number.NaN", None),
+        (r"-globalThis.Infinity", r"Number.NEGATIVE_INFINITY", None),
+        (r#"parseInt("10", 2);"#, r#"Number.parseInt("10", 2);"#, None),
+        (r#"parseFloat("10.5");"#, r#"Number.parseFloat("10.5");"#, None),
+        (
+            r"const foo = [NaN, Infinity];",
+            r"const foo = [Number.NaN, Infinity];",
+            Some(serde_json::json!([{"checkInfinity": false}])),
+        ),
+        // `isNaN()` and `isFinite()` are only suggested
+        (r"isNaN(foo);", r"isNaN(foo);", None),
+        (r"globalThis.isNaN(foo);", r"globalThis.isNaN(foo);", None),
+        (r"isFinite(foo);", r"isFinite(foo);", None),
+    ];
+
+    let suggestions = vec![
+        (r"isNaN(foo);", vec![r"Number.isNaN(foo);"], None),
+        (r"isFinite(foo);", vec![r"Number.isFinite(foo);"], None),
+        (r"globalThis.isNaN(foo);", vec![r"Number.isNaN(foo);"], None),
+        (r"global.isNaN(foo);", vec![r"Number.isNaN(foo);"], None),
+        (r"window.isNaN(foo);", vec![r"Number.isNaN(foo);"], None),
+        (r"self.isNaN(foo);", vec![r"Number.isNaN(foo);"], None),
     ];
 
-    Tester::new(PreferNumberProperties::NAME, pass, fail).test_and_snapshot();
+    Tester::new(PreferNumberProperties::NAME, pass, fail)
+        .expect_fix(fix)
+        .expect_fix_kind(FixKind::Safe)
+        .expect_suggestions(suggestions)
+        .test_and_snapshot();
 }
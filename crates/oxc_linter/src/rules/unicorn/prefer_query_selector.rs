@@ -49,7 +49,8 @@ declare_oxc_lint!(
     /// document.querySelector('li').querySelectorAll('a');
     /// ```
     PreferQuerySelector,
-    pedantic
+    pedantic,
+    fix
 );
 
 impl Rule for PreferQuerySelector {
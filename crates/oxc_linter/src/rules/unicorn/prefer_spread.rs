@@ -1,5 +1,5 @@
 use oxc_ast::{
-    ast::{Argument, Expression},
+    ast::{Argument, ArrayExpression, CallExpression, Expression},
     AstKind,
 };
 use oxc_diagnostics::{
@@ -10,7 +10,7 @@ use oxc_macros::declare_oxc_lint;
 use oxc_span::{GetSpan, Span};
 use phf::phf_set;
 
-use crate::{context::LintContext, rule::Rule, AstNode, Fix};
+use crate::{context::LintContext, rule::Rule, utils::needs_leading_semicolon, AstNode, Fix};
 
 #[derive(Debug, Error, Diagnostic)]
 #[error("eslint-plugin-unicorn(prefer-spread): Prefer the spread operator (`...`) over {1}")]
@@ -34,12 +34,18 @@ declare_oxc_lint!(
     /// // bad
     /// const foo = Array.from(set);
     /// const foo = Array.from(new Set([1, 2]));
+    /// const foo = array.concat(1, [2, 3]);
+    /// const foo = string.split("");
     ///
     /// // good
-    ///
+    /// const foo = [...set];
+    /// const foo = [...new Set([1, 2])];
+    /// const foo = [...array, 1, 2, 3];
+    /// const foo = [...string];
     /// ```
     PreferSpread,
-    style
+    style,
+    fix
 );
 
 impl Rule for PreferSpread {
@@ -74,7 +80,23 @@ impl Rule for PreferSpread {
                     return;
                 }
 
-                ctx.diagnostic(PreferSpreadDiagnostic(call_expr.span, "Array.from()"));
+                let diagnostic = PreferSpreadDiagnostic(call_expr.span, "Array.from()");
+                if has_lost_comments(call_expr.span, &[expr.span()], ctx) {
+                    ctx.diagnostic(diagnostic);
+                    return;
+                }
+
+                ctx.diagnostic_with_fix(diagnostic, || {
+                    // `Array.from([1])` to `[1]`
+                    let array = if let Expression::ArrayExpression(array_expr) =
+                        expr.without_parenthesized()
+                    {
+                        array_expr.span.source_text(ctx.source_text()).to_string()
+                    } else {
+                        format!("[...{}]", expr.span().source_text(ctx.source_text()))
+                    };
+                    Fix::new(with_leading_semicolon(array, node, ctx), call_expr.span)
+                });
             }
             // `array.concat()`
             "concat" => {
@@ -82,7 +104,13 @@ impl Rule for PreferSpread {
                     return;
                 }
 
-                ctx.diagnostic(PreferSpreadDiagnostic(call_expr.span, "array.concat()"));
+                let diagnostic = PreferSpreadDiagnostic(call_expr.span, "array.concat()");
+                match fix_concat(call_expr, member_expr.object(), ctx) {
+                    Some(array) => ctx.diagnostic_with_fix(diagnostic, || {
+                        Fix::new(with_leading_semicolon(array, node, ctx), call_expr.span)
+                    }),
+                    None => ctx.diagnostic(diagnostic),
+                }
             }
             // `array.slice()`
             "slice" => {
@@ -116,7 +144,7 @@ impl Rule for PreferSpread {
                     }
                 }
 
-                ctx.diagnostic(PreferSpreadDiagnostic(call_expr.span, "array.slice()"));
+                report_copy(node, call_expr, member_expr.object(), "array.slice()", ctx);
             }
             // `array.toSpliced()`
             "toSpliced" => {
@@ -131,7 +159,7 @@ impl Rule for PreferSpread {
                     return;
                 }
 
-                ctx.diagnostic(PreferSpreadDiagnostic(call_expr.span, "array.toSpliced()"));
+                report_copy(node, call_expr, member_expr.object(), "array.toSpliced()", ctx);
             }
             // `string.split()`
             "split" => {
@@ -152,10 +180,9 @@ impl Rule for PreferSpread {
                     PreferSpreadDiagnostic(call_expr.span, "string.split()"),
                     || {
                         let callee_obj = member_expr.object().without_parenthesized();
-                        Fix::new(
-                            format!("[...{}]", callee_obj.span().source_text(ctx.source_text())),
-                            call_expr.span,
-                        )
+                        let array =
+                            format!("[...{}]", callee_obj.span().source_text(ctx.source_text()));
+                        Fix::new(with_leading_semicolon(array, node, ctx), call_expr.span)
                     },
                 );
             }
@@ -164,6 +191,121 @@ impl Rule for PreferSpread {
     }
 }
 
+/// `array.slice()` and `array.toSpliced()` are only suggested to be replaced by `[...array]`,
+/// `array` may be a string or a typed array.
+fn report_copy<'a>(
+    node: &AstNode<'a>,
+    call_expr: &CallExpression<'a>,
+    object: &Expression<'a>,
+    method: &'static str,
+    ctx: &LintContext<'a>,
+) {
+    let diagnostic = PreferSpreadDiagnostic(call_expr.span, method);
+    if has_lost_comments(call_expr.span, &[object.span()], ctx) {
+        ctx.diagnostic(diagnostic);
+        return;
+    }
+    let array = format!("[...{}]", object.span().source_text(ctx.source_text()));
+    let fix = Fix::new(with_leading_semicolon(array, node, ctx), call_expr.span)
+        .with_message("Spread the array");
+    ctx.diagnostic_with_suggestions(diagnostic, vec![fix]);
+}
+
+/// `foo.concat(1, [2, 3])` to `[...foo, 1, 2, 3]`.
+///
+/// An argument can only be moved into the array if it is known whether it is an array, whose
+/// elements are spread, or not. Moving only some of them, e.g. `foo.concat(1, bar)` to
+/// `[...foo, 1].concat(bar)`, would still be reported, so nothing is fixed then.
+fn fix_concat(
+    call_expr: &CallExpression,
+    object: &Expression,
+    ctx: &LintContext,
+) -> Option<String> {
+    let source_text = ctx.source_text();
+    if !call_expr.arguments.iter().all(
+        |argument| matches!(argument, Argument::Expression(expr) if is_known_array_or_not(expr)),
+    ) {
+        return None;
+    }
+
+    let mut kept_spans = vec![object.span()];
+    kept_spans.extend(call_expr.arguments.iter().map(GetSpan::span));
+    if has_lost_comments(call_expr.span, &kept_spans, ctx) {
+        return None;
+    }
+
+    // The elements of the new array, and whether they end with a trailing comma
+    let mut elements = vec![];
+    match object.without_parenthesized() {
+        Expression::ArrayExpression(array_expr) => elements.extend(array_elements(array_expr, ctx)),
+        _ => elements.push((format!("...{}", object.span().source_text(source_text)), false)),
+    }
+    for argument in &call_expr.arguments {
+        let Argument::Expression(expr) = argument else { continue };
+        match expr.without_parenthesized() {
+            Expression::ArrayExpression(array_expr) => {
+                elements.extend(array_elements(array_expr, ctx));
+            }
+            _ => elements.push((expr.span().source_text(source_text).to_string(), false)),
+        }
+    }
+
+    // Holes at the end of the last array are kept by its trailing comma, e.g. `[1, ,]`
+    let trailing_comma =
+        if elements.last().is_some_and(|(_, trailing_comma)| *trailing_comma) { "," } else { "" };
+    let elements = elements.into_iter().map(|(text, _)| text).collect::<Vec<_>>().join(", ");
+    Some(format!("[{elements}{trailing_comma}]"))
+}
+
+/// The elements of a non empty array literal without the brackets and its trailing comma
+fn array_elements(array_expr: &ArrayExpression, ctx: &LintContext) -> Option<(String, bool)> {
+    if array_expr.elements.is_empty() {
+        return None;
+    }
+    let inner = Span::new(array_expr.span.start + 1, array_expr.span.end - 1);
+    let inner = inner.source_text(ctx.source_text()).trim();
+    let elements = inner.strip_suffix(',');
+    Some((elements.map_or(inner, str::trim_end).to_string(), elements.is_some()))
+}
+
+/// Whether `expr` is an array literal, or is known not to be an array, e.g. `1` or `a + b`
+fn is_known_array_or_not(expr: &Expression) -> bool {
+    match expr {
+        Expression::ParenthesizedExpression(paren_expr) => {
+            is_known_array_or_not(&paren_expr.expression)
+        }
+        // The value is not an array literal, but may be an array, e.g. `(0, [1])`
+        Expression::SequenceExpression(sequence_expr) => sequence_expr
+            .expressions
+            .last()
+            .is_some_and(|expr| is_known_array_or_not(expr) && !is_array_literal(expr)),
+        Expression::ArrayExpression(_)
+        | Expression::TemplateLiteral(_)
+        | Expression::UnaryExpression(_)
+        | Expression::BinaryExpression(_) => true,
+        _ => expr.is_literal(),
+    }
+}
+
+fn is_array_literal(expr: &Expression) -> bool {
+    matches!(expr.without_parenthesized(), Expression::ArrayExpression(_))
+}
+
+/// Whether there are comments in `span` outside of the `kept` spans, which a fix would remove
+fn has_lost_comments(span: Span, kept: &[Span], ctx: &LintContext) -> bool {
+    ctx.semantic().trivias().comments().range(span.start..span.end).any(|(start, _)| {
+        !kept.iter().any(|kept_span| kept_span.start <= *start && *start < kept_span.end)
+    })
+}
+
+fn with_leading_semicolon(array: String, node: &AstNode, ctx: &LintContext) -> String {
+    if needs_leading_semicolon(node, ctx) {
+        format!(";{array}")
+    } else {
+        array
+    }
+}
+
 const IGNORED_SLICE_CALLEE: phf::Set<&'static str> = phf_set! {
     "arrayBuffer",
     "blob",
@@ -215,7 +357,7 @@ fn is_not_array(expr: &Expression) -> bool {
 
 #[test]
 fn test() {
-    use crate::tester::Tester;
+    use crate::{tester::Tester, FixKind};
 
     let pass = vec![
         r"[...set].map(() => {});",
@@ -428,14 +570,104 @@ fn test() {
 
     let expect_fix = vec![
         // `Array.from()`
-        // `array.slice()`
-        // `array.toSpliced()`
+        (r"const x = Array.from(set);", r"const x = [...set];", None),
+        (r"Array.from(set).map(() => {});", r"[...set].map(() => {});", None),
+        (
+            r"Array.from(new Set([1, 2])).map(() => {});",
+            r"[...new Set([1, 2])].map(() => {});",
+            None,
+        ),
+        (r"const foo = `${Array.from(arrayLike)}`", r"const foo = `${[...arrayLike]}`", None),
+        (r"(Array).from(foo)", r"[...foo]", None),
+        (r"(Array.from)(foo)", r"[...foo]", None),
+        (r"(Array).from((0, foo))", r"[...(0, foo)]", None),
+        (r"Array.from(a ? b : c)", r"[...a ? b : c]", None),
+        (r"Array.from([...a, ...b], )", r"[...a, ...b]", None),
+        (r"Array.from([1])", r"[1]", None),
+        (
+            "foo()\nArray.from(set).forEach(doSomething)",
+            "foo()\n;[...set].forEach(doSomething)",
+            None,
+        ),
+        (
+            "foo();\nArray.from(set).forEach(doSomething)",
+            "foo();\n[...set].forEach(doSomething)",
+            None,
+        ),
+        (
+            r"/* 1 */ Array /* 2 */ .from /* 3 */ ( /* 4 */ a /* 5 */,)",
+            r"/* 1 */ Array /* 2 */ .from /* 3 */ ( /* 4 */ a /* 5 */,)",
+            None,
+        ),
+        // `array.concat()`
+        (r"[1].concat(2)", r"[1, 2]", None),
+        (r"[1].concat([2, 3])", r"[1, 2, 3]", None),
+        (r"[1].concat(2,)", r"[1, 2]", None),
+        (r"[1].concat([2, ...bar],)", r"[1, 2, ...bar]", None),
+        (r"[1,].concat(2)", r"[1, 2]", None),
+        (r"[1,].concat([2, 3],)", r"[1, 2, 3]", None),
+        (r"(( (( (( [1,] )).concat ))( (([2, 3])) ,) ))", r"(( [1, 2, 3] ))", None),
+        (r"foo.concat(2)", r"[...foo, 2]", None),
+        (r"foo.concat([2, 3])", r"[...foo, 2, 3]", None),
+        (r"(( (( ((foo)).concat ))( (([2, 3])) ,) ))", r"(( [...((foo)), 2, 3] ))", None),
+        (r"const foo = () => foo.concat(2)", r"const foo = () => [...foo, 2]", None),
+        (r"foo.concat((0, 1))", r"[...foo, (0, 1)]", None),
+        (r"foo.concat(-1, a + b, `c`)", r"[...foo, -1, a + b, `c`]", None),
+        (
+            r"let sortedScores = scores.concat().sort((a, b) => b[0] - a[0]);",
+            r"let sortedScores = [...scores].sort((a, b) => b[0] - a[0]);",
+            None,
+        ),
+        (
+            r"async function a() {return (await bar).concat(1)}",
+            r"async function a() {return [...(await bar), 1]}",
+            None,
+        ),
+        (r"[].concat([,], [])", r"[,]", None),
+        (r"[,].concat([,], [,])", r"[, , ,]", None),
+        (r"[1].concat([2,], [3,])", r"[1, 2, 3,]", None),
+        (r"[1].concat([2,,], [3,,])", r"[1, 2,, 3,,]", None),
+        (r"[].concat([], [])", r"[]", None),
+        (r"if (test) foo.concat(1)", r"if (test) [...foo, 1]", None),
+        (r"do foo.concat(1); while (test)", r"do [...foo, 1]; while (test)", None),
+        ("foo()\nbar.concat(1)", "foo()\n;[...bar, 1]", None),
+        (r#"foo.join(foo, bar).concat("...")"#, r#"[...foo.join(foo, bar), "..."]"#, None),
+        // Not known whether the arguments are arrays
+        (r"foo.concat(bar)", r"foo.concat(bar)", None),
+        (r"foo.concat(bar, 2)", r"foo.concat(bar, 2)", None),
+        (r"foo.concat(2, bar)", r"foo.concat(2, bar)", None),
+        (
+            r"(( (( (( [1,] )).concat ))( (([2, 3])) , bar ) ))",
+            r"(( (( (( [1,] )).concat ))( (([2, 3])) , bar ) ))",
+            None,
+        ),
+        (r"[].concat(...bar)", r"[].concat(...bar)", None),
+        (r"foo.concat((0, [1]))", r"foo.concat((0, [1]))", None),
+        (
+            r"async function a() {return [].concat(await bar)}",
+            r"async function a() {return [].concat(await bar)}",
+            None,
+        ),
+        (r"foo.concat(/* comment */ 1)", r"foo.concat(/* comment */ 1)", None),
+        (r"foo /* comment */ .concat(1)", r"foo /* comment */ .concat(1)", None),
         // `string.split()`
         (r#""🦄".split("")"#, r#"[..."🦄"]"#, None),
         (r#""foo bar baz".split("")"#, r#"[..."foo bar baz"]"#, None),
     ];
 
+    let expect_suggestions = vec![
+        (r"array.slice()", vec![r"[...array]"], None),
+        (r"array.slice(0)", vec![r"[...array]"], None),
+        (r"(( (( (( array )).slice ))() ))", vec![r"(( [...(( array ))] ))"], None),
+        (r"array.toSpliced()", vec![r"[...array]"], None),
+        (r"const copy = array.toSpliced()", vec![r"const copy = [...array]"], None),
+    ];
+
+    // `Array.from` also accepts array-likes, the object of `concat` may not be an array, and
+    // `split("")` splits a string into UTF-16 code units instead of code points
     Tester::new_without_config(PreferSpread::NAME, pass, fail)
         .expect_fix(expect_fix)
+        .expect_fix_kind(FixKind::Dangerous)
+        .expect_suggestions(expect_suggestions)
         .test_and_snapshot();
 }
@@ -1,12 +1,21 @@
-use oxc_ast::{ast::MemberExpression, AstKind};
+use oxc_ast::{
+    ast::{Argument, Expression, MemberExpression},
+    AstKind,
+};
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
     thiserror::{self, Error},
 };
 use oxc_macros::declare_oxc_lint;
-use oxc_span::{Atom, Span};
+use oxc_span::{Atom, GetSpan, Span};
+use oxc_syntax::operator::UnaryOperator;
 
-use crate::{context::LintContext, rule::Rule, AstNode};
+use crate::{
+    context::LintContext,
+    fixer::{Fix, FixKind},
+    rule::Rule,
+    AstNode,
+};
 
 #[derive(Debug, Error, Diagnostic)]
 #[error("eslint-plugin-unicorn(prefer-string-slice): Prefer String#slice() over String#{1}()")]
@@ -27,9 +36,20 @@ declare_oxc_lint!(
     ///
     /// ### Example
     /// ```javascript
+    /// // Bad
+    /// foo.substr(1, 2);
+    /// foo.substring(2, 1);
+    ///
+    /// // Good
+    /// foo.slice(1, 3);
+    /// foo.slice(1, 2);
     /// ```
+    ///
+    /// The arguments are only rewritten when the result is known to be equivalent, e.g.
+    /// `foo.substring(start, end)` is reported but not fixed.
     PreferStringSlice,
-    pedantic
+    pedantic,
+    fix
 );
 
 impl Rule for PreferStringSlice {
@@ -50,10 +70,181 @@ impl Rule for PreferStringSlice {
             _ => return,
         };
 
-        ctx.diagnostic(PreferStringSliceDiagnostic(span, name.clone()));
+        let diagnostic = PreferStringSliceDiagnostic(span, name.clone());
+        let replacements = call_expr
+            .arguments
+            .iter()
+            .map(|argument| match argument {
+                Argument::Expression(expr) => Some(expr),
+                Argument::SpreadElement(_) => None,
+            })
+            .collect::<Option<Vec<_>>>()
+            .and_then(|arguments| match (name.as_str(), arguments.as_slice()) {
+                (_, []) => Some(vec![]),
+                ("substr", [start]) => fix_substr_arguments(start, None, ctx),
+                ("substr", [start, length]) => fix_substr_arguments(start, Some(length), ctx),
+                (_, [start]) => fix_substring_arguments(start, None, ctx),
+                (_, [start, end]) => fix_substring_arguments(start, Some(end), ctx),
+                _ => None,
+            });
+        let Some(mut replacements) = replacements else {
+            ctx.diagnostic(diagnostic);
+            return;
+        };
+
+        ctx.diagnostic_with_fix(diagnostic, || {
+            replacements.insert(0, (span, "slice".to_string()));
+            let fix_span = Span::new(span.start, call_expr.span.end);
+            let mut content = String::new();
+            let mut last_end = fix_span.start;
+            for (replaced, text) in replacements {
+                content
+                    .push_str(Span::new(last_end, replaced.start).source_text(ctx.source_text()));
+                content.push_str(&text);
+                last_end = replaced.end;
+            }
+            content.push_str(Span::new(last_end, fix_span.end).source_text(ctx.source_text()));
+            Fix::new(content, fix_span).with_kind(FixKind::Safe)
+        });
     }
 }
 
+/// The replacements of the arguments of `substr(start, length)` to `slice(start, end)`,
+/// `None` if the end can't be computed statically.
+fn fix_substr_arguments(
+    start: &Expression,
+    length: Option<&Expression>,
+    ctx: &LintContext,
+) -> Option<Vec<(Span, String)>> {
+    let Some(length) = length else { return Some(vec![]) };
+
+    // `substr(0, length)`
+    if get_static_number(start) == Some(0.0) {
+        if number_literal_value(length).is_some() || is_length_property(length) {
+            return Some(vec![]);
+        }
+        // A negative length returns an empty string, a negative end does not
+        get_static_number(length)?;
+        let length_text = length.span().source_text(ctx.source_text());
+        return Some(vec![(length.span(), format!("Math.max(0, {length_text})"))]);
+    }
+
+    // `substr(1, 2)` to `slice(1, 3)`
+    if let (Some(start), Some(length_value)) =
+        (number_literal_value(start), number_literal_value(length))
+    {
+        return Some(vec![(length.span(), (start + length_value).to_string())]);
+    }
+
+    // `substr(1, foo.length)` to `slice(1, 1 + foo.length)`
+    let is_number_or_length =
+        |expr: &Expression| number_literal_value(expr).is_some() || is_length_property(expr);
+    if is_number_or_length(start) && is_number_or_length(length) {
+        let start_text = start.span().source_text(ctx.source_text());
+        return Some(vec![(
+            Span::new(length.span().start, length.span().start),
+            format!("{start_text} + "),
+        )]);
+    }
+
+    None
+}
+
+/// The replacements of the arguments of `substring(start, end)` to `slice(start, end)`,
+/// `None` if they may be negative or swapped.
+#[allow(clippy::float_cmp)]
+fn fix_substring_arguments<'a>(
+    start: &Expression<'a>,
+    end: Option<&Expression<'a>>,
+    ctx: &LintContext,
+) -> Option<Vec<(Span, String)>> {
+    // `substring` treats negative numbers as `0`, `slice` counts them from the end
+    let clamp =
+        |expr: &Expression| format!("Math.max(0, {})", expr.span().source_text(ctx.source_text()));
+    let start_number = get_numeric_value(start);
+
+    let Some(end) = end else {
+        if is_length_property(start) {
+            return Some(vec![]);
+        }
+        return Some(match start_number {
+            Some(number) if number < 0.0 => vec![(start.span(), "0".to_string())],
+            Some(_) => vec![],
+            None => vec![(start.span(), clamp(start))],
+        });
+    };
+    let end_number = get_numeric_value(end);
+
+    match (start_number, end_number) {
+        // `substring` swaps the arguments if `start` is greater than `end`
+        (Some(start_number), Some(end_number)) => {
+            let (new_start, new_end) = if start_number > end_number {
+                (end_number.max(0.0), start_number.max(0.0))
+            } else {
+                (start_number.max(0.0), end_number.max(0.0))
+            };
+            let mut replacements = vec![];
+            if new_start != start_number {
+                replacements.push((start.span(), new_start.to_string()));
+            }
+            if new_end != end_number {
+                replacements.push((end.span(), new_end.to_string()));
+            }
+            Some(replacements)
+        }
+        // `substring(0, end)` and `substring(end, 0)` to `slice(0, Math.max(0, end))`
+        (Some(number), None) | (None, Some(number)) if number == 0.0 => {
+            let other = if start_number.is_some() { end } else { start };
+            Some(vec![(start.span(), "0".to_string()), (end.span(), clamp(other))])
+        }
+        _ => None,
+    }
+}
+
+fn number_literal_value(expr: &Expression) -> Option<f64> {
+    match expr {
+        Expression::NumberLiteral(lit) => Some(lit.value),
+        _ => None,
+    }
+}
+
+/// The value of a number literal, or of a negated one, e.g. `-1`
+fn get_numeric_value(expr: &Expression) -> Option<f64> {
+    match expr {
+        Expression::UnaryExpression(unary_expr)
+            if unary_expr.operator == UnaryOperator::UnaryNegation =>
+        {
+            number_literal_value(&unary_expr.argument).map(|value| -value)
+        }
+        _ => number_literal_value(expr),
+    }
+}
+
+/// The value of a number known without evaluating any other code, e.g. `(100, 1)`
+fn get_static_number(expr: &Expression) -> Option<f64> {
+    match expr {
+        Expression::ParenthesizedExpression(paren_expr) => {
+            get_static_number(&paren_expr.expression)
+        }
+        Expression::SequenceExpression(sequence_expr) => sequence_expr
+            .expressions
+            .iter()
+            .map(get_static_number)
+            .collect::<Option<Vec<_>>>()?
+            .last()
+            .copied(),
+        _ => get_numeric_value(expr),
+    }
+}
+
+/// `foo.length`
+fn is_length_property(expr: &Expression) -> bool {
+    matches!(expr, Expression::MemberExpression(member_expr)
+        if matches!(&**member_expr, MemberExpression::StaticMemberExpression(_))
+            && !member_expr.optional()
+            && member_expr.static_property_name() == Some("length"))
+}
+
 #[test]
 fn test() {
     use crate::tester::Tester;
@@ -102,6 +293,7 @@ fn test() {
         r#""foo".substring(1, 2)"#,
         r#""foo".substring(2, 1)"#,
         r#""foo".substring(-1, -5)"#,
+        r#""foo".substring(-1)"#,
         r#""foo".substring(-1, 2)"#,
         r#""foo".substring(length)"#,
         r#""foobar".substring("foo".length)"#,
@@ -123,5 +315,67 @@ fn test() {
         r"foo.substring((10, bar))",
     ];
 
-    Tester::new_without_config(PreferStringSlice::NAME, pass, fail).test_and_snapshot();
+    let fix = vec![
+        (r"foo.substr()", r"foo.slice()", None),
+        (r"foo?.substr()", r"foo?.slice()", None),
+        (r"foo.bar?.substring()", r"foo.bar?.slice()", None),
+        (r"foo?.[0]?.substring()", r"foo?.[0]?.slice()", None),
+        (r"foo.bar.substr?.()", r"foo.bar.slice?.()", None),
+        (r"foo.bar?.substring?.()", r"foo.bar?.slice?.()", None),
+        (r#""foo".substr()"#, r#""foo".slice()"#, None),
+        (r#""foo".substr(1)"#, r#""foo".slice(1)"#, None),
+        (r#""foo".substr(1, 2)"#, r#""foo".slice(1, 3)"#, None),
+        (
+            r#""foo".substr(bar.length, Math.min(baz, 100))"#,
+            r#""foo".substr(bar.length, Math.min(baz, 100))"#,
+            None,
+        ),
+        (r#""foo".substr(1, length)"#, r#""foo".substr(1, length)"#, None),
+        (r#""foo".substr(1, "abc".length)"#, r#""foo".slice(1, 1 + "abc".length)"#, None),
+        (r#""foo".substr("1", 2)"#, r#""foo".substr("1", 2)"#, None),
+        (r#""foo".substr(0, -1)"#, r#""foo".slice(0, Math.max(0, -1))"#, None),
+        (r#""foo".substr(0, "foo".length)"#, r#""foo".slice(0, "foo".length)"#, None),
+        (r"foo.substr(start)", r"foo.slice(start)", None),
+        (r"foo.substr(start, length)", r"foo.substr(start, length)", None),
+        (r"foo.substr(1, 2, 3)", r"foo.substr(1, 2, 3)", None),
+        (
+            r#""Sample".substr(0, "Sample".lastIndexOf("/"))"#,
+            r#""Sample".substr(0, "Sample".lastIndexOf("/"))"#,
+            None,
+        ),
+        (r"foo.substring()", r"foo.slice()", None),
+        (r#""foo".substring()"#, r#""foo".slice()"#, None),
+        (r#""foo".substring(1)"#, r#""foo".slice(1)"#, None),
+        (r#""foo".substring(-1)"#, r#""foo".slice(0)"#, None),
+        (r#""foo".substring(1, 2)"#, r#""foo".slice(1, 2)"#, None),
+        (r#""foo".substring(2, 1)"#, r#""foo".slice(1, 2)"#, None),
+        (r#""foo".substring(-1, -5)"#, r#""foo".slice(0, 0)"#, None),
+        (r#""foo".substring(-1, 2)"#, r#""foo".slice(0, 2)"#, None),
+        (r#""foo".substring(length)"#, r#""foo".slice(Math.max(0, length))"#, None),
+        (r#""foobar".substring("foo".length)"#, r#""foobar".slice("foo".length)"#, None),
+        (r#""foo".substring(0, length)"#, r#""foo".slice(0, Math.max(0, length))"#, None),
+        (r#""foo".substring(length, 0)"#, r#""foo".slice(0, Math.max(0, length))"#, None),
+        (r"foo.substring(start)", r"foo.slice(Math.max(0, start))", None),
+        (r"foo.substring(start, end)", r"foo.substring(start, end)", None),
+        (r#""foo".substring(1, 3)"#, r#""foo".slice(1, 3)"#, None),
+        (r"foo.substring(1, 2, 3)", r"foo.substring(1, 2, 3)", None),
+        (r"foo.substr(0, ...bar)", r"foo.substr(0, ...bar)", None),
+        (r"foo.substr(...bar)", r"foo.substr(...bar)", None),
+        (r"foo.substr(0, (100, 1))", r"foo.slice(0, Math.max(0, (100, 1)))", None),
+        (r"foo.substr(0, 1, extraArgument)", r"foo.substr(0, 1, extraArgument)", None),
+        (
+            r"foo.substr((0, bar.length), (0, baz.length))",
+            r"foo.substr((0, bar.length), (0, baz.length))",
+            None,
+        ),
+        (r"foo.substring((10, 1), 0)", r"foo.slice(0, Math.max(0, (10, 1)))", None),
+        (r"foo.substring(0, (10, 1))", r"foo.slice(0, Math.max(0, (10, 1)))", None),
+        (r"foo.substring(0, await 1)", r"foo.slice(0, Math.max(0, await 1))", None),
+        (r"foo.substring((10, bar))", r"foo.slice(Math.max(0, (10, bar)))", None),
+    ];
+
+    Tester::new_without_config(PreferStringSlice::NAME, pass, fail)
+        .expect_fix(fix)
+        .expect_fix_kind(FixKind::Safe)
+        .test_and_snapshot();
 }
@@ -1,5 +1,5 @@
 use oxc_ast::{
-    ast::{Expression, MemberExpression, RegExpFlags, RegExpLiteral},
+    ast::{Argument, Expression, MemberExpression, RegExpFlags, RegExpLiteral},
     AstKind,
 };
 use oxc_diagnostics::{
@@ -9,7 +9,12 @@ use oxc_diagnostics::{
 use oxc_macros::declare_oxc_lint;
 use oxc_span::{GetSpan, Span};
 
-use crate::{context::LintContext, rule::Rule, AstNode};
+use crate::{
+    context::LintContext,
+    fixer::{Fix, FixKind},
+    rule::Rule,
+    AstNode,
+};
 
 #[derive(Debug, Error, Diagnostic)]
 enum PreferStringStartsEndsWithDiagnostic {
@@ -44,7 +49,8 @@ declare_oxc_lint!(
     /// foo.startsWith("abc");
     /// ```
     PreferStringStartsEndsWith,
-    correctness
+    correctness,
+    fix
 );
 
 impl Rule for PreferStringStartsEndsWith {
@@ -71,18 +77,66 @@ impl Rule for PreferStringStartsEndsWith {
             return;
         };
 
-        let Some(err_kind) = check_regex(regex) else { return };
+        let Some((err_kind, string)) = check_regex(regex) else { return };
 
-        match err_kind {
+        let diagnostic = match err_kind {
             ErrorKind::StartsWith => {
-                ctx.diagnostic(PreferStringStartsEndsWithDiagnostic::StartsWith(
-                    member_expr.span(),
-                ));
+                PreferStringStartsEndsWithDiagnostic::StartsWith(member_expr.span())
             }
             ErrorKind::EndsWith => {
-                ctx.diagnostic(PreferStringStartsEndsWithDiagnostic::EndsWith(member_expr.span()));
+                PreferStringStartsEndsWithDiagnostic::EndsWith(member_expr.span())
             }
+        };
+
+        let [Argument::Expression(target)] = call_expr.arguments.as_slice() else {
+            ctx.diagnostic(diagnostic);
+            return;
+        };
+
+        // Everything but the regex and the target is replaced
+        let trivias = ctx.semantic().trivias();
+        if trivias
+            .has_comments_between(Span::new(member_expr.object().span().end, target.span().start))
+            || trivias.has_comments_between(Span::new(target.span().end, call_expr.span.end))
+        {
+            ctx.diagnostic(diagnostic);
+            return;
         }
+
+        let method = err_kind.method_name();
+        let argument = quote_string(&string);
+        let target_text = target.span().source_text(ctx.source_text());
+        let object_text = if needs_parentheses_as_object(target) {
+            format!("({target_text})")
+        } else {
+            target_text.to_string()
+        };
+
+        // The regex literal always starts with `/` or `(`, so no ASI hazard is introduced
+        if is_string(target.without_parenthesized()) {
+            ctx.diagnostic_with_fix(diagnostic, || {
+                Fix::new(format!("{object_text}.{method}({argument})"), call_expr.span)
+                    .with_kind(FixKind::Safe)
+            });
+            return;
+        }
+
+        let nullish_text = if needs_parentheses_in_nullish_coalescing(target) {
+            format!("({target_text})")
+        } else {
+            target_text.to_string()
+        };
+        ctx.diagnostic_with_suggestions(
+            diagnostic,
+            vec![
+                Fix::new(format!("String({target_text}).{method}({argument})"), call_expr.span)
+                    .with_message(format!("Convert to string `String(…).{method}()`")),
+                Fix::new(format!("{object_text}?.{method}({argument})"), call_expr.span)
+                    .with_message(format!("Use optional chaining `…?.{method}()`")),
+                Fix::new(format!("({nullish_text} ?? '').{method}({argument})"), call_expr.span)
+                    .with_message(format!("Use nullish coalescing `(… ?? '').{method}()`")),
+            ],
+        );
     }
 }
 
@@ -91,31 +145,122 @@ enum ErrorKind {
     EndsWith,
 }
 
-fn check_regex(regexp_lit: &RegExpLiteral) -> Option<ErrorKind> {
+impl ErrorKind {
+    fn method_name(&self) -> &'static str {
+        match self {
+            Self::StartsWith => "startsWith",
+            Self::EndsWith => "endsWith",
+        }
+    }
+}
+
+/// Returns the string matched by an anchored regex, e.g. `"foo"` for `/^foo/`
+fn check_regex(regexp_lit: &RegExpLiteral) -> Option<(ErrorKind, String)> {
     if regexp_lit.regex.flags.intersects(RegExpFlags::I | RegExpFlags::M) {
         return None;
     }
 
-    if regexp_lit.regex.pattern.starts_with('^')
-        && is_simple_string(&regexp_lit.regex.pattern.as_str()[1..regexp_lit.regex.pattern.len()])
-    {
-        return Some(ErrorKind::StartsWith);
+    let pattern = regexp_lit.regex.pattern.as_str();
+
+    if let Some(string) = pattern.strip_prefix('^').and_then(parse_simple_string) {
+        return Some((ErrorKind::StartsWith, string));
     }
 
-    if regexp_lit.regex.pattern.ends_with('$')
-        && is_simple_string(
-            &regexp_lit.regex.pattern.as_str()[0..regexp_lit.regex.pattern.len() - 1],
-        )
-    {
-        return Some(ErrorKind::EndsWith);
+    if let Some(string) = pattern.strip_suffix('$').and_then(parse_simple_string) {
+        return Some((ErrorKind::EndsWith, string));
     }
 
     None
 }
 
-fn is_simple_string(str: &str) -> bool {
-    str.chars()
-        .all(|c| !matches!(c, '^' | '$' | '+' | '[' | '{' | '(' | '\\' | '.' | '?' | '*' | '|'))
+/// Parses a regex pattern that only matches a literal string, resolving escaped characters
+/// such as `\.` or `\n`. Returns `None` for any other regex syntax.
+fn parse_simple_string(pattern: &str) -> Option<String> {
+    let mut string = String::with_capacity(pattern.len());
+    let mut chars = pattern.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '^' | '$' | '+' | '[' | '{' | '(' | ')' | '.' | '?' | '*' | '|' => return None,
+            '\\' => {
+                let escaped = match chars.next()? {
+                    c @ ('^' | '$' | '\\' | '.' | '*' | '+' | '?' | '(' | ')' | '[' | ']' | '{'
+                    | '}' | '|' | '/') => c,
+                    'n' => '\n',
+                    'r' => '\r',
+                    't' => '\t',
+                    'v' => '\u{b}',
+                    'f' => '\u{c}',
+                    // `\0` is a null character unless followed by a digit
+                    '0' if !chars.peek().is_some_and(char::is_ascii_digit) => '\0',
+                    // Character classes, assertions, back references, unicode escapes, ...
+                    _ => return None,
+                };
+                string.push(escaped);
+            }
+            c => string.push(c),
+        }
+    }
+    Some(string)
+}
+
+fn quote_string(string: &str) -> String {
+    let mut quoted = String::with_capacity(string.len() + 2);
+    quoted.push('\'');
+    for c in string.chars() {
+        match c {
+            '\'' => quoted.push_str("\\'"),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            '\r' => quoted.push_str("\\r"),
+            '\t' => quoted.push_str("\\t"),
+            '\u{b}' => quoted.push_str("\\v"),
+            '\u{c}' => quoted.push_str("\\f"),
+            '\0' => quoted.push_str("\\0"),
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('\'');
+    quoted
+}
+
+fn is_string(expr: &Expression) -> bool {
+    match expr {
+        Expression::StringLiteral(_) | Expression::TemplateLiteral(_) => true,
+        Expression::CallExpression(call_expr) => {
+            call_expr.callee.is_specific_id("String") && call_expr.arguments.len() == 1
+        }
+        _ => false,
+    }
+}
+
+/// Whether `expr` must be wrapped to be used as `expr.startsWith()`
+fn needs_parentheses_as_object(expr: &Expression) -> bool {
+    !matches!(
+        expr,
+        Expression::ParenthesizedExpression(_)
+            | Expression::Identifier(_)
+            | Expression::ThisExpression(_)
+            | Expression::StringLiteral(_)
+            | Expression::TemplateLiteral(_)
+            | Expression::TaggedTemplateExpression(_)
+            | Expression::ArrayExpression(_)
+            | Expression::MemberExpression(_)
+            | Expression::CallExpression(_)
+            | Expression::ChainExpression(_)
+    )
+}
+
+/// Whether `expr` must be wrapped to be used as `expr ?? ''`
+fn needs_parentheses_in_nullish_coalescing(expr: &Expression) -> bool {
+    matches!(
+        expr,
+        Expression::LogicalExpression(_)
+            | Expression::ConditionalExpression(_)
+            | Expression::AssignmentExpression(_)
+            | Expression::ArrowExpression(_)
+            | Expression::YieldExpression(_)
+            | Expression::SequenceExpression(_)
+    )
 }
 
 #[test]
@@ -152,6 +297,14 @@ fn test() {
         r"/A|B$/.test(bar)",
         // Additional tests
         r"/^http/i.test(uri)",
+        r"/^\d/.test(bar)",
+        r"/\d$/.test(bar)",
+        r"/^\x61/.test(bar)",
+        r"/^\u0061/.test(bar)",
+        r"/^\b/.test(bar)",
+        r"/^\00/.test(bar)",
+        r"/foo\$/.test(bar)",
+        r"/^foo\\$/.test(bar)",
     ];
 
     let fail = vec![
@@ -196,7 +349,112 @@ fn test() {
         r"/a$/.test(String(unknown))",
         r"const a = /你$/.test('a');",
         r"const a = /^你/.test('a');",
+        // Escaped characters
+        r"/^\./.test('foo')",
+        r#"/\$$/.test("foo")"#,
+        r"/^\\/.test('foo')",
+        r"/^'/.test('foo')",
+        r"/^\n/.test('foo')",
+        r"/^foo\/bar/.test('foo')",
+        r"/^a/.test(/* comment */ 'a')",
+    ];
+
+    let fix = vec![
+        (r#"/^a/.test("string")"#, r#""string".startsWith('a')"#, None),
+        (r#"/^a/u.test("string")"#, r#""string".startsWith('a')"#, None),
+        (r#"/^a/v.test("string")"#, r#""string".startsWith('a')"#, None),
+        (r"/^a/.test(`string`)", r"`string`.startsWith('a')", None),
+        (r"/a$/.test(`${unknown}`)", r"`${unknown}`.endsWith('a')", None),
+        (r"/a$/.test(String(unknown))", r"String(unknown).endsWith('a')", None),
+        (r"const a = /你$/.test('a');", r"const a = 'a'.endsWith('你');", None),
+        (r"const a = /^你/.test('a');", r"const a = 'a'.startsWith('你');", None),
+        (r"/^\./.test('foo')", r"'foo'.startsWith('.')", None),
+        (r#"/\$$/.test("foo")"#, r#""foo".endsWith('$')"#, None),
+        (r"/^\\/.test('foo')", r"'foo'.startsWith('\\')", None),
+        (r"/^'/.test('foo')", r"'foo'.startsWith('\'')", None),
+        (r"/^\n/.test('foo')", r"'foo'.startsWith('\n')", None),
+        (r"/^foo\/bar/.test('foo')", r"'foo'.startsWith('foo/bar')", None),
+        (r"/^a/.test(/* comment */ 'a')", r"/^a/.test(/* comment */ 'a')", None),
+    ];
+
+    let suggestions = vec![
+        (
+            r"/^foo/.test(bar)",
+            vec![
+                r"String(bar).startsWith('foo')",
+                r"bar?.startsWith('foo')",
+                r"(bar ?? '').startsWith('foo')",
+            ],
+            None,
+        ),
+        (
+            r"/foo$/.test(bar)",
+            vec![
+                r"String(bar).endsWith('foo')",
+                r"bar?.endsWith('foo')",
+                r"(bar ?? '').endsWith('foo')",
+            ],
+            None,
+        ),
+        (
+            r"(/^b/).test((a))",
+            vec![
+                r"String((a)).startsWith('b')",
+                r"(a)?.startsWith('b')",
+                r"((a) ?? '').startsWith('b')",
+            ],
+            None,
+        ),
+        (
+            r"const fn = async () => /^b/.test(await foo)",
+            vec![
+                r"const fn = async () => String(await foo).startsWith('b')",
+                r"const fn = async () => (await foo)?.startsWith('b')",
+                r"const fn = async () => (await foo ?? '').startsWith('b')",
+            ],
+            None,
+        ),
+        (
+            r"/^a/.test(foo || bar)",
+            vec![
+                r"String(foo || bar).startsWith('a')",
+                r"(foo || bar)?.startsWith('a')",
+                r"((foo || bar) ?? '').startsWith('a')",
+            ],
+            None,
+        ),
+        (
+            r"/^a/.test(new SomeString)",
+            vec![
+                r"String(new SomeString).startsWith('a')",
+                r"(new SomeString)?.startsWith('a')",
+                r"(new SomeString ?? '').startsWith('a')",
+            ],
+            None,
+        ),
+        (
+            r"/^a/.test(foo?.bar)",
+            vec![
+                r"String(foo?.bar).startsWith('a')",
+                r"foo?.bar?.startsWith('a')",
+                r"(foo?.bar ?? '').startsWith('a')",
+            ],
+            None,
+        ),
+        (
+            r"/a$/.test(a ??= b)",
+            vec![
+                r"String(a ??= b).endsWith('a')",
+                r"(a ??= b)?.endsWith('a')",
+                r"((a ??= b) ?? '').endsWith('a')",
+            ],
+            None,
+        ),
     ];
 
-    Tester::new_without_config(PreferStringStartsEndsWith::NAME, pass, fail).test_and_snapshot();
+    Tester::new_without_config(PreferStringStartsEndsWith::NAME, pass, fail)
+        .expect_fix(fix)
+        .expect_fix_kind(FixKind::Safe)
+        .expect_suggestions(suggestions)
+        .test_and_snapshot();
 }
@@ -6,7 +6,12 @@ use oxc_diagnostics::{
 use oxc_macros::declare_oxc_lint;
 use oxc_span::{GetSpan, Span};
 
-use crate::{context::LintContext, fixer::Fix, rule::Rule, AstNode};
+use crate::{
+    context::LintContext,
+    fixer::{Fix, FixKind},
+    rule::Rule,
+    AstNode,
+};
 
 #[derive(Debug, Error, Diagnostic)]
 #[error("eslint-plugin-unicorn(require-number-to-fixed-digits-argument): Number method .toFixed() should have an argument")]
@@ -34,7 +39,8 @@ declare_oxc_lint!(
     /// number.toFixed();
     /// ```
     RequireNumberToFixedDigitsArgument,
-    pedantic
+    pedantic,
+    fix
 );
 
 impl Rule for RequireNumberToFixedDigitsArgument {
@@ -78,7 +84,7 @@ impl Rule for RequireNumberToFixedDigitsArgument {
                                 formatter.into_code()
                             };
 
-                            Fix::new(modified_code, parenthesis_span)
+                            Fix::new(modified_code, parenthesis_span).with_kind(FixKind::Safe)
                         },
                     );
                 }
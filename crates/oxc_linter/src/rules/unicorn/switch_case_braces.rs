@@ -7,7 +7,12 @@ use oxc_formatter::Gen;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::{GetSpan, Span};
 
-use crate::{context::LintContext, fixer::Fix, rule::Rule, AstNode};
+use crate::{
+    context::LintContext,
+    fixer::{Fix, FixKind},
+    rule::Rule,
+    AstNode,
+};
 
 #[derive(Debug, Error, Diagnostic)]
 #[error("eslint-plugin-unicorn(switch-case-braces):  Empty switch case shouldn't have braces and not-empty case should have braces around it.")]
@@ -39,7 +44,8 @@ declare_oxc_lint!(
     /// }
     /// ```
     SwitchCaseBraces,
-    style
+    style,
+    fix
 );
 
 impl Rule for SwitchCaseBraces {
@@ -57,7 +63,7 @@ impl Rule for SwitchCaseBraces {
                         if case_block.body.is_empty() {
                             ctx.diagnostic_with_fix(
                                 SwitchCaseBracesDiagnostic(case_block.span),
-                                || Fix::new("", case_block.span),
+                                || Fix::new("", case_block.span).with_kind(FixKind::Safe),
                             );
                         }
                     }
@@ -6,18 +6,18 @@ use oxc_diagnostics::{
 };
 
 use oxc_macros::declare_oxc_lint;
-use oxc_span::Span;
+use oxc_span::{GetSpan, Span};
 use regex::Regex;
 
 use crate::{
-    ast_util::{outermost_paren, outermost_paren_parent},
     context::LintContext,
+    fixer::{Fix, FixKind},
     rule::Rule,
     AstNode,
 };
 
 #[derive(Debug, Error, Diagnostic)]
-#[error("eslint-plugin-unicorn(throw-new-error): Require `new` when throwing an error.")]
+#[error("eslint-plugin-unicorn(throw-new-error): Require `new` when creating an error.")]
 #[diagnostic(severity(warning), help("While it's possible to create a new error without using the `new` keyword, it's better to be explicit."))]
 struct ThrowNewErrorDiagnostic(#[label] pub Span);
 
@@ -27,7 +27,7 @@ pub struct ThrowNewError;
 declare_oxc_lint!(
     /// ### What it does
     ///
-    /// Require `new` when throwing an error.`
+    /// Require `new` when creating an error.
     ///
     /// ### Why is this bad?
     ///
@@ -39,26 +39,29 @@ declare_oxc_lint!(
     /// throw Error('🦄');
     /// throw TypeError('unicorn');
     /// throw lib.TypeError('unicorn');
+    /// const error = Error('🦄');
     ///
     /// // Pass
     /// throw new Error('🦄');
     /// throw new TypeError('unicorn');
     /// throw new lib.TypeError('unicorn');
-    ///
+    /// const error = new Error('🦄');
     /// ```
     ThrowNewError,
-    style
+    style,
+    fix
 );
 
 impl Rule for ThrowNewError {
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::CallExpression(call_expr) = node.kind() else { return };
 
-        let Some(outermost_paren_node) = outermost_paren_parent(node, ctx) else { return };
-
-        let AstKind::ThrowStatement(_) = outermost_paren(outermost_paren_node, ctx).kind() else {
+        // `new` can not be used with optional chaining
+        if call_expr.optional
+            || matches!(ctx.nodes().parent_kind(node.id()), Some(AstKind::ChainExpression(_)))
+        {
             return;
-        };
+        }
 
         match &call_expr.callee.without_parenthesized() {
             Expression::Identifier(v) => {
@@ -79,7 +82,32 @@ impl Rule for ThrowNewError {
             _ => return,
         }
 
-        ctx.diagnostic(ThrowNewErrorDiagnostic(call_expr.span));
+        ctx.diagnostic_with_fix(ThrowNewErrorDiagnostic(call_expr.span), || {
+            let source_text = ctx.source_text();
+            let callee_span = call_expr.callee.span();
+            let callee = callee_span.source_text(source_text);
+            let rest = Span::new(callee_span.end, call_expr.span.end).source_text(source_text);
+            let needs_space = source_text[..call_expr.span.start as usize]
+                .chars()
+                .next_back()
+                .is_some_and(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '$'));
+            let space = if needs_space { " " } else { "" };
+            // `new getGlobalThis().Error()` would construct `getGlobalThis`
+            let content = if contains_call_expression(&call_expr.callee) {
+                format!("{space}new ({callee}){rest}")
+            } else {
+                format!("{space}new {callee}{rest}")
+            };
+            Fix::new(content, call_expr.span).with_kind(FixKind::Safe)
+        });
+    }
+}
+
+fn contains_call_expression(expr: &Expression) -> bool {
+    match expr {
+        Expression::CallExpression(_) => true,
+        Expression::MemberExpression(member_expr) => contains_call_expression(member_expr.object()),
+        _ => false,
     }
 }
 
@@ -110,6 +138,13 @@ fn test() {
         ("throw lib[Error]()", None),
         ("throw lib[\"Error\"]()", None),
         ("throw lib.getError()", None),
+        ("const error = new Error()", None),
+        ("const error = getError()", None),
+        ("Error.captureStackTrace(error)", None),
+        ("throw Error?.()", None),
+        ("throw lib?.Error()", None),
+        ("throw lib.Error?.()", None),
+        ("throw lib?.foo.Error()", None),
     ];
 
     let fail = vec![
@@ -135,7 +170,33 @@ fn test() {
         ("throw getGlobalThis().Error()", None),
         ("throw utils.getGlobalThis().Error()", None),
         ("throw (( getGlobalThis().Error ))()", None),
+        ("const error = Error('foo')", None),
+        ("foo(TypeError('bar'))", None),
+        ("function foo(){return(Error())}", None),
+    ];
+
+    let fix = vec![
+        ("throw Error()", "throw new Error()", None),
+        ("throw (Error)()", "throw new (Error)()", None),
+        ("throw lib.Error()", "throw new lib.Error()", None),
+        ("throw lib.mod.Error()", "throw new lib.mod.Error()", None),
+        ("throw lib[mod].Error()", "throw new lib[mod].Error()", None),
+        ("throw (lib.mod).Error()", "throw new (lib.mod).Error()", None),
+        ("throw Error('foo')", "throw new Error('foo')", None),
+        ("throw CustomError('foo')", "throw new CustomError('foo')", None),
+        ("throw Abc3Error('foo')", "throw new Abc3Error('foo')", None),
+        ("throw (( URIError() ))", "throw (( new URIError() ))", None),
+        ("throw (( URIError ))()", "throw new (( URIError ))()", None),
+        ("throw getGlobalThis().Error()", "throw new (getGlobalThis().Error)()", None),
+        ("throw utils.getGlobalThis().Error()", "throw new (utils.getGlobalThis().Error)()", None),
+        ("throw (( getGlobalThis().Error ))()", "throw new (( getGlobalThis().Error ))()", None),
+        ("const error = Error('foo')", "const error = new Error('foo')", None),
+        ("foo(TypeError('bar'))", "foo(new TypeError('bar'))", None),
+        ("function foo(){return(Error())}", "function foo(){return(new Error())}", None),
     ];
 
-    Tester::new(ThrowNewError::NAME, pass, fail).test_and_snapshot();
+    Tester::new(ThrowNewError::NAME, pass, fail)
+        .expect_fix(fix)
+        .expect_fix_kind(FixKind::Safe)
+        .test_and_snapshot();
 }
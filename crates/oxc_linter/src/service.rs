@@ -1,9 +1,12 @@
 use std::{
     collections::HashMap,
     fs,
-    path::Path,
+    path::{Path, PathBuf},
     rc::Rc,
-    sync::{Arc, Condvar, Mutex},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc, Condvar, Mutex,
+    },
 };
 
 use dashmap::DashMap;
@@ -11,13 +14,22 @@ use rayon::{iter::ParallelBridge, prelude::ParallelIterator};
 use rustc_hash::FxHashSet;
 
 use oxc_allocator::Allocator;
-use oxc_diagnostics::{DiagnosticSender, DiagnosticService, Error, FailedToOpenFileError};
+use oxc_diagnostics::{
+    DiagnosticSender, DiagnosticService, DiagnosticWithFix, Error, FailedToOpenFileError,
+};
 use oxc_parser::Parser;
 use oxc_resolver::{ResolveOptions, Resolver};
 use oxc_semantic::{ModuleRecord, SemanticBuilder};
 use oxc_span::{SourceType, VALID_EXTENSIONS};
 
-use crate::{partial_loader::PartialLoader, Fixer, LintContext, Linter, Message};
+use crate::{
+    partial_loader::PartialLoader, Fix, Fixer, LintCache, LintContext, LintResult, Linter, Message,
+};
+
+/// Maximum number of times the fixes are applied to a file, same as ESLint.
+///
+/// Applying a fix can enable another one, so the fixed code is linted and fixed again.
+const MAX_FIX_PASSES: usize = 10;
 
 #[derive(Clone)]
 pub struct LintService {
@@ -40,6 +52,23 @@ impl LintService {
         &self.runtime.linter
     }
 
+    /// Problems counted over all processed files, complete once [LintService::run] returns.
+    ///
+    /// # Panics
+    pub fn result(&self) -> LintResult {
+        *self.runtime.result.lock().unwrap()
+    }
+
+    /// Whether the warnings exceed [crate::LintOptions::max_warnings].
+    pub fn max_warnings_exceeded(&self) -> bool {
+        self.result().max_warnings_exceeded(self.linter().options().max_warnings)
+    }
+
+    /// Number of files which were parsed, i.e. not found in the cache.
+    pub fn number_of_parsed_files(&self) -> usize {
+        self.runtime.parsed_files.load(Ordering::Relaxed)
+    }
+
     pub fn number_of_dependencies(&self) -> usize {
         self.runtime.module_map.len() - self.runtime.paths.len()
     }
@@ -51,6 +80,12 @@ impl LintService {
             .iter()
             .par_bridge()
             .for_each_with(&self.runtime, |runtime, path| runtime.process_path(path, tx_error));
+        if let Some(cache) = &self.runtime.cache {
+            if let Err(err) = cache.save() {
+                let err = Error::msg(format!("Failed to write the lint cache: {err}"));
+                tx_error.send(Some((PathBuf::new(), vec![err]))).unwrap();
+            }
+        }
         tx_error.send(None).unwrap();
     }
 
@@ -110,11 +145,21 @@ pub struct Runtime {
     resolver: Resolver,
     module_map: ModuleMap,
     cache_state: CacheState,
-    partial_vue_loader: PartialLoader,
+    result: Mutex<LintResult>,
+    cache: Option<LintCache>,
+    parsed_files: AtomicUsize,
 }
 
 impl Runtime {
     fn new(cwd: Box<Path>, paths: &[Box<Path>], linter: Linter) -> Self {
+        let options = linter.options();
+        // Fixes rewrite the files and the import plugin depends on other files,
+        // neither can be served from the cache.
+        let cache = options
+            .cache_location
+            .clone()
+            .filter(|_| !options.fix && !options.import_plugin)
+            .map(|path| LintCache::load(path, &linter));
         Self {
             cwd,
             paths: paths.iter().cloned().collect(),
@@ -122,7 +167,9 @@ impl Runtime {
             resolver: Self::resolver(),
             module_map: ModuleMap::default(),
             cache_state: CacheState::default(),
-            partial_vue_loader: PartialLoader::Vue,
+            result: Mutex::default(),
+            cache,
+            parsed_files: AtomicUsize::new(0),
         }
     }
 
@@ -133,62 +180,149 @@ impl Runtime {
         })
     }
 
-    fn get_source_type_and_text(&self, path: &Path) -> Option<Result<(SourceType, String), Error>> {
-        let read_file = |path: &Path| -> Result<String, Error> {
-            fs::read_to_string(path)
-                .map_err(|e| Error::new(FailedToOpenFileError(path.to_path_buf(), e)))
-        };
-
-        if let Ok(source_type) = SourceType::from_path(path) {
-            match read_file(path) {
-                Ok(source_text) => Some(Ok((source_type, source_text))),
-                Err(e) => Some(Err(e)),
-            }
-        } else {
-            let ext = path.extension().and_then(std::ffi::OsStr::to_str)?;
-            let partial_loader = if ext == "vue" { Some(&self.partial_vue_loader) } else { None };
-            let partial_loader = partial_loader?;
-
-            let source_text = match read_file(path) {
-                Ok(source_text) => source_text,
-                Err(e) => return Some(Err(e)),
-            };
-
-            let ret = partial_loader.parse(&source_text);
-            Some(Ok((ret.source_type, ret.source_text)))
+    /// The text of the file at `path`, `None` if it is neither JavaScript nor handled by a partial
+    /// loader.
+    fn get_source_text(&self, path: &Path) -> Option<Result<String, Error>> {
+        let ext = path.extension().and_then(std::ffi::OsStr::to_str)?;
+        if SourceType::from_path(path).is_err()
+            && PartialLoader::from_extension(ext, self.linter.options()).is_none()
+        {
+            return None;
         }
+        Some(
+            fs::read_to_string(path)
+                .map_err(|e| Error::new(FailedToOpenFileError(path.to_path_buf(), e))),
+        )
     }
 
     fn process_path(&self, path: &Path, tx_error: &DiagnosticSender) {
         if self.init_cache_state(path) {
             return;
         }
-        let Some(source_type_and_text) = self.get_source_type_and_text(path) else { return };
-        let (source_type, source_text) = match source_type_and_text {
+        let Some(source_text) = self.get_source_text(path) else { return };
+        let source_text = match source_text {
             Ok(source_text) => source_text,
             Err(e) => {
+                self.result.lock().unwrap().add(&e, false);
                 tx_error.send(Some((path.to_path_buf(), vec![e]))).unwrap();
                 return;
             }
         };
         let allocator = Allocator::default();
-        let mut messages =
-            self.process_source(path, &allocator, &source_text, source_type, true, tx_error);
-
-        if self.linter.options().fix {
-            let fix_result = Fixer::new(&source_text, messages).fix();
-            fs::write(path, fix_result.fixed_code.as_bytes()).unwrap();
-            messages = fix_result.messages;
-        }
+        let cached_messages = self.cache.as_ref().and_then(|cache| cache.get(path, &source_text));
+        let mut messages = cached_messages.unwrap_or_else(|| {
+            let messages = self.process_file(path, &allocator, &source_text, tx_error);
+            if let Some(cache) = &self.cache {
+                cache.insert(path, &source_text, &messages);
+            }
+            messages
+        });
+
+        let fixed_code = if self.linter.options().fix {
+            let (code, remaining) = self.fix_source(path, &source_text, messages, tx_error);
+            fs::write(path, code.as_bytes()).unwrap();
+            messages = remaining;
+            Some(code)
+        } else {
+            None
+        };
 
         if !messages.is_empty() {
+            let mut result = LintResult::default();
+            for message in &messages {
+                result.add(&message.error, message.is_fixable());
+            }
+            *self.result.lock().unwrap() += result;
+            let fixes = messages.iter().map(|m| m.fix.as_ref().map(Fix::to_diagnostic_fix));
+            let fixes = fixes.collect::<Vec<_>>();
             let errors = messages.into_iter().map(|m| m.error).collect();
             let path = path.strip_prefix(&self.cwd).unwrap_or(path);
-            let diagnostics = DiagnosticService::wrap_diagnostics(path, &source_text, errors);
-            tx_error.send(Some(diagnostics)).unwrap();
+            let source_text = fixed_code.as_deref().unwrap_or(&source_text);
+            let (path, diagnostics) =
+                DiagnosticService::wrap_diagnostics(path, source_text, errors);
+            // The fixes wrap the diagnostics with their source code, for the reporters to find them
+            let diagnostics = diagnostics
+                .into_iter()
+                .zip(fixes)
+                .map(|(diagnostic, fix)| match fix {
+                    Some(fix) => Error::new(DiagnosticWithFix::new(diagnostic, fix)),
+                    None => diagnostic,
+                })
+                .collect();
+            tx_error.send(Some((path, diagnostics))).unwrap();
         }
     }
 
+    /// Apply the fixes of `messages`, then lint and fix the fixed code again until no fix is
+    /// applied anymore or after [MAX_FIX_PASSES] passes.
+    ///
+    /// Returns the fixed code and the messages which are left unfixed, reported on the fixed code.
+    fn fix_source(
+        &self,
+        path: &Path,
+        source_text: &str,
+        messages: Vec<Message>,
+        tx_error: &DiagnosticSender,
+    ) -> (String, Vec<Message<'static>>) {
+        let fix_result = Fixer::new(source_text, messages).fix();
+        let mut fixed = fix_result.fixed;
+        let mut messages = fix_result.messages.into_iter().map(Message::into_owned).collect();
+        let mut fixed_code = fix_result.fixed_code.into_owned();
+        let mut passes = 1;
+        while fixed {
+            let allocator = Allocator::default();
+            let relinted = self.process_file(path, &allocator, &fixed_code, tx_error);
+            if passes == MAX_FIX_PASSES {
+                messages = relinted.into_iter().map(Message::into_owned).collect();
+                break;
+            }
+            let fix_result = Fixer::new(&fixed_code, relinted).fix();
+            fixed = fix_result.fixed;
+            messages = fix_result.messages.into_iter().map(Message::into_owned).collect();
+            fixed_code = fix_result.fixed_code.into_owned();
+            passes += 1;
+        }
+        (fixed_code, messages)
+    }
+
+    /// Lint the code of a file, or each block of code extracted by its partial loader.
+    ///
+    /// The spans of the messages are offsets in `source_text`.
+    fn process_file<'a>(
+        &self,
+        path: &Path,
+        allocator: &'a Allocator,
+        source_text: &'a str,
+        tx_error: &DiagnosticSender,
+    ) -> Vec<Message<'a>> {
+        if let Ok(source_type) = SourceType::from_path(path) {
+            return self.process_source(path, allocator, source_text, source_type, true, tx_error);
+        }
+        let ext = path.extension().and_then(std::ffi::OsStr::to_str).unwrap_or_default();
+        let Some(partial_loader) = PartialLoader::from_extension(ext, self.linter.options()) else {
+            return vec![];
+        };
+        partial_loader
+            .parse(source_text)
+            .into_iter()
+            .flat_map(|source| {
+                let allocator = Allocator::default();
+                let messages = self.process_source(
+                    path,
+                    &allocator,
+                    &source.source_text,
+                    source.source_type,
+                    true,
+                    tx_error,
+                );
+                messages
+                    .into_iter()
+                    .map(|message| message.into_owned().with_offset(source.start))
+                    .collect::<Vec<_>>()
+            })
+            .collect()
+    }
+
     #[allow(clippy::too_many_arguments)]
     fn process_source<'a>(
         &self,
@@ -199,6 +333,7 @@ impl Runtime {
         check_syntax_errors: bool,
         tx_error: &DiagnosticSender,
     ) -> Vec<Message<'a>> {
+        self.parsed_files.fetch_add(1, Ordering::Relaxed);
         let ret = Parser::new(allocator, source_text, source_type)
             .allow_return_outside_function(true)
             .parse();
@@ -317,3 +452,140 @@ impl Runtime {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use std::{env, fs, sync::mpsc};
+
+    use super::LintService;
+    use crate::{rules::RULES, LintResult, Linter};
+
+    #[test]
+    fn fixes_are_applied_until_none_is_left() {
+        let dir = env::temp_dir().join("oxc_linter_service_fix");
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        let file = dir.join("escape.js");
+        // Both rules fix the whole string literal, only one fix is applied per pass and the
+        // fixed code is reported by the other rule again.
+        fs::write(&file, r"const a = '\x1b';").unwrap();
+
+        let rules = RULES
+            .iter()
+            .filter(|rule| matches!(rule.name(), "no-hex-escape" | "escape-case"))
+            .cloned()
+            .collect::<Vec<_>>();
+        let linter = Linter::new().with_rules(rules).with_fix(true);
+        let service = LintService::new(dir.clone().into(), &[file.clone().into()], linter);
+        let (tx_error, _rx_error) = mpsc::channel();
+        service.run(&tx_error);
+
+        assert_eq!(fs::read_to_string(&file).unwrap(), r"const a = '\u001B';");
+        assert_eq!(service.result(), LintResult::default());
+        // the second pass fixes the code of the first, the third is clean
+        assert_eq!(service.number_of_parsed_files(), 3);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    const VUE_SOURCE: &str =
+        "<template>\n  <div>🦀</div>\n</template>\n<script>\nlet a = 1;\ndebugger;\n</script>\n";
+
+    fn lint_vue_file(name: &str, source_text: &str, fix: bool) -> (String, Vec<usize>) {
+        lint_file(name, "debugger.vue", source_text, linter().with_fix(fix))
+    }
+
+    fn linter() -> Linter {
+        let rules = RULES
+            .iter()
+            .filter(|rule| matches!(rule.name(), "no-debugger" | "use-isnan"))
+            .cloned()
+            .collect::<Vec<_>>();
+        Linter::new().with_rules(rules)
+    }
+
+    /// The code of the file after linting, and the offsets of the reported diagnostics
+    fn lint_file(
+        name: &str,
+        file_name: &str,
+        source_text: &str,
+        linter: Linter,
+    ) -> (String, Vec<usize>) {
+        let dir = env::temp_dir().join(name);
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        let file = dir.join(file_name);
+        fs::write(&file, source_text).unwrap();
+
+        let service = LintService::new(dir.clone().into(), &[file.clone().into()], linter);
+        let (tx_error, rx_error) = mpsc::channel();
+        service.run(&tx_error);
+        let offsets = rx_error
+            .into_iter()
+            .map_while(|diagnostics| diagnostics)
+            .flat_map(|(_, diagnostics)| diagnostics)
+            .flat_map(|diagnostic| {
+                diagnostic
+                    .labels()
+                    .into_iter()
+                    .flatten()
+                    .map(|label| label.offset())
+                    .collect::<Vec<_>>()
+            })
+            .collect();
+        let code = fs::read_to_string(&file).unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+        (code, offsets)
+    }
+
+    #[test]
+    fn vue_diagnostics_are_reported_at_their_offsets_in_the_file() {
+        let (code, offsets) = lint_vue_file("oxc_linter_service_vue", VUE_SOURCE, false);
+        assert_eq!(code, VUE_SOURCE);
+        assert_eq!(offsets, vec![VUE_SOURCE.find("debugger").unwrap()]);
+    }
+
+    #[test]
+    fn vue_fixes_are_written_back_into_the_file() {
+        let (code, offsets) = lint_vue_file("oxc_linter_service_vue_fix", VUE_SOURCE, true);
+        assert_eq!(code, VUE_SOURCE.replace("debugger;", ""));
+        assert!(offsets.is_empty());
+    }
+
+    #[test]
+    fn vue_script_blocks_are_linted_separately() {
+        // only the first block is TypeScript
+        let source_text = concat!(
+            "<script lang=\"ts\">\nexport default {} as object;\nif (a == NaN) {}\n</script>\n",
+            "<template><div /></template>\n",
+            "<script setup>\ndebugger;\n</script>\n",
+        );
+        let (_, offsets) = lint_vue_file("oxc_linter_service_vue_blocks", source_text, false);
+        let expected = [source_text.find("NaN").unwrap(), source_text.find("debugger").unwrap()];
+        assert_eq!(offsets, expected);
+    }
+
+    #[test]
+    fn markdown_code_blocks_are_linted_with_the_markdown_plugin() {
+        let source_text =
+            "# Title\n\n- item\n\n  ```ts\n  if (a == NaN) {}\n  ```\n\n```js\ndebugger;\n```\n";
+        let (_, offsets) =
+            lint_file("oxc_linter_service_markdown_off", "README.md", source_text, linter());
+        assert!(offsets.is_empty());
+
+        let linter = linter().with_markdown_plugin(true);
+        let (_, offsets) =
+            lint_file("oxc_linter_service_markdown", "README.md", source_text, linter);
+        let expected = [source_text.find("NaN").unwrap(), source_text.find("debugger").unwrap()];
+        assert_eq!(offsets, expected);
+    }
+
+    #[test]
+    fn html_scripts_are_linted_as_modules_and_classic_scripts() {
+        let source_text = include_str!("../fixtures/html/index.html");
+        let (_, offsets) =
+            lint_file("oxc_linter_service_html", "index.html", source_text, linter());
+        let expected =
+            [source_text.find("debugger;").unwrap(), source_text.find("NaN) {}").unwrap()];
+        assert_eq!(offsets, expected);
+    }
+}
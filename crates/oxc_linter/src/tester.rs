@@ -9,23 +9,64 @@ use oxc_diagnostics::DiagnosticService;
 use serde_json::Value;
 
 use crate::{
-    config::parse_settings, rules::RULES, Fixer, LintOptions, LintService, LintSettings, Linter,
-    RuleEnum,
+    config::parse_settings, rules::RULES, FixFilter, FixKind, Fixer, LintOptions, LintService,
+    LintSettings, Linter, RuleEnum,
 };
 
 #[derive(Eq, PartialEq)]
 enum TestResult {
     Passed,
     Failed,
-    Fixed(String),
+    Fixed {
+        code: String,
+        /// The code after applying each suggestion on its own
+        suggestions: Vec<String>,
+        /// All reported problems were fixed
+        complete: bool,
+    },
+}
+
+/// A source to lint, with its rule configuration, settings and an optional path.
+///
+/// Tuples of `(source, config)`, `(source, config, settings)` and
+/// `(source, config, settings, path)` convert into a test case.
+#[derive(Debug, Clone)]
+pub struct TestCase {
+    source: String,
+    config: Option<Value>,
+    settings: Option<Value>,
+    /// Defaults to the path of the `Tester`
+    path: Option<PathBuf>,
+}
+
+impl<S: Into<String>> From<(S, Option<Value>)> for TestCase {
+    fn from((source, config): (S, Option<Value>)) -> Self {
+        Self { source: source.into(), config, settings: None, path: None }
+    }
+}
+
+impl<S: Into<String>> From<(S, Option<Value>, Option<Value>)> for TestCase {
+    fn from((source, config, settings): (S, Option<Value>, Option<Value>)) -> Self {
+        Self { source: source.into(), config, settings, path: None }
+    }
+}
+
+impl<S: Into<String>> From<(S, Option<Value>, Option<Value>, Option<PathBuf>)> for TestCase {
+    fn from(
+        (source, config, settings, path): (S, Option<Value>, Option<Value>, Option<PathBuf>),
+    ) -> Self {
+        Self { source: source.into(), config, settings, path }
+    }
 }
 
 pub struct Tester {
     rule_name: &'static str,
     rule_path: PathBuf,
-    expect_pass: Vec<(String, Option<Value>, Option<Value>)>,
-    expect_fail: Vec<(String, Option<Value>, Option<Value>)>,
+    expect_pass: Vec<TestCase>,
+    expect_fail: Vec<TestCase>,
     expect_fix: Vec<(String, String, Option<Value>)>,
+    expect_fix_kind: Option<FixKind>,
+    expect_suggestions: Vec<(String, Vec<String>, Option<Value>)>,
     snapshot: String,
     current_working_directory: Box<Path>,
     import_plugin: bool,
@@ -34,32 +75,22 @@ pub struct Tester {
 }
 
 impl Tester {
-    pub fn new<S: Into<String>>(
+    pub fn new<T: Into<TestCase>>(
         rule_name: &'static str,
-        expect_pass: Vec<(S, Option<Value>)>,
-        expect_fail: Vec<(S, Option<Value>)>,
+        expect_pass: Vec<T>,
+        expect_fail: Vec<T>,
     ) -> Self {
-        let expect_pass =
-            expect_pass.into_iter().map(|(s, r)| (s.into(), r, None)).collect::<Vec<_>>();
-        let expect_fail =
-            expect_fail.into_iter().map(|(s, r)| (s.into(), r, None)).collect::<Vec<_>>();
         Self::new_with_settings(rule_name, expect_pass, expect_fail)
     }
 
-    pub fn new_with_settings<S: Into<String>>(
+    pub fn new_with_settings<T: Into<TestCase>>(
         rule_name: &'static str,
-        expect_pass: Vec<(S, Option<Value>, Option<Value>)>,
-        expect_fail: Vec<(S, Option<Value>, Option<Value>)>,
+        expect_pass: Vec<T>,
+        expect_fail: Vec<T>,
     ) -> Self {
         let rule_path = PathBuf::from(rule_name.replace('-', "_")).with_extension("tsx");
-        let expect_pass = expect_pass
-            .into_iter()
-            .map(|(s, r, settings)| (s.into(), r, settings))
-            .collect::<Vec<_>>();
-        let expect_fail = expect_fail
-            .into_iter()
-            .map(|(s, r, settings)| (s.into(), r, settings))
-            .collect::<Vec<_>>();
+        let expect_pass = expect_pass.into_iter().map(Into::into).collect::<Vec<_>>();
+        let expect_fail = expect_fail.into_iter().map(Into::into).collect::<Vec<_>>();
         let current_working_directory =
             env::current_dir().unwrap().join("fixtures/import").into_boxed_path();
         Self {
@@ -68,6 +99,8 @@ impl Tester {
             expect_pass,
             expect_fail,
             expect_fix: vec![],
+            expect_fix_kind: None,
+            expect_suggestions: vec![],
             snapshot: String::new(),
             current_working_directory,
             import_plugin: false,
@@ -81,11 +114,9 @@ impl Tester {
         expect_pass: Vec<S>,
         expect_fail: Vec<S>,
     ) -> Self {
-        let expect_pass =
-            expect_pass.into_iter().map(|s| (s.into(), None, None)).collect::<Vec<_>>();
-        let expect_fail =
-            expect_fail.into_iter().map(|s| (s.into(), None, None)).collect::<Vec<_>>();
-        Self::new_with_settings(rule_name, expect_pass, expect_fail)
+        let expect_pass = expect_pass.into_iter().map(|s| (s.into(), None)).collect::<Vec<_>>();
+        let expect_fail = expect_fail.into_iter().map(|s| (s.into(), None)).collect::<Vec<_>>();
+        Self::new(rule_name, expect_pass, expect_fail)
     }
 
     pub fn update_expect_pass_fail<S: Into<String>>(
@@ -94,9 +125,9 @@ impl Tester {
         expect_fail: Vec<S>,
     ) -> Self {
         self.expect_pass =
-            expect_pass.into_iter().map(|s| (s.into(), None, None)).collect::<Vec<_>>();
+            expect_pass.into_iter().map(|s| TestCase::from((s, None))).collect::<Vec<_>>();
         self.expect_fail =
-            expect_fail.into_iter().map(|s| (s.into(), None, None)).collect::<Vec<_>>();
+            expect_fail.into_iter().map(|s| TestCase::from((s, None))).collect::<Vec<_>>();
         self
     }
 
@@ -127,10 +158,31 @@ impl Tester {
         self
     }
 
+    /// Assert that every fix produced by the `expect_fix` cases is of `kind`.
+    pub fn expect_fix_kind(mut self, kind: FixKind) -> Self {
+        self.expect_fix_kind = Some(kind);
+        self
+    }
+
+    /// The code after applying each suggestion of a test case, in the order they are reported.
+    pub fn expect_suggestions<S: Into<String>>(
+        mut self,
+        expect_suggestions: Vec<(S, Vec<S>, Option<Value>)>,
+    ) -> Self {
+        self.expect_suggestions = expect_suggestions
+            .into_iter()
+            .map(|(s, suggestions, r)| {
+                (s.into(), suggestions.into_iter().map(Into::into).collect(), r)
+            })
+            .collect::<Vec<_>>();
+        self
+    }
+
     pub fn test(&mut self) {
         self.test_pass();
         self.test_fail();
         self.test_fix();
+        self.test_suggestions();
     }
 
     pub fn test_and_snapshot(&mut self) {
@@ -146,26 +198,44 @@ impl Tester {
     }
 
     fn test_pass(&mut self) {
-        for (test, config, settings) in self.expect_pass.clone() {
-            let result = self.run(&test, config, false, &settings);
+        for TestCase { source, config, settings, path } in self.expect_pass.clone() {
+            let result = self.run(&source, config, false, &settings, path.as_deref());
             let passed = result == TestResult::Passed;
-            assert!(passed, "expect test to pass: {test} {}", self.snapshot);
+            assert!(passed, "expect test to pass: {source} {}", self.snapshot);
         }
     }
 
     fn test_fail(&mut self) {
-        for (test, config, settings) in self.expect_fail.clone() {
-            let result = self.run(&test, config, false, &settings);
+        for TestCase { source, config, settings, path } in self.expect_fail.clone() {
+            let result = self.run(&source, config, false, &settings, path.as_deref());
             let failed = result == TestResult::Failed;
-            assert!(failed, "expect test to fail: {test}");
+            assert!(failed, "expect test to fail: {source}");
         }
     }
 
     fn test_fix(&mut self) {
         for (test, expected, config) in self.expect_fix.clone() {
-            let result = self.run(&test, config, true, &None);
-            if let TestResult::Fixed(fixed_str) = result {
-                assert_eq!(expected, fixed_str);
+            let result = self.run(&test, config.clone(), true, &None, None);
+            if let TestResult::Fixed { code, complete, .. } = result {
+                assert_eq!(expected, code);
+                // The fixes must not be reported again, overlapping fixes are left for the next pass.
+                if complete {
+                    let result = self.run(&code, config, false, &None, None);
+                    assert!(result == TestResult::Passed, "expect fixed code to pass: {code}");
+                }
+            } else {
+                unreachable!()
+            }
+        }
+    }
+
+    fn test_suggestions(&mut self) {
+        for (test, expected, config) in self.expect_suggestions.clone() {
+            let result = self.run(&test, config, true, &None, None);
+            if let TestResult::Fixed { code, suggestions, .. } = result {
+                // suggestions are never applied by the fixer
+                assert_eq!(test, code, "expect suggestions not to be applied: {test}");
+                assert_eq!(expected, suggestions, "unexpected suggestions: {test}");
             } else {
                 unreachable!()
             }
@@ -178,13 +248,16 @@ impl Tester {
         config: Option<Value>,
         is_fix: bool,
         settings: &Option<Value>,
+        path: Option<&Path>,
     ) -> TestResult {
+        let rule_path = path.unwrap_or(&self.rule_path);
         let allocator = Allocator::default();
         let rule = self.find_rule().read_json(config);
         let lint_settings: LintSettings =
             settings.as_ref().map_or_else(LintSettings::default, parse_settings);
         let options = LintOptions::default()
             .with_fix(is_fix)
+            .with_fix_kind(FixFilter::All)
             .with_import_plugin(self.import_plugin)
             .with_jest_plugin(self.jest_plugin)
             .with_jsx_a11y_plugin(self.jsx_a11y_plugin);
@@ -193,9 +266,9 @@ impl Tester {
             .with_rules(vec![rule])
             .with_settings(lint_settings);
         let path_to_lint = if self.import_plugin {
-            self.current_working_directory.join(&self.rule_path)
+            self.current_working_directory.join(rule_path)
         } else {
-            self.rule_path.clone()
+            rule_path.to_path_buf()
         };
         let lint_service = LintService::from_linter(
             self.current_working_directory.clone(),
@@ -211,16 +284,33 @@ impl Tester {
         }
 
         if is_fix {
+            if let Some(expected) = self.expect_fix_kind {
+                for fix in result.iter().filter_map(|message| message.fix.as_ref()) {
+                    assert_eq!(expected, fix.kind, "unexpected fix kind: {source_text}");
+                }
+            }
+            let suggestions = result
+                .iter()
+                .flat_map(|message| &message.suggestions)
+                .map(|fix| {
+                    let (start, end) = (fix.span.start as usize, fix.span.end as usize);
+                    format!("{}{}{}", &source_text[..start], fix.content, &source_text[end..])
+                })
+                .collect();
+            // A single pass like ESLint's `RuleTester`, the expected output of ported tests
+            // may still contain problems.
             let fix_result = Fixer::new(source_text, result).fix();
-            return TestResult::Fixed(fix_result.fixed_code.to_string());
+            return TestResult::Fixed {
+                code: fix_result.fixed_code.to_string(),
+                suggestions,
+                complete: fix_result.messages.is_empty(),
+            };
         }
 
-        let diagnostic_path = if self.import_plugin {
-            self.rule_path.strip_prefix(&self.current_working_directory).unwrap()
-        } else {
-            &self.rule_path
-        }
-        .to_string_lossy();
+        let diagnostic_path = rule_path
+            .strip_prefix(&self.current_working_directory)
+            .unwrap_or(rule_path)
+            .to_string_lossy();
         let handler = GraphicalReportHandler::new_themed(GraphicalTheme::unicode_nocolor());
         for diagnostic in result {
             let diagnostic = diagnostic.error.with_source_code(source_text.to_string());
@@ -83,7 +83,7 @@ pub fn is_jest_file(ctx: &LintContext) -> bool {
         return true;
     };
 
-    let import_entries = &ctx.semantic().module_record().import_entries;
+    let import_entries = &ctx.module_record().import_entries;
 
     return import_entries.iter().any(|import_entry| {
         matches!(import_entry.module_request.name().as_str(), "@jest/globals")
@@ -312,6 +312,7 @@ pub struct ParsedExpectFnCall<'a> {
     pub members: Vec<KnownMemberExpressionProperty<'a>>,
     pub name: Cow<'a, str>,
     pub head: KnownMemberExpressionProperty<'a>,
+    // In `expect(1).toBe(2)`, "2" will be the argument of the matcher
     pub args: &'a oxc_allocator::Vec<'a, Argument<'a>>,
     // In `expect(1).not.resolved.toBe()`, "not", "resolved" will be modifier
     // it save a group of modifier index from members
@@ -330,6 +331,11 @@ impl<'a> ParsedExpectFnCall<'a> {
     pub fn modifiers(&self) -> Vec<&KnownMemberExpressionProperty<'a>> {
         self.modifier_indices.iter().filter_map(|i| self.members.get(*i)).collect::<Vec<_>>()
     }
+    /// The argument of the `expect` call, e.g. `a` in `expect(a).not.toBe(b)`.
+    pub fn expect_argument(&self) -> Option<&'a Argument<'a>> {
+        let Some(Expression::CallExpression(call_expr)) = self.head.parent else { return None };
+        call_expr.arguments.first()
+    }
 }
 
 struct ResolvedJestFn<'a> {
@@ -1,14 +1,15 @@
 use oxc_ast::{
     ast::{
-        CallExpression, Expression, JSXAttributeItem, JSXAttributeName, JSXAttributeValue,
-        JSXChild, JSXElement, JSXElementName, JSXExpression, JSXExpressionContainer,
-        JSXOpeningElement,
+        BindingPatternKind, CallExpression, Expression, ImportDeclarationSpecifier,
+        JSXAttributeItem, JSXAttributeName, JSXAttributeValue, JSXChild, JSXElement,
+        JSXElementName, JSXExpression, JSXExpressionContainer, JSXOpeningElement,
+        ModuleDeclaration,
     },
     AstKind,
 };
 use oxc_semantic::{AstNode, SymbolFlags};
 
-use crate::{JsxA11y, LintContext, LintSettings};
+use crate::{ast_util::get_declaration_of_variable, JsxA11y, LintContext, LintSettings};
 
 pub fn is_create_element_call(call_expr: &CallExpression) -> bool {
     if let Some(member_expr) = call_expr.callee.get_member_expr() {
@@ -18,6 +19,50 @@ pub fn is_create_element_call(call_expr: &CallExpression) -> bool {
     false
 }
 
+/// `React.createElement(...)`, or a call to `createElement` imported or destructured from React,
+/// e.g. `import { createElement } from 'react'` or `const { createElement } = React`
+pub fn is_react_create_element_call(call_expr: &CallExpression, ctx: &LintContext) -> bool {
+    match call_expr.callee.without_parenthesized() {
+        Expression::MemberExpression(member_expr) => {
+            member_expr.static_property_name() == Some("createElement")
+                && member_expr.object().is_specific_id("React")
+        }
+        Expression::Identifier(ident) => {
+            let Some(node) = get_declaration_of_variable(ident, ctx) else { return false };
+            match node.kind() {
+                AstKind::ModuleDeclaration(ModuleDeclaration::ImportDeclaration(import_decl)) => {
+                    import_decl.source.value == "react"
+                        && import_decl.specifiers.iter().flatten().any(|specifier| {
+                            matches!(specifier, ImportDeclarationSpecifier::ImportSpecifier(specifier)
+                                if specifier.local.name == ident.name
+                                    && specifier.imported.name().as_str() == "createElement")
+                        })
+                }
+                AstKind::VariableDeclarator(decl) => {
+                    let is_react = match decl.init.as_ref().map(Expression::without_parenthesized) {
+                        Some(Expression::Identifier(init)) => init.name == "React",
+                        Some(Expression::CallExpression(call)) => {
+                            call.common_js_require().is_some_and(|source| source.value == "react")
+                        }
+                        _ => false,
+                    };
+                    let BindingPatternKind::ObjectPattern(pattern) = &decl.id.kind else {
+                        return false;
+                    };
+                    is_react
+                        && pattern.properties.iter().any(|prop| {
+                            prop.key.is_specific_static_name("createElement")
+                                && matches!(&prop.value.kind, BindingPatternKind::BindingIdentifier(local)
+                                    if local.name == ident.name)
+                        })
+                }
+                _ => false,
+            }
+        }
+        _ => false,
+    }
+}
+
 pub fn has_jsx_prop<'a, 'b>(
     node: &'b JSXOpeningElement<'a>,
     target_prop: &'b str,
@@ -169,7 +214,7 @@ pub fn get_element_type(context: &LintContext, element: &JSXOpeningElement) -> O
         return None;
     };
 
-    let LintSettings { jsx_a11y } = context.settings();
+    let LintSettings { jsx_a11y, .. } = context.settings();
     let JsxA11y { polymorphic_prop_name, components } = jsx_a11y;
 
     if let Some(polymorphic_prop_name_value) = polymorphic_prop_name {
@@ -10,6 +10,7 @@ use oxc_ast::{
     AstKind,
 };
 use oxc_semantic::AstNode;
+use oxc_span::GetSpan;
 use oxc_syntax::operator::LogicalOperator;
 
 pub fn is_node_value_not_dom_node(expr: &Expression) -> bool {
@@ -243,3 +244,36 @@ pub fn is_same_member_expression(
 
     return is_same_reference(left.object(), right.object(), ctx);
 }
+
+/// Whether replacing `node` with code starting with `[` or `(` needs a leading `;` to not
+/// continue the previous statement, e.g. `foo\nArray.from(bar)` to `foo\n;[...bar]`
+pub fn needs_leading_semicolon(node: &AstNode, ctx: &LintContext) -> bool {
+    let start = node.kind().span().start;
+    let Some(stmt) = ctx.nodes().iter_parents(node.id()).find(|parent| {
+        matches!(parent.kind(), AstKind::ExpressionStatement(stmt) if stmt.span.start == start)
+    }) else {
+        return false;
+    };
+    // Only a statement in a list can follow a statement without a semicolon
+    let mut parents = ctx.nodes().iter_parents(stmt.id()).skip(1).map(AstNode::kind);
+    let is_in_statement_list = match (parents.next(), parents.next()) {
+        (Some(AstKind::FunctionBody(_)), Some(AstKind::ArrowExpression(arrow))) => {
+            !arrow.expression
+        }
+        (
+            Some(
+                AstKind::Program(_)
+                | AstKind::BlockStatement(_)
+                | AstKind::FunctionBody(_)
+                | AstKind::SwitchCase(_)
+                | AstKind::StaticBlock(_),
+            ),
+            _,
+        ) => true,
+        _ => false,
+    };
+    is_in_statement_list
+        && ctx.source_text()[..start as usize]
+            .trim_end()
+            .ends_with(|c: char| !matches!(c, ';' | '{' | '}' | ':'))
+}
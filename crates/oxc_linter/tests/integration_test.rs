@@ -21,6 +21,15 @@ declare_oxc_lint_test!(
     correctness
 );
 
+struct TestRule3;
+
+declare_oxc_lint_test!(
+    /// Dummy description3
+    TestRule3,
+    correctness,
+    fix
+);
+
 #[test]
 fn test_declare_oxc_lint() {
     // Simple, multiline documentation
@@ -31,4 +40,7 @@ fn test_declare_oxc_lint() {
 
     // Auto-generated kebab-case name
     assert_eq!(TestRule::NAME, "test-rule");
+
+    // Rules are not fixable unless declared with `fix`
+    assert_eq!((TestRule::FIXABLE, TestRule3::FIXABLE), (false, true));
 }
@@ -89,13 +89,19 @@ pub fn declare_all_lint_rules(metadata: AllLintRulesMeta) -> TokenStream {
                 }
             }
 
+            pub fn fixable(&self) -> bool {
+                match self {
+                    #(Self::#struct_names(_) => #struct_names::FIXABLE),*
+                }
+            }
+
             pub fn documentation(&self) -> Option<&'static str> {
                 match self {
                     #(Self::#struct_names(_) => #struct_names::documentation()),*
                 }
             }
 
-            pub fn plugin_name(&self) -> &str {
+            pub fn plugin_name(&self) -> &'static str {
                 match self {
                     #(Self::#struct_names(_) => #mod_names),*
                 }
@@ -9,6 +9,8 @@ use syn::{
 pub struct LintRuleMeta {
     name: Ident,
     category: Ident,
+    /// The rule fixes the problems it reports, declared with a trailing `fix`
+    fixable: bool,
     documentation: String,
     pub used_in_test: bool,
 }
@@ -34,15 +36,26 @@ impl Parse for LintRuleMeta {
         input.parse::<Token!(,)>()?;
         let category = input.parse()?;
 
+        let fixable = if input.peek(Token!(,)) && input.peek2(Ident) {
+            input.parse::<Token!(,)>()?;
+            let ident = input.parse::<Ident>()?;
+            if ident != "fix" {
+                return Err(Error::new_spanned(ident, "expected `fix`"));
+            }
+            true
+        } else {
+            false
+        };
+
         // Ignore the rest
         input.parse::<TokenStream>()?;
 
-        Ok(Self { name: struct_name, category, documentation, used_in_test: false })
+        Ok(Self { name: struct_name, category, fixable, documentation, used_in_test: false })
     }
 }
 
 pub fn declare_oxc_lint(metadata: LintRuleMeta) -> TokenStream {
-    let LintRuleMeta { name, category, documentation, used_in_test } = metadata;
+    let LintRuleMeta { name, category, fixable, documentation, used_in_test } = metadata;
     let canonical_name = name.to_string().to_case(Case::Kebab);
     let category = match category.to_string().as_str() {
         "correctness" => quote! { RuleCategory::Correctness },
@@ -69,6 +82,8 @@ pub fn declare_oxc_lint(metadata: LintRuleMeta) -> TokenStream {
 
             const CATEGORY: RuleCategory = #category;
 
+            const FIXABLE: bool = #fixable;
+
             fn documentation() -> Option<&'static str> {
                 Some(#documentation)
             }
@@ -10,6 +10,8 @@ mod declare_oxc_lint;
 /// 1. The documentation
 /// 2. The lint's struct
 ///
+/// followed by the category and, for rules which fix the problems they report, `fix`.
+///
 /// # Example
 ///
 /// ```
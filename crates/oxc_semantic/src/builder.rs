@@ -154,15 +154,11 @@ impl<'a> SemanticBuilder<'a> {
     }
 
     pub fn build(mut self, program: &Program<'a>) -> SemanticBuilderReturn<'a> {
-        if self.source_type.is_typescript_definition() {
-            self.scope.add_scope(None, ScopeFlags::Top);
-        } else {
-            self.visit_program(program);
+        self.visit_program(program);
 
-            // Checking syntax error on module record requires scope information from the previous AST pass
-            if self.check_syntax_error {
-                EarlyErrorJavaScript::check_module_record(&self);
-            }
+        // Checking syntax error on module record requires scope information from the previous AST pass
+        if self.check_syntax_error {
+            EarlyErrorJavaScript::check_module_record(&self);
         }
 
         let semantic = Semantic {
@@ -63,7 +63,7 @@ impl ModuleRecordBuilder {
                         });
                     });
 
-                    self.add_module_request(&module_request);
+                    self.add_module_request(&module_request, false);
                 }
             }
         }
@@ -77,12 +77,12 @@ impl ModuleRecordBuilder {
         self.module_record
     }
 
-    fn add_module_request(&mut self, name_span: &NameSpan) {
+    fn add_module_request(&mut self, name_span: &NameSpan, is_type: bool) {
         self.module_record
             .requested_modules
             .entry(name_span.name().clone())
             .or_default()
-            .push(name_span.span());
+            .push(RequestedModule::new(name_span.span(), is_type));
     }
 
     fn add_import_entry(&mut self, entry: ImportEntry) {
@@ -210,10 +210,19 @@ impl ModuleRecordBuilder {
     }
 
     fn visit_import_declaration(&mut self, decl: &ImportDeclaration) {
+        let module_request = NameSpan::new(decl.source.value.clone(), decl.source.span);
         if decl.import_kind.is_type() {
+            self.add_module_request(&module_request, true);
             return;
         }
-        let module_request = NameSpan::new(decl.source.value.clone(), decl.source.span);
+        // `import { type A, type B } from 'a'`
+        let is_type = decl.specifiers.as_ref().is_some_and(|specifiers| {
+            !specifiers.is_empty()
+                && specifiers.iter().all(|specifier| {
+                    matches!(specifier, ImportDeclarationSpecifier::ImportSpecifier(specifier)
+                        if specifier.import_kind.is_type())
+                })
+        });
         if let Some(specifiers) = &decl.specifiers {
             for specifier in specifiers {
                 let (import_name, local_name) = match specifier {
@@ -240,7 +249,7 @@ impl ModuleRecordBuilder {
                 });
             }
         }
-        self.add_module_request(&module_request);
+        self.add_module_request(&module_request, is_type);
     }
 
     fn visit_export_all_declaration(&mut self, decl: &ExportAllDeclaration) {
@@ -263,7 +272,7 @@ impl ModuleRecordBuilder {
         if let Some(exported_name) = &decl.exported {
             self.add_export_binding(exported_name.name().clone(), exported_name.span());
         }
-        self.add_module_request(&module_request);
+        self.add_module_request(&module_request, decl.export_kind.is_type());
     }
 
     fn visit_export_default_declaration(&mut self, decl: &ExportDefaultDeclaration) {
@@ -296,6 +305,9 @@ impl ModuleRecordBuilder {
 
     fn visit_export_named_declaration(&mut self, decl: &ExportNamedDeclaration) {
         if decl.export_kind.is_type() {
+            if let Some(source) = &decl.source {
+                self.add_module_request(&NameSpan::new(source.value.clone(), source.span), true);
+            }
             return;
         }
         // ignore all TypeScript syntax as they overload
@@ -307,7 +319,7 @@ impl ModuleRecordBuilder {
             decl.source.as_ref().map(|source| NameSpan::new(source.value.clone(), source.span));
 
         if let Some(module_request) = &module_request {
-            self.add_module_request(module_request);
+            self.add_module_request(module_request, false);
         }
 
         if let Some(decl) = &decl.declaration {
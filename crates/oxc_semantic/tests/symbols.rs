@@ -1,6 +1,7 @@
 mod util;
 
 use oxc_semantic::SymbolFlags;
+use oxc_span::SourceType;
 use util::SemanticTester;
 
 #[test]
@@ -87,3 +88,13 @@ fn test_types_simple() {
         .has_number_of_references(1)
         .test();
 }
+
+#[test]
+fn test_definition_file() {
+    let source_type = SourceType::default().with_module(true).with_typescript_definition(true);
+    SemanticTester::new("interface A {}\nexport type B = A;", source_type)
+        .has_root_symbol("A")
+        .contains_flags(SymbolFlags::Interface)
+        .has_number_of_references(1)
+        .test();
+}
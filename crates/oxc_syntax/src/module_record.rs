@@ -25,8 +25,10 @@ pub struct ModuleRecord {
     ///   import ImportClause FromClause
     ///   import ModuleSpecifier
     ///   export ExportFromClause FromClause
+    ///   import type ImportClause FromClause (TypeScript)
+    ///   export type ExportFromClause FromClause (TypeScript)
     /// Keyed by ModuleSpecifier, valued by all node occurrences
-    pub requested_modules: IndexMap<Atom, Vec<Span>, BuildHasherDefault<FxHasher>>,
+    pub requested_modules: IndexMap<Atom, Vec<RequestedModule>, BuildHasherDefault<FxHasher>>,
 
     /// `[[LoadedModules]]`
     ///
@@ -72,6 +74,29 @@ impl ModuleRecord {
     }
 }
 
+/// An occurrence of a ModuleSpecifier in [`ModuleRecord::requested_modules`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RequestedModule {
+    span: Span,
+    is_type: bool,
+}
+
+impl RequestedModule {
+    pub fn new(span: Span, is_type: bool) -> Self {
+        Self { span, is_type }
+    }
+
+    pub fn span(&self) -> Span {
+        self.span
+    }
+
+    /// `import type` and `export type`, or an import of which all specifiers are types, e.g.
+    /// `import { type A } from 'a'`
+    pub fn is_type(&self) -> bool {
+        self.is_type
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct NameSpan {
     name: Atom,